@@ -0,0 +1,69 @@
+-- =====================================================
+-- DB Writer 상태 / Dead-letter 테이블
+-- =====================================================
+-- 설명: 엔진 DB Writer 스레드의 신뢰성을 위한 테이블입니다.
+--
+-- db_writer_state:
+-- - DB Writer가 어디까지 반영했는지(high-water mark) 저장
+-- - 배치 트랜잭션과 같은 트랜잭션에서 갱신 → 배치 반영과 원자적
+-- - 재시작 시 WAL 시퀀스를 이 값보다 큰 값에서 시작
+--   (WAL에서 DB 명령을 다시 만들지 않음 → 크래시로 반영되지 못한 명령은 이 위치 이후 WAL 레코드로 수동 확인)
+--
+-- db_writer_dead_letters:
+-- - 재시도해도 반영할 수 없는 명령(poison command)을 보관
+-- - 배치 전체를 막지 않도록 격리 후 다음 명령 진행
+-- - 운영자가 원인 확인 후 수동 처리
+--
+-- orders.last_wal_seq:
+-- - 주문 상태를 마지막으로 변경한 WAL 시퀀스
+-- - 더 오래된 명령이 재생되어도 최신 상태를 덮어쓰지 않음
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS db_writer_state (
+    -- 단일 행 테이블 (id = 1)
+    id SMALLINT PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+
+    -- high-water mark: (wal_seq, command_index)
+    -- 하나의 WAL 레코드에서 여러 DB 명령이 파생될 수 있으므로 순번까지 저장
+    high_water_seq BIGINT NOT NULL DEFAULT 0,     -- 마지막으로 반영된 WAL 시퀀스
+    high_water_index INTEGER NOT NULL DEFAULT 0,  -- 해당 시퀀스 안에서의 명령 순번
+
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO db_writer_state (id) VALUES (1) ON CONFLICT (id) DO NOTHING;
+
+COMMENT ON TABLE db_writer_state IS 'DB Writer 진행 위치 (high-water mark, 단일 행)';
+COMMENT ON COLUMN db_writer_state.high_water_seq IS 'DB에 반영된 마지막 WAL 시퀀스';
+COMMENT ON COLUMN db_writer_state.high_water_index IS '마지막 WAL 시퀀스 안에서 반영된 명령 순번';
+
+CREATE TABLE IF NOT EXISTS db_writer_dead_letters (
+    id BIGSERIAL PRIMARY KEY,
+
+    -- 명령 위치
+    wal_seq BIGINT NOT NULL,
+    command_index INTEGER NOT NULL,
+
+    -- 명령 내용
+    command_type VARCHAR(50) NOT NULL,  -- InsertOrder, UpdateOrderStatus, InsertTrade, UpdateBalance
+    payload JSONB NOT NULL,             -- 명령 전체 (JSON)
+
+    -- 실패 정보
+    error TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 1,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE db_writer_dead_letters IS 'DB Writer가 반영하지 못한 명령 (poison command 격리)';
+COMMENT ON COLUMN db_writer_dead_letters.wal_seq IS '명령이 파생된 WAL 시퀀스';
+COMMENT ON COLUMN db_writer_dead_letters.command_index IS 'WAL 시퀀스 안에서의 명령 순번';
+COMMENT ON COLUMN db_writer_dead_letters.payload IS '명령 내용 (JSON)';
+COMMENT ON COLUMN db_writer_dead_letters.error IS '마지막 에러 메시지';
+
+CREATE INDEX IF NOT EXISTS idx_db_writer_dead_letters_wal_seq ON db_writer_dead_letters(wal_seq);
+
+ALTER TABLE orders
+ADD COLUMN IF NOT EXISTS last_wal_seq BIGINT NOT NULL DEFAULT 0;
+
+COMMENT ON COLUMN orders.last_wal_seq IS '주문 상태를 마지막으로 변경한 WAL 시퀀스 (오래된 명령 재생 방지)';
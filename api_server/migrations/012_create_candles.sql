@@ -0,0 +1,43 @@
+-- =====================================================
+-- 캔들 테이블 (candles)
+-- =====================================================
+-- 설명: 거래쌍/주기별 OHLCV 캔들 (차트용)
+--
+-- 생성 방식:
+-- - CandleService가 엔진 체결 이벤트(TradeExecuted)로 메모리에서 증분 집계
+-- - 변경된 캔들을 1초마다 upsert (같은 캔들은 최신 값으로 덮어씀 → 멱등)
+-- - 서버 시작 시 trades 테이블에서 마지막 캔들 이후 구간을 재집계 (backfill)
+--
+-- 주기: 1m, 5m, 15m, 1h, 4h, 1d (UTC 기준 정렬)
+-- 체결이 없는 구간은 행이 없음 (클라이언트가 이전 종가로 채움)
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS candles (
+    -- 거래쌍
+    base_mint VARCHAR(255) NOT NULL,   -- 기준 자산 (예: SOL)
+    quote_mint VARCHAR(255) NOT NULL,  -- 기준 통화 (예: USDT)
+
+    -- 주기 / 시작 시간
+    interval VARCHAR(8) NOT NULL,      -- 1m, 5m, 15m, 1h, 4h, 1d
+    open_time TIMESTAMPTZ NOT NULL,    -- 캔들 시작 시간 (주기 단위로 내림)
+
+    -- OHLCV
+    open DECIMAL(30, 9) NOT NULL,          -- 시가 (첫 체결가)
+    high DECIMAL(30, 9) NOT NULL,          -- 고가
+    low DECIMAL(30, 9) NOT NULL,           -- 저가
+    close DECIMAL(30, 9) NOT NULL,         -- 종가 (마지막 체결가)
+    volume DECIMAL(30, 9) NOT NULL,        -- 거래량 (base_mint)
+    quote_volume DECIMAL(30, 9) NOT NULL,  -- 거래대금 (quote_mint)
+    trade_count BIGINT NOT NULL,           -- 체결 건수
+
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (base_mint, quote_mint, interval, open_time)
+);
+
+COMMENT ON TABLE candles IS '거래쌍/주기별 OHLCV 캔들 (체결 이벤트로 증분 집계)';
+COMMENT ON COLUMN candles.interval IS '캔들 주기 (1m, 5m, 15m, 1h, 4h, 1d)';
+COMMENT ON COLUMN candles.open_time IS '캔들 시작 시간 (UTC, 주기 단위로 내림)';
+COMMENT ON COLUMN candles.volume IS '거래량 (base_mint 기준)';
+COMMENT ON COLUMN candles.quote_volume IS '거래대금 (quote_mint 기준, price * amount 합계)';
+COMMENT ON COLUMN candles.trade_count IS '캔들 구간의 체결 건수';
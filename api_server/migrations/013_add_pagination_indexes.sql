@@ -0,0 +1,33 @@
+-- =====================================================
+-- 목록 API 커서 페이지네이션 인덱스
+-- =====================================================
+-- 설명: /orders/my, /trades/my, /trades 의 keyset 페이지네이션용 복합 인덱스
+--
+-- 조회 방식:
+-- - ORDER BY created_at DESC, id DESC
+-- - 다음 페이지: WHERE (created_at, id) < (커서 시간, 커서 ID)
+-- - OFFSET 없이 인덱스에서 바로 이어서 읽음 (내역이 많은 사용자도 일정한 비용)
+--
+-- 기존 인덱스 대체:
+-- - idx_orders_user_id (user_id, status, created_at DESC) → id 추가
+-- - idx_trades_pair_time (base_mint, quote_mint, created_at DESC) → id 추가
+-- - idx_trades_buyer / idx_trades_seller (…, created_at DESC) → id 추가
+-- =====================================================
+
+-- 내 주문 (전체 / 상태별 / 거래쌍별)
+CREATE INDEX IF NOT EXISTS idx_orders_user_time ON orders(user_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_orders_user_status_time ON orders(user_id, status, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_orders_user_pair_time ON orders(user_id, base_mint, quote_mint, created_at DESC, id DESC);
+DROP INDEX IF EXISTS idx_orders_user_id;
+
+-- 거래쌍별 체결
+CREATE INDEX IF NOT EXISTS idx_trades_pair_time_id ON trades(base_mint, quote_mint, created_at DESC, id DESC);
+DROP INDEX IF EXISTS idx_trades_pair_time;
+
+-- 내 체결 (매수/매도 쪽을 각각 조회 후 병합, 거래쌍 필터 포함)
+CREATE INDEX IF NOT EXISTS idx_trades_buyer_time ON trades(buyer_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_trades_seller_time ON trades(seller_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_trades_buyer_pair_time ON trades(buyer_id, base_mint, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_trades_seller_pair_time ON trades(seller_id, base_mint, created_at DESC, id DESC);
+DROP INDEX IF EXISTS idx_trades_buyer;
+DROP INDEX IF EXISTS idx_trades_seller;
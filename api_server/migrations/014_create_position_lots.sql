@@ -0,0 +1,84 @@
+-- =====================================================
+-- 포지션 원가 lot 테이블 (positions, position_lots, position_realized_pnl)
+-- =====================================================
+-- 설명: 사용자/자산별 매수 lot과 매도 체결별 실현 손익
+--
+-- 갱신 방식:
+-- - 포지션 조회 시 positions.last_trade_id 이후의 체결만 lot에 반영 (증분)
+-- - 전체 체결 내역 재계산은 원가 계산 방식을 바꿀 때만 수행
+-- - positions 행을 FOR UPDATE로 잠근 트랜잭션 안에서 반영 (동시 조회 시 중복 반영 없음)
+--
+-- 원가 계산 방식 (cost_basis_method):
+-- - fifo: 먼저 매수한 lot부터 차감
+-- - lifo: 나중에 매수한 lot부터 차감
+-- - average_cost: 모든 lot을 같은 비율로 차감 (평균 단가 유지)
+--
+-- 수수료: 체결 금액 × 거래쌍 수수료율 (quote_mint 기준)
+-- - 매수: lot 원가에 포함
+-- - 매도: 매도 대금에서 차감
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS positions (
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    mint VARCHAR(255) NOT NULL,                          -- 자산 (예: SOL)
+
+    cost_basis_method VARCHAR(16) NOT NULL DEFAULT 'fifo',  -- fifo, lifo, average_cost
+
+    -- 누적 통계
+    realized_pnl DECIMAL(30, 9) NOT NULL DEFAULT 0,        -- 실현 손익 합계 (quote_mint)
+    total_fees DECIMAL(30, 9) NOT NULL DEFAULT 0,          -- 수수료 합계 (quote_mint)
+    total_buy_trades BIGINT NOT NULL DEFAULT 0,            -- 매수 체결 수
+    total_sell_trades BIGINT NOT NULL DEFAULT 0,           -- 매도 체결 수
+    total_bought_amount DECIMAL(30, 9) NOT NULL DEFAULT 0, -- 총 매수 수량
+    total_bought_cost DECIMAL(30, 9) NOT NULL DEFAULT 0,   -- 총 매수 금액 (price * amount, 수수료 제외)
+
+    -- 반영 위치
+    last_trade_id BIGINT NOT NULL DEFAULT 0,               -- 마지막으로 반영한 체결 ID
+
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, mint)
+);
+
+CREATE TABLE IF NOT EXISTS position_lots (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    mint VARCHAR(255) NOT NULL,
+
+    trade_id BIGINT NOT NULL,                   -- lot을 만든 매수 체결 ID
+    opened_at TIMESTAMPTZ NOT NULL,             -- 매수 체결 시간
+
+    amount DECIMAL(30, 9) NOT NULL,             -- 매수 수량
+    cost DECIMAL(30, 9) NOT NULL,               -- 매수 원가 (price * amount + 수수료)
+    remaining_amount DECIMAL(30, 9) NOT NULL,   -- 남은 수량 (0이면 모두 청산)
+    remaining_cost DECIMAL(30, 9) NOT NULL      -- 남은 수량의 원가
+);
+
+CREATE INDEX IF NOT EXISTS idx_position_lots_open
+    ON position_lots(user_id, mint, id)
+    WHERE remaining_amount > 0;
+
+CREATE TABLE IF NOT EXISTS position_realized_pnl (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    mint VARCHAR(255) NOT NULL,
+
+    trade_id BIGINT NOT NULL,                 -- 매도 체결 ID
+    closed_at TIMESTAMPTZ NOT NULL,           -- 매도 체결 시간
+
+    amount DECIMAL(30, 9) NOT NULL,           -- lot에서 차감한 수량
+    proceeds DECIMAL(30, 9) NOT NULL,         -- 매도 대금 (수수료 차감 후)
+    cost_basis DECIMAL(30, 9) NOT NULL,       -- 차감한 lot 원가
+    fee DECIMAL(30, 9) NOT NULL,              -- 매도 수수료
+    realized_pnl DECIMAL(30, 9) NOT NULL      -- proceeds - cost_basis
+);
+
+CREATE INDEX IF NOT EXISTS idx_position_realized_pnl_user_mint
+    ON position_realized_pnl(user_id, mint, trade_id DESC);
+
+COMMENT ON TABLE positions IS '사용자/자산별 포지션 (원가 계산 방식, 누적 통계, 반영한 마지막 체결)';
+COMMENT ON COLUMN positions.last_trade_id IS '마지막으로 lot에 반영한 체결 ID (이후 체결만 증분 반영)';
+COMMENT ON TABLE position_lots IS '매수 체결별 원가 lot (수수료 포함)';
+COMMENT ON COLUMN position_lots.remaining_cost IS '남은 수량의 원가 (매도 시 원가 계산 방식에 따라 차감)';
+COMMENT ON TABLE position_realized_pnl IS '매도 체결별 실현 손익';
+COMMENT ON COLUMN position_realized_pnl.amount IS 'lot에서 차감한 수량 (입금 등 원가가 없는 수량은 제외)';
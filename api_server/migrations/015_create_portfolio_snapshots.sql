@@ -0,0 +1,28 @@
+-- =====================================================
+-- 일별 자산 기록 테이블 (portfolio_snapshots)
+-- =====================================================
+-- 설명: 사용자별 CEX 잔고 총 평가액을 하루에 한 번 기록 (수익률 차트용)
+--
+-- 생성 방식:
+-- - PortfolioService가 매일 00:00 UTC에 잔고가 있는 모든 사용자를 평가해서 저장
+-- - 서버 시작 시에도 한 번 실행 (정각에 서버가 내려가 있었던 날을 채움)
+-- - 같은 날짜는 처음 기록을 유지 (ON CONFLICT DO NOTHING)
+--
+-- 평가 방식: 잔고 × 기준 통화 가격 (직접 거래쌍이 없으면 다른 거래쌍을 거쳐 환산)
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS portfolio_snapshots (
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    quote_mint VARCHAR(255) NOT NULL,       -- 기준 통화 (예: USDT)
+    snapshot_date DATE NOT NULL,            -- 기록 날짜 (UTC)
+
+    total_value DECIMAL(30, 9) NOT NULL,    -- 총 평가액 (quote_mint)
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, quote_mint, snapshot_date)
+);
+
+COMMENT ON TABLE portfolio_snapshots IS '사용자별 일별 CEX 잔고 총 평가액';
+COMMENT ON COLUMN portfolio_snapshots.snapshot_date IS '기록 날짜 (UTC, 하루에 한 행)';
+COMMENT ON COLUMN portfolio_snapshots.total_value IS '잔고 총 평가액 (환산 경로가 없는 자산 제외)';
@@ -0,0 +1,49 @@
+-- =====================================================
+-- 내역 내보내기 작업 테이블 (export_jobs)
+-- =====================================================
+-- 설명: 회계/세금용 체결·주문·잔고 기록·입금·출금 내보내기 (CSV / JSON) 비동기 작업
+--
+-- 처리 방식:
+-- - 요청 시 pending 행 생성 → 백그라운드에서 DB를 스트리밍으로 읽어 파일에 기록
+-- - 완료되면 completed + 파일 경로, 만료 시간 기록 (만료 후 파일 삭제 → expired)
+-- - 서버 재시작 시 pending/running 작업은 처음부터 다시 실행
+--
+-- 상태: pending → running → completed | failed, completed → expired
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS export_jobs (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+
+    -- 요청
+    dataset VARCHAR(32) NOT NULL,           -- trades, orders, balance_snapshots, deposits, withdrawals
+    format VARCHAR(8) NOT NULL,             -- csv, json
+    range_from TIMESTAMPTZ NOT NULL,        -- 시작 시간 (포함)
+    range_to TIMESTAMPTZ NOT NULL,          -- 끝 시간 (미포함)
+
+    -- 진행 상태
+    status VARCHAR(16) NOT NULL DEFAULT 'pending',  -- pending, running, completed, failed, expired
+    row_count BIGINT NOT NULL DEFAULT 0,            -- 기록한 행 수
+    file_path TEXT,                                 -- 완료된 파일 경로 (서버 내부)
+    error TEXT,                                     -- 실패 사유
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ,
+    expires_at TIMESTAMPTZ                          -- 다운로드 가능 기한
+);
+
+CREATE INDEX IF NOT EXISTS idx_export_jobs_user_time ON export_jobs(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_export_jobs_unfinished ON export_jobs(status) WHERE status IN ('pending', 'running');
+
+COMMENT ON TABLE export_jobs IS '체결/주문/잔고 기록 내보내기 작업 (CSV, JSON)';
+COMMENT ON COLUMN export_jobs.range_to IS '끝 시간 (미포함)';
+COMMENT ON COLUMN export_jobs.file_path IS '완료된 파일 경로 (다운로드 API로만 제공)';
+
+-- =====================================================
+-- 일별 자산 기록에 자산별 잔고 추가 (balance_snapshots 내보내기용)
+-- =====================================================
+-- assets: [{"mint", "balance", "available", "locked", "price", "value", "route"}] (PortfolioAsset 배열)
+
+ALTER TABLE portfolio_snapshots ADD COLUMN IF NOT EXISTS assets JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN portfolio_snapshots.assets IS '기록 시점 자산별 잔고/가격/평가액 (PortfolioAsset 배열)';
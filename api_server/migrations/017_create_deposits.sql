@@ -0,0 +1,58 @@
+-- =====================================================
+-- 입금 테이블 (deposits)
+-- =====================================================
+-- 설명: 사용자 입금 주소(Solana 지갑 / SPL 토큰 계정)로 들어온 온체인 입금 기록
+--
+-- 처리 방식:
+-- - 입금 감시(DepositService)가 confirmed 트랜잭션을 발견하면 confirming 행 생성
+-- - finalized 되면 crediting으로 선점 후 엔진 잔고 채널로 입금 → credited
+-- - (signature, mint) UNIQUE: 같은 트랜잭션은 자산별로 한 번만 기록/입금
+--
+-- 상태: confirming → crediting → credited, confirming → failed
+-- - crediting에서 멈춘 행은 입금 여부를 알 수 없으므로 자동 재시도하지 않음 (수동 확인)
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS deposits (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+
+    -- 자산
+    mint VARCHAR(32) NOT NULL,              -- CEX 자산 (예: SOL, USDT)
+    token_mint VARCHAR(64),                 -- SPL 토큰 mint 주소 (네이티브 SOL은 NULL)
+    amount DECIMAL(30, 9) NOT NULL,         -- 입금 수량 (소수 단위)
+
+    -- 온체인 정보
+    address VARCHAR(64) NOT NULL,           -- 입금 주소 (사용자 지갑)
+    signature VARCHAR(128) NOT NULL,        -- 트랜잭션 서명
+    slot BIGINT NOT NULL,
+
+    -- 진행 상태
+    status VARCHAR(16) NOT NULL DEFAULT 'confirming',  -- confirming, crediting, credited, failed
+    error TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),     -- 발견 시간
+    finalized_at TIMESTAMPTZ,
+    credited_at TIMESTAMPTZ,
+
+    CONSTRAINT uq_deposits_signature_mint UNIQUE (signature, mint)
+);
+
+CREATE INDEX IF NOT EXISTS idx_deposits_user_time ON deposits(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_deposits_pending ON deposits(status) WHERE status IN ('confirming', 'crediting');
+
+COMMENT ON TABLE deposits IS '온체인 입금 기록 (트랜잭션 서명 + 자산별 한 번만 입금)';
+COMMENT ON COLUMN deposits.token_mint IS 'SPL 토큰 mint 주소 (네이티브 SOL은 NULL)';
+COMMENT ON COLUMN deposits.status IS 'confirming, crediting, credited, failed';
+
+-- =====================================================
+-- 입금 감시 위치 (deposit_cursors)
+-- =====================================================
+-- 주소별로 마지막으로 처리한 트랜잭션 서명 (다음 조회는 이 서명 이후만)
+
+CREATE TABLE IF NOT EXISTS deposit_cursors (
+    address VARCHAR(64) PRIMARY KEY,        -- 감시 주소 (지갑 또는 SPL 토큰 계정)
+    last_signature VARCHAR(128) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE deposit_cursors IS '입금 감시 주소별 마지막 처리 서명';
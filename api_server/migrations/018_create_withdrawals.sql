@@ -0,0 +1,48 @@
+-- =====================================================
+-- 출금 테이블 (withdrawals)
+-- =====================================================
+-- 설명: CEX 잔고 → 외부 Solana 주소로의 온체인 출금 기록
+--
+-- 처리 방식:
+-- - 요청 시 requested 행 생성 후 엔진 잔고 보류(hold, 주문 잠금과 별도) → held_at 기록
+-- - 승인(자동/수동) → approved
+-- - 핫월렛이 지급 트랜잭션 서명 → 서명/만료 블록 높이 저장 후 전송 → broadcast
+-- - finalized 성공 → confirmed (보류 소진)
+-- - 거절 / 트랜잭션 실패 / 블록해시 만료 → failed (보류 해제)
+-- - 모든 상태 변경은 조건부 UPDATE (WHERE status = 이전 상태)
+--
+-- 상태: requested → approved → broadcast → confirmed
+--       requested | approved | broadcast → failed
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS withdrawals (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+
+    -- 자산
+    mint VARCHAR(32) NOT NULL,              -- CEX 자산 (예: SOL, USDT)
+    token_mint VARCHAR(64),                 -- SPL 토큰 mint 주소 (네이티브 SOL은 NULL)
+    amount DECIMAL(30, 9) NOT NULL CHECK (amount > 0),
+
+    -- 온체인 정보
+    destination VARCHAR(64) NOT NULL,       -- 받는 지갑 주소 (SPL 토큰은 이 지갑의 토큰 계정으로 지급)
+    signature VARCHAR(128),                 -- 지급 트랜잭션 서명 (broadcast 이후)
+    last_valid_block_height BIGINT,         -- 이 높이를 넘기면 트랜잭션 만료
+
+    -- 진행 상태
+    status VARCHAR(16) NOT NULL DEFAULT 'requested',  -- requested, approved, broadcast, confirmed, failed
+    error TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),    -- 요청 시간
+    held_at TIMESTAMPTZ,                              -- 엔진 잔고 보류 시간
+    approved_at TIMESTAMPTZ,
+    broadcast_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ                          -- confirmed / failed 시간
+);
+
+CREATE INDEX IF NOT EXISTS idx_withdrawals_user_time ON withdrawals(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_withdrawals_active ON withdrawals(status) WHERE status IN ('requested', 'approved', 'broadcast');
+
+COMMENT ON TABLE withdrawals IS '온체인 출금 기록 (진행 중인 출금은 엔진 잔고 보류)';
+COMMENT ON COLUMN withdrawals.held_at IS '엔진 잔고 보류 시간 (엔진 시작 시 이 값이 있는 진행 중 출금만 보류 복원)';
+COMMENT ON COLUMN withdrawals.status IS 'requested, approved, broadcast, confirmed, failed';
@@ -0,0 +1,79 @@
+-- =====================================================
+-- 원장 테이블 (ledger_entries, ledger_postings)
+-- =====================================================
+-- 설명: 모든 잔고 이동을 복식부기로 기록하는 append-only 원장
+--
+-- 구조:
+-- - ledger_entries: 분개 (잔고 이동 한 건 = WAL 시퀀스 + 사유 + 참조)
+-- - ledger_postings: 전기 (계정별 증감, 차변 +, 대변 -)
+--   → 분개마다 자산(mint)별 amount 합계 = 0
+--
+-- 계정:
+-- - 사용자 계정 (user_id 있음): available, locked
+-- - 시스템 계정 (user_id NULL): clearing, deposits, withdrawals, fees, adjustments
+--
+-- 처리 방식:
+-- - DB Writer가 user_balances UPDATE와 같은 트랜잭션에서 기록
+-- - 한 배치에 같은 분개의 전기가 모이면 계정별로 합산 (체결 clearing은 상쇄)
+-- - 수정/삭제 없음 (정정은 반대 분개로)
+--
+-- 사유 (reason) / 참조 (reference):
+-- - trade: 체결 ID          - fee: 체결 ID
+-- - deposit: 트랜잭션 서명   - withdrawal / withdrawal_refund: 출금 ID
+-- - lock / unlock: 주문 ID  - adjustment: 설명 (예: initial_balance)
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS ledger_entries (
+    id BIGSERIAL PRIMARY KEY,
+    wal_seq BIGINT NOT NULL,                -- 잔고 변경을 만든 WAL 시퀀스
+    reason VARCHAR(32) NOT NULL,            -- trade, fee, deposit, withdrawal, withdrawal_refund, lock, unlock, adjustment
+    reference VARCHAR(128) NOT NULL,        -- 체결 ID, 주문 ID, 출금 ID, 트랜잭션 서명 (없으면 빈 문자열)
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    UNIQUE (wal_seq, reason, reference)
+);
+
+CREATE TABLE IF NOT EXISTS ledger_postings (
+    id BIGSERIAL PRIMARY KEY,
+    entry_id BIGINT NOT NULL REFERENCES ledger_entries(id),
+    user_id BIGINT,                         -- NULL이면 시스템 계정 (사용자 삭제와 무관하게 보존)
+    mint VARCHAR(32) NOT NULL,
+    account VARCHAR(16) NOT NULL,           -- available, locked, clearing, deposits, withdrawals, fees, adjustments
+    amount DECIMAL(30, 9) NOT NULL CHECK (amount <> 0),  -- 차변 +, 대변 -
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_ledger_postings_user_time ON ledger_postings(user_id, created_at DESC, id DESC) WHERE user_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_ledger_postings_entry ON ledger_postings(entry_id);
+CREATE INDEX IF NOT EXISTS idx_ledger_entries_reference ON ledger_entries(reference);
+
+-- 기존 잔고를 기초 잔액 분개로 기록 (원장 합계 = user_balances)
+INSERT INTO ledger_entries (wal_seq, reason, reference)
+SELECT 0, 'adjustment', 'opening_balance'
+WHERE EXISTS (SELECT 1 FROM user_balances WHERE available <> 0 OR locked <> 0)
+ON CONFLICT (wal_seq, reason, reference) DO NOTHING;
+
+INSERT INTO ledger_postings (entry_id, user_id, mint, account, amount)
+SELECT e.id, b.user_id, b.mint_address, a.account, a.amount
+FROM user_balances b
+CROSS JOIN LATERAL (VALUES ('available', b.available), ('locked', b.locked)) AS a(account, amount)
+JOIN ledger_entries e ON e.wal_seq = 0 AND e.reason = 'adjustment' AND e.reference = 'opening_balance'
+WHERE a.amount <> 0
+  AND NOT EXISTS (SELECT 1 FROM ledger_postings p WHERE p.entry_id = e.id);
+
+INSERT INTO ledger_postings (entry_id, user_id, mint, account, amount)
+SELECT e.id, NULL, p.mint, 'adjustments', -SUM(p.amount)
+FROM ledger_postings p
+JOIN ledger_entries e ON e.id = p.entry_id
+WHERE e.wal_seq = 0 AND e.reason = 'adjustment' AND e.reference = 'opening_balance'
+  AND p.user_id IS NOT NULL
+  AND NOT EXISTS (
+      SELECT 1 FROM ledger_postings s WHERE s.entry_id = e.id AND s.user_id IS NULL
+  )
+GROUP BY e.id, p.mint
+HAVING SUM(p.amount) <> 0;
+
+COMMENT ON TABLE ledger_entries IS '원장 분개 (잔고 이동 한 건, append-only)';
+COMMENT ON TABLE ledger_postings IS '원장 전기 (분개마다 자산별 amount 합계 0)';
+COMMENT ON COLUMN ledger_postings.user_id IS 'NULL이면 시스템 계정 (clearing, deposits, withdrawals, fees, adjustments)';
+COMMENT ON COLUMN ledger_postings.amount IS '차변 +, 대변 -';
@@ -0,0 +1,42 @@
+-- =====================================================
+-- 내부 이체 테이블 (internal_transfers)
+-- =====================================================
+-- 설명: 거래소 사용자 간 available 잔고 이동 기록 (온체인 트랜잭션 없음)
+--
+-- 처리 방식:
+-- - 요청 시 pending 행 생성 (보내는 사용자 + 멱등 키로 UNIQUE → 같은 요청은 한 번만 처리)
+-- - 엔진 스레드에서 양쪽 잔고를 한 번에 이동 (BalanceCommand::Transfer)
+--   → 원장 분개 reason = 'transfer', reference = 이체 ID
+-- - 엔진 결과에 따라 completed / failed
+-- - 재시작 시 이전 프로세스의 pending 행은 원장 분개 유무로 completed / failed 결정
+--
+-- 상태: pending → completed | failed
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS internal_transfers (
+    id BIGSERIAL PRIMARY KEY,
+    from_user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    to_user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    mint VARCHAR(32) NOT NULL,
+    amount DECIMAL(30, 9) NOT NULL CHECK (amount > 0),
+    memo VARCHAR(256),
+
+    idempotency_key VARCHAR(64) NOT NULL,   -- 보내는 사용자가 지정 (재시도 시 같은 값)
+
+    status VARCHAR(16) NOT NULL DEFAULT 'pending',  -- pending, completed, failed
+    error TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ,                       -- completed / failed 시간
+
+    CHECK (from_user_id <> to_user_id),
+    UNIQUE (from_user_id, idempotency_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_internal_transfers_from_time ON internal_transfers(from_user_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_internal_transfers_to_time ON internal_transfers(to_user_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_internal_transfers_pending ON internal_transfers(created_at) WHERE status = 'pending';
+
+COMMENT ON TABLE internal_transfers IS '거래소 사용자 간 내부 이체 (원장 reason = transfer, reference = id)';
+COMMENT ON COLUMN internal_transfers.idempotency_key IS '보내는 사용자별 멱등 키 (같은 키 재요청은 기존 이체 반환)';
+COMMENT ON COLUMN internal_transfers.status IS 'pending, completed, failed';
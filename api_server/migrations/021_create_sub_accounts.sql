@@ -0,0 +1,27 @@
+-- =====================================================
+-- 서브 계정 테이블 (sub_accounts)
+-- =====================================================
+-- 설명: 마스터 사용자가 소유한 서브 계정 (전략별 잔고/주문 분리)
+--
+-- 처리 방식:
+-- - 서브 계정도 users 행 (잔고/주문/체결/원장이 모두 user_id 기준이라 그대로 분리됨)
+--   → 이메일은 내부 도메인의 합성 값, 비밀번호 해시는 사용 불가 값 (로그인 불가)
+-- - 마스터는 X-Sub-Account-Id 헤더로 서브 계정 대신 주문/조회
+-- - 잔고 이동은 내부 이체 (internal_transfers, 원장 reason = transfer)
+-- - 서브 계정 아래에 서브 계정은 만들 수 없음
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS sub_accounts (
+    user_id BIGINT PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    master_user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    label VARCHAR(32) NOT NULL,                     -- 마스터별 유니크 (예: "grid-sol")
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CHECK (user_id <> master_user_id),
+    UNIQUE (master_user_id, label)
+);
+
+CREATE INDEX IF NOT EXISTS idx_sub_accounts_master ON sub_accounts(master_user_id, user_id);
+
+COMMENT ON TABLE sub_accounts IS '마스터 사용자가 소유한 서브 계정 (user_id는 users 행, 로그인 불가)';
+COMMENT ON COLUMN sub_accounts.label IS '마스터별 유니크 이름 (영문 소문자, 숫자, -, _)';
@@ -0,0 +1,39 @@
+-- =====================================================
+-- API 키 테이블 (api_keys)
+-- =====================================================
+-- 설명: 프로그램 매매용 API 키 (비밀번호/JWT 없이 HMAC-SHA256 서명으로 인증)
+--
+-- 처리 방식:
+-- - 생성 시 key_id(공개)와 secret(비공개)을 발급, secret은 생성 응답에서 한 번만 반환
+--   → 서버도 서명을 다시 계산해야 하므로 secret 원문을 저장
+-- - 요청마다 X-Api-Key / X-Api-Timestamp / X-Api-Signature 헤더
+--   → signature = hex(HMAC-SHA256(secret, timestamp + METHOD + path?query + body))
+-- - scopes: read, trade, withdraw (요청 경로별 필요 권한)
+-- - ip_allowlist가 NULL이면 모든 IP 허용
+-- - expires_at이 지나거나 revoked_at이 있으면 사용 불가
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS api_keys (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    key_id VARCHAR(64) NOT NULL UNIQUE,             -- 공개 키 ID (X-Api-Key)
+    secret VARCHAR(128) NOT NULL,                   -- 서명 비밀 값
+    label VARCHAR(64) NOT NULL,
+    scopes TEXT[] NOT NULL,                         -- read, trade, withdraw
+    ip_allowlist TEXT[],                            -- IP 또는 CIDR (NULL: 제한 없음)
+
+    expires_at TIMESTAMPTZ,                         -- NULL: 만료 없음
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CHECK (cardinality(scopes) > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_keys_user ON api_keys(user_id, created_at DESC);
+
+COMMENT ON TABLE api_keys IS '프로그램 매매용 API 키 (HMAC-SHA256 요청 서명)';
+COMMENT ON COLUMN api_keys.key_id IS '공개 키 ID (X-Api-Key 헤더)';
+COMMENT ON COLUMN api_keys.secret IS '서명 비밀 값 (생성 응답에서만 반환)';
+COMMENT ON COLUMN api_keys.scopes IS 'read, trade, withdraw';
+COMMENT ON COLUMN api_keys.ip_allowlist IS '허용 IP / CIDR 목록 (NULL: 제한 없음)';
@@ -0,0 +1,56 @@
+-- =====================================================
+-- 사용자 역할 / 계정 동결 / 마켓 상태 / 관리자 감사 로그
+-- =====================================================
+-- 설명: 관리자 API (/api/admin)에 필요한 컬럼과 테이블
+--
+-- 처리 방식:
+-- - users.role: user(기본), support(조회 + 동결), admin(모든 관리 작업)
+--   → JWT Claims에 포함, 관리자 API는 요청마다 DB 역할도 다시 확인
+-- - users.frozen_at: 동결된 계정은 조회(GET)만 가능 (주문/출금/이체 불가)
+-- - market_statuses: 거래쌍별 상태 (행이 없으면 open)
+--   → cancel_only: 새 주문 불가, 취소 가능 / halted: 새 주문과 취소 모두 불가
+-- - admin_audit_logs: 관리자 작업마다 한 행 (누가, 무엇을, 어디에, 세부 내용)
+-- =====================================================
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS role VARCHAR(20) NOT NULL DEFAULT 'user'
+    CHECK (role IN ('user', 'support', 'admin'));
+ALTER TABLE users ADD COLUMN IF NOT EXISTS frozen_at TIMESTAMPTZ;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS frozen_reason TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_users_frozen ON users(id) WHERE frozen_at IS NOT NULL;
+
+COMMENT ON COLUMN users.role IS '역할: user, support, admin';
+COMMENT ON COLUMN users.frozen_at IS '계정 동결 시간 (NULL: 정상, 동결 시 조회만 가능)';
+COMMENT ON COLUMN users.frozen_reason IS '계정 동결 사유';
+
+CREATE TABLE IF NOT EXISTS market_statuses (
+    base_mint VARCHAR(255) NOT NULL,
+    quote_mint VARCHAR(255) NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('open', 'cancel_only', 'halted')),
+    reason TEXT,
+    updated_by BIGINT REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (base_mint, quote_mint)
+);
+
+COMMENT ON TABLE market_statuses IS '거래쌍별 거래 상태 (행이 없으면 open)';
+COMMENT ON COLUMN market_statuses.status IS 'open: 정상, cancel_only: 취소만 가능, halted: 주문/취소 중지';
+
+CREATE TABLE IF NOT EXISTS admin_audit_logs (
+    id BIGSERIAL PRIMARY KEY,
+    actor_user_id BIGINT NOT NULL REFERENCES users(id),
+    action VARCHAR(64) NOT NULL,                    -- 예: user.freeze, balance.adjust
+    target_type VARCHAR(32) NOT NULL,               -- 예: user, market, fee_config
+    target_id VARCHAR(255),                         -- 예: 사용자 ID, "SOL/USDT"
+    details JSONB NOT NULL DEFAULT '{}',            -- 요청 값, 변경 전/후 등
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_logs_created ON admin_audit_logs(created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_admin_audit_logs_actor ON admin_audit_logs(actor_user_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_admin_audit_logs_target ON admin_audit_logs(target_type, target_id, created_at DESC, id DESC);
+
+COMMENT ON TABLE admin_audit_logs IS '관리자 작업 감사 로그';
+COMMENT ON COLUMN admin_audit_logs.action IS '작업 이름 (예: market.status, fee.update, user.freeze, balance.adjust)';
+COMMENT ON COLUMN admin_audit_logs.details IS '작업 세부 내용 (요청 값, 변경 전/후)';
@@ -0,0 +1,51 @@
+-- =====================================================
+-- 수수료 설정 관리 (등급 / 적용 기간 / 변경 이력)
+-- =====================================================
+-- 설명: 관리자 API로 수수료 설정을 생성/수정/비활성화하고 엔진에 바로 반영
+--
+-- 처리 방식:
+-- - fee_configs.tier: 수수료 등급별 설정 (NULL: 모든 등급)
+-- - fee_configs.effective_from / effective_until: 적용 기간 (until NULL: 기한 없음)
+--   → 같은 (거래쌍, 등급) 범위에서 fee_type과 기간이 겹치는 활성 설정은 서비스에서 거부
+--   → 기간이 겹치지 않으면 같은 범위의 설정을 여러 개 둘 수 있으므로 유니크 인덱스 제거
+-- - users.fee_tier: 사용자 수수료 등급 (NULL: 등급 없음, 모든 등급 설정만 적용)
+-- - fee_config_history: 설정 변경마다 한 행 (변경 전/후 전체 값)
+-- =====================================================
+
+ALTER TABLE fee_configs ADD COLUMN IF NOT EXISTS tier VARCHAR(32);
+ALTER TABLE fee_configs ADD COLUMN IF NOT EXISTS effective_from TIMESTAMPTZ;
+ALTER TABLE fee_configs ADD COLUMN IF NOT EXISTS effective_until TIMESTAMPTZ;
+
+-- 기존 설정은 생성 시점부터 적용된 것으로 간주
+UPDATE fee_configs SET effective_from = created_at WHERE effective_from IS NULL;
+ALTER TABLE fee_configs ALTER COLUMN effective_from SET DEFAULT NOW();
+ALTER TABLE fee_configs ALTER COLUMN effective_from SET NOT NULL;
+
+ALTER TABLE fee_configs DROP CONSTRAINT IF EXISTS chk_fee_configs_effective_window;
+ALTER TABLE fee_configs ADD CONSTRAINT chk_fee_configs_effective_window
+    CHECK (effective_until IS NULL OR effective_until > effective_from);
+
+DROP INDEX IF EXISTS idx_fee_configs_unique_pair;
+
+COMMENT ON COLUMN fee_configs.tier IS '수수료 등급 (NULL이면 모든 등급에 적용, 예: vip1)';
+COMMENT ON COLUMN fee_configs.effective_from IS '적용 시작 시간';
+COMMENT ON COLUMN fee_configs.effective_until IS '적용 종료 시간 (NULL: 기한 없음, 이 시간부터 미적용)';
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS fee_tier VARCHAR(32);
+
+COMMENT ON COLUMN users.fee_tier IS '수수료 등급 (NULL: 등급 없음)';
+
+CREATE TABLE IF NOT EXISTS fee_config_history (
+    id BIGSERIAL PRIMARY KEY,
+    fee_config_id BIGINT NOT NULL REFERENCES fee_configs(id) ON DELETE CASCADE,
+    action VARCHAR(20) NOT NULL CHECK (action IN ('create', 'update', 'deactivate')),
+    old_config JSONB,                               -- 변경 전 (create는 NULL)
+    new_config JSONB NOT NULL,                      -- 변경 후
+    changed_by BIGINT REFERENCES users(id) ON DELETE SET NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_fee_config_history_config ON fee_config_history(fee_config_id, changed_at DESC, id DESC);
+
+COMMENT ON TABLE fee_config_history IS '수수료 설정 변경 이력';
+COMMENT ON COLUMN fee_config_history.action IS 'create: 생성, update: 수정, deactivate: 비활성화';
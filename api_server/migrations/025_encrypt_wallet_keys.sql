@@ -0,0 +1,26 @@
+-- =====================================================
+-- 지갑 개인 키 봉투 암호화 (envelope encryption)
+-- =====================================================
+-- 설명: 지갑마다 데이터 키(AES-256-GCM)로 개인 키를 암호화하고, 데이터 키는 마스터 키로 감싸서 저장
+--
+-- 처리 방식:
+-- - encrypted_private_key: base64(nonce || 데이터 키로 암호화한 개인 키)
+-- - wrapped_data_key: base64(nonce || 마스터 키로 감싼 데이터 키)
+-- - key_version: 데이터 키를 감싼 마스터 키 버전
+--   → 마스터 키 교체 후 `api_server rotate-wallet-keys`로 데이터 키만 새 버전으로 다시 감쌈
+-- - wrapped_data_key / key_version이 NULL인 기존 행은 Base64 평문 (rotate-wallet-keys가 암호화)
+-- =====================================================
+
+ALTER TABLE solana_wallets ADD COLUMN IF NOT EXISTS wrapped_data_key TEXT;
+ALTER TABLE solana_wallets ADD COLUMN IF NOT EXISTS key_version INTEGER;
+
+ALTER TABLE solana_wallets DROP CONSTRAINT IF EXISTS chk_solana_wallets_key_envelope;
+ALTER TABLE solana_wallets ADD CONSTRAINT chk_solana_wallets_key_envelope
+    CHECK ((wrapped_data_key IS NULL) = (key_version IS NULL));
+
+-- 재암호화 대상 조회용 (key_version이 현재 버전이 아닌 행)
+CREATE INDEX IF NOT EXISTS idx_solana_wallets_key_version ON solana_wallets(key_version);
+
+COMMENT ON COLUMN solana_wallets.encrypted_private_key IS '암호화된 Private Key (base64(nonce || AES-256-GCM 암호문), key_version이 NULL이면 Base64 평문)';
+COMMENT ON COLUMN solana_wallets.wrapped_data_key IS '마스터 키로 감싼 데이터 키 (base64(nonce || AES-256-GCM 암호문))';
+COMMENT ON COLUMN solana_wallets.key_version IS '데이터 키를 감싼 마스터 키 버전 (NULL: 암호화 전 기존 행)';
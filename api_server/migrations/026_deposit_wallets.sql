@@ -0,0 +1,21 @@
+-- =====================================================
+-- 입금 전용 지갑
+-- =====================================================
+-- 설명: CEX 입금 주소로 발급한 지갑은 입금 감시 대상이 되고 지갑 API 전송이 막힘
+--
+-- 처리 방식:
+-- - is_deposit_address = TRUE: DepositService가 감시, POST /api/wallets/:id/transfer 거부
+--   → 입금 후 같은 SOL을 온체인으로 빼내고 CEX 잔고를 다시 출금하는 이중 인출 방지
+-- - 입금 주소 발급 시 (GET /api/cex/deposits/address) 현재 서명 위치를 deposit_cursors에 저장한 뒤 전환
+--   → 전환 이전에 받은 금액은 입금으로 인정하지 않음
+-- - 기존 지갑은 모두 입금 주소로 감시되어 왔으므로 입금 전용으로 전환
+-- =====================================================
+
+ALTER TABLE solana_wallets ADD COLUMN IF NOT EXISTS is_deposit_address BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE solana_wallets SET is_deposit_address = TRUE;
+
+-- 입금 감시 대상 조회용
+CREATE INDEX IF NOT EXISTS idx_solana_wallets_deposit_address ON solana_wallets(id) WHERE is_deposit_address;
+
+COMMENT ON COLUMN solana_wallets.is_deposit_address IS 'CEX 입금 주소 여부 (TRUE: 입금 감시 대상, 지갑 API 전송 불가)';
@@ -0,0 +1,27 @@
+-- =====================================================
+-- API 키 서명 비밀 값 봉투 암호화 (envelope encryption)
+-- =====================================================
+-- 설명: 지갑 개인 키와 같은 방식(WALLET_MASTER_KEY_FILE)으로 secret을 암호화해서 저장
+--
+-- 처리 방식:
+-- - secret: base64(nonce || 데이터 키로 암호화한 secret, AAD: key_id)
+-- - wrapped_data_key: base64(nonce || 마스터 키로 감싼 데이터 키)
+-- - key_version: 데이터 키를 감싼 마스터 키 버전
+--   → `api_server rotate-wallet-keys`가 지갑과 함께 API 키도 현재 버전으로 다시 감쌈
+-- - wrapped_data_key / key_version이 NULL인 기존 행은 평문 (rotate-wallet-keys가 암호화)
+-- =====================================================
+
+ALTER TABLE api_keys ALTER COLUMN secret TYPE TEXT;
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS wrapped_data_key TEXT;
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS key_version INTEGER;
+
+ALTER TABLE api_keys DROP CONSTRAINT IF EXISTS chk_api_keys_secret_envelope;
+ALTER TABLE api_keys ADD CONSTRAINT chk_api_keys_secret_envelope
+    CHECK ((wrapped_data_key IS NULL) = (key_version IS NULL));
+
+-- 재암호화 대상 조회용 (key_version이 현재 버전이 아닌 행)
+CREATE INDEX IF NOT EXISTS idx_api_keys_key_version ON api_keys(key_version);
+
+COMMENT ON COLUMN api_keys.secret IS '서명 비밀 값 (base64(nonce || AES-256-GCM 암호문), key_version이 NULL이면 평문)';
+COMMENT ON COLUMN api_keys.wrapped_data_key IS '마스터 키로 감싼 데이터 키 (base64(nonce || AES-256-GCM 암호문))';
+COMMENT ON COLUMN api_keys.key_version IS '데이터 키를 감싼 마스터 키 버전 (NULL: 암호화 전 기존 행)';
@@ -0,0 +1,24 @@
+-- =====================================================
+-- API 키 서명 재사용 방지 테이블 (api_key_signatures)
+-- =====================================================
+-- 설명: 검증을 통과한 요청 서명을 기록해서 같은 요청을 다시 보내는 재전송(replay) 공격을 막음
+--
+-- 처리 방식:
+-- - 서명 검증 후 INSERT ... ON CONFLICT DO NOTHING → 이미 있으면 재사용으로 거부
+--   → 여러 서버 인스턴스 / 재시작 후에도 같은 서명을 한 번만 허용
+-- - expires_at: 요청 타임스탬프 + recv window (이후에는 타임스탬프 검사에서 거부되므로 기억할 필요 없음)
+-- - 만료된 행은 ApiKeyService가 주기적으로 삭제
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS api_key_signatures (
+    signature VARCHAR(64) PRIMARY KEY,              -- hex(HMAC-SHA256), 소문자
+    key_id VARCHAR(64) NOT NULL,                    -- 공개 키 ID (X-Api-Key)
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 만료된 서명 정리용
+CREATE INDEX IF NOT EXISTS idx_api_key_signatures_expires_at ON api_key_signatures(expires_at);
+
+COMMENT ON TABLE api_key_signatures IS '사용된 API 키 요청 서명 (재사용 방지, expires_at 이후 삭제)';
+COMMENT ON COLUMN api_key_signatures.expires_at IS '요청 타임스탬프 + recv window';
@@ -0,0 +1,1082 @@
+use crate::domains::admin::models::admin::{
+    AdjustBalanceRequest, AdjustBalanceResponse, FreezeUserRequest, RejectWithdrawalRequest, SetFeeTierRequest,
+    SetMarketStatusRequest, SetRoleRequest, WalStatusResponse,
+};
+use crate::domains::admin::models::audit_log::{AuditLog, AuditLogFilter};
+use crate::domains::auth::models::user::User;
+use crate::domains::cex::handlers::pagination;
+use crate::domains::cex::models::fee::{CreateFeeConfigRequest, FeeConfig, FeeConfigHistory, UpdateFeeConfigRequest};
+use crate::domains::cex::services::{validate_tier, FeeConfigChange, FeeScheduleReload};
+use crate::domains::cex::models::market_status::MarketStatusEntry;
+use crate::domains::cex::models::reconciliation::{ReconciliationReport, ReconciliationStatus, RunReconciliationRequest};
+use crate::domains::cex::engine::wal::WalDurability;
+use crate::shared::database::UserRepository;
+use crate::shared::middleware::role::{AdminUser, StaffUser};
+use crate::shared::services::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::{IntoParams, ToSchema};
+
+// =====================================================
+// Admin Handler
+// =====================================================
+// 역할: 운영 관리 API (/api/admin)
+//
+// 권한:
+// - support 이상 (StaffUser): 조회, 계정 동결
+// - admin (AdminUser): 역할 변경, 동결 해제, 잔고 조정, 거래쌍 상태, 수수료 설정/등급, 출금 승인/거절,
+//   잔고 정합성 검사 (결과에 모든 사용자 잔고가 포함됨)
+//
+// 특징:
+// - 모든 변경 작업은 성공한 뒤 감사 로그 기록 (admin_audit_logs, 기록 실패 시 500)
+// - 역할은 요청마다 DB에서 다시 확인 (토큰 발급 뒤 역할이 바뀐 경우)
+// =====================================================
+
+type ErrorResponse = (StatusCode, Json<serde_json::Value>);
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> ErrorResponse {
+    (status, Json(json!({ "error": message.into() })))
+}
+
+fn internal_error(context: &str, e: anyhow::Error) -> ErrorResponse {
+    error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("{}: {:#}", context, e))
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Users (사용자)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// 사용자 조회 핸들러 (역할, 동결 상태 포함)
+/// Get user handler
+///
+/// # Response
+/// - 200: 사용자 정보
+/// - 403: support 이상 아님
+/// - 404: 사용자 없음
+#[utoipa::path(
+    get,
+    path = "/api/admin/users/{id}",
+    params(("id" = u64, Path, description = "User ID")),
+    responses(
+        (status = 200, description = "User", body = User),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Support role required"),
+        (status = 404, description = "User not found")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_user(
+    State(app_state): State<AppState>,
+    StaffUser(_staff): StaffUser,
+    Path(user_id): Path<u64>,
+) -> Result<Json<User>, ErrorResponse> {
+    let user = UserRepository::new(app_state.db.pool().clone())
+        .get_user_by_id(user_id)
+        .await
+        .map_err(|e| internal_error("Failed to fetch user", e))?
+        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "User not found"))?;
+
+    Ok(Json(user))
+}
+
+/// 역할 변경 핸들러
+/// Set user role handler
+///
+/// 자기 자신의 역할은 바꿀 수 없습니다 (마지막 관리자가 스스로 권한을 잃는 것 방지).
+/// 바뀐 역할은 다음 access token부터 Claims에 반영되고, 관리자 API는 요청마다 DB 역할을 확인합니다.
+///
+/// # Response
+/// - 200: 변경 완료
+/// - 400: 자기 자신
+/// - 403: admin 아님
+/// - 404: 사용자 없음
+#[utoipa::path(
+    put,
+    path = "/api/admin/users/{id}/role",
+    params(("id" = u64, Path, description = "User ID")),
+    request_body = SetRoleRequest,
+    responses(
+        (status = 200, description = "Role updated", body = serde_json::Value),
+        (status = 400, description = "Cannot change own role"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 404, description = "User not found")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn set_user_role(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(user_id): Path<u64>,
+    Json(request): Json<SetRoleRequest>,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    if user_id == admin.user_id {
+        return Err(error_response(StatusCode::BAD_REQUEST, "Cannot change your own role"));
+    }
+
+    let service = &app_state.admin_state.admin_service;
+    let previous = service
+        .set_role(user_id, request.role)
+        .await
+        .map_err(|e| internal_error("Failed to set role", e))?
+        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "User not found"))?;
+
+    service
+        .record(
+            admin.user_id,
+            "user.role",
+            "user",
+            Some(&user_id.to_string()),
+            json!({ "from": previous, "to": request.role }),
+        )
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(json!({ "user_id": user_id, "role": request.role })))
+}
+
+/// 계정 동결 핸들러
+/// Freeze user handler
+///
+/// 동결된 계정(서브 계정 포함)은 조회(GET)만 가능하고 주문/출금/이체 등은 403입니다.
+/// 이미 걸려 있는 주문은 그대로 둡니다 (필요하면 거래쌍 상태로 처리).
+///
+/// # Response
+/// - 200: 동결 완료
+/// - 400: 자기 자신 또는 사유 없음
+/// - 403: support 이상 아님
+/// - 404: 사용자 없음
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{id}/freeze",
+    params(("id" = u64, Path, description = "User ID")),
+    request_body = FreezeUserRequest,
+    responses(
+        (status = 200, description = "User frozen", body = serde_json::Value),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Support role required"),
+        (status = 404, description = "User not found")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn freeze_user(
+    State(app_state): State<AppState>,
+    StaffUser(staff): StaffUser,
+    Path(user_id): Path<u64>,
+    Json(request): Json<FreezeUserRequest>,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    let reason = request.reason.trim();
+    if reason.is_empty() {
+        return Err(error_response(StatusCode::BAD_REQUEST, "reason is required"));
+    }
+    if user_id == staff.user_id {
+        return Err(error_response(StatusCode::BAD_REQUEST, "Cannot freeze your own account"));
+    }
+
+    let found = app_state
+        .auth_state
+        .frozen_accounts
+        .set(user_id, Some(reason))
+        .await
+        .map_err(|e| internal_error("Failed to freeze user", e))?;
+    if !found {
+        return Err(error_response(StatusCode::NOT_FOUND, "User not found"));
+    }
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(staff.user_id, "user.freeze", "user", Some(&user_id.to_string()), json!({ "reason": reason }))
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(json!({ "user_id": user_id, "frozen": true })))
+}
+
+/// 계정 동결 해제 핸들러
+/// Unfreeze user handler
+///
+/// # Response
+/// - 200: 해제 완료
+/// - 403: admin 아님
+/// - 404: 사용자 없음
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{id}/unfreeze",
+    params(("id" = u64, Path, description = "User ID")),
+    responses(
+        (status = 200, description = "User unfrozen", body = serde_json::Value),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 404, description = "User not found")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn unfreeze_user(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(user_id): Path<u64>,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    let found = app_state
+        .auth_state
+        .frozen_accounts
+        .set(user_id, None)
+        .await
+        .map_err(|e| internal_error("Failed to unfreeze user", e))?;
+    if !found {
+        return Err(error_response(StatusCode::NOT_FOUND, "User not found"));
+    }
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "user.unfreeze", "user", Some(&user_id.to_string()), json!({}))
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(json!({ "user_id": user_id, "frozen": false })))
+}
+
+/// 사용자 수수료 등급 변경 핸들러
+/// Set user fee tier handler
+///
+/// 변경 후 엔진 수수료 스케줄에 바로 반영합니다.
+///
+/// # Response
+/// - 200: 변경 완료
+/// - 400: 등급 형식 오류 (영문 소문자, 숫자, _, -, 최대 32자)
+/// - 403: admin 아님
+/// - 404: 사용자 없음
+#[utoipa::path(
+    put,
+    path = "/api/admin/users/{id}/fee-tier",
+    params(("id" = u64, Path, description = "User ID")),
+    request_body = SetFeeTierRequest,
+    responses(
+        (status = 200, description = "Fee tier updated", body = serde_json::Value),
+        (status = 400, description = "Invalid fee tier"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn set_user_fee_tier(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(user_id): Path<u64>,
+    Json(request): Json<SetFeeTierRequest>,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    if let Some(tier) = &request.fee_tier {
+        validate_tier(tier).map_err(|message| error_response(StatusCode::BAD_REQUEST, message))?;
+    }
+
+    let found = app_state
+        .cex_state
+        .fee_service
+        .set_user_fee_tier(user_id, request.fee_tier.as_deref())
+        .await
+        .map_err(|e| internal_error("Failed to set fee tier", e))?;
+    if !found {
+        return Err(error_response(StatusCode::NOT_FOUND, "User not found"));
+    }
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "user.fee_tier", "user", Some(&user_id.to_string()), json!({ "fee_tier": request.fee_tier }))
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    reload_fee_schedule_after_change(&app_state).await?;
+    Ok(Json(json!({ "user_id": user_id, "fee_tier": request.fee_tier })))
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Balances (잔고)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// 잔고 조정 핸들러
+/// Adjust balance handler
+///
+/// 엔진을 통해 available을 증감하고 원장에 adjustment로 기록합니다 (참조: memo).
+/// 회수(음수)는 available 안에서만 가능합니다 (locked는 건드리지 않음).
+///
+/// # Response
+/// - 200: 조정 결과
+/// - 400: 금액 0, memo 없음, available 부족
+/// - 403: admin 아님
+#[utoipa::path(
+    post,
+    path = "/api/admin/balances/adjust",
+    request_body = AdjustBalanceRequest,
+    responses(
+        (status = 200, description = "Balance adjusted", body = AdjustBalanceResponse),
+        (status = 400, description = "Invalid amount or insufficient balance"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn adjust_balance(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Json(request): Json<AdjustBalanceRequest>,
+) -> Result<Json<AdjustBalanceResponse>, ErrorResponse> {
+    let memo = request.memo.trim();
+    if memo.is_empty() {
+        return Err(error_response(StatusCode::BAD_REQUEST, "memo is required"));
+    }
+
+    let service = &app_state.admin_state.admin_service;
+    let adjustment = service
+        .adjust_balance(request.user_id, &request.mint, request.amount, memo)
+        .await
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Failed to adjust balance: {:#}", e)))?;
+
+    service
+        .record(
+            admin.user_id,
+            "balance.adjust",
+            "user",
+            Some(&request.user_id.to_string()),
+            json!({
+                "mint": request.mint,
+                "amount": request.amount,
+                "memo": memo,
+                "available_before": adjustment.available_before,
+                "available_after": adjustment.available_after,
+            }),
+        )
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(AdjustBalanceResponse {
+        user_id: request.user_id,
+        mint: request.mint,
+        amount: request.amount,
+        available_before: adjustment.available_before,
+        available_after: adjustment.available_after,
+    }))
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Engine (엔진 상태)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// WAL 내구성 정책 / fsync 지표 핸들러
+/// Get WAL durability and fsync metrics handler
+#[utoipa::path(
+    get,
+    path = "/api/admin/engine/wal",
+    responses(
+        (status = 200, description = "WAL durability policy and fsync metrics", body = WalStatusResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Support role required")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_wal_status(
+    State(app_state): State<AppState>,
+    StaffUser(_staff): StaffUser,
+) -> Json<WalStatusResponse> {
+    let (durability, metrics) = {
+        let engine = app_state.engine.lock().await;
+        (engine.wal_durability(), engine.wal_metrics())
+    };
+    let (group_commit_max_delay_us, group_commit_max_entries) = match durability {
+        WalDurability::GroupCommit { max_delay, max_entries } => {
+            (Some(max_delay.as_micros() as u64), Some(max_entries))
+        }
+        _ => (None, None),
+    };
+
+    Json(WalStatusResponse {
+        durability: durability.as_str().to_string(),
+        group_commit_max_delay_us,
+        group_commit_max_entries,
+        entries_written: metrics.entries_written,
+        fsync_count: metrics.fsync_count,
+        fsync_avg_us: metrics.fsync_avg_us(),
+        fsync_max_us: metrics.fsync_max_us,
+        fsync_last_us: metrics.fsync_last_us,
+        avg_batch_size: metrics.avg_batch_size(),
+        last_batch_size: metrics.last_batch_size,
+        max_batch_size: metrics.max_batch_size,
+        acks_completed: metrics.acks_completed,
+    })
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Reconciliation (잔고 정합성)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// 엔진 ↔ DB 잔고 정합성 검사 상태 조회 핸들러 (지표 + 마지막 결과)
+/// Get reconciliation status handler
+///
+/// # Response
+/// - 200: 지표 + 마지막 결과
+/// - 403: admin 아님
+#[utoipa::path(
+    get,
+    path = "/api/admin/reconciliation",
+    responses(
+        (status = 200, description = "Reconciliation status", body = ReconciliationStatus),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_reconciliation_status(
+    State(app_state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+) -> Json<ReconciliationStatus> {
+    Json(app_state.cex_state.reconciliation_service.status())
+}
+
+/// 정합성 검사 실행 핸들러
+/// Run reconciliation handler
+///
+/// # Request Body
+/// - repair: 엔진과 다른 DB 행을 엔진 값으로 복구 (RECONCILIATION_REPAIR=manual/auto일 때만)
+///
+/// # Response
+/// - 200: 검사 결과
+/// - 403: admin 아님
+/// - 409: 복구 비활성화 또는 이미 실행 중
+#[utoipa::path(
+    post,
+    path = "/api/admin/reconciliation/run",
+    request_body = RunReconciliationRequest,
+    responses(
+        (status = 200, description = "Reconciliation report", body = ReconciliationReport),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 409, description = "Repair disabled or reconciliation already running"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn run_reconciliation(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Json(request): Json<RunReconciliationRequest>,
+) -> Result<Json<ReconciliationReport>, ErrorResponse> {
+    let service = &app_state.cex_state.reconciliation_service;
+    if request.repair && !service.repair_allowed() {
+        return Err(error_response(StatusCode::CONFLICT, "Repair is disabled (RECONCILIATION_REPAIR=off)"));
+    }
+    if service.is_running() {
+        return Err(error_response(StatusCode::CONFLICT, "Reconciliation is already running"));
+    }
+
+    let report = service
+        .run(request.repair)
+        .await
+        .map_err(|e| internal_error("Reconciliation failed", e))?;
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(
+            admin.user_id,
+            "reconciliation.run",
+            "reconciliation",
+            None,
+            json!({
+                "repair": request.repair,
+                "discrepancies": report.discrepancies.len(),
+            }),
+        )
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(report))
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Markets (거래쌍 상태)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// 거래쌍 상태 목록 핸들러 (설정된 거래쌍만, 나머지는 open)
+/// Get market statuses handler
+#[utoipa::path(
+    get,
+    path = "/api/admin/markets",
+    responses(
+        (status = 200, description = "Configured market statuses", body = Vec<MarketStatusEntry>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Support role required")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_market_statuses(
+    State(app_state): State<AppState>,
+    StaffUser(_staff): StaffUser,
+) -> Json<Vec<MarketStatusEntry>> {
+    Json(app_state.cex_state.market_status_service.list())
+}
+
+/// 거래쌍 상태 변경 핸들러
+/// Set market status handler
+///
+/// - open: 정상
+/// - cancel_only: 새 주문 거부, 취소 가능
+/// - halted: 새 주문과 취소 모두 거부
+///
+/// # Response
+/// - 200: 저장된 상태
+/// - 403: admin 아님
+#[utoipa::path(
+    put,
+    path = "/api/admin/markets/status",
+    request_body = SetMarketStatusRequest,
+    responses(
+        (status = 200, description = "Market status updated", body = MarketStatusEntry),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn set_market_status(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Json(request): Json<SetMarketStatusRequest>,
+) -> Result<Json<MarketStatusEntry>, ErrorResponse> {
+    if request.base_mint.trim().is_empty() || request.quote_mint.trim().is_empty() {
+        return Err(error_response(StatusCode::BAD_REQUEST, "base_mint and quote_mint are required"));
+    }
+
+    let (previous, entry) = app_state
+        .cex_state
+        .market_status_service
+        .set(&request.base_mint, &request.quote_mint, request.status, request.reason.as_deref(), admin.user_id)
+        .await
+        .map_err(|e| internal_error("Failed to set market status", e))?;
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(
+            admin.user_id,
+            "market.status",
+            "market",
+            Some(&format!("{}/{}", entry.base_mint, entry.quote_mint)),
+            json!({ "from": previous, "to": entry.status, "reason": entry.reason }),
+        )
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(entry))
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Fees (수수료)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// 수수료 설정 변경 결과 → (변경 전, 변경 후) 또는 에러 응답
+fn fee_change_result(change: FeeConfigChange) -> Result<(Option<FeeConfig>, FeeConfig), ErrorResponse> {
+    match change {
+        FeeConfigChange::Saved { previous, config } => Ok((previous, config)),
+        FeeConfigChange::Invalid(message) => Err(error_response(StatusCode::BAD_REQUEST, message)),
+        FeeConfigChange::NotFound => Err(error_response(StatusCode::NOT_FOUND, "Fee config not found")),
+        FeeConfigChange::Inactive => Err(error_response(StatusCode::CONFLICT, "Fee config is already deactivated")),
+        FeeConfigChange::Overlaps(conflict) => Err((
+            StatusCode::CONFLICT,
+            Json(json!({
+                "error": format!("Overlaps active fee config {}", conflict.id),
+                "conflict": conflict,
+            })),
+        )),
+    }
+}
+
+/// 변경된 수수료 설정을 엔진에 반영
+///
+/// DB 변경은 이미 커밋되었으므로 실패하면 `POST /api/admin/fees/reload`로 다시 반영해야 합니다.
+async fn reload_fee_schedule_after_change(app_state: &AppState) -> Result<FeeScheduleReload, ErrorResponse> {
+    app_state
+        .cex_state
+        .fee_service
+        .reload_engine(&app_state.engine)
+        .await
+        .map_err(|e| internal_error("Fee config saved but engine reload failed (retry POST /api/admin/fees/reload)", e))
+}
+
+/// 수수료 설정 목록 핸들러 (활성, 적용 전/종료된 설정 포함)
+/// Get fee configs handler
+#[utoipa::path(
+    get,
+    path = "/api/admin/fees",
+    responses(
+        (status = 200, description = "Active fee configs", body = Vec<FeeConfig>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Support role required"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_fee_configs(
+    State(app_state): State<AppState>,
+    StaffUser(_staff): StaffUser,
+) -> Result<Json<Vec<FeeConfig>>, ErrorResponse> {
+    let fees = app_state
+        .cex_state
+        .fee_service
+        .get_all_active_fees()
+        .await
+        .map_err(|e| internal_error("Failed to fetch fee configs", e))?;
+
+    Ok(Json(fees))
+}
+
+/// 수수료 설정 생성 핸들러
+/// Create fee config handler
+///
+/// 같은 (거래쌍, 등급)에서 유형(both는 maker/taker와 겹침)과 적용 기간이 겹치는
+/// 활성 설정이 있으면 409입니다. 저장 후 엔진 수수료 스케줄에 바로 반영합니다.
+///
+/// # Response
+/// - 201: 생성된 수수료 설정
+/// - 400: 요청 값 오류 (수수료율 0 ~ 0.1, 유형, 등급, 기간)
+/// - 403: admin 아님
+/// - 409: 겹치는 활성 설정 있음
+#[utoipa::path(
+    post,
+    path = "/api/admin/fees",
+    request_body = CreateFeeConfigRequest,
+    responses(
+        (status = 201, description = "Fee config created", body = FeeConfig),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 409, description = "Overlaps an active fee config"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn create_fee_config(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Json(request): Json<CreateFeeConfigRequest>,
+) -> Result<(StatusCode, Json<FeeConfig>), ErrorResponse> {
+    let change = app_state
+        .cex_state
+        .fee_service
+        .create_fee_config(&request, admin.user_id)
+        .await
+        .map_err(|e| internal_error("Failed to create fee config", e))?;
+    let (_, config) = fee_change_result(change)?;
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "fee.create", "fee_config", Some(&config.id.to_string()), json!({ "config": config }))
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    reload_fee_schedule_after_change(&app_state).await?;
+    Ok((StatusCode::CREATED, Json(config)))
+}
+
+/// 수수료 설정 수정 핸들러 (수수료율, 적용 기간)
+/// Update fee config handler
+///
+/// 거래쌍/등급/유형은 바꿀 수 없습니다 (새 설정 생성 후 기존 설정 비활성화).
+///
+/// # Response
+/// - 200: 수정된 수수료 설정
+/// - 400: 요청 값 오류
+/// - 403: admin 아님
+/// - 404: 수수료 설정 없음
+/// - 409: 비활성화된 설정 또는 겹치는 활성 설정 있음
+#[utoipa::path(
+    put,
+    path = "/api/admin/fees/{id}",
+    params(("id" = u64, Path, description = "Fee config ID")),
+    request_body = UpdateFeeConfigRequest,
+    responses(
+        (status = 200, description = "Fee config updated", body = FeeConfig),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 404, description = "Fee config not found"),
+        (status = 409, description = "Fee config deactivated or overlaps an active fee config"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn update_fee_config(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(fee_config_id): Path<u64>,
+    Json(request): Json<UpdateFeeConfigRequest>,
+) -> Result<Json<FeeConfig>, ErrorResponse> {
+    let change = app_state
+        .cex_state
+        .fee_service
+        .update_fee_config(fee_config_id, &request, admin.user_id)
+        .await
+        .map_err(|e| internal_error("Failed to update fee config", e))?;
+    let (previous, config) = fee_change_result(change)?;
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(
+            admin.user_id,
+            "fee.update",
+            "fee_config",
+            Some(&fee_config_id.to_string()),
+            json!({ "from": previous, "to": config }),
+        )
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    reload_fee_schedule_after_change(&app_state).await?;
+    Ok(Json(config))
+}
+
+/// 수수료 설정 비활성화 핸들러 (행은 이력과 함께 보존)
+/// Deactivate fee config handler
+///
+/// # Response
+/// - 200: 비활성화된 수수료 설정
+/// - 403: admin 아님
+/// - 404: 수수료 설정 없음
+/// - 409: 이미 비활성화됨
+#[utoipa::path(
+    delete,
+    path = "/api/admin/fees/{id}",
+    params(("id" = u64, Path, description = "Fee config ID")),
+    responses(
+        (status = 200, description = "Fee config deactivated", body = FeeConfig),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 404, description = "Fee config not found"),
+        (status = 409, description = "Fee config already deactivated"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn deactivate_fee_config(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(fee_config_id): Path<u64>,
+) -> Result<Json<FeeConfig>, ErrorResponse> {
+    let change = app_state
+        .cex_state
+        .fee_service
+        .deactivate_fee_config(fee_config_id, admin.user_id)
+        .await
+        .map_err(|e| internal_error("Failed to deactivate fee config", e))?;
+    let (_, config) = fee_change_result(change)?;
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "fee.deactivate", "fee_config", Some(&fee_config_id.to_string()), json!({}))
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    reload_fee_schedule_after_change(&app_state).await?;
+    Ok(Json(config))
+}
+
+/// 수수료 설정 변경 이력 핸들러 (최신순)
+/// Get fee config history handler
+#[utoipa::path(
+    get,
+    path = "/api/admin/fees/{id}/history",
+    params(("id" = u64, Path, description = "Fee config ID")),
+    responses(
+        (status = 200, description = "Fee config history", body = Vec<FeeConfigHistory>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Support role required"),
+        (status = 404, description = "Fee config not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_fee_config_history(
+    State(app_state): State<AppState>,
+    StaffUser(_staff): StaffUser,
+    Path(fee_config_id): Path<u64>,
+) -> Result<Json<Vec<FeeConfigHistory>>, ErrorResponse> {
+    let history = app_state
+        .cex_state
+        .fee_service
+        .get_fee_config_history(fee_config_id)
+        .await
+        .map_err(|e| internal_error("Failed to fetch fee config history", e))?
+        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "Fee config not found"))?;
+
+    Ok(Json(history))
+}
+
+/// 엔진 수수료 스케줄 다시 반영 핸들러
+/// Reload fee schedule handler
+///
+/// 수수료 설정 변경은 자동으로 반영되므로 반영 실패 후 재시도나 DB를 직접 고친 경우에 사용합니다.
+///
+/// # Response
+/// - 200: 적용된 스케줄 버전, 규칙 수, 등급 사용자 수
+/// - 403: admin 아님
+#[utoipa::path(
+    post,
+    path = "/api/admin/fees/reload",
+    responses(
+        (status = 200, description = "Fee schedule applied", body = serde_json::Value),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn reload_fee_schedule(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    let reload = app_state
+        .cex_state
+        .fee_service
+        .reload_engine(&app_state.engine)
+        .await
+        .map_err(|e| internal_error("Failed to reload fee schedule", e))?;
+    let result = json!({
+        "version": reload.version,
+        "rules": reload.rules,
+        "tiered_users": reload.tiered_users,
+    });
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "fee.reload", "fee_schedule", None, result.clone())
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(result))
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Withdrawals (출금 승인)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// 출금 승인 핸들러 (requested → approved, 자동 승인 한도 초과 출금)
+/// Approve withdrawal handler
+///
+/// # Response
+/// - 200: 승인 완료
+/// - 403: admin 아님
+/// - 409: 출금이 없거나 requested 상태가 아님
+#[utoipa::path(
+    post,
+    path = "/api/admin/withdrawals/{id}/approve",
+    params(("id" = u64, Path, description = "Withdrawal ID")),
+    responses(
+        (status = 200, description = "Withdrawal approved", body = serde_json::Value),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 409, description = "Withdrawal not found or not awaiting approval"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn approve_withdrawal(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(withdrawal_id): Path<u64>,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    let approved = app_state
+        .cex_state
+        .withdrawal_service
+        .approve(withdrawal_id)
+        .await
+        .map_err(|e| internal_error("Failed to approve withdrawal", e))?;
+    if !approved {
+        return Err(error_response(StatusCode::CONFLICT, "Withdrawal not found or not awaiting approval"));
+    }
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "withdrawal.approve", "withdrawal", Some(&withdrawal_id.to_string()), json!({}))
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(json!({ "id": withdrawal_id, "status": "approved" })))
+}
+
+/// 출금 거절 핸들러 (requested → failed, 보류 잔고 해제)
+/// Reject withdrawal handler
+///
+/// # Response
+/// - 200: 거절 완료
+/// - 400: 사유 없음
+/// - 403: admin 아님
+/// - 409: 출금이 없거나 requested 상태가 아님
+#[utoipa::path(
+    post,
+    path = "/api/admin/withdrawals/{id}/reject",
+    params(("id" = u64, Path, description = "Withdrawal ID")),
+    request_body = RejectWithdrawalRequest,
+    responses(
+        (status = 200, description = "Withdrawal rejected", body = serde_json::Value),
+        (status = 400, description = "Reason is required"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin role required"),
+        (status = 409, description = "Withdrawal not found or not awaiting approval"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn reject_withdrawal(
+    State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(withdrawal_id): Path<u64>,
+    Json(request): Json<RejectWithdrawalRequest>,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    let reason = request.reason.trim();
+    if reason.is_empty() {
+        return Err(error_response(StatusCode::BAD_REQUEST, "reason is required"));
+    }
+
+    let rejected = app_state
+        .cex_state
+        .withdrawal_service
+        .reject(withdrawal_id, reason)
+        .await
+        .map_err(|e| internal_error("Failed to reject withdrawal", e))?;
+    if !rejected {
+        return Err(error_response(StatusCode::CONFLICT, "Withdrawal not found or not awaiting approval"));
+    }
+
+    app_state
+        .admin_state
+        .admin_service
+        .record(
+            admin.user_id,
+            "withdrawal.reject",
+            "withdrawal",
+            Some(&withdrawal_id.to_string()),
+            json!({ "reason": reason }),
+        )
+        .await
+        .map_err(|e| internal_error("Action applied but audit log failed", e))?;
+
+    Ok(Json(json!({ "id": withdrawal_id, "status": "failed" })))
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Audit logs (감사 로그)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// 감사 로그 쿼리 파라미터
+/// Query parameters for audit logs
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct AuditLogQuery {
+    /// 작업한 관리자 ID
+    #[serde(default)]
+    pub actor_user_id: Option<u64>,
+
+    /// 작업 이름 (예: user.freeze)
+    #[serde(default)]
+    pub action: Option<String>,
+
+    /// 대상 종류 (예: user, market)
+    #[serde(default)]
+    pub target_type: Option<String>,
+
+    /// 대상 ID (예: 사용자 ID, "SOL/USDT")
+    #[serde(default)]
+    pub target_id: Option<String>,
+
+    /// 기록 시간 하한 (RFC 3339, 포함)
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// 기록 시간 상한 (RFC 3339, 미포함)
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    /// 다음 페이지 커서 (이전 응답의 `X-Next-Cursor` 헤더 값)
+    #[serde(default)]
+    pub cursor: Option<String>,
+
+    /// 최대 조회 개수 (기본: 100, 최대: 1000)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// 감사 로그 조회 핸들러 (최신순)
+/// Get audit logs handler
+#[utoipa::path(
+    get,
+    path = "/api/admin/audit-logs",
+    params(AuditLogQuery),
+    responses(
+        (status = 200, description = "Audit logs", body = Vec<AuditLog>,
+            headers(("X-Next-Cursor" = String, description = "Cursor for the next page (absent on the last page)"))),
+        (status = 400, description = "Invalid filter or cursor"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Support role required"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_audit_logs(
+    State(app_state): State<AppState>,
+    StaffUser(_staff): StaffUser,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<(HeaderMap, Json<Vec<AuditLog>>), ErrorResponse> {
+    pagination::check_time_range(query.from, query.to)?;
+    let cursor = pagination::parse_cursor(query.cursor.as_deref())?;
+
+    let filter = AuditLogFilter {
+        actor_user_id: query.actor_user_id,
+        action: query.action,
+        target_type: query.target_type,
+        target_id: query.target_id,
+        from: query.from,
+        to: query.to,
+    };
+
+    let page = app_state
+        .admin_state
+        .admin_service
+        .get_audit_logs(&filter, cursor, query.limit)
+        .await
+        .map_err(|e| internal_error("Failed to fetch audit logs", e))?;
+
+    Ok((pagination::next_cursor_headers(page.next_cursor), Json(page.items)))
+}
@@ -0,0 +1,15 @@
+/// Admin 모듈
+/// Admin Module
+///
+/// 역할:
+/// - 운영 관리 API (/api/admin): 사용자 역할/동결, 잔고 조정, 거래쌍 상태, 수수료, 출금 승인, 봇 관리
+/// - 관리자 작업 감사 로그
+///
+/// 구조:
+/// - `handlers/admin_handler.rs`: 관리자 API 핸들러 (StaffUser / AdminUser extractor)
+/// - `services/admin_service.rs`: 역할 변경, 잔고 조정, 수수료율 변경, 감사 로그
+/// - `models/`: 요청/응답, 감사 로그 모델
+pub mod models;
+pub mod services;
+pub mod handlers;
+pub mod routes;
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use rust_decimal::Decimal;
+use crate::domains::auth::models::role::Role;
+use crate::domains::cex::models::market_status::MarketStatus;
+
+// =====================================================
+// 관리자 API 요청 / 응답 모델
+// =====================================================
+
+/// 역할 변경 요청
+/// Set role request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetRoleRequest {
+    /// 새 역할
+    pub role: Role,
+}
+
+/// 계정 동결 요청
+/// Freeze user request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct FreezeUserRequest {
+    /// 동결 사유 (감사 로그와 users.frozen_reason에 기록)
+    #[schema(example = "Suspicious withdrawal pattern")]
+    pub reason: String,
+}
+
+/// 잔고 조정 요청
+/// Balance adjustment request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AdjustBalanceRequest {
+    /// 대상 사용자 ID
+    pub user_id: u64,
+
+    /// 자산
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// available 증감량 (양수: 지급, 음수: 회수)
+    #[schema(value_type = String, example = "-12.5")]
+    pub amount: Decimal,
+
+    /// 조정 사유 (원장 참조로 기록)
+    #[schema(example = "Refund for incident #120")]
+    pub memo: String,
+}
+
+/// 잔고 조정 응답
+/// Balance adjustment response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdjustBalanceResponse {
+    pub user_id: u64,
+
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// 증감량
+    #[schema(value_type = String, example = "-12.5")]
+    pub amount: Decimal,
+
+    /// 조정 전 available
+    #[schema(value_type = String, example = "100")]
+    pub available_before: Decimal,
+
+    /// 조정 후 available
+    #[schema(value_type = String, example = "87.5")]
+    pub available_after: Decimal,
+}
+
+/// 거래쌍 상태 변경 요청
+/// Set market status request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetMarketStatusRequest {
+    #[schema(example = "SOL")]
+    pub base_mint: String,
+
+    #[schema(example = "USDT")]
+    pub quote_mint: String,
+
+    /// 새 상태
+    pub status: MarketStatus,
+
+    /// 변경 사유
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// 사용자 수수료 등급 변경 요청
+/// Set user fee tier request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetFeeTierRequest {
+    /// 새 등급 (null이면 등급 해제)
+    #[schema(example = "vip1")]
+    pub fee_tier: Option<String>,
+}
+
+/// 출금 거절 요청
+/// Reject withdrawal request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RejectWithdrawalRequest {
+    /// 거절 사유 (출금 error에 기록)
+    #[schema(example = "Destination address on deny list")]
+    pub reason: String,
+}
+
+/// WAL 내구성 정책 / fsync 지표
+/// WAL durability policy and fsync metrics
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WalStatusResponse {
+    /// 정책 (per_command / group_commit / async)
+    #[schema(example = "group_commit")]
+    pub durability: String,
+    /// group commit 최대 대기 시간 (µs, group_commit만)
+    pub group_commit_max_delay_us: Option<u64>,
+    /// group commit 최대 엔트리 수 (group_commit만)
+    pub group_commit_max_entries: Option<usize>,
+    /// 기록된 엔트리 수
+    pub entries_written: u64,
+    /// fsync 횟수
+    pub fsync_count: u64,
+    /// 평균 / 최대 / 마지막 fsync 지연 시간 (µs)
+    pub fsync_avg_us: u64,
+    pub fsync_max_us: u64,
+    pub fsync_last_us: u64,
+    /// fsync당 엔트리 수 (평균 / 마지막 / 최대)
+    pub avg_batch_size: u64,
+    pub last_batch_size: u64,
+    pub max_batch_size: u64,
+    /// 완료된 durable ack 수 (주문 응답)
+    pub acks_completed: u64,
+}
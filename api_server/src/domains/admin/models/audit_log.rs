@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+
+// =====================================================
+// AuditLog 모델 (관리자 감사 로그)
+// =====================================================
+// 역할: 관리자 API로 수행한 작업 기록 (누가, 무엇을, 어디에, 세부 내용)
+//
+// 특징:
+// - 작업이 성공한 뒤에 한 행 기록 (실패한 요청은 기록하지 않음)
+// - 수정/삭제 API 없음 (추가만)
+// - details: 요청 값, 변경 전/후 값 (JSON)
+// =====================================================
+
+/// 관리자 감사 로그
+/// Admin audit log entry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLog {
+    /// 로그 ID
+    pub id: u64,
+
+    /// 작업한 관리자 ID
+    pub actor_user_id: u64,
+
+    /// 작업 이름 (예: market.status, fee.update, user.freeze, balance.adjust)
+    #[schema(example = "user.freeze")]
+    pub action: String,
+
+    /// 대상 종류 (예: user, market, fee_config, withdrawal, bot)
+    #[schema(example = "user")]
+    pub target_type: String,
+
+    /// 대상 ID (예: 사용자 ID, "SOL/USDT")
+    #[schema(example = "42")]
+    pub target_id: Option<String>,
+
+    /// 세부 내용 (요청 값, 변경 전/후)
+    #[schema(value_type = Object)]
+    pub details: serde_json::Value,
+
+    /// 기록 시간
+    pub created_at: DateTime<Utc>,
+}
+
+/// 감사 로그 조회 필터
+#[derive(Debug, Default)]
+pub struct AuditLogFilter {
+    pub actor_user_id: Option<u64>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,6 @@
+// Admin domain models
+pub mod audit_log;
+pub mod admin;
+
+pub use audit_log::*;
+pub use admin::*;
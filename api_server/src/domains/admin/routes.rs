@@ -0,0 +1,117 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+use crate::shared::services::AppState;
+use crate::domains::bot::routes::create_bot_router;
+
+use super::handlers;
+
+/// 관리자 라우터 생성
+/// Create admin router
+///
+/// support 이상 (조회, 동결) / admin (그 외) 역할이 필요합니다.
+/// 변경 작업은 모두 감사 로그에 기록됩니다.
+///
+/// # Routes
+///
+/// ## Users (사용자)
+/// - `GET    /api/admin/users/:id` - 사용자 조회 (역할, 동결 상태) [support]
+/// - `PUT    /api/admin/users/:id/role` - 역할 변경 [admin]
+/// - `POST   /api/admin/users/:id/freeze` - 계정 동결 (조회만 가능) [support]
+/// - `POST   /api/admin/users/:id/unfreeze` - 동결 해제 [admin]
+/// - `PUT    /api/admin/users/:id/fee-tier` - 수수료 등급 변경 [admin]
+///
+/// ## Balances (잔고)
+/// - `POST   /api/admin/balances/adjust` - 잔고 조정 (원장 사유 adjustment) [admin]
+///
+/// ## Markets (거래쌍)
+/// - `GET    /api/admin/markets` - 거래쌍 상태 목록 [support]
+/// - `PUT    /api/admin/markets/status` - 거래쌍 상태 변경 (open/cancel_only/halted) [admin]
+///
+/// ## Fees (수수료)
+/// - `GET    /api/admin/fees` - 활성 수수료 설정 [support]
+/// - `POST   /api/admin/fees` - 수수료 설정 생성 [admin]
+/// - `PUT    /api/admin/fees/:id` - 수수료율 / 적용 기간 변경 [admin]
+/// - `DELETE /api/admin/fees/:id` - 수수료 설정 비활성화 [admin]
+/// - `GET    /api/admin/fees/:id/history` - 변경 이력 [support]
+/// - `POST   /api/admin/fees/reload` - 엔진 수수료 스케줄 다시 반영 [admin]
+///
+/// 수수료 설정/등급 변경은 저장 후 엔진에 바로 반영됩니다 (재시작 불필요).
+///
+/// ## Engine (엔진 상태)
+/// - `GET    /api/admin/engine/wal` - WAL 내구성 정책 / fsync 지표 [support]
+///
+/// ## Reconciliation (잔고 정합성)
+/// - `GET    /api/admin/reconciliation` - 엔진 ↔ DB 잔고 정합성 검사 상태/마지막 결과 [admin]
+/// - `POST   /api/admin/reconciliation/run` - 정합성 검사 실행 (선택적으로 DB 복구) [admin]
+///
+/// ## Withdrawals (출금)
+/// - `POST   /api/admin/withdrawals/:id/approve` - 출금 승인 [admin]
+/// - `POST   /api/admin/withdrawals/:id/reject` - 출금 거절 [admin]
+///
+/// ## Audit logs (감사 로그)
+/// - `GET    /api/admin/audit-logs` - 감사 로그 조회 [support]
+///
+/// ## Bot (봇 관리)
+/// - `DELETE /api/admin/bot/data` - 봇 주문/체결 삭제 [admin]
+/// - `GET    /api/admin/bot/cleanup-scheduler/status` - 정리 스케줄러 상태 [admin]
+/// - `POST   /api/admin/bot/cleanup-scheduler/enable` - 정리 스케줄러 활성화 [admin]
+/// - `POST   /api/admin/bot/cleanup-scheduler/disable` - 정리 스케줄러 비활성화 [admin]
+pub fn create_admin_router() -> Router<AppState> {
+    Router::new()
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Users (사용자)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/users/:id", get(handlers::get_user))
+        .route("/users/:id/role", put(handlers::set_user_role))
+        .route("/users/:id/freeze", post(handlers::freeze_user))
+        .route("/users/:id/unfreeze", post(handlers::unfreeze_user))
+        .route("/users/:id/fee-tier", put(handlers::set_user_fee_tier))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Balances (잔고)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/balances/adjust", post(handlers::adjust_balance))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Markets (거래쌍 상태)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/markets", get(handlers::get_market_statuses))
+        .route("/markets/status", put(handlers::set_market_status))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Fees (수수료)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/fees", get(handlers::get_fee_configs).post(handlers::create_fee_config))
+        .route("/fees/reload", post(handlers::reload_fee_schedule))
+        .route("/fees/:id", put(handlers::update_fee_config).delete(handlers::deactivate_fee_config))
+        .route("/fees/:id/history", get(handlers::get_fee_config_history))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Engine (엔진 상태)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/engine/wal", get(handlers::get_wal_status))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Reconciliation (잔고 정합성)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/reconciliation", get(handlers::get_reconciliation_status))
+        .route("/reconciliation/run", post(handlers::run_reconciliation))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Withdrawals (출금 승인)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/withdrawals/:id/approve", post(handlers::approve_withdrawal))
+        .route("/withdrawals/:id/reject", post(handlers::reject_withdrawal))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Audit logs (감사 로그)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .route("/audit-logs", get(handlers::get_audit_logs))
+
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Bot (봇 관리)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        .nest("/bot", create_bot_router())
+}
@@ -0,0 +1,147 @@
+use crate::shared::database::{AuditLogRepository, Database, UserRepository};
+use crate::shared::utils::cursor::{Page, PageCursor};
+use crate::domains::admin::models::audit_log::{AuditLog, AuditLogFilter};
+use crate::domains::auth::models::role::Role;
+use crate::domains::cex::engine::{Engine, runtime::HighPerformanceEngine};
+use crate::domains::cex::models::ledger::LedgerReason;
+use anyhow::{Context, Result, bail};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+// =====================================================
+// AdminService
+// =====================================================
+// 역할: 관리자 API 작업 중 다른 도메인 서비스에 없는 것 + 감사 로그
+//
+// 처리 방식:
+// - 거래쌍 상태, 계정 동결, 수수료 설정, 출금 승인/거절, 봇 관리는 각 도메인 서비스 사용
+// - 역할 변경, 잔고 조정은 여기서 처리
+// - 모든 작업은 성공한 뒤 record()로 감사 로그 기록 (기록 실패 시 핸들러는 500)
+// =====================================================
+
+/// 잔고 조정 결과
+#[derive(Debug, Clone)]
+pub struct BalanceAdjustment {
+    /// 조정 전 available
+    pub available_before: Decimal,
+    /// 조정 후 available
+    pub available_after: Decimal,
+}
+
+/// 관리자 서비스
+/// Admin service
+#[derive(Clone)]
+pub struct AdminService {
+    db: Database,
+    engine: Arc<Mutex<HighPerformanceEngine>>,
+}
+
+impl AdminService {
+    pub fn new(db: Database, engine: Arc<Mutex<HighPerformanceEngine>>) -> Self {
+        Self { db, engine }
+    }
+
+    /// 감사 로그 기록
+    /// Record an admin action
+    ///
+    /// 작업은 이미 반영되었지만, 감사 로그 없이 성공으로 응답하지 않도록 기록 실패는 에러로 돌려줍니다
+    /// (핸들러는 500 응답).
+    pub async fn record(
+        &self,
+        actor_user_id: u64,
+        action: &str,
+        target_type: &str,
+        target_id: Option<&str>,
+        details: serde_json::Value,
+    ) -> Result<()> {
+        AuditLogRepository::new(self.db.pool().clone())
+            .insert(actor_user_id, action, target_type, target_id, &details)
+            .await
+            .with_context(|| format!(
+                "Failed to record audit log (actor={}, action={}, target={}:{})",
+                actor_user_id,
+                action,
+                target_type,
+                target_id.unwrap_or("-"),
+            ))?;
+
+        Ok(())
+    }
+
+    /// 감사 로그 조회 (최신순)
+    /// Get audit logs
+    pub async fn get_audit_logs(
+        &self,
+        filter: &AuditLogFilter,
+        cursor: Option<PageCursor>,
+        limit: Option<i64>,
+    ) -> Result<Page<AuditLog>> {
+        // 제한 설정: 기본 100, 최대 1000
+        let limit = limit.unwrap_or(100).clamp(1, 1000);
+
+        AuditLogRepository::new(self.db.pool().clone())
+            .get_page(filter, cursor, limit)
+            .await
+    }
+
+    /// 역할 변경
+    /// Set user role
+    ///
+    /// # Returns
+    /// * `Ok(Some(이전 역할))` - 변경 완료
+    /// * `Ok(None)` - 사용자가 없음
+    pub async fn set_role(&self, user_id: u64, role: Role) -> Result<Option<Role>> {
+        let user_repo = UserRepository::new(self.db.pool().clone());
+        let Some(previous) = user_repo.get_role(user_id).await? else {
+            return Ok(None);
+        };
+
+        user_repo.set_role(user_id, role).await?;
+        Ok(Some(previous))
+    }
+
+    /// 잔고 조정 (available 증감, 원장 사유 adjustment)
+    /// Adjust a user's available balance
+    ///
+    /// # Arguments
+    /// * `amount` - 증감량 (양수: 지급, 음수: 회수)
+    /// * `memo` - 원장 참조로 기록
+    ///
+    /// # Errors
+    /// - amount가 0
+    /// - 회수량이 available보다 큼 (locked는 건드리지 않음)
+    pub async fn adjust_balance(
+        &self,
+        user_id: u64,
+        mint: &str,
+        amount: Decimal,
+        memo: &str,
+    ) -> Result<BalanceAdjustment> {
+        if amount.is_zero() {
+            bail!("Amount must not be zero");
+        }
+
+        // 조회와 반영 사이에 다른 잔고 명령이 끼지 않도록 엔진 잠금 유지
+        let engine = self.engine.lock().await;
+        let (available_before, _locked) = engine
+            .get_balance(user_id, mint)
+            .await
+            .context("Failed to fetch balance from engine")?;
+
+        let available_after = available_before + amount;
+        if available_after < Decimal::ZERO {
+            bail!(
+                "Insufficient available balance: {} {} (adjustment {})",
+                available_before, mint, amount
+            );
+        }
+
+        engine
+            .update_balance(user_id, mint, amount, LedgerReason::Adjustment, memo)
+            .await
+            .context("Failed to adjust balance in engine")?;
+
+        Ok(BalanceAdjustment { available_before, available_after })
+    }
+}
@@ -0,0 +1,23 @@
+// Admin domain state
+// 관리자 도메인 상태
+use std::sync::Arc;
+use crate::shared::database::Database;
+use crate::domains::admin::services::AdminService;
+use crate::domains::cex::engine::runtime::HighPerformanceEngine;
+
+/// Admin domain state
+/// 관리자 도메인에서 필요한 서비스들을 포함하는 상태
+#[derive(Clone)]
+pub struct AdminState {
+    pub admin_service: AdminService,
+}
+
+impl AdminState {
+    /// Create AdminState with database and engine
+    /// AdminState 생성 (데이터베이스 + 엔진 필요)
+    pub fn new(db: Database, engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>) -> Self {
+        Self {
+            admin_service: AdminService::new(db, engine),
+        }
+    }
+}
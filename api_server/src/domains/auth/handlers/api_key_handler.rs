@@ -0,0 +1,141 @@
+use crate::domains::auth::models::{ApiKey, CreateApiKeyRequest, CreateApiKeyResponse};
+use crate::shared::services::AppState;
+use crate::shared::errors::AuthError;
+use crate::shared::middleware::auth::AuthenticatedUser;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+// =====================================================
+// API Key Handler
+// =====================================================
+// 역할: API 키 발급/목록/폐기
+//
+// 특징:
+// - JWT(Bearer)로만 호출 가능 (API 키로 키를 만들거나 폐기할 수 없음)
+// - secret은 생성 응답에서 한 번만 반환
+// =====================================================
+
+/// API 키 생성 핸들러
+/// Create API key handler
+///
+/// # Request Body
+/// - label: 이름 (1-64자)
+/// - scopes: 권한 (read, trade, withdraw 중 하나 이상)
+/// - ip_allowlist: 허용 IP / CIDR (선택)
+/// - expires_at: 만료 시간 (선택)
+///
+/// # Response
+/// - 201: API 키 생성 (secret 포함, 다시 조회할 수 없음)
+/// - 400: 잘못된 요청 (이름, 권한, IP, 만료 시간, 활성 키 수 초과)
+/// - 401: 인증 실패
+/// - 503: secret 암호화용 마스터 키 미설정
+#[utoipa::path(
+    post,
+    path = "/api/auth/api-keys",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "API key created (the secret is shown only once)", body = CreateApiKeyResponse),
+        (status = 400, description = "Bad request (invalid label, scopes, IP allowlist or expiry, key limit reached)"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 503, description = "Secret encryption is not configured (WALLET_MASTER_KEY_FILE)")
+    ),
+    tag = "Auth",
+    security(("BearerAuth" = []))
+)]
+pub async fn create_api_key(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(request): Json<CreateApiKeyRequest>,
+) -> Result<(StatusCode, Json<CreateApiKeyResponse>), (StatusCode, Json<serde_json::Value>)> {
+    let (api_key, secret) = app_state
+        .auth_state
+        .api_key_service
+        .create_key(authenticated_user.user_id, &request)
+        .await
+        .map_err(|e: AuthError| -> (StatusCode, Json<serde_json::Value>) { e.into() })?;
+
+    Ok((StatusCode::CREATED, Json(CreateApiKeyResponse { api_key, secret })))
+}
+
+/// API 키 목록 핸들러
+/// List API keys handler
+///
+/// # Response
+/// - 200: API 키 목록 (최신순, 폐기된 키 포함, secret 제외)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/auth/api-keys",
+    responses(
+        (status = 200, description = "API keys retrieved successfully", body = Vec<ApiKey>),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_api_keys(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<Vec<ApiKey>>, (StatusCode, Json<serde_json::Value>)> {
+    let api_keys = app_state
+        .auth_state
+        .api_key_service
+        .list_keys(authenticated_user.user_id)
+        .await
+        .map_err(|e: AuthError| -> (StatusCode, Json<serde_json::Value>) { e.into() })?;
+
+    Ok(Json(api_keys))
+}
+
+/// API 키 폐기 핸들러
+/// Revoke API key handler
+///
+/// # Path Parameters
+/// - id: API 키 ID
+///
+/// # Response
+/// - 200: 폐기 완료
+/// - 401: 인증 실패
+/// - 404: 키가 없거나 이미 폐기됨
+#[utoipa::path(
+    delete,
+    path = "/api/auth/api-keys/{id}",
+    params(
+        ("id" = u64, Path, description = "API key ID")
+    ),
+    responses(
+        (status = 200, description = "API key revoked"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 404, description = "API key not found or already revoked")
+    ),
+    tag = "Auth",
+    security(("BearerAuth" = []))
+)]
+pub async fn revoke_api_key(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(id): Path<u64>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let revoked = app_state
+        .auth_state
+        .api_key_service
+        .revoke_key(authenticated_user.user_id, id)
+        .await
+        .map_err(|e: AuthError| -> (StatusCode, Json<serde_json::Value>) { e.into() })?;
+
+    if !revoked {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "API key not found or already revoked" })),
+        ));
+    }
+
+    Ok(Json(serde_json::json!({
+        "message": "API key revoked"
+    })))
+}
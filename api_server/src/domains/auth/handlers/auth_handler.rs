@@ -65,7 +65,7 @@ pub async fn signin(
     let access_token = app_state
         .auth_state
         .jwt_service
-        .generate_access_token(user.id, user.email.clone())
+        .generate_access_token(user.id, user.email.clone(), user.role)
         .map_err(|e| {
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
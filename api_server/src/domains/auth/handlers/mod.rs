@@ -1,5 +1,6 @@
 // Auth domain handlers
 pub mod auth_handler;
+pub mod api_key_handler;
 
 pub use auth_handler::*;
 
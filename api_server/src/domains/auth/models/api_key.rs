@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use axum::http::Method;
+use std::str::FromStr;
+use crate::domains::wallet::models::EncryptedKey;
+
+// =====================================================
+// ApiKey 모델
+// =====================================================
+// 역할: 프로그램 매매용 API 키 (비밀번호/JWT 없이 HMAC 서명으로 인증)
+//
+// 요청 서명:
+// - X-Api-Key: 공개 키 ID
+// - X-Api-Timestamp: 요청 시간 (Unix ms)
+// - X-Api-Signature: hex(HMAC-SHA256(secret, timestamp + METHOD + path?query + body))
+//
+// 권한 (scopes):
+// - read: 조회 (GET)
+// - trade: 주문 생성/취소 등 조회 이외의 거래 요청
+// - withdraw: 출금 / 내부 이체 / 지갑 전송
+// =====================================================
+
+/// API 키 권한
+/// API key scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyScope {
+    /// 조회
+    Read,
+    /// 주문 생성/취소
+    Trade,
+    /// 출금 / 내부 이체
+    Withdraw,
+}
+
+impl ApiKeyScope {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::Read => "read",
+            ApiKeyScope::Trade => "trade",
+            ApiKeyScope::Withdraw => "withdraw",
+        }
+    }
+
+    /// 요청에 필요한 권한
+    /// Scope required for a request
+    ///
+    /// # Returns
+    /// * `Some(scope)` - 이 권한이 있는 API 키로 호출 가능
+    /// * `None` - API 키로 호출할 수 없는 경로 (인증/키 관리, 관리자 API)
+    pub fn required_for(method: &Method, path: &str) -> Option<ApiKeyScope> {
+        // 인증/키 관리는 JWT만 (API 키로 새 키를 만들어 권한을 넓히지 못하도록)
+        if path.starts_with("/api/auth/") && path != "/api/auth/me" {
+            return None;
+        }
+        if path.starts_with("/api/admin/") {
+            return None;
+        }
+
+        if method == Method::GET || method == Method::HEAD {
+            return Some(ApiKeyScope::Read);
+        }
+
+        const WITHDRAW_PATHS: [&str; 4] = [
+            "/api/cex/withdrawals",
+            "/api/cex/transfers",
+            "/api/cex/sub-accounts/transfers",
+            "/api/wallets/",
+        ];
+        if WITHDRAW_PATHS.iter().any(|prefix| path.starts_with(prefix)) {
+            return Some(ApiKeyScope::Withdraw);
+        }
+
+        Some(ApiKeyScope::Trade)
+    }
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "read" => Ok(ApiKeyScope::Read),
+            "trade" => Ok(ApiKeyScope::Trade),
+            "withdraw" => Ok(ApiKeyScope::Withdraw),
+            _ => Err(anyhow::anyhow!("Unknown API key scope: {}", value)),
+        }
+    }
+}
+
+/// API 키
+/// API key
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = ApiKey)]
+pub struct ApiKey {
+    /// API key record ID
+    /// API 키 ID
+    #[schema(example = 1)]
+    pub id: u64,
+
+    /// Owner user ID
+    /// 소유 사용자 ID
+    pub user_id: u64,
+
+    /// Public key ID (send as `X-Api-Key`)
+    /// 공개 키 ID
+    #[schema(example = "ak_3f9c1b7e2d4a6c8e0b1d3f5a")]
+    pub key_id: String,
+
+    /// Encrypted signing secret (base64(nonce || AES-256-GCM ciphertext), plaintext if key_version is None)
+    /// 암호화된 서명 비밀 값 (내부용)
+    #[serde(skip)]
+    pub secret: String,
+
+    /// Data key wrapped by the master key
+    /// 마스터 키로 감싼 데이터 키 (None: 암호화 전 기존 행)
+    #[serde(skip)]
+    pub wrapped_data_key: Option<String>,
+
+    /// Master key version that wrapped the data key
+    /// 데이터 키를 감싼 마스터 키 버전 (None: 암호화 전 기존 행)
+    #[serde(skip)]
+    pub key_version: Option<u32>,
+
+    /// Label
+    /// 이름
+    #[schema(example = "grid-bot")]
+    pub label: String,
+
+    /// Scopes
+    /// 권한
+    pub scopes: Vec<ApiKeyScope>,
+
+    /// Allowed IPs / CIDRs (None: any)
+    /// 허용 IP / CIDR
+    #[schema(example = json!(["203.0.113.10", "10.0.0.0/8"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// Expiry (None: never)
+    /// 만료 시간
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Last used timestamp (updated at most once a minute)
+    /// 마지막 사용 시간
+    pub last_used_at: Option<DateTime<Utc>>,
+
+    /// Revoked timestamp
+    /// 폐기 시간
+    pub revoked_at: Option<DateTime<Utc>>,
+
+    /// Created timestamp
+    /// 생성 시간
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    /// 저장된 봉투 암호화 값 (암호화 전 기존 행이면 None)
+    pub fn encrypted_secret(&self) -> Option<EncryptedKey> {
+        Some(EncryptedKey {
+            encrypted_private_key: self.secret.clone(),
+            wrapped_data_key: self.wrapped_data_key.clone()?,
+            key_version: self.key_version?,
+        })
+    }
+}
+
+/// API 키 생성 요청
+/// Create API key request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateApiKeyRequest {
+    /// Label (1-64 chars)
+    /// 이름
+    #[schema(example = "grid-bot")]
+    pub label: String,
+
+    /// Scopes (at least one)
+    /// 권한
+    #[schema(example = json!(["read", "trade"]))]
+    pub scopes: Vec<ApiKeyScope>,
+
+    /// Allowed IPs / CIDRs (optional)
+    /// 허용 IP / CIDR
+    #[serde(default)]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// Expiry (optional, must be in the future)
+    /// 만료 시간
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// API 키 생성 응답 (secret은 이 응답에서만 반환)
+/// Create API key response (the secret is shown only once)
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(as = CreateApiKeyResponse)]
+pub struct CreateApiKeyResponse {
+    /// API key
+    /// API 키
+    pub api_key: ApiKey,
+
+    /// Signing secret (store it now; it cannot be retrieved again)
+    /// 서명 비밀 값
+    #[schema(example = "9b1f...e4")]
+    pub secret: String,
+}
+
+/// 서명 검증을 통과한 API 키 요청 (요청 extension)
+/// Verified API key request principal
+#[derive(Debug, Clone)]
+pub struct ApiKeyPrincipal {
+    pub user_id: u64,
+    pub email: String,
+    pub key_id: String,
+}
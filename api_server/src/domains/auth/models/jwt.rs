@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use super::role::Role;
 
 /// JWT Claims (토큰에 포함될 데이터)
 /// JWT Claims (data to be included in token)
@@ -12,6 +13,11 @@ pub struct Claims {
     /// Email
     pub email: String,
     
+    /// 역할 (역할 추가 전에 발급된 토큰은 user)
+    /// Role
+    #[serde(default)]
+    pub role: Role,
+    
     /// 만료 시간 (Unix timestamp)
     /// Expiration time (Unix timestamp)
     pub exp: i64,
@@ -24,13 +30,14 @@ pub struct Claims {
 impl Claims {
     /// 새 Claims 생성 (만료 시간 자동 계산)
     /// Create new Claims (expiration time automatically calculated)
-    pub fn new(user_id: u64, email: String, expiration_hours: i64) -> Self {
+    pub fn new(user_id: u64, email: String, role: Role, expiration_hours: i64) -> Self {
         let now = chrono::Utc::now().timestamp();
         let exp = now + (expiration_hours * 3600); // hours to seconds
 
         Self {
             user_id,
             email,
+            role,
             exp,
             iat: now,
         }
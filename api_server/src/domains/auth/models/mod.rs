@@ -3,9 +3,13 @@ pub mod auth;
 pub mod user;
 pub mod jwt;
 pub mod refresh_token;
+pub mod api_key;
+pub mod role;
 
 pub use auth::*;
 pub use user::*;
 pub use jwt::*;
 pub use refresh_token::*;
+pub use api_key::*;
+pub use role::*;
 
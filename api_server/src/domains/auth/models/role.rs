@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use std::str::FromStr;
+
+// =====================================================
+// Role 모델
+// =====================================================
+// 역할: 사용자 역할 (관리자 API 권한)
+//
+// 권한 (아래 역할은 위 역할의 권한을 모두 포함):
+// - user: 일반 사용자
+// - support: 관리자 조회 API, 계정 동결
+// - admin: 모든 관리 작업 (마켓 상태, 수수료, 잔고 조정, 역할 변경, 봇 관리)
+// =====================================================
+
+/// 사용자 역할
+/// User role
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// 일반 사용자
+    #[default]
+    User,
+    /// 고객 지원 (조회 + 동결)
+    Support,
+    /// 관리자
+    Admin,
+}
+
+impl Role {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::User => "user",
+            Role::Support => "support",
+            Role::Admin => "admin",
+        }
+    }
+
+    /// `required` 역할의 권한이 있는지
+    pub fn allows(&self, required: Role) -> bool {
+        *self >= required
+    }
+}
+
+impl FromStr for Role {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "user" => Ok(Role::User),
+            "support" => Ok(Role::Support),
+            "admin" => Ok(Role::Admin),
+            _ => Err(anyhow::anyhow!("Unknown role: {}", value)),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use chrono::{DateTime, Utc};
+use super::role::Role;
 
 // User 모델
 // 역할: NestJS의 DTO/Entity 같은 것
@@ -28,6 +29,23 @@ pub struct User {
     #[schema(example = "johndoe")]
     pub username: Option<String>,
 
+    /// Role
+    /// 역할
+    pub role: Role,
+
+    /// Frozen timestamp (None: active)
+    /// 계정 동결 시간 (동결 시 조회만 가능)
+    pub frozen_at: Option<DateTime<Utc>>,
+
+    /// Freeze reason
+    /// 동결 사유
+    pub frozen_reason: Option<String>,
+
+    /// Fee tier (None: no tier)
+    /// 수수료 등급 (None이면 등급 없음)
+    #[schema(example = "vip1")]
+    pub fee_tier: Option<String>,
+
     /// Created timestamp
     /// 생성 시간
     pub created_at: DateTime<Utc>,
@@ -45,6 +63,8 @@ pub struct UserResponse {
     pub id: u64,
     pub email: String,
     pub username: Option<String>,
+    pub role: Role,
+    pub frozen_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -55,6 +75,8 @@ impl From<User> for UserResponse {
             id: user.id,
             email: user.email,
             username: user.username,
+            role: user.role,
+            frozen_at: user.frozen_at,
             created_at: user.created_at,
             updated_at: user.updated_at,
         }
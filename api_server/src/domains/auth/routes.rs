@@ -1,7 +1,7 @@
 // Auth domain routes
 // 인증 도메인 라우터
-use axum::{routing::{get, post}, Router};
-use crate::domains::auth::handlers::auth_handler;
+use axum::{routing::{delete, get, post}, Router};
+use crate::domains::auth::handlers::{api_key_handler, auth_handler};
 use crate::shared::services::AppState;
 
 /// Create authentication router
@@ -13,5 +13,8 @@ pub fn create_auth_router() -> Router<AppState> {
         .route("/refresh", post(auth_handler::refresh))
         .route("/logout", post(auth_handler::logout))
         .route("/me", get(auth_handler::get_me))
+        // API 키 (JWT로만 관리)
+        .route("/api-keys", post(api_key_handler::create_api_key).get(api_key_handler::get_api_keys))
+        .route("/api-keys/:id", delete(api_key_handler::revoke_api_key))
 }
 
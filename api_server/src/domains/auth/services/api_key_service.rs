@@ -0,0 +1,508 @@
+use crate::shared::database::{ApiKeyRepository, Database};
+use crate::domains::auth::models::api_key::{ApiKey, ApiKeyPrincipal, ApiKeyScope, CreateApiKeyRequest};
+use crate::domains::wallet::models::EncryptedKey;
+use crate::domains::wallet::services::KeyRotationReport;
+use crate::domains::wallet::services::key_vault::WalletKeyCipher;
+use crate::shared::errors::AuthError;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use rand::distributions::Alphanumeric;
+use sha2::Sha256;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, Ordering};
+use zeroize::Zeroizing;
+
+// =====================================================
+// ApiKeyService
+// =====================================================
+// 역할: API 키 발급/폐기와 HMAC-SHA256 요청 서명 검증
+//
+// 서명 검증 순서:
+// 1. 키 조회 (없음 / 폐기 / 만료 → 401)
+// 2. 타임스탬프가 서버 시간 ± recv window 안인지 (→ 401)
+// 3. HMAC-SHA256(secret, timestamp + METHOD + path?query + body) 비교 (상수 시간, → 401)
+// 4. 같은 서명 재사용 여부 (api_key_signatures에 recv window 동안 기억, → 401)
+// 5. IP 허용 목록 / 권한 (→ 403)
+//
+// secret은 지갑 개인 키와 같은 마스터 키(WALLET_MASTER_KEY_FILE)로 봉투 암호화해서 저장 (AAD: key_id)
+// =====================================================
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 서명 허용 시간 오차 환경 변수 (ms)
+const RECV_WINDOW_ENV: &str = "API_KEY_RECV_WINDOW_MS";
+
+/// 기본 서명 허용 시간 오차 (ms)
+const DEFAULT_RECV_WINDOW_MS: i64 = 5_000;
+
+/// 사용자당 최대 활성 API 키 수
+const MAX_ACTIVE_KEYS: usize = 20;
+
+/// 이름 최대 길이
+const MAX_LABEL_LEN: usize = 64;
+
+/// IP 허용 목록 최대 개수
+const MAX_IP_ALLOWLIST: usize = 32;
+
+/// 만료된 서명 정리 간격 (ms)
+const SIGNATURE_PRUNE_INTERVAL_MS: i64 = 60_000;
+
+/// 재암호화 시 한 번에 조회하는 키 수
+const ROTATION_BATCH_SIZE: i64 = 500;
+
+/// 서명 대상 문자열
+///
+/// `timestamp + METHOD + path?query + body`
+pub fn signature_payload(timestamp: &str, method: &str, path_and_query: &str, body: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(timestamp.len() + method.len() + path_and_query.len() + body.len());
+    payload.extend_from_slice(timestamp.as_bytes());
+    payload.extend_from_slice(method.to_ascii_uppercase().as_bytes());
+    payload.extend_from_slice(path_and_query.as_bytes());
+    payload.extend_from_slice(body);
+    payload
+}
+
+/// 서명 비교 (상수 시간)
+fn verify_signature(secret: &[u8], payload: &[u8], signature_hex: &str) -> bool {
+    let Ok(signature) = hex::decode(signature_hex) else {
+        return false;
+    };
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(payload);
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// IP / CIDR 항목 파싱 (예: "203.0.113.10", "10.0.0.0/8", "2001:db8::/32")
+fn parse_ip_rule(rule: &str) -> Option<(IpAddr, u32)> {
+    let (addr, prefix) = match rule.split_once('/') {
+        Some((addr, prefix)) => (addr.parse::<IpAddr>().ok()?, Some(prefix.parse::<u32>().ok()?)),
+        None => (rule.parse::<IpAddr>().ok()?, None),
+    };
+    let max_prefix = if addr.is_ipv4() { 32 } else { 128 };
+    let prefix = prefix.unwrap_or(max_prefix);
+    (prefix <= max_prefix).then_some((addr, prefix))
+}
+
+/// IP가 허용 목록에 있는지
+fn ip_allowed(allowlist: &[String], ip: IpAddr) -> bool {
+    // IPv4-mapped IPv6 (::ffff:a.b.c.d)는 IPv4로 비교
+    let ip = match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(ip),
+        ip => ip,
+    };
+
+    allowlist.iter().filter_map(|rule| parse_ip_rule(rule)).any(|(network, prefix)| {
+        match (network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    })
+}
+
+/// 랜덤 문자열 (영숫자)
+fn random_alphanumeric(len: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+/// 생성 요청 검증
+fn validate_request(request: &CreateApiKeyRequest) -> Result<(), AuthError> {
+    let label = request.label.trim();
+    if label.is_empty() || label.chars().count() > MAX_LABEL_LEN {
+        return Err(AuthError::InvalidRequest(format!("Label must be 1-{} characters", MAX_LABEL_LEN)));
+    }
+    if request.scopes.is_empty() {
+        return Err(AuthError::InvalidRequest("At least one scope is required".to_string()));
+    }
+    if let Some(allowlist) = &request.ip_allowlist {
+        if allowlist.is_empty() || allowlist.len() > MAX_IP_ALLOWLIST {
+            return Err(AuthError::InvalidRequest(format!(
+                "IP allowlist must have 1-{} entries (omit it to allow any IP)",
+                MAX_IP_ALLOWLIST
+            )));
+        }
+        if let Some(rule) = allowlist.iter().find(|rule| parse_ip_rule(rule.trim()).is_none()) {
+            return Err(AuthError::InvalidRequest(format!("Invalid IP or CIDR: {}", rule)));
+        }
+    }
+    if request.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        return Err(AuthError::InvalidRequest("Expiry must be in the future".to_string()));
+    }
+    Ok(())
+}
+
+/// 서명 검증 요청
+/// Signed request to verify
+pub struct SignedRequest<'a> {
+    pub key_id: &'a str,
+    pub timestamp: &'a str,
+    pub signature: &'a str,
+    pub method: &'a str,
+    pub path_and_query: &'a str,
+    pub body: &'a [u8],
+    pub client_ip: Option<IpAddr>,
+    pub required_scope: ApiKeyScope,
+}
+
+/// API 키 서비스
+/// API key Service
+#[derive(Clone)]
+pub struct ApiKeyService {
+    db: Database,
+    /// secret 암호화 (WALLET_MASTER_KEY_FILE, 없으면 키 생성 / 암호화된 키 사용 불가)
+    key_cipher: Option<WalletKeyCipher>,
+    /// 서명 허용 시간 오차 (ms)
+    recv_window_ms: i64,
+    /// 마지막으로 만료된 서명을 정리한 시간 (ms)
+    last_signature_prune_ms: Arc<AtomicI64>,
+}
+
+impl ApiKeyService {
+    /// 새 ApiKeyService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    ///
+    /// # 환경 변수
+    /// * `API_KEY_RECV_WINDOW_MS` - 서명 허용 시간 오차 (기본: 5000)
+    /// * `WALLET_MASTER_KEY_FILE` - secret 암호화용 마스터 키
+    pub fn new(db: Database) -> Result<Self, AuthError> {
+        let key_cipher = WalletKeyCipher::from_env()
+            .map_err(|e| AuthError::Internal(format!("Failed to load wallet master key: {:#}", e)))?;
+        let recv_window_ms = std::env::var(RECV_WINDOW_ENV)
+            .ok()
+            .and_then(|value| value.parse::<i64>().ok())
+            .filter(|value| *value > 0)
+            .unwrap_or(DEFAULT_RECV_WINDOW_MS);
+
+        Ok(Self {
+            db,
+            key_cipher,
+            recv_window_ms,
+            last_signature_prune_ms: Arc::new(AtomicI64::new(0)),
+        })
+    }
+
+    fn key_cipher(&self) -> Result<&WalletKeyCipher, AuthError> {
+        self.key_cipher.as_ref().ok_or(AuthError::KeyEncryptionUnavailable)
+    }
+
+    /// 서명 검증용 secret (암호화 전 기존 행은 평문 그대로)
+    fn decrypt_secret(&self, api_key: &ApiKey) -> Result<Zeroizing<Vec<u8>>, AuthError> {
+        match api_key.encrypted_secret() {
+            Some(encrypted) => self
+                .key_cipher()?
+                .decrypt(&api_key.key_id, &encrypted)
+                .map_err(|e| AuthError::Internal(format!("Failed to decrypt API key secret: {:#}", e))),
+            None => Ok(Zeroizing::new(api_key.secret.as_bytes().to_vec())),
+        }
+    }
+
+    /// API 키 생성
+    /// Create API key
+    ///
+    /// # Returns
+    /// * `Ok((ApiKey, secret))` - secret은 이 응답에서만 반환
+    /// * `Err(InvalidRequest)` - 잘못된 이름/권한/IP/만료, 활성 키 수 초과
+    /// * `Err(KeyEncryptionUnavailable)` - 마스터 키 미설정
+    pub async fn create_key(&self, user_id: u64, request: &CreateApiKeyRequest) -> Result<(ApiKey, String), AuthError> {
+        validate_request(request)?;
+        let key_cipher = self.key_cipher()?;
+
+        let repo = ApiKeyRepository::new(self.db.pool().clone());
+        let keys = repo
+            .list_by_user(user_id)
+            .await
+            .map_err(|e| AuthError::DatabaseError(format!("Failed to fetch API keys: {}", e)))?;
+        let now = Utc::now();
+        let active = keys
+            .iter()
+            .filter(|key| key.revoked_at.is_none() && key.expires_at.is_none_or(|expires_at| expires_at > now))
+            .count();
+        if active >= MAX_ACTIVE_KEYS {
+            return Err(AuthError::InvalidRequest(format!("Active API key limit reached ({})", MAX_ACTIVE_KEYS)));
+        }
+
+        let mut scopes = request.scopes.clone();
+        scopes.sort_by_key(|scope| scope.as_str());
+        scopes.dedup();
+        let ip_allowlist = request
+            .ip_allowlist
+            .as_ref()
+            .map(|allowlist| allowlist.iter().map(|rule| rule.trim().to_string()).collect::<Vec<_>>());
+
+        let key_id = format!("ak_{}", random_alphanumeric(24));
+        let secret = random_alphanumeric(48);
+        let encrypted_secret = key_cipher
+            .encrypt(&key_id, secret.as_bytes())
+            .map_err(|e| AuthError::Internal(format!("Failed to encrypt API key secret: {:#}", e)))?;
+        let api_key = repo
+            .create(
+                user_id,
+                &key_id,
+                &encrypted_secret,
+                request.label.trim(),
+                &scopes,
+                ip_allowlist.as_deref(),
+                request.expires_at,
+            )
+            .await
+            .map_err(|e| AuthError::DatabaseError(format!("Failed to create API key: {}", e)))?;
+
+        Ok((api_key, secret))
+    }
+
+    /// API 키 목록
+    /// List API keys
+    pub async fn list_keys(&self, user_id: u64) -> Result<Vec<ApiKey>, AuthError> {
+        ApiKeyRepository::new(self.db.pool().clone())
+            .list_by_user(user_id)
+            .await
+            .map_err(|e| AuthError::DatabaseError(format!("Failed to fetch API keys: {}", e)))
+    }
+
+    /// API 키 폐기
+    /// Revoke API key
+    ///
+    /// # Returns
+    /// 폐기되었는지 (없거나 이미 폐기된 키면 false)
+    pub async fn revoke_key(&self, user_id: u64, id: u64) -> Result<bool, AuthError> {
+        ApiKeyRepository::new(self.db.pool().clone())
+            .revoke(user_id, id)
+            .await
+            .map_err(|e| AuthError::DatabaseError(format!("Failed to revoke API key: {}", e)))
+    }
+
+    /// 서명된 요청 검증
+    /// Verify a signed request
+    ///
+    /// # Returns
+    /// * `Ok(ApiKeyPrincipal)` - 검증 성공
+    /// * `Err(InvalidApiKey)` - 키 없음/폐기/만료, 시간 초과, 서명 불일치, 재사용 (401)
+    /// * `Err(Forbidden)` - IP 허용 목록 밖, 권한 부족 (403)
+    pub async fn authenticate(&self, request: &SignedRequest<'_>) -> Result<ApiKeyPrincipal, AuthError> {
+        // 1. 키 조회
+        let (api_key, email) = ApiKeyRepository::new(self.db.pool().clone())
+            .get_by_key_id(request.key_id)
+            .await
+            .map_err(|e| AuthError::DatabaseError(format!("Failed to fetch API key: {}", e)))?
+            .ok_or_else(|| AuthError::InvalidApiKey("Unknown API key".to_string()))?;
+
+        let now = Utc::now();
+        if api_key.revoked_at.is_some() {
+            return Err(AuthError::InvalidApiKey("API key has been revoked".to_string()));
+        }
+        if api_key.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(AuthError::InvalidApiKey("API key has expired".to_string()));
+        }
+
+        // 2. 타임스탬프
+        let now_ms = now.timestamp_millis();
+        let timestamp_ms = request
+            .timestamp
+            .parse::<i64>()
+            .map_err(|_| AuthError::InvalidApiKey("Timestamp must be Unix milliseconds".to_string()))?;
+        if (now_ms - timestamp_ms).abs() > self.recv_window_ms {
+            return Err(AuthError::InvalidApiKey(format!(
+                "Timestamp is outside the {}ms window",
+                self.recv_window_ms
+            )));
+        }
+
+        // 3. 서명
+        let payload = signature_payload(request.timestamp, request.method, request.path_and_query, request.body);
+        if !verify_signature(&self.decrypt_secret(&api_key)?, &payload, request.signature) {
+            return Err(AuthError::InvalidApiKey("Signature mismatch".to_string()));
+        }
+
+        // 4. 재사용 방지 (서명에 타임스탬프가 포함되므로 window 동안만 기억, 인스턴스 간 공유)
+        let repo = ApiKeyRepository::new(self.db.pool().clone());
+        let expires_at = DateTime::<Utc>::from_timestamp_millis(timestamp_ms + self.recv_window_ms)
+            .ok_or_else(|| AuthError::InvalidApiKey("Timestamp must be Unix milliseconds".to_string()))?;
+        let first_use = repo
+            .record_signature(&api_key.key_id, &request.signature.to_ascii_lowercase(), expires_at)
+            .await
+            .map_err(|e| AuthError::DatabaseError(format!("Failed to record API key signature: {}", e)))?;
+        if !first_use {
+            return Err(AuthError::InvalidApiKey("Replayed request".to_string()));
+        }
+        self.prune_signatures(now_ms);
+
+        // 5. IP / 권한
+        if let Some(allowlist) = &api_key.ip_allowlist {
+            let allowed = request.client_ip.is_some_and(|ip| ip_allowed(allowlist, ip));
+            if !allowed {
+                return Err(AuthError::Forbidden("Client IP is not in the API key allowlist".to_string()));
+            }
+        }
+        if !api_key.scopes.contains(&request.required_scope) {
+            return Err(AuthError::Forbidden(format!(
+                "API key lacks the '{}' scope",
+                request.required_scope.as_str()
+            )));
+        }
+
+        // 마지막 사용 시간 (응답을 기다리지 않음)
+        let id = api_key.id;
+        tokio::spawn(async move {
+            if let Err(e) = repo.touch_last_used(id).await {
+                eprintln!("[ApiKey] {:#}", e);
+            }
+        });
+
+        Ok(ApiKeyPrincipal {
+            user_id: api_key.user_id,
+            email,
+            key_id: api_key.key_id,
+        })
+    }
+}
+
+impl ApiKeyService {
+    /// 만료된 서명 정리 (SIGNATURE_PRUNE_INTERVAL_MS에 한 번, 응답을 기다리지 않음)
+    fn prune_signatures(&self, now_ms: i64) {
+        let last = self.last_signature_prune_ms.load(Ordering::Relaxed);
+        if now_ms - last < SIGNATURE_PRUNE_INTERVAL_MS
+            || self
+                .last_signature_prune_ms
+                .compare_exchange(last, now_ms, Ordering::Relaxed, Ordering::Relaxed)
+                .is_err()
+        {
+            return;
+        }
+
+        let repo = ApiKeyRepository::new(self.db.pool().clone());
+        tokio::spawn(async move {
+            if let Err(e) = repo.delete_expired_signatures().await {
+                eprintln!("[ApiKey] {:#}", e);
+            }
+        });
+    }
+
+    /// 모든 API 키 secret을 현재 마스터 키 버전으로 재암호화
+    /// Re-wrap every API key secret with the current master key version
+    ///
+    /// WalletService::rotate_keys와 같은 방식 (암호화된 행은 데이터 키만 다시 감싸고, 평문 행은 새로 암호화)
+    /// 한 키가 실패해도 나머지는 계속 처리하고 실패 목록을 반환
+    pub async fn rotate_secrets(&self) -> Result<KeyRotationReport, AuthError> {
+        let key_cipher = self.key_cipher()?;
+        let repo = ApiKeyRepository::new(self.db.pool().clone());
+        let mut report = KeyRotationReport {
+            key_version: key_cipher.current_version(),
+            ..Default::default()
+        };
+
+        let mut after_id = 0;
+        loop {
+            let keys = repo
+                .get_keys_for_rotation(report.key_version, after_id, ROTATION_BATCH_SIZE)
+                .await
+                .map_err(|e| AuthError::DatabaseError(format!("Failed to fetch API keys for key rotation: {:#}", e)))?;
+            let Some(last) = keys.last() else {
+                break;
+            };
+            after_id = last.id;
+
+            for api_key in keys {
+                let new_secret = match api_key.encrypted_secret() {
+                    Some(encrypted) => key_cipher.rewrap(&encrypted).map(|(key_version, wrapped_data_key)| {
+                        EncryptedKey { wrapped_data_key, key_version, ..encrypted }
+                    }),
+                    None => key_cipher.encrypt(&api_key.key_id, api_key.secret.as_bytes()),
+                };
+                let new_secret = match new_secret {
+                    Ok(new_secret) => new_secret,
+                    Err(e) => {
+                        report.failed.push((api_key.id, format!("{:#}", e)));
+                        continue;
+                    }
+                };
+
+                match repo.update_secret(api_key.id, api_key.key_version, &new_secret).await {
+                    Ok(true) if api_key.key_version.is_some() => report.rewrapped += 1,
+                    Ok(true) => report.encrypted += 1,
+                    Ok(false) => {}
+                    Err(e) => report.failed.push((api_key.id, format!("{:#}", e))),
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Method;
+
+    /// 서명 계산 (클라이언트 쪽 계산과 같은 방식, hex)
+    fn sign(secret: &str, payload: &[u8]) -> String {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(payload);
+        format!("{:x}", mac.finalize().into_bytes())
+    }
+
+    /// 테스트: 서명 계산/검증 (알려진 HMAC-SHA256 값)
+    #[test]
+    fn test_sign_and_verify() {
+        // RFC 4231 test case 2
+        let signature = sign("Jefe", b"what do ya want for nothing?");
+        assert_eq!(signature, "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+
+        let payload = signature_payload("1700000000000", "post", "/api/cex/orders", br#"{"amount":"1"}"#);
+        assert_eq!(payload, br#"1700000000000POST/api/cex/orders{"amount":"1"}"#.to_vec());
+
+        let signature = sign("secret", &payload);
+        assert!(verify_signature(b"secret", &payload, &signature));
+        assert!(verify_signature(b"secret", &payload, &signature.to_ascii_uppercase()));
+        assert!(!verify_signature(b"other", &payload, &signature));
+        assert!(!verify_signature(b"secret", b"tampered", &signature));
+        assert!(!verify_signature(b"secret", &payload, "not-hex"));
+    }
+
+    /// 테스트: IP / CIDR 허용 목록
+    #[test]
+    fn test_ip_allowed() {
+        let allowlist = vec!["203.0.113.10".to_string(), "10.0.0.0/8".to_string(), "2001:db8::/32".to_string()];
+
+        assert!(ip_allowed(&allowlist, "203.0.113.10".parse().unwrap()));
+        assert!(!ip_allowed(&allowlist, "203.0.113.11".parse().unwrap()));
+        assert!(ip_allowed(&allowlist, "10.255.1.2".parse().unwrap()));
+        assert!(ip_allowed(&allowlist, "::ffff:10.1.2.3".parse().unwrap()));
+        assert!(ip_allowed(&allowlist, "2001:db8:1::1".parse().unwrap()));
+        assert!(!ip_allowed(&allowlist, "2001:db9::1".parse().unwrap()));
+
+        assert!(ip_allowed(&["0.0.0.0/0".to_string()], "198.51.100.1".parse().unwrap()));
+        assert!(parse_ip_rule("10.0.0.0/33").is_none());
+        assert!(parse_ip_rule("not-an-ip").is_none());
+    }
+
+    /// 테스트: 경로별 필요 권한
+    #[test]
+    fn test_required_scope() {
+        assert_eq!(ApiKeyScope::required_for(&Method::GET, "/api/cex/orders/my"), Some(ApiKeyScope::Read));
+        assert_eq!(ApiKeyScope::required_for(&Method::POST, "/api/cex/orders"), Some(ApiKeyScope::Trade));
+        assert_eq!(ApiKeyScope::required_for(&Method::DELETE, "/api/cex/orders/7"), Some(ApiKeyScope::Trade));
+        assert_eq!(ApiKeyScope::required_for(&Method::POST, "/api/cex/withdrawals"), Some(ApiKeyScope::Withdraw));
+        assert_eq!(ApiKeyScope::required_for(&Method::POST, "/api/cex/transfers"), Some(ApiKeyScope::Withdraw));
+        assert_eq!(ApiKeyScope::required_for(&Method::GET, "/api/auth/me"), Some(ApiKeyScope::Read));
+
+        assert_eq!(ApiKeyScope::required_for(&Method::POST, "/api/auth/api-keys"), None);
+        assert_eq!(ApiKeyScope::required_for(&Method::GET, "/api/auth/api-keys"), None);
+        assert_eq!(ApiKeyScope::required_for(&Method::POST, "/api/admin/bot/cleanup-scheduler/enable"), None);
+        assert_eq!(ApiKeyScope::required_for(&Method::GET, "/api/admin/audit-logs"), None);
+    }
+}
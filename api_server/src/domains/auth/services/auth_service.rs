@@ -1,6 +1,7 @@
-use crate::shared::database::{Database, UserRepository, RefreshTokenRepository};
+use crate::shared::database::{Database, UserRepository, RefreshTokenRepository, SubAccountRepository};
 use crate::domains::auth::models::{User, SignupRequest, SigninRequest, RefreshTokenCreate};
 use crate::domains::auth::services::JwtService;
+use crate::domains::cex::services::SUB_ACCOUNT_EMAIL_DOMAIN;
 use crate::shared::errors::AuthError;
 use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use argon2::password_hash::{rand_core::OsRng, SaltString};
@@ -46,7 +47,11 @@ impl AuthService {
         // Repository 생성 (Service 내부에서)
         let user_repo = UserRepository::new(self.db.pool().clone());
 
-        // 1. 이메일 중복 확인
+        // 1. 이메일 중복 확인 (서브 계정 합성 이메일 도메인은 가입 불가)
+        if request.email.to_ascii_lowercase().ends_with(&format!("@{}", SUB_ACCOUNT_EMAIL_DOMAIN)) {
+            return Err(AuthError::EmailAlreadyExists { email: request.email });
+        }
+
         let existing_user = user_repo
             .get_user_by_email(&request.email)
             .await
@@ -92,6 +97,15 @@ impl AuthService {
             None => return Err(AuthError::InvalidCredentials),
         };
 
+        // 서브 계정은 로그인 불가 (마스터가 X-Sub-Account-Id 헤더로 대신 사용)
+        let sub_account = SubAccountRepository::new(self.db.pool().clone())
+            .get_by_user_id(user.id)
+            .await
+            .map_err(|e| AuthError::DatabaseError(format!("Failed to fetch sub-account: {}", e)))?;
+        if sub_account.is_some() {
+            return Err(AuthError::InvalidCredentials);
+        }
+
         // 2. 비밀번호 검증
         Self::verify_password(&request.password, &user.password_hash)?;
 
@@ -173,7 +187,7 @@ impl AuthService {
             .ok_or(AuthError::InvalidToken)?;
 
         // 5. 새 Access Token 생성
-        let access_token = self.jwt_service.generate_access_token(user.id, user.email.clone())?;
+        let access_token = self.jwt_service.generate_access_token(user.id, user.email.clone(), user.role)?;
 
         // 6. 기존 Refresh Token 무효화 (새 토큰 생성 전에 먼저 무효화)
         refresh_token_repo
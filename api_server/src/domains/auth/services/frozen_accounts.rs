@@ -0,0 +1,67 @@
+use crate::shared::database::{Database, UserRepository};
+use anyhow::Result;
+use parking_lot::RwLock;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// =====================================================
+// FrozenAccounts
+// =====================================================
+// 역할: 동결된 사용자 ID 캐시 (요청마다 DB 조회 없이 검사)
+//
+// 처리 방식:
+// - 서버 시작 시 users.frozen_at IS NOT NULL인 사용자 로드
+// - 관리자 동결/해제는 DB 저장 후 캐시에 반영
+// - 동결된 사용자는 인증 단계에서 조회(GET/HEAD)만 허용
+// =====================================================
+
+/// 동결 계정 캐시
+/// Frozen account cache
+#[derive(Clone)]
+pub struct FrozenAccounts {
+    db: Database,
+    user_ids: Arc<RwLock<HashSet<u64>>>,
+}
+
+impl FrozenAccounts {
+    pub fn new(db: Database) -> Self {
+        Self {
+            db,
+            user_ids: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+
+    /// DB에서 동결 계정 로드 (서버 시작 시)
+    /// Load frozen accounts from database
+    pub async fn load(&self) -> Result<usize> {
+        let ids = UserRepository::new(self.db.pool().clone()).list_frozen_ids().await?;
+        let count = ids.len();
+        *self.user_ids.write() = ids.into_iter().collect();
+        Ok(count)
+    }
+
+    /// 동결 여부
+    pub fn is_frozen(&self, user_id: u64) -> bool {
+        self.user_ids.read().contains(&user_id)
+    }
+
+    /// 계정 동결 / 해제 (reason이 None이면 해제)
+    /// Freeze or unfreeze a user
+    ///
+    /// # Returns
+    /// 사용자가 있으면 true
+    pub async fn set(&self, user_id: u64, reason: Option<&str>) -> Result<bool> {
+        let updated = UserRepository::new(self.db.pool().clone())
+            .set_frozen(user_id, reason)
+            .await?;
+        if updated {
+            let mut user_ids = self.user_ids.write();
+            if reason.is_some() {
+                user_ids.insert(user_id);
+            } else {
+                user_ids.remove(&user_id);
+            }
+        }
+        Ok(updated)
+    }
+}
@@ -1,6 +1,7 @@
 // src/domains/auth/services/jwt_service.rs
 use crate::shared::errors::AuthError;
 use crate::domains::auth::models::jwt::Claims;
+use crate::domains::auth::models::role::Role;
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use sha2::{Sha256, Digest};
 use rand::Rng;
@@ -31,8 +32,8 @@ impl JwtService {
 
     /// Access Token 발급 (짧은 수명)
     /// Generate Access Token (short lifetime)
-    pub fn generate_access_token(&self, user_id: u64, email: String) -> Result<String, AuthError> {
-        let claims = Claims::new(user_id, email, 1); // 1시간 만료
+    pub fn generate_access_token(&self, user_id: u64, email: String, role: Role) -> Result<String, AuthError> {
+        let claims = Claims::new(user_id, email, role, 1); // 1시간 만료
 
         encode(&Header::default(), &claims, &self.encoding_key)
             .map_err(|e| AuthError::Internal(format!("Failed to generate access token: {}", e)))
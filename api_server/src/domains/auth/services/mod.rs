@@ -1,9 +1,13 @@
 // Auth domain services
 pub mod auth_service;
+pub mod api_key_service;
+pub mod frozen_accounts;
 pub mod jwt_service;
 pub mod state;
 
 pub use auth_service::*;
+pub use api_key_service::*;
+pub use frozen_accounts::*;
 pub use jwt_service::*;
 pub use state::*;
 
@@ -1,7 +1,8 @@
 // Auth domain state
 // 인증 도메인 상태
 use crate::shared::database::Database;
-use crate::domains::auth::services::{AuthService, JwtService};
+use crate::domains::auth::services::{ApiKeyService, AuthService, FrozenAccounts, JwtService};
+use anyhow::Result;
 
 /// Auth domain state
 /// 인증 도메인에서 필요한 서비스들을 포함하는 상태
@@ -9,16 +10,20 @@ use crate::domains::auth::services::{AuthService, JwtService};
 pub struct AuthState {
     pub auth_service: AuthService,
     pub jwt_service: JwtService,
+    pub api_key_service: ApiKeyService,
+    pub frozen_accounts: FrozenAccounts,
 }
 
 impl AuthState {
     /// Create AuthState with database and JWT service
     /// AuthState 생성 (데이터베이스와 JWT 서비스 필요)
-    pub fn new(db: Database, jwt_service: JwtService) -> Self {
-        Self {
-            auth_service: AuthService::with_jwt_service(db, jwt_service.clone()),
+    pub fn new(db: Database, jwt_service: JwtService) -> Result<Self> {
+        Ok(Self {
+            auth_service: AuthService::with_jwt_service(db.clone(), jwt_service.clone()),
             jwt_service,
-        }
+            api_key_service: ApiKeyService::new(db.clone())?,
+            frozen_accounts: FrozenAccounts::new(db),
+        })
     }
 }
 
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use crate::shared::services::AppState;
 use crate::shared::database::UserRepository;
+use crate::shared::middleware::role::AdminUser;
 
 /// 봇 데이터 삭제 요청
 /// Delete bot data request
@@ -48,17 +49,21 @@ pub struct DeleteBotDataResponse {
 /// - 데이터베이스 오류
 #[utoipa::path(
     delete,
-    path = "/api/bot/data",
+    path = "/api/admin/bot/data",
     request_body = DeleteBotDataRequest,
     responses(
         (status = 200, description = "봇 데이터 삭제 성공", body = DeleteBotDataResponse),
         (status = 400, description = "잘못된 요청"),
+        (status = 401, description = "인증 실패"),
+        (status = 403, description = "관리자 아님"),
         (status = 500, description = "서버 오류")
     ),
-    tag = "Bot"
+    tag = "Bot",
+    security(("BearerAuth" = []))
 )]
 pub async fn delete_bot_data(
     State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
     Json(request): Json<DeleteBotDataRequest>,
 ) -> axum::response::Response {
     // 데이터베이스 연결 가져오기
@@ -133,6 +138,26 @@ pub async fn delete_bot_data(
         }
     };
     
+    if let Err(e) = app_state
+        .admin_state
+        .admin_service
+        .record(
+            admin.user_id,
+            "bot.delete_data",
+            "bot",
+            Some(&user_id.to_string()),
+            serde_json::json!({
+                "bot_email": request.bot_email,
+                "deleted_orders": deleted_orders.len(),
+                "deleted_trades": deleted_trades.len(),
+            }),
+        )
+        .await
+    {
+        eprintln!("[Bot Handler] {:#}", e);
+        return (axum::http::StatusCode::INTERNAL_SERVER_ERROR, "Audit log error").into_response();
+    }
+    
     (axum::http::StatusCode::OK, axum::Json(DeleteBotDataResponse {
         deleted_orders: deleted_orders.len() as u64,
         deleted_trades: deleted_trades.len() as u64,
@@ -151,15 +176,19 @@ pub async fn delete_bot_data(
 /// 봇 데이터 정리 스케줄러의 활성화 상태를 조회합니다.
 #[utoipa::path(
     get,
-    path = "/api/bot/cleanup-scheduler/status",
+    path = "/api/admin/bot/cleanup-scheduler/status",
     responses(
         (status = 200, description = "스케줄러 상태 조회 성공", body = serde_json::Value),
+        (status = 401, description = "인증 실패"),
+        (status = 403, description = "관리자 아님"),
         (status = 500, description = "서버 오류")
     ),
-    tag = "Bot"
+    tag = "Bot",
+    security(("BearerAuth" = []))
 )]
 pub async fn get_cleanup_scheduler_status(
     State(app_state): State<AppState>,
+    AdminUser(_admin): AdminUser,
 ) -> axum::response::Response {
     let is_enabled = app_state.bot_cleanup_scheduler.is_enabled();
     
@@ -180,17 +209,30 @@ pub async fn get_cleanup_scheduler_status(
 /// 봇 데이터 정리 스케줄러를 활성화합니다.
 #[utoipa::path(
     post,
-    path = "/api/bot/cleanup-scheduler/enable",
+    path = "/api/admin/bot/cleanup-scheduler/enable",
     responses(
         (status = 200, description = "스케줄러 활성화 성공", body = serde_json::Value),
+        (status = 401, description = "인증 실패"),
+        (status = 403, description = "관리자 아님"),
         (status = 500, description = "서버 오류")
     ),
-    tag = "Bot"
+    tag = "Bot",
+    security(("BearerAuth" = []))
 )]
 pub async fn enable_cleanup_scheduler(
     State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
 ) -> axum::response::Response {
     app_state.bot_cleanup_scheduler.enable();
+    if let Err(e) = app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "bot.cleanup_scheduler", "bot", None, serde_json::json!({ "enabled": true }))
+        .await
+    {
+        eprintln!("[Bot Handler] {:#}", e);
+        return (axum::http::StatusCode::INTERNAL_SERVER_ERROR, "Audit log error").into_response();
+    }
     
     (axum::http::StatusCode::OK, axum::Json(serde_json::json!({
         "enabled": true,
@@ -204,17 +246,30 @@ pub async fn enable_cleanup_scheduler(
 /// 봇 데이터 정리 스케줄러를 비활성화합니다.
 #[utoipa::path(
     post,
-    path = "/api/bot/cleanup-scheduler/disable",
+    path = "/api/admin/bot/cleanup-scheduler/disable",
     responses(
         (status = 200, description = "스케줄러 비활성화 성공", body = serde_json::Value),
+        (status = 401, description = "인증 실패"),
+        (status = 403, description = "관리자 아님"),
         (status = 500, description = "서버 오류")
     ),
-    tag = "Bot"
+    tag = "Bot",
+    security(("BearerAuth" = []))
 )]
 pub async fn disable_cleanup_scheduler(
     State(app_state): State<AppState>,
+    AdminUser(admin): AdminUser,
 ) -> axum::response::Response {
     app_state.bot_cleanup_scheduler.disable();
+    if let Err(e) = app_state
+        .admin_state
+        .admin_service
+        .record(admin.user_id, "bot.cleanup_scheduler", "bot", None, serde_json::json!({ "enabled": false }))
+        .await
+    {
+        eprintln!("[Bot Handler] {:#}", e);
+        return (axum::http::StatusCode::INTERNAL_SERVER_ERROR, "Audit log error").into_response();
+    }
     
     (axum::http::StatusCode::OK, axum::Json(serde_json::json!({
         "enabled": false,
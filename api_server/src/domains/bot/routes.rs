@@ -6,6 +6,7 @@ use crate::domains::bot::handlers::bot_handler;
 /// Create bot router
 /// 
 /// 봇 관련 API 엔드포인트를 제공합니다.
+/// 관리자 라우터의 `/api/admin/bot` 아래에 연결됩니다 (admin 역할 필요).
 pub fn create_bot_router() -> Router<AppState> {
     Router::new()
         .route("/data", axum::routing::delete(bot_handler::delete_bot_data))
@@ -323,9 +323,10 @@ impl BotManager {
         amount: Decimal,
     ) -> Result<()> {
         use crate::domains::cex::engine::Engine;
+        use crate::domains::cex::models::ledger::LedgerReason;
         let engine_guard = self.engine.lock().await;
         engine_guard
-            .update_balance(user_id, mint, amount)
+            .update_balance(user_id, mint, amount, LedgerReason::Adjustment, "bot_balance")
             .await
             .context(format!("Failed to set bot balance: user_id={}, mint={}, amount={}", user_id, mint, amount))?;
         
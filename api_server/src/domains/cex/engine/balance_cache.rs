@@ -12,6 +12,11 @@
 // 1. 주문 생성 → available 차감, locked 증가
 // 2. 주문 체결 → locked 차감, 상대방 available 증가
 // 3. 주문 취소 → locked 차감, available 증가
+//
+// 보류 (hold, 출금용 - 주문 잠금과 별도):
+// 1. 보류 → available 차감, hold_id별로 기록
+// 2. 해제 (release) → available 복구
+// 3. 소진 (consume) → 기록만 삭제 (자산이 거래소 밖으로 나감)
 // =====================================================
 
 use std::collections::HashMap;
@@ -56,6 +61,15 @@ impl Balance {
     }
 }
 
+/// 잔고 보류 (출금 등 주문 외 용도)
+/// Balance hold (e.g. withdrawal), separate from order locks
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceHold {
+    pub user_id: u64,
+    pub mint: String,
+    pub amount: Decimal,
+}
+
 /// 메모리 기반 잔고 캐시
 /// 
 /// 구조:
@@ -71,6 +85,9 @@ pub struct BalanceCache {
     /// Key: (user_id, mint_address)
     /// Value: Balance
     balances: HashMap<(u64, String), Balance>,
+    /// Key: hold_id (예: 출금 ID)
+    /// Value: 보류 중인 잔고 (available에서 이미 차감됨)
+    holds: HashMap<u64, BalanceHold>,
 }
 
 impl BalanceCache {
@@ -78,6 +95,7 @@ impl BalanceCache {
     pub fn new() -> Self {
         Self {
             balances: HashMap::new(),
+            holds: HashMap::new(),
         }
     }
     
@@ -87,6 +105,7 @@ impl BalanceCache {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             balances: HashMap::with_capacity(capacity),
+            holds: HashMap::new(),
         }
     }
     
@@ -265,9 +284,96 @@ impl BalanceCache {
         }
     }
 
+    /// 잔고 보류 (출금 요청 시)
+    /// available 차감 → hold_id로 기록 (locked와 별도)
+    /// 
+    /// # Arguments
+    /// * `hold_id` - 보류 ID (예: 출금 ID, 중복 불가)
+    /// * `user_id` - 사용자 ID
+    /// * `mint` - 자산 종류
+    /// * `amount` - 보류할 금액
+    /// 
+    /// # Returns
+    /// 성공 또는 에러 (잔고 부족, 이미 있는 hold_id)
+    pub fn place_hold(
+        &mut self,
+        hold_id: u64,
+        user_id: u64,
+        mint: &str,
+        amount: Decimal,
+    ) -> Result<()> {
+        if self.holds.contains_key(&hold_id) {
+            bail!("Hold already exists: hold_id={}", hold_id);
+        }
+        
+        let balance = self.get_balance_mut(user_id, mint);
+        if balance.available < amount {
+            bail!(
+                "Insufficient balance: user={}, mint={}, required={}, available={}",
+                user_id, mint, amount, balance.available
+            );
+        }
+        balance.available -= amount;
+        
+        self.restore_hold(hold_id, user_id, mint, amount);
+        Ok(())
+    }
+    
+    /// 보류 기록만 복원 (엔진 시작 시, available은 이미 차감된 상태)
+    pub fn restore_hold(&mut self, hold_id: u64, user_id: u64, mint: &str, amount: Decimal) {
+        self.holds.insert(hold_id, BalanceHold {
+            user_id,
+            mint: mint.to_string(),
+            amount,
+        });
+    }
+    
+    /// 보류 해제 (출금 실패 시)
+    /// 보류 금액을 available로 복구
+    pub fn release_hold(&mut self, hold_id: u64) -> Result<BalanceHold> {
+        let hold = self.take_hold(hold_id)?;
+        self.get_balance_mut(hold.user_id, &hold.mint).available += hold.amount;
+        Ok(hold)
+    }
+    
+    /// 보류 소진 (출금 완료 시)
+    /// 보류 기록만 삭제 (available은 보류 시 이미 차감됨)
+    pub fn consume_hold(&mut self, hold_id: u64) -> Result<BalanceHold> {
+        self.take_hold(hold_id)
+    }
+    
+    /// 보류 조회
+    pub fn get_hold(&self, hold_id: u64) -> Option<&BalanceHold> {
+        self.holds.get(&hold_id)
+    }
+    
+    /// 사용자 자산별 보류 합계
+    pub fn held_balance(&self, user_id: u64, mint: &str) -> Decimal {
+        self.holds
+            .values()
+            .filter(|hold| hold.user_id == user_id && hold.mint == mint)
+            .map(|hold| hold.amount)
+            .sum()
+    }
+    
+    fn take_hold(&mut self, hold_id: u64) -> Result<BalanceHold> {
+        match self.holds.remove(&hold_id) {
+            Some(hold) => Ok(hold),
+            None => bail!("Hold not found: hold_id={}", hold_id),
+        }
+    }
+
+    /// 모든 잔고 순회 (정합성 검사 스냅샷용)
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &str, &Balance)> {
+        self.balances
+            .iter()
+            .map(|((user_id, mint), balance)| (*user_id, mint.as_str(), balance))
+    }
+
     /// 모든 잔고 삭제 (벤치마크/테스트 초기화용)
     pub fn clear(&mut self) {
         self.balances.clear();
+        self.holds.clear();
     }
 }
 
@@ -371,5 +477,37 @@ mod tests {
         
         assert!(result.is_err());
     }
+    
+    /// 테스트: 출금 보류
+    /// 
+    /// 보류는 locked와 별도로 available에서 차감되고,
+    /// 해제하면 복구, 소진하면 기록만 삭제되는지 확인합니다.
+    #[test]
+    fn test_withdrawal_holds() {
+        let mut cache = BalanceCache::new();
+        cache.set_balance(1, "SOL", Decimal::new(10, 0), Decimal::new(2, 0));
+        
+        cache.place_hold(100, 1, "SOL", Decimal::new(4, 0)).expect("Failed to place hold");
+        cache.place_hold(101, 1, "SOL", Decimal::new(5, 0)).expect("Failed to place hold");
+        
+        let balance = cache.get_balance(1, "SOL").unwrap();
+        assert_eq!(balance.available, Decimal::new(1, 0));
+        assert_eq!(balance.locked, Decimal::new(2, 0));
+        assert_eq!(cache.held_balance(1, "SOL"), Decimal::new(9, 0));
+        
+        // 잔고 부족 / 중복 hold_id
+        assert!(cache.place_hold(102, 1, "SOL", Decimal::new(2, 0)).is_err());
+        assert!(cache.place_hold(100, 1, "SOL", Decimal::ONE).is_err());
+        
+        // 해제: available 복구
+        cache.release_hold(100).expect("Failed to release hold");
+        assert_eq!(cache.get_balance(1, "SOL").unwrap().available, Decimal::new(5, 0));
+        
+        // 소진: available 그대로, 보류만 삭제
+        let consumed = cache.consume_hold(101).expect("Failed to consume hold");
+        assert_eq!(consumed.amount, Decimal::new(5, 0));
+        assert_eq!(cache.get_balance(1, "SOL").unwrap().available, Decimal::new(5, 0));
+        assert_eq!(cache.held_balance(1, "SOL"), Decimal::ZERO);
+        assert!(cache.release_hold(101).is_err());
+    }
 }
-
@@ -0,0 +1,292 @@
+// =====================================================
+// EventBus - 엔진 이벤트 발행/구독
+// =====================================================
+// 역할: 엔진 스레드에서 발생한 EngineEvent를 in-process 구독자에게 전달
+//
+// 핵심 설계:
+// 1. 모든 이벤트에 단조 증가하는 시퀀스 번호 부여 (구독자가 누락 감지 가능)
+// 2. 구독자마다 bounded 큐 (tokio mpsc) → 엔진은 try_send만 사용 (블로킹 없음)
+// 3. 큐가 가득 찬 느린 구독자는 즉시 구독 해제 (엔진을 막지 않음)
+// 4. 구독자가 없으면 이벤트 생성 자체를 건너뜀 (`has_subscribers()`)
+//
+// 사용처:
+// - 시세 (WebSocket, 멀티캐스트)
+// - 알림 / 포지션 추적 / 메트릭
+// =====================================================
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::{TryRecvError, TrySendError};
+
+use crate::domains::cex::engine::types::EngineEvent;
+
+/// 구독자 큐 기본 크기
+pub const DEFAULT_SUBSCRIBER_CAPACITY: usize = 4096;
+
+/// 시퀀스가 부여된 엔진 이벤트
+#[derive(Debug, Clone, Serialize)]
+pub struct SequencedEvent {
+    /// 이벤트 시퀀스 (1부터 시작, 발행 순서)
+    pub seq: u64,
+    /// 발행 시간 (밀리초)
+    pub timestamp: i64,
+    /// 이벤트 내용
+    pub event: EngineEvent,
+}
+
+/// 구독자 슬롯 (버스 내부)
+struct Subscriber {
+    id: u64,
+    name: String,
+    sender: mpsc::Sender<Arc<SequencedEvent>>,
+}
+
+/// 엔진 이벤트 버스
+///
+/// # Thread Safety
+/// - `publish()`는 엔진 스레드에서 호출 (단일 발행자)
+/// - `subscribe()`는 어느 스레드에서든 호출 가능
+///
+/// # Examples
+/// ```
+/// let mut subscription = engine.event_bus().subscribe("market_data");
+/// while let Some(event) = subscription.recv().await {
+///     println!("#{} {:?}", event.seq, event.event);
+/// }
+/// ```
+pub struct EventBus {
+    /// 구독자 목록
+    subscribers: Mutex<Vec<Subscriber>>,
+    /// 구독자 수 (핫 패스에서 락 없이 확인)
+    subscriber_count: AtomicUsize,
+    /// 마지막으로 발행된 이벤트 시퀀스
+    last_seq: AtomicU64,
+    /// 다음 구독자 ID
+    next_subscriber_id: AtomicU64,
+    /// 느려서 해제된 구독자 수 (누적)
+    dropped_subscribers: AtomicU64,
+}
+
+impl EventBus {
+    /// 새 이벤트 버스 생성
+    pub fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+            subscriber_count: AtomicUsize::new(0),
+            last_seq: AtomicU64::new(0),
+            next_subscriber_id: AtomicU64::new(1),
+            dropped_subscribers: AtomicU64::new(0),
+        }
+    }
+
+    /// 구독 (기본 큐 크기)
+    ///
+    /// # Arguments
+    /// * `name` - 구독자 이름 (로그용)
+    pub fn subscribe(&self, name: &str) -> EventSubscription {
+        self.subscribe_with_capacity(name, DEFAULT_SUBSCRIBER_CAPACITY)
+    }
+
+    /// 구독 (큐 크기 지정)
+    ///
+    /// # Arguments
+    /// * `name` - 구독자 이름 (로그용)
+    /// * `capacity` - 큐 크기 (가득 차면 구독 해제)
+    pub fn subscribe_with_capacity(&self, name: &str, capacity: usize) -> EventSubscription {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        let id = self.next_subscriber_id.fetch_add(1, Ordering::Relaxed);
+
+        let mut subscribers = self.subscribers.lock();
+        subscribers.push(Subscriber {
+            id,
+            name: name.to_string(),
+            sender,
+        });
+        self.subscriber_count.store(subscribers.len(), Ordering::Release);
+
+        EventSubscription { id, receiver }
+    }
+
+    /// 구독자가 있는지 확인 (락 없음)
+    ///
+    /// 엔진 스레드는 구독자가 없으면 이벤트 생성을 건너뜁니다.
+    pub fn has_subscribers(&self) -> bool {
+        self.subscriber_count.load(Ordering::Acquire) > 0
+    }
+
+    /// 이벤트 발행
+    ///
+    /// # Arguments
+    /// * `event` - 발행할 이벤트
+    ///
+    /// # Returns
+    /// 부여된 시퀀스 (구독자가 없으면 None, 시퀀스도 증가하지 않음)
+    ///
+    /// # 느린 구독자 처리
+    /// 큐가 가득 찬 구독자는 목록에서 제거합니다 (Sender drop → 구독자는 남은 이벤트를 읽은 뒤 None 수신).
+    /// 이미 구독을 해제한(Receiver drop) 구독자도 여기서 정리됩니다.
+    pub fn publish(&self, event: EngineEvent) -> Option<u64> {
+        if !self.has_subscribers() {
+            return None;
+        }
+
+        let seq = self.last_seq.fetch_add(1, Ordering::AcqRel) + 1;
+        let event = Arc::new(SequencedEvent {
+            seq,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            event,
+        });
+
+        let mut subscribers = self.subscribers.lock();
+        subscribers.retain(|subscriber| match subscriber.sender.try_send(Arc::clone(&event)) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                eprintln!(
+                    "[Event Bus] Dropping slow subscriber '{}' (id={}) at seq {}",
+                    subscriber.name, subscriber.id, seq
+                );
+                self.dropped_subscribers.fetch_add(1, Ordering::Relaxed);
+                false
+            }
+            Err(TrySendError::Closed(_)) => false,
+        });
+        self.subscriber_count.store(subscribers.len(), Ordering::Release);
+
+        Some(seq)
+    }
+
+    /// 마지막으로 발행된 이벤트 시퀀스
+    pub fn last_seq(&self) -> u64 {
+        self.last_seq.load(Ordering::Acquire)
+    }
+
+    /// 현재 구독자 수
+    pub fn subscriber_count(&self) -> usize {
+        self.subscriber_count.load(Ordering::Acquire)
+    }
+
+    /// 느려서 해제된 구독자 수 (누적)
+    pub fn dropped_subscribers(&self) -> u64 {
+        self.dropped_subscribers.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 이벤트 구독 핸들
+///
+/// drop하면 다음 발행 시 버스에서 제거됩니다.
+/// `recv()`가 None을 반환하면 버스에서 해제된 것입니다 (느린 구독자 또는 엔진 종료).
+pub struct EventSubscription {
+    id: u64,
+    receiver: mpsc::Receiver<Arc<SequencedEvent>>,
+}
+
+impl EventSubscription {
+    /// 구독자 ID
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 다음 이벤트 수신 (async)
+    pub async fn recv(&mut self) -> Option<Arc<SequencedEvent>> {
+        self.receiver.recv().await
+    }
+
+    /// 다음 이벤트 수신 (블로킹, async 컨텍스트 밖에서 사용)
+    pub fn blocking_recv(&mut self) -> Option<Arc<SequencedEvent>> {
+        self.receiver.blocking_recv()
+    }
+
+    /// 대기 중인 이벤트 수신 (논블로킹)
+    ///
+    /// # Returns
+    /// * `Ok(Some(event))` - 이벤트 수신
+    /// * `Ok(None)` - 대기 중인 이벤트 없음
+    /// * `Err` - 버스에서 해제됨
+    pub fn try_recv(&mut self) -> anyhow::Result<Option<Arc<SequencedEvent>>> {
+        match self.receiver.try_recv() {
+            Ok(event) => Ok(Some(event)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => {
+                Err(anyhow::anyhow!("Event subscription {} was closed", self.id))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::cex::engine::types::TradingPair;
+
+    fn cancelled(order_id: u64) -> EngineEvent {
+        EngineEvent::OrderCancelled {
+            order_id,
+            user_id: 1,
+            trading_pair: TradingPair::new("SOL".to_string(), "USDT".to_string()),
+        }
+    }
+
+    #[test]
+    fn publish_without_subscribers_is_skipped() {
+        let bus = EventBus::new();
+        assert_eq!(bus.publish(cancelled(1)), None);
+        assert_eq!(bus.last_seq(), 0);
+    }
+
+    #[test]
+    fn subscribers_receive_sequenced_events() {
+        let bus = EventBus::new();
+        let mut first = bus.subscribe("first");
+        let mut second = bus.subscribe("second");
+
+        assert_eq!(bus.publish(cancelled(1)), Some(1));
+        assert_eq!(bus.publish(cancelled(2)), Some(2));
+
+        for subscription in [&mut first, &mut second] {
+            assert_eq!(subscription.try_recv().unwrap().unwrap().seq, 1);
+            assert_eq!(subscription.try_recv().unwrap().unwrap().seq, 2);
+            assert!(subscription.try_recv().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn slow_subscriber_is_dropped_without_blocking() {
+        let bus = EventBus::new();
+        let mut slow = bus.subscribe_with_capacity("slow", 1);
+        let mut fast = bus.subscribe("fast");
+
+        bus.publish(cancelled(1));
+        bus.publish(cancelled(2));
+
+        assert_eq!(bus.subscriber_count(), 1);
+        assert_eq!(bus.dropped_subscribers(), 1);
+
+        // 느린 구독자는 큐에 남은 이벤트를 읽은 뒤 해제됨
+        assert_eq!(slow.try_recv().unwrap().unwrap().seq, 1);
+        assert!(slow.try_recv().is_err());
+
+        assert_eq!(fast.try_recv().unwrap().unwrap().seq, 1);
+        assert_eq!(fast.try_recv().unwrap().unwrap().seq, 2);
+    }
+
+    #[test]
+    fn dropped_subscription_is_removed_on_publish() {
+        let bus = EventBus::new();
+        let subscription = bus.subscribe("short_lived");
+        drop(subscription);
+
+        bus.publish(cancelled(1));
+
+        assert_eq!(bus.subscriber_count(), 0);
+        assert_eq!(bus.dropped_subscribers(), 0);
+    }
+}
@@ -6,11 +6,12 @@
 // 핵심 책임:
 // 1. WAL 메시지 발행 (먼저! - 복구 가능성 보장)
 // 2. 잔고 업데이트 (메모리)
-// 3. Trade 레코드 생성 (DB 저장 준비)
-// 4. Order 상태 업데이트
+// 3. 수수료 차감 (엔진 수수료 스케줄, 받은 자산에서 차감)
+// 4. Trade 레코드 생성 (DB 저장 준비)
+// 5. Order 상태 업데이트
 //
 // 처리 흐름:
-// MatchResult → WAL 메시지 발행 → 잔고 업데이트 → Trade 생성 → DB 큐
+// MatchResult → WAL 메시지 발행 → 잔고 업데이트 → 수수료 차감 → Trade 생성 → DB 큐
 // 
 // 안전성:
 // - WAL 메시지를 먼저 발행하므로 WAL Thread가 디스크에 기록
@@ -22,14 +23,19 @@
 // - 실제 디스크 쓰기는 WAL Thread (Core 1)에서 처리
 // =====================================================
 
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use anyhow::{Result, Context as AnyhowContext};
 use chrono::Utc;
 use crossbeam::channel::Sender;
-use crate::domains::cex::engine::types::MatchResult;
+use crate::domains::cex::engine::types::{MatchResult, TradeFees};
 use crate::domains::cex::engine::balance_cache::BalanceCache;
-use crate::domains::cex::engine::wal::WalEntry;
+use crate::domains::cex::engine::fee_schedule::{FeeSchedule, Liquidity, SharedFeeSchedule};
+use crate::domains::cex::engine::wal::{WalEntry, WalMessage, WalSequence};
 use crate::domains::cex::engine::runtime::db_commands::DbCommand;
+use crate::domains::cex::models::ledger::LedgerReason;
+
+/// 수수료 소수 자릿수 (user_balances / ledger_postings DECIMAL(30, 9))
+const FEE_SCALE: u32 = 9;
 
 /// 체결 실행 결과
 /// Executor가 처리한 결과를 담는 구조체
@@ -37,6 +43,8 @@ use crate::domains::cex::engine::runtime::db_commands::DbCommand;
 pub struct ExecutionResult {
     /// 체결 정보
     pub match_result: MatchResult,
+    /// 차감한 수수료
+    pub fees: TradeFees,
     /// 체결 성공 여부
     pub success: bool,
     /// 에러 메시지 (실패 시)
@@ -48,6 +56,7 @@ pub struct ExecutionResult {
 /// 구성 요소:
 /// - balance_cache: 메모리 잔고 관리
 /// - wal_sender: WAL 메시지 발행 채널
+/// - fee_schedule: 수수료 스케줄 (엔진 스레드가 SetFeeSchedule 명령 순서대로 교체)
 /// 
 /// 메시지 발행 방식:
 /// - Executor는 WAL에 직접 쓰지 않음
@@ -63,9 +72,11 @@ pub struct Executor {
     /// 메모리 잔고 캐시
     balance_cache: BalanceCache,
     /// WAL 메시지 발행 채널 (Option으로 감싸서 테스트 시 None 가능)
-    wal_sender: Option<Sender<WalEntry>>,
+    wal_sender: Option<Sender<WalMessage>>,
     /// DB Writer 채널 (Option으로 감싸서 테스트 시 None 가능)
     db_sender: Option<Sender<DbCommand>>,
+    /// 수수료 스케줄 (HighPerformanceEngine과 공유)
+    fee_schedule: SharedFeeSchedule,
 }
 
 impl Executor {
@@ -73,31 +84,35 @@ impl Executor {
     /// 
     /// # Arguments
     /// * `wal_sender` - WAL 메시지 전송 채널
+    /// * `db_sender` - DB Writer 채널
+    /// * `fee_schedule` - 수수료 스케줄 (엔진과 공유)
     /// 
     /// # Example
     /// ```ignore
     /// let (wal_tx, wal_rx) = crossbeam::channel::bounded(10000);
-    /// let executor = Executor::new(Some(wal_tx), Some(db_tx));
+    /// let executor = Executor::new(Some(wal_tx), Some(db_tx), fee_schedule);
     /// 
     /// // WAL Thread에서 wal_rx로 메시지 수신 & 처리
     /// ```
     pub fn new(
-        wal_sender: Option<Sender<WalEntry>>,
+        wal_sender: Option<Sender<WalMessage>>,
         db_sender: Option<Sender<DbCommand>>,
+        fee_schedule: SharedFeeSchedule,
     ) -> Self {
         Self {
             balance_cache: BalanceCache::new(),
             wal_sender,
             db_sender,
+            fee_schedule,
         }
     }
     
     /// WAL 없이 생성 (테스트용)
     /// 
     /// # Note
-    /// 테스트에서는 WAL 메시지 발행을 생략
+    /// 테스트에서는 WAL 메시지 발행을 생략, 수수료 없음
     pub fn new_without_wal() -> Self {
-        Self::new(None, None)
+        Self::new(None, None, SharedFeeSchedule::default())
     }
     
     /// 채널 Sender 해제 (엔진 종료 시 호출)
@@ -112,15 +127,17 @@ impl Executor {
     /// 
     /// # Arguments
     /// * `match_result` - Matcher가 생성한 매칭 결과
+    /// * `taker_order_id` - 새로 들어와 체결시킨 주문 ID (수수료 maker/taker 구분)
     /// 
     /// # Returns
-    /// ExecutionResult - 실행 결과
+    /// ExecutionResult - 실행 결과 (차감한 수수료 포함)
     /// 
     /// # Process (순서 중요!)
     /// 1. WAL 메시지 발행 (먼저!)
     /// 2. 잔고 확인 (locked 확인)
     /// 3. 잔고 이체 (locked → available)
-    /// 4. WAL에 잔고 업데이트 메시지 발행
+    /// 4. 수수료 차감 (매수자: base_mint, 매도자: quote_mint, LedgerReason::Fee)
+    /// 5. WAL에 잔고 업데이트 메시지 발행
     /// 
     /// # 안전성
     /// - WAL 메시지를 먼저 발행하므로 WAL Thread가 디스크에 기록
@@ -130,7 +147,7 @@ impl Executor {
     /// - Channel send: ~100ns (디스크 대기 없음!)
     /// - WAL Thread가 비동기로 디스크 쓰기
     /// - Engine은 즉시 다음 작업 진행
-    pub fn execute_trade(&mut self, match_result: &MatchResult) -> Result<ExecutionResult> {
+    pub fn execute_trade(&mut self, match_result: &MatchResult, taker_order_id: u64) -> Result<ExecutionResult> {
         // ============================================
         // Step 1: WAL 메시지 발행 (가장 먼저!)
         // ============================================
@@ -150,7 +167,7 @@ impl Executor {
             };
             
             // ★ 메시지 발행 (~100ns, 빠름!)
-            sender.send(entry)
+            sender.send(WalMessage::entry(entry))
                 .context("Failed to send trade to WAL channel")?;
         }
         
@@ -179,6 +196,11 @@ impl Executor {
             true,  // locked에서 차감
         ).context("Failed to transfer base asset from seller to buyer")?;
         
+        // 2-3. 수수료: 각자 받은 자산(available)에서 차감
+        let fees = Self::trade_fees(&self.fee_schedule.read(), match_result, taker_order_id);
+        self.balance_cache.add_available(match_result.buyer_id, &match_result.base_mint, -fees.buyer_fee);
+        self.balance_cache.add_available(match_result.seller_id, &match_result.quote_mint, -fees.seller_fee);
+        
         // ============================================
         // Step 3: DB Writer 채널로 체결 내역 전송 (실시간)
         // ============================================
@@ -188,6 +210,7 @@ impl Executor {
         
         if let Some(sender) = &self.db_sender {
             let cmd = DbCommand::InsertTrade {
+                wal_seq: WalSequence::current(),
                 trade_id,
                 buy_order_id: match_result.buy_order_id,
                 sell_order_id: match_result.sell_order_id,
@@ -217,81 +240,111 @@ impl Executor {
         if let Some(db_sender) = &self.db_sender {
             // 매수자 USDT 잔고 업데이트 (locked에서 차감됨)
             let _ = db_sender.send(DbCommand::UpdateBalance {
+                wal_seq: WalSequence::current(),
                 user_id: match_result.buyer_id,
                 mint: match_result.quote_mint.clone(),
                 available_delta: None, // available은 변경 없음 (locked에서 차감)
                 locked_delta: Some(-total_value), // locked에서 차감
+                reason: LedgerReason::Trade,
+                reference: trade_id.to_string(),
             });
             
             // 매도자 USDT 잔고 업데이트 (available에 추가됨)
             let _ = db_sender.send(DbCommand::UpdateBalance {
+                wal_seq: WalSequence::current(),
                 user_id: match_result.seller_id,
                 mint: match_result.quote_mint.clone(),
                 available_delta: Some(total_value), // available에 추가
                 locked_delta: None, // locked는 변경 없음
+                reason: LedgerReason::Trade,
+                reference: trade_id.to_string(),
             });
             
             // 매도자 기준 자산 잔고 업데이트 (locked에서 차감됨)
             let _ = db_sender.send(DbCommand::UpdateBalance {
+                wal_seq: WalSequence::current(),
                 user_id: match_result.seller_id,
                 mint: match_result.base_mint.clone(),
                 available_delta: None, // available은 변경 없음 (locked에서 차감)
                 locked_delta: Some(-match_result.amount), // locked에서 차감
+                reason: LedgerReason::Trade,
+                reference: trade_id.to_string(),
             });
             
             // 매수자 기준 자산 잔고 업데이트 (available에 추가됨)
             let _ = db_sender.send(DbCommand::UpdateBalance {
+                wal_seq: WalSequence::current(),
                 user_id: match_result.buyer_id,
                 mint: match_result.base_mint.clone(),
                 available_delta: Some(match_result.amount), // available에 추가
                 locked_delta: None, // locked는 변경 없음
+                reason: LedgerReason::Trade,
+                reference: trade_id.to_string(),
             });
+            
+            // 수수료 (available에서 차감, 반대편은 fees 계정)
+            for (user_id, mint, fee) in [
+                (match_result.buyer_id, &match_result.base_mint, fees.buyer_fee),
+                (match_result.seller_id, &match_result.quote_mint, fees.seller_fee),
+            ] {
+                if fee > Decimal::ZERO {
+                    let _ = db_sender.send(DbCommand::UpdateBalance {
+                        wal_seq: WalSequence::current(),
+                        user_id,
+                        mint: mint.clone(),
+                        available_delta: Some(-fee),
+                        locked_delta: None,
+                        reason: LedgerReason::Fee,
+                        reference: trade_id.to_string(),
+                    });
+                }
+            }
         }
         
         // WAL에도 기록 (복구용)
         if let Some(sender) = &self.wal_sender {
             // 매수자 USDT 잔고
             if let Some(buyer_usdt) = self.balance_cache.get_balance(match_result.buyer_id, &match_result.quote_mint) {
-                sender.send(WalEntry::BalanceUpdated {
+                sender.send(WalMessage::entry(WalEntry::BalanceUpdated {
                     user_id: match_result.buyer_id,
                     mint: match_result.quote_mint.clone(),
                     available: buyer_usdt.available.to_string(),
                     locked: buyer_usdt.locked.to_string(),
                     timestamp: Utc::now().timestamp_millis(),
-                })?;
+                }))?;
             }
             
             // 매수자 기준 자산 잔고
             if let Some(buyer_base) = self.balance_cache.get_balance(match_result.buyer_id, &match_result.base_mint) {
-                sender.send(WalEntry::BalanceUpdated {
+                sender.send(WalMessage::entry(WalEntry::BalanceUpdated {
                     user_id: match_result.buyer_id,
                     mint: match_result.base_mint.clone(),
                     available: buyer_base.available.to_string(),
                     locked: buyer_base.locked.to_string(),
                     timestamp: Utc::now().timestamp_millis(),
-                })?;
+                }))?;
             }
             
             // 매도자 USDT 잔고
             if let Some(seller_usdt) = self.balance_cache.get_balance(match_result.seller_id, &match_result.quote_mint) {
-                sender.send(WalEntry::BalanceUpdated {
+                sender.send(WalMessage::entry(WalEntry::BalanceUpdated {
                     user_id: match_result.seller_id,
                     mint: match_result.quote_mint.clone(),
                     available: seller_usdt.available.to_string(),
                     locked: seller_usdt.locked.to_string(),
                     timestamp: Utc::now().timestamp_millis(),
-                })?;
+                }))?;
             }
             
             // 매도자 기준 자산 잔고
             if let Some(seller_base) = self.balance_cache.get_balance(match_result.seller_id, &match_result.base_mint) {
-                sender.send(WalEntry::BalanceUpdated {
+                sender.send(WalMessage::entry(WalEntry::BalanceUpdated {
                     user_id: match_result.seller_id,
                     mint: match_result.base_mint.clone(),
                     available: seller_base.available.to_string(),
                     locked: seller_base.locked.to_string(),
                     timestamp: Utc::now().timestamp_millis(),
-                })?;
+                }))?;
             }
         }
         
@@ -300,11 +353,32 @@ impl Executor {
         // ============================================
         Ok(ExecutionResult {
             match_result: match_result.clone(),
+            fees,
             success: true,
             error: None,
         })
     }
     
+    /// 체결 수수료 계산 (받은 수량 × 수수료율, 적용되는 규칙이 없으면 0)
+    ///
+    /// DB에 저장할 수 있는 소수 9자리로 올림 (메모리 잔고와 DB 잔고가 어긋나지 않도록)
+    fn trade_fees(schedule: &FeeSchedule, match_result: &MatchResult, taker_order_id: u64) -> TradeFees {
+        let now = Utc::now();
+        let rate = |user_id: u64, order_id: u64| {
+            let liquidity = if order_id == taker_order_id { Liquidity::Taker } else { Liquidity::Maker };
+            schedule
+                .rate_for_user(user_id, &match_result.base_mint, &match_result.quote_mint, liquidity, now)
+                .unwrap_or(Decimal::ZERO)
+        };
+        
+        let round = |fee: Decimal| fee.round_dp_with_strategy(FEE_SCALE, RoundingStrategy::AwayFromZero);
+        
+        TradeFees {
+            buyer_fee: round(match_result.amount * rate(match_result.buyer_id, match_result.buy_order_id)),
+            seller_fee: round(match_result.total_value() * rate(match_result.seller_id, match_result.sell_order_id)),
+        }
+    }
+    
     /// 주문 생성 시 잔고 잠금
     /// 
     /// # Arguments
@@ -328,12 +402,12 @@ impl Executor {
     ) -> Result<()> {
         // WAL 메시지 발행 (먼저!)
         if let Some(sender) = &self.wal_sender {
-            sender.send(WalEntry::BalanceLocked {
+            sender.send(WalMessage::entry(WalEntry::BalanceLocked {
                 user_id,
                 mint: mint.to_string(),
                 amount: amount.to_string(),
                 timestamp: Utc::now().timestamp_millis(),
-            })?;
+            }))?;
         }
         
         // 잔고 잠금
@@ -363,11 +437,11 @@ impl Executor {
     ) -> Result<()> {
         // WAL 메시지 발행
         if let Some(sender) = &self.wal_sender {
-            sender.send(WalEntry::OrderCancelled {
+            sender.send(WalMessage::entry(WalEntry::OrderCancelled {
                 order_id,
                 user_id,
                 timestamp: Utc::now().timestamp_millis(),
-            })?;
+            }))?;
         }
         
         // 잔고 잠금 해제
@@ -428,7 +502,7 @@ mod tests {
         };
         
         // 체결 실행
-        let result = executor.execute_trade(&match_result);
+        let result = executor.execute_trade(&match_result, 1);
         assert!(result.is_ok());
         
         // 잔고 확인
@@ -448,6 +522,102 @@ mod tests {
         assert_eq!(seller_usdt.available, Decimal::from(100)); // +100 USDT
     }
     
+    #[test]
+    fn test_executor_charges_fees_on_received_assets() {
+        use crate::domains::cex::engine::fee_schedule::{FeeRule, FeeType};
+        use parking_lot::RwLock;
+        use std::collections::HashMap;
+        use std::sync::Arc;
+        
+        // taker 0.1%, maker 0.05%
+        let rule = |id, fee_type, rate| FeeRule {
+            id,
+            base_mint: None,
+            quote_mint: None,
+            tier: None,
+            fee_type,
+            fee_rate: Decimal::new(rate, 4),
+            effective_from: Utc::now() - chrono::Duration::hours(1),
+            effective_until: None,
+        };
+        let schedule = FeeSchedule::new(vec![rule(1, FeeType::Taker, 10), rule(2, FeeType::Maker, 5)], HashMap::new());
+        let mut executor = Executor::new(None, None, Arc::new(RwLock::new(Arc::new(schedule))));
+        
+        executor.balance_cache_mut().set_balance(100, "USDT", Decimal::ZERO, Decimal::from(100));
+        executor.balance_cache_mut().set_balance(200, "SOL", Decimal::ZERO, Decimal::from(1));
+        
+        // 매수 주문(1)이 taker, 오더북의 매도 주문(2)이 maker
+        let match_result = MatchResult {
+            buy_order_id: 1,
+            sell_order_id: 2,
+            buyer_id: 100,
+            seller_id: 200,
+            price: Decimal::from(100),
+            amount: Decimal::from(1),
+            base_mint: "SOL".to_string(),
+            quote_mint: "USDT".to_string(),
+        };
+        let result = executor.execute_trade(&match_result, 1).unwrap();
+        assert_eq!(result.fees, TradeFees {
+            buyer_fee: Decimal::new(1, 3),   // 1 SOL × 0.1%
+            seller_fee: Decimal::new(5, 2),  // 100 USDT × 0.05%
+        });
+        
+        // 받은 자산에서 수수료만큼 차감
+        let buyer_sol = executor.balance_cache().get_balance(100, "SOL").unwrap();
+        assert_eq!(buyer_sol.available, Decimal::new(999, 3));
+        let seller_usdt = executor.balance_cache().get_balance(200, "USDT").unwrap();
+        assert_eq!(seller_usdt.available, Decimal::new(9995, 2));
+    }
+    
+    #[test]
+    fn test_executor_rounds_fees_to_db_scale() {
+        use crate::domains::cex::engine::fee_schedule::{FeeRule, FeeType};
+        use parking_lot::RwLock;
+        use std::collections::HashMap;
+        use std::sync::Arc;
+        
+        // taker 0.1%, maker 0.05%
+        let rule = |id, fee_type, rate| FeeRule {
+            id,
+            base_mint: None,
+            quote_mint: None,
+            tier: None,
+            fee_type,
+            fee_rate: Decimal::new(rate, 4),
+            effective_from: Utc::now() - chrono::Duration::hours(1),
+            effective_until: None,
+        };
+        let schedule = FeeSchedule::new(vec![rule(1, FeeType::Taker, 10), rule(2, FeeType::Maker, 5)], HashMap::new());
+        let mut executor = Executor::new(None, None, Arc::new(RwLock::new(Arc::new(schedule))));
+        
+        executor.balance_cache_mut().set_balance(100, "USDT", Decimal::ZERO, Decimal::from(100));
+        executor.balance_cache_mut().set_balance(200, "SOL", Decimal::ZERO, Decimal::from(1));
+        
+        // 0.123456789 SOL × 12.5 USDT = 1.5432098625 USDT
+        let match_result = MatchResult {
+            buy_order_id: 1,
+            sell_order_id: 2,
+            buyer_id: 100,
+            seller_id: 200,
+            price: Decimal::new(125, 1),
+            amount: Decimal::new(123_456_789, 9),
+            base_mint: "SOL".to_string(),
+            quote_mint: "USDT".to_string(),
+        };
+        let result = executor.execute_trade(&match_result, 1).unwrap();
+        
+        // 0.000123456789 SOL → 0.000123457, 0.00077160493125 USDT → 0.000771605 (소수 9자리 올림)
+        assert_eq!(result.fees, TradeFees {
+            buyer_fee: Decimal::new(123_457, 9),
+            seller_fee: Decimal::new(771_605, 9),
+        });
+        assert!(result.fees.buyer_fee.scale() <= 9 && result.fees.seller_fee.scale() <= 9);
+        
+        let buyer_sol = executor.balance_cache().get_balance(100, "SOL").unwrap();
+        assert_eq!(buyer_sol.available, Decimal::new(123_333_332, 9));
+    }
+    
     #[test]
     fn test_executor_lock_balance() {
         let mut executor = Executor::new_without_wal();
@@ -505,7 +675,7 @@ mod tests {
         };
         
         // 체결 실행 (실패해야 함)
-        let result = executor.execute_trade(&match_result);
+        let result = executor.execute_trade(&match_result, 1);
         assert!(result.is_err()); // 잔고 부족으로 에러
     }
     
@@ -530,7 +700,7 @@ mod tests {
             quote_mint: "USDT".to_string(),
         };
         
-        executor.execute_trade(&match1).unwrap();
+        executor.execute_trade(&match1, 1).unwrap();
         
         // 두 번째 체결: 0.5 SOL @ 100 USDT
         let match2 = MatchResult {
@@ -544,7 +714,7 @@ mod tests {
             quote_mint: "USDT".to_string(),
         };
         
-        executor.execute_trade(&match2).unwrap();
+        executor.execute_trade(&match2, 3).unwrap();
         
         // 최종 잔고 확인
         // 매수자: 1000 USDT → 850 USDT, 0 SOL → 1.5 SOL
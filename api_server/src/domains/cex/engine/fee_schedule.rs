@@ -0,0 +1,293 @@
+// =====================================================
+// FeeSchedule - 엔진 수수료 스케줄
+// =====================================================
+// 역할: 활성 수수료 설정(fee_configs)과 사용자 등급을 메모리에 올려
+//       체결마다 DB 조회 없이 수수료율 결정
+//
+// 처리 방식:
+// - FeeService가 DB에서 스케줄을 만들고 OrderCommand::SetFeeSchedule로 엔진에 전달
+// - 엔진 스레드가 명령 순서대로 교체 (앞선 주문은 이전 스케줄, 이후 주문은 새 스케줄)
+// - 읽는 쪽은 SharedFeeSchedule에서 Arc만 복사 (교체 중에도 이전 스케줄로 계산 가능)
+//
+// 규칙 선택 (가장 구체적인 것부터):
+// 1. 거래쌍: base+quote 일치 → base만 → quote만 → 전체
+// 2. 등급: 사용자 등급 일치 → 모든 등급 (tier NULL)
+// 3. 유형: maker/taker 일치 → both
+// 4. 적용 시작이 가장 늦은 설정
+// =====================================================
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use rust_decimal::Decimal;
+
+/// 엔진과 읽는 쪽이 공유하는 현재 스케줄
+pub type SharedFeeSchedule = Arc<RwLock<Arc<FeeSchedule>>>;
+
+/// 체결에서의 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Liquidity {
+    /// 오더북에 있던 주문
+    Maker,
+    /// 새로 들어와 체결시킨 주문
+    Taker,
+}
+
+/// 수수료 유형 (fee_configs.fee_type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeType {
+    Taker,
+    Maker,
+    Both,
+}
+
+impl FeeType {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeeType::Taker => "taker",
+            FeeType::Maker => "maker",
+            FeeType::Both => "both",
+        }
+    }
+
+    /// 해당 역할에 적용되는지
+    pub fn covers(&self, liquidity: Liquidity) -> bool {
+        match self {
+            FeeType::Both => true,
+            FeeType::Maker => liquidity == Liquidity::Maker,
+            FeeType::Taker => liquidity == Liquidity::Taker,
+        }
+    }
+
+    /// 두 유형이 같은 역할에 함께 적용되는지 (both는 maker, taker 모두와 겹침)
+    pub fn intersects(&self, other: FeeType) -> bool {
+        *self == FeeType::Both || other == FeeType::Both || *self == other
+    }
+}
+
+impl FromStr for FeeType {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "taker" => Ok(FeeType::Taker),
+            "maker" => Ok(FeeType::Maker),
+            "both" => Ok(FeeType::Both),
+            _ => Err(anyhow::anyhow!("Unknown fee type: {}", value)),
+        }
+    }
+}
+
+/// 수수료 규칙 (활성 fee_configs 한 행)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeRule {
+    /// fee_configs.id
+    pub id: u64,
+    /// 기준 자산 (None: 전체)
+    pub base_mint: Option<String>,
+    /// 기준 통화 (None: 전체)
+    pub quote_mint: Option<String>,
+    /// 수수료 등급 (None: 모든 등급)
+    pub tier: Option<String>,
+    pub fee_type: FeeType,
+    pub fee_rate: Decimal,
+    /// 적용 시작 (포함)
+    pub effective_from: DateTime<Utc>,
+    /// 적용 종료 (미포함, None: 기한 없음)
+    pub effective_until: Option<DateTime<Utc>>,
+}
+
+impl FeeRule {
+    /// 같은 (거래쌍, 등급) 범위인지
+    fn same_scope(&self, other: &FeeRule) -> bool {
+        self.base_mint == other.base_mint && self.quote_mint == other.quote_mint && self.tier == other.tier
+    }
+
+    /// 두 규칙이 같은 체결에 동시에 적용될 수 있는지 (같은 범위 + 유형 겹침 + 기간 겹침)
+    ///
+    /// 겹치는 규칙이 있으면 어느 쪽이 적용될지 설정만 보고 알 수 없으므로 저장 전에 거부합니다.
+    pub fn overlaps(&self, other: &FeeRule) -> bool {
+        if !self.same_scope(other) || !self.fee_type.intersects(other.fee_type) {
+            return false;
+        }
+
+        let starts_before_other_ends = other.effective_until.is_none_or(|until| self.effective_from < until);
+        let other_starts_before_end = self.effective_until.is_none_or(|until| other.effective_from < until);
+        starts_before_other_ends && other_starts_before_end
+    }
+
+    /// 해당 시점에 적용 중인지
+    pub fn is_effective(&self, at: DateTime<Utc>) -> bool {
+        self.effective_from <= at && self.effective_until.is_none_or(|until| at < until)
+    }
+
+    fn applies_to(&self, base_mint: &str, quote_mint: &str, tier: Option<&str>, liquidity: Liquidity, at: DateTime<Utc>) -> bool {
+        self.base_mint.as_deref().is_none_or(|base| base == base_mint)
+            && self.quote_mint.as_deref().is_none_or(|quote| quote == quote_mint)
+            && self.tier.as_deref().is_none_or(|rule_tier| Some(rule_tier) == tier)
+            && self.fee_type.covers(liquidity)
+            && self.is_effective(at)
+    }
+
+    /// 우선순위 (작을수록 구체적)
+    fn specificity(&self) -> (u8, u8, u8) {
+        let pair = match (&self.base_mint, &self.quote_mint) {
+            (Some(_), Some(_)) => 0,
+            (Some(_), None) => 1,
+            (None, Some(_)) => 2,
+            (None, None) => 3,
+        };
+        let tier = if self.tier.is_some() { 0 } else { 1 };
+        let fee_type = if self.fee_type == FeeType::Both { 1 } else { 0 };
+        (pair, tier, fee_type)
+    }
+}
+
+/// 수수료 스케줄 (규칙 + 사용자 등급)
+#[derive(Debug, Clone, Default)]
+pub struct FeeSchedule {
+    /// 엔진이 적용할 때 매기는 버전 (0: 아직 적용 전)
+    version: u64,
+    rules: Vec<FeeRule>,
+    /// 사용자 ID → 수수료 등급 (등급 없는 사용자는 없음)
+    user_tiers: HashMap<u64, String>,
+}
+
+impl FeeSchedule {
+    pub fn new(rules: Vec<FeeRule>, user_tiers: HashMap<u64, String>) -> Self {
+        Self { version: 0, rules, user_tiers }
+    }
+
+    /// 엔진이 적용한 버전
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// 엔진 스레드에서 적용할 때 버전 지정
+    pub(crate) fn set_version(&mut self, version: u64) {
+        self.version = version;
+    }
+
+    pub fn rules(&self) -> &[FeeRule] {
+        &self.rules
+    }
+
+    /// 등급이 지정된 사용자 수
+    pub fn tiered_users(&self) -> usize {
+        self.user_tiers.len()
+    }
+
+    /// 사용자 수수료 등급
+    pub fn tier(&self, user_id: u64) -> Option<&str> {
+        self.user_tiers.get(&user_id).map(String::as_str)
+    }
+
+    /// 수수료율 조회
+    ///
+    /// # Returns
+    /// 적용되는 규칙이 없으면 None
+    pub fn rate(&self, base_mint: &str, quote_mint: &str, tier: Option<&str>, liquidity: Liquidity, at: DateTime<Utc>) -> Option<Decimal> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.applies_to(base_mint, quote_mint, tier, liquidity, at))
+            .min_by_key(|rule| (rule.specificity(), std::cmp::Reverse(rule.effective_from)))
+            .map(|rule| rule.fee_rate)
+    }
+
+    /// 사용자 등급을 반영한 수수료율 조회
+    pub fn rate_for_user(&self, user_id: u64, base_mint: &str, quote_mint: &str, liquidity: Liquidity, at: DateTime<Utc>) -> Option<Decimal> {
+        self.rate(base_mint, quote_mint, self.tier(user_id), liquidity, at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, hour, 0, 0).unwrap()
+    }
+
+    fn rule(id: u64, pair: (Option<&str>, Option<&str>), tier: Option<&str>, fee_type: FeeType, rate: i64) -> FeeRule {
+        FeeRule {
+            id,
+            base_mint: pair.0.map(str::to_string),
+            quote_mint: pair.1.map(str::to_string),
+            tier: tier.map(str::to_string),
+            fee_type,
+            fee_rate: Decimal::new(rate, 4),
+            effective_from: at(0),
+            effective_until: None,
+        }
+    }
+
+    #[test]
+    fn test_overlap_requires_same_scope_type_and_window() {
+        let base = rule(1, (Some("SOL"), Some("USDT")), None, FeeType::Both, 10);
+
+        // 같은 범위, both는 maker와 겹침
+        assert!(base.overlaps(&rule(2, (Some("SOL"), Some("USDT")), None, FeeType::Maker, 5)));
+        // 다른 등급, 다른 거래쌍은 겹치지 않음
+        assert!(!base.overlaps(&rule(3, (Some("SOL"), Some("USDT")), Some("vip1"), FeeType::Both, 5)));
+        assert!(!base.overlaps(&rule(4, (Some("SOL"), None), None, FeeType::Both, 5)));
+        // maker / taker는 서로 겹치지 않음
+        let maker = rule(5, (None, None), None, FeeType::Maker, 5);
+        assert!(!maker.overlaps(&rule(6, (None, None), None, FeeType::Taker, 5)));
+
+        // 기간: [0, 6)과 [6, ∞)는 이어지지만 겹치지 않음
+        let mut until_six = base.clone();
+        until_six.effective_until = Some(at(6));
+        let mut from_six = rule(7, (Some("SOL"), Some("USDT")), None, FeeType::Both, 8);
+        from_six.effective_from = at(6);
+        assert!(!until_six.overlaps(&from_six));
+        assert!(!from_six.overlaps(&until_six));
+
+        from_six.effective_from = at(5);
+        assert!(until_six.overlaps(&from_six));
+    }
+
+    #[test]
+    fn test_rate_prefers_most_specific_rule() {
+        let schedule = FeeSchedule::new(
+            vec![
+                rule(1, (None, None), None, FeeType::Both, 10),
+                rule(2, (Some("SOL"), None), None, FeeType::Both, 8),
+                rule(3, (Some("SOL"), Some("USDT")), None, FeeType::Taker, 7),
+                rule(4, (None, None), Some("vip1"), FeeType::Both, 4),
+                rule(5, (Some("SOL"), Some("USDT")), Some("vip1"), FeeType::Maker, 0),
+            ],
+            HashMap::from([(42, "vip1".to_string())]),
+        );
+        let now = at(1);
+
+        assert_eq!(schedule.rate("ETH", "USDT", None, Liquidity::Taker, now), Some(Decimal::new(10, 4)));
+        assert_eq!(schedule.rate("SOL", "USDC", None, Liquidity::Maker, now), Some(Decimal::new(8, 4)));
+        assert_eq!(schedule.rate("SOL", "USDT", None, Liquidity::Taker, now), Some(Decimal::new(7, 4)));
+        // taker 전용 규칙은 maker에 적용되지 않음 → SOL 전체 규칙
+        assert_eq!(schedule.rate("SOL", "USDT", None, Liquidity::Maker, now), Some(Decimal::new(8, 4)));
+
+        // 등급 사용자: 거래쌍이 더 구체적인 규칙이 등급보다 우선
+        assert_eq!(schedule.rate_for_user(42, "ETH", "USDT", Liquidity::Taker, now), Some(Decimal::new(4, 4)));
+        assert_eq!(schedule.rate_for_user(42, "SOL", "USDT", Liquidity::Maker, now), Some(Decimal::ZERO));
+        assert_eq!(schedule.rate_for_user(42, "SOL", "USDT", Liquidity::Taker, now), Some(Decimal::new(7, 4)));
+        assert_eq!(schedule.rate_for_user(7, "ETH", "USDT", Liquidity::Taker, now), Some(Decimal::new(10, 4)));
+    }
+
+    #[test]
+    fn test_rate_respects_effective_window() {
+        let mut current = rule(1, (None, None), None, FeeType::Both, 10);
+        current.effective_until = Some(at(12));
+        let mut next = rule(2, (None, None), None, FeeType::Both, 6);
+        next.effective_from = at(12);
+        let schedule = FeeSchedule::new(vec![current, next], HashMap::new());
+
+        assert_eq!(schedule.rate("SOL", "USDT", None, Liquidity::Taker, at(11)), Some(Decimal::new(10, 4)));
+        assert_eq!(schedule.rate("SOL", "USDT", None, Liquidity::Taker, at(12)), Some(Decimal::new(6, 4)));
+        assert_eq!(schedule.rate("SOL", "USDT", None, Liquidity::Taker, at(0) - Duration::hours(1)), None);
+    }
+}
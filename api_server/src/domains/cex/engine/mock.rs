@@ -1,7 +1,8 @@
-use super::{Engine, TradingPair, OrderEntry, MatchResult};
+use super::{Engine, TradingPair, OrderEntry, MatchResult, HoldAction};
 use anyhow::{Result, bail};
 use async_trait::async_trait;
 use rust_decimal::Decimal;
+use crate::domains::cex::models::ledger::LedgerReason;
 
 /// Mock Engine (테스트용 임시 구현)
 /// Mock Engine (temporary implementation for testing)
@@ -88,12 +89,39 @@ impl Engine for MockEngine {
         _user_id: u64,
         _mint: &str,
         _available_delta: Decimal,
+        _reason: LedgerReason,
+        _reference: &str,
     ) -> Result<()> {
         // TODO: 실제 잔고 업데이트 구현 필요
         // 일단 성공으로 처리 (테스트용)
         Ok(())
     }
 
+    async fn update_hold(
+        &self,
+        _hold_id: u64,
+        _user_id: u64,
+        _mint: &str,
+        _amount: Decimal,
+        _action: HoldAction,
+        _reason: &str,
+    ) -> Result<()> {
+        // TODO: 실제 잔고 보류 구현 필요
+        Ok(())
+    }
+
+    async fn transfer_balance(
+        &self,
+        _transfer_id: u64,
+        _from_user_id: u64,
+        _to_user_id: u64,
+        _mint: &str,
+        _amount: Decimal,
+    ) -> Result<()> {
+        // TODO: 실제 내부 이체 구현 필요
+        Ok(())
+    }
+
     async fn start(&mut self) -> Result<()> {
         // TODO: 실제 엔진 시작 로직 구현 필요
         Ok(())
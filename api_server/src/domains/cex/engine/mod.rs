@@ -22,6 +22,8 @@ pub mod matcher;
 pub mod executor;
 pub mod balance_cache;
 pub mod wal;
+pub mod event_bus;
+pub mod fee_schedule;
 pub mod runtime;
 
 // TODO: 나중에 구현
@@ -31,9 +33,10 @@ use anyhow::Result;
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use chrono::Utc;
+use crate::domains::cex::models::ledger::LedgerReason;
 
 pub use types::{
-    TradingPair, OrderEntry, MatchResult, EngineEvent, OrderStatus,
+    TradingPair, OrderEntry, MatchResult, HoldAction, OutcomeUnknown,
 };
 pub use mock::MockEngine;
 
@@ -306,6 +309,8 @@ pub trait Engine: Send + Sync {
     /// * `user_id` - 사용자 ID
     /// * `mint` - 자산 종류 (예: "SOL", "USDT")
     /// * `available_delta` - available 증감량 (양수: 입금, 음수: 출금)
+    /// * `reason` - 원장 분개 사유 (예: Deposit, Adjustment)
+    /// * `reference` - 원장 참조 (예: 트랜잭션 서명)
     /// 
     /// # Returns
     /// * `Ok(())` - 업데이트 성공
@@ -324,6 +329,8 @@ pub trait Engine: Send + Sync {
     ///     123,  // user_id
     ///     "USDT",
     ///     Decimal::new(100, 0),  // +100 USDT
+    ///     LedgerReason::Deposit,
+    ///     "5VfY...",  // 트랜잭션 서명
     /// ).await?;
     /// 
     /// // 50 USDT 출금
@@ -331,6 +338,8 @@ pub trait Engine: Send + Sync {
     ///     123,  // user_id
     ///     "USDT",
     ///     Decimal::new(-50, 0),  // -50 USDT
+    ///     LedgerReason::Adjustment,
+    ///     "manual",
     /// ).await?;
     /// ```
     async fn update_balance(
@@ -338,6 +347,67 @@ pub trait Engine: Send + Sync {
         user_id: u64,
         mint: &str,
         available_delta: Decimal,
+        reason: LedgerReason,
+        reference: &str,
+    ) -> Result<()>;
+
+    /// 잔고 보류 전이 (출금)
+    /// Apply balance hold transition (withdrawal)
+    /// 
+    /// 주문 잠금(locked)과 별도로 hold_id별 보류를 관리합니다.
+    /// 모든 전이(잔고 변화가 없는 Keep 포함)는 WAL에 기록됩니다.
+    /// 
+    /// # Arguments
+    /// * `hold_id` - 보류 ID (출금 ID)
+    /// * `user_id` - 사용자 ID
+    /// * `mint` - 자산 종류
+    /// * `amount` - 보류 금액 (Place에서 사용, 나머지는 기록용)
+    /// * `action` - 보류 처리 (Place / Keep / Consume / Release)
+    /// * `reason` - WAL에 기록할 전이 이름 (예: "withdrawal:approved")
+    /// 
+    /// # Returns
+    /// * `Ok(())` - 처리 성공
+    /// * `Err` - 잔고 부족 (Place), 보류 없음 (Keep / Consume / Release)
+    /// * `Err(OutcomeUnknown)` - 응답을 받지 못함 (엔진이 나중에 처리할 수 있음)
+    /// 
+    /// # 동작
+    /// - Place: available 감소, 보류 기록
+    /// - Keep: 변화 없음
+    /// - Consume: 보류 기록 삭제 (available 그대로)
+    /// - Release: 보류 기록 삭제, available 증가
+    async fn update_hold(
+        &self,
+        hold_id: u64,
+        user_id: u64,
+        mint: &str,
+        amount: Decimal,
+        action: HoldAction,
+        reason: &str,
+    ) -> Result<()>;
+
+    /// 내부 이체 (사용자 → 사용자)
+    /// Transfer available balance between users
+    /// 
+    /// 엔진 스레드에서 차감과 증가를 한 번에 처리합니다.
+    /// 
+    /// # Arguments
+    /// * `transfer_id` - 이체 ID (원장 참조)
+    /// * `from_user_id` - 보내는 사용자 ID
+    /// * `to_user_id` - 받는 사용자 ID
+    /// * `mint` - 자산 종류
+    /// * `amount` - 이체 금액 (양수)
+    /// 
+    /// # Returns
+    /// * `Ok(())` - 이체 성공
+    /// * `Err` - available 부족, 금액 0 이하, 같은 사용자
+    /// * `Err(OutcomeUnknown)` - 응답을 받지 못함 (엔진이 나중에 이동할 수 있음)
+    async fn transfer_balance(
+        &self,
+        transfer_id: u64,
+        from_user_id: u64,
+        to_user_id: u64,
+        mint: &str,
+        amount: Decimal,
     ) -> Result<()>;
 
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -77,6 +77,14 @@ impl OrderBookSide {
         self.orders.get_mut(price)
     }
     
+    /// 특정 가격 레벨 요약 (남은 수량 합계, 주문 수) - 레벨이 없으면 (0, 0)
+    pub fn level_summary(&self, price: &Decimal) -> (Decimal, usize) {
+        self.orders
+            .get(price)
+            .map(|queue| (queue.iter().map(|o| o.remaining_amount).sum(), queue.len()))
+            .unwrap_or((Decimal::ZERO, 0))
+    }
+    
     /// 전체 주문 수
     pub fn total_orders(&self) -> usize {
         self.total_orders
@@ -188,6 +196,15 @@ impl OrderBook {
             .collect()
     }
     
+    /// 특정 방향/가격 레벨 요약 (남은 수량 합계, 주문 수)
+    pub fn level_summary(&self, order_type: &str, price: &Decimal) -> (Decimal, usize) {
+        match order_type {
+            "buy" => self.buy_orders.level_summary(price),
+            "sell" => self.sell_orders.level_summary(price),
+            _ => (Decimal::ZERO, 0),
+        }
+    }
+    
     /// 거래 쌍
     pub fn trading_pair(&self) -> &TradingPair {
         &self.trading_pair
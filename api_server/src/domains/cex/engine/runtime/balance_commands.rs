@@ -7,6 +7,8 @@
 // 사용 시나리오:
 // 1. 외부 지갑에서 우리 지갑으로 자산 입금 (온체인 트랜잭션)
 // 2. 어드민 또는 이벤트로 인한 서비스 내 잔액 업데이트
+// 3. 잔고 정합성 검사용 스냅샷 (엔진 스레드에서 일관된 시점)
+// 4. 사용자 간 내부 이체 (온체인 출금/입금 없이 available 이동)
 //
 // 특징:
 // - 주문 큐와 별도 큐로 분리 (입금 우선 처리)
@@ -16,6 +18,9 @@
 use anyhow::Result;
 use tokio::sync::oneshot;
 use rust_decimal::Decimal;
+use crate::domains::cex::engine::types::HoldAction;
+use crate::domains::cex::models::ledger::LedgerReason;
+use super::db_commands::DbWriteCursor;
 
 /// 잔고 업데이트 명령
 /// 
@@ -29,6 +34,8 @@ pub enum BalanceCommand {
     /// * `user_id` - 사용자 ID
     /// * `mint` - 자산 종류 (예: "SOL", "USDT")
     /// * `available_delta` - available 증감량 (양수: 입금, 음수: 출금)
+    /// * `reason` - 원장 분개 사유 (예: Deposit, Adjustment)
+    /// * `reference` - 원장 참조 (예: 트랜잭션 서명)
     /// * `response` - 결과를 반환할 oneshot 채널
     /// 
     /// # 처리 과정
@@ -45,6 +52,8 @@ pub enum BalanceCommand {
     ///     user_id: 123,
     ///     mint: "USDT".to_string(),
     ///     available_delta: Decimal::new(100, 0),
+    ///     reason: LedgerReason::Deposit,
+    ///     reference: signature,
     ///     response: tx,
     /// }
     /// ```
@@ -52,7 +61,91 @@ pub enum BalanceCommand {
         user_id: u64,
         mint: String,
         available_delta: Decimal,  // 양수: 입금, 음수: 출금
+        reason: LedgerReason,
+        reference: String,
         response: oneshot::Sender<Result<()>>,
     },
+    
+    /// 잔고 보류 전이 (출금)
+    /// 
+    /// # Fields
+    /// * `hold_id` - 보류 ID (출금 ID)
+    /// * `user_id` - 사용자 ID
+    /// * `mint` - 자산 종류
+    /// * `amount` - 보류 금액
+    /// * `action` - Place / Keep / Consume / Release
+    /// * `reason` - WAL에 기록할 전이 이름 (예: "withdrawal:approved")
+    /// * `response` - 결과를 반환할 oneshot 채널
+    /// 
+    /// # 처리 과정
+    /// 1. BalanceCache 보류 처리 (실패 시 에러 반환, WAL 기록 없음)
+    /// 2. WAL 메시지 발행 (BalanceHold, available이 바뀌면 BalanceUpdated도)
+    /// 3. available이 바뀌면 DB 명령 전송 (UpdateBalance)
+    UpdateHold {
+        hold_id: u64,
+        user_id: u64,
+        mint: String,
+        amount: Decimal,
+        action: HoldAction,
+        reason: String,
+        response: oneshot::Sender<Result<()>>,
+    },
+    
+    /// 내부 이체 (사용자 → 사용자)
+    /// 
+    /// # Fields
+    /// * `transfer_id` - 이체 ID (원장 참조)
+    /// * `from_user_id` - 보내는 사용자 ID
+    /// * `to_user_id` - 받는 사용자 ID
+    /// * `mint` - 자산 종류
+    /// * `amount` - 이체 금액 (양수)
+    /// * `response` - 결과를 반환할 oneshot 채널
+    /// 
+    /// # 처리 과정
+    /// 1. 보내는 사용자 available 확인 후 차감, 받는 사용자 available 증가 (실패 시 에러 반환, WAL 기록 없음)
+    /// 2. WAL 메시지 발행 (BalanceTransfer + 양쪽 BalanceUpdated)
+    /// 3. DB 명령 전송 (양쪽 UpdateBalance, 같은 WAL 시퀀스 → 원장 분개 하나)
+    Transfer {
+        transfer_id: u64,
+        from_user_id: u64,
+        to_user_id: u64,
+        mint: String,
+        amount: Decimal,
+        response: oneshot::Sender<Result<()>>,
+    },
+    
+    /// 잔고 스냅샷 (정합성 검사)
+    /// 
+    /// # Fields
+    /// * `response` - 스냅샷을 반환할 oneshot 채널
+    /// 
+    /// # 처리 과정
+    /// 1. BalanceCache 전체 복사 (엔진 스레드 안이므로 주문/체결 처리와 원자적)
+    /// 2. DB Writer에 Barrier 전송 → 스냅샷 이전 DB 명령이 모두 반영되면 ack
+    Snapshot {
+        response: oneshot::Sender<Result<BalanceSnapshot>>,
+    },
+}
+
+/// 엔진 잔고 스냅샷 항목
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceSnapshotEntry {
+    pub user_id: u64,
+    pub mint: String,
+    pub available: Decimal,
+    pub locked: Decimal,
 }
 
+/// 엔진 잔고 스냅샷
+/// 
+/// `wal_seq` 이하 시퀀스의 DB 명령만 스냅샷에 반영되어 있습니다.
+/// `db_flushed`가 ack를 받으면 그 명령들이 모두 DB에 반영(또는 dead-letter)된 상태입니다.
+#[derive(Debug)]
+pub struct BalanceSnapshot {
+    /// 스냅샷 시점의 WAL 시퀀스
+    pub wal_seq: u64,
+    /// (user_id, mint)별 잔고
+    pub balances: Vec<BalanceSnapshotEntry>,
+    /// DB Writer Barrier ack (DB Writer가 없으면 None)
+    pub db_flushed: Option<crossbeam::channel::Receiver<DbWriteCursor>>,
+}
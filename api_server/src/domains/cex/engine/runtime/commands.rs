@@ -15,6 +15,7 @@ use tokio::sync::oneshot;
 use rust_decimal::Decimal;
 
 use crate::domains::cex::engine::types::{TradingPair, OrderEntry, MatchResult};
+use crate::domains::cex::engine::fee_schedule::FeeSchedule;
 
 /// 엔진 스레드로 전달할 명령
 /// 
@@ -130,5 +131,19 @@ pub enum OrderCommand {
         amount: Decimal,
         response: oneshot::Sender<Result<()>>,
     },
+    
+    /// 수수료 스케줄 교체 (재시작 없이 수수료 설정 반영)
+    /// 
+    /// # Fields
+    /// * `schedule` - 새 스케줄 (활성 수수료 설정 + 사용자 등급)
+    /// * `response` - 적용된 스케줄 버전을 반환할 oneshot 채널
+    /// 
+    /// # 처리 과정
+    /// 1. 이전 버전 + 1로 버전 지정
+    /// 2. 공유 스케줄 교체 (먼저 들어온 주문은 이전 스케줄로 처리됨)
+    SetFeeSchedule {
+        schedule: FeeSchedule,
+        response: oneshot::Sender<Result<u64>>,
+    },
 }
 
@@ -12,7 +12,7 @@
 // prod (인스턴스, 2코어):
 //   - Engine: Core 0
 //   - WAL: Core 1
-//   - 나머지: None (OS가 알아서 배치)
+//   - 나머지: None (OS가 알아서 배치, UDP Feed 포함)
 // =====================================================
 
 /// 코어 설정 구조체
@@ -31,6 +31,8 @@ pub struct CoreConfig {
     pub wal_core: usize,
     /// DB Writer 스레드 코어 (dev만 Some)
     pub db_writer_core: Option<usize>,
+    /// UDP Feed 스레드 코어 (dev만 Some, 피드가 활성화된 경우에만 사용)
+    pub udp_feed_core: Option<usize>,
 }
 
 impl CoreConfig {
@@ -46,11 +48,11 @@ impl CoreConfig {
     /// ```
     /// // dev 환경
     /// RUST_ENV=dev
-    /// // → engine_core: 0, wal_core: 1, db_writer_core: Some(2)
+    /// // → engine_core: 0, wal_core: 1, db_writer_core: Some(2), udp_feed_core: Some(3)
     /// 
     /// // prod 환경
     /// RUST_ENV=prod
-    /// // → engine_core: 0, wal_core: 1, db_writer_core: None
+    /// // → engine_core: 0, wal_core: 1, db_writer_core: None, udp_feed_core: None
     /// ```
     pub fn from_env() -> Self {
         let env = std::env::var("RUST_ENV").unwrap_or_else(|_| "dev".to_string());
@@ -62,6 +64,7 @@ impl CoreConfig {
                     engine_core: 0,
                     wal_core: 1,
                     db_writer_core: Some(2),
+                    udp_feed_core: Some(3),
                 }
             }
             "prod" => {
@@ -70,6 +73,7 @@ impl CoreConfig {
                     engine_core: 0,
                     wal_core: 1,
                     db_writer_core: None,  // 코어 고정 안 함
+                    udp_feed_core: None,
                 }
             }
             _ => {
@@ -78,6 +82,7 @@ impl CoreConfig {
                     engine_core: 0,
                     wal_core: 1,
                     db_writer_core: None,
+                    udp_feed_core: None,
                 }
             }
         }
@@ -0,0 +1,812 @@
+// =====================================================
+// DB Batch - DB Writer 배치 병합 및 벌크 쓰기
+// =====================================================
+// 역할: DB Writer 배치를 테이블별로 병합한 뒤 UNNEST 기반 multi-row 쿼리로 저장
+//
+// 병합 규칙:
+// - InsertOrder: 주문 ID별 1행 (첫 번째 명령 유지)
+// - UpdateOrderStatus: 주문 ID별 마지막 상태만 반영
+// - InsertTrade: 체결 ID별 1행
+// - UpdateBalance: (user_id, mint)별 증감량 합산
+//   + 원장 전기는 합산하지 않고 명령마다 기록 (분개: wal_seq + 사유 + 참조)
+//
+// 특징:
+// - 테이블당 쿼리 1개 → 배치 크기와 무관하게 왕복 횟수 고정
+// - 배치당 트랜잭션 1개 (high-water mark 저장 포함)
+// =====================================================
+
+use std::collections::{HashMap, HashSet};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::shared::database::repositories::cex::DbWriterStateRepository;
+use crate::domains::cex::models::ledger::{LedgerAccount, LedgerReason};
+use super::db_commands::{DbCommand, PendingDbCommand};
+
+/// 주문 INSERT 행
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OrderInsertRow {
+    pub wal_seq: u64,
+    pub order_id: u64,
+    pub user_id: u64,
+    pub order_type: String,
+    pub order_side: String,
+    pub base_mint: String,
+    pub quote_mint: String,
+    pub price: Option<Decimal>,
+    pub amount: Decimal,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 주문 상태 UPDATE 행 (주문별 마지막 상태)
+///
+/// `filled_quote_amount`가 None이면 기존 값을 유지합니다 (취소 명령만 반영된 경우).
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OrderStatusRow {
+    pub wal_seq: u64,
+    pub order_id: u64,
+    pub status: String,
+    pub filled_amount: Decimal,
+    pub filled_quote_amount: Option<Decimal>,
+}
+
+/// 체결 INSERT 행
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TradeInsertRow {
+    pub trade_id: u64,
+    pub buy_order_id: u64,
+    pub sell_order_id: u64,
+    pub buyer_id: u64,
+    pub seller_id: u64,
+    pub price: Decimal,
+    pub amount: Decimal,
+    pub base_mint: String,
+    pub quote_mint: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 잔고 증감 행 ((user_id, mint)별 합산)
+///
+/// `upsert`가 true면 레코드가 없을 때 생성 (available 증감이 하나라도 있는 경우),
+/// false면 locked만 변경하므로 기존 레코드가 있어야 합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BalanceDeltaRow {
+    pub user_id: u64,
+    pub mint: String,
+    pub available_delta: Decimal,
+    pub locked_delta: Decimal,
+    pub upsert: bool,
+}
+
+/// 원장 전기 행
+///
+/// `user_id`가 None이면 시스템 계정 (사유별 반대편 계정).
+/// 같은 (wal_seq, reason, reference)의 행은 한 분개로 묶이며 자산별 합계가 0입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LedgerPostingRow {
+    pub wal_seq: u64,
+    pub reason: LedgerReason,
+    pub reference: String,
+    pub user_id: Option<u64>,
+    pub mint: String,
+    pub account: LedgerAccount,
+    pub amount: Decimal,
+}
+
+/// 원장 전기 합산 키 (분개 + 계정)
+type LedgerPostingKey = (u64, LedgerReason, String, Option<u64>, String, LedgerAccount);
+
+impl LedgerPostingRow {
+    /// 잔고 증감 명령 하나의 전기 (차변 +, 대변 -)
+    ///
+    /// # 처리
+    /// 1. available / locked 증감 → 사용자 계정 전기
+    /// 2. 합계가 0이 아니면 사유별 시스템 계정으로 반대편 전기 (분개 합계 0 유지)
+    ///
+    /// 예: 입금 100 → available +100 / deposits -100
+    ///     잠금 50 → available -50 / locked +50 (반대편 없음)
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn for_balance_update(
+        wal_seq: u64,
+        user_id: u64,
+        mint: &str,
+        available_delta: Option<Decimal>,
+        locked_delta: Option<Decimal>,
+        reason: LedgerReason,
+        reference: &str,
+    ) -> Vec<Self> {
+        let posting = |user_id: Option<u64>, account: LedgerAccount, amount: Decimal| Self {
+            wal_seq,
+            reason,
+            reference: reference.to_string(),
+            user_id,
+            mint: mint.to_string(),
+            account,
+            amount,
+        };
+
+        let mut rows = Vec::with_capacity(3);
+        let mut total = Decimal::ZERO;
+        for (account, delta) in [
+            (LedgerAccount::Available, available_delta),
+            (LedgerAccount::Locked, locked_delta),
+        ] {
+            if let Some(delta) = delta.filter(|delta| !delta.is_zero()) {
+                rows.push(posting(Some(user_id), account, delta));
+                total += delta;
+            }
+        }
+        if !total.is_zero() {
+            rows.push(posting(None, reason.contra_account(), -total));
+        }
+        rows
+    }
+
+    /// 같은 분개 + 계정의 전기 합산 (합계 0인 행 제거)
+    ///
+    /// 한 배치에 체결의 매수/매도 쪽이 모두 있으면 clearing 전기가 상쇄되어 사라집니다.
+    fn merge(rows: Vec<Self>) -> Vec<Self> {
+        let mut merged: Vec<Self> = Vec::with_capacity(rows.len());
+        let mut index: HashMap<LedgerPostingKey, usize> = HashMap::new();
+
+        for row in rows {
+            let key = (
+                row.wal_seq,
+                row.reason,
+                row.reference.clone(),
+                row.user_id,
+                row.mint.clone(),
+                row.account,
+            );
+            match index.get(&key) {
+                Some(&idx) => merged[idx].amount += row.amount,
+                None => {
+                    index.insert(key, merged.len());
+                    merged.push(row);
+                }
+            }
+        }
+
+        merged.retain(|row| !row.amount.is_zero());
+        merged
+    }
+}
+
+/// 병합된 DB Writer 배치
+///
+/// # 처리 순서 (외래키 제약조건)
+/// 1. 주문 INSERT
+/// 2. 주문 상태 UPDATE
+/// 3. 체결 INSERT (주문이 있어야 함)
+/// 4. 잔고 UPDATE
+/// 5. 원장 분개/전기 INSERT
+#[derive(Debug, Default)]
+pub struct DbBatchPlan {
+    pub(crate) orders: Vec<OrderInsertRow>,
+    pub(crate) order_statuses: Vec<OrderStatusRow>,
+    pub(crate) trades: Vec<TradeInsertRow>,
+    pub(crate) balances: Vec<BalanceDeltaRow>,
+    pub(crate) ledger: Vec<LedgerPostingRow>,
+}
+
+impl DbBatchPlan {
+    /// 배치를 테이블별로 병합
+    ///
+    /// # Arguments
+    /// * `batch` - 위치(cursor) 순서로 정렬된 명령들
+    ///
+    /// # Errors
+    /// 주문 ID가 0인 InsertOrder가 있으면 에러 (ID 생성기 미초기화)
+    pub fn from_batch(batch: &[PendingDbCommand]) -> Result<Self> {
+        let mut plan = Self::default();
+        let mut order_ids: HashSet<u64> = HashSet::new();
+        let mut status_index: HashMap<u64, usize> = HashMap::new();
+        let mut trade_ids: HashSet<u64> = HashSet::new();
+        let mut balance_index: HashMap<(u64, &str), usize> = HashMap::new();
+
+        for pending in batch {
+            match &pending.command {
+                DbCommand::InsertOrder {
+                    wal_seq,
+                    order_id,
+                    user_id,
+                    order_type,
+                    order_side,
+                    base_mint,
+                    quote_mint,
+                    price,
+                    amount,
+                    created_at,
+                } => {
+                    // ID 생성기로 생성한 ID를 사용 (auto increment 사용 안 함)
+                    if *order_id == 0 {
+                        return Err(anyhow::anyhow!(
+                            "Order ID is 0. ID generator may not be initialized properly."
+                        ));
+                    }
+                    if !order_ids.insert(*order_id) {
+                        continue;
+                    }
+                    plan.orders.push(OrderInsertRow {
+                        wal_seq: *wal_seq,
+                        order_id: *order_id,
+                        user_id: *user_id,
+                        order_type: order_type.clone(),
+                        order_side: order_side.clone(),
+                        base_mint: base_mint.clone(),
+                        quote_mint: quote_mint.clone(),
+                        price: *price,
+                        amount: *amount,
+                        created_at: *created_at,
+                    });
+                }
+
+                DbCommand::UpdateOrderStatus {
+                    wal_seq,
+                    order_id,
+                    status,
+                    filled_amount,
+                    filled_quote_amount,
+                } => {
+                    // 취소 시에는 filled_quote_amount를 변경하지 않음 (앞선 상태의 값 유지)
+                    let quote = if status == "cancelled" {
+                        None
+                    } else {
+                        Some(*filled_quote_amount)
+                    };
+
+                    match status_index.get(order_id) {
+                        Some(&idx) => {
+                            let row = &mut plan.order_statuses[idx];
+                            row.wal_seq = *wal_seq;
+                            row.status = status.clone();
+                            row.filled_amount = *filled_amount;
+                            row.filled_quote_amount = quote.or(row.filled_quote_amount);
+                        }
+                        None => {
+                            status_index.insert(*order_id, plan.order_statuses.len());
+                            plan.order_statuses.push(OrderStatusRow {
+                                wal_seq: *wal_seq,
+                                order_id: *order_id,
+                                status: status.clone(),
+                                filled_amount: *filled_amount,
+                                filled_quote_amount: quote,
+                            });
+                        }
+                    }
+                }
+
+                DbCommand::InsertTrade {
+                    trade_id,
+                    buy_order_id,
+                    sell_order_id,
+                    buyer_id,
+                    seller_id,
+                    price,
+                    amount,
+                    base_mint,
+                    quote_mint,
+                    timestamp,
+                    ..
+                } => {
+                    // buy_order_id, sell_order_id가 0이면 스킵 (주문이 아직 DB에 INSERT되지 않음)
+                    if *buy_order_id == 0 || *sell_order_id == 0 {
+                        eprintln!(
+                            "[DB Writer] Skipping trade insert: buy_order_id={}, sell_order_id={} (orders not yet inserted)",
+                            buy_order_id, sell_order_id
+                        );
+                        continue;
+                    }
+                    if !trade_ids.insert(*trade_id) {
+                        continue;
+                    }
+                    plan.trades.push(TradeInsertRow {
+                        trade_id: *trade_id,
+                        buy_order_id: *buy_order_id,
+                        sell_order_id: *sell_order_id,
+                        buyer_id: *buyer_id,
+                        seller_id: *seller_id,
+                        price: *price,
+                        amount: *amount,
+                        base_mint: base_mint.clone(),
+                        quote_mint: quote_mint.clone(),
+                        timestamp: *timestamp,
+                    });
+                }
+
+                DbCommand::UpdateBalance {
+                    wal_seq,
+                    user_id,
+                    mint,
+                    available_delta,
+                    locked_delta,
+                    reason,
+                    reference,
+                } => {
+                    // 변경 없음
+                    if available_delta.is_none() && locked_delta.is_none() {
+                        continue;
+                    }
+
+                    plan.ledger.extend(LedgerPostingRow::for_balance_update(
+                        *wal_seq,
+                        *user_id,
+                        mint,
+                        *available_delta,
+                        *locked_delta,
+                        *reason,
+                        reference,
+                    ));
+
+                    let idx = *balance_index
+                        .entry((*user_id, mint.as_str()))
+                        .or_insert_with(|| {
+                            plan.balances.push(BalanceDeltaRow {
+                                user_id: *user_id,
+                                mint: mint.clone(),
+                                available_delta: Decimal::ZERO,
+                                locked_delta: Decimal::ZERO,
+                                upsert: false,
+                            });
+                            plan.balances.len() - 1
+                        });
+
+                    let row = &mut plan.balances[idx];
+                    if let Some(delta) = available_delta {
+                        row.available_delta += *delta;
+                        row.upsert = true;
+                    }
+                    if let Some(delta) = locked_delta {
+                        row.locked_delta += *delta;
+                    }
+                }
+
+                // DbBatchWriter::push에서 처리 (배치에 들어가지 않음)
+                DbCommand::Barrier(_) => {}
+            }
+        }
+
+        plan.ledger = LedgerPostingRow::merge(plan.ledger);
+        Ok(plan)
+    }
+
+    /// 병합 후 쓰게 될 행 수
+    pub fn row_count(&self) -> usize {
+        self.orders.len() + self.order_statuses.len() + self.trades.len() + self.balances.len() + self.ledger.len()
+    }
+}
+
+/// 배치를 DB에 쓰기 (async)
+///
+/// # Arguments
+/// * `batch` - DB 명령 배치 (위치 순서)
+/// * `db_pool` - 데이터베이스 연결 풀
+///
+/// # 처리 과정
+/// 1. 배치 병합 (`DbBatchPlan::from_batch`)
+/// 2. 트랜잭션 시작
+/// 3. 테이블별 UNNEST 벌크 쿼리 (주문 → 주문 상태 → 체결 → 잔고 → 원장)
+/// 4. high-water mark 저장 (배치의 마지막 명령 위치)
+/// 5. 커밋
+///
+/// # Note
+/// 실패 시 배치는 그대로 남아 있으므로 호출자가 재시도할 수 있습니다.
+pub async fn flush_batch(
+    batch: &[PendingDbCommand],
+    db_pool: &PgPool,
+) -> Result<()> {
+    let Some(last) = batch.last() else {
+        return Ok(());
+    };
+
+    let plan = DbBatchPlan::from_batch(batch)?;
+
+    // 트랜잭션 시작
+    let mut tx = db_pool.begin().await
+        .context("Failed to begin transaction")?;
+
+    insert_orders(&mut tx, &plan.orders).await?;
+    update_order_statuses(&mut tx, &plan.order_statuses).await?;
+    insert_trades(&mut tx, &plan.trades).await?;
+    update_balances(&mut tx, &plan.balances).await?;
+    insert_ledger(&mut tx, &plan.ledger).await?;
+
+    // high-water mark 저장 (배치 반영과 원자적)
+    DbWriterStateRepository::save_high_water_mark(&mut tx, last.cursor).await?;
+
+    // 트랜잭션 커밋
+    tx.commit().await
+        .context("Failed to commit transaction")?;
+
+    Ok(())
+}
+
+/// 주문 벌크 INSERT (`ON CONFLICT (id)` upsert)
+async fn insert_orders(
+    tx: &mut Transaction<'_, Postgres>,
+    rows: &[OrderInsertRow],
+) -> Result<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO orders (
+            id, user_id, order_type, order_side, base_mint, quote_mint,
+            price, amount, filled_amount, filled_quote_amount, status, created_at, updated_at,
+            last_wal_seq
+        )
+        SELECT id, user_id, order_type, order_side, base_mint, quote_mint,
+               price, amount, 0, 0, 'pending', created_at, created_at,
+               last_wal_seq
+        FROM UNNEST(
+            $1::BIGINT[], $2::BIGINT[], $3::TEXT[], $4::TEXT[], $5::TEXT[], $6::TEXT[],
+            $7::NUMERIC[], $8::NUMERIC[], $9::TIMESTAMPTZ[], $10::BIGINT[]
+        ) AS t(id, user_id, order_type, order_side, base_mint, quote_mint,
+               price, amount, created_at, last_wal_seq)
+        ON CONFLICT (id) DO UPDATE SET
+            updated_at = EXCLUDED.updated_at
+        "#
+    )
+    .bind(rows.iter().map(|r| r.order_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.user_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.order_type.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.order_side.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.base_mint.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.quote_mint.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.price).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.amount).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.created_at).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.wal_seq as i64).collect::<Vec<_>>())
+    .execute(&mut **tx)
+    .await
+    .with_context(|| format!("Failed to bulk insert {} orders", rows.len()))?;
+
+    Ok(())
+}
+
+/// 주문 상태 벌크 UPDATE
+///
+/// `last_wal_seq`보다 오래된 상태는 무시합니다 (재생 시 최신 상태 보호).
+async fn update_order_statuses(
+    tx: &mut Transaction<'_, Postgres>,
+    rows: &[OrderStatusRow],
+) -> Result<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE orders AS o
+        SET status = u.status,
+            filled_amount = u.filled_amount,
+            filled_quote_amount = COALESCE(u.filled_quote_amount, o.filled_quote_amount),
+            updated_at = $6,
+            last_wal_seq = u.wal_seq
+        FROM UNNEST(
+            $1::BIGINT[], $2::TEXT[], $3::NUMERIC[], $4::NUMERIC[], $5::BIGINT[]
+        ) AS u(id, status, filled_amount, filled_quote_amount, wal_seq)
+        WHERE o.id = u.id AND o.last_wal_seq <= u.wal_seq
+        "#
+    )
+    .bind(rows.iter().map(|r| r.order_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.status.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.filled_amount).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.filled_quote_amount).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.wal_seq as i64).collect::<Vec<_>>())
+    .bind(Utc::now())
+    .execute(&mut **tx)
+    .await
+    .with_context(|| format!("Failed to bulk update {} order statuses", rows.len()))?;
+
+    Ok(())
+}
+
+/// 체결 벌크 INSERT (`ON CONFLICT (id) DO NOTHING`)
+async fn insert_trades(
+    tx: &mut Transaction<'_, Postgres>,
+    rows: &[TradeInsertRow],
+) -> Result<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO trades (
+            id, buy_order_id, sell_order_id, buyer_id, seller_id,
+            price, amount, base_mint, quote_mint, created_at
+        )
+        SELECT * FROM UNNEST(
+            $1::BIGINT[], $2::BIGINT[], $3::BIGINT[], $4::BIGINT[], $5::BIGINT[],
+            $6::NUMERIC[], $7::NUMERIC[], $8::TEXT[], $9::TEXT[], $10::TIMESTAMPTZ[]
+        )
+        ON CONFLICT (id) DO NOTHING
+        "#
+    )
+    .bind(rows.iter().map(|r| r.trade_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.buy_order_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.sell_order_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.buyer_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.seller_id as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.price).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.amount).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.base_mint.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.quote_mint.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.timestamp).collect::<Vec<_>>())
+    .execute(&mut **tx)
+    .await
+    .with_context(|| format!("Failed to bulk insert {} trades", rows.len()))?;
+
+    Ok(())
+}
+
+/// 잔고 벌크 UPDATE
+///
+/// # 처리
+/// - upsert 행: 레코드가 없으면 생성, 있으면 증감
+/// - locked 전용 행: 기존 레코드만 증감 (없으면 에러)
+async fn update_balances(
+    tx: &mut Transaction<'_, Postgres>,
+    rows: &[BalanceDeltaRow],
+) -> Result<()> {
+    let (upserts, locked_only): (Vec<&BalanceDeltaRow>, Vec<&BalanceDeltaRow>) =
+        rows.iter().partition(|r| r.upsert);
+    let now = Utc::now();
+
+    if !upserts.is_empty() {
+        sqlx::query(
+            r#"
+            INSERT INTO user_balances (user_id, mint_address, available, locked, created_at, updated_at)
+            SELECT user_id, mint_address, available, locked, $5, $5
+            FROM UNNEST($1::BIGINT[], $2::TEXT[], $3::NUMERIC[], $4::NUMERIC[])
+                AS t(user_id, mint_address, available, locked)
+            ON CONFLICT (user_id, mint_address)
+            DO UPDATE SET
+                available = user_balances.available + EXCLUDED.available,
+                locked = user_balances.locked + EXCLUDED.locked,
+                updated_at = EXCLUDED.updated_at
+            "#
+        )
+        .bind(upserts.iter().map(|r| r.user_id as i64).collect::<Vec<_>>())
+        .bind(upserts.iter().map(|r| r.mint.clone()).collect::<Vec<_>>())
+        .bind(upserts.iter().map(|r| r.available_delta).collect::<Vec<_>>())
+        .bind(upserts.iter().map(|r| r.locked_delta).collect::<Vec<_>>())
+        .bind(now)
+        .execute(&mut **tx)
+        .await
+        .with_context(|| format!("Failed to bulk upsert {} balances", upserts.len()))?;
+    }
+
+    if !locked_only.is_empty() {
+        let result = sqlx::query(
+            r#"
+            UPDATE user_balances AS b
+            SET locked = b.locked + u.locked_delta, updated_at = $4
+            FROM UNNEST($1::BIGINT[], $2::TEXT[], $3::NUMERIC[])
+                AS u(user_id, mint_address, locked_delta)
+            WHERE b.user_id = u.user_id AND b.mint_address = u.mint_address
+            "#
+        )
+        .bind(locked_only.iter().map(|r| r.user_id as i64).collect::<Vec<_>>())
+        .bind(locked_only.iter().map(|r| r.mint.clone()).collect::<Vec<_>>())
+        .bind(locked_only.iter().map(|r| r.locked_delta).collect::<Vec<_>>())
+        .bind(now)
+        .execute(&mut **tx)
+        .await
+        .with_context(|| format!("Failed to bulk update {} locked balances", locked_only.len()))?;
+
+        if result.rows_affected() != locked_only.len() as u64 {
+            return Err(anyhow::anyhow!(
+                "Balance not found: {} of {} locked balance updates matched no row",
+                locked_only.len() as u64 - result.rows_affected(),
+                locked_only.len()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// 원장 분개/전기 벌크 INSERT
+///
+/// # 처리
+/// 1. 분개: (wal_seq, reason, reference)별 1행 (`ON CONFLICT DO NOTHING`,
+///    배치 경계로 나뉜 같은 분개는 기존 분개에 전기만 추가)
+/// 2. 전기: 분개 ID를 조인해 INSERT
+pub(crate) async fn insert_ledger(
+    tx: &mut Transaction<'_, Postgres>,
+    rows: &[LedgerPostingRow],
+) -> Result<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO ledger_entries (wal_seq, reason, reference, created_at)
+        SELECT DISTINCT wal_seq, reason, reference, $4
+        FROM UNNEST($1::BIGINT[], $2::TEXT[], $3::TEXT[]) AS t(wal_seq, reason, reference)
+        ON CONFLICT (wal_seq, reason, reference) DO NOTHING
+        "#
+    )
+    .bind(rows.iter().map(|r| r.wal_seq as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.reason.as_str()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.reference.clone()).collect::<Vec<_>>())
+    .bind(now)
+    .execute(&mut **tx)
+    .await
+    .with_context(|| format!("Failed to insert ledger entries for {} postings", rows.len()))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO ledger_postings (entry_id, user_id, mint, account, amount, created_at)
+        SELECT e.id, p.user_id, p.mint, p.account, p.amount, $8
+        FROM UNNEST(
+            $1::BIGINT[], $2::TEXT[], $3::TEXT[], $4::BIGINT[], $5::TEXT[], $6::TEXT[], $7::NUMERIC[]
+        ) AS p(wal_seq, reason, reference, user_id, mint, account, amount)
+        JOIN ledger_entries e
+          ON e.wal_seq = p.wal_seq AND e.reason = p.reason AND e.reference = p.reference
+        "#
+    )
+    .bind(rows.iter().map(|r| r.wal_seq as i64).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.reason.as_str()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.reference.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.user_id.map(|id| id as i64)).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.mint.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.account.as_str()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.amount).collect::<Vec<_>>())
+    .bind(now)
+    .execute(&mut **tx)
+    .await
+    .with_context(|| format!("Failed to insert {} ledger postings", rows.len()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::db_commands::DbWriteCursor;
+
+    fn pending(wal_seq: u64, index: u32, command: DbCommand) -> PendingDbCommand {
+        PendingDbCommand {
+            cursor: DbWriteCursor { wal_seq, index },
+            command,
+        }
+    }
+
+    fn balance(wal_seq: u64, user_id: u64, available: Option<i64>, locked: Option<i64>) -> DbCommand {
+        DbCommand::UpdateBalance {
+            wal_seq,
+            user_id,
+            mint: "USDT".to_string(),
+            available_delta: available.map(|v| Decimal::new(v, 0)),
+            locked_delta: locked.map(|v| Decimal::new(v, 0)),
+            reason: LedgerReason::Lock,
+            reference: wal_seq.to_string(),
+        }
+    }
+
+    fn status(wal_seq: u64, status: &str, filled: i64, quote: i64) -> DbCommand {
+        DbCommand::UpdateOrderStatus {
+            wal_seq,
+            order_id: 7,
+            status: status.to_string(),
+            filled_amount: Decimal::new(filled, 0),
+            filled_quote_amount: Decimal::new(quote, 0),
+        }
+    }
+
+    #[test]
+    fn balance_deltas_are_summed_per_user_and_mint() {
+        let batch = vec![
+            pending(1, 0, balance(1, 1, None, Some(100))),
+            pending(2, 0, balance(2, 1, None, Some(-40))),
+            pending(2, 1, balance(2, 2, Some(40), None)),
+            pending(3, 0, balance(3, 2, Some(-10), Some(10))),
+        ];
+
+        let plan = DbBatchPlan::from_batch(&batch).unwrap();
+
+        assert_eq!(plan.balances, vec![
+            BalanceDeltaRow {
+                user_id: 1,
+                mint: "USDT".to_string(),
+                available_delta: Decimal::ZERO,
+                locked_delta: Decimal::new(60, 0),
+                upsert: false,
+            },
+            BalanceDeltaRow {
+                user_id: 2,
+                mint: "USDT".to_string(),
+                available_delta: Decimal::new(30, 0),
+                locked_delta: Decimal::new(10, 0),
+                upsert: true,
+            },
+        ]);
+    }
+
+    #[test]
+    fn order_status_updates_fold_into_last_and_keep_quote_on_cancel() {
+        let batch = vec![
+            pending(1, 0, status(1, "partial", 1, 100)),
+            pending(2, 0, status(2, "partial", 2, 200)),
+            pending(3, 0, status(3, "cancelled", 2, 0)),
+        ];
+
+        let plan = DbBatchPlan::from_batch(&batch).unwrap();
+
+        assert_eq!(plan.order_statuses, vec![OrderStatusRow {
+            wal_seq: 3,
+            order_id: 7,
+            status: "cancelled".to_string(),
+            filled_amount: Decimal::new(2, 0),
+            filled_quote_amount: Some(Decimal::new(200, 0)),
+        }]);
+        assert_eq!(plan.row_count(), 1);
+    }
+
+    #[test]
+    fn ledger_postings_balance_per_entry_and_cancel_clearing() {
+        let trade = |user_id: u64, available: Option<i64>, locked: Option<i64>| DbCommand::UpdateBalance {
+            wal_seq: 5,
+            user_id,
+            mint: "USDT".to_string(),
+            available_delta: available.map(|v| Decimal::new(v, 0)),
+            locked_delta: locked.map(|v| Decimal::new(v, 0)),
+            reason: LedgerReason::Trade,
+            reference: "77".to_string(),
+        };
+        let deposit = DbCommand::UpdateBalance {
+            wal_seq: 6,
+            user_id: 3,
+            mint: "USDT".to_string(),
+            available_delta: Some(Decimal::new(100, 0)),
+            locked_delta: None,
+            reason: LedgerReason::Deposit,
+            reference: "sig".to_string(),
+        };
+        let batch = vec![
+            pending(4, 0, balance(4, 1, Some(-50), Some(50))),
+            pending(5, 0, trade(1, None, Some(-50))),
+            pending(5, 1, trade(2, Some(50), None)),
+            pending(6, 0, deposit),
+        ];
+
+        let plan = DbBatchPlan::from_batch(&batch).unwrap();
+
+        // 잠금: available/locked 이동만, 체결: clearing 상쇄, 입금: deposits 반대편
+        let postings: Vec<(u64, Option<u64>, LedgerAccount, Decimal)> = plan
+            .ledger
+            .iter()
+            .map(|row| (row.wal_seq, row.user_id, row.account, row.amount))
+            .collect();
+        assert_eq!(postings, vec![
+            (4, Some(1), LedgerAccount::Available, Decimal::new(-50, 0)),
+            (4, Some(1), LedgerAccount::Locked, Decimal::new(50, 0)),
+            (5, Some(1), LedgerAccount::Locked, Decimal::new(-50, 0)),
+            (5, Some(2), LedgerAccount::Available, Decimal::new(50, 0)),
+            (6, Some(3), LedgerAccount::Available, Decimal::new(100, 0)),
+            (6, None, LedgerAccount::Deposits, Decimal::new(-100, 0)),
+        ]);
+        for wal_seq in 4..=6 {
+            let total: Decimal = plan.ledger.iter().filter(|row| row.wal_seq == wal_seq).map(|row| row.amount).sum();
+            assert!(total.is_zero());
+        }
+
+        // 체결 한쪽만 있으면 clearing으로 균형 유지
+        let half = LedgerPostingRow::for_balance_update(
+            5, 1, "USDT", None, Some(Decimal::new(-50, 0)), LedgerReason::Trade, "77",
+        );
+        assert_eq!(half.len(), 2);
+        assert_eq!(half[1].account, LedgerAccount::Clearing);
+        assert_eq!(half[1].user_id, None);
+        assert_eq!(half[1].amount, Decimal::new(50, 0));
+    }
+}
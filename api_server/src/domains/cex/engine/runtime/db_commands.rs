@@ -6,24 +6,33 @@
 // 사용 목적:
 // - 주문 생성/업데이트
 // - 체결 내역 저장
-// - 잔고 업데이트
+// - 잔고 업데이트 (+ 원장 분개, 사유/참조)
+// - Barrier (앞서 보낸 명령이 모두 반영되었는지 확인, 잔고 정합성 검사용)
 //
 // 특징:
 // - Lock-free 채널로 전송 (~100ns)
 // - Non-blocking (메인 엔진 성능 영향 없음)
+// - 모든 명령은 파생된 WAL 시퀀스(`wal_seq`)를 가짐 (멱등 쓰기/high-water mark 기준)
 // =====================================================
 
 use rust_decimal::Decimal;
 use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domains::cex::models::ledger::LedgerReason;
 
 /// DB Writer 스레드로 전달할 명령
 /// 
 /// 각 명령은 DB Writer 스레드에서 배치로 처리됩니다.
-#[derive(Debug, Clone)]
+/// 
+/// # wal_seq
+/// 명령이 파생된 WAL 레코드의 시퀀스 (`WalSequence::current()`).
+/// 하나의 WAL 레코드(예: TradeExecuted)에서 여러 명령이 파생될 수 있습니다.
+#[derive(Debug, Clone, Serialize)]
 pub enum DbCommand {
     /// 주문 생성
     /// 
     /// # Fields
+    /// * `wal_seq` - 파생된 WAL 시퀀스
     /// * `order_id` - 주문 ID
     /// * `user_id` - 사용자 ID
     /// * `order_type` - 주문 타입 ("buy" or "sell")
@@ -34,6 +43,7 @@ pub enum DbCommand {
     /// * `amount` - 주문 수량
     /// * `created_at` - 생성 시간
     InsertOrder {
+        wal_seq: u64,
         order_id: u64,
         user_id: u64,
         order_type: String,
@@ -48,11 +58,13 @@ pub enum DbCommand {
     /// 주문 상태 업데이트
     /// 
     /// # Fields
+    /// * `wal_seq` - 파생된 WAL 시퀀스
     /// * `order_id` - 주문 ID
     /// * `status` - 새 상태 ("pending", "partial", "filled", "cancelled")
     /// * `filled_amount` - 체결된 수량
     /// * `filled_quote_amount` - 체결된 금액 (USDT 기준)
     UpdateOrderStatus {
+        wal_seq: u64,
         order_id: u64,
         status: String,
         filled_amount: Decimal,
@@ -62,6 +74,7 @@ pub enum DbCommand {
     /// 체결 내역 저장
     /// 
     /// # Fields
+    /// * `wal_seq` - 파생된 WAL 시퀀스
     /// * `trade_id` - 체결 ID (ID 생성기로 생성)
     /// * `buy_order_id` - 매수 주문 ID
     /// * `sell_order_id` - 매도 주문 ID
@@ -73,6 +86,7 @@ pub enum DbCommand {
     /// * `quote_mint` - 기준 통화
     /// * `timestamp` - 체결 시간
     InsertTrade {
+        wal_seq: u64,
         trade_id: u64,
         buy_order_id: u64,
         sell_order_id: u64,
@@ -88,15 +102,104 @@ pub enum DbCommand {
     /// 잔고 업데이트
     /// 
     /// # Fields
+    /// * `wal_seq` - 파생된 WAL 시퀀스
     /// * `user_id` - 사용자 ID
     /// * `mint` - 자산 종류
     /// * `available_delta` - available 증감량 (None이면 변경 없음)
     /// * `locked_delta` - locked 증감량 (None이면 변경 없음)
+    /// * `reason` - 원장 분개 사유
+    /// * `reference` - 원장 참조 (체결 ID, 주문 ID, 출금 ID, 트랜잭션 서명 등)
+    /// 
+    /// # 원장
+    /// DB Writer가 같은 트랜잭션에서 증감량을 원장 전기로 기록합니다.
+    /// (사용자 계정 증감 + 합계가 0이 아니면 사유별 시스템 계정으로 반대편 전기)
     UpdateBalance {
+        wal_seq: u64,
         user_id: u64,
         mint: String,
         available_delta: Option<Decimal>,
         locked_delta: Option<Decimal>,
+        reason: LedgerReason,
+        reference: String,
     },
+    
+    /// 반영 확인 (DB 쓰기 없음)
+    /// 
+    /// 채널은 FIFO이므로 Barrier 이전에 보낸 명령은 모두 DB Writer가 이미 받았습니다.
+    /// DB Writer는 현재 배치를 쓴 뒤 high-water mark로 ack를 보냅니다.
+    /// (dead-letter로 빠진 명령도 처리 완료로 간주)
+    /// 
+    /// 배치에 들어가지 않으므로 (dead-letter 대상 아님) 직렬화하지 않습니다.
+    #[serde(skip)]
+    Barrier(crossbeam::channel::Sender<DbWriteCursor>),
+}
+
+impl DbCommand {
+    /// 명령이 파생된 WAL 시퀀스
+    pub fn wal_seq(&self) -> u64 {
+        match self {
+            DbCommand::InsertOrder { wal_seq, .. }
+            | DbCommand::UpdateOrderStatus { wal_seq, .. }
+            | DbCommand::InsertTrade { wal_seq, .. }
+            | DbCommand::UpdateBalance { wal_seq, .. } => *wal_seq,
+            // 배치에 들어가지 않음 (DbBatchWriter::push에서 처리)
+            DbCommand::Barrier(_) => 0,
+        }
+    }
+    
+    /// 명령 종류 (로그/dead-letter 기록용)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DbCommand::InsertOrder { .. } => "InsertOrder",
+            DbCommand::UpdateOrderStatus { .. } => "UpdateOrderStatus",
+            DbCommand::InsertTrade { .. } => "InsertTrade",
+            DbCommand::UpdateBalance { .. } => "UpdateBalance",
+            DbCommand::Barrier(_) => "Barrier",
+        }
+    }
+}
+
+/// DB Writer 진행 위치 (high-water mark)
+/// 
+/// 같은 WAL 시퀀스에서 파생된 명령이 여러 개일 수 있으므로
+/// `(wal_seq, index)` 쌍으로 명령 하나를 식별합니다.
+/// 
+/// # 순서
+/// `wal_seq` → `index` 순으로 비교 (derive된 Ord)
+/// 
+/// # 사용 목적
+/// - DB에 반영된 마지막 위치를 `db_writer_state`에 저장
+/// - 재시작 시 WAL 시퀀스를 이 위치보다 큰 값에서 시작 (`WalSequence::initialize`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DbWriteCursor {
+    /// WAL 시퀀스
+    pub wal_seq: u64,
+    /// 같은 WAL 시퀀스 안에서의 명령 순번 (0부터)
+    pub index: u32,
+}
+
+impl DbWriteCursor {
+    /// 다음 명령의 위치 계산
+    /// 
+    /// # Arguments
+    /// * `wal_seq` - 다음 명령의 WAL 시퀀스
+    /// 
+    /// # Returns
+    /// 같은 시퀀스면 index + 1, 새 시퀀스면 index = 0
+    pub fn next_for(&self, wal_seq: u64) -> Self {
+        if wal_seq == self.wal_seq {
+            Self { wal_seq, index: self.index + 1 }
+        } else {
+            Self { wal_seq, index: 0 }
+        }
+    }
 }
 
+/// DB Writer 배치에 쌓인 명령 (위치 포함)
+#[derive(Debug, Clone)]
+pub struct PendingDbCommand {
+    /// 명령 위치
+    pub cursor: DbWriteCursor,
+    /// DB 명령
+    pub command: DbCommand,
+}
@@ -7,6 +7,7 @@
 // 1. 싱글 스레드 엔진 (Core 0 고정) - 모든 주문 순차 처리
 // 2. WAL 스레드 (Core 1 고정) - 디스크 쓰기 전용
 // 3. DB Writer 스레드 (Core 2, 로컬만) - 배치 DB 쓰기
+// 3-1. UDP Feed 스레드 (Core 3, 로컬만, 선택) - 바이너리 시세 멀티캐스트
 // 4. Lock-free 채널 - 스레드 간 통신 (crossbeam::channel)
 // 5. 환경별 코어 설정 - dev(11코어), prod(2코어)
 //
@@ -29,17 +30,21 @@ use rust_decimal::Decimal;
 use async_trait::async_trait;
 
 use crate::shared::database::Database;
-use crate::domains::cex::engine::types::{TradingPair, OrderEntry, MatchResult};
+use crate::domains::cex::engine::types::{TradingPair, OrderEntry, MatchResult, EngineEvent, HoldAction, OutcomeUnknown};
 use crate::domains::cex::engine::orderbook::OrderBook;
 use crate::domains::cex::engine::matcher::Matcher;
 use crate::domains::cex::engine::executor::Executor;
-use crate::domains::cex::engine::wal::WalEntry;
+use crate::domains::cex::engine::event_bus::EventBus;
+use crate::domains::cex::engine::fee_schedule::{FeeSchedule, SharedFeeSchedule};
+use crate::domains::cex::engine::wal::{WalMessage, WalSequence, WalDurability, WalMetrics, WalMetricsSnapshot};
 use crate::domains::cex::engine::Engine;
+use crate::domains::cex::models::ledger::LedgerReason;
 
 use super::commands::OrderCommand;
 use super::balance_commands::BalanceCommand;
 use super::config::CoreConfig;
 use super::db_commands::DbCommand;
+use super::udp_feed::{UdpFeedConfig, FeedState};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EngineMode {
@@ -61,6 +66,33 @@ impl EngineMode {
     }
 }
 
+/// 오더북의 모든 가격 레벨을 `BookLevelChanged` 이벤트로 변환
+///
+/// # Arguments
+/// * `orderbooks` - 거래쌍별 오더북 (읽기 락을 잡은 상태)
+///
+/// # Returns
+/// 거래쌍/방향/가격별 레벨 이벤트 (발행되지 않은 스냅샷)
+pub(crate) fn collect_book_levels(orderbooks: &HashMap<TradingPair, OrderBook>) -> Vec<EngineEvent> {
+    let mut levels = Vec::new();
+
+    for (pair, orderbook) in orderbooks.iter() {
+        for (side, book_side) in [("buy", &orderbook.buy_orders), ("sell", &orderbook.sell_orders)] {
+            for (price, queue) in book_side.iter() {
+                levels.push(EngineEvent::BookLevelChanged {
+                    trading_pair: pair.clone(),
+                    side: side.to_string(),
+                    price: *price,
+                    total_amount: queue.iter().map(|o| o.remaining_amount).sum(),
+                    order_count: queue.len(),
+                });
+            }
+        }
+    }
+
+    levels
+}
+
 /// 고성능 체결 엔진
 /// 
 /// 싱글 스레드 엔진 + 멀티 스레드 워커 구조로
@@ -80,7 +112,7 @@ impl EngineMode {
 /// [crossbeam::channel] (Lock-free)
 ///     ↓ wal_rx.recv()
 /// WAL Thread (Core 1)
-///     └─ WalWriter::append() → fsync()
+///     └─ WalWriter::write() → fsync() (WalDurability 정책에 따라 group commit)
 /// ```
 /// 
 /// # 성능
@@ -143,7 +175,7 @@ pub struct HighPerformanceEngine {
     /// - TradeExecuted
     /// - BalanceLocked
     /// - BalanceUpdated
-    wal_tx: Option<Sender<WalEntry>>,
+    wal_tx: Option<Sender<WalMessage>>,
     
     /// WAL 메시지 수신 채널 (Receiver)
     /// 
@@ -151,7 +183,7 @@ pub struct HighPerformanceEngine {
     /// 
     /// # 사용 위치
     /// - `wal_thread_loop()`에서 `wal_rx.recv()` 호출
-    wal_rx: Receiver<WalEntry>,
+    wal_rx: Receiver<WalMessage>,
     
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     // 핵심 컴포넌트 (엔진 스레드에서만 접근)
@@ -222,6 +254,13 @@ pub struct HighPerformanceEngine {
     /// - `stop()`에서 종료 대기
     db_writer_thread: Option<thread::JoinHandle<()>>,
     
+    /// UDP 피드 스레드 핸들 (피드 스레드 + TCP 복구 스레드)
+    /// 
+    /// # 생명주기
+    /// - `start()`에서 생성 (`UDP_FEED_ENABLED=true`인 경우만)
+    /// - `stop()`에서 종료 대기 (running 플래그로 종료)
+    udp_feed_threads: Vec<thread::JoinHandle<()>>,
+    
     /// DB Writer 채널 (Sender)
     /// 
     /// 엔진 스레드에서 DB Writer 스레드로 명령을 전송할 때 사용
@@ -280,6 +319,36 @@ pub struct HighPerformanceEngine {
     /// - `WAL_DIR`: WAL 디렉토리 경로 지정 가능
     wal_dir: std::path::PathBuf,
 
+    /// WAL fsync 정책
+    /// 
+    /// # 환경 변수
+    /// - `WAL_DURABILITY`: per_command / group_commit (기본값) / async
+    /// - `WAL_GROUP_COMMIT_US`, `WAL_GROUP_COMMIT_ENTRIES`: group commit 윈도우
+    wal_durability: WalDurability,
+
+    /// WAL 지표 (fsync 지연, 배치 크기)
+    /// 
+    /// WAL 스레드가 기록하고 API/모니터링에서 스냅샷으로 조회
+    wal_metrics: Arc<WalMetrics>,
+
+    /// 엔진 이벤트 버스
+    /// 
+    /// 엔진 스레드가 주문/체결/잔고/호가 변경 이벤트를 발행하고
+    /// 시세, 알림 등 다른 컴포넌트가 구독합니다.
+    event_bus: Arc<EventBus>,
+
+    /// 현재 수수료 스케줄
+    /// 
+    /// 엔진 스레드가 `SetFeeSchedule` 명령으로 교체하고
+    /// Executor가 체결 정산 시 읽어서 수수료를 차감합니다.
+    fee_schedule: SharedFeeSchedule,
+
+    /// UDP 시세 피드 설정 (None이면 비활성)
+    /// 
+    /// # 환경 변수
+    /// - `UDP_FEED_ENABLED`, `UDP_FEED_GROUP`, `UDP_FEED_TARGETS` 등 (`UdpFeedConfig` 참고)
+    udp_feed: Option<UdpFeedConfig>,
+
     /// 실행 모드 (표준/벤치)
     mode: EngineMode,
 }
@@ -348,7 +417,10 @@ impl HighPerformanceEngine {
         // Matcher: 상태 없음 (stateless), Arc로 공유
         let matcher = Arc::new(Matcher::new());
         
-        // Executor: BalanceCache 포함, WAL Sender + DB Sender 전달
+        // 수수료 스케줄: 엔진 스레드가 교체, Executor가 체결 정산 시 적용
+        let fee_schedule: SharedFeeSchedule = Arc::new(RwLock::new(Arc::new(FeeSchedule::default())));
+        
+        // Executor: BalanceCache 포함, WAL Sender + DB Sender + 수수료 스케줄 전달
         let executor = Arc::new(Mutex::new(Executor::new(
             match mode {
                 EngineMode::Standard => Some(wal_tx_inner.clone()),
@@ -358,6 +430,7 @@ impl HighPerformanceEngine {
                 EngineMode::Standard => Some(db_tx_inner.clone()),
                 EngineMode::Bench => None,
             },
+            Arc::clone(&fee_schedule),
         )));
         
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -393,9 +466,18 @@ impl HighPerformanceEngine {
             engine_thread: None,
             wal_thread: None,
             db_writer_thread: None,
+            udp_feed_threads: Vec::new(),
             running: Arc::new(AtomicBool::new(false)),
             db: db.into(),
             wal_dir,
+            wal_durability: WalDurability::from_env(),
+            wal_metrics: Arc::new(WalMetrics::new()),
+            event_bus: Arc::new(EventBus::new()),
+            fee_schedule,
+            udp_feed: match mode {
+                EngineMode::Standard => UdpFeedConfig::from_env(),
+                EngineMode::Bench => None,
+            },
             mode,
         }
     }
@@ -415,7 +497,7 @@ impl HighPerformanceEngine {
     /// 엔진 시작 (내부 구현)
     /// `&mut self`를 사용하여 필드를 직접 수정합니다.
     pub async fn start_impl(&mut self) -> Result<()> {
-        use crate::shared::database::repositories::cex::{OrderRepository, UserBalanceRepository};
+        use crate::shared::database::repositories::cex::{OrderRepository, UserBalanceRepository, DbWriterStateRepository, WithdrawalRepository};
         use crate::domains::cex::engine::order_to_entry;
         use anyhow::Context;
         
@@ -512,6 +594,24 @@ impl HighPerformanceEngine {
                 }
                 eprintln!("[Engine Start] Completed processing all {} active orders", active_orders_count);
             }
+
+            // 진행 중인 출금 보류 복원 (DB available에는 이미 보류 금액이 빠져 있음)
+            let active_withdrawals = WithdrawalRepository::new(db.pool().clone())
+                .get_held()
+                .await
+                .context("Failed to load active withdrawals from database")?;
+            {
+                let mut executor = self.executor.lock();
+                for withdrawal in &active_withdrawals {
+                    executor.balance_cache_mut().restore_hold(
+                        withdrawal.id,
+                        withdrawal.user_id,
+                        &withdrawal.mint,
+                        withdrawal.amount,
+                    );
+                }
+            }
+            eprintln!("[Engine Start] Restored {} withdrawal holds", active_withdrawals.len());
         } else {
             let mut executor = self.executor.lock();
             executor.balance_cache_mut().clear();
@@ -530,11 +630,28 @@ impl HighPerformanceEngine {
                 .expect("DB must exist in Standard mode")
                 .pool()
                 .clone();
+
+            // high-water mark 로드
+            // WAL에서 미반영 명령을 다시 만들지는 않음 (high-water mark 이후 WAL 레코드는 수동 확인)
+            let high_water = DbWriterStateRepository::new(db_pool.clone())
+                .get_high_water_mark()
+                .await
+                .context("Failed to load DB writer high-water mark")?;
+            eprintln!(
+                "[Engine Start] DB writer high-water mark: wal_seq={}, index={}",
+                high_water.wal_seq, high_water.index
+            );
+
+            // WAL 시퀀스는 high-water mark보다 큰 값에서 시작
+            WalSequence::initialize(high_water.wal_seq);
+
+            let dead_letter_path = self.wal_dir.join("db_dead_letters.jsonl");
             let db_writer_thread = thread::spawn(move || {
-                super::threads::db_writer_thread_loop(db_rx, db_pool);
+                super::threads::db_writer_thread_loop(db_rx, db_pool, high_water, dead_letter_path);
             });
             self.db_writer_thread = Some(db_writer_thread);
         } else {
+            WalSequence::initialize(0);
             self.db_writer_thread = None;
         }
         
@@ -545,11 +662,13 @@ impl HighPerformanceEngine {
         if self.mode.use_wal() {
             let wal_rx = self.wal_rx.clone();
             let wal_dir = self.wal_dir.clone();
+            let durability = self.wal_durability;
+            let metrics = Arc::clone(&self.wal_metrics);
             let wal_thread = thread::spawn(move || {
-                super::threads::wal_thread_loop(wal_rx, wal_dir);
+                super::threads::wal_thread_loop(wal_rx, wal_dir, durability, metrics);
             });
             self.wal_thread = Some(wal_thread);
-            eprintln!("[Engine Start] WAL thread started");
+            eprintln!("[Engine Start] WAL thread started (durability: {:?})", self.wal_durability);
         } else {
             self.wal_thread = None;
             eprintln!("[Engine Start] WAL thread disabled");
@@ -574,6 +693,8 @@ impl HighPerformanceEngine {
         let matcher = Arc::clone(&self.matcher);
         let executor = Arc::clone(&self.executor);
         let running = Arc::clone(&self.running);
+        let events = Arc::clone(&self.event_bus);
+        let fee_schedule = Arc::clone(&self.fee_schedule);
         
         let db_for_thread = self.db.clone();
         let engine_thread = thread::spawn(move || {
@@ -587,10 +708,35 @@ impl HighPerformanceEngine {
                 executor,
                 running,
                 db_for_thread,
+                events,
+                fee_schedule,
             );
         });
         self.engine_thread = Some(engine_thread);
         eprintln!("[Engine Start] Engine thread started");
+        
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // 7. UDP 시세 피드 시작 (설정된 경우)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        
+        if let Some(config) = self.udp_feed.clone() {
+            let state = Arc::new(Mutex::new(FeedState::new(config.retransmit_capacity)));
+            
+            let tcp_addr = config.tcp_addr;
+            let recovery_state = Arc::clone(&state);
+            let recovery_running = Arc::clone(&self.running);
+            self.udp_feed_threads.push(thread::spawn(move || {
+                super::udp_feed::udp_feed_recovery_loop(tcp_addr, recovery_state, recovery_running);
+            }));
+            
+            let events = Arc::clone(&self.event_bus);
+            let orderbooks = Arc::clone(&self.orderbooks);
+            let running = Arc::clone(&self.running);
+            self.udp_feed_threads.push(thread::spawn(move || {
+                super::udp_feed::udp_feed_thread_loop(config, state, events, orderbooks, running);
+            }));
+            eprintln!("[Engine Start] UDP feed threads started");
+        }
         eprintln!("[Engine Start] Engine initialization completed successfully");
         
         Ok(())
@@ -656,6 +802,14 @@ impl HighPerformanceEngine {
             eprintln!("[Engine Stop] DB Writer thread joined");
         }
         
+        if !self.udp_feed_threads.is_empty() {
+            eprintln!("[Engine Stop] Waiting for UDP feed threads...");
+            for handle in self.udp_feed_threads.drain(..) {
+                handle.join().map_err(|e| anyhow::anyhow!("UDP feed thread panicked: {:?}", e))?;
+            }
+            eprintln!("[Engine Stop] UDP feed threads joined");
+        }
+        
         eprintln!("[Engine Stop] Shutdown complete");
         Ok(())
     }
@@ -688,6 +842,141 @@ impl HighPerformanceEngine {
         }
     }
 
+    /// WAL fsync 정책 조회
+    pub fn wal_durability(&self) -> WalDurability {
+        self.wal_durability
+    }
+
+    /// WAL 지표 스냅샷 조회
+    /// 
+    /// # Returns
+    /// fsync 횟수/지연, group commit 배치 크기 등
+    pub fn wal_metrics(&self) -> WalMetricsSnapshot {
+        self.wal_metrics.snapshot()
+    }
+
+    /// 잔고 스냅샷 조회 (정합성 검사)
+    /// 
+    /// 엔진 스레드에서 BalanceCache를 복사하고 DB Writer에 Barrier를 보냅니다.
+    /// 
+    /// # Returns
+    /// 스냅샷 (WAL 시퀀스 + 잔고 + DB 반영 ack 수신기)
+    pub async fn balance_snapshot(&self) -> Result<super::balance_commands::BalanceSnapshot> {
+        let (tx, rx) = oneshot::channel();
+        
+        let balance_tx = self.balance_tx.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Engine is not running"))?;
+        balance_tx.send(BalanceCommand::Snapshot { response: tx })
+            .map_err(|e| anyhow::anyhow!("Failed to send snapshot command: {}", e))?;
+        
+        timeout(Duration::from_secs(5), rx)
+            .await
+            .map_err(|_| anyhow::anyhow!("Balance snapshot timeout"))?
+            .map_err(|e| anyhow::anyhow!("Failed to receive response: {}", e))?
+    }
+
+    /// 엔진 이벤트 버스 조회
+    /// 
+    /// # Returns
+    /// 구독용 `EventBus` (엔진 재시작과 무관하게 같은 버스)
+    /// 
+    /// # Examples
+    /// ```
+    /// let mut subscription = engine.event_bus().subscribe("notifications");
+    /// ```
+    pub fn event_bus(&self) -> Arc<EventBus> {
+        Arc::clone(&self.event_bus)
+    }
+
+    /// 수수료 스케줄 교체 (재시작 없이 반영)
+    /// 
+    /// 주문 큐를 통해 엔진 스레드로 전달되므로 이미 큐에 있는 주문이 먼저 처리됩니다.
+    /// 
+    /// # Returns
+    /// 적용된 스케줄 버전
+    pub async fn set_fee_schedule(&self, schedule: FeeSchedule) -> Result<u64> {
+        let (tx, rx) = oneshot::channel();
+        
+        let order_tx = self.order_tx.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Engine is not running"))?;
+        order_tx.send(OrderCommand::SetFeeSchedule { schedule, response: tx })
+            .map_err(|e| anyhow::anyhow!("Failed to send fee schedule command: {}", e))?;
+        
+        timeout(Duration::from_secs(5), rx)
+            .await
+            .map_err(|_| anyhow::anyhow!("Set fee schedule timeout"))?
+            .map_err(|e| anyhow::anyhow!("Failed to receive response: {}", e))?
+    }
+
+    /// 현재 오더북의 모든 가격 레벨을 `BookLevelChanged` 이벤트로 조회
+    ///
+    /// 이벤트 버스 구독자가 초기 호가 상태를 만들 때 사용합니다.
+    /// 구독을 먼저 만든 뒤 호출하면, 이후 수신하는 레벨 이벤트(절대값)로 상태가 수렴합니다.
+    ///
+    /// # Returns
+    /// 거래쌍/방향/가격별 레벨 이벤트 (발행되지 않은 스냅샷)
+    pub fn book_level_snapshot(&self) -> Vec<EngineEvent> {
+        collect_book_levels(&self.orderbooks.read())
+    }
+
+    /// 오더북에 남아있는 모든 주문 조회 (사용자 스트림 초기화용)
+    ///
+    /// # Returns
+    /// 지정가 주문 복사본 (체결 수량/남은 수량 포함)
+    pub fn resting_orders(&self) -> Vec<OrderEntry> {
+        let orderbooks = self.orderbooks.read();
+        orderbooks
+            .values()
+            .flat_map(|orderbook| orderbook.buy_orders.iter().chain(orderbook.sell_orders.iter()))
+            .flat_map(|(_, queue)| queue.iter().cloned())
+            .collect()
+    }
+
+    /// 주문 제출 후 WAL 내구성 확보까지 대기
+    /// 
+    /// `submit_order()`와 달리 주문이 처리되고 해당 WAL 엔트리가
+    /// `WalDurability` 정책에 따라 디스크에 반영된 뒤에 체결 결과를 반환합니다.
+    /// 
+    /// 엔진 락을 잡은 채로 fsync를 기다리지 않도록 명령 전송만 하고
+    /// 대기용 future를 반환합니다 (group commit이 여러 주문을 한 번에 묶을 수 있게).
+    /// 
+    /// # Arguments
+    /// * `order` - 제출할 주문
+    /// 
+    /// # Returns
+    /// 대기용 future
+    /// * `Ok(Vec<MatchResult>)` - 체결 결과 (WAL 반영 완료)
+    /// * `Err` - 주문 처리 실패, WAL 쓰기 실패 또는 타임아웃
+    /// 
+    /// # Examples
+    /// ```
+    /// let durable = engine.lock().await.submit_order_durable(order)?;
+    /// let matches = durable.await?;
+    /// ```
+    pub fn submit_order_durable(
+        &self,
+        order: OrderEntry,
+    ) -> Result<impl std::future::Future<Output = Result<Vec<MatchResult>>> + Send + use<>> {
+        let (tx, rx) = oneshot::channel();
+
+        let cmd = OrderCommand::SubmitOrder {
+            order,
+            response: Some(tx),
+        };
+
+        self.order_tx.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Engine is not running"))?
+            .send(cmd)
+            .map_err(|e| anyhow::anyhow!("Failed to send order to engine: {}", e))?;
+
+        Ok(async move {
+            timeout(Duration::from_secs(5), rx)
+                .await
+                .map_err(|_| anyhow::anyhow!("Submit order timeout"))?
+                .map_err(|e| anyhow::anyhow!("Failed to receive response: {}", e))?
+        })
+    }
+
     /// 벤치모드에서 직접 주문 처리 (큐/oneshot 우회)
     #[cfg(any(test, feature = "bench_mode"))]
     pub fn bench_submit_direct(&self, order: OrderEntry) -> Result<Vec<MatchResult>> {
@@ -702,6 +991,7 @@ impl HighPerformanceEngine {
             &self.orderbooks,
             &self.matcher,
             &self.executor,
+            &self.event_bus,
         )
     }
 }
@@ -885,6 +1175,8 @@ impl Engine for HighPerformanceEngine {
         user_id: u64,
         mint: &str,
         available_delta: Decimal,
+        reason: LedgerReason,
+        reference: &str,
     ) -> Result<()> {
         let (tx, rx) = oneshot::channel();
         
@@ -892,6 +1184,8 @@ impl Engine for HighPerformanceEngine {
             user_id,
             mint: mint.to_string(),
             available_delta,
+            reason,
+            reference: reference.to_string(),
             response: tx,
         };
         
@@ -904,6 +1198,68 @@ impl Engine for HighPerformanceEngine {
             .map_err(|e| anyhow::anyhow!("Failed to receive response: {}", e))?
     }
     
+    /// 잔고 보류 전이 (출금)
+    async fn update_hold(
+        &self,
+        hold_id: u64,
+        user_id: u64,
+        mint: &str,
+        amount: Decimal,
+        action: HoldAction,
+        reason: &str,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        
+        let cmd = BalanceCommand::UpdateHold {
+            hold_id,
+            user_id,
+            mint: mint.to_string(),
+            amount,
+            action,
+            reason: reason.to_string(),
+            response: tx,
+        };
+        
+        self.balance_tx.as_ref().unwrap().send(cmd)
+            .map_err(|e| anyhow::anyhow!("Failed to send update_hold command: {}", e))?;
+        
+        // 명령이 이미 큐에 있으므로 시간 초과 / 응답 채널 끊김은 결과를 알 수 없음 (엔진이 나중에 보류할 수 있음)
+        timeout(Duration::from_millis(100), rx)
+            .await
+            .map_err(|_| OutcomeUnknown("Update hold timeout".to_string()))?
+            .map_err(|e| OutcomeUnknown(format!("Failed to receive response: {}", e)))?
+    }
+    
+    /// 내부 이체 (trait 구현)
+    async fn transfer_balance(
+        &self,
+        transfer_id: u64,
+        from_user_id: u64,
+        to_user_id: u64,
+        mint: &str,
+        amount: Decimal,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        
+        let cmd = BalanceCommand::Transfer {
+            transfer_id,
+            from_user_id,
+            to_user_id,
+            mint: mint.to_string(),
+            amount,
+            response: tx,
+        };
+        
+        self.balance_tx.as_ref().unwrap().send(cmd)
+            .map_err(|e| anyhow::anyhow!("Failed to send transfer command: {}", e))?;
+        
+        // 명령이 이미 큐에 있으므로 시간 초과 / 응답 채널 끊김은 결과를 알 수 없음 (엔진이 나중에 이동할 수 있음)
+        timeout(Duration::from_millis(100), rx)
+            .await
+            .map_err(|_| OutcomeUnknown("Transfer timeout".to_string()))?
+            .map_err(|e| OutcomeUnknown(format!("Failed to receive response: {}", e)))?
+    }
+    
     /// 엔진 시작 (trait 구현)
     async fn start(&mut self) -> Result<()> {
         self.start_impl().await
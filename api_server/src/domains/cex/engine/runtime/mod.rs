@@ -8,6 +8,8 @@
 // - commands.rs: OrderCommand enum
 // - config.rs: CoreConfig (환경별 코어 설정)
 // - threads.rs: 스레드 루프 함수들
+// - db_batch.rs: DB Writer 배치 병합 및 벌크 쓰기
+// - udp_feed.rs: 바이너리 시세 UDP 피드 + TCP 복구 서비스
 // =====================================================
 
 pub mod config;
@@ -16,10 +18,12 @@ pub mod balance_commands;
 pub mod engine;
 pub mod threads;
 pub mod db_commands;
+pub mod db_batch;
+pub mod udp_feed;
 
 pub use engine::HighPerformanceEngine;
 pub use config::CoreConfig;
 pub use commands::OrderCommand;
-pub use balance_commands::BalanceCommand;
+pub use balance_commands::{BalanceCommand, BalanceSnapshot, BalanceSnapshotEntry};
 pub use db_commands::DbCommand;
 pub use threads::{engine_thread_loop, wal_thread_loop, db_writer_thread_loop};
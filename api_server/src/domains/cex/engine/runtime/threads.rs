@@ -16,16 +16,22 @@ use parking_lot::{RwLock, Mutex};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use crate::shared::database::Database;
+use crate::shared::database::repositories::cex::DbWriterStateRepository;
 
-use crate::domains::cex::engine::types::{TradingPair, OrderEntry, MatchResult};
+use crate::domains::cex::engine::types::{TradingPair, OrderEntry, MatchResult, EngineEvent, HoldAction, TradeFees};
+use crate::domains::cex::models::ledger::LedgerReason;
 use crate::domains::cex::engine::orderbook::OrderBook;
 use crate::domains::cex::engine::matcher::Matcher;
 use crate::domains::cex::engine::executor::Executor;
-use crate::domains::cex::engine::wal::{WalEntry, WalWriter};
+use crate::domains::cex::engine::event_bus::EventBus;
+use crate::domains::cex::engine::fee_schedule::{FeeSchedule, SharedFeeSchedule};
+use crate::domains::cex::engine::wal::{WalEntry, WalMessage, WalSequence, WalWriter, WalDurability, WalMetrics, WalAck};
 
 use super::commands::OrderCommand;
 use super::balance_commands::BalanceCommand;
 use super::config::CoreConfig;
+use super::db_commands::{DbWriteCursor, PendingDbCommand};
+use super::db_batch::flush_batch;
 
 // =====================================================
 // 엔진 스레드 루프
@@ -92,13 +98,15 @@ use super::config::CoreConfig;
 pub fn engine_thread_loop(
     order_rx: Receiver<OrderCommand>,
     balance_rx: Receiver<BalanceCommand>,
-    wal_tx: Option<crossbeam::channel::Sender<WalEntry>>,
+    wal_tx: Option<crossbeam::channel::Sender<WalMessage>>,
     db_tx: Option<crossbeam::channel::Sender<super::db_commands::DbCommand>>,
     orderbooks: Arc<RwLock<HashMap<TradingPair, OrderBook>>>,
     matcher: Arc<Matcher>,
     executor: Arc<Mutex<Executor>>,
     running: Arc<std::sync::atomic::AtomicBool>,
     db: Option<Database>,
+    events: Arc<EventBus>,
+    fee_schedule: SharedFeeSchedule,
 ) {
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     // 1. 코어 고정 (Core 0)
@@ -141,17 +149,52 @@ pub fn engine_thread_loop(
             Ok(cmd) => {
                 // 잔고 업데이트 처리
                 match cmd {
-                    BalanceCommand::UpdateBalance { user_id, mint, available_delta, response } => {
+                    BalanceCommand::UpdateBalance { user_id, mint, available_delta, reason, reference, response } => {
                         handle_update_balance(
                             user_id,
                             mint,
                             available_delta,
+                            reason,
+                            reference,
                             response,
                             wal_tx.as_ref(),
                             db_tx.as_ref(),
                             &executor,
+                            &events,
                         );
                     }
+                    BalanceCommand::UpdateHold { hold_id, user_id, mint, amount, action, reason, response } => {
+                        handle_update_hold(
+                            hold_id,
+                            user_id,
+                            mint,
+                            amount,
+                            action,
+                            reason,
+                            response,
+                            wal_tx.as_ref(),
+                            db_tx.as_ref(),
+                            &executor,
+                            &events,
+                        );
+                    }
+                    BalanceCommand::Transfer { transfer_id, from_user_id, to_user_id, mint, amount, response } => {
+                        handle_transfer(
+                            transfer_id,
+                            from_user_id,
+                            to_user_id,
+                            mint,
+                            amount,
+                            response,
+                            wal_tx.as_ref(),
+                            db_tx.as_ref(),
+                            &executor,
+                            &events,
+                        );
+                    }
+                    BalanceCommand::Snapshot { response } => {
+                        handle_balance_snapshot(response, db_tx.as_ref(), &executor);
+                    }
                 }
                 continue; // 다음 루프로 (주문 큐 확인 전에 다시 잔고 큐 확인)
             }
@@ -190,6 +233,7 @@ pub fn engine_thread_loop(
                             &orderbooks,
                             &matcher,
                             &executor,
+                            &events,
                         );
                     }
                     OrderCommand::CancelOrder { order_id, user_id, trading_pair, response } => {
@@ -203,6 +247,7 @@ pub fn engine_thread_loop(
                             &orderbooks,
                             &executor,
                             db.clone(),
+                            &events,
                         );
                     }
                     OrderCommand::GetOrderbook { trading_pair, depth, response } => {
@@ -230,6 +275,7 @@ pub fn engine_thread_loop(
                             wal_tx.as_ref(),
                             db_tx.as_ref(),
                             &executor,
+                            &events,
                         );
                     }
                     OrderCommand::UnlockBalance { user_id, mint, amount, response } => {
@@ -241,8 +287,12 @@ pub fn engine_thread_loop(
                             wal_tx.as_ref(),
                             db_tx.as_ref(),
                             &executor,
+                            &events,
                         );
                     }
+                    OrderCommand::SetFeeSchedule { schedule, response } => {
+                        handle_set_fee_schedule(schedule, response, &fee_schedule);
+                    }
                 }
             }
                 Err(crossbeam::channel::RecvTimeoutError::Timeout) => {
@@ -271,6 +321,7 @@ pub fn engine_thread_loop(
                                             &orderbooks,
                                             &matcher,
                                             &executor,
+                                            &events,
                                         );
                                     }
                                     OrderCommand::CancelOrder { order_id, user_id, trading_pair, response } => {
@@ -284,6 +335,7 @@ pub fn engine_thread_loop(
                                             &orderbooks,
                                             &executor,
                                             db.clone(),
+                                            &events,
                                         );
                                     }
                                     OrderCommand::GetOrderbook { trading_pair, depth, response } => {
@@ -311,6 +363,7 @@ pub fn engine_thread_loop(
                                             wal_tx.as_ref(),
                                             db_tx.as_ref(),
                                             &executor,
+                                            &events,
                                         );
                                     }
                                     OrderCommand::UnlockBalance { user_id, mint, amount, response } => {
@@ -322,8 +375,12 @@ pub fn engine_thread_loop(
                                             wal_tx.as_ref(),
                                             db_tx.as_ref(),
                                             &executor,
+                                            &events,
                                         );
                                     }
+                                    OrderCommand::SetFeeSchedule { schedule, response } => {
+                                        handle_set_fee_schedule(schedule, response, &fee_schedule);
+                                    }
                                 }
                                 continue;
                             }
@@ -361,33 +418,53 @@ pub fn engine_thread_loop(
 fn handle_submit_order(
     order: OrderEntry,
     response: Option<tokio::sync::oneshot::Sender<Result<Vec<MatchResult>>>>,
-    wal_tx: Option<&crossbeam::channel::Sender<WalEntry>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
     db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
     orderbooks: &Arc<RwLock<HashMap<TradingPair, OrderBook>>>,
     matcher: &Arc<Matcher>,
     executor: &Arc<Mutex<Executor>>,
+    events: &EventBus,
 ) {
-    let result = process_submit_order(order, wal_tx, db_tx, orderbooks, matcher, executor);
+    let result = process_submit_order(order, wal_tx, db_tx, orderbooks, matcher, executor, events);
     
     // response가 Some인 경우만 응답 전송 (비동기 처리 시 None)
-    if let Some(tx) = response {
-        let _ = tx.send(result);
+    let Some(tx) = response else {
+        return;
+    };
+    
+    // WAL 활성 시: 배리어를 WAL 채널에 넣어 이 주문의 엔트리가
+    // 디스크에 반영(fsync)된 뒤에 응답 (채널이 FIFO이므로 순서 보장)
+    match wal_tx {
+        Some(wal) => {
+            let ack: WalAck = Box::new(move |durable| {
+                let _ = tx.send(durable.and(result));
+            });
+            if let Err(e) = wal.send(WalMessage::Barrier(ack)) {
+                if let WalMessage::Barrier(ack) = e.into_inner() {
+                    ack(Err(anyhow::anyhow!("WAL thread is not running")));
+                }
+            }
+        }
+        None => {
+            let _ = tx.send(result);
+        }
     }
 }
 
 pub(crate) fn process_submit_order(
     mut order: OrderEntry,
-    wal_tx: Option<&crossbeam::channel::Sender<WalEntry>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
     db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
     orderbooks: &Arc<RwLock<HashMap<TradingPair, OrderBook>>>,
     matcher: &Arc<Matcher>,
     executor: &Arc<Mutex<Executor>>,
+    events: &EventBus,
 ) -> Result<Vec<MatchResult>> {
     // 1. TradingPair 찾기
     let pair = TradingPair::new(order.base_mint.clone(), order.quote_mint.clone());
     
     // 2. 잔고 잠금 (주문 제출 전에 잠금)
-    {
+    let (lock_mint, lock_amount) = {
         let mut executor_guard = executor.lock();
         let (lock_mint, lock_amount) = if order.order_type == "buy" {
             // 매수: quote_mint 잠금
@@ -419,25 +496,20 @@ pub(crate) fn process_submit_order(
                     order.user_id, lock_mint, lock_amount, e
                 );
             }
+            events.publish(EngineEvent::OrderRejected {
+                order_id: order.id,
+                user_id: order.user_id,
+                trading_pair: pair.clone(),
+                reason: format!("Failed to lock balance: {}", e),
+            });
             return Err(anyhow::anyhow!("Failed to lock balance: {}", e));
         }
         
-        // DB Writer로 잔고 업데이트 명령 전송 (available 감소, locked 증가)
-        if let Some(tx) = db_tx {
-            let db_cmd = super::db_commands::DbCommand::UpdateBalance {
-                user_id: order.user_id,
-                mint: lock_mint.to_string(),
-                available_delta: Some(-lock_amount), // available 감소
-                locked_delta: Some(lock_amount), // locked 증가
-            };
-            if let Err(e) = tx.send(db_cmd) {
-                eprintln!("Failed to send DB update command for balance lock: order_id={}, user_id={}, mint={}, amount={}, error={}",
-                    order.id, order.user_id, lock_mint, lock_amount, e);
-            }
-        }
-    }
+        (lock_mint.clone(), lock_amount)
+    };
     
     // 3. WAL 메시지 발행 (OrderCreated) - 잔고 잠금 후!
+    // 잠금 DB 명령보다 먼저 보내야 WalSequence::current()가 이 레코드를 가리킴
     if let Some(tx) = wal_tx {
         let wal_entry = WalEntry::OrderCreated {
             order_id: order.id,
@@ -449,13 +521,36 @@ pub(crate) fn process_submit_order(
             amount: order.amount.to_string(),
             timestamp: order.created_at.timestamp_millis(),
         };
-        let _ = tx.send(wal_entry);
+        let _ = tx.send(WalMessage::entry(wal_entry));
+    }
+    
+    // 3-1. DB Writer로 잔고 잠금 명령 전송 (available 감소, locked 증가)
+    if let Some(tx) = db_tx {
+        let db_cmd = super::db_commands::DbCommand::UpdateBalance {
+            wal_seq: WalSequence::current(),
+            user_id: order.user_id,
+            mint: lock_mint.clone(),
+            available_delta: Some(-lock_amount), // available 감소
+            locked_delta: Some(lock_amount), // locked 증가
+            reason: LedgerReason::Lock,
+            reference: order.id.to_string(),
+        };
+        if let Err(e) = tx.send(db_cmd) {
+            eprintln!("Failed to send DB update command for balance lock: order_id={}, user_id={}, mint={}, amount={}, error={}",
+                order.id, order.user_id, lock_mint, lock_amount, e);
+        }
+    }
+    
+    // 3-2. 이벤트 발행 (OrderAccepted) - 구독자가 있을 때만 주문 복사
+    if events.has_subscribers() {
+        events.publish(EngineEvent::OrderAccepted { order: order.clone() });
     }
     
-    // 3-1. 주문을 DB에 저장 (배치로 처리됨, trade insert 전에 필요 - 외래키 제약)
+    // 3-3. 주문을 DB에 저장 (배치로 처리됨, trade insert 전에 필요 - 외래키 제약)
     // 주문 ID는 DB Writer가 INSERT 시 auto increment로 생성됨
     if let Some(tx) = db_tx {
         let db_cmd = super::db_commands::DbCommand::InsertOrder {
+            wal_seq: WalSequence::current(),
             order_id: order.id,  // 임시 ID (0), DB Writer가 실제 ID 생성
             user_id: order.user_id,
             order_type: order.order_type.clone(),
@@ -511,8 +606,9 @@ pub(crate) fn process_submit_order(
         {
             let mut executor_guard = executor.lock();
             for match_result in &matches {
-                if let Err(e) = executor_guard.execute_trade(match_result) {
-                    eprintln!("Failed to execute trade: {}", e);
+                match executor_guard.execute_trade(match_result, order_after_match.id) {
+                    Ok(execution) => publish_trade(events, match_result, execution.fees),
+                    Err(e) => eprintln!("Failed to execute trade: {}", e),
                 }
             }
         }
@@ -530,6 +626,14 @@ pub(crate) fn process_submit_order(
             };
             
             if unlock_amount > Decimal::ZERO {
+                // 남은 잔량 만료 이벤트 (IOC)
+                events.publish(EngineEvent::OrderExpired {
+                    order_id: order_after_match.id,
+                    user_id: order_after_match.user_id,
+                    trading_pair: pair.clone(),
+                    filled_amount: matches.iter().map(|m| m.amount).sum(),
+                });
+                
                 // 메모리 잔고 잠금 해제
                 if let Err(e) = executor_guard.unlock_balance_for_cancel(
                     order_after_match.id,
@@ -542,10 +646,13 @@ pub(crate) fn process_submit_order(
                     // DB Writer로 잔고 업데이트 명령 전송 (locked 감소, available 증가)
                     if let Some(tx) = db_tx {
                         let db_cmd = super::db_commands::DbCommand::UpdateBalance {
+                            wal_seq: WalSequence::current(),
                             user_id: order_after_match.user_id,
                             mint: unlock_mint.to_string(),
                             available_delta: Some(unlock_amount), // available 증가
                             locked_delta: Some(-unlock_amount), // locked 감소
+                            reason: LedgerReason::Unlock,
+                            reference: order_after_match.id.to_string(),
                         };
                         if let Err(e) = tx.send(db_cmd) {
                             eprintln!("Failed to send DB update command for unlock: {}", e);
@@ -565,6 +672,7 @@ pub(crate) fn process_submit_order(
                 .sum();
             
             let db_cmd = super::db_commands::DbCommand::UpdateOrderStatus {
+                wal_seq: WalSequence::current(),
                 order_id: order_after_match.id,
                 status: "filled".to_string(),
                 filled_amount: total_filled_amount,
@@ -578,6 +686,9 @@ pub(crate) fn process_submit_order(
             }
         }
         
+        // 잔고/호가 변경 이벤트 발행
+        publish_submit_changes(events, orderbooks, executor, &pair, &order_after_match, &matches);
+        
         // 시장가 주문은 항상 성공으로 처리 (IOC 방식)
         return Ok(matches);
     }
@@ -586,9 +697,10 @@ pub(crate) fn process_submit_order(
     {
         let mut executor_guard = executor.lock();
         for match_result in &matches {
-            if let Err(e) = executor_guard.execute_trade(match_result) {
+            match executor_guard.execute_trade(match_result, order_after_match.id) {
+                Ok(execution) => publish_trade(events, match_result, execution.fees),
                 // 에러 발생 시 로그 기록
-                eprintln!("Failed to execute trade: {}", e);
+                Err(e) => eprintln!("Failed to execute trade: {}", e),
             }
         }
     }
@@ -613,6 +725,7 @@ pub(crate) fn process_submit_order(
                     .sum();
                 
                 let db_cmd = super::db_commands::DbCommand::UpdateOrderStatus {
+                    wal_seq: WalSequence::current(),
                     order_id: order_after_match.id,
                     status: "partial".to_string(),
                     filled_amount: total_filled_amount,
@@ -699,10 +812,13 @@ pub(crate) fn process_submit_order(
                 // 남은 locked를 available로 이동 (available 증가, locked 감소)
                 if let Some(tx) = db_tx {
                     let db_cmd = super::db_commands::DbCommand::UpdateBalance {
+                        wal_seq: WalSequence::current(),
                         user_id: order_after_match.user_id,
                         mint: unlock_mint.to_string(),
                         available_delta: Some(unlock_amount), // available 증가
                         locked_delta: Some(-unlock_amount), // locked 감소
+                        reason: LedgerReason::Unlock,
+                        reference: order_after_match.id.to_string(),
                     };
                     if let Err(e) = tx.send(db_cmd) {
                         eprintln!(
@@ -725,6 +841,7 @@ pub(crate) fn process_submit_order(
                 .sum();
             
             let db_cmd = super::db_commands::DbCommand::UpdateOrderStatus {
+                wal_seq: WalSequence::current(),
                 order_id: order_after_match.id,
                 status: "filled".to_string(),
                 filled_amount: total_filled_amount,
@@ -739,9 +856,159 @@ pub(crate) fn process_submit_order(
         }
     }
     
+    // 10. 잔고/호가 변경 이벤트 발행
+    publish_submit_changes(events, orderbooks, executor, &pair, &order_after_match, &matches);
+    
     Ok(matches)
 }
 
+/// 체결 이벤트 발행 (TradeExecuted)
+fn publish_trade(events: &EventBus, match_result: &MatchResult, fees: TradeFees) {
+    if !events.has_subscribers() {
+        return;
+    }
+    events.publish(EngineEvent::TradeExecuted {
+        match_result: match_result.clone(),
+        buyer_id: match_result.buyer_id,
+        seller_id: match_result.seller_id,
+        fees,
+    });
+}
+
+/// 주문 처리 후 잔고/호가 변경 이벤트 발행
+/// 
+/// # 발행 대상
+/// - 잔고: 주문자의 잠금 자산 + 체결 당사자들의 기준 자산/기준 통화
+/// - 주문 (L3): 오더북에 남은 지정가 주문
+/// - 호가: 체결된 상대편 가격 레벨 + 지정가 주문이 남은 가격 레벨
+fn publish_submit_changes(
+    events: &EventBus,
+    orderbooks: &Arc<RwLock<HashMap<TradingPair, OrderBook>>>,
+    executor: &Arc<Mutex<Executor>>,
+    pair: &TradingPair,
+    order: &OrderEntry,
+    matches: &[MatchResult],
+) {
+    if !events.has_subscribers() {
+        return;
+    }
+    
+    let lock_mint = if order.order_type == "buy" { &order.quote_mint } else { &order.base_mint };
+    let mut balances = vec![(order.user_id, lock_mint.clone())];
+    for m in matches {
+        for key in [
+            (m.buyer_id, m.quote_mint.clone()),
+            (m.buyer_id, m.base_mint.clone()),
+            (m.seller_id, m.quote_mint.clone()),
+            (m.seller_id, m.base_mint.clone()),
+        ] {
+            if !balances.contains(&key) {
+                balances.push(key);
+            }
+        }
+    }
+    publish_balance_changes(events, executor, &balances);
+    
+    // 매칭 후 남은 지정가 주문은 오더북 큐의 맨 뒤에 추가됨 (L3)
+    if order.order_side == "limit" && order.remaining_amount > Decimal::ZERO {
+        if let Some(price) = order.price {
+            events.publish(EngineEvent::BookOrderAdded {
+                trading_pair: pair.clone(),
+                order_id: order.id,
+                side: order.order_type.clone(),
+                price,
+                amount: order.remaining_amount,
+            });
+        }
+    }
+    
+    let maker_side = if order.order_type == "buy" { "sell" } else { "buy" };
+    let mut levels: Vec<(&str, Decimal)> = Vec::new();
+    for m in matches {
+        if !levels.contains(&(maker_side, m.price)) {
+            levels.push((maker_side, m.price));
+        }
+    }
+    if order.order_side == "limit" && order.remaining_amount > Decimal::ZERO {
+        if let Some(price) = order.price {
+            levels.push((order.order_type.as_str(), price));
+        }
+    }
+    publish_book_levels(events, orderbooks, pair, &levels);
+}
+
+/// 잔고 변경 이벤트 발행 (BalanceChanged)
+/// 
+/// # Arguments
+/// * `balances` - 변경된 (user_id, mint) 목록 (현재 캐시 값으로 발행)
+fn publish_balance_changes(
+    events: &EventBus,
+    executor: &Arc<Mutex<Executor>>,
+    balances: &[(u64, String)],
+) {
+    if !events.has_subscribers() {
+        return;
+    }
+    
+    let snapshots: Vec<(u64, String, Decimal, Decimal)> = {
+        let executor_guard = executor.lock();
+        balances
+            .iter()
+            .map(|(user_id, mint)| {
+                let (available, locked) = executor_guard
+                    .balance_cache()
+                    .get_balance(*user_id, mint)
+                    .map(|b| (b.available, b.locked))
+                    .unwrap_or((Decimal::ZERO, Decimal::ZERO));
+                (*user_id, mint.clone(), available, locked)
+            })
+            .collect()
+    };
+    
+    for (user_id, mint, available, locked) in snapshots {
+        events.publish(EngineEvent::BalanceChanged { user_id, mint, available, locked });
+    }
+}
+
+/// 호가 레벨 변경 이벤트 발행 (BookLevelChanged)
+/// 
+/// # Arguments
+/// * `levels` - 변경된 (방향, 가격) 목록 (현재 오더북 값으로 발행, 레벨이 없으면 수량 0)
+fn publish_book_levels(
+    events: &EventBus,
+    orderbooks: &Arc<RwLock<HashMap<TradingPair, OrderBook>>>,
+    pair: &TradingPair,
+    levels: &[(&str, Decimal)],
+) {
+    if !events.has_subscribers() || levels.is_empty() {
+        return;
+    }
+    
+    let summaries: Vec<(String, Decimal, Decimal, usize)> = {
+        let orderbooks_guard = orderbooks.read();
+        levels
+            .iter()
+            .map(|(side, price)| {
+                let (total_amount, order_count) = orderbooks_guard
+                    .get(pair)
+                    .map(|ob| ob.level_summary(side, price))
+                    .unwrap_or((Decimal::ZERO, 0));
+                (side.to_string(), *price, total_amount, order_count)
+            })
+            .collect()
+    };
+    
+    for (side, price, total_amount, order_count) in summaries {
+        events.publish(EngineEvent::BookLevelChanged {
+            trading_pair: pair.clone(),
+            side,
+            price,
+            total_amount,
+            order_count,
+        });
+    }
+}
+
 /// CancelOrder 명령 처리
 /// 
 /// # 처리 과정
@@ -756,11 +1023,12 @@ fn handle_cancel_order(
     user_id: u64,
     trading_pair: TradingPair,
     response: tokio::sync::oneshot::Sender<Result<OrderEntry>>,
-    wal_tx: Option<&crossbeam::channel::Sender<WalEntry>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
     db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
     orderbooks: &Arc<RwLock<HashMap<TradingPair, OrderBook>>>,
     executor: &Arc<Mutex<Executor>>,
     db: Option<Database>,
+    events: &EventBus,
 ) {
     // 1. OrderBook에서 주문 찾기
     let mut orderbooks_guard = orderbooks.write();
@@ -842,6 +1110,9 @@ fn handle_cancel_order(
         }
     }
     
+    // 오더북 락 해제 (이후 DB 조회/이벤트 발행은 락 없이)
+    drop(orderbooks_guard);
+    
     let order_type = found_order.as_ref().map(|o| o.order_type.clone());
     
     // 3. OrderBook에서 찾지 못했으면 DB에서 조회
@@ -927,7 +1198,7 @@ fn handle_cancel_order(
             user_id,
             timestamp: chrono::Utc::now().timestamp_millis(),
         };
-        let _ = tx.send(wal_entry);
+        let _ = tx.send(WalMessage::entry(wal_entry));
     }
     
     // 4. 잔고 잠금 해제 (remaining_amount만큼)
@@ -959,6 +1230,7 @@ fn handle_cancel_order(
     // 5. DB에 주문 상태 업데이트 (cancelled)
     if let Some(tx) = db_tx {
         let db_cmd = super::db_commands::DbCommand::UpdateOrderStatus {
+            wal_seq: WalSequence::current(),
             order_id,
             status: "cancelled".to_string(),
             filled_amount: order.filled_amount,
@@ -972,17 +1244,31 @@ fn handle_cancel_order(
     // 6. 잔고 업데이트를 DB에 반영 (unlock)
     if let Some(tx) = db_tx {
         let db_cmd = super::db_commands::DbCommand::UpdateBalance {
+            wal_seq: WalSequence::current(),
             user_id,
             mint: unlock_mint.to_string(),
             available_delta: Some(unlock_amount), // available 증가
             locked_delta: Some(-unlock_amount), // locked 감소
+            reason: LedgerReason::Unlock,
+            reference: order_id.to_string(),
         };
         if let Err(e) = tx.send(db_cmd) {
             eprintln!("Failed to send UpdateBalance command for unlock: {}", e);
         }
     }
     
-    // 7. 취소된 주문 반환
+    // 7. 이벤트 발행 (OrderCancelled + 잔고/호가 변경)
+    events.publish(EngineEvent::OrderCancelled {
+        order_id,
+        user_id,
+        trading_pair: trading_pair.clone(),
+    });
+    publish_balance_changes(events, executor, &[(user_id, unlock_mint.to_string())]);
+    if let (Some(price), Some(side)) = (found_price, order_type.as_deref()) {
+        publish_book_levels(events, orderbooks, &trading_pair, &[(side, price)]);
+    }
+    
+    // 8. 취소된 주문 반환
     let _ = response.send(Ok(order));
 }
 
@@ -1079,9 +1365,10 @@ fn handle_lock_balance(
     mint: String,
     amount: rust_decimal::Decimal,
     response: tokio::sync::oneshot::Sender<Result<()>>,
-    wal_tx: Option<&crossbeam::channel::Sender<WalEntry>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
     db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
     executor: &Arc<Mutex<Executor>>,
+    events: &EventBus,
 ) {
     let mut executor = executor.lock();
     
@@ -1093,14 +1380,29 @@ fn handle_lock_balance(
             // DB Writer로 잔고 업데이트 명령 전송 (available 감소, locked 증가)
             if let Some(tx) = db_tx {
                 let db_cmd = super::db_commands::DbCommand::UpdateBalance {
+                    wal_seq: WalSequence::current(),
                     user_id,
                     mint: mint.clone(),
                     available_delta: Some(-amount), // available 감소
                     locked_delta: Some(amount), // locked 증가
+                    reason: LedgerReason::Lock,
+                    reference: String::new(),
                 };
                 let _ = tx.send(db_cmd);
             }
             
+            // 이벤트 발행 (BalanceChanged)
+            if events.has_subscribers() {
+                if let Some(balance) = executor.balance_cache().get_balance(user_id, &mint) {
+                    events.publish(EngineEvent::BalanceChanged {
+                        user_id,
+                        mint: mint.clone(),
+                        available: balance.available,
+                        locked: balance.locked,
+                    });
+                }
+            }
+            
             let _ = response.send(Ok(()));
         }
         Err(e) => {
@@ -1115,9 +1417,10 @@ fn handle_unlock_balance(
     mint: String,
     amount: rust_decimal::Decimal,
     response: tokio::sync::oneshot::Sender<Result<()>>,
-    wal_tx: Option<&crossbeam::channel::Sender<WalEntry>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
     db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
     executor: &Arc<Mutex<Executor>>,
+    events: &EventBus,
 ) {
     let mut executor = executor.lock();
     
@@ -1128,7 +1431,7 @@ fn handle_unlock_balance(
             user_id,
             timestamp: chrono::Utc::now().timestamp_millis(),
         };
-        let _ = tx.send(wal_entry);
+        let _ = tx.send(WalMessage::entry(wal_entry));
     }
     
     // 잔고 잠금 해제
@@ -1137,14 +1440,29 @@ fn handle_unlock_balance(
             // DB Writer로 잔고 업데이트 명령 전송 (locked 감소, available 증가)
             if let Some(tx) = db_tx {
                 let db_cmd = super::db_commands::DbCommand::UpdateBalance {
+                    wal_seq: WalSequence::current(),
                     user_id,
                     mint: mint.clone(),
                     available_delta: Some(amount), // available 증가
                     locked_delta: Some(-amount), // locked 감소
+                    reason: LedgerReason::Unlock,
+                    reference: String::new(),
                 };
                 let _ = tx.send(db_cmd);
             }
             
+            // 이벤트 발행 (BalanceChanged)
+            if events.has_subscribers() {
+                if let Some(balance) = executor.balance_cache().get_balance(user_id, &mint) {
+                    events.publish(EngineEvent::BalanceChanged {
+                        user_id,
+                        mint: mint.clone(),
+                        available: balance.available,
+                        locked: balance.locked,
+                    });
+                }
+            }
+            
             let _ = response.send(Ok(()));
         }
         Err(e) => {
@@ -1153,6 +1471,24 @@ fn handle_unlock_balance(
     }
 }
 
+/// SetFeeSchedule 명령 처리 (수수료 스케줄 교체)
+///
+/// 엔진 스레드에서 명령 순서대로 교체하므로 이 명령보다 먼저 들어온 주문은
+/// 이전 스케줄, 이후 주문은 새 스케줄을 봅니다.
+fn handle_set_fee_schedule(
+    mut schedule: FeeSchedule,
+    response: tokio::sync::oneshot::Sender<Result<u64>>,
+    fee_schedule: &SharedFeeSchedule,
+) {
+    let mut current = fee_schedule.write();
+    let version = current.version() + 1;
+    schedule.set_version(version);
+    *current = Arc::new(schedule);
+    drop(current);
+
+    let _ = response.send(Ok(version));
+}
+
 /// UpdateBalance 명령 처리 (입금/출금)
 /// 
 /// # 처리 과정
@@ -1160,12 +1496,15 @@ fn handle_unlock_balance(
 /// 2. available 업데이트 (기존 + delta)
 /// 3. WAL 메시지 발행 (BalanceUpdated)
 /// 4. DB 명령 전송 (UpdateBalance) → DB Writer가 배치로 처리
-/// 5. 성공/실패 결과를 response로 전송
+/// 5. 이벤트 발행 (BalanceChanged)
+/// 6. 성공/실패 결과를 response로 전송
 /// 
 /// # Arguments
 /// * `user_id` - 사용자 ID
 /// * `mint` - 자산 종류 (예: "SOL", "USDT")
 /// * `available_delta` - available 증감량 (양수: 입금, 음수: 출금)
+/// * `reason` - 원장 분개 사유
+/// * `reference` - 원장 참조
 /// * `response` - 결과를 반환할 oneshot 채널
 /// * `wal_tx` - WAL 메시지 전송 채널
 /// * `db_tx` - DB 명령 전송 채널
@@ -1178,6 +1517,8 @@ fn handle_unlock_balance(
 ///     123,
 ///     "USDT".to_string(),
 ///     Decimal::new(100, 0),
+///     LedgerReason::Deposit,
+///     signature,
 ///     response,
 ///     wal_tx,
 ///     db_tx,
@@ -1188,10 +1529,13 @@ fn handle_update_balance(
     user_id: u64,
     mint: String,
     available_delta: rust_decimal::Decimal,
+    reason: LedgerReason,
+    reference: String,
     response: tokio::sync::oneshot::Sender<Result<()>>,
-    wal_tx: Option<&crossbeam::channel::Sender<WalEntry>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
     db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
     executor: &Arc<Mutex<Executor>>,
+    events: &EventBus,
 ) {
     // 1. BalanceCache에서 잔고 업데이트
     let (new_available, new_locked) = {
@@ -1219,25 +1563,312 @@ fn handle_update_balance(
             locked: new_locked.to_string(),
             timestamp: chrono::Utc::now().timestamp_millis(),
         };
-        let _ = tx.send(wal_entry);
+        let _ = tx.send(WalMessage::entry(wal_entry));
     }
     
     // 3. DB 명령 전송 (UpdateBalance)
     // DB Writer 스레드가 배치로 처리 (100개 또는 10ms마다)
     if let Some(tx) = db_tx {
         let db_cmd = super::db_commands::DbCommand::UpdateBalance {
+            wal_seq: WalSequence::current(),
             user_id,
             mint: mint.clone(),
             available_delta: Some(available_delta),
             locked_delta: None,  // 입금/출금은 available만 변경
+            reason,
+            reference,
         };
         let _ = tx.send(db_cmd);
     }
     
-    // 4. 성공 결과 반환
+    // 4. 이벤트 발행 (BalanceChanged)
+    events.publish(EngineEvent::BalanceChanged {
+        user_id,
+        mint,
+        available: new_available,
+        locked: new_locked,
+    });
+    
+    // 5. 성공 결과 반환
+    let _ = response.send(Ok(()));
+}
+
+/// 잔고 보류 전이 처리 (출금)
+/// 
+/// # Arguments
+/// * `hold_id` - 보류 ID (출금 ID)
+/// * `action` - Place / Keep / Consume / Release
+/// * `reason` - WAL에 기록할 전이 이름
+/// 
+/// # 처리 과정
+/// 1. BalanceCache 보류 처리 (실패 시 에러 반환)
+/// 2. WAL 메시지 발행 (BalanceHold + available 변화 시 BalanceUpdated)
+/// 3. available 변화 시 DB 명령 전송 (UpdateBalance) + 이벤트 발행 (BalanceChanged)
+/// 
+/// # Note
+/// DB의 available에는 보류 금액이 빠진 상태로 저장됩니다.
+/// 엔진 시작 시 진행 중인 출금으로 보류 기록을 복원합니다.
+#[allow(clippy::too_many_arguments)]
+fn handle_update_hold(
+    hold_id: u64,
+    user_id: u64,
+    mint: String,
+    amount: Decimal,
+    action: HoldAction,
+    reason: String,
+    response: tokio::sync::oneshot::Sender<Result<()>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
+    db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
+    executor: &Arc<Mutex<Executor>>,
+    events: &EventBus,
+) {
+    // 1. BalanceCache 보류 처리 (available 증감량 계산)
+    let result = {
+        let mut executor_guard = executor.lock();
+        let balance_cache = executor_guard.balance_cache_mut();
+        
+        let applied = match action {
+            HoldAction::Place => balance_cache
+                .place_hold(hold_id, user_id, &mint, amount)
+                .map(|_| -amount),
+            HoldAction::Keep => match balance_cache.get_hold(hold_id) {
+                Some(_) => Ok(Decimal::ZERO),
+                None => Err(anyhow::anyhow!("Hold not found: hold_id={}", hold_id)),
+            },
+            HoldAction::Consume => balance_cache.consume_hold(hold_id).map(|_| Decimal::ZERO),
+            HoldAction::Release => balance_cache.release_hold(hold_id).map(|hold| hold.amount),
+        };
+        
+        applied.map(|available_delta| {
+            let balance = balance_cache.get_balance(user_id, &mint)
+                .cloned()
+                .unwrap_or_else(crate::domains::cex::engine::balance_cache::Balance::new);
+            (available_delta, balance.available, balance.locked)
+        })
+    };
+    
+    let (available_delta, new_available, new_locked) = match result {
+        Ok(applied) => applied,
+        Err(e) => {
+            let _ = response.send(Err(e));
+            return;
+        }
+    };
+    
+    // 2. WAL 메시지 발행 (모든 전이 기록)
+    if let Some(tx) = wal_tx {
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let _ = tx.send(WalMessage::entry(WalEntry::BalanceHold {
+            hold_id,
+            user_id,
+            mint: mint.clone(),
+            amount: amount.to_string(),
+            action: action.as_str().to_string(),
+            reason,
+            timestamp,
+        }));
+        if !available_delta.is_zero() {
+            let _ = tx.send(WalMessage::entry(WalEntry::BalanceUpdated {
+                user_id,
+                mint: mint.clone(),
+                available: new_available.to_string(),
+                locked: new_locked.to_string(),
+                timestamp,
+            }));
+        }
+    }
+    
+    // 3. available이 바뀐 경우만 DB 반영 + 이벤트
+    if !available_delta.is_zero() {
+        if let Some(tx) = db_tx {
+            let _ = tx.send(super::db_commands::DbCommand::UpdateBalance {
+                wal_seq: WalSequence::current(),
+                user_id,
+                mint: mint.clone(),
+                available_delta: Some(available_delta),
+                locked_delta: None,
+                reason: if available_delta.is_sign_negative() {
+                    LedgerReason::Withdrawal
+                } else {
+                    LedgerReason::WithdrawalRefund
+                },
+                reference: hold_id.to_string(),
+            });
+        }
+        
+        events.publish(EngineEvent::BalanceChanged {
+            user_id,
+            mint,
+            available: new_available,
+            locked: new_locked,
+        });
+    }
+    
+    let _ = response.send(Ok(()));
+}
+
+/// 내부 이체 처리 (사용자 → 사용자 available 이동)
+/// 
+/// # Arguments
+/// * `transfer_id` - 이체 ID (원장 참조)
+/// * `from_user_id` / `to_user_id` - 보내는/받는 사용자 ID
+/// * `amount` - 이체 금액 (양수)
+/// 
+/// # 처리 과정
+/// 1. BalanceCache 이체 (available 부족 시 에러 반환, 상태 변경 없음)
+/// 2. WAL 메시지 발행 (BalanceTransfer + 양쪽 BalanceUpdated)
+/// 3. DB 명령 전송 (양쪽 UpdateBalance, BalanceTransfer의 WAL 시퀀스)
+/// 4. 이벤트 발행 (양쪽 BalanceChanged)
+/// 
+/// # Note
+/// 엔진 스레드에서 한 번에 처리되므로 차감과 증가 사이에 주문/체결이 끼어들지 않습니다.
+#[allow(clippy::too_many_arguments)]
+fn handle_transfer(
+    transfer_id: u64,
+    from_user_id: u64,
+    to_user_id: u64,
+    mint: String,
+    amount: Decimal,
+    response: tokio::sync::oneshot::Sender<Result<()>>,
+    wal_tx: Option<&crossbeam::channel::Sender<WalMessage>>,
+    db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
+    executor: &Arc<Mutex<Executor>>,
+    events: &EventBus,
+) {
+    use crate::domains::cex::engine::balance_cache::Balance;
+
+    if amount <= Decimal::ZERO {
+        let _ = response.send(Err(anyhow::anyhow!("Transfer amount must be positive")));
+        return;
+    }
+    if from_user_id == to_user_id {
+        let _ = response.send(Err(anyhow::anyhow!("Cannot transfer to the same user")));
+        return;
+    }
+
+    // 1. BalanceCache 이체 (available → available)
+    let result = {
+        let mut executor_guard = executor.lock();
+        let balance_cache = executor_guard.balance_cache_mut();
+        
+        balance_cache
+            .transfer(from_user_id, to_user_id, &mint, amount, false)
+            .map(|_| {
+                let from = balance_cache.get_balance(from_user_id, &mint).cloned().unwrap_or_else(Balance::new);
+                let to = balance_cache.get_balance(to_user_id, &mint).cloned().unwrap_or_else(Balance::new);
+                (from, to)
+            })
+    };
+    
+    let (from_balance, to_balance) = match result {
+        Ok(balances) => balances,
+        Err(e) => {
+            let _ = response.send(Err(e));
+            return;
+        }
+    };
+    
+    // 2. WAL 메시지 발행 (이체 → 양쪽 잔고)
+    let mut wal_seq = WalSequence::current();
+    if let Some(tx) = wal_tx {
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let _ = tx.send(WalMessage::entry(WalEntry::BalanceTransfer {
+            transfer_id,
+            from_user_id,
+            to_user_id,
+            mint: mint.clone(),
+            amount: amount.to_string(),
+            timestamp,
+        }));
+        wal_seq = WalSequence::current();
+        for (user_id, balance) in [(from_user_id, &from_balance), (to_user_id, &to_balance)] {
+            let _ = tx.send(WalMessage::entry(WalEntry::BalanceUpdated {
+                user_id,
+                mint: mint.clone(),
+                available: balance.available.to_string(),
+                locked: balance.locked.to_string(),
+                timestamp,
+            }));
+        }
+    }
+    
+    // 3. DB 명령 전송 (같은 시퀀스 + 참조 → 원장 분개 하나, clearing 상쇄)
+    if let Some(tx) = db_tx {
+        for (user_id, available_delta) in [(from_user_id, -amount), (to_user_id, amount)] {
+            let _ = tx.send(super::db_commands::DbCommand::UpdateBalance {
+                wal_seq,
+                user_id,
+                mint: mint.clone(),
+                available_delta: Some(available_delta),
+                locked_delta: None,
+                reason: LedgerReason::Transfer,
+                reference: transfer_id.to_string(),
+            });
+        }
+    }
+    
+    // 4. 이벤트 발행
+    for (user_id, balance) in [(from_user_id, from_balance), (to_user_id, to_balance)] {
+        events.publish(EngineEvent::BalanceChanged {
+            user_id,
+            mint: mint.clone(),
+            available: balance.available,
+            locked: balance.locked,
+        });
+    }
+    
     let _ = response.send(Ok(()));
 }
 
+/// Snapshot 명령 처리 (잔고 정합성 검사)
+/// 
+/// # 처리 과정
+/// 1. BalanceCache 전체 복사 + 현재 WAL 시퀀스 기록
+/// 2. DB Writer에 Barrier 전송 (스냅샷 이전 DB 명령 반영 확인용)
+/// 
+/// # Note
+/// 엔진 스레드에서 실행되므로 복사 중에 주문/체결/입금이 끼어들지 않습니다.
+fn handle_balance_snapshot(
+    response: tokio::sync::oneshot::Sender<Result<super::balance_commands::BalanceSnapshot>>,
+    db_tx: Option<&crossbeam::channel::Sender<super::db_commands::DbCommand>>,
+    executor: &Arc<Mutex<Executor>>,
+) {
+    use super::balance_commands::{BalanceSnapshot, BalanceSnapshotEntry};
+
+    let balances: Vec<BalanceSnapshotEntry> = {
+        let executor_guard = executor.lock();
+        executor_guard
+            .balance_cache()
+            .iter()
+            .map(|(user_id, mint, balance)| BalanceSnapshotEntry {
+                user_id,
+                mint: mint.to_string(),
+                available: balance.available,
+                locked: balance.locked,
+            })
+            .collect()
+    };
+    let wal_seq = WalSequence::current();
+
+    let db_flushed = match db_tx {
+        Some(tx) => {
+            let (ack_tx, ack_rx) = crossbeam::channel::bounded(1);
+            if let Err(e) = tx.send(super::db_commands::DbCommand::Barrier(ack_tx)) {
+                let _ = response.send(Err(anyhow::anyhow!("Failed to send DB barrier: {}", e)));
+                return;
+            }
+            Some(ack_rx)
+        }
+        None => None,
+    };
+
+    let _ = response.send(Ok(BalanceSnapshot {
+        wal_seq,
+        balances,
+        db_flushed,
+    }));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1276,53 +1907,296 @@ mod tests {
 
         let order = sample_limit_buy(1, 1);
         let result =
-            super::process_submit_order(order, None, None, &orderbooks, &matcher, &executor).unwrap();
+            super::process_submit_order(order, None, None, &orderbooks, &matcher, &executor, &EventBus::new()).unwrap();
         assert!(result.is_empty());
 
         let books = orderbooks.read();
         assert_eq!(books.len(), 1);
     }
-}
 
-// =====================================================
-// WAL 스레드 루프
-// =====================================================
-// 역할: WAL 메시지를 받아서 디스크에 순차 쓰기
-//
-// 처리 과정:
-// 1. 코어 고정 (Core 1)
-// 2. WalWriter 생성
-// 3. WAL 메시지 수신 루프
-// 4. WalWriter::append() 호출
-// 5. 주기적 fsync()
-// =====================================================
+    #[test]
+    fn submit_order_publishes_engine_events() {
+        let orderbooks = Arc::new(RwLock::new(HashMap::new()));
+        let matcher = Arc::new(Matcher::new());
+        let executor = Arc::new(Mutex::new(Executor::new_without_wal()));
+        let events = EventBus::new();
 
-/// WAL 스레드 메인 루프
-/// 
-/// # Arguments
-/// * `wal_rx` - WAL 메시지 수신 채널
-/// * `wal_dir` - WAL 디렉토리 경로
-/// 
-/// # 처리 흐름
-/// ```
-/// loop {
-///     wal_rx.recv() → WalEntry
-///         ↓
-///     WalWriter::append(entry)
-///         ↓
-///     (10개마다) fsync()
-/// }
-/// ```
-pub fn wal_thread_loop(
-    wal_rx: Receiver<WalEntry>,
-    wal_dir: std::path::PathBuf,
-) {
-    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-    // 1. 코어 고정 (Core 1)
-    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-    
-    let config = CoreConfig::from_env();
-    CoreConfig::set_core(Some(config.wal_core));
+        {
+            let mut exec = executor.lock();
+            exec.balance_cache_mut()
+                .set_balance(1, "USDT", Decimal::new(10_000, 0), Decimal::ZERO);
+            exec.balance_cache_mut()
+                .set_balance(2, "SOL", Decimal::new(10, 0), Decimal::ZERO);
+        }
+
+        super::process_submit_order(sample_limit_buy(1, 1), None, None, &orderbooks, &matcher, &executor, &events)
+            .unwrap();
+
+        let mut subscription = events.subscribe("test");
+        let mut sell = sample_limit_buy(2, 2);
+        sell.order_type = "sell".to_string();
+        let matches =
+            super::process_submit_order(sell, None, None, &orderbooks, &matcher, &executor, &events).unwrap();
+        assert_eq!(matches.len(), 1);
+
+        let mut kinds = Vec::new();
+        while let Some(event) = subscription.try_recv().unwrap() {
+            assert_eq!(event.seq, kinds.len() as u64 + 1);
+            kinds.push(match &event.event {
+                EngineEvent::OrderAccepted { .. } => "accepted",
+                EngineEvent::TradeExecuted { .. } => "trade",
+                EngineEvent::BalanceChanged { .. } => "balance",
+                EngineEvent::BookLevelChanged { side, total_amount, .. } => {
+                    assert_eq!(side, "buy");
+                    assert_eq!(*total_amount, Decimal::ZERO);
+                    "level"
+                }
+                other => panic!("unexpected event: {:?}", other),
+            });
+        }
+        assert_eq!(kinds, vec!["accepted", "trade", "balance", "balance", "balance", "balance", "level"]);
+    }
+
+    #[test]
+    fn submit_order_tags_lock_with_order_created_seq() {
+        use super::super::db_commands::DbCommand;
+
+        let orderbooks = Arc::new(RwLock::new(HashMap::new()));
+        let matcher = Arc::new(Matcher::new());
+        let executor = Arc::new(Mutex::new(Executor::new_without_wal()));
+        let (wal_tx, wal_rx) = crossbeam::channel::unbounded();
+        let (db_tx, db_rx) = crossbeam::channel::unbounded();
+
+        {
+            let mut exec = executor.lock();
+            exec.balance_cache_mut()
+                .set_balance(1, "USDT", Decimal::new(10_000, 0), Decimal::ZERO);
+        }
+
+        super::process_submit_order(
+            sample_limit_buy(1, 1), Some(&wal_tx), Some(&db_tx), &orderbooks, &matcher, &executor, &EventBus::new(),
+        )
+        .unwrap();
+
+        let order_created_seq = match wal_rx.try_recv().unwrap() {
+            WalMessage::Entry(record) => {
+                assert!(matches!(record.entry, WalEntry::OrderCreated { .. }));
+                record.seq
+            }
+            other => panic!("unexpected WAL message: {:?}", other),
+        };
+        // 다른 테스트도 전역 시퀀스를 올리므로 같은 값이 아니라 순서만 확인
+        match db_rx.try_recv().unwrap() {
+            DbCommand::UpdateBalance { wal_seq, reason, .. } => {
+                assert_eq!(reason, LedgerReason::Lock);
+                assert!(wal_seq >= order_created_seq);
+            }
+            other => panic!("unexpected DB command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn wal_group_commit_acks_barrier_after_fsync() {
+        use crate::domains::cex::engine::wal::WalReader;
+        use std::time::Duration;
+
+        let wal_dir = std::env::temp_dir().join(format!("wal_group_commit_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&wal_dir);
+
+        let (wal_tx, wal_rx) = crossbeam::channel::unbounded();
+        let metrics = Arc::new(WalMetrics::new());
+        let durability = WalDurability::GroupCommit {
+            max_delay: Duration::from_millis(1),
+            max_entries: 64,
+        };
+
+        let thread_dir = wal_dir.clone();
+        let thread_metrics = Arc::clone(&metrics);
+        let handle = std::thread::spawn(move || {
+            wal_thread_loop(wal_rx, thread_dir, durability, thread_metrics);
+        });
+
+        for user_id in 1..=3 {
+            wal_tx.send(WalMessage::entry(WalEntry::BalanceLocked {
+                user_id,
+                mint: "USDT".to_string(),
+                amount: "100".to_string(),
+                timestamp: 0,
+            })).unwrap();
+        }
+
+        let (ack_tx, ack_rx) = crossbeam::channel::bounded(1);
+        wal_tx.send(WalMessage::Barrier(Box::new(move |durable| {
+            let _ = ack_tx.send(durable.is_ok());
+        }))).unwrap();
+
+        assert!(ack_rx.recv_timeout(Duration::from_secs(5)).unwrap());
+
+        drop(wal_tx);
+        handle.join().unwrap();
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.entries_written, 3);
+        assert_eq!(snapshot.acks_completed, 1);
+        assert!(snapshot.fsync_count >= 1);
+
+        let file = std::fs::read_dir(&wal_dir).unwrap().next().unwrap().unwrap().path();
+        assert_eq!(WalReader::new(file).read_all().unwrap().len(), 3);
+
+        let _ = std::fs::remove_dir_all(&wal_dir);
+    }
+
+    #[test]
+    fn wal_error_fails_barriers_until_next_fsync() {
+        let wal_dir = std::env::temp_dir().join(format!("wal_sticky_error_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&wal_dir);
+        let mut wal_writer = WalWriter::new(&wal_dir, usize::MAX).unwrap();
+        let metrics = WalMetrics::new();
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let barrier = |results: &Arc<Mutex<Vec<bool>>>| {
+            let results = Arc::clone(results);
+            WalMessage::Barrier(Box::new(move |durable| results.lock().push(durable.is_ok())))
+        };
+
+        // ack 없는 배치에서 쓰기 실패 → 에러 유지
+        let mut batch = WalBatch::default();
+        batch.fail("disk full".to_string());
+        batch.commit(&mut wal_writer, WalDurability::Async, &metrics, false);
+        assert!(batch.error.is_some());
+
+        // 다음 배치의 barrier는 실패, flush + fsync 성공 후 에러 해제
+        batch.handle(barrier(&results), &mut wal_writer, WalDurability::Async, &metrics);
+        batch.commit(&mut wal_writer, WalDurability::Async, &metrics, false);
+        assert!(batch.error.is_none());
+
+        batch.handle(barrier(&results), &mut wal_writer, WalDurability::Async, &metrics);
+        batch.commit(&mut wal_writer, WalDurability::Async, &metrics, false);
+        assert_eq!(*results.lock(), vec![false, true]);
+
+        let _ = std::fs::remove_dir_all(&wal_dir);
+    }
+
+    #[tokio::test]
+    async fn db_writer_numbers_commands_within_wal_seq() {
+        use super::super::db_commands::DbCommand;
+
+        let balance = |wal_seq: u64| DbCommand::UpdateBalance {
+            wal_seq,
+            user_id: 1,
+            mint: "USDT".to_string(),
+            available_delta: Some(Decimal::ONE),
+            locked_delta: None,
+            reason: LedgerReason::Adjustment,
+            reference: String::new(),
+        };
+
+        let db_pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let high_water = DbWriteCursor { wal_seq: 9, index: 0 };
+        let mut writer = DbBatchWriter::new(db_pool, high_water, std::env::temp_dir());
+        let mut batch = Vec::new();
+
+        // 같은 WAL 레코드에서 파생된 명령 3개는 index 0, 1, 2
+        for _ in 0..3 {
+            writer.push(&mut batch, balance(10));
+        }
+        writer.push(&mut batch, balance(11));
+
+        let cursors: Vec<DbWriteCursor> = batch.iter().map(|pending| pending.cursor).collect();
+        assert_eq!(cursors, vec![
+            DbWriteCursor { wal_seq: 10, index: 0 },
+            DbWriteCursor { wal_seq: 10, index: 1 },
+            DbWriteCursor { wal_seq: 10, index: 2 },
+            DbWriteCursor { wal_seq: 11, index: 0 },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn db_writer_barrier_waits_for_pending_batch() {
+        use super::super::db_commands::DbCommand;
+
+        let db_pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let high_water = DbWriteCursor { wal_seq: 10, index: 0 };
+        let mut writer = DbBatchWriter::new(db_pool, high_water, std::env::temp_dir());
+        let mut batch = Vec::new();
+
+        // 배치가 비어 있으면 즉시 ack
+        let (ack_tx, ack_rx) = crossbeam::channel::bounded(1);
+        writer.push(&mut batch, DbCommand::Barrier(ack_tx));
+        assert_eq!(ack_rx.try_recv().unwrap(), high_water);
+
+        // 쓸 명령이 있으면 flush 후 ack (Barrier는 위치를 차지하지 않음)
+        writer.push(&mut batch, DbCommand::UpdateOrderStatus {
+            wal_seq: 11,
+            order_id: 1,
+            status: "cancelled".to_string(),
+            filled_amount: Decimal::ZERO,
+            filled_quote_amount: Decimal::ZERO,
+        });
+        let (ack_tx, ack_rx) = crossbeam::channel::bounded(1);
+        writer.push(&mut batch, DbCommand::Barrier(ack_tx));
+        assert!(ack_rx.try_recv().is_err());
+        assert_eq!(batch.len(), 1);
+        assert_eq!(writer.barriers.len(), 1);
+    }
+
+    #[test]
+    fn db_writer_retry_backoff_is_capped() {
+        assert_eq!(retry_backoff(1), DB_WRITER_BASE_BACKOFF);
+        assert_eq!(retry_backoff(2), DB_WRITER_BASE_BACKOFF * 2);
+        assert_eq!(retry_backoff(64), DB_WRITER_MAX_BACKOFF);
+    }
+}
+
+// =====================================================
+// WAL 스레드 루프
+// =====================================================
+// 역할: WAL 메시지를 받아서 디스크에 순차 쓰기
+//
+// 처리 과정:
+// 1. 코어 고정 (Core 1)
+// 2. WalWriter 생성
+// 3. WAL 메시지 수신 루프 (배치 단위)
+// 4. WalWriter::write() 호출
+// 5. durability 정책에 따라 fsync() 후 Barrier ack 완료
+// =====================================================
+
+/// WAL 스레드 메인 루프
+/// 
+/// # Arguments
+/// * `wal_rx` - WAL 메시지 수신 채널
+/// * `wal_dir` - WAL 디렉토리 경로
+/// * `durability` - fsync 정책 (per_command / group_commit / async)
+/// * `metrics` - fsync 지연 시간 및 배치 크기 메트릭
+/// 
+/// # 처리 흐름
+/// ```
+/// loop {
+///     wal_rx.recv() → 첫 메시지 (블로킹)
+///         ↓
+///     정책에 따라 배치 모으기
+///       - per_command: 엔트리마다 fsync
+///       - group_commit: max_delay 경과 또는 max_entries 누적까지 수집
+///       - async: 이미 도착한 메시지만 수집
+///         ↓
+///     fsync() (async는 flush만)
+///         ↓
+///     배치 안의 Barrier ack 완료 (SubmitOrder 응답 전송)
+/// }
+/// ```
+pub fn wal_thread_loop(
+    wal_rx: Receiver<WalMessage>,
+    wal_dir: std::path::PathBuf,
+    durability: WalDurability,
+    metrics: Arc<WalMetrics>,
+) {
+    use std::time::Instant;
+    
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    // 1. 코어 고정 (Core 1)
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    
+    let config = CoreConfig::from_env();
+    CoreConfig::set_core(Some(config.wal_core));
     
     // WAL 스레드는 실시간 스케줄링 불필요 (I/O 바운드)
     
@@ -1330,36 +2204,212 @@ pub fn wal_thread_loop(
     // 2. WalWriter 생성
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     
-    let mut wal_writer = match WalWriter::new(&wal_dir, 10) {
+    let mut wal_writer = match WalWriter::new(&wal_dir, durability.sync_interval()) {
         Ok(writer) => writer,
         Err(e) => {
             eprintln!("Failed to create WalWriter: {}", e);
+            // 채널을 계속 비워서 엔진이 막히지 않게 하고, ack는 실패로 응답
+            for msg in wal_rx.iter() {
+                if let WalMessage::Barrier(ack) = msg {
+                    ack(Err(anyhow::anyhow!("WAL is unavailable: {}", e)));
+                }
+            }
             return;
         }
     };
     
+    eprintln!("[WAL] Durability policy: {:?}", durability);
+    
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-    // 3. 메인 루프
+    // 3. 메인 루프 (배치 단위)
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     
+    let mut batch = WalBatch::default();
+    
     loop {
-        match wal_rx.recv() {
-            Ok(entry) => {
-                // WAL 파일에 쓰기
-                if let Err(e) = wal_writer.append(&entry) {
-                    eprintln!("Failed to write to WAL: {}", e);
-                }
-            }
+        // 첫 메시지 대기 (블로킹)
+        let first = match wal_rx.recv() {
+            Ok(msg) => msg,
             Err(_) => {
                 // 채널이 닫힘 (정상 종료)
                 // 마지막 동기화
-                let _ = wal_writer.sync();
+                batch.commit(&mut wal_writer, durability, &metrics, true);
                 break;
             }
+        };
+        let batch_start = Instant::now();
+        batch.handle(first, &mut wal_writer, durability, &metrics);
+        
+        // 정책에 따라 배치 모으기
+        let mut closed = false;
+        match durability {
+            WalDurability::GroupCommit { max_delay, max_entries } => {
+                while wal_writer.pending_entries() < max_entries {
+                    let remaining = max_delay.saturating_sub(batch_start.elapsed());
+                    if remaining.is_zero() {
+                        break;
+                    }
+                    match wal_rx.recv_timeout(remaining) {
+                        Ok(msg) => batch.handle(msg, &mut wal_writer, durability, &metrics),
+                        Err(crossbeam::channel::RecvTimeoutError::Timeout) => break,
+                        Err(crossbeam::channel::RecvTimeoutError::Disconnected) => {
+                            closed = true;
+                            break;
+                        }
+                    }
+                }
+            }
+            WalDurability::PerCommand | WalDurability::Async => {
+                // 대기 없이 이미 도착한 메시지만 처리
+                loop {
+                    match wal_rx.try_recv() {
+                        Ok(msg) => batch.handle(msg, &mut wal_writer, durability, &metrics),
+                        Err(crossbeam::channel::TryRecvError::Empty) => break,
+                        Err(crossbeam::channel::TryRecvError::Disconnected) => {
+                            closed = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        
+        // fsync + ack 완료
+        batch.commit(&mut wal_writer, durability, &metrics, closed);
+        
+        if closed {
+            break;
         }
     }
 }
 
+/// WAL 스레드의 현재 배치 상태
+/// 
+/// 배치 안에서 받은 Barrier ack와 쓰기 에러를 모아두었다가
+/// `commit()`에서 한 번에 완료합니다.
+/// 
+/// 쓰기/동기화 에러는 이후 배치에서 flush + fsync가 성공할 때까지 유지되고,
+/// 그 전까지 받은 Barrier는 모두 실패합니다 (유실된 엔트리 뒤의 ack 방지).
+#[derive(Default)]
+struct WalBatch {
+    /// durable 확인 후 호출할 ack
+    acks: Vec<WalAck>,
+    /// 마지막 쓰기/동기화 에러 (flush + fsync 성공 전까지 유지)
+    error: Option<String>,
+    /// 이번 배치에서 에러 발생 여부
+    failed: bool,
+}
+
+impl WalBatch {
+    /// 메시지 하나 처리
+    fn handle(
+        &mut self,
+        msg: WalMessage,
+        wal_writer: &mut WalWriter,
+        durability: WalDurability,
+        metrics: &WalMetrics,
+    ) {
+        match msg {
+            WalMessage::Entry(record) => {
+                // WAL 파일에 쓰기
+                if let Err(e) = wal_writer.write(&record) {
+                    eprintln!("Failed to write to WAL: {}", e);
+                    self.fail(e.to_string());
+                    return;
+                }
+                metrics.record_entry();
+                
+                // per_command: 엔트리마다 fsync
+                if durability == WalDurability::PerCommand {
+                    self.sync(wal_writer, metrics, false);
+                }
+            }
+            WalMessage::Barrier(ack) => {
+                self.acks.push(ack);
+            }
+        }
+    }
+    
+    /// 에러 기록 (이번 배치 실패)
+    fn fail(&mut self, error: String) {
+        self.error.get_or_insert(error);
+        self.failed = true;
+    }
+    
+    /// fsync 후 메트릭 기록
+    /// 
+    /// # Arguments
+    /// * `force` - 기록된 엔트리가 없어도 flush + fsync (에러 해제 확인용)
+    /// 
+    /// # Returns
+    /// flush + fsync 성공 여부 (할 일이 없었으면 true)
+    fn sync(&mut self, wal_writer: &mut WalWriter, metrics: &WalMetrics, force: bool) -> bool {
+        let pending = wal_writer.pending_entries();
+        if pending == 0 && !force {
+            return true;
+        }
+        
+        let started = std::time::Instant::now();
+        match wal_writer.sync() {
+            Ok(()) => {
+                if pending > 0 {
+                    metrics.record_fsync(started.elapsed(), pending);
+                }
+                true
+            }
+            Err(e) => {
+                eprintln!("Failed to sync WAL: {}", e);
+                self.fail(e.to_string());
+                false
+            }
+        }
+    }
+    
+    /// 배치 마무리: 정책에 따라 fsync/flush 후 ack 완료
+    /// 
+    /// 남아 있는 에러가 있으면 이번 배치의 ack는 모두 실패하고,
+    /// 이번 배치에서 새 에러 없이 flush + fsync가 성공하면 에러를 해제합니다.
+    /// 
+    /// # Arguments
+    /// * `shutdown` - 종료 시 true (async 정책이어도 fsync)
+    fn commit(
+        &mut self,
+        wal_writer: &mut WalWriter,
+        durability: WalDurability,
+        metrics: &WalMetrics,
+        shutdown: bool,
+    ) {
+        let recovering = self.error.is_some();
+        let synced = if durability == WalDurability::Async && !shutdown && !recovering {
+            // async: 커널 버퍼까지만 (fsync 없음)
+            if let Err(e) = wal_writer.flush() {
+                eprintln!("Failed to flush WAL: {}", e);
+                self.fail(e.to_string());
+            }
+            false
+        } else {
+            // 에러가 남아 있으면 async 정책이어도 flush + fsync로 해제 확인
+            self.sync(wal_writer, metrics, recovering)
+        };
+        
+        if !self.acks.is_empty() {
+            metrics.record_acks(self.acks.len());
+            for ack in self.acks.drain(..) {
+                match &self.error {
+                    None => ack(Ok(())),
+                    Some(e) => ack(Err(anyhow::anyhow!("WAL write failed: {}", e))),
+                }
+            }
+        }
+        
+        if recovering && synced && !self.failed {
+            eprintln!("[WAL] Recovered after flush + fsync");
+            self.error = None;
+        }
+        self.failed = false;
+    }
+}
+
 // =====================================================
 // DB Writer 스레드 루프
 // =====================================================
@@ -1367,30 +2417,51 @@ pub fn wal_thread_loop(
 //
 // 처리 과정:
 // 1. 코어 고정 (Core 2, dev 환경만)
-// 2. DB 명령 수신 루프
+// 2. DB 명령 수신 루프 (high-water mark 이하 명령은 건너뜀)
 // 3. 배치로 모으기 (10ms 또는 100개)
-// 4. DB에 배치 쓰기 (트랜잭션)
+// 4. DB에 배치 쓰기 (트랜잭션 + high-water mark 갱신)
+// 5. 실패 시 재시도 (일시적 에러) 또는 명령 단위 격리 → dead-letter
 // =====================================================
 
+/// 재시도 대기 시간 (첫 번째)
+const DB_WRITER_BASE_BACKOFF: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// 재시도 대기 시간 (최대)
+const DB_WRITER_MAX_BACKOFF: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// 종료 중 일시적 에러 재시도 횟수
+///
+/// 평상시에는 일시적 에러를 무한 재시도하지만 (순서 보장),
+/// 종료 중에는 이 횟수 이후 dead-letter 파일로 내보내고 종료합니다.
+const DB_WRITER_SHUTDOWN_RETRIES: u32 = 5;
+
 /// DB Writer 스레드 메인 루프
-/// 
+///
 /// # Arguments
 /// * `db_rx` - DB 명령 수신 채널
 /// * `db_pool` - 데이터베이스 연결 풀
-/// 
+/// * `high_water` - DB에 이미 반영된 마지막 명령 위치 (`db_writer_state`)
+/// * `dead_letter_path` - DB에 dead-letter를 기록할 수 없을 때 사용할 파일
+///
 /// # 처리 흐름
 /// ```
 /// loop {
 ///     db_rx.recv() → DbCommand
 ///         ↓
+///     cursor 계산 (wal_seq, index)
+///         ↓
 ///     batch.push(cmd)
 ///         ↓
 ///     (10ms 또는 100개마다)
 ///         ↓
-///     db.execute_batch(&batch)
+///     flush_batch(&batch) + high-water mark 저장 (같은 트랜잭션)
+///         ↓
+///     실패 시:
+///       - 일시적 에러 (연결 끊김 등): 백오프 후 같은 배치 재시도 (순서 유지)
+///       - 그 외 에러: 명령 단위로 격리 실행 → 실패한 명령만 dead-letter
 /// }
 /// ```
-/// 
+///
 /// # 배치 전략
 /// - 시간 기반: 10ms마다 배치 쓰기
 /// - 크기 기반: 100개 모이면 즉시 쓰기
@@ -1398,49 +2469,49 @@ pub fn wal_thread_loop(
 pub fn db_writer_thread_loop(
     db_rx: Receiver<super::db_commands::DbCommand>,
     db_pool: PgPool,
+    high_water: DbWriteCursor,
+    dead_letter_path: std::path::PathBuf,
 ) {
-    use super::db_commands::DbCommand;
     use std::time::{Duration, Instant};
-    
+
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     // 1. 코어 고정 (Core 2, dev 환경만)
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-    
+
     let config = CoreConfig::from_env();
     if let Some(core) = config.db_writer_core {
         CoreConfig::set_core(Some(core));
     }
-    
+
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     // 2. 배치 변수 초기화
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-    
+
     let mut batch = Vec::new();
     let batch_size_limit = 100;
     let batch_time_limit = Duration::from_millis(10);
     let mut last_flush = Instant::now();
-    
+    let mut writer = DbBatchWriter::new(db_pool, high_water, dead_letter_path);
+
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     // 3. 메인 루프 (Tokio 런타임 필요)
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-    
+
     // Tokio 런타임 생성 (DB 작업은 async이므로)
     let rt = tokio::runtime::Runtime::new()
         .expect("Failed to create Tokio runtime for DB Writer");
-    
+
     rt.block_on(async {
         loop {
             // 채널이 닫혔는지 먼저 확인 (논블로킹, 즉시 반환)
             match db_rx.try_recv() {
                 Ok(cmd) => {
                     // 명령 수신
-                    batch.push(cmd);
-                    
+                    writer.push(&mut batch, cmd);
+
                     // 크기 기반 배치 쓰기 (100개 모이면)
                     if batch.len() >= batch_size_limit {
-                        if let Err(e) = flush_batch(&mut batch, &db_pool).await {
-                            eprintln!("Failed to flush DB batch: {}", e);
-                        }
+                        writer.flush(&mut batch, false).await;
                         last_flush = Instant::now();
                     }
                     continue; // 다음 루프로
@@ -1448,35 +2519,29 @@ pub fn db_writer_thread_loop(
                 Err(crossbeam::channel::TryRecvError::Disconnected) => {
                     // 채널이 닫힘 (정상 종료) - 즉시 감지
                     // 마지막 배치 쓰기
-                    if !batch.is_empty() {
-                        let _ = flush_batch(&mut batch, &db_pool).await;
-                    }
+                    writer.flush(&mut batch, true).await;
                     break;
                 }
                 Err(crossbeam::channel::TryRecvError::Empty) => {
                     // 채널이 비어있지만 아직 열려있음
                     // 타임아웃 설정 (10ms)
                     let timeout = batch_time_limit.saturating_sub(last_flush.elapsed());
-                    
+
                     if timeout == Duration::ZERO {
                         // 시간 기반 배치 쓰기 (10ms 경과)
                         if !batch.is_empty() {
-                            if let Err(e) = flush_batch(&mut batch, &db_pool).await {
-                                eprintln!("Failed to flush DB batch: {}", e);
-                            }
+                            writer.flush(&mut batch, false).await;
                             last_flush = Instant::now();
                         }
                         // 채널이 닫혔는지 다시 확인 (논블로킹)
                         match db_rx.try_recv() {
                             Ok(cmd) => {
-                                batch.push(cmd);
+                                writer.push(&mut batch, cmd);
                                 continue;
                             }
                             Err(crossbeam::channel::TryRecvError::Disconnected) => {
                                 // 채널이 닫힘
-                                if !batch.is_empty() {
-                                    let _ = flush_batch(&mut batch, &db_pool).await;
-                                }
+                                writer.flush(&mut batch, true).await;
                                 break;
                             }
                             Err(crossbeam::channel::TryRecvError::Empty) => {
@@ -1488,28 +2553,22 @@ pub fn db_writer_thread_loop(
                         // 타임아웃까지 대기
                         match db_rx.recv_timeout(timeout) {
                             Ok(cmd) => {
-                                batch.push(cmd);
+                                writer.push(&mut batch, cmd);
                                 if batch.len() >= batch_size_limit {
-                                    if let Err(e) = flush_batch(&mut batch, &db_pool).await {
-                                        eprintln!("Failed to flush DB batch: {}", e);
-                                    }
+                                    writer.flush(&mut batch, false).await;
                                     last_flush = Instant::now();
                                 }
                             }
                             Err(crossbeam::channel::RecvTimeoutError::Timeout) => {
                                 // 시간 기반 배치 쓰기 (10ms 경과)
                                 if !batch.is_empty() {
-                                    if let Err(e) = flush_batch(&mut batch, &db_pool).await {
-                                        eprintln!("Failed to flush DB batch: {}", e);
-                                    }
+                                    writer.flush(&mut batch, false).await;
                                     last_flush = Instant::now();
                                 }
                             }
                             Err(crossbeam::channel::RecvTimeoutError::Disconnected) => {
                                 // 채널이 닫힘 (정상 종료)
-                                if !batch.is_empty() {
-                                    let _ = flush_batch(&mut batch, &db_pool).await;
-                                }
+                                writer.flush(&mut batch, true).await;
                                 break;
                             }
                         }
@@ -1520,213 +2579,521 @@ pub fn db_writer_thread_loop(
     });
 }
 
-/// 배치를 DB에 쓰기 (async)
-/// 
-/// # Arguments
-/// * `batch` - DB 명령 배치
-/// * `db_pool` - 데이터베이스 연결 풀
-/// 
-/// # 처리 과정
-/// 1. 트랜잭션 시작
-/// 2. 각 명령 처리
-/// 3. 커밋
-async fn flush_batch(
-    batch: &mut Vec<super::db_commands::DbCommand>,
-    db_pool: &PgPool,
+/// DB Writer 배치 처리기
+///
+/// 명령 위치(cursor) 계산, 재시도, 명령 단위 격리, dead-letter 기록을 담당합니다.
+///
+/// # 멱등성
+/// - 배치 반영과 high-water mark 저장이 같은 트랜잭션 → 둘 다 반영되거나 둘 다 롤백
+/// - 주문/체결 INSERT는 ID 기반 upsert, 주문 상태는 `last_wal_seq`로 역행 방지
+///
+/// # 재시작
+/// WAL에서 DB 명령을 다시 만들지 않습니다 (WAL 레코드에 원장 사유/수수료 등이 없음).
+/// high-water mark는 재시작 후 WAL 시퀀스 시작값으로만 쓰입니다.
+/// 크래시로 반영되지 못한 명령은 자동 복구하지 않으므로
+/// WAL 파일에서 high-water mark 이후 레코드를 보고 수동으로 확인해야 합니다.
+struct DbBatchWriter {
+    /// 데이터베이스 연결 풀
+    db_pool: PgPool,
+    /// DB에 반영된 마지막 명령 위치
+    high_water: DbWriteCursor,
+    /// 마지막으로 수신한 명령 위치
+    last_received: DbWriteCursor,
+    /// dead-letter 파일 경로 (DB 기록 실패 시)
+    dead_letter_path: std::path::PathBuf,
+    /// 현재 배치를 쓴 뒤 보낼 Barrier ack
+    barriers: Vec<crossbeam::channel::Sender<DbWriteCursor>>,
+}
+
+impl DbBatchWriter {
+    fn new(db_pool: PgPool, high_water: DbWriteCursor, dead_letter_path: std::path::PathBuf) -> Self {
+        Self {
+            db_pool,
+            high_water,
+            last_received: DbWriteCursor::default(),
+            dead_letter_path,
+            barriers: Vec::new(),
+        }
+    }
+
+    /// 명령 수신: 위치 계산 후 배치에 추가
+    ///
+    /// Barrier는 배치가 비어 있으면 즉시, 아니면 다음 flush 후 ack합니다.
+    fn push(&mut self, batch: &mut Vec<PendingDbCommand>, command: super::db_commands::DbCommand) {
+        if let super::db_commands::DbCommand::Barrier(ack) = command {
+            if batch.is_empty() {
+                let _ = ack.send(self.high_water);
+            } else {
+                self.barriers.push(ack);
+            }
+            return;
+        }
+
+        let cursor = self.last_received.next_for(command.wal_seq());
+        self.last_received = cursor;
+        batch.push(PendingDbCommand { cursor, command });
+    }
+
+    /// 배치 쓰기 (재시도/격리 포함)
+    ///
+    /// # Arguments
+    /// * `batch` - 쓸 명령들 (처리 후 비워짐)
+    /// * `shutting_down` - 종료 중이면 재시도 횟수 제한
+    ///
+    /// # 처리
+    /// 1. 배치 전체를 한 트랜잭션으로 쓰기
+    /// 2. 일시적 에러 → 백오프 후 같은 배치 재시도 (뒤 명령이 앞지르지 않음)
+    /// 3. 그 외 에러 → 명령 하나씩 격리 실행 (실패한 명령만 dead-letter)
+    /// 4. 대기 중인 Barrier ack 전송
+    async fn flush(&mut self, batch: &mut Vec<PendingDbCommand>, shutting_down: bool) {
+        self.write_batch(batch, shutting_down).await;
+
+        for ack in self.barriers.drain(..) {
+            let _ = ack.send(self.high_water);
+        }
+    }
+
+    async fn write_batch(&mut self, batch: &mut Vec<PendingDbCommand>, shutting_down: bool) {
+        if batch.is_empty() {
+            return;
+        }
+
+        let mut attempt = 0u32;
+        loop {
+            attempt += 1;
+
+            let error = match flush_batch(batch, &self.db_pool).await {
+                Ok(()) => {
+                    if let Some(last) = batch.last() {
+                        self.high_water = last.cursor;
+                    }
+                    batch.clear();
+                    return;
+                }
+                Err(e) => e,
+            };
+
+            if !is_transient_db_error(&error) {
+                eprintln!(
+                    "[DB Writer] Failed to flush DB batch ({} commands): {:#}. Retrying commands one by one...",
+                    batch.len(), error
+                );
+                let pending = std::mem::take(batch);
+                for cmd in pending {
+                    self.apply_single(cmd, attempt, shutting_down).await;
+                }
+                return;
+            }
+
+            if shutting_down && attempt >= DB_WRITER_SHUTDOWN_RETRIES {
+                eprintln!(
+                    "[DB Writer] Giving up on {} commands during shutdown after {} attempts: {:#}",
+                    batch.len(), attempt, error
+                );
+                let error = format!("{:#}", error);
+                for cmd in batch.drain(..) {
+                    self.write_dead_letter_file(&cmd, &error, attempt);
+                }
+                return;
+            }
+
+            let backoff = retry_backoff(attempt);
+            eprintln!(
+                "[DB Writer] Transient error flushing DB batch (attempt {}), retrying in {:?}: {:#}",
+                attempt, backoff, error
+            );
+            tokio::time::sleep(backoff).await;
+        }
+    }
+
+    /// 명령 하나를 단독 트랜잭션으로 쓰기
+    ///
+    /// # Arguments
+    /// * `pending` - 쓸 명령
+    /// * `prior_attempts` - 배치 단위로 이미 시도한 횟수
+    /// * `shutting_down` - 종료 중 여부
+    async fn apply_single(&mut self, pending: PendingDbCommand, prior_attempts: u32, shutting_down: bool) {
+        let mut attempt = prior_attempts;
+        loop {
+            attempt += 1;
+
+            let result = async {
+                let mut tx = self.db_pool.begin().await
+                    .context("Failed to begin transaction")?;
+                apply_db_command(&mut tx, &pending.command).await?;
+                DbWriterStateRepository::save_high_water_mark(&mut tx, pending.cursor).await?;
+                tx.commit().await
+                    .context("Failed to commit transaction")?;
+                Ok::<(), anyhow::Error>(())
+            }.await;
+
+            let error = match result {
+                Ok(()) => {
+                    self.high_water = pending.cursor;
+                    return;
+                }
+                Err(e) => e,
+            };
+
+            if !is_transient_db_error(&error) {
+                // poison command → dead-letter
+                self.dead_letter(&pending, &format!("{:#}", error), attempt).await;
+                return;
+            }
+
+            if shutting_down && attempt >= prior_attempts + DB_WRITER_SHUTDOWN_RETRIES {
+                self.write_dead_letter_file(&pending, &format!("{:#}", error), attempt);
+                return;
+            }
+
+            tokio::time::sleep(retry_backoff(attempt - prior_attempts)).await;
+        }
+    }
+
+    /// dead-letter 기록 (DB 우선, 실패 시 파일)
+    ///
+    /// dead-letter 기록과 high-water mark 갱신을 같은 트랜잭션으로 처리하여
+    /// 재시작 시 같은 명령을 다시 시도하지 않도록 합니다.
+    async fn dead_letter(&mut self, pending: &PendingDbCommand, error: &str, attempts: u32) {
+        eprintln!(
+            "[DB Writer] Moving {} (wal_seq={}, index={}) to dead-letter after {} attempts: {}",
+            pending.command.kind(), pending.cursor.wal_seq, pending.cursor.index, attempts, error
+        );
+
+        let result = async {
+            let mut tx = self.db_pool.begin().await
+                .context("Failed to begin transaction")?;
+            DbWriterStateRepository::insert_dead_letter(&mut tx, pending, error, attempts).await?;
+            DbWriterStateRepository::save_high_water_mark(&mut tx, pending.cursor).await?;
+            tx.commit().await
+                .context("Failed to commit transaction")?;
+            Ok::<(), anyhow::Error>(())
+        }.await;
+
+        match result {
+            Ok(()) => self.high_water = pending.cursor,
+            Err(e) => {
+                eprintln!("[DB Writer] Failed to record dead-letter in database: {:#}", e);
+                self.write_dead_letter_file(pending, error, attempts);
+            }
+        }
+    }
+
+    /// dead-letter 파일에 기록 (JSON Lines)
+    ///
+    /// DB에 접근할 수 없을 때 사용하는 마지막 수단입니다.
+    /// high-water mark는 갱신되지 않으므로 재시작 후 복구 대상에 포함됩니다.
+    fn write_dead_letter_file(&self, pending: &PendingDbCommand, error: &str, attempts: u32) {
+        use std::io::Write;
+
+        let line = serde_json::json!({
+            "wal_seq": pending.cursor.wal_seq,
+            "command_index": pending.cursor.index,
+            "command_type": pending.command.kind(),
+            "payload": pending.command,
+            "error": error,
+            "attempts": attempts,
+            "created_at": chrono::Utc::now(),
+        });
+
+        let result = (|| -> Result<()> {
+            if let Some(dir) = self.dead_letter_path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.dead_letter_path)?;
+            writeln!(file, "{}", line)?;
+            file.sync_all()?;
+            Ok(())
+        })();
+
+        if let Err(e) = result {
+            // 파일에도 쓸 수 없으면 로그로만 남김 (명령 내용 포함)
+            eprintln!(
+                "[DB Writer] Failed to write dead-letter file {:?}: {:#}. Lost command: {}",
+                self.dead_letter_path, e, line
+            );
+        }
+    }
+}
+
+/// 재시도 대기 시간 계산 (지수 백오프)
+///
+/// 10ms, 20ms, 40ms, ... 최대 1초
+fn retry_backoff(attempt: u32) -> std::time::Duration {
+    let factor = 1u32 << attempt.saturating_sub(1).min(10);
+    DB_WRITER_BASE_BACKOFF
+        .saturating_mul(factor)
+        .min(DB_WRITER_MAX_BACKOFF)
+}
+
+/// 일시적 DB 에러 여부
+///
+/// 재시도하면 성공할 수 있는 에러 (연결 끊김, 풀 타임아웃, 직렬화 충돌 등)만 true.
+/// 제약조건 위반 같은 에러는 재시도해도 같은 결과이므로 false.
+///
+/// # SQLSTATE
+/// - 08xxx: 연결 에러
+/// - 40001: serialization_failure
+/// - 40P01: deadlock_detected
+/// - 53xxx: 리소스 부족
+/// - 57P0x: 서버 종료/재시작
+fn is_transient_db_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| match cause.downcast_ref::<sqlx::Error>() {
+        Some(sqlx::Error::Io(_))
+        | Some(sqlx::Error::Tls(_))
+        | Some(sqlx::Error::PoolTimedOut)
+        | Some(sqlx::Error::WorkerCrashed) => true,
+        Some(sqlx::Error::Database(db_error)) => db_error
+            .code()
+            .map(|code| {
+                code.starts_with("08")
+                    || code.starts_with("53")
+                    || code.starts_with("57P0")
+                    || code == "40001"
+                    || code == "40P01"
+            })
+            .unwrap_or(false),
+        _ => false,
+    })
+}
+
+/// DB 명령 하나를 트랜잭션 안에서 실행
+///
+/// # 멱등성
+/// - InsertOrder: `ON CONFLICT (id)` upsert
+/// - UpdateOrderStatus: `last_wal_seq`보다 오래된 명령은 무시
+/// - InsertTrade: `ON CONFLICT (id) DO NOTHING`
+/// - UpdateBalance: 증감 연산이므로 high-water mark로 중복 반영 방지
+///   (원장 전기도 같은 트랜잭션에서 기록)
+async fn apply_db_command(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    cmd: &super::db_commands::DbCommand,
 ) -> Result<()> {
     use super::db_commands::DbCommand;
-    
-    if batch.is_empty() {
-        return Ok(());
-    }
-    
-    // 트랜잭션 시작
-    let mut tx = db_pool.begin().await
-        .context("Failed to begin transaction")?;
-    
-    // 배치 정렬: InsertOrder를 먼저 처리 (외래키 제약조건을 위해)
-    // 1. InsertOrder (주문 먼저 생성)
-    // 2. UpdateOrderStatus (주문 상태 업데이트)
-    // 3. InsertTrade (체결 내역 - 주문이 있어야 함)
-    // 4. UpdateBalance (잔고 업데이트)
-    batch.sort_by(|a, b| {
-        let priority = |cmd: &DbCommand| match cmd {
-            DbCommand::InsertOrder { .. } => 1,
-            DbCommand::UpdateOrderStatus { .. } => 2,
-            DbCommand::InsertTrade { .. } => 3,
-            DbCommand::UpdateBalance { .. } => 4,
-        };
-        priority(a).cmp(&priority(b))
-    });
-    
-    // 각 명령 처리
-    for cmd in batch.drain(..) {
-        match cmd {
-            DbCommand::InsertOrder {
-                order_id,
-                user_id,
-                order_type,
-                order_side,
-                base_mint,
-                quote_mint,
-                price,
-                amount,
-                created_at,
-            } => {
-                // ID 생성기로 생성한 ID를 사용 (auto increment 사용 안 함)
-                // order_id가 0이면 에러 (ID 생성기가 제대로 작동하지 않음)
-                if order_id == 0 {
-                    return Err(anyhow::anyhow!(
-                        "Order ID is 0. ID generator may not be initialized properly."
-                    ));
-                }
-                
-                // 지정된 ID로 INSERT
+
+    match cmd {
+        DbCommand::InsertOrder {
+            wal_seq,
+            order_id,
+            user_id,
+            order_type,
+            order_side,
+            base_mint,
+            quote_mint,
+            price,
+            amount,
+            created_at,
+        } => {
+            // ID 생성기로 생성한 ID를 사용 (auto increment 사용 안 함)
+            // order_id가 0이면 에러 (ID 생성기가 제대로 작동하지 않음)
+            if *order_id == 0 {
+                return Err(anyhow::anyhow!(
+                    "Order ID is 0. ID generator may not be initialized properly."
+                ));
+            }
+
+            // 지정된 ID로 INSERT
+            sqlx::query(
+                r#"
+                INSERT INTO orders (
+                    id, user_id, order_type, order_side, base_mint, quote_mint,
+                    price, amount, filled_amount, filled_quote_amount, status, created_at, updated_at,
+                    last_wal_seq
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                ON CONFLICT (id) DO UPDATE SET
+                    updated_at = $13
+                "#
+            )
+            .bind(*order_id as i64)
+            .bind(*user_id as i64)
+            .bind(order_type)
+            .bind(order_side)
+            .bind(base_mint)
+            .bind(quote_mint)
+            .bind(price)
+            .bind(amount)
+            .bind(rust_decimal::Decimal::ZERO)  // filled_amount
+            .bind(rust_decimal::Decimal::ZERO)  // filled_quote_amount
+            .bind("pending")  // status
+            .bind(created_at)
+            .bind(created_at)
+            .bind(*wal_seq as i64)
+            .execute(&mut **tx)
+            .await
+            .context("Failed to insert order")?;
+        }
+
+        DbCommand::UpdateOrderStatus {
+            wal_seq,
+            order_id,
+            status,
+            filled_amount,
+            filled_quote_amount,
+        } => {
+            // 취소 시에는 filled_quote_amount를 변경하지 않음 (기존 값 유지)
+            // last_wal_seq보다 오래된 명령은 무시 (재생 시 최신 상태 보호)
+            if status == "cancelled" {
                 sqlx::query(
                     r#"
-                    INSERT INTO orders (
-                        id, user_id, order_type, order_side, base_mint, quote_mint,
-                        price, amount, filled_amount, filled_quote_amount, status, created_at, updated_at
-                    )
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-                    ON CONFLICT (id) DO UPDATE SET
-                        updated_at = $13
+                    UPDATE orders
+                    SET status = $1, filled_amount = $2, updated_at = $3, last_wal_seq = $5
+                    WHERE id = $4 AND last_wal_seq <= $5
                     "#
                 )
-                .bind(order_id as i64)
-                .bind(user_id as i64)
-                .bind(&order_type)
-                .bind(&order_side)
-                .bind(&base_mint)
-                .bind(&quote_mint)
-                .bind(&price)
-                .bind(&amount)
-                .bind(rust_decimal::Decimal::ZERO)  // filled_amount
-                .bind(rust_decimal::Decimal::ZERO)  // filled_quote_amount
-                .bind("pending")  // status
-                .bind(created_at)
-                .bind(created_at)
-                .execute(&mut *tx)
+                .bind(status)
+                .bind(filled_amount)
+                .bind(chrono::Utc::now())
+                .bind(*order_id as i64)
+                .bind(*wal_seq as i64)
+                .execute(&mut **tx)
                 .await
-                .context("Failed to insert order")?;
+                .context("Failed to update order status")?;
+            } else {
+                sqlx::query(
+                    r#"
+                    UPDATE orders
+                    SET status = $1, filled_amount = $2, filled_quote_amount = $3, updated_at = $4, last_wal_seq = $6
+                    WHERE id = $5 AND last_wal_seq <= $6
+                    "#
+                )
+                .bind(status)
+                .bind(filled_amount)
+                .bind(filled_quote_amount)
+                .bind(chrono::Utc::now())
+                .bind(*order_id as i64)
+                .bind(*wal_seq as i64)
+                .execute(&mut **tx)
+                .await
+                .context("Failed to update order status")?;
             }
-            
-            DbCommand::UpdateOrderStatus {
-                order_id,
-                status,
-                filled_amount,
-                filled_quote_amount,
-            } => {
-                // 취소 시에는 filled_quote_amount를 변경하지 않음 (기존 값 유지)
-                if status == "cancelled" {
-                    sqlx::query(
-                        r#"
-                        UPDATE orders
-                        SET status = $1, filled_amount = $2, updated_at = $3
-                        WHERE id = $4
-                        "#
-                    )
-                    .bind(&status)
-                    .bind(&filled_amount)
-                    .bind(chrono::Utc::now())
-                    .bind(order_id as i64)
-                    .execute(&mut *tx)
-                    .await
-                    .context("Failed to update order status")?;
-                } else {
-                    sqlx::query(
-                        r#"
-                        UPDATE orders
-                        SET status = $1, filled_amount = $2, filled_quote_amount = $3, updated_at = $4
-                        WHERE id = $5
-                        "#
-                    )
-                    .bind(&status)
-                    .bind(&filled_amount)
-                    .bind(&filled_quote_amount)
-                    .bind(chrono::Utc::now())
-                    .bind(order_id as i64)
-                    .execute(&mut *tx)
-                    .await
-                    .context("Failed to update order status")?;
-                }
+        }
+
+        DbCommand::InsertTrade {
+            trade_id,
+            buy_order_id,
+            sell_order_id,
+            buyer_id,
+            seller_id,
+            price,
+            amount,
+            base_mint,
+            quote_mint,
+            timestamp,
+            ..
+        } => {
+            // buy_order_id, sell_order_id가 0이면 스킵 (주문이 아직 DB에 INSERT되지 않음)
+            if *buy_order_id == 0 || *sell_order_id == 0 {
+                eprintln!(
+                    "[DB Writer] Skipping trade insert: buy_order_id={}, sell_order_id={} (orders not yet inserted)",
+                    buy_order_id, sell_order_id
+                );
+                return Ok(());
             }
-            
-            DbCommand::InsertTrade {
-                trade_id,
-                buy_order_id,
-                sell_order_id,
-                buyer_id,
-                seller_id,
-                price,
-                amount,
-                base_mint,
-                quote_mint,
-                timestamp,
-            } => {
-                // buy_order_id, sell_order_id가 0이면 스킵 (주문이 아직 DB에 INSERT되지 않음)
-                if buy_order_id == 0 || sell_order_id == 0 {
-                    eprintln!(
-                        "[DB Writer] Skipping trade insert: buy_order_id={}, sell_order_id={} (orders not yet inserted)",
-                        buy_order_id, sell_order_id
-                    );
-                    continue;
-                }
-                
-                // ID 생성기로 생성한 trade_id 사용
+
+            // ID 생성기로 생성한 trade_id 사용
+            sqlx::query(
+                r#"
+                INSERT INTO trades (
+                    id, buy_order_id, sell_order_id, buyer_id, seller_id,
+                    price, amount, base_mint, quote_mint, created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                ON CONFLICT (id) DO NOTHING
+                "#
+            )
+            .bind(*trade_id as i64)
+            .bind(*buy_order_id as i64)
+            .bind(*sell_order_id as i64)
+            .bind(*buyer_id as i64)
+            .bind(*seller_id as i64)
+            .bind(price)
+            .bind(amount)
+            .bind(base_mint)
+            .bind(quote_mint)
+            .bind(timestamp)
+            .execute(&mut **tx)
+            .await
+            .with_context(|| format!(
+                "Failed to insert trade: trade_id={}, buy_order_id={}, sell_order_id={}, buyer_id={}, seller_id={}",
+                trade_id, buy_order_id, sell_order_id, buyer_id, seller_id
+            ))?;
+        }
+
+        DbCommand::UpdateBalance {
+            wal_seq,
+            user_id,
+            mint,
+            available_delta,
+            locked_delta,
+            reason,
+            reference,
+        } => {
+            // 배치 트랜잭션 안에서 실행 (high-water mark와 원자적)
+            // available 증감이 있으면 레코드가 없을 때 생성, locked만 있으면 기존 레코드 필요
+            let result = if available_delta.is_some() {
+                sqlx::query(
+                    r#"
+                    INSERT INTO user_balances (user_id, mint_address, available, locked, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $5)
+                    ON CONFLICT (user_id, mint_address)
+                    DO UPDATE SET
+                        available = user_balances.available + $3,
+                        locked = user_balances.locked + $4,
+                        updated_at = $5
+                    "#
+                )
+                .bind(*user_id as i64)
+                .bind(mint)
+                .bind(available_delta.unwrap_or(Decimal::ZERO))
+                .bind(locked_delta.unwrap_or(Decimal::ZERO))
+                .bind(chrono::Utc::now())
+                .execute(&mut **tx)
+                .await
+            } else if let Some(locked_delta) = locked_delta {
                 sqlx::query(
                     r#"
-                    INSERT INTO trades (
-                        id, buy_order_id, sell_order_id, buyer_id, seller_id,
-                        price, amount, base_mint, quote_mint, created_at
-                    )
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-                    ON CONFLICT (id) DO NOTHING
+                    UPDATE user_balances
+                    SET locked = locked + $1, updated_at = $2
+                    WHERE user_id = $3 AND mint_address = $4
                     "#
                 )
-                .bind(trade_id as i64)
-                .bind(buy_order_id as i64)
-                .bind(sell_order_id as i64)
-                .bind(buyer_id as i64)
-                .bind(seller_id as i64)
-                .bind(&price)
-                .bind(&amount)
-                .bind(&base_mint)
-                .bind(&quote_mint)
-                .bind(timestamp)
-                .execute(&mut *tx)
+                .bind(locked_delta)
+                .bind(chrono::Utc::now())
+                .bind(*user_id as i64)
+                .bind(mint)
+                .execute(&mut **tx)
                 .await
-                .with_context(|| format!(
-                    "Failed to insert trade: trade_id={}, buy_order_id={}, sell_order_id={}, buyer_id={}, seller_id={}",
-                    trade_id, buy_order_id, sell_order_id, buyer_id, seller_id
-                ))?;
+            } else {
+                // 변경 없음
+                return Ok(());
+            };
+
+            let result = result.context("Failed to update balance")?;
+            if result.rows_affected() == 0 {
+                return Err(anyhow::anyhow!(
+                    "Balance not found: user_id={}, mint={}", user_id, mint
+                ));
             }
-            
-            DbCommand::UpdateBalance {
-                user_id,
+
+            let postings = super::db_batch::LedgerPostingRow::for_balance_update(
+                *wal_seq,
+                *user_id,
                 mint,
-                available_delta,
-                locked_delta,
-            } => {
-                use crate::shared::database::repositories::cex::UserBalanceRepository;
-                use crate::domains::cex::models::balance::UserBalanceUpdate;
-                
-                let balance_repo = UserBalanceRepository::new(db_pool.clone());
-                let update = UserBalanceUpdate {
-                    available_delta,
-                    locked_delta,
-                };
-                
-                balance_repo.update_balance(user_id, &mint, &update).await
-                    .context("Failed to update balance")?;
-            }
+                *available_delta,
+                *locked_delta,
+                *reason,
+                reference,
+            );
+            super::db_batch::insert_ledger(tx, &postings).await?;
         }
+
+        // DbBatchWriter::push에서 처리 (배치에 들어가지 않음)
+        DbCommand::Barrier(_) => {}
     }
-    
-    // 트랜잭션 커밋
-    tx.commit().await
-        .context("Failed to commit transaction")?;
-    
+
     Ok(())
 }
-
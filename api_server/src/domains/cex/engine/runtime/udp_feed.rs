@@ -0,0 +1,768 @@
+// =====================================================
+// UDP Feed - 바이너리 시세 멀티캐스트 (Core 3)
+// =====================================================
+// 역할: 코로케이션 전략용 저지연 시세 피드 (WebSocket JSON 대체)
+//
+// 구조:
+// - 피드 스레드 (UDP Feed 코어 고정): 이벤트 버스 → 바이너리 패킷 → UDP 멀티캐스트/유니캐스트
+// - TCP 복구 스레드: 스냅샷 / 재전송 요청 처리 (gap recovery)
+//
+// 패킷 (little-endian, UDP 데이터그램 1개 = 패킷 1개):
+// - 헤더 20바이트: magic u16 | version u8 | type u8 | seq u64 | timestamp_ms i64
+// - 본문: 메시지 타입별 (`FeedMessage::encode` 참고)
+// - Decimal은 `Decimal::serialize()` 16바이트 (정밀도 손실 없음)
+//
+// 시퀀스:
+// - 피드 전체에서 1씩 증가 (book_level, trade, reset)
+// - heartbeat는 마지막 seq를 담아 1초마다 전송 (마지막 패킷 유실 감지용, seq 증가 없음)
+// - reset 수신 시 (피드 재구성) TCP 스냅샷을 다시 받아야 함
+//
+// TCP 복구 (요청 1줄 → u32 길이 prefix 프레임들 → 연결 종료):
+// - "SNAPSHOT\n" → book_level... + snapshot_end (모두 스냅샷 seq)
+// - "RETRANSMIT <from> <to>\n" → 해당 범위 패킷 원본 (없으면 retransmit_unavailable)
+// =====================================================
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use anyhow::{bail, Context, Result};
+use parking_lot::{Mutex, RwLock};
+use rust_decimal::Decimal;
+
+use crate::domains::cex::engine::event_bus::{EventBus, SequencedEvent};
+use crate::domains::cex::engine::orderbook::OrderBook;
+use crate::domains::cex::engine::types::{EngineEvent, TradingPair};
+
+use super::config::CoreConfig;
+
+/// 패킷 매직 넘버 ("CX")
+pub const FEED_MAGIC: u16 = 0x4358;
+
+/// 패킷 포맷 버전
+pub const FEED_VERSION: u8 = 1;
+
+/// 패킷 헤더 크기 (바이트)
+pub const FEED_HEADER_LEN: usize = 20;
+
+/// 피드 전용 이벤트 구독 큐 크기 (피드가 끊기지 않도록 넉넉하게)
+const FEED_SUBSCRIBER_CAPACITY: usize = 65_536;
+
+/// heartbeat 주기
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 이벤트가 없을 때 sleep 전까지 스핀 횟수
+const IDLE_SPINS: u32 = 1_000;
+
+/// 이벤트가 없을 때 sleep 시간
+const IDLE_SLEEP: Duration = Duration::from_micros(100);
+
+/// TCP 복구 연결 타임아웃
+const RECOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// UDP 피드 설정
+///
+/// # 환경 변수
+/// * `UDP_FEED_ENABLED` - "true"면 활성화 (기본값: 비활성)
+/// * `UDP_FEED_GROUP` - 멀티캐스트 그룹 주소 (기본값: 239.10.0.1:30001)
+/// * `UDP_FEED_TARGETS` - 유니캐스트 대상 목록 (쉼표 구분, 설정 시 멀티캐스트 대신 사용)
+/// * `UDP_FEED_TTL` - 멀티캐스트 TTL (기본값: 1, 같은 서브넷)
+/// * `UDP_FEED_TCP_ADDR` - 스냅샷/재전송 TCP 주소 (기본값: 0.0.0.0:30002)
+/// * `UDP_FEED_RETRANSMIT_CAPACITY` - 재전송용으로 보관할 패킷 수 (기본값: 65536)
+#[derive(Debug, Clone)]
+pub struct UdpFeedConfig {
+    /// 전송 대상 (멀티캐스트 그룹 1개 또는 유니캐스트 목록)
+    pub targets: Vec<SocketAddr>,
+    /// 멀티캐스트 TTL
+    pub multicast_ttl: u32,
+    /// TCP 복구 서비스 주소
+    pub tcp_addr: SocketAddr,
+    /// 재전송 버퍼 크기
+    pub retransmit_capacity: usize,
+}
+
+impl UdpFeedConfig {
+    /// 환경 변수에서 설정 읽기
+    ///
+    /// # Returns
+    /// 비활성화되었거나 주소가 잘못되었으면 None (경고 출력)
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("UDP_FEED_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let targets = match std::env::var("UDP_FEED_TARGETS") {
+            Ok(list) if !list.trim().is_empty() => list
+                .split(',')
+                .map(|addr| addr.trim().parse::<SocketAddr>())
+                .collect::<Result<Vec<_>, _>>(),
+            _ => std::env::var("UDP_FEED_GROUP")
+                .unwrap_or_else(|_| "239.10.0.1:30001".to_string())
+                .parse::<SocketAddr>()
+                .map(|addr| vec![addr]),
+        };
+        let targets = match targets {
+            Ok(targets) => targets,
+            Err(e) => {
+                eprintln!("[UDP Feed] Invalid UDP_FEED_GROUP/UDP_FEED_TARGETS ({}), feed disabled", e);
+                return None;
+            }
+        };
+
+        let tcp_addr = match std::env::var("UDP_FEED_TCP_ADDR")
+            .unwrap_or_else(|_| "0.0.0.0:30002".to_string())
+            .parse::<SocketAddr>()
+        {
+            Ok(addr) => addr,
+            Err(e) => {
+                eprintln!("[UDP Feed] Invalid UDP_FEED_TCP_ADDR ({}), feed disabled", e);
+                return None;
+            }
+        };
+
+        let multicast_ttl = std::env::var("UDP_FEED_TTL")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(1);
+        let retransmit_capacity = std::env::var("UDP_FEED_RETRANSMIT_CAPACITY")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(65_536);
+
+        Some(Self {
+            targets,
+            multicast_ttl,
+            tcp_addr,
+            retransmit_capacity,
+        })
+    }
+}
+
+/// 피드 메시지 (패킷 본문)
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedMessage {
+    /// 호가 레벨 변경 (type 1, 절대값, amount 0이면 삭제)
+    ///
+    /// 본문: pair | side u8 (0=buy, 1=sell) | price dec | amount dec | order_count u32
+    BookLevel {
+        trading_pair: TradingPair,
+        side: String,
+        price: Decimal,
+        amount: Decimal,
+        order_count: u32,
+    },
+
+    /// 체결 (type 2)
+    ///
+    /// 본문: pair | price dec | amount dec | buy_order_id u64 | sell_order_id u64
+    Trade {
+        trading_pair: TradingPair,
+        price: Decimal,
+        amount: Decimal,
+        buy_order_id: u64,
+        sell_order_id: u64,
+    },
+
+    /// 피드 재구성 (type 3, 본문 없음) → TCP 스냅샷 재요청 필요
+    Reset,
+
+    /// 연결 유지 (type 4, 본문 없음, seq = 마지막 발행 seq)
+    Heartbeat,
+
+    /// 스냅샷 끝 (type 5, TCP 전용)
+    ///
+    /// 본문: level_count u32
+    SnapshotEnd { level_count: u32 },
+
+    /// 재전송 불가 (type 6, TCP 전용) → 스냅샷으로 복구
+    ///
+    /// 본문: first_available u64 (보관 중인 가장 오래된 seq, 없으면 0)
+    RetransmitUnavailable { first_available: u64 },
+}
+
+impl FeedMessage {
+    fn type_id(&self) -> u8 {
+        match self {
+            FeedMessage::BookLevel { .. } => 1,
+            FeedMessage::Trade { .. } => 2,
+            FeedMessage::Reset => 3,
+            FeedMessage::Heartbeat => 4,
+            FeedMessage::SnapshotEnd { .. } => 5,
+            FeedMessage::RetransmitUnavailable { .. } => 6,
+        }
+    }
+
+    /// 엔진 이벤트 → 피드 메시지 (시세와 무관한 이벤트면 None)
+    fn from_event(event: &EngineEvent) -> Option<Self> {
+        match event {
+            EngineEvent::BookLevelChanged { trading_pair, side, price, total_amount, order_count } => {
+                Some(FeedMessage::BookLevel {
+                    trading_pair: trading_pair.clone(),
+                    side: side.clone(),
+                    price: *price,
+                    amount: *total_amount,
+                    order_count: *order_count as u32,
+                })
+            }
+            EngineEvent::TradeExecuted { match_result, .. } => Some(FeedMessage::Trade {
+                trading_pair: TradingPair::new(
+                    match_result.base_mint.clone(),
+                    match_result.quote_mint.clone(),
+                ),
+                price: match_result.price,
+                amount: match_result.amount,
+                buy_order_id: match_result.buy_order_id,
+                sell_order_id: match_result.sell_order_id,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// 피드 패킷 (헤더 + 메시지)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedPacket {
+    /// 피드 시퀀스
+    pub seq: u64,
+    /// 시간 (밀리초)
+    pub timestamp: i64,
+    /// 메시지
+    pub message: FeedMessage,
+}
+
+impl FeedPacket {
+    /// 바이너리 인코딩
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(96);
+        buf.extend_from_slice(&FEED_MAGIC.to_le_bytes());
+        buf.push(FEED_VERSION);
+        buf.push(self.message.type_id());
+        buf.extend_from_slice(&self.seq.to_le_bytes());
+        buf.extend_from_slice(&self.timestamp.to_le_bytes());
+        debug_assert_eq!(buf.len(), FEED_HEADER_LEN);
+
+        match &self.message {
+            FeedMessage::BookLevel { trading_pair, side, price, amount, order_count } => {
+                put_pair(&mut buf, trading_pair);
+                buf.push(if side == "buy" { 0 } else { 1 });
+                buf.extend_from_slice(&price.serialize());
+                buf.extend_from_slice(&amount.serialize());
+                buf.extend_from_slice(&order_count.to_le_bytes());
+            }
+            FeedMessage::Trade { trading_pair, price, amount, buy_order_id, sell_order_id } => {
+                put_pair(&mut buf, trading_pair);
+                buf.extend_from_slice(&price.serialize());
+                buf.extend_from_slice(&amount.serialize());
+                buf.extend_from_slice(&buy_order_id.to_le_bytes());
+                buf.extend_from_slice(&sell_order_id.to_le_bytes());
+            }
+            FeedMessage::Reset | FeedMessage::Heartbeat => {}
+            FeedMessage::SnapshotEnd { level_count } => {
+                buf.extend_from_slice(&level_count.to_le_bytes());
+            }
+            FeedMessage::RetransmitUnavailable { first_available } => {
+                buf.extend_from_slice(&first_available.to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    /// 바이너리 디코딩 (클라이언트/테스트용)
+    ///
+    /// # Returns
+    /// * `Err` - 매직/버전 불일치, 알 수 없는 타입, 길이 부족
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < FEED_HEADER_LEN {
+            bail!("Packet is shorter than the {}-byte header", FEED_HEADER_LEN);
+        }
+        let mut reader = PacketReader { bytes, pos: 0 };
+        if u16::from_le_bytes(reader.array()?) != FEED_MAGIC {
+            bail!("Invalid feed magic");
+        }
+        let version = reader.u8()?;
+        if version != FEED_VERSION {
+            bail!("Unsupported feed version {}", version);
+        }
+        let type_id = reader.u8()?;
+        let seq = u64::from_le_bytes(reader.array()?);
+        let timestamp = i64::from_le_bytes(reader.array()?);
+
+        let message = match type_id {
+            1 => FeedMessage::BookLevel {
+                trading_pair: reader.pair()?,
+                side: if reader.u8()? == 0 { "buy" } else { "sell" }.to_string(),
+                price: Decimal::deserialize(reader.array()?),
+                amount: Decimal::deserialize(reader.array()?),
+                order_count: u32::from_le_bytes(reader.array()?),
+            },
+            2 => FeedMessage::Trade {
+                trading_pair: reader.pair()?,
+                price: Decimal::deserialize(reader.array()?),
+                amount: Decimal::deserialize(reader.array()?),
+                buy_order_id: u64::from_le_bytes(reader.array()?),
+                sell_order_id: u64::from_le_bytes(reader.array()?),
+            },
+            3 => FeedMessage::Reset,
+            4 => FeedMessage::Heartbeat,
+            5 => FeedMessage::SnapshotEnd {
+                level_count: u32::from_le_bytes(reader.array()?),
+            },
+            6 => FeedMessage::RetransmitUnavailable {
+                first_available: u64::from_le_bytes(reader.array()?),
+            },
+            other => bail!("Unknown feed message type {}", other),
+        };
+
+        Ok(Self { seq, timestamp, message })
+    }
+}
+
+/// 거래쌍 인코딩 (u8 길이 + UTF-8, base → quote)
+fn put_pair(buf: &mut Vec<u8>, trading_pair: &TradingPair) {
+    for mint in [&trading_pair.base_mint, &trading_pair.quote_mint] {
+        let bytes = &mint.as_bytes()[..mint.len().min(u8::MAX as usize)];
+        buf.push(bytes.len() as u8);
+        buf.extend_from_slice(bytes);
+    }
+}
+
+/// 패킷 디코딩용 커서
+struct PacketReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl PacketReader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8]> {
+        let end = self.pos + len;
+        if end > self.bytes.len() {
+            bail!("Truncated feed packet");
+        }
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn pair(&mut self) -> Result<TradingPair> {
+        let mut mints = Vec::with_capacity(2);
+        for _ in 0..2 {
+            let len = self.u8()? as usize;
+            mints.push(String::from_utf8(self.take(len)?.to_vec()).context("Invalid mint")?);
+        }
+        let quote_mint = mints.pop().unwrap_or_default();
+        let base_mint = mints.pop().unwrap_or_default();
+        Ok(TradingPair::new(base_mint, quote_mint))
+    }
+}
+
+/// 한 방향의 호가 (가격 → (수량, 주문 수))
+type FeedLevels = BTreeMap<Decimal, (Decimal, u32)>;
+
+/// 피드 상태 (피드 스레드가 갱신, TCP 복구 스레드가 조회)
+///
+/// 호가 상태와 재전송 버퍼가 같은 seq 기준이므로 스냅샷 이후 패킷으로 정확히 이어받을 수 있습니다.
+pub struct FeedState {
+    /// 마지막 발행 seq
+    seq: u64,
+    /// 거래쌍별 호가 ([매수, 매도])
+    books: HashMap<TradingPair, [FeedLevels; 2]>,
+    /// 재전송 버퍼 (seq 오름차순 인코딩 패킷)
+    history: VecDeque<(u64, Arc<Vec<u8>>)>,
+    /// 재전송 버퍼 최대 크기
+    capacity: usize,
+}
+
+impl FeedState {
+    /// 새 피드 상태 생성
+    ///
+    /// # Arguments
+    /// * `capacity` - 재전송 버퍼 크기
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            seq: 0,
+            books: HashMap::new(),
+            history: VecDeque::with_capacity(capacity.min(65_536)),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// 마지막 발행 seq
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// 메시지 발행 (seq 부여 + 호가 반영 + 재전송 버퍼 저장)
+    ///
+    /// # Returns
+    /// 전송할 인코딩 패킷
+    pub fn publish(&mut self, message: FeedMessage, timestamp: i64) -> Arc<Vec<u8>> {
+        if let FeedMessage::BookLevel { trading_pair, side, price, amount, order_count } = &message {
+            self.set_level(trading_pair, side, *price, *amount, *order_count);
+        }
+
+        self.seq += 1;
+        let packet = Arc::new(FeedPacket { seq: self.seq, timestamp, message }.encode());
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back((self.seq, Arc::clone(&packet)));
+        packet
+    }
+
+    /// 엔진 오더북 기준으로 호가 재구성 후 reset 발행
+    ///
+    /// # Arguments
+    /// * `levels` - `BookLevelChanged` 이벤트 목록 (발행되지 않은 스냅샷)
+    pub fn reset(&mut self, levels: &[EngineEvent], timestamp: i64) -> Arc<Vec<u8>> {
+        self.books.clear();
+        for level in levels {
+            if let Some(FeedMessage::BookLevel { trading_pair, side, price, amount, order_count }) =
+                FeedMessage::from_event(level)
+            {
+                self.set_level(&trading_pair, &side, price, amount, order_count);
+            }
+        }
+        self.publish(FeedMessage::Reset, timestamp)
+    }
+
+    /// 현재 호가 스냅샷 패킷 (모두 현재 seq, 마지막은 snapshot_end)
+    pub fn snapshot(&self, timestamp: i64) -> Vec<Vec<u8>> {
+        let mut packets = Vec::new();
+        for (trading_pair, sides) in &self.books {
+            for (side, levels) in ["buy", "sell"].iter().zip(sides.iter()) {
+                for (price, (amount, order_count)) in levels {
+                    let message = FeedMessage::BookLevel {
+                        trading_pair: trading_pair.clone(),
+                        side: side.to_string(),
+                        price: *price,
+                        amount: *amount,
+                        order_count: *order_count,
+                    };
+                    packets.push(FeedPacket { seq: self.seq, timestamp, message }.encode());
+                }
+            }
+        }
+
+        let message = FeedMessage::SnapshotEnd { level_count: packets.len() as u32 };
+        packets.push(FeedPacket { seq: self.seq, timestamp, message }.encode());
+        packets
+    }
+
+    /// 재전송 패킷 조회 (from..=to)
+    ///
+    /// # Returns
+    /// * `Ok(packets)` - 요청 범위 원본 패킷 (to가 마지막 seq를 넘으면 마지막 seq까지)
+    /// * `Err(first_available)` - 범위 시작이 버퍼에서 밀려남
+    pub fn retransmit(&self, from: u64, to: u64) -> std::result::Result<Vec<Arc<Vec<u8>>>, u64> {
+        let first_available = self.history.front().map(|(seq, _)| *seq).unwrap_or(0);
+        if from == 0 || from < first_available || from > self.seq {
+            return Err(first_available);
+        }
+
+        let offset = (from - first_available) as usize;
+        Ok(self
+            .history
+            .iter()
+            .skip(offset)
+            .take_while(|(seq, _)| *seq <= to)
+            .map(|(_, packet)| Arc::clone(packet))
+            .collect())
+    }
+
+    fn set_level(&mut self, trading_pair: &TradingPair, side: &str, price: Decimal, amount: Decimal, order_count: u32) {
+        let sides = self.books.entry(trading_pair.clone()).or_default();
+        let levels = &mut sides[if side == "buy" { 0 } else { 1 }];
+        if amount.is_zero() || order_count == 0 {
+            levels.remove(&price);
+        } else {
+            levels.insert(price, (amount, order_count));
+        }
+    }
+}
+
+// =====================================================
+// 피드 스레드 루프
+// =====================================================
+// 처리 과정:
+// 1. 코어 고정 (UDP Feed 코어, 설정된 경우)
+// 2. 이벤트 버스 구독 → 오더북 스냅샷으로 상태 재구성 → reset 발행
+// 3. 이벤트 → 바이너리 패킷 → 모든 대상에 전송
+// 4. 이벤트가 없으면 잠시 스핀 후 sleep, 1초마다 heartbeat
+// 5. 버스에서 해제되면 (느린 구독자) 2번부터 다시
+// =====================================================
+
+/// UDP 피드 스레드 메인 루프
+///
+/// # Arguments
+/// * `config` - 피드 설정
+/// * `state` - 피드 상태 (TCP 복구 스레드와 공유)
+/// * `events` - 엔진 이벤트 버스
+/// * `orderbooks` - 엔진 오더북 (재구성용)
+/// * `running` - 실행 플래그 (false가 되면 종료)
+pub fn udp_feed_thread_loop(
+    config: UdpFeedConfig,
+    state: Arc<Mutex<FeedState>>,
+    events: Arc<EventBus>,
+    orderbooks: Arc<RwLock<HashMap<TradingPair, OrderBook>>>,
+    running: Arc<AtomicBool>,
+) {
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    // 1. 코어 고정 (Core 3, dev 환경만)
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+    let core_config = CoreConfig::from_env();
+    if let Some(core) = core_config.udp_feed_core {
+        CoreConfig::set_core(Some(core));
+    }
+
+    let socket = match open_socket(&config) {
+        Ok(socket) => socket,
+        Err(e) => {
+            eprintln!("[UDP Feed] Failed to open socket: {:#}", e);
+            return;
+        }
+    };
+    eprintln!("[UDP Feed] Publishing to {:?}", config.targets);
+
+    let send = |packet: &[u8]| {
+        for target in &config.targets {
+            if let Err(e) = socket.send_to(packet, target) {
+                eprintln!("[UDP Feed] Send to {} failed: {}", target, e);
+            }
+        }
+    };
+
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    // 2. 구독 + 이벤트 루프
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+    while running.load(Ordering::Relaxed) {
+        let mut subscription = events.subscribe_with_capacity("udp_feed", FEED_SUBSCRIBER_CAPACITY);
+        let levels = super::engine::collect_book_levels(&orderbooks.read());
+        let packet = state.lock().reset(&levels, chrono::Utc::now().timestamp_millis());
+        send(&packet);
+
+        let mut last_sent = Instant::now();
+        let mut idle_spins = 0u32;
+
+        while running.load(Ordering::Relaxed) {
+            let event: Arc<SequencedEvent> = match subscription.try_recv() {
+                Ok(Some(event)) => event,
+                Ok(None) => {
+                    if last_sent.elapsed() >= HEARTBEAT_INTERVAL {
+                        let seq = state.lock().seq();
+                        let heartbeat = FeedPacket {
+                            seq,
+                            timestamp: chrono::Utc::now().timestamp_millis(),
+                            message: FeedMessage::Heartbeat,
+                        };
+                        send(&heartbeat.encode());
+                        last_sent = Instant::now();
+                    }
+                    if idle_spins < IDLE_SPINS {
+                        idle_spins += 1;
+                        std::hint::spin_loop();
+                    } else {
+                        std::thread::sleep(IDLE_SLEEP);
+                    }
+                    continue;
+                }
+                Err(_) => {
+                    eprintln!("[UDP Feed] Event subscription closed, rebuilding feed state");
+                    break;
+                }
+            };
+            idle_spins = 0;
+
+            if let Some(message) = FeedMessage::from_event(&event.event) {
+                let packet = state.lock().publish(message, event.timestamp);
+                send(&packet);
+                last_sent = Instant::now();
+            }
+        }
+    }
+
+    eprintln!("[UDP Feed] Thread stopped");
+}
+
+/// UDP 송신 소켓 생성 (멀티캐스트 대상이면 TTL 설정)
+fn open_socket(config: &UdpFeedConfig) -> Result<UdpSocket> {
+    let socket = UdpSocket::bind("0.0.0.0:0").context("Failed to bind UDP socket")?;
+    if config.targets.iter().any(|target| target.ip().is_multicast()) {
+        socket
+            .set_multicast_ttl_v4(config.multicast_ttl)
+            .context("Failed to set multicast TTL")?;
+        socket
+            .set_multicast_loop_v4(true)
+            .context("Failed to enable multicast loopback")?;
+    }
+    Ok(socket)
+}
+
+// =====================================================
+// TCP 복구 스레드 루프
+// =====================================================
+// 처리 과정:
+// 1. 논블로킹 accept (running 플래그 확인을 위해 주기적으로 깨어남)
+// 2. 연결마다 스레드 생성 → 요청 1줄 처리 → 프레임 전송 → 종료
+// =====================================================
+
+/// TCP 스냅샷/재전송 서비스 루프
+///
+/// # Arguments
+/// * `tcp_addr` - 바인드 주소
+/// * `state` - 피드 상태
+/// * `running` - 실행 플래그 (false가 되면 종료)
+pub fn udp_feed_recovery_loop(tcp_addr: SocketAddr, state: Arc<Mutex<FeedState>>, running: Arc<AtomicBool>) {
+    let listener = match TcpListener::bind(tcp_addr).and_then(|listener| {
+        listener.set_nonblocking(true)?;
+        Ok(listener)
+    }) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("[UDP Feed] Failed to bind recovery service on {}: {}", tcp_addr, e);
+            return;
+        }
+    };
+    eprintln!("[UDP Feed] Recovery service listening on {}", tcp_addr);
+
+    while running.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                let state = Arc::clone(&state);
+                std::thread::spawn(move || {
+                    if let Err(e) = handle_recovery_request(stream, &state) {
+                        eprintln!("[UDP Feed] Recovery request from {} failed: {:#}", peer, e);
+                    }
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Err(e) => {
+                eprintln!("[UDP Feed] Recovery accept failed: {}", e);
+                std::thread::sleep(Duration::from_millis(100));
+            }
+        }
+    }
+}
+
+/// 복구 요청 1건 처리
+fn handle_recovery_request(stream: TcpStream, state: &Mutex<FeedState>) -> Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(RECOVERY_TIMEOUT))?;
+    stream.set_write_timeout(Some(RECOVERY_TIMEOUT))?;
+
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let packets = recovery_response(line.trim(), state)?;
+
+    let mut writer = std::io::BufWriter::new(&stream);
+    for packet in packets {
+        writer.write_all(&(packet.len() as u32).to_le_bytes())?;
+        writer.write_all(&packet)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// 요청 → 응답 패킷 (락은 패킷 복사 동안만 보유)
+fn recovery_response(request: &str, state: &Mutex<FeedState>) -> Result<Vec<Arc<Vec<u8>>>> {
+    let timestamp = chrono::Utc::now().timestamp_millis();
+    let mut parts = request.split_whitespace();
+
+    match parts.next() {
+        Some("SNAPSHOT") => Ok(state.lock().snapshot(timestamp).into_iter().map(Arc::new).collect()),
+        Some("RETRANSMIT") => {
+            let from: u64 = parts.next().context("Missing from seq")?.parse()?;
+            let to: u64 = parts.next().context("Missing to seq")?.parse()?;
+            let state = state.lock();
+            match state.retransmit(from, to) {
+                Ok(packets) => Ok(packets),
+                Err(first_available) => {
+                    let message = FeedMessage::RetransmitUnavailable { first_available };
+                    Ok(vec![Arc::new(FeedPacket { seq: state.seq(), timestamp, message }.encode())])
+                }
+            }
+        }
+        _ => bail!("Unknown recovery request '{}'", request),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair() -> TradingPair {
+        TradingPair::new("SOL".to_string(), "USDT".to_string())
+    }
+
+    fn level(price: i64, amount: i64) -> FeedMessage {
+        FeedMessage::BookLevel {
+            trading_pair: pair(),
+            side: "buy".to_string(),
+            price: Decimal::new(price, 1),
+            amount: Decimal::new(amount, 0),
+            order_count: 1,
+        }
+    }
+
+    #[test]
+    fn packets_round_trip() {
+        let messages = [
+            level(1005, 3),
+            FeedMessage::Trade {
+                trading_pair: pair(),
+                price: Decimal::new(1005, 1),
+                amount: Decimal::new(25, 2),
+                buy_order_id: 7,
+                sell_order_id: 8,
+            },
+            FeedMessage::Reset,
+            FeedMessage::RetransmitUnavailable { first_available: 42 },
+        ];
+
+        for (seq, message) in messages.into_iter().enumerate() {
+            let packet = FeedPacket { seq: seq as u64 + 1, timestamp: 1_700_000_000_000, message };
+            let bytes = packet.encode();
+            assert_eq!(u16::from_le_bytes([bytes[0], bytes[1]]), FEED_MAGIC);
+            assert_eq!(FeedPacket::decode(&bytes).unwrap(), packet);
+            assert!(FeedPacket::decode(&bytes[..bytes.len() - 1]).is_err());
+        }
+    }
+
+    #[test]
+    fn snapshot_and_retransmit_share_sequence() {
+        let mut state = FeedState::new(2);
+        state.publish(level(1000, 1), 0);
+        state.publish(level(1010, 2), 0);
+        state.publish(level(1000, 0), 0);
+
+        let snapshot = state.snapshot(0);
+        assert_eq!(snapshot.len(), 2);
+        let end = FeedPacket::decode(&snapshot[1]).unwrap();
+        assert_eq!(end.seq, 3);
+        assert_eq!(end.message, FeedMessage::SnapshotEnd { level_count: 1 });
+
+        let packets = state.retransmit(2, 10).unwrap();
+        let seqs: Vec<u64> = packets.iter().map(|p| FeedPacket::decode(p).unwrap().seq).collect();
+        assert_eq!(seqs, vec![2, 3]);
+        assert_eq!(state.retransmit(1, 3), Err(2));
+    }
+}
@@ -294,29 +294,61 @@ impl MatchResult {
     }
 }
 
+/// 체결 수수료 (Executor가 정산하면서 차감한 금액)
+/// Trade fees charged at settlement
+///
+/// 각자 받은 자산에서 차감합니다 (매수자: base_mint, 매도자: quote_mint).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TradeFees {
+    /// 매수자 수수료 (base_mint)
+    pub buyer_fee: Decimal,
+
+    /// 매도자 수수료 (quote_mint)
+    pub seller_fee: Decimal,
+}
+
 /// 엔진 이벤트 타입
 /// Engine Event Type
 /// 
-/// 엔진에서 발생하는 이벤트를 나타냅니다.
-/// WAL (Write-Ahead Log) 및 DB 쓰기 큐에서 사용됩니다.
+/// 엔진 스레드에서 발생하는 이벤트를 나타냅니다.
+/// `EventBus`를 통해 시퀀스 번호와 함께 구독자(시세, 알림, 포지션, 메트릭 등)에게 발행됩니다.
 /// 
 /// # Variants
-/// * `OrderCreated` - 주문 생성
+/// * `OrderAccepted` - 주문 접수 (잔고 잠금 성공)
+/// * `OrderRejected` - 주문 거부 (잔고 부족 등)
 /// * `OrderCancelled` - 주문 취소
+/// * `OrderExpired` - 주문 만료 (시장가 IOC 미체결 잔량)
 /// * `TradeExecuted` - 체결 실행
+/// * `BalanceChanged` - 잔고 변경
+/// * `BookLevelChanged` - 오더북 가격 레벨 변경
 /// 
 /// # 용도
-/// 1. WAL 기록: 복구를 위해 모든 이벤트를 순차적으로 기록
-/// 2. DB 쓰기 큐: 비동기 배치 쓰기를 위한 이벤트 큐
+/// 매칭 루프를 건드리지 않고 엔진 상태 변화를 구독하기 위한 in-process 이벤트 스트림
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum EngineEvent {
-    /// 주문 생성 이벤트
-    /// Order created event
-    OrderCreated {
-        /// 생성된 주문
+    /// 주문 접수 이벤트
+    /// Order accepted event
+    OrderAccepted {
+        /// 접수된 주문 (매칭 전 상태)
         order: OrderEntry,
     },
     
+    /// 주문 거부 이벤트
+    /// Order rejected event
+    OrderRejected {
+        /// 거부된 주문 ID
+        order_id: u64,
+        
+        /// 주문한 사용자 ID
+        user_id: u64,
+        
+        /// 거래쌍
+        trading_pair: TradingPair,
+        
+        /// 거부 사유
+        reason: String,
+    },
+    
     /// 주문 취소 이벤트
     /// Order cancelled event
     OrderCancelled {
@@ -330,6 +362,24 @@ pub enum EngineEvent {
         trading_pair: TradingPair,
     },
     
+    /// 주문 만료 이벤트
+    /// Order expired event
+    /// 
+    /// 시장가 주문(IOC)이 전량 체결되지 못해 남은 잔량이 취소될 때 발행됩니다.
+    OrderExpired {
+        /// 만료된 주문 ID
+        order_id: u64,
+        
+        /// 주문한 사용자 ID
+        user_id: u64,
+        
+        /// 거래쌍
+        trading_pair: TradingPair,
+        
+        /// 체결된 수량
+        filled_amount: Decimal,
+    },
+    
     /// 체결 실행 이벤트
     /// Trade executed event
     TradeExecuted {
@@ -341,6 +391,68 @@ pub enum EngineEvent {
         
         /// 매도자 ID
         seller_id: u64,
+
+        /// 정산 시 차감한 수수료
+        fees: TradeFees,
+    },
+    
+    /// 잔고 변경 이벤트
+    /// Balance changed event
+    BalanceChanged {
+        /// 사용자 ID
+        user_id: u64,
+        
+        /// 자산 종류
+        mint: String,
+        
+        /// 변경 후 사용 가능 잔고
+        available: Decimal,
+        
+        /// 변경 후 잠긴 잔고
+        locked: Decimal,
+    },
+    
+    /// 오더북 가격 레벨 변경 이벤트
+    /// Book level changed event
+    /// 
+    /// `total_amount`가 0이면 해당 가격 레벨이 사라진 것입니다.
+    BookLevelChanged {
+        /// 거래쌍
+        trading_pair: TradingPair,
+        
+        /// 호가 방향 ("buy" or "sell")
+        side: String,
+        
+        /// 가격
+        price: Decimal,
+        
+        /// 해당 가격의 남은 수량 합계
+        total_amount: Decimal,
+        
+        /// 해당 가격의 주문 개수
+        order_count: usize,
+    },
+    
+    /// 오더북 주문 추가 이벤트 (L3)
+    /// Book order added event
+    /// 
+    /// 지정가 주문이 매칭 후 남은 수량으로 오더북에 올라갔을 때 발행됩니다 (해당 가격 큐의 맨 뒤).
+    /// 이후 변화는 `TradeExecuted`(메이커 체결), `OrderCancelled`로 추적합니다.
+    BookOrderAdded {
+        /// 거래쌍
+        trading_pair: TradingPair,
+        
+        /// 주문 ID
+        order_id: u64,
+        
+        /// 호가 방향 ("buy" or "sell")
+        side: String,
+        
+        /// 가격
+        price: Decimal,
+        
+        /// 오더북에 올라간 수량 (매칭 후 남은 수량)
+        amount: Decimal,
     },
 }
 
@@ -391,3 +503,49 @@ impl OrderStatus {
         }
     }
 }
+
+/// 잔고 보류 처리 방식
+/// Balance hold action
+/// 
+/// 출금 등 주문 외 용도의 보류를 상태 전이마다 어떻게 처리할지 나타냅니다.
+/// (모든 전이는 WAL에 기록)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HoldAction {
+    /// 보류 (available 차감)
+    /// Place hold
+    Place,
+    
+    /// 보류 유지 (잔고 변화 없음, 전이 기록만)
+    /// Keep hold
+    Keep,
+    
+    /// 보류 소진 (자산이 거래소 밖으로 나감)
+    /// Consume hold
+    Consume,
+    
+    /// 보류 해제 (available 복구)
+    /// Release hold
+    Release,
+}
+
+/// 엔진 명령 처리 결과를 알 수 없음
+/// Engine command outcome is unknown
+///
+/// 명령은 이미 엔진 채널에 들어갔지만 응답을 받지 못한 경우입니다 (응답 시간 초과, 응답 채널 끊김).
+/// 엔진 스레드가 나중에 명령을 처리할 수 있으므로 호출자는 실패로 확정하면 안 됩니다.
+#[derive(Debug, thiserror::Error)]
+#[error("Engine outcome unknown: {0}")]
+pub struct OutcomeUnknown(pub String);
+
+impl HoldAction {
+    /// 문자열로 변환
+    /// Convert to string
+    pub fn as_str(&self) -> &str {
+        match self {
+            HoldAction::Place => "place",
+            HoldAction::Keep => "keep",
+            HoldAction::Consume => "consume",
+            HoldAction::Release => "release",
+        }
+    }
+}
@@ -17,6 +17,8 @@
 use std::fs::{File, OpenOptions};
 use std::io::{Write, BufWriter, BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use anyhow::{Result, Context};
 use serde::{Serialize, Deserialize};
 use crate::domains::cex::engine::types::MatchResult;
@@ -74,6 +76,322 @@ pub enum WalEntry {
         user_id: u64,
         timestamp: i64,
     },
+    
+    /// 잔고 보류 전이 (출금 상태 변경마다 기록)
+    BalanceHold {
+        hold_id: u64,
+        user_id: u64,
+        mint: String,
+        amount: String,
+        action: String,  // "place", "keep", "consume", "release"
+        reason: String,  // 예: "withdrawal:approved"
+        timestamp: i64,
+    },
+    
+    /// 사용자 간 내부 이체 (available → available)
+    BalanceTransfer {
+        transfer_id: u64,
+        from_user_id: u64,
+        to_user_id: u64,
+        mint: String,
+        amount: String,
+        timestamp: i64,
+    },
+}
+
+/// WAL 시퀀스 (마지막으로 할당된 번호)
+static WAL_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// WAL 시퀀스 생성기
+/// 
+/// 모든 WAL 레코드에 단조 증가하는 시퀀스 번호를 부여합니다.
+/// DB Writer는 이 시퀀스로 어디까지 DB에 반영했는지(high-water mark)를 기록합니다.
+/// 
+/// # 시퀀스 형식
+/// `(timestamp_ms << 20) + counter` (ID 생성기와 동일)
+/// - 서버 재시작 후에도 이전 시퀀스보다 큰 값에서 시작
+/// 
+/// # Thread Safety
+/// 엔진 스레드(싱글 스레드)에서만 할당하므로 채널 순서 = 시퀀스 순서
+pub struct WalSequence;
+
+impl WalSequence {
+    /// 시퀀스 초기화
+    /// 
+    /// # Arguments
+    /// * `last_applied` - DB에 반영된 마지막 시퀀스 (high-water mark)
+    /// 
+    /// # Note
+    /// 현재 타임스탬프 기반 값과 `last_applied` 중 큰 값에서 시작합니다.
+    /// (시계가 뒤로 가더라도 시퀀스가 감소하지 않도록)
+    pub fn initialize(last_applied: u64) {
+        let timestamp_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+        WAL_SEQUENCE.store((timestamp_ms << 20).max(last_applied), Ordering::SeqCst);
+    }
+    
+    /// 다음 시퀀스 할당
+    pub fn next() -> u64 {
+        WAL_SEQUENCE.fetch_add(1, Ordering::SeqCst) + 1
+    }
+    
+    /// 마지막으로 할당된 시퀀스
+    /// 
+    /// DB 명령은 직전에 발행된 WAL 레코드에서 파생되므로
+    /// 엔진 스레드에서 이 값을 `DbCommand::wal_seq`로 사용합니다.
+    pub fn current() -> u64 {
+        WAL_SEQUENCE.load(Ordering::SeqCst)
+    }
+}
+
+/// WAL 레코드 (시퀀스 + 엔트리)
+/// 
+/// 디스크에는 `{"seq": 123, "OrderCreated": {...}}` 형태로 한 줄씩 기록됩니다.
+/// 시퀀스가 없는 이전 형식의 라인은 `seq = 0`으로 읽습니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalRecord {
+    /// WAL 시퀀스
+    #[serde(default)]
+    pub seq: u64,
+    /// 엔트리
+    #[serde(flatten)]
+    pub entry: WalEntry,
+}
+
+/// WAL 스레드가 완료를 알려주는 콜백
+/// 
+/// 인자로 durable 여부(`Ok`) 또는 WAL 쓰기 실패(`Err`)를 받습니다.
+pub type WalAck = Box<dyn FnOnce(Result<()>) + Send + Sync>;
+
+/// WAL 채널 메시지
+/// 
+/// 엔진 스레드 → WAL 스레드로 전달되는 메시지입니다.
+/// 
+/// # Variants
+/// * `Entry` - 디스크에 기록할 레코드 (시퀀스 포함)
+/// * `Barrier` - 앞서 보낸 모든 엔트리가 durable해지면 호출할 ack
+/// 
+/// # Barrier 동작
+/// 채널은 FIFO이므로, Barrier 이전에 보낸 엔트리는 모두 이미 버퍼에 쓰여 있습니다.
+/// WAL 스레드는 현재 durability 정책에 따라 해당 엔트리들이 보장되는 시점에 ack를 호출합니다.
+/// (예: SubmitOrder 응답은 주문의 WAL 레코드가 durable해진 뒤에만 전송)
+pub enum WalMessage {
+    /// 기록할 레코드
+    Entry(WalRecord),
+    /// durable 확인 후 호출할 ack
+    Barrier(WalAck),
+}
+
+impl WalMessage {
+    /// 엔트리 메시지 생성 (다음 WAL 시퀀스 할당)
+    /// 
+    /// # Note
+    /// 시퀀스는 생성 시점에 할당되므로 생성 직후 채널로 전송해야 합니다.
+    pub fn entry(entry: WalEntry) -> Self {
+        WalMessage::Entry(WalRecord {
+            seq: WalSequence::next(),
+            entry,
+        })
+    }
+}
+
+impl std::fmt::Debug for WalMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WalMessage::Entry(record) => f.debug_tuple("Entry").field(record).finish(),
+            WalMessage::Barrier(_) => f.write_str("Barrier"),
+        }
+    }
+}
+
+/// WAL durability 정책
+/// 
+/// 언제 fsync를 호출하고, 언제 Barrier(ack)를 완료할지 결정합니다.
+/// 
+/// # Variants
+/// * `PerCommand` - 엔트리마다 fsync (가장 안전, 가장 느림)
+/// * `GroupCommit` - `max_delay` 경과 또는 `max_entries` 누적 중 먼저 오는 시점에 fsync
+/// * `Async` - fsync 없이 커널 버퍼까지만 flush (종료 시에만 fsync, 크래시 시 손실 가능)
+/// 
+/// # 환경 변수
+/// * `WAL_DURABILITY` - "per_command", "group_commit", "async" (기본값: "group_commit")
+/// * `WAL_GROUP_COMMIT_US` - 그룹 커밋 최대 대기 시간 (µs, 기본값: 500)
+/// * `WAL_GROUP_COMMIT_ENTRIES` - 그룹 커밋 최대 엔트리 수 (기본값: 64)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalDurability {
+    /// 엔트리마다 fsync
+    PerCommand,
+    /// 그룹 커밋 (시간 또는 개수 기준)
+    GroupCommit {
+        /// 첫 엔트리 이후 fsync까지 최대 대기 시간
+        max_delay: Duration,
+        /// fsync 없이 누적할 최대 엔트리 수
+        max_entries: usize,
+    },
+    /// fsync 없음 (커널 버퍼까지만)
+    Async,
+}
+
+impl Default for WalDurability {
+    fn default() -> Self {
+        WalDurability::GroupCommit {
+            max_delay: Duration::from_micros(500),
+            max_entries: 64,
+        }
+    }
+}
+
+impl WalDurability {
+    /// 정책 이름 (WAL_DURABILITY 값)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WalDurability::PerCommand => "per_command",
+            WalDurability::GroupCommit { .. } => "group_commit",
+            WalDurability::Async => "async",
+        }
+    }
+
+    /// 환경 변수에서 정책 읽기
+    /// 
+    /// 알 수 없는 값이면 경고를 출력하고 기본값(group_commit)을 사용합니다.
+    pub fn from_env() -> Self {
+        let mode = std::env::var("WAL_DURABILITY").unwrap_or_else(|_| "group_commit".to_string());
+        
+        match mode.as_str() {
+            "per_command" => WalDurability::PerCommand,
+            "async" => WalDurability::Async,
+            "group_commit" => {
+                let max_delay_us = std::env::var("WAL_GROUP_COMMIT_US")
+                    .ok()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(500);
+                let max_entries = std::env::var("WAL_GROUP_COMMIT_ENTRIES")
+                    .ok()
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .filter(|n| *n > 0)
+                    .unwrap_or(64);
+                WalDurability::GroupCommit {
+                    max_delay: Duration::from_micros(max_delay_us),
+                    max_entries,
+                }
+            }
+            other => {
+                eprintln!("[WAL] Unknown WAL_DURABILITY '{}', falling back to group_commit", other);
+                WalDurability::default()
+            }
+        }
+    }
+    
+    /// `WalWriter::append()`에 사용할 fsync 주기
+    pub fn sync_interval(&self) -> usize {
+        match self {
+            WalDurability::PerCommand => 1,
+            WalDurability::GroupCommit { max_entries, .. } => *max_entries,
+            WalDurability::Async => usize::MAX,
+        }
+    }
+}
+
+/// WAL 메트릭 (fsync 지연 시간, 배치 크기)
+/// 
+/// WAL 스레드가 기록하고, 다른 스레드는 `snapshot()`으로 읽습니다.
+/// 모든 필드는 atomic이므로 락 없이 공유 가능합니다 (`Arc<WalMetrics>`).
+#[derive(Debug, Default)]
+pub struct WalMetrics {
+    /// 기록된 엔트리 수
+    entries_written: AtomicU64,
+    /// fsync 호출 횟수
+    fsync_count: AtomicU64,
+    /// fsync 누적 지연 시간 (µs)
+    fsync_total_us: AtomicU64,
+    /// fsync 최대 지연 시간 (µs)
+    fsync_max_us: AtomicU64,
+    /// 마지막 fsync 지연 시간 (µs)
+    fsync_last_us: AtomicU64,
+    /// 마지막 커밋 배치 크기 (엔트리 수)
+    last_batch_size: AtomicU64,
+    /// 최대 커밋 배치 크기 (엔트리 수)
+    max_batch_size: AtomicU64,
+    /// 완료된 ack 수
+    acks_completed: AtomicU64,
+}
+
+/// WAL 메트릭 스냅샷
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct WalMetricsSnapshot {
+    pub entries_written: u64,
+    pub fsync_count: u64,
+    pub fsync_total_us: u64,
+    pub fsync_max_us: u64,
+    pub fsync_last_us: u64,
+    pub last_batch_size: u64,
+    pub max_batch_size: u64,
+    pub acks_completed: u64,
+}
+
+impl WalMetricsSnapshot {
+    /// 평균 fsync 지연 시간 (µs)
+    pub fn fsync_avg_us(&self) -> u64 {
+        if self.fsync_count == 0 {
+            0
+        } else {
+            self.fsync_total_us / self.fsync_count
+        }
+    }
+    
+    /// fsync당 평균 배치 크기
+    pub fn avg_batch_size(&self) -> u64 {
+        if self.fsync_count == 0 {
+            0
+        } else {
+            self.entries_written / self.fsync_count
+        }
+    }
+}
+
+impl WalMetrics {
+    /// 새 메트릭 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// 엔트리 기록
+    pub fn record_entry(&self) {
+        self.entries_written.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    /// fsync 기록
+    /// 
+    /// # Arguments
+    /// * `latency` - fsync 소요 시간
+    /// * `batch_size` - 이번 fsync로 durable해진 엔트리 수
+    pub fn record_fsync(&self, latency: Duration, batch_size: usize) {
+        let us = latency.as_micros() as u64;
+        self.fsync_count.fetch_add(1, Ordering::Relaxed);
+        self.fsync_total_us.fetch_add(us, Ordering::Relaxed);
+        self.fsync_max_us.fetch_max(us, Ordering::Relaxed);
+        self.fsync_last_us.store(us, Ordering::Relaxed);
+        self.last_batch_size.store(batch_size as u64, Ordering::Relaxed);
+        self.max_batch_size.fetch_max(batch_size as u64, Ordering::Relaxed);
+    }
+    
+    /// ack 완료 기록
+    pub fn record_acks(&self, count: usize) {
+        self.acks_completed.fetch_add(count as u64, Ordering::Relaxed);
+    }
+    
+    /// 현재 값 스냅샷
+    pub fn snapshot(&self) -> WalMetricsSnapshot {
+        WalMetricsSnapshot {
+            entries_written: self.entries_written.load(Ordering::Relaxed),
+            fsync_count: self.fsync_count.load(Ordering::Relaxed),
+            fsync_total_us: self.fsync_total_us.load(Ordering::Relaxed),
+            fsync_max_us: self.fsync_max_us.load(Ordering::Relaxed),
+            fsync_last_us: self.fsync_last_us.load(Ordering::Relaxed),
+            last_batch_size: self.last_batch_size.load(Ordering::Relaxed),
+            max_batch_size: self.max_batch_size.load(Ordering::Relaxed),
+            acks_completed: self.acks_completed.load(Ordering::Relaxed),
+        }
+    }
 }
 
 /// WAL Writer
@@ -136,10 +454,10 @@ impl WalWriter {
         })
     }
     
-    /// WAL에 엔트리 추가
+    /// WAL에 레코드 추가
     /// 
     /// # Arguments
-    /// * `entry` - 기록할 WAL 엔트리
+    /// * `record` - 기록할 WAL 레코드
     /// 
     /// # Process
     /// 1. 엔트리를 JSON으로 직렬화
@@ -150,10 +468,26 @@ impl WalWriter {
     /// # Performance
     /// - 버퍼 쓰기: ~100ns (메모리)
     /// - fsync(): ~0.5ms (디스크, sync_interval마다)
-    pub fn append(&mut self, entry: &WalEntry) -> Result<()> {
+    pub fn append(&mut self, record: &WalRecord) -> Result<()> {
+        self.write(record)?;
+        
+        // sync_interval마다 fsync 호출
+        if self.entries_since_sync >= self.sync_interval {
+            self.sync()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// WAL 버퍼에 레코드 쓰기 (fsync 없음)
+    /// 
+    /// # Note
+    /// fsync 시점은 호출자가 결정합니다 (WAL 스레드의 durability 정책).
+    /// 쓰기 후 `pending_entries()`가 1 증가합니다.
+    pub fn write(&mut self, record: &WalRecord) -> Result<()> {
         // JSON 직렬화
         // serde_json::to_string()은 구조체를 JSON 문자열로 변환
-        let json = serde_json::to_string(entry)
+        let json = serde_json::to_string(record)
             .context("Failed to serialize WAL entry")?;
         
         // BufWriter에 쓰기 (아직 디스크 X, 메모리 버퍼 O)
@@ -162,15 +496,22 @@ impl WalWriter {
             .context("Failed to write to WAL buffer")?;
         
         self.entries_since_sync += 1;
-        
-        // sync_interval마다 fsync 호출
-        if self.entries_since_sync >= self.sync_interval {
-            self.sync()?;
-        }
-        
         Ok(())
     }
     
+    /// 메모리 버퍼 → 커널 버퍼 플러시 (fsync 없음)
+    /// 
+    /// 프로세스 크래시에는 안전하지만, OS 크래시/정전 시 손실될 수 있습니다.
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+            .context("Failed to flush WAL buffer")
+    }
+    
+    /// 마지막 fsync 이후 기록된 엔트리 수
+    pub fn pending_entries(&self) -> usize {
+        self.entries_since_sync
+    }
+    
     /// 강제 동기화 (fsync)
     /// 
     /// # Process
@@ -223,18 +564,30 @@ impl WalReader {
     /// 
     /// # Returns
     /// Vec<WalEntry> - 시간 순서대로 정렬된 엔트리들
+    pub fn read_all(&self) -> Result<Vec<WalEntry>> {
+        Ok(self
+            .read_records()?
+            .into_iter()
+            .map(|record| record.entry)
+            .collect())
+    }
+    
+    /// WAL 파일에서 모든 레코드 읽기 (시퀀스 포함)
+    /// 
+    /// # Returns
+    /// Vec<WalRecord> - 시퀀스 순서대로 정렬된 레코드들
     /// 
     /// # Process
     /// 1. 파일 열기
     /// 2. 라인별로 읽기
     /// 3. JSON 파싱
-    /// 4. WalEntry로 역직렬화
-    pub fn read_all(&self) -> Result<Vec<WalEntry>> {
+    /// 4. WalRecord로 역직렬화
+    pub fn read_records(&self) -> Result<Vec<WalRecord>> {
         let file = File::open(&self.file_path)
             .context("Failed to open WAL file for reading")?;
         
         let reader = BufReader::new(file);
-        let mut entries = Vec::new();
+        let mut records = Vec::new();
         
         // 라인별로 읽기
         // lines()는 Iterator<Item = Result<String>>을 반환
@@ -242,13 +595,12 @@ impl WalReader {
             let line = line.context(format!("Failed to read line {}", line_num + 1))?;
             
             // JSON 파싱
-            let entry: WalEntry = serde_json::from_str(&line)
+            let record: WalRecord = serde_json::from_str(&line)
                 .context(format!("Failed to parse WAL entry at line {}", line_num + 1))?;
             
-            entries.push(entry);
+            records.push(record);
         }
         
-        Ok(entries)
+        Ok(records)
     }
 }
-
@@ -3,7 +3,7 @@
 // 역할: 잔고 조회 API 엔드포인트 처리
 
 use crate::shared::services::AppState;
-use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::middleware::sub_account::TradingAccount;
 use crate::domains::cex::models::balance::{ExchangeBalancesResponse, ExchangeBalanceResponse};
 use axum::{extract::{Path, State}, http::StatusCode, Json};
 
@@ -30,11 +30,11 @@ use axum::{extract::{Path, State}, http::StatusCode, Json};
 )]
 pub async fn get_all_balances(
     State(app_state): State<AppState>,
-    authenticated_user: AuthenticatedUser,
+    trading_account: TradingAccount,
 ) -> Result<Json<ExchangeBalancesResponse>, (StatusCode, Json<serde_json::Value>)> {
-    // JWT 토큰에서 추출한 user_id 사용
-    // Use user_id extracted from JWT token
-    let user_id = authenticated_user.user_id;
+    // JWT 토큰의 user_id (X-Sub-Account-Id 헤더가 있으면 서브 계정 ID)
+    // Use user_id from JWT token (or the sub-account from X-Sub-Account-Id)
+    let user_id = trading_account.user_id;
 
     // BalanceService를 통해 모든 잔고 조회
     // Get all balances through BalanceService
@@ -86,12 +86,12 @@ pub async fn get_all_balances(
 )]
 pub async fn get_balance(
     State(app_state): State<AppState>,
-    authenticated_user: AuthenticatedUser,
+    trading_account: TradingAccount,
     Path(mint): Path<String>,
 ) -> Result<Json<ExchangeBalanceResponse>, (StatusCode, Json<serde_json::Value>)> {
-    // JWT 토큰에서 추출한 user_id 사용
-    // Use user_id extracted from JWT token
-    let user_id = authenticated_user.user_id;
+    // JWT 토큰의 user_id (X-Sub-Account-Id 헤더가 있으면 서브 계정 ID)
+    // Use user_id from JWT token (or the sub-account from X-Sub-Account-Id)
+    let user_id = trading_account.user_id;
 
     // BalanceService를 통해 특정 자산 잔고 조회
     // Get specific asset balance through BalanceService
@@ -0,0 +1,131 @@
+use crate::domains::cex::models::candle::{Candle, CandleInterval};
+use crate::shared::services::AppState;
+use axum::{
+    extract::{State, Query},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::{ToSchema, IntoParams};
+
+// =====================================================
+// Candle Handler
+// =====================================================
+// 역할: 차트용 OHLCV 캔들 조회 API
+//
+// 특징:
+// - 읽기 전용 (캔들은 CandleService가 체결 이벤트로 집계)
+// - 실시간 갱신은 `/api/cex/ws/market`의 `candles` 채널 사용
+// =====================================================
+
+/// 캔들 조회 쿼리 파라미터
+/// Query parameters for candles
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct CandlesQuery {
+    /// 기준 자산 (예: "SOL")
+    /// Base asset (e.g., "SOL")
+    pub base_mint: String,
+
+    /// 기준 통화 (예: "USDT", 기본값)
+    /// Quote currency (e.g., "USDT", default)
+    #[serde(default = "default_quote_mint")]
+    pub quote_mint: String,
+
+    /// 캔들 주기 (1m, 5m, 15m, 1h, 4h, 1d)
+    /// Candle interval
+    #[param(value_type = String, example = "1m")]
+    pub interval: CandleInterval,
+
+    /// 시작 시간 (RFC 3339, 포함, 기본: to에서 limit개 주기 전)
+    /// Start time (inclusive)
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// 끝 시간 (RFC 3339, 미포함, 기본: 현재 진행 중인 캔들까지)
+    /// End time (exclusive)
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    /// 최대 조회 개수 (기본: 500, 최대: 1000)
+    /// Limit (default: 500, max: 1000)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+fn default_quote_mint() -> String {
+    "USDT".to_string()
+}
+
+/// 캔들 조회 핸들러
+/// Get candles handler
+///
+/// 특정 거래쌍의 OHLCV 캔들을 시간 오름차순으로 조회합니다.
+/// 체결이 없었던 구간은 캔들이 없습니다 (이전 종가로 채워서 표시).
+///
+/// # Query Parameters
+/// - base_mint: 기준 자산 (required, 예: "SOL")
+/// - quote_mint: 기준 통화 (optional, 기본: "USDT")
+/// - interval: 캔들 주기 (required, 1m/5m/15m/1h/4h/1d)
+/// - from, to: 조회 구간 (optional, RFC 3339)
+/// - limit: 최대 조회 개수 (optional, 기본: 500, 최대: 1000)
+///
+/// # Response
+/// - 200: 캔들 조회 성공
+/// - 400: 잘못된 요청 (알 수 없는 주기, from >= to)
+/// - 500: 서버 오류
+///
+/// # 용도
+/// - 거래소 차트 (초기 로드, 과거 구간 스크롤)
+#[utoipa::path(
+    get,
+    path = "/api/cex/candles",
+    params(
+        CandlesQuery
+    ),
+    responses(
+        (status = 200, description = "Candles retrieved successfully", body = Vec<Candle>),
+        (status = 400, description = "Bad request"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Trades"
+)]
+pub async fn get_candles(
+    State(app_state): State<AppState>,
+    Query(query): Query<CandlesQuery>,
+) -> Result<Json<Vec<Candle>>, (StatusCode, Json<serde_json::Value>)> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from >= to
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "`from` must be earlier than `to`"
+            })),
+        ));
+    }
+
+    // Service 호출
+    let candles = app_state
+        .cex_state
+        .candle_service
+        .get_candles(
+            &query.base_mint,
+            &query.quote_mint,
+            query.interval,
+            query.from,
+            query.to,
+            query.limit,
+        )
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch candles: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(candles))
+}
@@ -0,0 +1,148 @@
+use crate::domains::cex::models::deposit::{Deposit, DepositAddressResponse};
+use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::services::AppState;
+use axum::{
+    extract::{State, Query},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+use utoipa::{ToSchema, IntoParams};
+
+// =====================================================
+// Deposit Handler
+// =====================================================
+// 역할: 입금 주소 조회 / 입금 내역 API
+//
+// 특징:
+// - 입금 주소는 사용자 Solana 지갑 (없으면 생성)
+// - 입금은 DepositService가 온체인을 감시해 finalized 후 자동으로 잔고에 반영
+// =====================================================
+
+/// 입금 주소 쿼리 파라미터
+/// Query parameters for deposit address
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct DepositAddressQuery {
+    /// 자산 (예: "SOL", "USDT")
+    /// Asset (e.g., "SOL", "USDT")
+    pub mint: String,
+}
+
+/// 입금 내역 쿼리 파라미터
+/// Query parameters for deposit history
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct DepositListQuery {
+    /// 자산 필터 (optional)
+    /// Asset filter (optional)
+    #[serde(default)]
+    pub mint: Option<String>,
+
+    /// 최대 조회 개수 (기본: 50, 최대: 200)
+    /// Limit (default: 50, max: 200)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// 입금 주소 조회 핸들러
+/// Get deposit address handler
+///
+/// SOL은 지갑 주소, SPL 토큰은 지갑의 Associated Token Account로 보내야 합니다.
+///
+/// # Query Parameters
+/// - mint: 자산 (예: "SOL", "USDT")
+///
+/// # Response
+/// - 200: 입금 주소
+/// - 400: 입금을 지원하지 않는 자산
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/deposits/address",
+    params(
+        DepositAddressQuery
+    ),
+    responses(
+        (status = 200, description = "Deposit address retrieved successfully", body = DepositAddressResponse),
+        (status = 400, description = "Asset is not supported for deposits"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_deposit_address(
+    State(app_state): State<AppState>,
+    Query(query): Query<DepositAddressQuery>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<DepositAddressResponse>, (StatusCode, Json<serde_json::Value>)> {
+    let deposit_service = &app_state.cex_state.deposit_service;
+    let address = deposit_service
+        .get_deposit_address(authenticated_user.user_id, &query.mint)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to get deposit address: {}", e)
+                })),
+            )
+        })?;
+
+    address.map(Json).ok_or_else(|| {
+        let supported: Vec<&str> = deposit_service.assets().iter().map(|asset| asset.mint.as_str()).collect();
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!("Deposits are not supported for {} (supported: {})", query.mint, supported.join(", "))
+            })),
+        )
+    })
+}
+
+/// 내 입금 내역 핸들러
+/// Get my deposits handler
+///
+/// # Query Parameters
+/// - mint: 자산 필터 (optional)
+/// - limit: 최대 조회 개수 (optional, 기본: 50, 최대: 200)
+///
+/// # Response
+/// - 200: 입금 내역 (최신순, confirming/crediting/credited/failed)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/deposits",
+    params(
+        DepositListQuery
+    ),
+    responses(
+        (status = 200, description = "Deposits retrieved successfully", body = Vec<Deposit>),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_my_deposits(
+    State(app_state): State<AppState>,
+    Query(query): Query<DepositListQuery>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<Vec<Deposit>>, (StatusCode, Json<serde_json::Value>)> {
+    let deposits = app_state
+        .cex_state
+        .deposit_service
+        .list_deposits(authenticated_user.user_id, query.mint.as_deref(), query.limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch deposits: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(deposits))
+}
@@ -0,0 +1,267 @@
+use crate::domains::cex::models::export::{CreateExportRequest, ExportJob, ExportStatus};
+use crate::domains::cex::services::MAX_EXPORT_RANGE_DAYS;
+use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::services::AppState;
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::Response,
+    Json,
+};
+use chrono::Duration;
+use serde::Deserialize;
+use tokio_util::io::ReaderStream;
+use utoipa::{IntoParams, ToSchema};
+
+// =====================================================
+// Export Handler
+// =====================================================
+// 역할: 체결/주문/일별 잔고 내역 내보내기 API (CSV / JSON)
+//
+// 흐름:
+// 1. POST /exports → 작업 생성 (202, status: pending)
+// 2. GET /exports/:id 로 상태 확인 (pending → running → completed | failed)
+// 3. completed 이면 GET /exports/:id/download 로 파일 다운로드 (기한 내)
+// =====================================================
+
+/// 내보내기 작업 목록 쿼리 파라미터
+/// Query parameters for export job list
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct ExportListQuery {
+    /// 최대 조회 개수 (기본: 50, 최대: 200)
+    /// Limit (default: 50, max: 200)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+type ErrorResponse = (StatusCode, Json<serde_json::Value>);
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> ErrorResponse {
+    (status, Json(serde_json::json!({ "error": message.into() })))
+}
+
+/// 내보내기 작업 생성 핸들러
+/// Create export job handler
+///
+/// 파일은 백그라운드에서 작성되므로 바로 작업 정보를 반환합니다.
+///
+/// # Request Body
+/// - dataset: trades, orders, balance_snapshots, deposits, withdrawals
+/// - format: csv, json (optional, 기본: csv)
+/// - from, to: 기간 (RFC 3339, from 포함 / to 미포함, 최대 366일)
+///
+/// # Response
+/// - 202: 작업 생성됨 (status: pending)
+/// - 400: 잘못된 요청 (from >= to, 기간 초과)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    post,
+    path = "/api/cex/exports",
+    request_body = CreateExportRequest,
+    responses(
+        (status = 202, description = "Export job accepted", body = ExportJob),
+        (status = 400, description = "Bad request (invalid range)"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Exports",
+    security(("BearerAuth" = []))
+)]
+pub async fn create_export(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(request): Json<CreateExportRequest>,
+) -> Result<(StatusCode, Json<ExportJob>), ErrorResponse> {
+    if request.from >= request.to {
+        return Err(error_response(StatusCode::BAD_REQUEST, "`from` must be earlier than `to`"));
+    }
+    if request.to - request.from > Duration::days(MAX_EXPORT_RANGE_DAYS) {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Export range must not exceed {} days", MAX_EXPORT_RANGE_DAYS),
+        ));
+    }
+
+    let job = app_state
+        .cex_state
+        .export_service
+        .create_export(authenticated_user.user_id, &request)
+        .await
+        .map_err(|e| {
+            error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to create export: {}", e),
+            )
+        })?;
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+/// 내 내보내기 작업 목록 핸들러
+/// List export jobs handler
+///
+/// # Query Parameters
+/// - limit: 최대 조회 개수 (optional, 기본: 50, 최대: 200)
+///
+/// # Response
+/// - 200: 작업 목록 (최신순)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/exports",
+    params(
+        ExportListQuery
+    ),
+    responses(
+        (status = 200, description = "Export jobs retrieved successfully", body = Vec<ExportJob>),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Exports",
+    security(("BearerAuth" = []))
+)]
+pub async fn list_exports(
+    State(app_state): State<AppState>,
+    Query(query): Query<ExportListQuery>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<Vec<ExportJob>>, ErrorResponse> {
+    let jobs = app_state
+        .cex_state
+        .export_service
+        .list_jobs(authenticated_user.user_id, query.limit)
+        .await
+        .map_err(|e| {
+            error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to fetch exports: {}", e),
+            )
+        })?;
+
+    Ok(Json(jobs))
+}
+
+/// 내보내기 작업 상태 조회 핸들러
+/// Get export job handler
+///
+/// # Path Parameters
+/// - id: 작업 ID
+///
+/// # Response
+/// - 200: 작업 정보
+/// - 401: 인증 실패
+/// - 404: 작업 없음 (다른 사용자 작업 포함)
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/exports/{id}",
+    params(
+        ("id" = u64, Path, description = "Export job ID")
+    ),
+    responses(
+        (status = 200, description = "Export job retrieved successfully", body = ExportJob),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 404, description = "Export job not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Exports",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_export(
+    State(app_state): State<AppState>,
+    Path(id): Path<u64>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<ExportJob>, ErrorResponse> {
+    let job = find_job(&app_state, authenticated_user.user_id, id).await?;
+    Ok(Json(job))
+}
+
+/// 내보내기 파일 다운로드 핸들러
+/// Download export file handler
+///
+/// 파일을 스트리밍으로 전송합니다 (Content-Disposition: attachment).
+///
+/// # Path Parameters
+/// - id: 작업 ID
+///
+/// # Response
+/// - 200: 파일 (text/csv 또는 application/json)
+/// - 401: 인증 실패
+/// - 404: 작업 없음
+/// - 409: 아직 완료되지 않았거나 실패한 작업
+/// - 410: 다운로드 기한 지남
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/exports/{id}/download",
+    params(
+        ("id" = u64, Path, description = "Export job ID")
+    ),
+    responses(
+        (status = 200, description = "Export file", content_type = "text/csv"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 404, description = "Export job not found"),
+        (status = 409, description = "Export is not completed"),
+        (status = 410, description = "Export has expired"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Exports",
+    security(("BearerAuth" = []))
+)]
+pub async fn download_export(
+    State(app_state): State<AppState>,
+    Path(id): Path<u64>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Response, ErrorResponse> {
+    let job = find_job(&app_state, authenticated_user.user_id, id).await?;
+
+    match job.status {
+        ExportStatus::Completed => {}
+        ExportStatus::Expired => {
+            return Err(error_response(StatusCode::GONE, "Export has expired"));
+        }
+        status => {
+            return Err(error_response(
+                StatusCode::CONFLICT,
+                format!("Export is not completed (status: {})", status.as_str()),
+            ));
+        }
+    }
+    if job.expires_at.is_some_and(|expires_at| expires_at <= chrono::Utc::now()) {
+        return Err(error_response(StatusCode::GONE, "Export has expired"));
+    }
+
+    let file = app_state
+        .cex_state
+        .export_service
+        .open_file(&job)
+        .await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))?;
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, job.format.content_type())
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", job.file_name()),
+        )
+        .body(Body::from_stream(ReaderStream::new(file)))
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// 사용자 작업 조회 (없으면 404)
+async fn find_job(app_state: &AppState, user_id: u64, id: u64) -> Result<ExportJob, ErrorResponse> {
+    app_state
+        .cex_state
+        .export_service
+        .get_job(user_id, id)
+        .await
+        .map_err(|e| {
+            error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to fetch export: {}", e),
+            )
+        })?
+        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "Export not found"))
+}
@@ -0,0 +1,135 @@
+use crate::domains::cex::models::ledger::{LedgerFilter, LedgerPosting, LedgerReason};
+use crate::shared::services::AppState;
+use crate::shared::middleware::sub_account::TradingAccount;
+use super::pagination;
+use axum::{
+    extract::{State, Query},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::{ToSchema, IntoParams};
+
+// =====================================================
+// Ledger Handler
+// =====================================================
+// 역할: 사용자 원장(잔고 이동 내역) 조회 API
+//
+// 특징:
+// - 읽기 전용 (원장 기록은 DB Writer가 잔고 UPDATE와 함께 수행)
+// - 내 계정(available, locked) 전기만 반환 (시스템 계정 제외)
+// - 체결/수수료/입금/출금/잠금/해제마다 사유와 참조(체결 ID, 주문 ID, 트랜잭션 서명 등) 포함
+// =====================================================
+
+/// 원장 쿼리 파라미터
+/// Query parameters for ledger
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct LedgerQuery {
+    /// 자산 필터 (예: "USDT")
+    /// Asset filter
+    #[serde(default)]
+    pub mint: Option<String>,
+
+    /// 사유 필터 (trade, fee, deposit, withdrawal, withdrawal_refund, lock, unlock, adjustment, transfer)
+    /// Reason filter
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    /// 참조 필터 (체결 ID, 주문 ID, 출금 ID, 트랜잭션 서명)
+    /// Reference filter
+    #[serde(default)]
+    pub reference: Option<String>,
+
+    /// 기록 시간 하한 (RFC 3339, 포함)
+    /// Posted at or after
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// 기록 시간 상한 (RFC 3339, 미포함)
+    /// Posted before
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    /// 다음 페이지 커서 (이전 응답의 `X-Next-Cursor` 헤더 값)
+    /// Cursor from the previous response's `X-Next-Cursor` header
+    #[serde(default)]
+    pub cursor: Option<String>,
+
+    /// 최대 조회 개수 (기본: 100, 최대: 1000)
+    /// Limit (default: 100, max: 1000)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// 내 원장 조회 핸들러
+/// Get my ledger handler
+/// 
+/// 내 잔고 계정의 전기(차변/대변)를 최신순으로 조회합니다.
+/// 다음 페이지가 있으면 `X-Next-Cursor` 헤더로 커서를 반환합니다.
+/// 
+/// # Query Parameters
+/// - mint: 자산 필터 (optional)
+/// - reason: 사유 필터 (optional)
+/// - reference: 참조 필터 (optional)
+/// - from, to: 기록 시간 구간 (optional, RFC 3339)
+/// - cursor: 다음 페이지 커서 (optional)
+/// - limit: 최대 조회 개수 (optional, default: 100, max: 1000)
+/// 
+/// # Response
+/// - 200: 원장 전기 목록
+/// - 400: 잘못된 필터 값 또는 커서
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/ledger",
+    params(
+        LedgerQuery
+    ),
+    responses(
+        (status = 200, description = "Ledger postings retrieved successfully", body = Vec<LedgerPosting>,
+            headers(("X-Next-Cursor" = String, description = "Cursor for the next page (absent on the last page)"))),
+        (status = 400, description = "Invalid filter or cursor"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Balances",
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+pub async fn get_my_ledger(
+    State(app_state): State<AppState>,
+    TradingAccount { user_id }: TradingAccount,
+    Query(query): Query<LedgerQuery>,
+) -> Result<(HeaderMap, Json<Vec<LedgerPosting>>), (StatusCode, Json<serde_json::Value>)> {
+    let reasons = LedgerReason::ALL.map(|reason| reason.as_str());
+    pagination::check_one_of("reason", query.reason.as_deref(), &reasons)?;
+    pagination::check_time_range(query.from, query.to)?;
+    let cursor = pagination::parse_cursor(query.cursor.as_deref())?;
+
+    let filter = LedgerFilter {
+        mint: query.mint,
+        reason: query.reason.and_then(|reason| reason.parse().ok()),
+        reference: query.reference,
+        from: query.from,
+        to: query.to,
+    };
+
+    let page = app_state
+        .cex_state
+        .ledger_service
+        .get_my_ledger(user_id, &filter, cursor, query.limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch ledger: {}", e)
+                })),
+            )
+        })?;
+
+    Ok((pagination::next_cursor_headers(page.next_cursor), Json(page.items)))
+}
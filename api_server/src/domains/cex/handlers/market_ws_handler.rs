@@ -0,0 +1,217 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+};
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::domains::cex::engine::types::TradingPair;
+use crate::domains::cex::services::{MarketChannel, MarketDataFrame, MarketDataService};
+use crate::shared::services::AppState;
+
+// =====================================================
+// Market WebSocket Handler
+// =====================================================
+// 역할: 공개 시세 WebSocket 엔드포인트 (인증 없음)
+//
+// 프로토콜:
+// → {"op":"subscribe","channel":"depth","base_mint":"SOL","quote_mint":"USDT"}
+// ← {"type":"subscribed","channel":"depth","base_mint":"SOL","quote_mint":"USDT"}
+// ← {"type":"depth_snapshot",...,"seq":10,"bids":[...],"asks":[...]}
+// ← {"type":"depth_update",...,"seq":11,...}
+// → {"op":"unsubscribe","channel":"depth","base_mint":"SOL","quote_mint":"USDT"}
+// → {"op":"ping"}  ← {"type":"pong"}
+//
+// 채널: depth (L2 스냅샷 + 증분), l3 (주문 단위 스냅샷 + 이벤트), trades, ticker (1초, 24시간 통계),
+//       candles (모든 주기 캔들 갱신, `interval` 필드로 구분)
+// =====================================================
+
+/// 클라이언트 → 서버 메시지
+/// Client request message
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ClientMessage {
+    /// 채널 구독
+    Subscribe {
+        channel: MarketChannel,
+        base_mint: String,
+        #[serde(default = "default_quote_mint")]
+        quote_mint: String,
+    },
+
+    /// 채널 구독 해제
+    Unsubscribe {
+        channel: MarketChannel,
+        base_mint: String,
+        #[serde(default = "default_quote_mint")]
+        quote_mint: String,
+    },
+
+    /// 연결 확인
+    Ping,
+}
+
+fn default_quote_mint() -> String {
+    "USDT".to_string()
+}
+
+/// 공개 시세 WebSocket 핸들러
+/// Public market data WebSocket handler
+///
+/// `GET /api/cex/ws/market` (WebSocket upgrade)
+///
+/// # 용도
+/// - 거래소 UI의 오더북/체결/시세 실시간 표시 (폴링 대체)
+/// - 봇의 호가 추적
+pub async fn market_ws(
+    State(app_state): State<AppState>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let service = app_state.cex_state.market_data_service.clone();
+    ws.on_upgrade(move |socket| run_market_session(socket, service))
+}
+
+/// WebSocket 세션 루프
+///
+/// # 처리 과정
+/// 1. 브로드캐스트 수신기 생성 (구독 요청보다 먼저)
+/// 2. 클라이언트 메시지 → 구독/해제 (depth/l3 구독 시 스냅샷 전송)
+/// 3. 브로드캐스트 프레임 → 구독 중인 채널/거래쌍만 전송
+/// 4. 수신기가 밀리면 (Lagged) depth/l3 구독을 스냅샷으로 재동기화
+async fn run_market_session(mut socket: WebSocket, service: MarketDataService) {
+    let mut frames = service.subscribe();
+    let mut subscriptions: HashSet<(MarketChannel, TradingPair)> = HashSet::new();
+    // depth/l3 구독별 마지막으로 보낸 시퀀스 (이하의 업데이트는 스냅샷에 포함됨)
+    let mut snapshot_seqs: HashMap<(MarketChannel, TradingPair), u64> = HashMap::new();
+
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => {
+                let text = match incoming {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                    Some(Ok(_)) => continue,
+                };
+
+                let replies = handle_client_message(
+                    &text,
+                    &service,
+                    &mut subscriptions,
+                    &mut snapshot_seqs,
+                );
+                for reply in replies {
+                    if socket.send(Message::Text(reply)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            frame = frames.recv() => {
+                let frame = match frame {
+                    Ok(frame) => frame,
+                    Err(RecvError::Lagged(skipped)) => {
+                        eprintln!("[Market WS] Session lagged by {} frames, resyncing snapshots", skipped);
+                        for ((channel, trading_pair), seq) in snapshot_seqs.iter_mut() {
+                            let (snapshot_seq, snapshot) = match service.snapshot(*channel, trading_pair) {
+                                Some(snapshot) => snapshot,
+                                None => continue,
+                            };
+                            *seq = snapshot_seq;
+                            if socket.send(Message::Text(snapshot)).await.is_err() {
+                                return;
+                            }
+                        }
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                if let Some(payload) = forward_frame(&frame, &subscriptions, &mut snapshot_seqs) {
+                    if socket.send(Message::Text(payload.to_string())).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 클라이언트 메시지 처리
+///
+/// # Returns
+/// 클라이언트에게 보낼 응답 메시지들 (ack, 스냅샷, 에러)
+fn handle_client_message(
+    text: &str,
+    service: &MarketDataService,
+    subscriptions: &mut HashSet<(MarketChannel, TradingPair)>,
+    snapshot_seqs: &mut HashMap<(MarketChannel, TradingPair), u64>,
+) -> Vec<String> {
+    let message: ClientMessage = match serde_json::from_str(text) {
+        Ok(message) => message,
+        Err(e) => {
+            return vec![serde_json::json!({
+                "type": "error",
+                "message": format!("Invalid message: {}", e)
+            })
+            .to_string()];
+        }
+    };
+
+    match message {
+        ClientMessage::Subscribe { channel, base_mint, quote_mint } => {
+            let trading_pair = TradingPair::new(base_mint, quote_mint);
+            let mut replies = vec![ack("subscribed", channel, &trading_pair)];
+            if let Some((seq, snapshot)) = service.snapshot(channel, &trading_pair) {
+                snapshot_seqs.insert((channel, trading_pair.clone()), seq);
+                replies.push(snapshot);
+            }
+            subscriptions.insert((channel, trading_pair));
+            replies
+        }
+        ClientMessage::Unsubscribe { channel, base_mint, quote_mint } => {
+            let trading_pair = TradingPair::new(base_mint, quote_mint);
+            snapshot_seqs.remove(&(channel, trading_pair.clone()));
+            subscriptions.remove(&(channel, trading_pair.clone()));
+            vec![ack("unsubscribed", channel, &trading_pair)]
+        }
+        ClientMessage::Ping => vec![serde_json::json!({ "type": "pong" }).to_string()],
+    }
+}
+
+/// 브로드캐스트 프레임 필터링
+///
+/// # Returns
+/// 전송할 payload (구독하지 않았거나 이미 스냅샷에 포함된 업데이트면 None)
+fn forward_frame<'a>(
+    frame: &'a Arc<MarketDataFrame>,
+    subscriptions: &HashSet<(MarketChannel, TradingPair)>,
+    snapshot_seqs: &mut HashMap<(MarketChannel, TradingPair), u64>,
+) -> Option<&'a str> {
+    if !subscriptions.contains(&(frame.channel, frame.trading_pair.clone())) {
+        return None;
+    }
+
+    if frame.channel.is_sequenced() {
+        let last_seq = snapshot_seqs.get_mut(&(frame.channel, frame.trading_pair.clone()))?;
+        if frame.seq <= *last_seq {
+            return None;
+        }
+        *last_seq = frame.seq;
+    }
+
+    Some(frame.payload.as_str())
+}
+
+fn ack(kind: &str, channel: MarketChannel, trading_pair: &TradingPair) -> String {
+    serde_json::json!({
+        "type": kind,
+        "channel": channel,
+        "base_mint": trading_pair.base_mint,
+        "quote_mint": trading_pair.quote_mint,
+    })
+    .to_string()
+}
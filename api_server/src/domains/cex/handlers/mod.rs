@@ -4,9 +4,32 @@
 pub mod balance_handler;
 pub mod order_handler;
 pub mod trade_handler;
+pub mod candle_handler;
+pub mod ticker_handler;
 pub mod position_handler;
+pub mod portfolio_handler;
+pub mod export_handler;
+pub mod deposit_handler;
+pub mod withdrawal_handler;
+pub mod ledger_handler;
+pub mod transfer_handler;
+pub mod sub_account_handler;
+pub mod market_ws_handler;
+pub mod user_ws_handler;
+pub(crate) mod pagination;
 
 pub use balance_handler::*;
 pub use order_handler::*;
 pub use trade_handler::*;
+pub use candle_handler::*;
+pub use ticker_handler::*;
 pub use position_handler::*;
+pub use portfolio_handler::*;
+pub use export_handler::*;
+pub use deposit_handler::*;
+pub use withdrawal_handler::*;
+pub use ledger_handler::*;
+pub use transfer_handler::*;
+pub use sub_account_handler::*;
+pub use market_ws_handler::*;
+pub use user_ws_handler::*;
@@ -1,11 +1,15 @@
-use crate::domains::cex::models::order::{Order, CreateOrderRequest};
+use crate::domains::cex::models::order::{Order, CreateOrderRequest, OrderFilter};
+use crate::domains::cex::engine::types::TradingPair;
+use crate::domains::cex::services::L3Order;
 use crate::shared::services::AppState;
-use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::middleware::sub_account::TradingAccount;
+use super::pagination;
 use axum::{
     extract::{State, Path, Query},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     Json,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{ToSchema, IntoParams};
 
@@ -56,7 +60,7 @@ use utoipa::{ToSchema, IntoParams};
 )]
 pub async fn create_order(
     State(app_state): State<AppState>,
-    AuthenticatedUser { user_id, .. }: AuthenticatedUser,
+    TradingAccount { user_id }: TradingAccount,
     Json(request): Json<CreateOrderRequest>,
 ) -> Result<(StatusCode, Json<Order>), (StatusCode, Json<serde_json::Value>)> {
     // Service 호출
@@ -112,7 +116,7 @@ pub async fn create_order(
 )]
 pub async fn cancel_order(
     State(app_state): State<AppState>,
-    AuthenticatedUser { user_id, .. }: AuthenticatedUser,
+    TradingAccount { user_id }: TradingAccount,
     Path(order_id): Path<u64>,
 ) -> Result<Json<Order>, (StatusCode, Json<serde_json::Value>)> {
     // Service 호출
@@ -165,7 +169,7 @@ pub async fn cancel_order(
 )]
 pub async fn get_order(
     State(app_state): State<AppState>,
-    AuthenticatedUser { user_id, .. }: AuthenticatedUser,
+    TradingAccount { user_id }: TradingAccount,
     Path(order_id): Path<u64>,
 ) -> Result<Json<Order>, (StatusCode, Json<serde_json::Value>)> {
     // Service 호출
@@ -190,37 +194,78 @@ pub async fn get_order(
 /// Query parameters for my orders
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
 pub struct MyOrdersQuery {
+    /// 기준 자산 필터 (예: "SOL")
+    /// Base asset filter
+    #[serde(default)]
+    pub base_mint: Option<String>,
+
+    /// 기준 통화 필터 (예: "USDT")
+    /// Quote currency filter
+    #[serde(default)]
+    pub quote_mint: Option<String>,
+
+    /// 주문 유형 필터 (buy, sell)
+    /// Order type filter
+    #[serde(default)]
+    pub order_type: Option<String>,
+
+    /// 주문 방식 필터 (limit, market)
+    /// Order side filter
+    #[serde(default)]
+    pub order_side: Option<String>,
+
     /// 주문 상태 필터 (pending, partial, filled, cancelled)
     /// Order status filter
     #[serde(default)]
     pub status: Option<String>,
-    
-    /// 최대 조회 개수 (기본: 50)
-    /// Limit (default: 50)
+
+    /// 생성 시간 하한 (RFC 3339, 포함)
+    /// Created at or after
     #[serde(default)]
-    pub limit: Option<i64>,
-    
-    /// 페이지네이션 오프셋 (기본: 0)
-    /// Offset for pagination (default: 0)
+    pub from: Option<DateTime<Utc>>,
+
+    /// 생성 시간 상한 (RFC 3339, 미포함)
+    /// Created before
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    /// 다음 페이지 커서 (이전 응답의 `X-Next-Cursor` 헤더 값)
+    /// Cursor from the previous response's `X-Next-Cursor` header
+    #[serde(default)]
+    pub cursor: Option<String>,
+
+    /// 제거됨: 보내면 400 (`cursor` 사용)
+    /// Removed, rejected with 400 (use `cursor`)
     #[serde(default)]
     pub offset: Option<i64>,
+    
+    /// 최대 조회 개수 (기본: 50, 최대: 1000)
+    /// Limit (default: 50, max: 1000)
+    #[serde(default)]
+    pub limit: Option<i64>,
 }
 
 /// 내 주문 목록 조회 핸들러
 /// Get my orders handler
 /// 
-/// 현재 로그인한 사용자의 주문 목록을 조회합니다.
+/// 현재 로그인한 사용자의 주문 목록을 최신순으로 조회합니다.
+/// 다음 페이지가 있으면 `X-Next-Cursor` 헤더로 커서를 반환합니다.
 /// 
 /// # Authentication
 /// JWT 토큰 필요
 /// 
 /// # Query Parameters
+/// - base_mint, quote_mint: 거래쌍 필터 (optional)
+/// - order_type: buy/sell (optional)
+/// - order_side: limit/market (optional)
 /// - status: 주문 상태 필터 (optional)
-/// - limit: 최대 조회 개수 (optional, default: 50)
-/// - offset: 페이지네이션 오프셋 (optional, default: 0)
+/// - from, to: 생성 시간 구간 (optional, RFC 3339)
+/// - cursor: 다음 페이지 커서 (optional)
+/// - limit: 최대 조회 개수 (optional, default: 50, max: 1000)
 /// 
 /// # Response
 /// - 200: 주문 목록 조회 성공
+/// - 400: 잘못된 필터 값 또는 커서, 제거된 `offset` 사용
 /// - 401: 인증 실패
 #[utoipa::path(
     get,
@@ -229,7 +274,9 @@ pub struct MyOrdersQuery {
         MyOrdersQuery
     ),
     responses(
-        (status = 200, description = "My orders retrieved successfully", body = Vec<Order>),
+        (status = 200, description = "My orders retrieved successfully", body = Vec<Order>,
+            headers(("X-Next-Cursor" = String, description = "Cursor for the next page (absent on the last page)"))),
+        (status = 400, description = "Invalid filter or cursor, or removed `offset` parameter"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
     ),
@@ -240,19 +287,31 @@ pub struct MyOrdersQuery {
 )]
 pub async fn get_my_orders(
     State(app_state): State<AppState>,
-    AuthenticatedUser { user_id, .. }: AuthenticatedUser,
+    TradingAccount { user_id }: TradingAccount,
     Query(query): Query<MyOrdersQuery>,
-) -> Result<Json<Vec<Order>>, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<(HeaderMap, Json<Vec<Order>>), (StatusCode, Json<serde_json::Value>)> {
+    pagination::check_one_of("order_type", query.order_type.as_deref(), &["buy", "sell"])?;
+    pagination::check_one_of("order_side", query.order_side.as_deref(), &["limit", "market"])?;
+    pagination::check_one_of("status", query.status.as_deref(), &["pending", "partial", "filled", "cancelled"])?;
+    pagination::check_time_range(query.from, query.to)?;
+    pagination::reject_offset(query.offset)?;
+    let cursor = pagination::parse_cursor(query.cursor.as_deref())?;
+
+    let filter = OrderFilter {
+        base_mint: query.base_mint,
+        quote_mint: query.quote_mint,
+        order_type: query.order_type,
+        order_side: query.order_side,
+        status: query.status,
+        from: query.from,
+        to: query.to,
+    };
+
     // Service 호출
-    let orders = app_state
+    let page = app_state
         .cex_state
         .order_service
-        .get_my_orders(
-            user_id,
-            query.status.as_deref(),
-            query.limit,
-            query.offset,
-        )
+        .get_my_orders(user_id, &filter, cursor, query.limit)
         .await
         .map_err(|e| {
             (
@@ -263,7 +322,7 @@ pub async fn get_my_orders(
             )
         })?;
 
-    Ok(Json(orders))
+    Ok((pagination::next_cursor_headers(page.next_cursor), Json(page.items)))
 }
 
 /// 오더북 응답 모델
@@ -351,3 +410,69 @@ pub async fn get_orderbook(
     Ok(Json(OrderbookResponse { bids, asks }))
 }
 
+
+/// L3 오더북 응답 모델
+/// Level-3 (order-by-order) orderbook response model
+#[derive(Debug, Serialize, ToSchema)]
+pub struct L3OrderbookResponse {
+    /// 기준 자산
+    pub base_mint: String,
+
+    /// 기준 통화
+    pub quote_mint: String,
+
+    /// 스냅샷 시퀀스 (`/api/cex/ws/market`의 l3 채널에서 이 값보다 큰 이벤트부터 적용)
+    /// Snapshot sequence (apply l3 channel events with a greater seq)
+    pub seq: u64,
+
+    /// 매수 주문 (가격 내림차순, 같은 가격은 시간 우선순위)
+    /// Buy orders (price descending, then queue order)
+    pub bids: Vec<L3Order>,
+
+    /// 매도 주문 (가격 오름차순, 같은 가격은 시간 우선순위)
+    /// Sell orders (price ascending, then queue order)
+    pub asks: Vec<L3Order>,
+}
+
+/// L3 오더북 조회 핸들러
+/// Get level-3 orderbook handler
+///
+/// 특정 거래쌍의 주문 단위 호가를 조회합니다.
+/// 주문 ID는 익명화되어 있어 사용자 주문 ID와 연결할 수 없습니다.
+///
+/// # Query Parameters
+/// - base_mint: 기준 자산 (required, 예: "SOL")
+/// - quote_mint: 기준 통화 (optional, 기본: "USDT")
+///
+/// # Response
+/// - 200: L3 오더북 조회 성공
+#[utoipa::path(
+    get,
+    path = "/api/cex/orderbook/l3",
+    params(
+        OrderbookQuery
+    ),
+    responses(
+        (status = 200, description = "Level-3 orderbook retrieved successfully", body = L3OrderbookResponse),
+        (status = 400, description = "Bad request")
+    ),
+    tag = "Orders"
+)]
+pub async fn get_l3_orderbook(
+    State(app_state): State<AppState>,
+    Query(query): Query<OrderbookQuery>,
+) -> Json<L3OrderbookResponse> {
+    let trading_pair = TradingPair::new(query.base_mint, query.quote_mint);
+    let (seq, bids, asks) = app_state
+        .cex_state
+        .market_data_service
+        .l3_book(&trading_pair);
+
+    Json(L3OrderbookResponse {
+        base_mint: trading_pair.base_mint,
+        quote_mint: trading_pair.quote_mint,
+        seq,
+        bids,
+        asks,
+    })
+}
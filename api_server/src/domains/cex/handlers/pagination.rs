@@ -0,0 +1,107 @@
+use crate::shared::utils::cursor::PageCursor;
+use axum::{
+    http::{HeaderMap, HeaderValue, StatusCode},
+    Json,
+};
+use chrono::{DateTime, Utc};
+
+// =====================================================
+// 목록 API 공통 (커서 페이지네이션)
+// =====================================================
+// 역할: 목록 핸들러의 쿼리 검증과 다음 페이지 커서 응답
+//
+// 응답 형식:
+// - 본문: 기존과 같은 배열 (최신순)
+// - 헤더: `X-Next-Cursor` (다음 페이지가 있을 때만)
+//   → 다음 요청에 `cursor=<값>`으로 전달
+// =====================================================
+
+/// 다음 페이지 커서 응답 헤더
+pub const NEXT_CURSOR_HEADER: &str = "x-next-cursor";
+
+type HandlerError = (StatusCode, Json<serde_json::Value>);
+
+fn bad_request(message: String) -> HandlerError {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({ "error": message })),
+    )
+}
+
+/// `cursor` 쿼리 파라미터 해석 (잘못된 커서는 400)
+pub fn parse_cursor(cursor: Option<&str>) -> Result<Option<PageCursor>, HandlerError> {
+    cursor
+        .map(PageCursor::decode)
+        .transpose()
+        .map_err(|e| bad_request(format!("Invalid cursor: {}", e)))
+}
+
+/// 제거된 `offset` 파라미터 거부 (400, 무시하면 같은 첫 페이지를 계속 받게 됨)
+pub fn reject_offset(offset: Option<i64>) -> Result<(), HandlerError> {
+    match offset {
+        Some(_) => Err(bad_request(
+            "`offset` is no longer supported; pass the `X-Next-Cursor` response header value as `cursor`".to_string(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// 필터 값 검증 (허용 목록 밖이면 400)
+pub fn check_one_of(field: &str, value: Option<&str>, allowed: &[&str]) -> Result<(), HandlerError> {
+    match value {
+        Some(value) if !allowed.contains(&value) => Err(bad_request(format!(
+            "Invalid {}: '{}' (expected one of: {})",
+            field,
+            value,
+            allowed.join(", ")
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// 기간 검증 (`from < to`가 아니면 400)
+pub fn check_time_range(from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> Result<(), HandlerError> {
+    match (from, to) {
+        (Some(from), Some(to)) if from >= to => {
+            Err(bad_request("`from` must be earlier than `to`".to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 다음 페이지 커서 헤더 (마지막 페이지면 비어 있음)
+pub fn next_cursor_headers(next_cursor: Option<PageCursor>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(cursor) = next_cursor
+        && let Ok(value) = HeaderValue::from_str(&cursor.encode())
+    {
+        headers.insert(NEXT_CURSOR_HEADER, value);
+    }
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reject_offset_and_invalid_cursor() {
+        assert!(reject_offset(None).is_ok());
+        let (status, Json(body)) = reject_offset(Some(0)).unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("cursor"));
+
+        assert_eq!(parse_cursor(None).unwrap(), None);
+        assert_eq!(parse_cursor(Some("not a cursor")).unwrap_err().0, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_next_cursor_header() {
+        assert!(next_cursor_headers(None).is_empty());
+
+        let cursor = PageCursor::new(Utc::now(), 42);
+        let headers = next_cursor_headers(Some(cursor));
+        let value = headers.get(NEXT_CURSOR_HEADER).unwrap().to_str().unwrap();
+        assert_eq!(PageCursor::decode(value).unwrap().id, 42);
+    }
+}
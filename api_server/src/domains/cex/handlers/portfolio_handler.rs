@@ -0,0 +1,180 @@
+use crate::domains::cex::models::portfolio::{EquitySnapshot, PortfolioValuation};
+use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::services::AppState;
+use axum::{
+    extract::{State, Query},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::{ToSchema, IntoParams};
+
+// =====================================================
+// Portfolio Handler
+// =====================================================
+// 역할: 사용자 포트폴리오 평가 / 일별 자산 기록 조회 API
+//
+// 특징:
+// - 평가는 현재 호가/최근 체결가 기준 (요청 시점 계산)
+// - 일별 기록은 PortfolioService가 매일 00:00 UTC에 저장 (USDT 기준)
+// =====================================================
+
+/// 포트폴리오 평가 쿼리 파라미터
+/// Query parameters for portfolio valuation
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct PortfolioQuery {
+    /// 기준 통화 (예: "USDT", 기본값)
+    /// Quote currency (e.g., "USDT", default)
+    #[serde(default = "default_quote_mint")]
+    pub quote_mint: String,
+}
+
+/// 일별 자산 기록 쿼리 파라미터
+/// Query parameters for equity history
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct PortfolioHistoryQuery {
+    /// 기준 통화 (기본: "USDT", 현재 USDT만 기록)
+    /// Quote currency (default: "USDT")
+    #[serde(default = "default_quote_mint")]
+    pub quote_mint: String,
+
+    /// 시작 날짜 (YYYY-MM-DD, 포함)
+    /// Start date (inclusive)
+    #[serde(default)]
+    #[param(value_type = Option<String>, example = "2026-01-01")]
+    pub from: Option<NaiveDate>,
+
+    /// 끝 날짜 (YYYY-MM-DD, 포함)
+    /// End date (inclusive)
+    #[serde(default)]
+    #[param(value_type = Option<String>, example = "2026-01-31")]
+    pub to: Option<NaiveDate>,
+
+    /// 최대 조회 개수 (기본: 365, 최대: 3650, 범위가 더 넓으면 최근 날짜 우선)
+    /// Limit (default: 365, max: 3650)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+fn default_quote_mint() -> String {
+    "USDT".to_string()
+}
+
+/// 포트폴리오 평가 핸들러
+/// Get portfolio valuation handler
+///
+/// 사용자의 모든 CEX 잔고를 기준 통화로 평가합니다.
+/// 기준 통화와 직접 거래쌍이 없는 자산은 다른 거래쌍을 거쳐 환산합니다.
+///
+/// # Query Parameters
+/// - quote_mint: 기준 통화 (optional, 기본: "USDT")
+///
+/// # Response
+/// - 200: 평가 결과 (환산 경로가 없는 자산은 price/value가 null, 총액에서 제외)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/portfolio",
+    params(
+        PortfolioQuery
+    ),
+    responses(
+        (status = 200, description = "Portfolio valued successfully", body = PortfolioValuation),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Positions",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_portfolio(
+    State(app_state): State<AppState>,
+    Query(query): Query<PortfolioQuery>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<PortfolioValuation>, (StatusCode, Json<serde_json::Value>)> {
+    let valuation = app_state
+        .cex_state
+        .portfolio_service
+        .get_portfolio(authenticated_user.user_id, &query.quote_mint)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to value portfolio: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(valuation))
+}
+
+/// 일별 자산 기록 조회 핸들러
+/// Get equity history handler
+///
+/// 매일 00:00 UTC에 기록한 총 평가액을 날짜 오름차순으로 조회합니다 (수익률 차트용).
+///
+/// # Query Parameters
+/// - quote_mint: 기준 통화 (optional, 기본: "USDT")
+/// - from, to: 날짜 범위 (optional, YYYY-MM-DD, 포함)
+/// - limit: 최대 조회 개수 (optional, 기본: 365, 최대: 3650)
+///
+/// # Response
+/// - 200: 일별 기록 (기록이 없으면 빈 배열)
+/// - 400: 잘못된 요청 (from > to)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/portfolio/history",
+    params(
+        PortfolioHistoryQuery
+    ),
+    responses(
+        (status = 200, description = "Equity history retrieved successfully", body = Vec<EquitySnapshot>),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Positions",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_portfolio_history(
+    State(app_state): State<AppState>,
+    Query(query): Query<PortfolioHistoryQuery>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<Vec<EquitySnapshot>>, (StatusCode, Json<serde_json::Value>)> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "`from` must not be later than `to`"
+            })),
+        ));
+    }
+
+    let history = app_state
+        .cex_state
+        .portfolio_service
+        .get_history(
+            authenticated_user.user_id,
+            &query.quote_mint,
+            query.from,
+            query.to,
+            query.limit,
+        )
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch equity history: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(history))
+}
@@ -3,9 +3,13 @@
 // 역할: 포지션 조회 API 엔드포인트 처리 (평균 매수가, 손익, 수익률 등)
 
 use crate::shared::services::AppState;
-use crate::shared::middleware::auth::AuthenticatedUser;
-use crate::domains::cex::models::position::{AssetPositionResponse, AllPositionsResponse};
-use axum::{extract::{Path, State}, http::StatusCode, Json};
+use crate::shared::middleware::sub_account::TradingAccount;
+use crate::domains::cex::models::position::{
+    AssetPositionResponse, AllPositionsResponse, CostBasisMethodRequest, PositionLotsResponse,
+};
+use axum::{extract::{Path, Query, State}, http::StatusCode, Json};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
 
 /// 사용자의 특정 자산 포지션 조회 핸들러
 /// Get position for specific asset
@@ -18,7 +22,7 @@ use axum::{extract::{Path, State}, http::StatusCode, Json};
 /// 
 /// # Returns
 /// * `200 OK` - 포지션 정보 반환 (자산을 보유한 경우)
-/// * `404 Not Found` - 포지션 정보 없음 (자산을 보유하지 않거나 매수 거래가 없는 경우)
+/// * `404 Not Found` - 포지션 정보 없음 (자산을 보유하지 않은 경우)
 /// * `401 Unauthorized` - 인증 실패
 /// * `500 Internal Server Error` - 서버 오류
 /// 
@@ -31,16 +35,20 @@ use axum::{extract::{Path, State}, http::StatusCode, Json};
 ///     "available": "10.0",
 ///     "locked": "1.0",
 ///     "average_entry_price": "100.5",
+///     "cost_basis_method": "fifo",
+///     "open_amount": "10.0",
+///     "cost_basis": "1005.0",
 ///     "total_bought_amount": "15.0",
 ///     "total_bought_cost": "1507.5",
 ///     "current_market_price": "110.0",
 ///     "current_value": "1210.0",
-///     "unrealized_pnl": "702.5",
-///     "unrealized_pnl_percent": "46.6",
+///     "unrealized_pnl": "95.0",
+///     "unrealized_pnl_percent": "9.45",
 ///     "trade_summary": {
 ///       "total_buy_trades": 5,
 ///       "total_sell_trades": 2,
-///       "realized_pnl": "50.0"
+///       "realized_pnl": "50.0",
+///       "total_fees": "0.3"
 ///     }
 ///   }
 /// }
@@ -53,7 +61,7 @@ use axum::{extract::{Path, State}, http::StatusCode, Json};
     ),
     responses(
         (status = 200, description = "Position retrieved successfully", body = AssetPositionResponse),
-        (status = 404, description = "Position not found (user does not hold this asset or has no buy trades)"),
+        (status = 404, description = "Position not found (user does not hold this asset)"),
         (status = 401, description = "Unauthorized (missing or invalid token)"),
         (status = 500, description = "Internal server error")
     ),
@@ -63,11 +71,11 @@ use axum::{extract::{Path, State}, http::StatusCode, Json};
 pub async fn get_position(
     State(app_state): State<AppState>,
     Path(mint): Path<String>,
-    authenticated_user: AuthenticatedUser,
+    trading_account: TradingAccount,
 ) -> Result<Json<AssetPositionResponse>, (StatusCode, Json<serde_json::Value>)> {
-    // JWT 토큰에서 추출한 user_id 사용
-    // Use user_id extracted from JWT token
-    let user_id = authenticated_user.user_id;
+    // JWT 토큰의 user_id (X-Sub-Account-Id 헤더가 있으면 서브 계정 ID)
+    // Use user_id from JWT token (or the sub-account from X-Sub-Account-Id)
+    let user_id = trading_account.user_id;
 
     // PositionService를 통해 포지션 정보 조회
     // Get position information through PositionService
@@ -117,16 +125,20 @@ pub async fn get_position(
 ///       "available": "10.0",
 ///       "locked": "1.0",
 ///       "average_entry_price": "100.5",
+///       "cost_basis_method": "fifo",
+///       "open_amount": "10.0",
+///       "cost_basis": "1005.0",
 ///       "total_bought_amount": "15.0",
 ///       "total_bought_cost": "1507.5",
 ///       "current_market_price": "110.0",
 ///       "current_value": "1210.0",
-///       "unrealized_pnl": "702.5",
-///       "unrealized_pnl_percent": "46.6",
+///       "unrealized_pnl": "95.0",
+///       "unrealized_pnl_percent": "9.45",
 ///       "trade_summary": {
 ///         "total_buy_trades": 5,
 ///         "total_sell_trades": 2,
-///         "realized_pnl": "50.0"
+///         "realized_pnl": "50.0",
+///         "total_fees": "0.3"
 ///       }
 ///     }
 ///   ]
@@ -145,11 +157,11 @@ pub async fn get_position(
 )]
 pub async fn get_all_positions(
     State(app_state): State<AppState>,
-    authenticated_user: AuthenticatedUser,
+    trading_account: TradingAccount,
 ) -> Result<Json<AllPositionsResponse>, (StatusCode, Json<serde_json::Value>)> {
-    // JWT 토큰에서 추출한 user_id 사용
-    // Use user_id extracted from JWT token
-    let user_id = authenticated_user.user_id;
+    // JWT 토큰의 user_id (X-Sub-Account-Id 헤더가 있으면 서브 계정 ID)
+    // Use user_id from JWT token (or the sub-account from X-Sub-Account-Id)
+    let user_id = trading_account.user_id;
 
     // PositionService를 통해 모든 포지션 정보 조회
     // Get all positions through PositionService
@@ -170,3 +182,125 @@ pub async fn get_all_positions(
     Ok(Json(AllPositionsResponse { positions }))
 }
 
+
+/// 포지션 lot 조회 쿼리 파라미터
+/// Query parameters for position lots
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct PositionLotsQuery {
+    /// 최근 매도 체결별 실현 손익 개수 (기본: 50, 최대: 1000)
+    /// Number of recent realized P&L entries (default: 50, max: 1000)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// 사용자의 특정 자산 원가 lot 조회 핸들러
+/// Get cost basis lots for specific asset
+/// 
+/// 경로: GET /api/cex/positions/{mint}/lots
+/// 인증: 필요 (JWT 토큰)
+/// 
+/// 남은 매수 lot(수수료 포함 원가)과 최근 매도 체결별 실현 손익을 반환합니다.
+/// 조회 시점까지의 새 체결만 lot에 반영합니다 (전체 내역 재계산 없음).
+/// 
+/// # Path Parameters
+/// * `mint` - 자산 식별자 (예: "SOL")
+/// 
+/// # Returns
+/// * `200 OK` - lot 목록과 실현 손익
+/// * `401 Unauthorized` - 인증 실패
+/// * `500 Internal Server Error` - 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/positions/{mint}/lots",
+    params(
+        ("mint" = String, Path, description = "Asset identifier (e.g., 'SOL')"),
+        PositionLotsQuery
+    ),
+    responses(
+        (status = 200, description = "Position lots retrieved successfully", body = PositionLotsResponse),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Positions",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_position_lots(
+    State(app_state): State<AppState>,
+    Path(mint): Path<String>,
+    Query(query): Query<PositionLotsQuery>,
+    trading_account: TradingAccount,
+) -> Result<Json<PositionLotsResponse>, (StatusCode, Json<serde_json::Value>)> {
+    let lots = app_state
+        .cex_state
+        .position_service
+        .get_position_lots(trading_account.user_id, &mint, query.limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch position lots: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(lots))
+}
+
+/// 사용자의 특정 자산 원가 계산 방식 변경 핸들러
+/// Change cost basis method for specific asset
+/// 
+/// 경로: PUT /api/cex/positions/{mint}/cost-basis
+/// 인증: 필요 (JWT 토큰)
+/// 
+/// 방식이 바뀌면 해당 자산의 lot과 실현 손익을 전체 체결 내역으로 다시 계산합니다.
+/// 
+/// # Request Body
+/// ```json
+/// { "method": "lifo" }
+/// ```
+/// method: "fifo" | "lifo" | "average_cost"
+/// 
+/// # Returns
+/// * `200 OK` - 다시 계산한 lot 목록과 실현 손익
+/// * `400 Bad Request` - 알 수 없는 방식
+/// * `401 Unauthorized` - 인증 실패
+/// * `500 Internal Server Error` - 서버 오류
+#[utoipa::path(
+    put,
+    path = "/api/cex/positions/{mint}/cost-basis",
+    params(
+        ("mint" = String, Path, description = "Asset identifier (e.g., 'SOL')")
+    ),
+    request_body = CostBasisMethodRequest,
+    responses(
+        (status = 200, description = "Cost basis method changed, lots rebuilt", body = PositionLotsResponse),
+        (status = 400, description = "Unknown cost basis method"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Positions",
+    security(("BearerAuth" = []))
+)]
+pub async fn set_cost_basis_method(
+    State(app_state): State<AppState>,
+    Path(mint): Path<String>,
+    trading_account: TradingAccount,
+    Json(request): Json<CostBasisMethodRequest>,
+) -> Result<Json<PositionLotsResponse>, (StatusCode, Json<serde_json::Value>)> {
+    let lots = app_state
+        .cex_state
+        .position_service
+        .set_cost_basis_method(trading_account.user_id, &mint, request.method)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to change cost basis method: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(lots))
+}
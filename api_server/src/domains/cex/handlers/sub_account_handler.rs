@@ -0,0 +1,251 @@
+use crate::domains::cex::models::sub_account::{
+    CreateSubAccountRequest, SubAccount, SubAccountBalancesResponse, SubAccountPositionsResponse,
+    SubAccountTransferRequest,
+};
+use crate::domains::cex::models::transfer::Transfer;
+use crate::domains::cex::services::CreateTransferResult;
+use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::services::AppState;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    Json,
+};
+
+// =====================================================
+// Sub-account Handler
+// =====================================================
+// 역할: 서브 계정 생성/목록, 계정 간 이체, 합산 잔고/포지션 API
+//
+// 특징:
+// - 모두 마스터(로그인한 사용자) 기준 (X-Sub-Account-Id 헤더 사용 안 함)
+// - 서브 계정 대신 주문/조회는 기존 API에 X-Sub-Account-Id 헤더 지정
+// =====================================================
+
+/// 서브 계정 생성 핸들러
+/// Create sub-account handler
+///
+/// # Request Body
+/// - label: 이름 (영문 소문자, 숫자, '-', '_', 최대 32자)
+///
+/// # Response
+/// - 201: 서브 계정 생성 (user_id를 X-Sub-Account-Id로 사용)
+/// - 400: 잘못된 이름, 서브 계정에서 요청, 개수 초과
+/// - 401: 인증 실패
+/// - 409: 같은 이름의 서브 계정이 이미 있음
+#[utoipa::path(
+    post,
+    path = "/api/cex/sub-accounts",
+    request_body = CreateSubAccountRequest,
+    responses(
+        (status = 201, description = "Sub-account created", body = SubAccount),
+        (status = 400, description = "Bad request (invalid label, nested sub-account, limit reached)"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 409, description = "Sub-account with this label already exists")
+    ),
+    tag = "CEX Sub-accounts",
+    security(("BearerAuth" = []))
+)]
+pub async fn create_sub_account(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(request): Json<CreateSubAccountRequest>,
+) -> Result<(StatusCode, Json<SubAccount>), (StatusCode, Json<serde_json::Value>)> {
+    let sub_account = app_state
+        .cex_state
+        .sub_account_service
+        .create_sub_account(authenticated_user.user_id, request.label.trim())
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("{:#}", e)
+                })),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({ "error": "Sub-account with this label already exists" })),
+            )
+        })?;
+
+    Ok((StatusCode::CREATED, Json(sub_account)))
+}
+
+/// 서브 계정 목록 핸들러
+/// List sub-accounts handler
+///
+/// # Response
+/// - 200: 서브 계정 목록 (생성순)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/sub-accounts",
+    responses(
+        (status = 200, description = "Sub-accounts retrieved successfully", body = Vec<SubAccount>),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Sub-accounts",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_sub_accounts(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<Vec<SubAccount>>, (StatusCode, Json<serde_json::Value>)> {
+    let sub_accounts = app_state
+        .cex_state
+        .sub_account_service
+        .list_sub_accounts(authenticated_user.user_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch sub-accounts: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(sub_accounts))
+}
+
+/// 계정 간 이체 핸들러 (마스터 ↔ 서브, 서브 ↔ 서브)
+/// Transfer between the master and its sub-accounts handler
+///
+/// # Request Body
+/// - from_account_id / to_account_id: 본인 ID 또는 본인의 서브 계정 ID
+/// - mint, amount, memo: 이체 내용
+/// - idempotency_key: 멱등 키 (보내는 계정 기준)
+///
+/// # Response
+/// - 201: 이체 완료
+/// - 200: 같은 멱등 키의 기존 이체
+/// - 400: 잘못된 요청 (소유하지 않은 계정, 잘못된 수량, 잔고 부족)
+/// - 401: 인증 실패
+/// - 409: 같은 멱등 키로 다른 내용의 이체가 이미 있음
+#[utoipa::path(
+    post,
+    path = "/api/cex/sub-accounts/transfers",
+    request_body = SubAccountTransferRequest,
+    responses(
+        (status = 201, description = "Transfer completed", body = Transfer),
+        (status = 200, description = "Existing transfer for this idempotency key", body = Transfer),
+        (status = 400, description = "Bad request (account not owned, invalid amount, insufficient balance)"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 409, description = "Idempotency key already used for a different transfer")
+    ),
+    tag = "CEX Sub-accounts",
+    security(("BearerAuth" = []))
+)]
+pub async fn transfer_between_sub_accounts(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(request): Json<SubAccountTransferRequest>,
+) -> Result<(StatusCode, Json<Transfer>), (StatusCode, Json<serde_json::Value>)> {
+    let result = app_state
+        .cex_state
+        .sub_account_service
+        .transfer(authenticated_user.user_id, &request)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("{:#}", e)
+                })),
+            )
+        })?;
+
+    match result {
+        CreateTransferResult::Created(transfer) => Ok((StatusCode::CREATED, Json(transfer))),
+        CreateTransferResult::Replayed(transfer) => Ok((StatusCode::OK, Json(transfer))),
+        CreateTransferResult::KeyReused => Err((
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": "Idempotency key already used for a different transfer"
+            })),
+        )),
+    }
+}
+
+/// 합산 잔고 핸들러
+/// Aggregated balances handler
+///
+/// # Response
+/// - 200: 자산별 합계 + 계정별 잔고 (마스터 먼저)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/sub-accounts/balances",
+    responses(
+        (status = 200, description = "Aggregated balances", body = SubAccountBalancesResponse),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Sub-accounts",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_sub_account_balances(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<SubAccountBalancesResponse>, (StatusCode, Json<serde_json::Value>)> {
+    let response = app_state
+        .cex_state
+        .sub_account_service
+        .aggregated_balances(authenticated_user.user_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch balances: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(response))
+}
+
+/// 합산 포지션 핸들러
+/// Aggregated positions handler
+///
+/// # Response
+/// - 200: 자산별 합계 + 계정별 포지션 (마스터 먼저)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/sub-accounts/positions",
+    responses(
+        (status = 200, description = "Aggregated positions", body = SubAccountPositionsResponse),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Sub-accounts",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_sub_account_positions(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<SubAccountPositionsResponse>, (StatusCode, Json<serde_json::Value>)> {
+    let response = app_state
+        .cex_state
+        .sub_account_service
+        .aggregated_positions(authenticated_user.user_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch positions: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,77 @@
+use crate::domains::cex::models::ticker::Ticker;
+use crate::shared::services::AppState;
+use axum::{
+    extract::{State, Query},
+    Json,
+};
+use serde::Deserialize;
+use utoipa::{ToSchema, IntoParams};
+
+// =====================================================
+// Ticker Handler
+// =====================================================
+// 역할: 24시간 롤링 ticker 조회 API
+//
+// 특징:
+// - 읽기 전용, DB 조회 없음 (TickerService 메모리 상태)
+// - 실시간 갱신은 `/api/cex/ws/market`의 `ticker` 채널 사용
+// =====================================================
+
+/// ticker 조회 쿼리 파라미터
+/// Query parameters for ticker
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct TickerQuery {
+    /// 기준 자산 (예: "SOL", 생략하면 모든 거래쌍)
+    /// Base asset (e.g., "SOL", all markets if omitted)
+    #[serde(default)]
+    pub base_mint: Option<String>,
+
+    /// 기준 통화 (예: "USDT", 기본값)
+    /// Quote currency (e.g., "USDT", default)
+    #[serde(default = "default_quote_mint")]
+    pub quote_mint: String,
+}
+
+fn default_quote_mint() -> String {
+    "USDT".to_string()
+}
+
+/// 24시간 ticker 조회 핸들러
+/// Get 24h ticker handler
+///
+/// 최근 24시간 시가/고가/저가/최근가/변동률/거래량/체결 건수와 최우선 호가를 조회합니다.
+///
+/// # Query Parameters
+/// - base_mint: 기준 자산 (optional, 생략하면 모든 거래쌍)
+/// - quote_mint: 기준 통화 (optional, 기본: "USDT", base_mint가 있을 때만 사용)
+///
+/// # Response
+/// - 200: ticker 목록 (base_mint 지정 시 1개, 체결이 없으면 통계가 비어 있음)
+///
+/// # 용도
+/// - 거래소 메인 페이지 마켓 목록
+/// - 거래 화면 상단 24시간 통계
+#[utoipa::path(
+    get,
+    path = "/api/cex/ticker",
+    params(
+        TickerQuery
+    ),
+    responses(
+        (status = 200, description = "Tickers retrieved successfully", body = Vec<Ticker>)
+    ),
+    tag = "CEX Trades"
+)]
+pub async fn get_ticker(
+    State(app_state): State<AppState>,
+    Query(query): Query<TickerQuery>,
+) -> Json<Vec<Ticker>> {
+    let ticker_service = &app_state.cex_state.ticker_service;
+
+    let tickers = match query.base_mint {
+        Some(base_mint) => vec![ticker_service.get_ticker(&base_mint, &query.quote_mint)],
+        None => ticker_service.get_tickers(),
+    };
+
+    Json(tickers)
+}
@@ -1,11 +1,13 @@
-use crate::domains::cex::models::trade::Trade;
+use crate::domains::cex::models::trade::{Trade, TradeFilter};
 use crate::shared::services::AppState;
-use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::middleware::sub_account::TradingAccount;
+use super::pagination;
 use axum::{
     extract::{State, Query},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     Json,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{ToSchema, IntoParams};
 
@@ -31,6 +33,21 @@ pub struct TradesQuery {
     /// Quote currency (e.g., "USDT", default)
     #[serde(default = "default_quote_mint")]
     pub quote_mint: String,
+
+    /// 체결 시간 하한 (RFC 3339, 포함)
+    /// Executed at or after
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// 체결 시간 상한 (RFC 3339, 미포함)
+    /// Executed before
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    /// 다음 페이지 커서 (이전 응답의 `X-Next-Cursor` 헤더 값)
+    /// Cursor from the previous response's `X-Next-Cursor` header
+    #[serde(default)]
+    pub cursor: Option<String>,
     
     /// 최대 조회 개수 (기본: 50, 최대: 1000)
     /// Limit (default: 50, max: 1000)
@@ -45,16 +62,19 @@ fn default_quote_mint() -> String {
 /// 거래쌍별 체결 내역 조회 핸들러
 /// Get trades for trading pair handler
 /// 
-/// 특정 거래쌍(예: SOL/USDT)의 최근 체결 내역을 조회합니다.
+/// 특정 거래쌍(예: SOL/USDT)의 체결 내역을 최신순으로 조회합니다.
+/// 다음 페이지가 있으면 `X-Next-Cursor` 헤더로 커서를 반환합니다.
 /// 
 /// # Query Parameters
 /// - base_mint: 기준 자산 (required, 예: "SOL")
 /// - quote_mint: 기준 통화 (optional, 기본: "USDT")
+/// - from, to: 체결 시간 구간 (optional, RFC 3339)
+/// - cursor: 다음 페이지 커서 (optional)
 /// - limit: 최대 조회 개수 (optional, 기본: 50, 최대: 1000)
 /// 
 /// # Response
 /// - 200: 체결 내역 조회 성공
-/// - 400: 잘못된 요청
+/// - 400: 잘못된 요청 (기간, 커서)
 /// - 500: 서버 오류
 /// 
 /// # 용도
@@ -67,7 +87,8 @@ fn default_quote_mint() -> String {
         TradesQuery
     ),
     responses(
-        (status = 200, description = "Trades retrieved successfully", body = Vec<Trade>),
+        (status = 200, description = "Trades retrieved successfully", body = Vec<Trade>,
+            headers(("X-Next-Cursor" = String, description = "Cursor for the next page (absent on the last page)"))),
         (status = 400, description = "Bad request"),
         (status = 500, description = "Internal server error")
     ),
@@ -76,12 +97,22 @@ fn default_quote_mint() -> String {
 pub async fn get_trades(
     State(app_state): State<AppState>,
     Query(query): Query<TradesQuery>,
-) -> Result<Json<Vec<Trade>>, (StatusCode, Json<serde_json::Value>)> {
+) -> Result<(HeaderMap, Json<Vec<Trade>>), (StatusCode, Json<serde_json::Value>)> {
+    pagination::check_time_range(query.from, query.to)?;
+    let cursor = pagination::parse_cursor(query.cursor.as_deref())?;
+
+    let filter = TradeFilter {
+        base_mint: Some(query.base_mint),
+        quote_mint: Some(query.quote_mint),
+        from: query.from,
+        to: query.to,
+    };
+
     // Service 호출
-    let trades = app_state
+    let page = app_state
         .cex_state
         .trade_service
-        .get_trades(&query.base_mint, &query.quote_mint, query.limit)
+        .get_trades(&filter, cursor, query.limit)
         .await
         .map_err(|e| {
             (
@@ -92,51 +123,80 @@ pub async fn get_trades(
             )
         })?;
 
-    Ok(Json(trades))
+    Ok((pagination::next_cursor_headers(page.next_cursor), Json(page.items)))
 }
 
 /// 내 체결 내역 쿼리 파라미터
 /// Query parameters for my trades
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
 pub struct MyTradesQuery {
-    /// 자산 식별자 (선택, 특정 자산만 필터링)
-    /// Asset identifier (optional, filter by specific asset)
+    /// 기준 자산 필터 (예: "SOL", 이전 이름 `mint`도 허용)
+    /// Base asset filter (legacy name `mint` also accepted)
+    #[serde(default, alias = "mint")]
+    pub base_mint: Option<String>,
+
+    /// 기준 통화 필터 (예: "USDT")
+    /// Quote currency filter
     #[serde(default)]
-    pub mint: Option<String>,
-    
-    /// 최대 조회 개수 (기본: 100)
-    /// Limit (default: 100)
+    pub quote_mint: Option<String>,
+
+    /// 내 방향 필터 (buy: 내가 매수자, sell: 내가 매도자)
+    /// My side filter
     #[serde(default)]
-    pub limit: Option<i64>,
-    
-    /// 페이지네이션 오프셋 (기본: 0)
-    /// Offset for pagination (default: 0)
+    pub side: Option<String>,
+
+    /// 체결 시간 하한 (RFC 3339, 포함)
+    /// Executed at or after
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// 체결 시간 상한 (RFC 3339, 미포함)
+    /// Executed before
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    /// 다음 페이지 커서 (이전 응답의 `X-Next-Cursor` 헤더 값)
+    /// Cursor from the previous response's `X-Next-Cursor` header
+    #[serde(default)]
+    pub cursor: Option<String>,
+
+    /// 제거됨: 보내면 400 (`cursor` 사용)
+    /// Removed, rejected with 400 (use `cursor`)
     #[serde(default)]
     pub offset: Option<i64>,
+    
+    /// 최대 조회 개수 (기본: 100, 최대: 1000)
+    /// Limit (default: 100, max: 1000)
+    #[serde(default)]
+    pub limit: Option<i64>,
 }
 
 /// 내 체결 내역 조회 핸들러
 /// Get my trades handler
 /// 
-/// 현재 로그인한 사용자가 참여한 모든 체결 내역을 조회합니다.
-/// 특정 자산(mint)을 지정하면 해당 자산의 거래 내역만 필터링합니다.
+/// 현재 로그인한 사용자가 참여한 체결 내역을 최신순으로 조회합니다.
+/// 다음 페이지가 있으면 `X-Next-Cursor` 헤더로 커서를 반환합니다.
 /// 
 /// # Authentication
 /// JWT 토큰 필요
 /// 
 /// # Query Parameters
-/// - mint: 자산 식별자 (optional, 예: "SOL", 특정 자산만 필터링)
-/// - limit: 최대 조회 개수 (optional, default: 100)
-/// - offset: 페이지네이션 오프셋 (optional, default: 0)
+/// - base_mint (또는 mint), quote_mint: 거래쌍 필터 (optional)
+/// - side: buy/sell (optional, 내가 매수자/매도자인 체결)
+/// - from, to: 체결 시간 구간 (optional, RFC 3339)
+/// - cursor: 다음 페이지 커서 (optional)
+/// - limit: 최대 조회 개수 (optional, default: 100, max: 1000)
 /// 
 /// # Response
 /// - 200: 체결 내역 조회 성공
+/// - 400: 잘못된 필터 값 또는 커서, 제거된 `offset` 사용
 /// - 401: 인증 실패
 /// - 500: 서버 오류
 /// 
 /// # 용도
 /// - 사용자 마이페이지의 "내 거래 내역" 표시
 /// - 특정 자산의 거래 내역 조회 (포지션 페이지에서 사용)
+/// - 리포트 작업 (커서로 전체 내역 순회)
 #[utoipa::path(
     get,
     path = "/api/cex/trades/my",
@@ -144,7 +204,9 @@ pub struct MyTradesQuery {
         MyTradesQuery
     ),
     responses(
-        (status = 200, description = "My trades retrieved successfully", body = Vec<Trade>),
+        (status = 200, description = "My trades retrieved successfully", body = Vec<Trade>,
+            headers(("X-Next-Cursor" = String, description = "Cursor for the next page (absent on the last page)"))),
+        (status = 400, description = "Invalid filter or cursor, or removed `offset` parameter"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
     ),
@@ -155,14 +217,26 @@ pub struct MyTradesQuery {
 )]
 pub async fn get_my_trades(
     State(app_state): State<AppState>,
-    AuthenticatedUser { user_id, .. }: AuthenticatedUser,
+    TradingAccount { user_id }: TradingAccount,
     Query(query): Query<MyTradesQuery>,
-) -> Result<Json<Vec<Trade>>, (StatusCode, Json<serde_json::Value>)> {
-    // Service 호출 (mint 파라미터 전달)
-    let trades = app_state
+) -> Result<(HeaderMap, Json<Vec<Trade>>), (StatusCode, Json<serde_json::Value>)> {
+    pagination::check_one_of("side", query.side.as_deref(), &["buy", "sell"])?;
+    pagination::check_time_range(query.from, query.to)?;
+    pagination::reject_offset(query.offset)?;
+    let cursor = pagination::parse_cursor(query.cursor.as_deref())?;
+
+    let filter = TradeFilter {
+        base_mint: query.base_mint,
+        quote_mint: query.quote_mint,
+        from: query.from,
+        to: query.to,
+    };
+
+    // Service 호출
+    let page = app_state
         .cex_state
         .trade_service
-        .get_my_trades(user_id, query.mint.as_deref(), query.limit, query.offset)
+        .get_my_trades(user_id, &filter, query.side.as_deref(), cursor, query.limit)
         .await
         .map_err(|e| {
             (
@@ -173,7 +247,7 @@ pub async fn get_my_trades(
             )
         })?;
 
-    Ok(Json(trades))
+    Ok((pagination::next_cursor_headers(page.next_cursor), Json(page.items)))
 }
 
 /// 최근 가격 응답 모델
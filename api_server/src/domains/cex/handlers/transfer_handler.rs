@@ -0,0 +1,234 @@
+use crate::domains::cex::models::transfer::{CreateTransferRequest, Transfer, TransferDirection, TransferStatus};
+use crate::domains::cex::services::CreateTransferResult;
+use crate::shared::middleware::sub_account::TradingAccount;
+use crate::shared::services::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+// =====================================================
+// Transfer Handler
+// =====================================================
+// 역할: 거래소 사용자 간 내부 이체 / 이체 내역 API
+//
+// 특징:
+// - 엔진 스레드에서 양쪽 available을 한 번에 이동 (온체인 트랜잭션 없음)
+// - 멱등 키 필수 (Idempotency-Key 헤더 또는 body의 idempotency_key)
+//   → 같은 키 재요청은 기존 이체 반환 (200), 다른 내용이면 409
+// - 보낸 사용자와 받은 사용자 모두 내역/상세 조회 가능
+// =====================================================
+
+/// 멱등 키 헤더
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// 이체 내역 쿼리 파라미터
+/// Query parameters for transfer history
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct TransferListQuery {
+    /// 방향 필터 (outgoing, incoming, 없으면 둘 다)
+    /// Direction filter (optional)
+    #[serde(default)]
+    pub direction: Option<TransferDirection>,
+
+    /// 자산 필터 (optional)
+    /// Asset filter (optional)
+    #[serde(default)]
+    pub mint: Option<String>,
+
+    /// 최대 조회 개수 (기본: 50, 최대: 200)
+    /// Limit (default: 50, max: 200)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// 내부 이체 핸들러
+/// Create internal transfer handler
+///
+/// # Headers
+/// - Idempotency-Key: 멱등 키 (없으면 body의 idempotency_key 사용)
+///
+/// # Request Body
+/// - to_user_id 또는 to_email: 받는 사용자
+/// - mint: 자산 (예: "USDT")
+/// - amount: 이체 수량
+/// - memo: 메모 (optional)
+///
+/// # Response
+/// - 201: 이체 완료 (status: completed)
+/// - 202: 엔진 응답을 받지 못함 (status: pending, 원장에 반영되면 completed)
+/// - 200: 같은 멱등 키의 기존 이체
+/// - 400: 잘못된 요청 (멱등 키 없음, 받는 사용자 없음, 잘못된 수량, 잔고 부족)
+/// - 401: 인증 실패
+/// - 409: 같은 멱등 키로 다른 내용의 이체가 이미 있음
+#[utoipa::path(
+    post,
+    path = "/api/cex/transfers",
+    request_body = CreateTransferRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Idempotency key (alternative to body idempotency_key)")
+    ),
+    responses(
+        (status = 201, description = "Transfer completed", body = Transfer),
+        (status = 202, description = "Transfer outcome not yet known (status: pending)", body = Transfer),
+        (status = 200, description = "Existing transfer for this idempotency key", body = Transfer),
+        (status = 400, description = "Bad request (missing idempotency key, unknown recipient, invalid amount, insufficient balance)"),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 409, description = "Idempotency key already used for a different transfer")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn create_transfer(
+    State(app_state): State<AppState>,
+    trading_account: TradingAccount,
+    headers: HeaderMap,
+    Json(request): Json<CreateTransferRequest>,
+) -> Result<(StatusCode, Json<Transfer>), (StatusCode, Json<serde_json::Value>)> {
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .or_else(|| request.idempotency_key.clone())
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "Idempotency key is required (Idempotency-Key header or idempotency_key)"
+                })),
+            )
+        })?;
+
+    let result = app_state
+        .cex_state
+        .transfer_service
+        .create_transfer(trading_account.user_id, &request, idempotency_key.trim())
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("{:#}", e)
+                })),
+            )
+        })?;
+
+    match result {
+        CreateTransferResult::Created(transfer) if transfer.status == TransferStatus::Pending => {
+            Ok((StatusCode::ACCEPTED, Json(transfer)))
+        }
+        CreateTransferResult::Created(transfer) => Ok((StatusCode::CREATED, Json(transfer))),
+        CreateTransferResult::Replayed(transfer) => Ok((StatusCode::OK, Json(transfer))),
+        CreateTransferResult::KeyReused => Err((
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": "Idempotency key already used for a different transfer"
+            })),
+        )),
+    }
+}
+
+/// 내 이체 내역 핸들러
+/// Get my transfers handler
+///
+/// # Query Parameters
+/// - direction: outgoing / incoming (optional, 기본: 둘 다)
+/// - mint: 자산 필터 (optional)
+/// - limit: 최대 조회 개수 (optional, 기본: 50, 최대: 200)
+///
+/// # Response
+/// - 200: 보낸/받은 이체 내역 (최신순)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/transfers",
+    params(
+        TransferListQuery
+    ),
+    responses(
+        (status = 200, description = "Transfers retrieved successfully", body = Vec<Transfer>),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_my_transfers(
+    State(app_state): State<AppState>,
+    Query(query): Query<TransferListQuery>,
+    trading_account: TradingAccount,
+) -> Result<Json<Vec<Transfer>>, (StatusCode, Json<serde_json::Value>)> {
+    let transfers = app_state
+        .cex_state
+        .transfer_service
+        .list_transfers(trading_account.user_id, query.direction, query.mint.as_deref(), query.limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch transfers: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(transfers))
+}
+
+/// 이체 조회 핸들러
+/// Get transfer handler
+///
+/// # Path Parameters
+/// - id: 이체 ID
+///
+/// # Response
+/// - 200: 이체 정보
+/// - 401: 인증 실패
+/// - 404: 이체 없음 (보내거나 받은 이체가 아닌 경우 포함)
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/transfers/{id}",
+    params(
+        ("id" = u64, Path, description = "Transfer ID")
+    ),
+    responses(
+        (status = 200, description = "Transfer retrieved successfully", body = Transfer),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 404, description = "Transfer not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_transfer(
+    State(app_state): State<AppState>,
+    Path(id): Path<u64>,
+    trading_account: TradingAccount,
+) -> Result<Json<Transfer>, (StatusCode, Json<serde_json::Value>)> {
+    let transfer = app_state
+        .cex_state
+        .transfer_service
+        .get_transfer(trading_account.user_id, id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch transfer: {}", e)
+                })),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": "Transfer not found" })),
+            )
+        })?;
+
+    Ok(Json(transfer))
+}
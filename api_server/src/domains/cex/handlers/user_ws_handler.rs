@@ -0,0 +1,207 @@
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::domains::cex::services::{ResumeResult, UserStreamService};
+use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::services::AppState;
+
+// =====================================================
+// User WebSocket Handler
+// =====================================================
+// 역할: 인증된 사용자의 주문/체결/잔고 실시간 스트림
+//
+// 인증:
+// - `Authorization: Bearer <token>` 헤더 (봇/서버)
+// - `?token=<token>` 쿼리 (브라우저 WebSocket은 헤더를 설정할 수 없음)
+// - 둘 다 `AuthenticatedUser`와 같은 JWT 검증 사용
+//
+// 재연결 (resume):
+// - `?stream_id=<id>&since=<seq>`로 연결하면 since 이후 메시지를 먼저 재전송
+// - 재전송할 수 없으면 `resync_required` 후 현재 시점부터 스트리밍 (REST로 상태 재조회 필요)
+//
+// 프로토콜:
+// ← {"type":"hello","user_id":1,"stream_id":1700000000000,"seq":42}
+// ← {"type":"order_update","seq":43,...} / {"type":"fill",...} / {"type":"balance",...}
+// → {"op":"ping"}  ← {"type":"pong"}
+// =====================================================
+
+/// 사용자 스트림 연결 쿼리 파라미터
+/// Query parameters for user stream connection
+#[derive(Debug, Deserialize)]
+pub struct UserWsQuery {
+    /// Access Token (Authorization 헤더가 없을 때)
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// 이전 연결의 스트림 ID (hello 메시지에서 받은 값)
+    #[serde(default)]
+    pub stream_id: Option<i64>,
+
+    /// 이전 연결에서 마지막으로 받은 시퀀스
+    #[serde(default)]
+    pub since: Option<u64>,
+}
+
+/// 클라이언트 → 서버 메시지
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ClientMessage {
+    /// 연결 확인
+    Ping,
+}
+
+/// 사용자 스트림 WebSocket 핸들러
+/// Authenticated user stream WebSocket handler
+///
+/// `GET /api/cex/ws/user` (WebSocket upgrade)
+///
+/// # Response
+/// - 101: WebSocket 연결
+/// - 401: 인증 실패 (업그레이드 전에 거부)
+pub async fn user_ws(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<UserWsQuery>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let header_token = headers
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    let token = match header_token.or(query.token.as_deref()) {
+        Some(token) => token,
+        None => {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({ "error": "Missing access token" })),
+            )
+                .into_response();
+        }
+    };
+
+    let user = match AuthenticatedUser::from_token(&app_state, token) {
+        Ok(user) => user,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    let service = app_state.cex_state.user_stream_service.clone();
+    ws.on_upgrade(move |socket| run_user_session(socket, service, user.user_id, query))
+}
+
+/// WebSocket 세션 루프
+///
+/// # 처리 과정
+/// 1. 브로드캐스트 수신기 생성 (resume 조회보다 먼저)
+/// 2. hello 전송 후 since 이후 메시지 재전송 (또는 resync_required)
+/// 3. 브로드캐스트 프레임 중 자기 user_id + 이미 보낸 시퀀스 이후만 전송
+/// 4. 수신기가 밀리면 (Lagged) 재전송 버퍼에서 복구
+async fn run_user_session(
+    mut socket: WebSocket,
+    service: UserStreamService,
+    user_id: u64,
+    query: UserWsQuery,
+) {
+    let mut frames = service.subscribe();
+
+    // 다른 스트림(서버 재시작 전)의 시퀀스는 의미가 없음
+    let since = match query.stream_id {
+        Some(stream_id) if stream_id != service.stream_id() => Some(0),
+        _ => query.since,
+    };
+
+    let (last_seq, resumed) = service.resume(user_id, since);
+    let hello = serde_json::json!({
+        "type": "hello",
+        "user_id": user_id,
+        "stream_id": service.stream_id(),
+        "seq": last_seq,
+    });
+    if socket.send(Message::Text(hello.to_string())).await.is_err() {
+        return;
+    }
+    let mut sent_seq = match send_resume(&mut socket, resumed).await {
+        Some(_) => last_seq,
+        None => return,
+    };
+
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => {
+                let text = match incoming {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                    Some(Ok(_)) => continue,
+                };
+
+                let reply = match serde_json::from_str::<ClientMessage>(&text) {
+                    Ok(ClientMessage::Ping) => serde_json::json!({ "type": "pong" }),
+                    Err(e) => serde_json::json!({
+                        "type": "error",
+                        "message": format!("Invalid message: {}", e)
+                    }),
+                };
+                if socket.send(Message::Text(reply.to_string())).await.is_err() {
+                    break;
+                }
+            }
+            frame = frames.recv() => {
+                let frame = match frame {
+                    Ok(frame) => frame,
+                    Err(RecvError::Lagged(skipped)) => {
+                        eprintln!(
+                            "[User WS] Session for user {} lagged by {} frames, replaying from seq {}",
+                            user_id, skipped, sent_seq
+                        );
+                        let (last_seq, resumed) = service.resume(user_id, Some(sent_seq));
+                        if send_resume(&mut socket, resumed).await.is_none() {
+                            break;
+                        }
+                        sent_seq = last_seq;
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                if frame.user_id != user_id || frame.seq <= sent_seq {
+                    continue;
+                }
+                if socket.send(Message::Text(frame.payload.clone())).await.is_err() {
+                    break;
+                }
+                sent_seq = frame.seq;
+            }
+        }
+    }
+}
+
+/// 재전송 결과 전송
+///
+/// # Returns
+/// 전송 성공 시 Some(()), 연결이 끊기면 None
+async fn send_resume(socket: &mut WebSocket, resumed: ResumeResult) -> Option<()> {
+    match resumed {
+        ResumeResult::Replay(payloads) => {
+            for payload in payloads {
+                socket.send(Message::Text(payload)).await.ok()?;
+            }
+        }
+        ResumeResult::ResyncRequired { last_seq } => {
+            let message = serde_json::json!({
+                "type": "resync_required",
+                "seq": last_seq,
+            });
+            socket.send(Message::Text(message.to_string())).await.ok()?;
+        }
+    }
+    Some(())
+}
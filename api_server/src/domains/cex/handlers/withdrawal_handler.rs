@@ -0,0 +1,184 @@
+use crate::domains::cex::models::withdrawal::{CreateWithdrawalRequest, Withdrawal};
+use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::services::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+// =====================================================
+// Withdrawal Handler
+// =====================================================
+// 역할: 온체인 출금 요청 / 출금 내역 API
+//
+// 특징:
+// - 요청 즉시 잔고 보류 (available 감소, 주문 잠금과 별도)
+// - 이후 처리는 WithdrawalService가 진행
+//   (requested → approved → broadcast → confirmed / failed)
+// - failed 이면 보류 금액은 available로 복구
+// =====================================================
+
+/// 출금 내역 쿼리 파라미터
+/// Query parameters for withdrawal history
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct WithdrawalListQuery {
+    /// 자산 필터 (optional)
+    /// Asset filter (optional)
+    #[serde(default)]
+    pub mint: Option<String>,
+
+    /// 최대 조회 개수 (기본: 50, 최대: 200)
+    /// Limit (default: 50, max: 200)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// 출금 요청 핸들러
+/// Request withdrawal handler
+///
+/// # Request Body
+/// - mint: 자산 (예: "SOL", "USDT")
+/// - amount: 출금 수량 (자산 소수 자릿수 이내)
+/// - destination: 받는 지갑 주소 (SPL 토큰은 이 지갑의 토큰 계정으로 지급)
+///
+/// # Response
+/// - 201: 출금 요청됨 (status: requested, 잔고 보류)
+///   → 엔진 응답을 받지 못하면 held_at 없이 error와 함께 반환 (원장에 보류가 반영되면 held_at 기록)
+/// - 400: 잘못된 요청 (지원하지 않는 자산, 잘못된 주소/수량, 잔고 부족, 출금 비활성화)
+/// - 401: 인증 실패
+#[utoipa::path(
+    post,
+    path = "/api/cex/withdrawals",
+    request_body = CreateWithdrawalRequest,
+    responses(
+        (status = 201, description = "Withdrawal requested", body = Withdrawal),
+        (status = 400, description = "Bad request (unsupported asset, invalid address or amount, insufficient balance)"),
+        (status = 401, description = "Unauthorized (missing or invalid token)")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn create_withdrawal(
+    State(app_state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(request): Json<CreateWithdrawalRequest>,
+) -> Result<(StatusCode, Json<Withdrawal>), (StatusCode, Json<serde_json::Value>)> {
+    let withdrawal = app_state
+        .cex_state
+        .withdrawal_service
+        .create_withdrawal(authenticated_user.user_id, &request)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("{:#}", e)
+                })),
+            )
+        })?;
+
+    Ok((StatusCode::CREATED, Json(withdrawal)))
+}
+
+/// 내 출금 내역 핸들러
+/// Get my withdrawals handler
+///
+/// # Query Parameters
+/// - mint: 자산 필터 (optional)
+/// - limit: 최대 조회 개수 (optional, 기본: 50, 최대: 200)
+///
+/// # Response
+/// - 200: 출금 내역 (최신순)
+/// - 401: 인증 실패
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/withdrawals",
+    params(
+        WithdrawalListQuery
+    ),
+    responses(
+        (status = 200, description = "Withdrawals retrieved successfully", body = Vec<Withdrawal>),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_my_withdrawals(
+    State(app_state): State<AppState>,
+    Query(query): Query<WithdrawalListQuery>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<Vec<Withdrawal>>, (StatusCode, Json<serde_json::Value>)> {
+    let withdrawals = app_state
+        .cex_state
+        .withdrawal_service
+        .list_withdrawals(authenticated_user.user_id, query.mint.as_deref(), query.limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch withdrawals: {}", e)
+                })),
+            )
+        })?;
+
+    Ok(Json(withdrawals))
+}
+
+/// 출금 조회 핸들러
+/// Get withdrawal handler
+///
+/// # Path Parameters
+/// - id: 출금 ID
+///
+/// # Response
+/// - 200: 출금 정보
+/// - 401: 인증 실패
+/// - 404: 출금 없음 (다른 사용자 출금 포함)
+/// - 500: 서버 오류
+#[utoipa::path(
+    get,
+    path = "/api/cex/withdrawals/{id}",
+    params(
+        ("id" = u64, Path, description = "Withdrawal ID")
+    ),
+    responses(
+        (status = 200, description = "Withdrawal retrieved successfully", body = Withdrawal),
+        (status = 401, description = "Unauthorized (missing or invalid token)"),
+        (status = 404, description = "Withdrawal not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "CEX Balances",
+    security(("BearerAuth" = []))
+)]
+pub async fn get_withdrawal(
+    State(app_state): State<AppState>,
+    Path(id): Path<u64>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<Withdrawal>, (StatusCode, Json<serde_json::Value>)> {
+    let withdrawal = app_state
+        .cex_state
+        .withdrawal_service
+        .get_withdrawal(authenticated_user.user_id, id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch withdrawal: {}", e)
+                })),
+            )
+        })?;
+
+    withdrawal.map(Json).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "Withdrawal not found" })),
+        )
+    })
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+
+// =====================================================
+// Candle 모델
+// =====================================================
+// 역할: 거래쌍/주기별 OHLCV 캔들 (차트 데이터)
+// 설명: 체결(Trade)을 주기 단위로 묶어 시가/고가/저가/종가/거래량을 집계
+//
+// 주기 정렬:
+// - 모든 주기는 UNIX epoch(UTC) 기준으로 내림
+// - 1d 캔들은 UTC 00:00 시작
+// =====================================================
+
+/// 캔들 주기
+/// Candle interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+pub enum CandleInterval {
+    #[serde(rename = "1m")]
+    M1,
+    #[serde(rename = "5m")]
+    M5,
+    #[serde(rename = "15m")]
+    M15,
+    #[serde(rename = "1h")]
+    H1,
+    #[serde(rename = "4h")]
+    H4,
+    #[serde(rename = "1d")]
+    D1,
+}
+
+impl CandleInterval {
+    /// 지원하는 모든 주기 (짧은 순)
+    pub const ALL: [CandleInterval; 6] = [
+        CandleInterval::M1,
+        CandleInterval::M5,
+        CandleInterval::M15,
+        CandleInterval::H1,
+        CandleInterval::H4,
+        CandleInterval::D1,
+    ];
+
+    /// 주기 길이 (초)
+    pub fn seconds(&self) -> i64 {
+        match self {
+            CandleInterval::M1 => 60,
+            CandleInterval::M5 => 5 * 60,
+            CandleInterval::M15 => 15 * 60,
+            CandleInterval::H1 => 60 * 60,
+            CandleInterval::H4 => 4 * 60 * 60,
+            CandleInterval::D1 => 24 * 60 * 60,
+        }
+    }
+
+    /// DB/API 문자열 ("1m", "5m", ...)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CandleInterval::M1 => "1m",
+            CandleInterval::M5 => "5m",
+            CandleInterval::M15 => "15m",
+            CandleInterval::H1 => "1h",
+            CandleInterval::H4 => "4h",
+            CandleInterval::D1 => "1d",
+        }
+    }
+
+    /// 시간이 속한 캔들의 시작 시간
+    ///
+    /// # Examples
+    /// ```
+    /// // 12:34:56 → 1m: 12:34:00, 15m: 12:30:00, 1h: 12:00:00
+    /// let open_time = CandleInterval::M15.bucket_start(timestamp);
+    /// ```
+    pub fn bucket_start(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let seconds = time.timestamp();
+        let start = seconds - seconds.rem_euclid(self.seconds());
+        Utc.timestamp_opt(start, 0).single().unwrap_or(time)
+    }
+}
+
+impl fmt::Display for CandleInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CandleInterval {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        CandleInterval::ALL
+            .into_iter()
+            .find(|interval| interval.as_str() == value)
+            .ok_or_else(|| anyhow::anyhow!("Unknown candle interval: {}", value))
+    }
+}
+
+/// OHLCV 캔들
+/// OHLCV candle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Candle {
+    /// 기준 자산
+    #[schema(example = "SOL")]
+    pub base_mint: String,
+
+    /// 기준 통화
+    #[schema(example = "USDT")]
+    pub quote_mint: String,
+
+    /// 주기
+    pub interval: CandleInterval,
+
+    /// 캔들 시작 시간 (UTC)
+    pub open_time: DateTime<Utc>,
+
+    /// 시가 (첫 체결가)
+    #[schema(value_type = String, example = "100.0")]
+    pub open: Decimal,
+
+    /// 고가
+    #[schema(value_type = String, example = "101.5")]
+    pub high: Decimal,
+
+    /// 저가
+    #[schema(value_type = String, example = "99.8")]
+    pub low: Decimal,
+
+    /// 종가 (마지막 체결가)
+    #[schema(value_type = String, example = "100.7")]
+    pub close: Decimal,
+
+    /// 거래량 (base_mint)
+    #[schema(value_type = String, example = "42.5")]
+    pub volume: Decimal,
+
+    /// 거래대금 (quote_mint)
+    #[schema(value_type = String, example = "4275.3")]
+    pub quote_volume: Decimal,
+
+    /// 체결 건수
+    pub trade_count: u64,
+}
+
+impl Candle {
+    /// 첫 체결로 새 캔들 생성
+    ///
+    /// # Arguments
+    /// * `interval` - 주기
+    /// * `executed_at` - 체결 시간 (캔들 시작 시간은 주기 단위로 내림)
+    pub fn open(
+        base_mint: &str,
+        quote_mint: &str,
+        interval: CandleInterval,
+        executed_at: DateTime<Utc>,
+        price: Decimal,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            base_mint: base_mint.to_string(),
+            quote_mint: quote_mint.to_string(),
+            interval,
+            open_time: interval.bucket_start(executed_at),
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: amount,
+            quote_volume: price * amount,
+            trade_count: 1,
+        }
+    }
+
+    /// 같은 구간의 체결 반영
+    pub fn apply(&mut self, price: Decimal, amount: Decimal) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.volume += amount;
+        self.quote_volume += price * amount;
+        self.trade_count += 1;
+    }
+
+    /// 캔들 종료 시간 (다음 캔들 시작 시간)
+    pub fn close_time(&self) -> DateTime<Utc> {
+        self.open_time + chrono::Duration::seconds(self.interval.seconds())
+    }
+}
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// =====================================================
+// Deposit 모델
+// =====================================================
+// 역할: 온체인 입금 (SOL / SPL 토큰) → CEX 잔고 입금 기록
+//
+// 입금 주소:
+// - 사용자 Solana 지갑 (wallet 도메인, 사용자당 1개)
+// - SOL: 지갑 주소로 전송
+// - SPL 토큰: 지갑의 Associated Token Account로 전송
+//
+// 상태 흐름:
+// confirming (confirmed, finalized 대기) → crediting (입금 처리 중) → credited
+// confirming → failed (트랜잭션 실패 / 사라짐)
+// =====================================================
+
+/// 입금 상태
+/// Deposit status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositStatus {
+    /// confirmed 트랜잭션, finalized 대기
+    Confirming,
+    /// finalized, 잔고 입금 처리 중
+    Crediting,
+    /// CEX 잔고에 입금 완료
+    Credited,
+    /// 트랜잭션 실패 또는 롤백
+    Failed,
+}
+
+impl DepositStatus {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DepositStatus::Confirming => "confirming",
+            DepositStatus::Crediting => "crediting",
+            DepositStatus::Credited => "credited",
+            DepositStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for DepositStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "confirming" => Ok(DepositStatus::Confirming),
+            "crediting" => Ok(DepositStatus::Crediting),
+            "credited" => Ok(DepositStatus::Credited),
+            "failed" => Ok(DepositStatus::Failed),
+            _ => Err(anyhow::anyhow!("Unknown deposit status: {}", value)),
+        }
+    }
+}
+
+/// 입금 기록
+/// Deposit
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = Deposit)]
+pub struct Deposit {
+    /// Deposit ID
+    /// 입금 ID
+    #[schema(example = 1)]
+    pub id: u64,
+
+    /// User ID
+    /// 사용자 ID
+    pub user_id: u64,
+
+    /// Asset (e.g., "SOL", "USDT")
+    /// 자산
+    #[schema(example = "SOL")]
+    pub mint: String,
+
+    /// SPL token mint address (null for native SOL)
+    /// SPL 토큰 mint 주소 (네이티브 SOL은 null)
+    pub token_mint: Option<String>,
+
+    /// Deposited amount
+    /// 입금 수량
+    #[schema(value_type = String, example = "1.5")]
+    pub amount: Decimal,
+
+    /// Deposit address (user's wallet)
+    /// 입금 주소 (사용자 지갑)
+    pub address: String,
+
+    /// Transaction signature
+    /// 트랜잭션 서명
+    pub signature: String,
+
+    /// Slot
+    /// 슬롯
+    pub slot: u64,
+
+    /// Status
+    /// 상태
+    pub status: DepositStatus,
+
+    /// Failure reason
+    /// 실패 사유
+    pub error: Option<String>,
+
+    /// Detected timestamp
+    /// 발견 시간
+    pub created_at: DateTime<Utc>,
+
+    /// Finalized timestamp
+    /// finalized 시간
+    pub finalized_at: Option<DateTime<Utc>>,
+
+    /// Credited timestamp
+    /// 잔고 입금 시간
+    pub credited_at: Option<DateTime<Utc>>,
+}
+
+/// 새 입금 기록 (감시 중 발견)
+/// New deposit detected on-chain
+#[derive(Debug, Clone)]
+pub struct DepositCreate {
+    pub user_id: u64,
+    pub mint: String,
+    pub token_mint: Option<String>,
+    pub amount: Decimal,
+    pub address: String,
+    pub signature: String,
+    pub slot: u64,
+}
+
+/// 입금 주소 응답
+/// Deposit address response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepositAddressResponse {
+    /// Asset
+    /// 자산
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// Address to send to (wallet for SOL, associated token account for SPL tokens)
+    /// 전송할 주소 (SOL: 지갑, SPL 토큰: Associated Token Account)
+    pub address: String,
+
+    /// Owner wallet address
+    /// 지갑 주소
+    pub owner: String,
+
+    /// SPL token mint address (null for native SOL)
+    /// SPL 토큰 mint 주소 (네이티브 SOL은 null)
+    pub token_mint: Option<String>,
+}
@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+// =====================================================
+// Export 모델
+// =====================================================
+// 역할: 회계/세금용 내역 내보내기 작업 (요청, 상태)
+//
+// 데이터 종류 (dataset):
+// - trades: 내 체결 (방향, 가격, 수량, 체결 금액, 수수료)
+// - orders: 내 주문 (생성 시간 기준)
+// - balance_snapshots: 일별 자산별 잔고/평가액 (00:00 UTC 기록)
+// - deposits: 온체인 입금 (발견 시간 기준)
+// - withdrawals: 온체인 출금 (요청 시간 기준)
+//
+// 형식 (format):
+// - csv: 첫 줄 헤더, 값은 문자열 그대로
+// - json: 객체 배열 (숫자도 문자열, 다른 API 응답과 동일)
+// =====================================================
+
+/// 내보낼 데이터 종류
+/// Export dataset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportDataset {
+    Trades,
+    Orders,
+    BalanceSnapshots,
+    Deposits,
+    Withdrawals,
+}
+
+impl ExportDataset {
+    /// 지원하는 모든 종류
+    pub const ALL: [ExportDataset; 5] = [
+        ExportDataset::Trades,
+        ExportDataset::Orders,
+        ExportDataset::BalanceSnapshots,
+        ExportDataset::Deposits,
+        ExportDataset::Withdrawals,
+    ];
+
+    /// DB/API 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportDataset::Trades => "trades",
+            ExportDataset::Orders => "orders",
+            ExportDataset::BalanceSnapshots => "balance_snapshots",
+            ExportDataset::Deposits => "deposits",
+            ExportDataset::Withdrawals => "withdrawals",
+        }
+    }
+}
+
+impl fmt::Display for ExportDataset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExportDataset {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ExportDataset::ALL
+            .into_iter()
+            .find(|dataset| dataset.as_str() == value)
+            .ok_or_else(|| anyhow::anyhow!("Unknown export dataset: {}", value))
+    }
+}
+
+/// 파일 형식
+/// Export file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// DB/API 문자열 (파일 확장자와 같음)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// 다운로드 Content-Type
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Json => "application/json",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(anyhow::anyhow!("Unknown export format: {}", value)),
+        }
+    }
+}
+
+/// 내보내기 작업 상태
+/// Export job status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportStatus {
+    /// 대기 중
+    Pending,
+    /// 파일 작성 중
+    Running,
+    /// 다운로드 가능
+    Completed,
+    /// 실패 (error 참고)
+    Failed,
+    /// 다운로드 기한 지남 (파일 삭제됨)
+    Expired,
+}
+
+impl ExportStatus {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportStatus::Pending => "pending",
+            ExportStatus::Running => "running",
+            ExportStatus::Completed => "completed",
+            ExportStatus::Failed => "failed",
+            ExportStatus::Expired => "expired",
+        }
+    }
+}
+
+impl FromStr for ExportStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(ExportStatus::Pending),
+            "running" => Ok(ExportStatus::Running),
+            "completed" => Ok(ExportStatus::Completed),
+            "failed" => Ok(ExportStatus::Failed),
+            "expired" => Ok(ExportStatus::Expired),
+            _ => Err(anyhow::anyhow!("Unknown export status: {}", value)),
+        }
+    }
+}
+
+/// 내보내기 작업
+/// Export job
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = ExportJob)]
+pub struct ExportJob {
+    /// Job ID
+    /// 작업 ID
+    #[schema(example = 1)]
+    pub id: u64,
+
+    /// Owner user ID
+    /// 요청한 사용자 ID
+    #[serde(skip)]
+    pub user_id: u64,
+
+    /// Dataset
+    /// 데이터 종류
+    pub dataset: ExportDataset,
+
+    /// File format
+    /// 파일 형식
+    pub format: ExportFormat,
+
+    /// Range start (inclusive)
+    /// 시작 시간 (포함)
+    pub from: DateTime<Utc>,
+
+    /// Range end (exclusive)
+    /// 끝 시간 (미포함)
+    pub to: DateTime<Utc>,
+
+    /// Job status
+    /// 작업 상태
+    pub status: ExportStatus,
+
+    /// Number of rows written
+    /// 기록한 행 수
+    #[schema(example = 1250)]
+    pub row_count: u64,
+
+    /// Failure reason
+    /// 실패 사유
+    pub error: Option<String>,
+
+    /// Created timestamp
+    /// 요청 시간
+    pub created_at: DateTime<Utc>,
+
+    /// Completed timestamp
+    /// 완료 시간
+    pub completed_at: Option<DateTime<Utc>>,
+
+    /// Download deadline (file is deleted afterwards)
+    /// 다운로드 가능 기한 (이후 파일 삭제)
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Server-side file path (never exposed)
+    /// 파일 경로 (서버 내부용)
+    #[serde(skip)]
+    pub file_path: Option<String>,
+}
+
+impl ExportJob {
+    /// 다운로드 파일 이름 (예: "trades_20260101_20260201.csv")
+    pub fn file_name(&self) -> String {
+        format!(
+            "{}_{}_{}.{}",
+            self.dataset,
+            self.from.format("%Y%m%d"),
+            self.to.format("%Y%m%d"),
+            self.format.as_str()
+        )
+    }
+}
+
+/// 내보내기 요청 모델
+/// Create export request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateExportRequest {
+    /// Dataset to export
+    /// 데이터 종류 (trades, orders, balance_snapshots, deposits, withdrawals)
+    pub dataset: ExportDataset,
+
+    /// File format (default: csv)
+    /// 파일 형식 (csv, json, 기본: csv)
+    #[serde(default = "default_export_format")]
+    pub format: ExportFormat,
+
+    /// Range start (RFC 3339, inclusive)
+    /// 시작 시간 (포함)
+    pub from: DateTime<Utc>,
+
+    /// Range end (RFC 3339, exclusive)
+    /// 끝 시간 (미포함)
+    pub to: DateTime<Utc>,
+}
+
+fn default_export_format() -> ExportFormat {
+    ExportFormat::Csv
+}
@@ -17,7 +17,12 @@ use rust_decimal::Decimal;
 // 수수료 유형:
 // - 'taker': 시장가 주문자 수수료
 // - 'maker': 지정가 주문자 수수료
-// - 'both': 모두 동일한 수수료 (현재는 이 방식)
+// - 'both': 모두 동일한 수수료
+// 
+// 적용 범위:
+// - tier: 사용자 수수료 등급별 설정 (NULL이면 모든 등급)
+// - effective_from / effective_until: 적용 기간
+// - 같은 (거래쌍, 등급)에서 유형과 기간이 겹치는 활성 설정은 만들 수 없음
 // =====================================================
 
 /// 수수료 설정 정보 (데이터베이스에서 조회한 수수료 설정)
@@ -51,6 +56,19 @@ pub struct FeeConfig {
     #[schema(example = "both")]
     pub fee_type: String,
 
+    /// Fee tier (NULL means applies to all tiers)
+    /// 수수료 등급 (NULL이면 모든 등급에 적용)
+    #[schema(example = "vip1")]
+    pub tier: Option<String>,
+
+    /// Effective from (inclusive)
+    /// 적용 시작 시간
+    pub effective_from: DateTime<Utc>,
+
+    /// Effective until (exclusive, NULL means no end)
+    /// 적용 종료 시간 (NULL이면 기한 없음)
+    pub effective_until: Option<DateTime<Utc>>,
+
     /// Is this fee config active?
     /// 이 수수료 설정이 활성화되어 있는가?
     pub is_active: bool,
@@ -87,8 +105,126 @@ pub struct FeeConfigCreate {
     /// 수수료 유형
     pub fee_type: String,
 
-    /// Is active
-    /// 활성화 여부
-    pub is_active: bool,
+    /// Fee tier (None for all tiers)
+    /// 수수료 등급 (None이면 모든 등급)
+    pub tier: Option<String>,
+
+    /// Effective from
+    /// 적용 시작 시간
+    pub effective_from: DateTime<Utc>,
+
+    /// Effective until (None for no end)
+    /// 적용 종료 시간 (None이면 기한 없음)
+    pub effective_until: Option<DateTime<Utc>>,
+}
+
+// =====================================================
+// 수수료 설정 변경 이력
+// =====================================================
+/// 수수료 설정 변경 이력 (변경 전/후 전체 값)
+/// Fee config change history entry
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FeeConfigHistory {
+    /// History entry ID
+    /// 이력 ID
+    pub id: u64,
+
+    /// Fee config ID
+    /// 수수료 설정 ID
+    pub fee_config_id: u64,
+
+    /// Action: 'create', 'update', or 'deactivate'
+    /// 변경 종류: 'create' (생성), 'update' (수정), 'deactivate' (비활성화)
+    #[schema(example = "update")]
+    pub action: String,
+
+    /// Config before the change (None for create)
+    /// 변경 전 설정 (생성은 None)
+    pub old_config: Option<FeeConfig>,
+
+    /// Config after the change
+    /// 변경 후 설정
+    pub new_config: FeeConfig,
+
+    /// Admin who made the change
+    /// 변경한 관리자 ID
+    pub changed_by: Option<u64>,
+
+    /// Change timestamp
+    /// 변경 시간
+    pub changed_at: DateTime<Utc>,
 }
 
+
+// =====================================================
+// 수수료 설정 관리 요청 (관리자 API)
+// =====================================================
+/// 수수료 설정 생성 요청
+/// Create fee config request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateFeeConfigRequest {
+    /// Base asset (omit for all pairs)
+    /// 기준 자산 (생략하면 모든 거래쌍)
+    #[serde(default)]
+    #[schema(example = "SOL")]
+    pub base_mint: Option<String>,
+
+    /// Quote currency (omit for all pairs)
+    /// 기준 통화 (생략하면 모든 거래쌍)
+    #[serde(default)]
+    #[schema(example = "USDT")]
+    pub quote_mint: Option<String>,
+
+    /// Fee tier (omit for all tiers)
+    /// 수수료 등급 (생략하면 모든 등급)
+    #[serde(default)]
+    #[schema(example = "vip1")]
+    pub tier: Option<String>,
+
+    /// Fee rate (0 ~ 0.1, up to 6 decimal places)
+    /// 수수료율 (0 ~ 0.1, 소수점 6자리까지)
+    #[schema(value_type = String, example = "0.0008")]
+    pub fee_rate: Decimal,
+
+    /// Fee type: 'taker', 'maker', or 'both' (default)
+    /// 수수료 유형 (기본값 both)
+    #[serde(default = "default_fee_type")]
+    #[schema(example = "both")]
+    pub fee_type: String,
+
+    /// Effective from (omit for now)
+    /// 적용 시작 시간 (생략하면 지금)
+    #[serde(default)]
+    pub effective_from: Option<DateTime<Utc>>,
+
+    /// Effective until (omit for no end)
+    /// 적용 종료 시간 (생략하면 기한 없음)
+    #[serde(default)]
+    pub effective_until: Option<DateTime<Utc>>,
+}
+
+fn default_fee_type() -> String {
+    "both".to_string()
+}
+
+/// 수수료 설정 수정 요청 (수수료율과 적용 기간만 변경 가능)
+/// Update fee config request
+///
+/// 거래쌍/등급/유형을 바꾸려면 새 설정을 만들고 기존 설정을 비활성화합니다.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateFeeConfigRequest {
+    /// Fee rate (0 ~ 0.1, up to 6 decimal places)
+    /// 수수료율 (0 ~ 0.1, 소수점 6자리까지)
+    #[schema(value_type = String, example = "0.0006")]
+    pub fee_rate: Decimal,
+
+    /// Effective from (omit to keep current)
+    /// 적용 시작 시간 (생략하면 기존 값 유지)
+    #[serde(default)]
+    pub effective_from: Option<DateTime<Utc>>,
+
+    /// Effective until (omit for no end)
+    /// 적용 종료 시간 (생략하면 기한 없음)
+    #[serde(default)]
+    pub effective_until: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// =====================================================
+// Ledger 모델 (복식부기 원장)
+// =====================================================
+// 역할: 모든 잔고 이동을 설명하는 append-only 분개 기록
+//
+// 구조:
+// - 분개 (ledger_entries): 잔고 이동 한 건 (사유 + 참조, 예: trade + 체결 ID)
+// - 전기 (ledger_postings): 계정별 증감 (차변 +, 대변 -), 분개마다 자산별 합계 0
+//
+// 계정:
+// - 사용자 계정: available, locked (user_balances 컬럼과 대응)
+// - 시스템 계정 (user_id 없음): clearing, deposits, withdrawals, fees, adjustments
+//   → 사용자 계정 증감의 반대편 (예: 입금 = available 차변 / deposits 대변)
+//
+// 작성:
+// - DB Writer가 잔고 UPDATE와 같은 트랜잭션에서 기록 (DbCommand::UpdateBalance의 사유/참조)
+// =====================================================
+
+/// 원장 분개 사유
+/// Ledger entry reason
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerReason {
+    /// 체결 정산 (참조: 체결 ID)
+    Trade,
+    /// 수수료 (참조: 체결 ID)
+    Fee,
+    /// 온체인 입금 (참조: 트랜잭션 서명)
+    Deposit,
+    /// 출금 보류 (참조: 출금 ID)
+    Withdrawal,
+    /// 출금 실패/거절로 보류 해제 (참조: 출금 ID)
+    WithdrawalRefund,
+    /// 주문 잔고 잠금 (참조: 주문 ID)
+    Lock,
+    /// 주문 잔고 잠금 해제 (참조: 주문 ID)
+    Unlock,
+    /// 수동 조정 / 초기 지급 (참조: 설명)
+    Adjustment,
+    /// 사용자 간 내부 이체 (참조: 이체 ID)
+    Transfer,
+}
+
+impl LedgerReason {
+    /// 지원하는 모든 사유
+    pub const ALL: [LedgerReason; 9] = [
+        LedgerReason::Trade,
+        LedgerReason::Fee,
+        LedgerReason::Deposit,
+        LedgerReason::Withdrawal,
+        LedgerReason::WithdrawalRefund,
+        LedgerReason::Lock,
+        LedgerReason::Unlock,
+        LedgerReason::Adjustment,
+        LedgerReason::Transfer,
+    ];
+
+    /// DB/API 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LedgerReason::Trade => "trade",
+            LedgerReason::Fee => "fee",
+            LedgerReason::Deposit => "deposit",
+            LedgerReason::Withdrawal => "withdrawal",
+            LedgerReason::WithdrawalRefund => "withdrawal_refund",
+            LedgerReason::Lock => "lock",
+            LedgerReason::Unlock => "unlock",
+            LedgerReason::Adjustment => "adjustment",
+            LedgerReason::Transfer => "transfer",
+        }
+    }
+
+    /// 사용자 계정 증감의 반대편 시스템 계정
+    ///
+    /// 체결/내부 이체는 양쪽이 clearing에서 상쇄되고,
+    /// 잠금/해제는 available ↔ locked 이동이라 보통 반대편이 필요 없습니다.
+    pub fn contra_account(&self) -> LedgerAccount {
+        match self {
+            LedgerReason::Trade
+            | LedgerReason::Transfer
+            | LedgerReason::Lock
+            | LedgerReason::Unlock => LedgerAccount::Clearing,
+            LedgerReason::Fee => LedgerAccount::Fees,
+            LedgerReason::Deposit => LedgerAccount::Deposits,
+            LedgerReason::Withdrawal | LedgerReason::WithdrawalRefund => LedgerAccount::Withdrawals,
+            LedgerReason::Adjustment => LedgerAccount::Adjustments,
+        }
+    }
+}
+
+impl FromStr for LedgerReason {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        LedgerReason::ALL
+            .into_iter()
+            .find(|reason| reason.as_str() == value)
+            .ok_or_else(|| anyhow::anyhow!("Unknown ledger reason: {}", value))
+    }
+}
+
+/// 원장 계정
+/// Ledger account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerAccount {
+    /// 사용자 사용 가능 잔고
+    Available,
+    /// 사용자 주문 잠금 잔고
+    Locked,
+    /// 체결 정산 (시스템)
+    Clearing,
+    /// 외부 입금 (시스템)
+    Deposits,
+    /// 외부 출금 (시스템)
+    Withdrawals,
+    /// 수수료 수익 (시스템)
+    Fees,
+    /// 조정 / 초기 지급 (시스템)
+    Adjustments,
+}
+
+impl LedgerAccount {
+    /// DB/API 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LedgerAccount::Available => "available",
+            LedgerAccount::Locked => "locked",
+            LedgerAccount::Clearing => "clearing",
+            LedgerAccount::Deposits => "deposits",
+            LedgerAccount::Withdrawals => "withdrawals",
+            LedgerAccount::Fees => "fees",
+            LedgerAccount::Adjustments => "adjustments",
+        }
+    }
+}
+
+impl FromStr for LedgerAccount {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "available" => Ok(LedgerAccount::Available),
+            "locked" => Ok(LedgerAccount::Locked),
+            "clearing" => Ok(LedgerAccount::Clearing),
+            "deposits" => Ok(LedgerAccount::Deposits),
+            "withdrawals" => Ok(LedgerAccount::Withdrawals),
+            "fees" => Ok(LedgerAccount::Fees),
+            "adjustments" => Ok(LedgerAccount::Adjustments),
+            _ => Err(anyhow::anyhow!("Unknown ledger account: {}", value)),
+        }
+    }
+}
+
+/// 차변 / 대변
+/// Debit or credit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerSide {
+    /// 차변 (사용자 계정 증가)
+    Debit,
+    /// 대변 (사용자 계정 감소)
+    Credit,
+}
+
+/// 원장 전기 (사용자 계정 한 줄)
+/// Ledger posting on one of the user's accounts
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = LedgerPosting)]
+pub struct LedgerPosting {
+    /// Posting ID
+    /// 전기 ID
+    #[schema(example = 1)]
+    pub id: u64,
+
+    /// Journal entry ID (postings of the same entry balance to zero per asset)
+    /// 분개 ID (같은 분개의 전기는 자산별 합계 0)
+    pub entry_id: u64,
+
+    /// Reason
+    /// 사유
+    pub reason: LedgerReason,
+
+    /// Reference (trade ID, order ID, withdrawal ID or transaction signature)
+    /// 참조 (체결 ID, 주문 ID, 출금 ID, 트랜잭션 서명)
+    #[schema(example = "123456")]
+    pub reference: String,
+
+    /// Asset
+    /// 자산
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// Account (available or locked)
+    /// 계정
+    pub account: LedgerAccount,
+
+    /// Debit (increase) or credit (decrease)
+    /// 차변 (증가) / 대변 (감소)
+    pub side: LedgerSide,
+
+    /// Amount (always positive)
+    /// 금액 (항상 양수)
+    #[schema(value_type = String, example = "100.5")]
+    pub amount: Decimal,
+
+    /// Posted timestamp
+    /// 기록 시간
+    pub created_at: DateTime<Utc>,
+}
+
+/// 원장 조회 필터
+/// Ledger filter
+#[derive(Debug, Clone, Default)]
+pub struct LedgerFilter {
+    pub mint: Option<String>,
+    pub reason: Option<LedgerReason>,
+    pub reference: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+
+// =====================================================
+// MarketStatus 모델 (거래쌍 상태)
+// =====================================================
+// 역할: 관리자가 거래쌍별로 주문 접수를 제한
+//
+// 상태:
+// - open: 정상 (market_statuses 행이 없으면 open)
+// - cancel_only: 새 주문 불가, 취소 가능 (상장 폐지 준비, 점검 전 정리)
+// - halted: 새 주문과 취소 모두 불가 (긴급 중지)
+// =====================================================
+
+/// 거래쌍 상태
+/// Market (trading pair) status
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketStatus {
+    /// 정상
+    #[default]
+    Open,
+    /// 취소만 가능
+    CancelOnly,
+    /// 주문/취소 중지
+    Halted,
+}
+
+impl MarketStatus {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketStatus::Open => "open",
+            MarketStatus::CancelOnly => "cancel_only",
+            MarketStatus::Halted => "halted",
+        }
+    }
+
+    /// 새 주문 접수 가능 여부
+    pub fn accepts_orders(&self) -> bool {
+        *self == MarketStatus::Open
+    }
+
+    /// 주문 취소 가능 여부
+    pub fn accepts_cancels(&self) -> bool {
+        *self != MarketStatus::Halted
+    }
+}
+
+impl FromStr for MarketStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "open" => Ok(MarketStatus::Open),
+            "cancel_only" => Ok(MarketStatus::CancelOnly),
+            "halted" => Ok(MarketStatus::Halted),
+            _ => Err(anyhow::anyhow!("Unknown market status: {}", value)),
+        }
+    }
+}
+
+/// 거래쌍 상태 정보
+/// Market status entry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MarketStatusEntry {
+    /// 기준 자산
+    #[schema(example = "SOL")]
+    pub base_mint: String,
+
+    /// 기준 통화
+    #[schema(example = "USDT")]
+    pub quote_mint: String,
+
+    /// 상태
+    pub status: MarketStatus,
+
+    /// 변경 사유
+    pub reason: Option<String>,
+
+    /// 변경한 관리자 ID
+    pub updated_by: Option<u64>,
+
+    /// 변경 시간
+    pub updated_at: DateTime<Utc>,
+}
@@ -4,10 +4,32 @@ pub mod order;
 pub mod trade;
 pub mod fee;
 pub mod position;
+pub mod candle;
+pub mod ticker;
+pub mod portfolio;
+pub mod export;
+pub mod deposit;
+pub mod withdrawal;
+pub mod ledger;
+pub mod reconciliation;
+pub mod transfer;
+pub mod sub_account;
+pub mod market_status;
 
 pub use balance::*;
 pub use order::*;
 pub use trade::*;
 pub use fee::*;
 pub use position::*;
+pub use candle::*;
+pub use ticker::*;
+pub use portfolio::*;
+pub use export::*;
+pub use deposit::*;
+pub use withdrawal::*;
+pub use ledger::*;
+pub use reconciliation::*;
+pub use transfer::*;
+pub use sub_account::*;
+pub use market_status::*;
 
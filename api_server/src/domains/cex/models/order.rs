@@ -216,6 +216,35 @@ pub struct OrdersResponse {
     pub orders: Vec<Order>,
 }
 
+// =====================================================
+// 주문 목록 필터 (Orders List Filter)
+// =====================================================
+/// 주문 목록 조회 필터 (None이면 조건 없음)
+/// Filter for listing orders
+#[derive(Debug, Clone, Default)]
+pub struct OrderFilter {
+    /// 기준 자산
+    pub base_mint: Option<String>,
+
+    /// 기준 통화
+    pub quote_mint: Option<String>,
+
+    /// 주문 유형: 'buy' 또는 'sell'
+    pub order_type: Option<String>,
+
+    /// 주문 방식: 'limit' 또는 'market'
+    pub order_side: Option<String>,
+
+    /// 주문 상태: 'pending', 'partial', 'filled', 'cancelled'
+    pub status: Option<String>,
+
+    /// 생성 시간 하한 (포함)
+    pub from: Option<DateTime<Utc>>,
+
+    /// 생성 시간 상한 (미포함)
+    pub to: Option<DateTime<Utc>>,
+}
+
 // =====================================================
 // 오더북 응답 (Order Book Response)
 // =====================================================
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use rust_decimal::Decimal;
+use chrono::{DateTime, NaiveDate, Utc};
+
+// =====================================================
+// Portfolio 모델
+// =====================================================
+// 역할: 사용자의 모든 CEX 잔고를 하나의 기준 통화로 평가한 결과와 일별 자산 기록
+//
+// 가격 결정:
+// - 거래쌍 가격 = 최우선 매수/매도 호가의 중간값 (호가가 한쪽만 있으면 24시간 최근 체결가)
+// - 기준 통화와 직접 거래쌍이 없으면 다른 거래쌍을 거쳐 환산 (예: BONK → SOL → USDT)
+// - 역방향 거래쌍도 사용 (USDT를 SOL로 평가하면 1 / SOL-USDT 가격)
+//
+// 예시 (quote_mint: USDT):
+// - SOL 2개 (SOL/USDT 중간가 150) → value 300, route ["SOL", "USDT"]
+// - BONK 1,000,000개 (BONK/SOL 0.0000002, SOL/USDT 150) → value 30, route ["BONK", "SOL", "USDT"]
+// - USDT 100 → value 100, route ["USDT"]
+// =====================================================
+
+/// 자산별 평가 정보
+/// Valuation of a single asset
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+#[schema(as = PortfolioAsset)]
+pub struct PortfolioAsset {
+    /// Asset identifier
+    /// 자산 식별자
+    #[schema(example = "SOL")]
+    pub mint: String,
+
+    /// Balance (available + locked)
+    /// 보유 수량
+    #[schema(value_type = String, example = "2.0")]
+    pub balance: Decimal,
+
+    /// Available balance
+    /// 사용 가능 잔고
+    #[schema(value_type = String, example = "1.5")]
+    pub available: Decimal,
+
+    /// Locked balance
+    /// 잠긴 잔고
+    #[schema(value_type = String, example = "0.5")]
+    pub locked: Decimal,
+
+    /// Price in the quote currency (None if no market route)
+    /// 기준 통화 가격 (환산 경로가 없으면 None)
+    #[schema(value_type = String, example = "150.0")]
+    pub price: Option<Decimal>,
+
+    /// balance × price (None if no market route)
+    /// 평가액 (환산 경로가 없으면 None)
+    #[schema(value_type = String, example = "300.0")]
+    pub value: Option<Decimal>,
+
+    /// Assets the price was routed through (empty if no route)
+    /// 환산 경로 (자산 → … → 기준 통화, 경로가 없으면 빈 배열)
+    #[schema(example = json!(["SOL", "USDT"]))]
+    pub route: Vec<String>,
+}
+
+/// 포트폴리오 평가 결과
+/// Portfolio valuation
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+#[schema(as = PortfolioValuation)]
+pub struct PortfolioValuation {
+    /// Quote currency of all values
+    /// 기준 통화
+    #[schema(example = "USDT")]
+    pub quote_mint: String,
+
+    /// Sum of asset values (assets without a route are excluded)
+    /// 총 평가액 (환산 경로가 없는 자산 제외)
+    #[schema(value_type = String, example = "430.0")]
+    pub total_value: Decimal,
+
+    /// Assets, largest value first (unpriced assets last)
+    /// 자산별 평가 (평가액 큰 순, 평가 불가 자산은 마지막)
+    pub assets: Vec<PortfolioAsset>,
+
+    /// Valuation time
+    /// 평가 시간
+    pub valued_at: DateTime<Utc>,
+}
+
+/// 일별 자산 기록
+/// Daily equity snapshot
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+#[schema(as = EquitySnapshot)]
+pub struct EquitySnapshot {
+    /// Snapshot date (UTC)
+    /// 기록 날짜 (UTC)
+    #[schema(value_type = String, example = "2026-01-31")]
+    pub snapshot_date: NaiveDate,
+
+    /// Quote currency
+    /// 기준 통화
+    #[schema(example = "USDT")]
+    pub quote_mint: String,
+
+    /// Total value at snapshot time
+    /// 기록 시점 총 평가액
+    #[schema(value_type = String, example = "430.0")]
+    pub total_value: Decimal,
+
+    /// Snapshot time
+    /// 기록 시간
+    pub created_at: DateTime<Utc>,
+}
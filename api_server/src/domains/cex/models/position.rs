@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use rust_decimal::Decimal;
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::str::FromStr;
 
 // =====================================================
 // Position 모델
@@ -9,20 +12,69 @@ use rust_decimal::Decimal;
 // 설명: 거래소에서 사용자가 보유한 자산의 투자 성과를 나타냄
 // 
 // 포지션 정보:
-// - average_entry_price: 평균 매수가 (모든 매수 체결의 가중 평균)
+// - 매수 체결마다 원가 lot 생성 (수수료 포함), 매도 체결은 lot을 차감하며 실현 손익 기록
+// - average_entry_price: 남은 lot의 평균 단가 (cost_basis / open_amount)
 // - current_market_price: 현재 시장 가격
-// - unrealized_pnl: 미실현 손익 (현재 평가액 - 총 매수 금액)
+// - unrealized_pnl: 미실현 손익 (현재 가격 × open_amount - cost_basis)
 // - unrealized_pnl_percent: 미실현 수익률 (%)
 // 
-// 예시:
-// - SOL을 평균 100 USDT에 10개 매수
-// - 현재 SOL 가격이 110 USDT
-//   → average_entry_price: 100.0
-//   → current_market_price: 110.0
-//   → unrealized_pnl: 100.0 USDT (10개 × 10 USDT)
-//   → unrealized_pnl_percent: 10.0%
+// 예시 (FIFO, 수수료 0):
+// - SOL 10개를 100 USDT에, 10개를 120 USDT에 매수
+// - 15개를 130 USDT에 매도
+//   → 첫 lot 10개(원가 1000) + 둘째 lot 5개(원가 600) 차감
+//   → realized_pnl: 1950 - 1600 = 350 USDT
+//   → 남은 lot: 5개, 원가 600 (average_entry_price: 120.0)
 // =====================================================
 
+/// 원가 계산 방식
+/// Cost basis method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CostBasisMethod {
+    /// 먼저 매수한 lot부터 차감
+    #[default]
+    Fifo,
+    /// 나중에 매수한 lot부터 차감
+    Lifo,
+    /// 모든 lot을 같은 비율로 차감 (평균 단가 유지)
+    AverageCost,
+}
+
+impl CostBasisMethod {
+    /// 지원하는 모든 방식
+    pub const ALL: [CostBasisMethod; 3] = [
+        CostBasisMethod::Fifo,
+        CostBasisMethod::Lifo,
+        CostBasisMethod::AverageCost,
+    ];
+
+    /// DB/API 문자열 ("fifo", "lifo", "average_cost")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CostBasisMethod::Fifo => "fifo",
+            CostBasisMethod::Lifo => "lifo",
+            CostBasisMethod::AverageCost => "average_cost",
+        }
+    }
+}
+
+impl fmt::Display for CostBasisMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CostBasisMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        CostBasisMethod::ALL
+            .into_iter()
+            .find(|method| method.as_str() == value)
+            .ok_or_else(|| anyhow::anyhow!("Unknown cost basis method: {}", value))
+    }
+}
+
 /// 사용자 자산 포지션 정보
 /// User asset position information
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
@@ -48,13 +100,28 @@ pub struct AssetPosition {
     #[schema(value_type = String, example = "1.0")]
     pub locked: Decimal,
 
-    /// Average entry price (weighted average of all buy trades)
-    /// 평균 매수가 (모든 매수 체결의 가중 평균)
+    /// Average entry price of open lots (cost_basis / open_amount, fees included)
+    /// 평균 매수가 (남은 lot 기준, 수수료 포함)
     /// Unit: USDT per asset
     /// 단위: 자산 1개당 USDT
     #[schema(value_type = String, example = "100.5")]
     pub average_entry_price: Option<Decimal>,
 
+    /// Cost basis method
+    /// 원가 계산 방식
+    pub cost_basis_method: CostBasisMethod,
+
+    /// Amount held in open lots (bought on the exchange and not sold yet)
+    /// 남은 lot 수량 (입금 등 매수 체결이 아닌 수량은 제외)
+    #[schema(value_type = String, example = "10.0")]
+    pub open_amount: Decimal,
+
+    /// Cost of open lots (fees included)
+    /// 남은 lot 원가 (수수료 포함)
+    /// Unit: USDT
+    #[schema(value_type = String, example = "1005.0")]
+    pub cost_basis: Decimal,
+
     /// Total amount bought (sum of all buy trade amounts)
     /// 총 매수 수량 (모든 매수 체결 수량의 합)
     #[schema(value_type = String, example = "15.0")]
@@ -79,8 +146,8 @@ pub struct AssetPosition {
     #[schema(value_type = String, example = "1210.0")]
     pub current_value: Option<Decimal>,
 
-    /// Unrealized profit/loss (current_value - total_bought_cost)
-    /// 미실현 손익 (현재 평가액 - 총 매수 금액)
+    /// Unrealized profit/loss of open lots (current_market_price × open_amount - cost_basis)
+    /// 미실현 손익 (남은 lot 평가액 - 남은 lot 원가)
     /// Positive: profit, Negative: loss
     /// 양수: 이익, 음수: 손실
     /// Unit: USDT
@@ -89,8 +156,8 @@ pub struct AssetPosition {
 
     /// Unrealized profit/loss percentage
     /// 미실현 수익률 (%)
-    /// Formula: (unrealized_pnl / total_bought_cost) × 100
-    /// 공식: (미실현 손익 / 총 매수 금액) × 100
+    /// Formula: (unrealized_pnl / cost_basis) × 100
+    /// 공식: (미실현 손익 / 남은 lot 원가) × 100
     #[schema(value_type = String, example = "46.6")]
     pub unrealized_pnl_percent: Option<Decimal>,
 
@@ -114,13 +181,117 @@ pub struct TradeSummary {
     #[schema(example = 2)]
     pub total_sell_trades: u64,
 
-    /// Realized profit/loss from sell trades
-    /// 매도로 인한 실현 손익
+    /// Realized profit/loss from sell trades (sum of per-trade realized P&L)
+    /// 매도로 인한 실현 손익 (매도 체결별 실현 손익의 합)
     /// Positive: profit, Negative: loss
     /// 양수: 이익, 음수: 손실
     /// Unit: USDT
     #[schema(value_type = String, example = "50.0")]
     pub realized_pnl: Decimal,
+
+    /// Total fees paid on buy and sell trades
+    /// 수수료 합계 (매수/매도)
+    /// Unit: USDT
+    #[schema(value_type = String, example = "0.3")]
+    pub total_fees: Decimal,
+}
+
+// =====================================================
+// 원가 lot / 실현 손익 (Lots / Realized P&L)
+// =====================================================
+
+/// 포지션 누적 통계 (positions 테이블)
+/// Accumulated position statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PositionStats {
+    pub realized_pnl: Decimal,
+    pub total_fees: Decimal,
+    pub total_buy_trades: u64,
+    pub total_sell_trades: u64,
+    pub total_bought_amount: Decimal,
+    pub total_bought_cost: Decimal,
+    /// 마지막으로 반영한 체결 ID
+    pub last_trade_id: u64,
+}
+
+/// 매수 체결별 원가 lot
+/// Cost basis lot created by a buy trade
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+#[schema(as = PositionLot)]
+pub struct PositionLot {
+    /// Lot ID (0 until persisted)
+    /// lot ID
+    #[schema(example = 1)]
+    pub lot_id: u64,
+
+    /// Buy trade that opened the lot
+    /// lot을 만든 매수 체결 ID
+    #[schema(example = 1001)]
+    pub trade_id: u64,
+
+    /// Buy trade time
+    /// 매수 체결 시간
+    pub opened_at: DateTime<Utc>,
+
+    /// Bought amount
+    /// 매수 수량
+    #[schema(value_type = String, example = "10.0")]
+    pub amount: Decimal,
+
+    /// Buy cost (price × amount + fee)
+    /// 매수 원가 (수수료 포함)
+    #[schema(value_type = String, example = "1000.1")]
+    pub cost: Decimal,
+
+    /// Amount not sold yet
+    /// 남은 수량
+    #[schema(value_type = String, example = "5.0")]
+    pub remaining_amount: Decimal,
+
+    /// Cost of the remaining amount
+    /// 남은 수량의 원가
+    #[schema(value_type = String, example = "500.05")]
+    pub remaining_cost: Decimal,
+}
+
+/// 매도 체결별 실현 손익
+/// Realized P&L of a closing (sell) trade
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+#[schema(as = RealizedPnlEntry)]
+pub struct RealizedPnlEntry {
+    /// Sell trade ID
+    /// 매도 체결 ID
+    #[schema(example = 1002)]
+    pub trade_id: u64,
+
+    /// Sell trade time
+    /// 매도 체결 시간
+    pub closed_at: DateTime<Utc>,
+
+    /// Amount taken from lots
+    /// lot에서 차감한 수량
+    #[schema(value_type = String, example = "5.0")]
+    pub amount: Decimal,
+
+    /// Sale proceeds after fee
+    /// 매도 대금 (수수료 차감 후)
+    #[schema(value_type = String, example = "549.95")]
+    pub proceeds: Decimal,
+
+    /// Cost of the lots taken
+    /// 차감한 lot 원가
+    #[schema(value_type = String, example = "500.05")]
+    pub cost_basis: Decimal,
+
+    /// Sell fee
+    /// 매도 수수료
+    #[schema(value_type = String, example = "0.05")]
+    pub fee: Decimal,
+
+    /// proceeds - cost_basis
+    /// 실현 손익
+    #[schema(value_type = String, example = "49.9")]
+    pub realized_pnl: Decimal,
 }
 
 // =====================================================
@@ -147,3 +318,50 @@ pub struct AllPositionsResponse {
     pub positions: Vec<AssetPosition>,
 }
 
+
+/// 포지션 lot 조회 응답 모델
+/// Position lots response model
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(as = PositionLotsResponse)]
+pub struct PositionLotsResponse {
+    /// Asset identifier
+    /// 자산 식별자
+    #[schema(example = "SOL")]
+    pub mint: String,
+
+    /// Cost basis method
+    /// 원가 계산 방식
+    pub cost_basis_method: CostBasisMethod,
+
+    /// Amount held in open lots
+    /// 남은 lot 수량
+    #[schema(value_type = String, example = "5.0")]
+    pub open_amount: Decimal,
+
+    /// Cost of open lots
+    /// 남은 lot 원가
+    #[schema(value_type = String, example = "500.05")]
+    pub cost_basis: Decimal,
+
+    /// Total realized P&L
+    /// 실현 손익 합계
+    #[schema(value_type = String, example = "49.9")]
+    pub realized_pnl: Decimal,
+
+    /// Open lots (oldest first)
+    /// 남은 lot 목록 (오래된 순)
+    pub lots: Vec<PositionLot>,
+
+    /// Realized P&L of recent sell trades (newest first)
+    /// 최근 매도 체결별 실현 손익 (최신순)
+    pub realized: Vec<RealizedPnlEntry>,
+}
+
+/// 원가 계산 방식 변경 요청 모델
+/// Change cost basis method request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CostBasisMethodRequest {
+    /// New cost basis method (the asset's lots are rebuilt from trade history)
+    /// 새 원가 계산 방식 (해당 자산의 lot을 체결 내역으로 다시 계산)
+    pub method: CostBasisMethod,
+}
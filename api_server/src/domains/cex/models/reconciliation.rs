@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+// =====================================================
+// Reconciliation 모델 (엔진 ↔ DB 잔고 정합성 검사)
+// =====================================================
+// 역할: 엔진 BalanceCache, user_balances, 원장 합계를 (user, mint)별로 비교한 결과
+//
+// 비교 기준:
+// - 엔진: 엔진 스레드에서 찍은 스냅샷 (WAL 시퀀스 S 시점)
+// - DB: user_balances - (S 이후 원장 전기) → S 시점 DB 잔고
+// - 원장: 사용자 계정(available, locked) 전기 합계
+// =====================================================
+
+/// (user, mint)별 잔고 불일치
+/// Balance discrepancy for one (user, mint)
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+#[schema(as = BalanceDiscrepancy)]
+pub struct BalanceDiscrepancy {
+    /// 사용자 ID
+    /// User ID
+    pub user_id: u64,
+
+    /// 자산
+    /// Asset
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// 엔진 available (스냅샷 시점)
+    /// Engine available at snapshot
+    #[schema(value_type = String, example = "100.5")]
+    pub engine_available: Decimal,
+
+    /// 엔진 locked (스냅샷 시점)
+    /// Engine locked at snapshot
+    #[schema(value_type = String, example = "0")]
+    pub engine_locked: Decimal,
+
+    /// DB available (스냅샷 시점으로 환산)
+    /// Database available at snapshot
+    #[schema(value_type = String, example = "100.5")]
+    pub db_available: Decimal,
+
+    /// DB locked (스냅샷 시점으로 환산)
+    /// Database locked at snapshot
+    #[schema(value_type = String, example = "0")]
+    pub db_locked: Decimal,
+
+    /// 원장 available 합계 (스냅샷 시점)
+    /// Ledger available total at snapshot
+    #[schema(value_type = String, example = "100.5")]
+    pub ledger_available: Decimal,
+
+    /// 원장 locked 합계 (스냅샷 시점)
+    /// Ledger locked total at snapshot
+    #[schema(value_type = String, example = "0")]
+    pub ledger_locked: Decimal,
+
+    /// 엔진과 DB가 다름
+    /// Engine and database differ
+    pub engine_mismatch: bool,
+
+    /// DB와 원장 합계가 다름
+    /// Database and ledger differ
+    pub ledger_mismatch: bool,
+
+    /// DB 행을 엔진 값으로 복구했는지
+    /// Whether the database row was repaired from engine state
+    pub repaired: bool,
+}
+
+/// 정합성 검사 1회 결과
+/// Reconciliation run report
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = ReconciliationReport)]
+pub struct ReconciliationReport {
+    /// 엔진 스냅샷 WAL 시퀀스
+    /// Engine snapshot WAL sequence
+    pub wal_seq: u64,
+
+    /// 스냅샷 이전 명령 반영 후 DB Writer high-water mark (WAL 시퀀스)
+    /// DB writer high-water mark after the barrier
+    pub db_high_water: u64,
+
+    /// 비교한 (user, mint) 수
+    /// Number of (user, mint) pairs checked
+    pub checked: u64,
+
+    /// 불일치 목록
+    /// Discrepancies
+    pub discrepancies: Vec<BalanceDiscrepancy>,
+
+    /// 복구 실행 여부
+    /// Whether repair was requested
+    pub repair: bool,
+
+    /// 시작 시간
+    /// Started at
+    pub started_at: DateTime<Utc>,
+
+    /// 종료 시간
+    /// Finished at
+    pub finished_at: DateTime<Utc>,
+}
+
+/// 정합성 검사 지표
+/// Reconciliation metrics
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default)]
+#[schema(as = ReconciliationMetrics)]
+pub struct ReconciliationMetrics {
+    /// 완료된 실행 수
+    /// Completed runs
+    pub runs: u64,
+
+    /// 실패한 실행 수
+    /// Failed runs
+    pub failures: u64,
+
+    /// 마지막 실행의 불일치 수
+    /// Discrepancies found by the last run
+    pub last_discrepancies: u64,
+
+    /// 누적 불일치 수
+    /// Discrepancies found in total
+    pub total_discrepancies: u64,
+
+    /// 누적 복구 행 수
+    /// Rows repaired in total
+    pub total_repaired: u64,
+
+    /// 마지막 실행 소요 시간 (ms)
+    /// Duration of the last run in milliseconds
+    pub last_duration_ms: u64,
+
+    /// 마지막 실행 시간
+    /// Last run timestamp
+    pub last_run_at: Option<DateTime<Utc>>,
+
+    /// 마지막 실패 에러
+    /// Last failure
+    pub last_error: Option<String>,
+}
+
+/// 정합성 검사 상태 응답
+/// Reconciliation status response
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = ReconciliationStatus)]
+pub struct ReconciliationStatus {
+    /// 실행 간격 (초, 0이면 주기 실행 비활성화)
+    /// Interval in seconds (0 = periodic runs disabled)
+    pub interval_seconds: u64,
+
+    /// 복구 모드 (off, manual, auto)
+    /// Repair mode
+    #[schema(example = "off")]
+    pub repair_mode: String,
+
+    /// 지표
+    /// Metrics
+    pub metrics: ReconciliationMetrics,
+
+    /// 마지막 결과
+    /// Last report
+    pub last_report: Option<ReconciliationReport>,
+}
+
+/// 정합성 검사 실행 요청
+/// Run reconciliation request
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default)]
+#[schema(as = RunReconciliationRequest)]
+pub struct RunReconciliationRequest {
+    /// 엔진과 다른 DB 행을 엔진 값으로 복구 (복구 모드가 manual/auto일 때만 허용)
+    /// Repair mismatching database rows from engine state (requires repair mode manual or auto)
+    #[serde(default)]
+    pub repair: bool,
+}
+
+/// DB 잔고 + 원장 합계 (정합성 검사 입력)
+///
+/// `*_after`는 기준 WAL 시퀀스 이후 원장 전기 합계입니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StoredBalance {
+    pub user_id: u64,
+    pub mint: String,
+    pub available: Decimal,
+    pub locked: Decimal,
+    pub ledger_available: Decimal,
+    pub ledger_locked: Decimal,
+    pub available_after: Decimal,
+    pub locked_after: Decimal,
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use crate::domains::cex::models::balance::UserBalance;
+use crate::domains::cex::models::position::AssetPosition;
+
+// =====================================================
+// SubAccount 모델 (서브 계정)
+// =====================================================
+// 역할: 마스터 사용자가 소유한 서브 계정과 합산 조회 응답
+//
+// 특징:
+// - 서브 계정도 users 행이므로 잔고/주문/체결이 user_id 기준으로 분리됨
+// - 로그인 불가, 마스터가 X-Sub-Account-Id 헤더로 대신 거래
+// - 마스터 + 서브 계정 전체의 잔고/포지션을 자산별로 합산해 조회
+// =====================================================
+
+/// 서브 계정
+/// Sub-account
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = SubAccount)]
+pub struct SubAccount {
+    /// Sub-account user ID (use as `X-Sub-Account-Id`)
+    /// 서브 계정 사용자 ID
+    #[schema(example = 42)]
+    pub user_id: u64,
+
+    /// Master user ID
+    /// 마스터 사용자 ID
+    #[schema(example = 1)]
+    pub master_user_id: u64,
+
+    /// Label (unique per master)
+    /// 이름 (마스터별 유니크)
+    #[schema(example = "grid-sol")]
+    pub label: String,
+
+    /// Created timestamp
+    /// 생성 시간
+    pub created_at: DateTime<Utc>,
+}
+
+/// 서브 계정 생성 요청
+/// Create sub-account request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateSubAccountRequest {
+    /// Label (1-32 chars: lowercase letters, digits, '-', '_')
+    /// 이름
+    #[schema(example = "grid-sol")]
+    pub label: String,
+}
+
+/// 서브 계정 간 이체 요청 (마스터 ↔ 서브, 서브 ↔ 서브)
+/// Transfer between the master and its sub-accounts
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SubAccountTransferRequest {
+    /// Source account (master user ID or one of its sub-accounts)
+    /// 보내는 계정
+    #[schema(example = 1)]
+    pub from_account_id: u64,
+
+    /// Destination account (master user ID or one of its sub-accounts)
+    /// 받는 계정
+    #[schema(example = 42)]
+    pub to_account_id: u64,
+
+    /// Asset (e.g., "SOL", "USDT")
+    /// 자산
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// Amount to transfer
+    /// 이체 수량
+    #[schema(value_type = String, example = "1000")]
+    pub amount: Decimal,
+
+    /// Memo (optional)
+    /// 메모
+    #[serde(default)]
+    pub memo: Option<String>,
+
+    /// Idempotency key (scoped to the source account)
+    /// 멱등 키 (보내는 계정 기준)
+    #[schema(example = "rebalance-2026-10-18-001")]
+    pub idempotency_key: String,
+}
+
+/// 자산별 합산 잔고
+/// Balance summed across the master and its sub-accounts
+#[derive(Debug, Serialize, ToSchema, Clone, PartialEq)]
+#[schema(as = AggregatedBalance)]
+pub struct AggregatedBalance {
+    /// Asset identifier
+    /// 자산
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// Sum of available balances
+    /// available 합계
+    #[schema(value_type = String, example = "15000.0")]
+    pub available: Decimal,
+
+    /// Sum of locked balances
+    /// locked 합계
+    #[schema(value_type = String, example = "500.0")]
+    pub locked: Decimal,
+
+    /// available + locked
+    /// 총 잔고
+    #[schema(value_type = String, example = "15500.0")]
+    pub total: Decimal,
+}
+
+/// 계정별 잔고
+/// Balances of one account
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(as = AccountBalances)]
+pub struct AccountBalances {
+    /// Account user ID
+    /// 계정 사용자 ID
+    pub user_id: u64,
+
+    /// Sub-account label (None for the master)
+    /// 서브 계정 이름 (마스터는 None)
+    pub label: Option<String>,
+
+    /// Balances
+    /// 잔고 목록
+    pub balances: Vec<UserBalance>,
+}
+
+/// 서브 계정 합산 잔고 응답
+/// Aggregated balances response
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(as = SubAccountBalancesResponse)]
+pub struct SubAccountBalancesResponse {
+    /// Totals per asset
+    /// 자산별 합계
+    pub totals: Vec<AggregatedBalance>,
+
+    /// Per-account breakdown (master first)
+    /// 계정별 잔고 (마스터 먼저)
+    pub accounts: Vec<AccountBalances>,
+}
+
+/// 자산별 합산 포지션
+/// Position summed across the master and its sub-accounts
+#[derive(Debug, Serialize, ToSchema, Clone, PartialEq)]
+#[schema(as = AggregatedPosition)]
+pub struct AggregatedPosition {
+    /// Asset identifier
+    /// 자산
+    #[schema(example = "SOL")]
+    pub mint: String,
+
+    /// Sum of current balances (available + locked)
+    /// 보유 수량 합계
+    #[schema(value_type = String, example = "30.0")]
+    pub current_balance: Decimal,
+
+    /// Sum of open lot amounts
+    /// 남은 lot 수량 합계
+    #[schema(value_type = String, example = "25.0")]
+    pub open_amount: Decimal,
+
+    /// Sum of open lot costs
+    /// 남은 lot 원가 합계
+    #[schema(value_type = String, example = "2600.0")]
+    pub cost_basis: Decimal,
+
+    /// cost_basis / open_amount
+    /// 합산 평균 매수가
+    #[schema(value_type = String, example = "104.0")]
+    pub average_entry_price: Option<Decimal>,
+
+    /// Sum of realized P&L
+    /// 실현 손익 합계
+    #[schema(value_type = String, example = "350.0")]
+    pub realized_pnl: Decimal,
+
+    /// Sum of fees paid
+    /// 수수료 합계
+    #[schema(value_type = String, example = "1.2")]
+    pub total_fees: Decimal,
+
+    /// Current market price
+    /// 현재 시장 가격
+    #[schema(value_type = String, example = "110.0")]
+    pub current_market_price: Option<Decimal>,
+
+    /// Sum of current values (None if no account has a market price)
+    /// 현재 평가액 합계
+    #[schema(value_type = String, example = "3300.0")]
+    pub current_value: Option<Decimal>,
+
+    /// Sum of unrealized P&L (None if no account has a market price)
+    /// 미실현 손익 합계
+    #[schema(value_type = String, example = "150.0")]
+    pub unrealized_pnl: Option<Decimal>,
+}
+
+/// 계정별 포지션
+/// Positions of one account
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(as = AccountPositions)]
+pub struct AccountPositions {
+    /// Account user ID
+    /// 계정 사용자 ID
+    pub user_id: u64,
+
+    /// Sub-account label (None for the master)
+    /// 서브 계정 이름 (마스터는 None)
+    pub label: Option<String>,
+
+    /// Positions
+    /// 포지션 목록
+    pub positions: Vec<AssetPosition>,
+}
+
+/// 서브 계정 합산 포지션 응답
+/// Aggregated positions response
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(as = SubAccountPositionsResponse)]
+pub struct SubAccountPositionsResponse {
+    /// Totals per asset
+    /// 자산별 합계
+    pub totals: Vec<AggregatedPosition>,
+
+    /// Per-account breakdown (master first)
+    /// 계정별 포지션 (마스터 먼저)
+    pub accounts: Vec<AccountPositions>,
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use rust_decimal::Decimal;
+
+// =====================================================
+// Ticker 모델
+// =====================================================
+// 역할: 거래쌍별 24시간 롤링 시세 통계
+// 설명: 최근 24시간 체결로 시가/고가/저가/거래량을 계산하고
+//       최근 체결가와 최우선 호가를 함께 제공
+//
+// 윈도우:
+// - [now - 24h, now] 구간의 체결 (1초 단위로 만료)
+// - 구간 내 체결이 없으면 open/high/low/변동률은 null, 거래량은 0
+// =====================================================
+
+/// 24시간 ticker
+/// 24-hour rolling ticker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Ticker {
+    /// 기준 자산 (예: "SOL")
+    pub base_mint: String,
+
+    /// 기준 통화 (예: "USDT")
+    pub quote_mint: String,
+
+    /// 24시간 전 이후 첫 체결가
+    #[schema(value_type = Option<String>, example = "98.5")]
+    pub open: Option<Decimal>,
+
+    /// 24시간 최고가
+    #[schema(value_type = Option<String>, example = "104.2")]
+    pub high: Option<Decimal>,
+
+    /// 24시간 최저가
+    #[schema(value_type = Option<String>, example = "97.1")]
+    pub low: Option<Decimal>,
+
+    /// 최근 체결가 (24시간보다 오래된 체결일 수 있음, 체결이 없으면 null)
+    #[schema(value_type = Option<String>, example = "101.3")]
+    pub last_price: Option<Decimal>,
+
+    /// 가격 변동 (last_price - open)
+    #[schema(value_type = Option<String>, example = "2.8")]
+    pub price_change: Option<Decimal>,
+
+    /// 가격 변동률 (%, 소수점 2자리)
+    #[schema(value_type = Option<String>, example = "2.84")]
+    pub price_change_percent: Option<Decimal>,
+
+    /// 24시간 거래량 (기준 자산)
+    #[schema(value_type = String, example = "1520.75")]
+    pub base_volume: Decimal,
+
+    /// 24시간 거래대금 (기준 통화)
+    #[schema(value_type = String, example = "153210.4")]
+    pub quote_volume: Decimal,
+
+    /// 24시간 체결 건수
+    pub trade_count: u64,
+
+    /// 최우선 매수 호가
+    #[schema(value_type = Option<String>, example = "101.2")]
+    pub best_bid: Option<Decimal>,
+
+    /// 최우선 매도 호가
+    #[schema(value_type = Option<String>, example = "101.4")]
+    pub best_ask: Option<Decimal>,
+
+    /// 윈도우 시작 시간 (밀리초)
+    pub open_time: i64,
+
+    /// 윈도우 끝 시간 = 계산 시점 (밀리초)
+    pub close_time: i64,
+}
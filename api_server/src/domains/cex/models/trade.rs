@@ -62,6 +62,19 @@ pub struct Trade {
     pub created_at: DateTime<Utc>,
 }
 
+/// 사용자 기준 체결 + 정산 시 차감한 수수료 (내보내기용)
+/// User's fill with the fees charged at settlement
+///
+/// 매수/매도 쪽이 아니면 None (자기 체결이면 둘 다 Some)
+#[derive(Debug, Clone)]
+pub struct UserFill {
+    pub trade: Trade,
+    /// 매수 수수료 (base_mint)
+    pub buyer_fee: Option<Decimal>,
+    /// 매도 수수료 (quote_mint)
+    pub seller_fee: Option<Decimal>,
+}
+
 // =====================================================
 // 체결 내역 생성용 (Repository에서 사용)
 // =====================================================
@@ -107,3 +120,23 @@ pub struct TradesResponse {
     pub trades: Vec<Trade>,
 }
 
+
+// =====================================================
+// 체결 내역 필터 (Trades List Filter)
+// =====================================================
+/// 체결 내역 조회 필터 (None이면 조건 없음)
+/// Filter for listing trades
+#[derive(Debug, Clone, Default)]
+pub struct TradeFilter {
+    /// 기준 자산
+    pub base_mint: Option<String>,
+
+    /// 기준 통화
+    pub quote_mint: Option<String>,
+
+    /// 체결 시간 하한 (포함)
+    pub from: Option<DateTime<Utc>>,
+
+    /// 체결 시간 상한 (미포함)
+    pub to: Option<DateTime<Utc>>,
+}
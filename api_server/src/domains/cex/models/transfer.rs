@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// =====================================================
+// Transfer 모델 (내부 이체)
+// =====================================================
+// 역할: 거래소 사용자 간 available 잔고 이동 기록
+//
+// 특징:
+// - 온체인 트랜잭션 없이 엔진 스레드에서 양쪽 잔고를 한 번에 이동
+// - 보내는 사용자 + 멱등 키로 중복 요청 방지
+// - 보내는 사용자와 받는 사용자 모두 조회 가능
+//
+// 상태 흐름:
+// pending → completed | failed
+// =====================================================
+
+/// 내부 이체 상태
+/// Internal transfer status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferStatus {
+    /// 엔진 처리 대기
+    Pending,
+    /// 잔고 이동 완료
+    Completed,
+    /// 실패 (잔고 부족 등, 잔고 변화 없음)
+    Failed,
+}
+
+impl TransferStatus {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransferStatus::Pending => "pending",
+            TransferStatus::Completed => "completed",
+            TransferStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for TransferStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(TransferStatus::Pending),
+            "completed" => Ok(TransferStatus::Completed),
+            "failed" => Ok(TransferStatus::Failed),
+            _ => Err(anyhow::anyhow!("Unknown transfer status: {}", value)),
+        }
+    }
+}
+
+/// 조회하는 사용자 기준 이체 방향
+/// Transfer direction relative to the requesting user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferDirection {
+    /// 보낸 이체
+    Outgoing,
+    /// 받은 이체
+    Incoming,
+}
+
+/// 내부 이체 기록
+/// Internal transfer
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = Transfer)]
+pub struct Transfer {
+    /// Transfer ID
+    /// 이체 ID
+    #[schema(example = 1)]
+    pub id: u64,
+
+    /// Sender user ID
+    /// 보내는 사용자 ID
+    pub from_user_id: u64,
+
+    /// Recipient user ID
+    /// 받는 사용자 ID
+    pub to_user_id: u64,
+
+    /// Asset (e.g., "SOL", "USDT")
+    /// 자산
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// Transfer amount
+    /// 이체 수량
+    #[schema(value_type = String, example = "250.5")]
+    pub amount: Decimal,
+
+    /// Memo
+    /// 메모
+    pub memo: Option<String>,
+
+    /// Sender's idempotency key (internal)
+    /// 보내는 사용자 멱등 키 (내부용)
+    #[serde(skip)]
+    pub idempotency_key: String,
+
+    /// Status
+    /// 상태
+    pub status: TransferStatus,
+
+    /// Failure reason
+    /// 실패 사유
+    pub error: Option<String>,
+
+    /// Requested timestamp
+    /// 요청 시간
+    pub created_at: DateTime<Utc>,
+
+    /// Completed / failed timestamp
+    /// 완료 (completed / failed) 시간
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// 내부 이체 요청
+/// Create internal transfer request
+///
+/// 받는 사용자는 `to_user_id` 또는 `to_email` 중 하나로 지정합니다.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTransferRequest {
+    /// Recipient user ID
+    /// 받는 사용자 ID
+    #[serde(default)]
+    #[schema(example = 42)]
+    pub to_user_id: Option<u64>,
+
+    /// Recipient email
+    /// 받는 사용자 이메일
+    #[serde(default)]
+    #[schema(example = "desk-b@example.com")]
+    pub to_email: Option<String>,
+
+    /// Asset (e.g., "SOL", "USDT")
+    /// 자산
+    #[schema(example = "USDT")]
+    pub mint: String,
+
+    /// Amount to transfer
+    /// 이체 수량
+    #[schema(value_type = String, example = "250.5")]
+    pub amount: Decimal,
+
+    /// Memo (optional, max 256 chars)
+    /// 메모
+    #[serde(default)]
+    pub memo: Option<String>,
+
+    /// Idempotency key (optional if the `Idempotency-Key` header is set)
+    /// 멱등 키 (Idempotency-Key 헤더가 없을 때 사용)
+    #[serde(default)]
+    #[schema(example = "desk-rebalance-2026-10-18-001")]
+    pub idempotency_key: Option<String>,
+}
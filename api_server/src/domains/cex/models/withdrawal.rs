@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// =====================================================
+// Withdrawal 모델
+// =====================================================
+// 역할: CEX 잔고 → 외부 Solana 주소 온체인 출금 기록
+//
+// 잔고 보류:
+// - 요청 시 엔진 잔고에 출금 ID로 보류 (주문 잠금 locked와 별도)
+// - confirmed 이면 보류 소진, failed 이면 보류 해제 (available 복구)
+//
+// 상태 흐름:
+// requested → approved → broadcast → confirmed
+// requested | approved | broadcast → failed (거절 / 트랜잭션 실패 / 만료)
+// =====================================================
+
+/// 출금 상태
+/// Withdrawal status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WithdrawalStatus {
+    /// 요청됨, 승인 대기 (잔고 보류)
+    Requested,
+    /// 승인됨, 지급 트랜잭션 전송 대기
+    Approved,
+    /// 지급 트랜잭션 전송됨, finalized 대기
+    Broadcast,
+    /// 온체인 지급 완료
+    Confirmed,
+    /// 거절 / 실패 (보류 해제)
+    Failed,
+}
+
+impl WithdrawalStatus {
+    /// DB 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WithdrawalStatus::Requested => "requested",
+            WithdrawalStatus::Approved => "approved",
+            WithdrawalStatus::Broadcast => "broadcast",
+            WithdrawalStatus::Confirmed => "confirmed",
+            WithdrawalStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for WithdrawalStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "requested" => Ok(WithdrawalStatus::Requested),
+            "approved" => Ok(WithdrawalStatus::Approved),
+            "broadcast" => Ok(WithdrawalStatus::Broadcast),
+            "confirmed" => Ok(WithdrawalStatus::Confirmed),
+            "failed" => Ok(WithdrawalStatus::Failed),
+            _ => Err(anyhow::anyhow!("Unknown withdrawal status: {}", value)),
+        }
+    }
+}
+
+/// 출금 기록
+/// Withdrawal
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[schema(as = Withdrawal)]
+pub struct Withdrawal {
+    /// Withdrawal ID
+    /// 출금 ID
+    #[schema(example = 1)]
+    pub id: u64,
+
+    /// User ID
+    /// 사용자 ID
+    pub user_id: u64,
+
+    /// Asset (e.g., "SOL", "USDT")
+    /// 자산
+    #[schema(example = "SOL")]
+    pub mint: String,
+
+    /// SPL token mint address (null for native SOL)
+    /// SPL 토큰 mint 주소 (네이티브 SOL은 null)
+    pub token_mint: Option<String>,
+
+    /// Withdrawal amount
+    /// 출금 수량
+    #[schema(value_type = String, example = "1.5")]
+    pub amount: Decimal,
+
+    /// Destination wallet address
+    /// 받는 지갑 주소
+    pub destination: String,
+
+    /// Payout transaction signature (after broadcast)
+    /// 지급 트랜잭션 서명
+    pub signature: Option<String>,
+
+    /// Last block height at which the payout transaction is valid
+    /// 지급 트랜잭션 만료 블록 높이 (내부용)
+    #[serde(skip)]
+    pub last_valid_block_height: Option<u64>,
+
+    /// Status
+    /// 상태
+    pub status: WithdrawalStatus,
+
+    /// Failure reason
+    /// 실패 사유
+    pub error: Option<String>,
+
+    /// Requested timestamp
+    /// 요청 시간
+    pub created_at: DateTime<Utc>,
+
+    /// Balance hold timestamp (internal)
+    /// 잔고 보류 시간 (내부용)
+    #[serde(skip)]
+    pub held_at: Option<DateTime<Utc>>,
+
+    /// Approved timestamp
+    /// 승인 시간
+    pub approved_at: Option<DateTime<Utc>>,
+
+    /// Broadcast timestamp
+    /// 전송 시간
+    pub broadcast_at: Option<DateTime<Utc>>,
+
+    /// Confirmed / failed timestamp
+    /// 완료 (confirmed / failed) 시간
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// 출금 요청
+/// Create withdrawal request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWithdrawalRequest {
+    /// Asset (e.g., "SOL", "USDT")
+    /// 자산
+    #[schema(example = "SOL")]
+    pub mint: String,
+
+    /// Amount to withdraw
+    /// 출금 수량
+    #[schema(value_type = String, example = "1.5")]
+    pub amount: Decimal,
+
+    /// Destination wallet address (for SPL tokens, paid to this wallet's associated token account)
+    /// 받는 지갑 주소 (SPL 토큰은 이 지갑의 Associated Token Account로 지급)
+    #[schema(example = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM")]
+    pub destination: String,
+}
@@ -1,5 +1,5 @@
 use axum::{
-    routing::{get, post, delete},
+    routing::{get, post, put, delete},
     Router,
 };
 use crate::shared::services::AppState;
@@ -19,20 +19,54 @@ use super::handlers;
 /// - `GET    /api/cex/orders/:id` - 주문 조회
 /// - `GET    /api/cex/orders/my` - 내 주문 목록
 /// - `GET    /api/cex/orderbook` - 오더북 조회
+/// - `GET    /api/cex/orderbook/l3` - L3 (주문 단위) 오더북 조회
 /// 
 /// ## Trades (체결)
 /// - `GET    /api/cex/trades` - 거래쌍별 체결 내역
 /// - `GET    /api/cex/trades/my` - 내 체결 내역
 /// - `GET    /api/cex/price` - 최근 체결 가격
 /// - `GET    /api/cex/volume` - 24시간 거래량
+/// - `GET    /api/cex/ticker` - 24시간 ticker (전체 또는 거래쌍)
+/// - `GET    /api/cex/candles` - OHLCV 캔들 (1m/5m/15m/1h/4h/1d)
 /// 
 /// ## Balances (잔고)
 /// - `GET    /api/cex/balances` - 내 잔고 조회
 /// - `POST   /api/cex/balances` - 잔고 초기화
+/// - `GET    /api/cex/deposits/address` - 입금 주소 조회 (SOL: 지갑, SPL: 토큰 계정)
+/// - `GET    /api/cex/deposits` - 내 입금 내역
+/// - `POST   /api/cex/withdrawals` - 출금 요청 (잔고 보류)
+/// - `GET    /api/cex/withdrawals` - 내 출금 내역
+/// - `GET    /api/cex/withdrawals/:id` - 출금 상태 조회
+/// - `POST   /api/cex/transfers` - 내부 이체 (사용자 간 available 이동, 멱등 키)
+/// - `GET    /api/cex/transfers` - 내 이체 내역 (보낸/받은)
+/// - `GET    /api/cex/transfers/:id` - 이체 조회
+/// - `GET    /api/cex/ledger` - 내 원장 (잔고 이동 내역)
 /// 
 /// ## Positions (포지션)
 /// - `GET    /api/cex/positions` - 모든 자산 포지션 조회
 /// - `GET    /api/cex/positions/:mint` - 특정 자산 포지션 조회
+/// - `GET    /api/cex/positions/:mint/lots` - 특정 자산 원가 lot / 실현 손익 조회
+/// - `PUT    /api/cex/positions/:mint/cost-basis` - 원가 계산 방식 변경 (FIFO/LIFO/평균 원가)
+/// - `GET    /api/cex/portfolio` - 모든 잔고 평가 (기준 통화 선택)
+/// - `GET    /api/cex/portfolio/history` - 일별 총 평가액 기록
+/// 
+/// ## Sub-accounts (서브 계정, 다른 API는 X-Sub-Account-Id 헤더로 서브 계정 대신 호출)
+/// - `POST   /api/cex/sub-accounts` - 서브 계정 생성
+/// - `GET    /api/cex/sub-accounts` - 내 서브 계정 목록
+/// - `POST   /api/cex/sub-accounts/transfers` - 계정 간 이체 (마스터 ↔ 서브, 서브 ↔ 서브)
+/// - `GET    /api/cex/sub-accounts/balances` - 마스터 + 서브 계정 합산 잔고
+/// - `GET    /api/cex/sub-accounts/positions` - 마스터 + 서브 계정 합산 포지션
+/// 
+/// ## Exports (내역 내보내기)
+/// - `POST   /api/cex/exports` - 내보내기 작업 생성 (trades/orders/balance_snapshots/deposits/withdrawals, CSV/JSON)
+///   → 작업 생성은 다른 생성 API와 같이 POST (GET은 조회 전용, 같은 경로의 GET은 목록)
+/// - `GET    /api/cex/exports` - 내 내보내기 작업 목록
+/// - `GET    /api/cex/exports/:id` - 작업 상태 조회
+/// - `GET    /api/cex/exports/:id/download` - 완료된 파일 다운로드
+/// 
+/// ## WebSocket (실시간)
+/// - `GET    /api/cex/ws/market` - 공개 시세 (depth, l3, trades, ticker, candles)
+/// - `GET    /api/cex/ws/user` - 내 주문/체결/잔고 (JWT 필요)
 pub fn create_cex_router() -> Router<AppState> {
     Router::new()
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -53,6 +87,7 @@ pub fn create_cex_router() -> Router<AppState> {
         
         // 오더북 조회
         .route("/orderbook", get(handlers::get_orderbook))
+        .route("/orderbook/l3", get(handlers::get_l3_orderbook))
         
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         // Trades (체결)
@@ -70,6 +105,12 @@ pub fn create_cex_router() -> Router<AppState> {
         // 24시간 거래량
         .route("/volume", get(handlers::get_24h_volume))
         
+        // 24시간 ticker
+        .route("/ticker", get(handlers::get_ticker))
+        
+        // 캔들 (차트)
+        .route("/candles", get(handlers::get_candles))
+        
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         // Balances (잔고)
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -80,6 +121,27 @@ pub fn create_cex_router() -> Router<AppState> {
         // 특정 자산 잔고 조회
         .route("/balances/:mint", get(handlers::get_balance))
         
+        // 입금 주소 조회 (지갑이 없으면 생성)
+        .route("/deposits/address", get(handlers::get_deposit_address))
+        
+        // 내 입금 내역
+        .route("/deposits", get(handlers::get_my_deposits))
+        
+        // 출금 요청 / 내 출금 내역
+        .route("/withdrawals", post(handlers::create_withdrawal).get(handlers::get_my_withdrawals))
+        
+        // 출금 상태 조회
+        .route("/withdrawals/:id", get(handlers::get_withdrawal))
+        
+        // 내부 이체 / 내 이체 내역 (보낸/받은)
+        .route("/transfers", post(handlers::create_transfer).get(handlers::get_my_transfers))
+        
+        // 이체 조회
+        .route("/transfers/:id", get(handlers::get_transfer))
+        
+        // 내 원장 (잔고 이동 내역)
+        .route("/ledger", get(handlers::get_my_ledger))
+        
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         // Positions (포지션)
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -89,4 +151,53 @@ pub fn create_cex_router() -> Router<AppState> {
         
         // 특정 자산 포지션 조회
         .route("/positions/:mint", get(handlers::get_position))
+        
+        // 특정 자산 원가 lot / 실현 손익 조회
+        .route("/positions/:mint/lots", get(handlers::get_position_lots))
+        
+        // 원가 계산 방식 변경 (lot 재계산)
+        .route("/positions/:mint/cost-basis", put(handlers::set_cost_basis_method))
+        
+        // 모든 잔고 평가 (기준 통화로 환산)
+        .route("/portfolio", get(handlers::get_portfolio))
+        
+        // 일별 총 평가액 기록 (수익률 차트)
+        .route("/portfolio/history", get(handlers::get_portfolio_history))
+        
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Sub-accounts (서브 계정)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        
+        // 서브 계정 생성 / 목록
+        .route("/sub-accounts", post(handlers::create_sub_account).get(handlers::get_sub_accounts))
+        
+        // 계정 간 이체 (마스터 ↔ 서브, 서브 ↔ 서브)
+        .route("/sub-accounts/transfers", post(handlers::transfer_between_sub_accounts))
+        
+        // 합산 잔고 / 포지션
+        .route("/sub-accounts/balances", get(handlers::get_sub_account_balances))
+        .route("/sub-accounts/positions", get(handlers::get_sub_account_positions))
+        
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // Exports (내역 내보내기)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        
+        // 내보내기 작업 생성 / 내 작업 목록
+        .route("/exports", post(handlers::create_export).get(handlers::list_exports))
+        
+        // 작업 상태 조회
+        .route("/exports/:id", get(handlers::get_export))
+        
+        // 완료된 파일 다운로드 (스트리밍)
+        .route("/exports/:id/download", get(handlers::download_export))
+        
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        // WebSocket (실시간)
+        // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+        
+        // 공개 시세 (호가/체결/ticker 구독)
+        .route("/ws/market", get(handlers::market_ws))
+        
+        // 내 주문/체결/잔고 스트림 (JWT 인증, resume 지원)
+        .route("/ws/user", get(handlers::user_ws))
 }
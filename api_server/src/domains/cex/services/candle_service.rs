@@ -0,0 +1,423 @@
+// =====================================================
+// CandleService - OHLCV 캔들 집계
+// =====================================================
+// 역할: 엔진 체결 이벤트로 캔들을 증분 집계하고 저장/조회/실시간 발행
+//
+// 흐름:
+// 1. 이벤트 버스 구독 (backfill 동안 들어온 이벤트는 큐에 대기)
+// 2. trades 테이블에서 마지막 캔들 이후 구간을 재집계 (backfill, cutoff 시점까지)
+// 3. cutoff 이후 TradeExecuted → 모든 주기 캔들 갱신 → `candles` 채널 발행
+// 4. 변경된 캔들은 1초마다 candles 테이블에 upsert
+//
+// 조회:
+// - DB 캔들 + 아직 저장되지 않은 진행 중 캔들(메모리)을 합쳐서 반환
+// =====================================================
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use tokio::time::{interval, sleep, Duration};
+
+use crate::domains::cex::engine::runtime::HighPerformanceEngine;
+use crate::domains::cex::engine::types::{EngineEvent, TradingPair};
+use crate::domains::cex::models::candle::{Candle, CandleInterval};
+use crate::domains::cex::services::MarketDataService;
+use crate::shared::database::{CandleRepository, Database, TradeRepository};
+
+/// DB 저장 주기
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// backfill 시 체결 내역 페이지 크기
+const BACKFILL_PAGE_SIZE: i64 = 1000;
+
+/// 조회 기본 개수
+const DEFAULT_CANDLE_LIMIT: i64 = 500;
+
+/// 조회 최대 개수
+const MAX_CANDLE_LIMIT: i64 = 1000;
+
+/// 이벤트 버스 재구독 대기 시간
+const RESUBSCRIBE_DELAY: Duration = Duration::from_millis(100);
+
+/// 캔들 키 (거래쌍, 주기)
+type CandleKey = (TradingPair, CandleInterval);
+
+/// 진행 중인 캔들 + 저장 대기 캔들
+#[derive(Debug, Default)]
+pub struct CandleBook {
+    /// 거래쌍/주기별 마지막 캔들
+    current: HashMap<CandleKey, Candle>,
+    /// 저장 대기 캔들 (같은 캔들은 최신 값만 유지)
+    dirty: HashMap<(TradingPair, CandleInterval, DateTime<Utc>), Candle>,
+}
+
+impl CandleBook {
+    /// 체결 반영 (모든 주기)
+    ///
+    /// # Arguments
+    /// * `executed_at` - 체결 시간 (진행 중인 캔들보다 이전 구간이면 해당 주기는 무시)
+    ///
+    /// # Returns
+    /// 갱신된 캔들 (주기별 1개)
+    pub fn apply_trade(
+        &mut self,
+        trading_pair: &TradingPair,
+        price: Decimal,
+        amount: Decimal,
+        executed_at: DateTime<Utc>,
+    ) -> Vec<Candle> {
+        let mut updated = Vec::with_capacity(CandleInterval::ALL.len());
+
+        for interval in CandleInterval::ALL {
+            let open_time = interval.bucket_start(executed_at);
+            let key = (trading_pair.clone(), interval);
+
+            let candle = match self.current.get_mut(&key) {
+                Some(candle) if candle.open_time == open_time => {
+                    candle.apply(price, amount);
+                    candle.clone()
+                }
+                Some(candle) if candle.open_time > open_time => continue,
+                _ => {
+                    let candle = Candle::open(
+                        &trading_pair.base_mint,
+                        &trading_pair.quote_mint,
+                        interval,
+                        executed_at,
+                        price,
+                        amount,
+                    );
+                    self.current.insert(key, candle.clone());
+                    candle
+                }
+            };
+
+            self.dirty.insert((trading_pair.clone(), interval, open_time), candle.clone());
+            updated.push(candle);
+        }
+
+        updated
+    }
+
+    /// 저장 대기 캔들 꺼내기
+    pub fn take_dirty(&mut self) -> Vec<Candle> {
+        self.dirty.drain().map(|(_, candle)| candle).collect()
+    }
+
+    /// 저장 실패한 캔들 되돌리기 (그 사이 갱신된 캔들은 최신 값 유지)
+    fn restore_dirty(&mut self, candles: Vec<Candle>) {
+        for candle in candles {
+            let key = (
+                TradingPair::new(candle.base_mint.clone(), candle.quote_mint.clone()),
+                candle.interval,
+                candle.open_time,
+            );
+            self.dirty.entry(key).or_insert(candle);
+        }
+    }
+
+    /// 진행 중인 캔들 조회
+    pub fn current(&self, trading_pair: &TradingPair, interval: CandleInterval) -> Option<&Candle> {
+        self.current.get(&(trading_pair.clone(), interval))
+    }
+}
+
+/// 캔들 서비스
+/// Candle (OHLCV) service
+///
+/// # 사용 흐름
+/// 1. 엔진 시작 후 `start()` 호출 (backfill + 이벤트 구독 + 저장 태스크)
+/// 2. REST는 `get_candles()`로 조회
+/// 3. 실시간 캔들은 `/api/cex/ws/market`의 `candles` 채널로 수신
+#[derive(Clone)]
+pub struct CandleService {
+    /// 데이터베이스 연결
+    db: Database,
+    /// 체결 엔진 (이벤트 버스)
+    engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+    /// 실시간 캔들 발행 대상
+    market_data: MarketDataService,
+    /// 캔들 상태
+    book: Arc<Mutex<CandleBook>>,
+}
+
+impl CandleService {
+    /// 새 CandleService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    /// * `engine` - 체결 엔진
+    /// * `market_data` - 공개 시세 서비스 (`candles` 채널 발행)
+    pub fn new(
+        db: Database,
+        engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+        market_data: MarketDataService,
+    ) -> Self {
+        Self {
+            db,
+            engine,
+            market_data,
+            book: Arc::new(Mutex::new(CandleBook::default())),
+        }
+    }
+
+    /// 백그라운드 태스크 시작 (엔진 시작 이후 호출)
+    ///
+    /// # 처리 과정
+    /// 1. 이벤트 버스 구독 → cutoff 시점 기록
+    /// 2. 거래쌍별 backfill (cutoff 이전 체결)
+    /// 3. cutoff 이후 체결 이벤트로 캔들 갱신 + 발행
+    /// 4. 이벤트 버스에서 해제되면 저장 후 재구독 (다시 backfill)
+    /// 5. 별도 태스크에서 1초마다 변경된 캔들 저장
+    pub fn start(&self) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let mut subscription = service.engine.lock().await.event_bus().subscribe("candles");
+                let cutoff = Utc::now();
+
+                if let Err(e) = service.backfill_all(cutoff).await {
+                    eprintln!("[Candles] Backfill failed: {:#}", e);
+                }
+
+                while let Some(event) = subscription.recv().await {
+                    if let EngineEvent::TradeExecuted { match_result, .. } = &event.event {
+                        let executed_at = Utc
+                            .timestamp_millis_opt(event.timestamp)
+                            .single()
+                            .unwrap_or_else(Utc::now);
+                        if executed_at <= cutoff {
+                            continue;
+                        }
+
+                        let trading_pair = TradingPair::new(
+                            match_result.base_mint.clone(),
+                            match_result.quote_mint.clone(),
+                        );
+                        let updated = service.book.lock().apply_trade(
+                            &trading_pair,
+                            match_result.price,
+                            match_result.amount,
+                            executed_at,
+                        );
+                        for candle in &updated {
+                            service.market_data.publish_candle(candle);
+                        }
+                    }
+                }
+
+                eprintln!("[Candles] Event subscription closed, resubscribing");
+                service.flush().await;
+                sleep(RESUBSCRIBE_DELAY).await;
+            }
+        });
+
+        let service = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(FLUSH_INTERVAL);
+            loop {
+                ticker.tick().await;
+                service.flush().await;
+            }
+        });
+
+        eprintln!("[Candles] Started ({} intervals)", CandleInterval::ALL.len());
+    }
+
+    /// 캔들 조회 (open_time 오름차순)
+    ///
+    /// # Arguments
+    /// * `from` - 시작 시간 (없으면 `to`에서 limit개 주기 전)
+    /// * `to` - 끝 시간 (미포함, 없으면 현재 진행 중인 캔들까지)
+    /// * `limit` - 최대 개수 (기본 500, 최대 1000)
+    ///
+    /// # Returns
+    /// 체결이 있었던 구간의 캔들 (진행 중인 캔들 포함)
+    pub async fn get_candles(
+        &self,
+        base_mint: &str,
+        quote_mint: &str,
+        interval: CandleInterval,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Candle>> {
+        let limit = limit.unwrap_or(DEFAULT_CANDLE_LIMIT).clamp(1, MAX_CANDLE_LIMIT);
+        let to = to.unwrap_or_else(|| Utc::now() + chrono::Duration::seconds(interval.seconds()));
+        let from = from.unwrap_or_else(|| to - chrono::Duration::seconds(interval.seconds() * limit));
+
+        let mut candles = CandleRepository::new(self.db.pool().clone())
+            .get_range(base_mint, quote_mint, interval, from, to, limit)
+            .await?;
+
+        // 아직 저장되지 않은 진행 중 캔들 반영
+        let trading_pair = TradingPair::new(base_mint.to_string(), quote_mint.to_string());
+        if let Some(current) = self.book.lock().current(&trading_pair, interval)
+            && current.open_time >= from
+            && current.open_time < to
+        {
+            match candles.last_mut() {
+                Some(last) if last.open_time == current.open_time => *last = current.clone(),
+                Some(last) if last.open_time > current.open_time => {}
+                _ => {
+                    candles.push(current.clone());
+                    if candles.len() as i64 > limit {
+                        candles.remove(0);
+                    }
+                }
+            }
+        }
+
+        Ok(candles)
+    }
+
+    /// 모든 거래쌍 backfill
+    async fn backfill_all(&self, cutoff: DateTime<Utc>) -> Result<()> {
+        let pairs = TradeRepository::new(self.db.pool().clone())
+            .get_trading_pairs()
+            .await?;
+
+        for (base_mint, quote_mint) in pairs {
+            let trading_pair = TradingPair::new(base_mint, quote_mint);
+            let count = self
+                .backfill(&trading_pair, cutoff)
+                .await
+                .with_context(|| format!("Failed to backfill candles for {}/{}", trading_pair.base_mint, trading_pair.quote_mint))?;
+            if count > 0 {
+                eprintln!(
+                    "[Candles] Backfilled {} candles for {}/{}",
+                    count, trading_pair.base_mint, trading_pair.quote_mint
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// 거래쌍 backfill (체결 내역 → 캔들 재집계)
+    ///
+    /// # 처리 과정
+    /// 1. 마지막 1m 캔들이 속한 날(1d 구간)의 시작부터 재집계 (모든 주기 구간을 온전히 다시 계산)
+    /// 2. `TradeRepository::get_by_pair`로 최신 체결부터 페이지 조회 → 재개 위치 이전이면 중단
+    /// 3. 시간순으로 집계 → upsert → 진행 중인 캔들로 사용
+    ///
+    /// # Returns
+    /// 저장한 캔들 개수
+    pub async fn backfill(&self, trading_pair: &TradingPair, cutoff: DateTime<Utc>) -> Result<usize> {
+        let candle_repo = CandleRepository::new(self.db.pool().clone());
+        let trade_repo = TradeRepository::new(self.db.pool().clone());
+        let (base_mint, quote_mint) = (&trading_pair.base_mint, &trading_pair.quote_mint);
+
+        let resume_from = candle_repo
+            .get_latest_open_time(base_mint, quote_mint, CandleInterval::M1)
+            .await?
+            .map(|open_time| CandleInterval::D1.bucket_start(open_time));
+
+        let mut trades = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = trade_repo
+                .get_by_pair(base_mint, quote_mint, Some(BACKFILL_PAGE_SIZE), Some(offset))
+                .await?;
+            let page_len = page.len() as i64;
+            offset += page_len;
+
+            let reached_resume_point = match (resume_from, page.last()) {
+                (Some(resume_from), Some(oldest)) => oldest.created_at < resume_from,
+                _ => false,
+            };
+            trades.extend(page.into_iter().filter(|trade| {
+                trade.created_at <= cutoff && resume_from.is_none_or(|from| trade.created_at >= from)
+            }));
+
+            if reached_resume_point || page_len < BACKFILL_PAGE_SIZE {
+                break;
+            }
+        }
+        trades.sort_by_key(|trade| (trade.created_at, trade.id));
+
+        let mut rebuilt = CandleBook::default();
+        for trade in &trades {
+            rebuilt.apply_trade(trading_pair, trade.price, trade.amount, trade.created_at);
+        }
+
+        let candles = rebuilt.take_dirty();
+        for chunk in candles.chunks(BACKFILL_PAGE_SIZE as usize) {
+            candle_repo.upsert_many(chunk).await?;
+        }
+
+        let mut book = self.book.lock();
+        for (key, candle) in rebuilt.current {
+            book.current.insert(key, candle);
+        }
+        Ok(candles.len())
+    }
+
+    /// 변경된 캔들 저장 (실패 시 다음 주기에 재시도)
+    async fn flush(&self) {
+        let candles = self.book.lock().take_dirty();
+        if candles.is_empty() {
+            return;
+        }
+
+        let repo = CandleRepository::new(self.db.pool().clone());
+        if let Err(e) = repo.upsert_many(&candles).await {
+            eprintln!("[Candles] Failed to persist {} candles: {:#}", candles.len(), e);
+            self.book.lock().restore_dirty(candles);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair() -> TradingPair {
+        TradingPair::new("SOL".to_string(), "USDT".to_string())
+    }
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 - 1_700_000_000 % 86_400 + seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn trades_roll_into_new_buckets_per_interval() {
+        let mut book = CandleBook::default();
+        book.apply_trade(&pair(), Decimal::new(100, 0), Decimal::new(1, 0), at(10));
+        book.apply_trade(&pair(), Decimal::new(105, 0), Decimal::new(2, 0), at(50));
+        let updated = book.apply_trade(&pair(), Decimal::new(98, 0), Decimal::new(1, 0), at(70));
+        assert_eq!(updated.len(), CandleInterval::ALL.len());
+
+        let minute = book.current(&pair(), CandleInterval::M1).unwrap();
+        assert_eq!(minute.open_time, at(60));
+        assert_eq!(minute.trade_count, 1);
+
+        let five = book.current(&pair(), CandleInterval::M5).unwrap();
+        assert_eq!(five.open_time, at(0));
+        assert_eq!(
+            (five.open, five.high, five.low, five.close),
+            (Decimal::new(100, 0), Decimal::new(105, 0), Decimal::new(98, 0), Decimal::new(98, 0))
+        );
+        assert_eq!(five.volume, Decimal::new(4, 0));
+        assert_eq!(five.quote_volume, Decimal::new(408, 0));
+
+        // 1m 캔들 2개 (00:00, 00:01) + 나머지 주기 각 1개
+        assert_eq!(book.take_dirty().len(), CandleInterval::ALL.len() + 1);
+        assert!(book.take_dirty().is_empty());
+    }
+
+    #[test]
+    fn late_trades_do_not_reopen_closed_buckets() {
+        let mut book = CandleBook::default();
+        book.apply_trade(&pair(), Decimal::new(100, 0), Decimal::new(1, 0), at(130));
+        let updated = book.apply_trade(&pair(), Decimal::new(90, 0), Decimal::new(1, 0), at(30));
+
+        // 1m 구간은 이미 지나갔으므로 무시, 5m 이상은 같은 구간이라 반영
+        assert_eq!(updated.len(), CandleInterval::ALL.len() - 1);
+        assert_eq!(book.current(&pair(), CandleInterval::M1).unwrap().low, Decimal::new(100, 0));
+        assert_eq!(book.current(&pair(), CandleInterval::M5).unwrap().low, Decimal::new(90, 0));
+        assert_eq!("15m".parse::<CandleInterval>().unwrap(), CandleInterval::M15);
+    }
+}
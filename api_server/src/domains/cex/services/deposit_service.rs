@@ -0,0 +1,653 @@
+use crate::shared::clients::SolanaClient;
+use crate::shared::database::{Database, DepositRepository, SolanaWalletRepository};
+use crate::domains::cex::models::deposit::{Deposit, DepositAddressResponse, DepositCreate, DepositStatus};
+use crate::domains::cex::models::ledger::LedgerReason;
+use crate::domains::cex::engine::{Engine, runtime::HighPerformanceEngine};
+use crate::domains::wallet::services::WalletService;
+use anyhow::{Context, Result, bail};
+use chrono::{Duration as ChronoDuration, Utc};
+use rust_decimal::Decimal;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::interval;
+
+// =====================================================
+// DepositService
+// =====================================================
+// 역할: 온체인 입금 감시 → CEX 잔고 입금
+//
+// 입금 주소:
+// - 사용자 Solana 지갑 (wallet 도메인, 없으면 처음 조회할 때 생성)
+// - SPL 토큰은 지갑의 Associated Token Account
+// - 처음 조회할 때 입금 주소로 전환 (is_deposit_address) → 지갑 API 전송 불가
+//   → 현재 서명 위치부터 감시 (전환 전에 받은 금액은 입금으로 인정하지 않음)
+//
+// 처리 과정 (POLL_INTERVAL마다):
+// 1. 감시: 모든 지갑 + 토큰 계정의 새 트랜잭션 서명 조회 (deposit_cursors 이후)
+//    → 트랜잭션 잔고 변화(pre/post balances)에서 지갑 소유 계정의 증가분을 입금으로 기록 (confirming)
+//    → 수탁 지갑(solana_wallets)이 서명한 트랜잭션은 무시 (사용자 지갑 간 이동을 입금으로 중복 인정하지 않음)
+// 2. 확정: confirming 입금의 서명 상태 조회
+//    → finalized 이면 crediting 선점 후 엔진 잔고 채널(BalanceCommand::UpdateBalance)로 입금 → credited
+//    → 실패/사라진 트랜잭션은 failed
+//
+// 한 번만 입금:
+// - deposits (signature, mint) UNIQUE → 같은 트랜잭션을 여러 번 발견해도 한 행
+// - confirming → crediting 조건부 UPDATE로 선점한 경우에만 엔진에 입금
+// - crediting에서 멈춘 행은 자동 재시도하지 않음 (수동 확인)
+//
+// 자산 설정:
+// - SOL (네이티브, 9자리) 기본 지원
+// - SPL 토큰: 환경 변수 DEPOSIT_SPL_TOKENS="USDT:<mint 주소>:6,USDC:<mint 주소>:6"
+// =====================================================
+
+/// SPL 토큰 설정 환경 변수
+const DEPOSIT_SPL_TOKENS_ENV: &str = "DEPOSIT_SPL_TOKENS";
+
+/// 네이티브 SOL 자산 / 소수 자릿수
+const NATIVE_MINT: &str = "SOL";
+const NATIVE_DECIMALS: u32 = 9;
+
+/// SPL Token / Associated Token Account 프로그램
+pub(crate) const TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub(crate) const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// 감시 간격
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 서명 조회 페이지 크기 (RPC 최대 1000)
+const SIGNATURE_PAGE_SIZE: usize = 1000;
+
+/// 한 번에 확정 확인할 입금 수
+const CONFIRM_BATCH_SIZE: i64 = 500;
+
+/// 노드가 서명을 모르는 상태로 이 시간이 지나면 실패 처리 (포크로 사라진 트랜잭션)
+const DROPPED_DEPOSIT_TIMEOUT_MINUTES: i64 = 10;
+
+/// 입금 목록 기본/최대 개수
+const DEFAULT_LIST_LIMIT: i64 = 50;
+const MAX_LIST_LIMIT: i64 = 200;
+
+/// 입금 가능 자산
+/// Depositable asset
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepositAsset {
+    /// CEX 자산 이름 (예: "SOL", "USDT")
+    pub mint: String,
+    /// SPL 토큰 mint 주소 (네이티브 SOL은 None)
+    pub token_mint: Option<Pubkey>,
+    /// 온체인 소수 자릿수
+    pub decimals: u32,
+}
+
+impl DepositAsset {
+    /// SOL + DEPOSIT_SPL_TOKENS 설정
+    pub fn from_env() -> Result<Vec<DepositAsset>> {
+        let mut assets = vec![DepositAsset {
+            mint: NATIVE_MINT.to_string(),
+            token_mint: None,
+            decimals: NATIVE_DECIMALS,
+        }];
+
+        if let Ok(value) = std::env::var(DEPOSIT_SPL_TOKENS_ENV) {
+            assets.extend(parse_spl_tokens(&value)?);
+        }
+        Ok(assets)
+    }
+
+    /// 지갑 기준 입금 주소 (SOL: 지갑, SPL: Associated Token Account)
+    pub fn deposit_address(&self, owner: &Pubkey) -> Pubkey {
+        match &self.token_mint {
+            Some(token_mint) => associated_token_address(owner, token_mint),
+            None => *owner,
+        }
+    }
+}
+
+/// "심볼:mint 주소:소수 자릿수" 목록 파싱 (쉼표 구분)
+fn parse_spl_tokens(value: &str) -> Result<Vec<DepositAsset>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let parts: Vec<&str> = entry.split(':').collect();
+            let [mint, token_mint, decimals] = parts.as_slice() else {
+                bail!("Invalid {} entry (expected SYMBOL:MINT:DECIMALS): {}", DEPOSIT_SPL_TOKENS_ENV, entry);
+            };
+            Ok(DepositAsset {
+                mint: mint.to_string(),
+                token_mint: Some(Pubkey::from_str(token_mint).context(format!("Invalid token mint: {}", token_mint))?),
+                decimals: decimals.parse().context(format!("Invalid decimals: {}", decimals))?,
+            })
+        })
+        .collect()
+}
+
+/// Associated Token Account 주소
+pub(crate) fn associated_token_address(owner: &Pubkey, token_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), token_mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// 트랜잭션에서 발견한 입금
+#[derive(Debug, Clone, PartialEq)]
+struct ObservedDeposit {
+    mint: String,
+    token_mint: Option<String>,
+    amount: Decimal,
+}
+
+/// 트랜잭션 서명자 중 수탁 지갑 주소 (있으면 입금으로 인정하지 않음)
+///
+/// - jsonParsed: accountKeys의 signer 필드
+/// - 문자열 accountKeys: 앞의 header.numRequiredSignatures개
+fn custodial_signer<'a>(transaction: &'a Value, custodial: &HashSet<String>) -> Option<&'a str> {
+    let message = &transaction["transaction"]["message"];
+    let required_signatures = message["header"]["numRequiredSignatures"].as_u64().unwrap_or(0) as usize;
+    message["accountKeys"]
+        .as_array()?
+        .iter()
+        .enumerate()
+        .filter_map(|(index, key)| match key.as_str() {
+            Some(key) => (index < required_signatures).then_some(key),
+            None => key["pubkey"].as_str().filter(|_| key["signer"].as_bool() == Some(true)),
+        })
+        .find(|signer| custodial.contains(*signer))
+}
+
+/// 트랜잭션(jsonParsed)에서 지갑 소유 계정의 자산별 증가분 계산
+///
+/// - SOL: 지갑 계정의 lamports 변화 (preBalances → postBalances)
+/// - SPL: 지갑이 소유한 토큰 계정의 변화 합 (preTokenBalances → postTokenBalances)
+/// - 실패한 트랜잭션, 감소분, 설정에 없는 토큰은 무시
+fn parse_deposits(transaction: &Value, owner: &str, assets: &[DepositAsset]) -> Result<Vec<ObservedDeposit>> {
+    let meta = transaction.get("meta").context("Transaction has no meta")?;
+    if !meta["err"].is_null() {
+        return Ok(Vec::new());
+    }
+
+    let mut deposits = Vec::new();
+
+    // SOL
+    let account_keys = transaction["transaction"]["message"]["accountKeys"]
+        .as_array()
+        .context("Transaction has no account keys")?;
+    let owner_index = account_keys.iter().position(|key| {
+        key.as_str().or_else(|| key["pubkey"].as_str()) == Some(owner)
+    });
+    if let Some(index) = owner_index
+        && let Some(native) = assets.iter().find(|asset| asset.token_mint.is_none())
+    {
+        let pre = meta["preBalances"][index].as_u64().context("Missing pre balance")?;
+        let post = meta["postBalances"][index].as_u64().context("Missing post balance")?;
+        if post > pre {
+            deposits.push(ObservedDeposit {
+                mint: native.mint.clone(),
+                token_mint: None,
+                amount: Decimal::from_i128_with_scale((post - pre) as i128, native.decimals),
+            });
+        }
+    }
+
+    // SPL 토큰 (계정 인덱스별 잔고 → mint별 합)
+    let token_amounts = |balances: &Value| -> Result<HashMap<u64, (String, i128)>> {
+        let mut amounts = HashMap::new();
+        for balance in balances.as_array().into_iter().flatten() {
+            if balance["owner"].as_str() != Some(owner) {
+                continue;
+            }
+            let index = balance["accountIndex"].as_u64().context("Missing token account index")?;
+            let mint = balance["mint"].as_str().context("Missing token mint")?;
+            let amount: i128 = balance["uiTokenAmount"]["amount"]
+                .as_str()
+                .context("Missing token amount")?
+                .parse()
+                .context("Invalid token amount")?;
+            amounts.insert(index, (mint.to_string(), amount));
+        }
+        Ok(amounts)
+    };
+    let pre_tokens = token_amounts(&meta["preTokenBalances"])?;
+    let post_tokens = token_amounts(&meta["postTokenBalances"])?;
+
+    let mut token_deltas: HashMap<String, i128> = HashMap::new();
+    for (index, (mint, post)) in &post_tokens {
+        let pre = pre_tokens.get(index).map(|(_, amount)| *amount).unwrap_or(0);
+        *token_deltas.entry(mint.clone()).or_default() += post - pre;
+    }
+    for (index, (mint, pre)) in &pre_tokens {
+        if !post_tokens.contains_key(index) {
+            *token_deltas.entry(mint.clone()).or_default() -= pre;
+        }
+    }
+
+    for asset in assets {
+        let Some(token_mint) = asset.token_mint.map(|mint| mint.to_string()) else {
+            continue;
+        };
+        if let Some(delta) = token_deltas.get(&token_mint)
+            && *delta > 0
+        {
+            deposits.push(ObservedDeposit {
+                mint: asset.mint.clone(),
+                token_mint: Some(token_mint),
+                amount: Decimal::from_i128_with_scale(*delta, asset.decimals),
+            });
+        }
+    }
+
+    Ok(deposits)
+}
+
+/// 입금 서비스
+/// Deposit Service
+#[derive(Clone)]
+pub struct DepositService {
+    db: Database,
+    /// 잔고 입금 (BalanceCommand::UpdateBalance)
+    engine: Arc<Mutex<HighPerformanceEngine>>,
+    solana_client: SolanaClient,
+    /// 입금 주소 (사용자 지갑) 생성/조회
+    wallet_service: WalletService,
+    /// 입금 가능 자산
+    assets: Arc<Vec<DepositAsset>>,
+}
+
+impl DepositService {
+    /// 새 DepositService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    /// * `engine` - 체결 엔진 (잔고 채널)
+    pub fn new(db: Database, engine: Arc<Mutex<HighPerformanceEngine>>) -> Result<Self> {
+        Ok(Self {
+            solana_client: SolanaClient::new()?,
+            wallet_service: WalletService::new(db.clone())?,
+            assets: Arc::new(DepositAsset::from_env()?),
+            db,
+            engine,
+        })
+    }
+
+    /// 입금 가능 자산 목록
+    pub fn assets(&self) -> &[DepositAsset] {
+        &self.assets
+    }
+
+    /// 입금 감시 시작
+    ///
+    /// # 처리 과정
+    /// 1. crediting에서 멈춘 입금 경고 (수동 확인 필요)
+    /// 2. POLL_INTERVAL마다 새 트랜잭션 감시 → 확정된 입금 처리
+    pub fn start(&self) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            let deposit_repo = DepositRepository::new(service.db.pool().clone());
+            match deposit_repo.get_by_status(DepositStatus::Crediting, CONFIRM_BATCH_SIZE).await {
+                Ok(stuck) => {
+                    for deposit in stuck {
+                        eprintln!(
+                            "[Deposit] Deposit {} ({} {}, signature={}) was interrupted while crediting, manual review required",
+                            deposit.id, deposit.amount, deposit.mint, deposit.signature
+                        );
+                    }
+                }
+                Err(e) => eprintln!("[Deposit] Failed to load crediting deposits: {:#}", e),
+            }
+
+            let mut ticker = interval(POLL_INTERVAL);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = service.scan_all().await {
+                    eprintln!("[Deposit] Scan failed: {:#}", e);
+                }
+                if let Err(e) = service.process_confirming().await {
+                    eprintln!("[Deposit] Confirmation check failed: {:#}", e);
+                }
+            }
+        });
+
+        let mints: Vec<&str> = self.assets.iter().map(|asset| asset.mint.as_str()).collect();
+        eprintln!("[Deposit] Started (assets: {})", mints.join(", "));
+    }
+
+    /// 입금 주소 조회 (지갑이 없으면 생성)
+    /// Get deposit address
+    ///
+    /// # 처리 과정
+    /// 1. 사용자 지갑 조회 (없으면 생성)
+    /// 2. 아직 입금 주소가 아니면 자산별 주소의 최신 서명을 감시 시작 위치로 저장 후 입금 주소로 전환
+    ///
+    /// # Returns
+    /// 지원하지 않는 자산이면 None
+    pub async fn get_deposit_address(&self, user_id: u64, mint: &str) -> Result<Option<DepositAddressResponse>> {
+        let Some(asset) = self.assets.iter().find(|asset| asset.mint == mint) else {
+            return Ok(None);
+        };
+
+        let wallets = self.wallet_service.get_user_wallets(user_id).await?;
+        let wallet = match wallets.into_iter().next() {
+            Some(wallet) => wallet,
+            None => self.wallet_service.create_wallet(user_id).await?,
+        };
+        let owner = SolanaClient::parse_pubkey(&wallet.public_key)?;
+
+        if !wallet.is_deposit_address {
+            // 전환 전 이력은 입금으로 보지 않음 (지갑 API로 이미 빼냈을 수 있음)
+            let deposit_repo = DepositRepository::new(self.db.pool().clone());
+            for asset in self.assets.iter() {
+                let address = asset.deposit_address(&owner);
+                let latest = self.solana_client.get_signatures_for_address(&address, None, None, 1).await?;
+                if let Some(status) = latest.first() {
+                    deposit_repo.set_cursor(&address.to_string(), &status.signature).await?;
+                }
+            }
+            SolanaWalletRepository::new(self.db.pool().clone())
+                .mark_deposit_address(wallet.id)
+                .await?;
+            eprintln!("[Deposit] Wallet {} of user {} is now a deposit address", wallet.id, user_id);
+        }
+
+        Ok(Some(DepositAddressResponse {
+            mint: asset.mint.clone(),
+            address: asset.deposit_address(&owner).to_string(),
+            owner: wallet.public_key,
+            token_mint: asset.token_mint.map(|mint| mint.to_string()),
+        }))
+    }
+
+    /// 사용자 입금 목록 (최신순)
+    pub async fn list_deposits(&self, user_id: u64, mint: Option<&str>, limit: Option<i64>) -> Result<Vec<Deposit>> {
+        let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT).clamp(1, MAX_LIST_LIMIT);
+        DepositRepository::new(self.db.pool().clone())
+            .list_by_user(user_id, mint, limit)
+            .await
+    }
+
+    /// 모든 입금 주소 감시
+    async fn scan_all(&self) -> Result<()> {
+        let wallet_repo = SolanaWalletRepository::new(self.db.pool().clone());
+        let wallets = wallet_repo.get_all_wallet_addresses().await?;
+        let custodial: HashSet<String> = wallets.iter().map(|(_, public_key, _)| public_key.clone()).collect();
+
+        for (user_id, owner, is_deposit_address) in wallets {
+            if !is_deposit_address {
+                continue;
+            }
+            let owner_pubkey = match SolanaClient::parse_pubkey(&owner) {
+                Ok(pubkey) => pubkey,
+                Err(e) => {
+                    eprintln!("[Deposit] Skipping wallet of user {}: {:#}", user_id, e);
+                    continue;
+                }
+            };
+
+            // 지갑 + 토큰 계정에 같은 트랜잭션이 보이면 한 번만 처리
+            let mut seen = HashSet::new();
+            for asset in self.assets.iter() {
+                let address = asset.deposit_address(&owner_pubkey);
+                if let Err(e) = self.scan_address(user_id, &owner, &address, &custodial, &mut seen).await {
+                    eprintln!("[Deposit] Failed to scan {} ({}): {:#}", address, asset.mint, e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 주소 하나 감시 (마지막 처리 서명 이후, 오래된 순으로 처리)
+    async fn scan_address(
+        &self,
+        user_id: u64,
+        owner: &str,
+        address: &Pubkey,
+        custodial: &HashSet<String>,
+        seen: &mut HashSet<String>,
+    ) -> Result<()> {
+        let deposit_repo = DepositRepository::new(self.db.pool().clone());
+        let address_str = address.to_string();
+        let cursor = deposit_repo.get_cursor(&address_str).await?;
+
+        // 1. 새 서명 조회 (최신순 페이지 → 오래된 순으로 뒤집기)
+        let mut signatures = Vec::new();
+        let mut before: Option<String> = None;
+        loop {
+            let page = self
+                .solana_client
+                .get_signatures_for_address(address, before.as_deref(), cursor.as_deref(), SIGNATURE_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            before = page.last().map(|status| status.signature.clone());
+            signatures.extend(page);
+            if page_len < SIGNATURE_PAGE_SIZE {
+                break;
+            }
+        }
+        signatures.reverse();
+
+        // 2. 트랜잭션별 입금 기록
+        let mut last_processed = None;
+        for status in signatures {
+            if status.err.is_none() && seen.insert(status.signature.clone()) {
+                let Some(transaction) = self.solana_client.get_transaction_json(&status.signature).await? else {
+                    // 아직 조회할 수 없음 → 다음 주기에 이 서명부터 다시
+                    break;
+                };
+
+                if let Some(signer) = custodial_signer(&transaction, custodial) {
+                    eprintln!(
+                        "[Deposit] Ignoring transfer to {} signed by custodial wallet {} (signature={})",
+                        owner, signer, status.signature
+                    );
+                    last_processed = Some(status.signature);
+                    continue;
+                }
+
+                for observed in parse_deposits(&transaction, owner, &self.assets)? {
+                    let deposit = DepositCreate {
+                        user_id,
+                        mint: observed.mint,
+                        token_mint: observed.token_mint,
+                        amount: observed.amount,
+                        address: owner.to_string(),
+                        signature: status.signature.clone(),
+                        slot: status.slot,
+                    };
+                    if deposit_repo.insert_if_new(&deposit).await? {
+                        eprintln!(
+                            "[Deposit] Detected {} {} for user {} (signature={})",
+                            deposit.amount, deposit.mint, user_id, deposit.signature
+                        );
+                    }
+                }
+            }
+            last_processed = Some(status.signature);
+        }
+
+        // 3. 처리한 위치 저장
+        if let Some(signature) = last_processed {
+            deposit_repo.set_cursor(&address_str, &signature).await?;
+        }
+        Ok(())
+    }
+
+    /// confirming 입금 확정 확인 → finalized 이면 잔고 입금
+    async fn process_confirming(&self) -> Result<()> {
+        let deposit_repo = DepositRepository::new(self.db.pool().clone());
+        let deposits = deposit_repo.get_by_status(DepositStatus::Confirming, CONFIRM_BATCH_SIZE).await?;
+        if deposits.is_empty() {
+            return Ok(());
+        }
+
+        let signatures: Vec<String> = deposits.iter().map(|deposit| deposit.signature.clone()).collect();
+        let states = self.solana_client.get_signature_states(&signatures, false).await?;
+        let dropped_before = Utc::now() - ChronoDuration::minutes(DROPPED_DEPOSIT_TIMEOUT_MINUTES);
+
+        for (deposit, state) in deposits.iter().zip(states) {
+            match state {
+                Some(state) if state.failed => {
+                    deposit_repo.mark_failed(deposit.id, "Transaction failed").await?;
+                    eprintln!("[Deposit] Deposit {} failed on-chain (signature={})", deposit.id, deposit.signature);
+                }
+                Some(state) if state.finalized => self.credit(&deposit_repo, deposit).await?,
+                Some(_) => {}
+                None if deposit.created_at < dropped_before => {
+                    deposit_repo.mark_failed(deposit.id, "Transaction was dropped").await?;
+                    eprintln!("[Deposit] Deposit {} dropped (signature={})", deposit.id, deposit.signature);
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// 선점 후 엔진 잔고 채널로 입금
+    async fn credit(&self, deposit_repo: &DepositRepository, deposit: &Deposit) -> Result<()> {
+        if !deposit_repo.claim_for_credit(deposit.id).await? {
+            return Ok(());
+        }
+
+        let result = self
+            .engine
+            .lock()
+            .await
+            .update_balance(deposit.user_id, &deposit.mint, deposit.amount, LedgerReason::Deposit, &deposit.signature)
+            .await;
+
+        match result {
+            Ok(()) => {
+                deposit_repo.mark_credited(deposit.id).await?;
+                eprintln!(
+                    "[Deposit] Credited {} {} to user {} (deposit={})",
+                    deposit.amount, deposit.mint, deposit.user_id, deposit.id
+                );
+            }
+            Err(e) => {
+                // 엔진 반영 여부를 알 수 없으므로 crediting 유지 (수동 확인)
+                eprintln!(
+                    "[Deposit] Failed to credit deposit {}, manual review required: {:#}",
+                    deposit.id, e
+                );
+                deposit_repo.set_error(deposit.id, &format!("Credit failed: {:#}", e)).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const OWNER: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+    const SENDER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+    fn assets() -> Vec<DepositAsset> {
+        let mut assets = vec![DepositAsset {
+            mint: NATIVE_MINT.to_string(),
+            token_mint: None,
+            decimals: NATIVE_DECIMALS,
+        }];
+        assets.extend(parse_spl_tokens(&format!("USDT:{}:6", USDT_MINT)).unwrap());
+        assets
+    }
+
+    #[test]
+    fn parses_native_sol_deposit_and_ignores_failed_transactions() {
+        let mut transaction = json!({
+            "slot": 100,
+            "transaction": { "message": { "accountKeys": [
+                { "pubkey": SENDER, "signer": true, "writable": true },
+                { "pubkey": OWNER, "signer": false, "writable": true },
+                { "pubkey": "11111111111111111111111111111111", "signer": false, "writable": false }
+            ] } },
+            "meta": {
+                "err": null,
+                "preBalances": [5_000_000_000u64, 1_000_000_000u64, 1],
+                "postBalances": [3_499_995_000u64, 2_500_000_000u64, 1],
+                "preTokenBalances": [],
+                "postTokenBalances": []
+            }
+        });
+
+        let deposits = parse_deposits(&transaction, OWNER, &assets()).unwrap();
+        assert_eq!(
+            deposits,
+            vec![ObservedDeposit { mint: "SOL".to_string(), token_mint: None, amount: Decimal::new(15, 1) }]
+        );
+
+        // 보낸 쪽 지갑 기준으로는 입금 없음
+        assert!(parse_deposits(&transaction, SENDER, &assets()).unwrap().is_empty());
+
+        transaction["meta"]["err"] = json!({ "InstructionError": [0, "Custom"] });
+        assert!(parse_deposits(&transaction, OWNER, &assets()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn detects_custodial_signers() {
+        let custodial: HashSet<String> = [SENDER.to_string()].into();
+        let parsed = json!({ "transaction": { "message": { "accountKeys": [
+            { "pubkey": SENDER, "signer": true, "writable": true },
+            { "pubkey": OWNER, "signer": false, "writable": true }
+        ] } } });
+        assert_eq!(custodial_signer(&parsed, &custodial), Some(SENDER));
+
+        // 수탁 지갑이 받는 쪽일 뿐이면 무시하지 않음
+        let receiving: HashSet<String> = [OWNER.to_string()].into();
+        assert_eq!(custodial_signer(&parsed, &receiving), None);
+
+        let legacy = json!({ "transaction": { "message": {
+            "header": { "numRequiredSignatures": 1 },
+            "accountKeys": [OWNER, SENDER]
+        } } });
+        assert_eq!(custodial_signer(&legacy, &custodial), None);
+        assert_eq!(custodial_signer(&legacy, &receiving), Some(OWNER));
+    }
+
+    #[test]
+    fn parses_spl_deposit_for_owned_token_accounts_only() {
+        let owner_ata = associated_token_address(&Pubkey::from_str(OWNER).unwrap(), &Pubkey::from_str(USDT_MINT).unwrap());
+        let other_mint = "So11111111111111111111111111111111111111112";
+        let transaction = json!({
+            "slot": 200,
+            "transaction": { "message": { "accountKeys": [
+                SENDER, "SenderTokenAccount1111111111111111111111111", owner_ata.to_string(), "OwnerOtherTokenAccount111111111111111111111"
+            ] } },
+            "meta": {
+                "err": null,
+                "preBalances": [5_000_000_000u64, 2_039_280, 0, 2_039_280],
+                "postBalances": [4_997_955_720u64, 2_039_280, 2_039_280, 2_039_280],
+                "preTokenBalances": [
+                    { "accountIndex": 1, "mint": USDT_MINT, "owner": SENDER, "uiTokenAmount": { "amount": "500000000", "decimals": 6 } },
+                    { "accountIndex": 3, "mint": other_mint, "owner": OWNER, "uiTokenAmount": { "amount": "1", "decimals": 9 } }
+                ],
+                "postTokenBalances": [
+                    { "accountIndex": 1, "mint": USDT_MINT, "owner": SENDER, "uiTokenAmount": { "amount": "375000000", "decimals": 6 } },
+                    { "accountIndex": 2, "mint": USDT_MINT, "owner": OWNER, "uiTokenAmount": { "amount": "125000000", "decimals": 6 } },
+                    { "accountIndex": 3, "mint": other_mint, "owner": OWNER, "uiTokenAmount": { "amount": "9", "decimals": 9 } }
+                ]
+            }
+        });
+
+        // 새로 만든 토큰 계정 (pre 없음) 증가분만 입금, 설정에 없는 토큰은 무시
+        let deposits = parse_deposits(&transaction, OWNER, &assets()).unwrap();
+        assert_eq!(
+            deposits,
+            vec![ObservedDeposit {
+                mint: "USDT".to_string(),
+                token_mint: Some(USDT_MINT.to_string()),
+                amount: Decimal::new(125, 0),
+            }]
+        );
+        assert!(parse_deposits(&transaction, SENDER, &assets()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,553 @@
+use crate::shared::database::{Database, DepositRepository, ExportJobRepository, OrderRepository, PortfolioSnapshotRepository, TradeRepository, WithdrawalRepository};
+use crate::domains::cex::models::export::{CreateExportRequest, ExportDataset, ExportFormat, ExportJob};
+use crate::domains::cex::models::trade::UserFill;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, SecondsFormat, Utc};
+use futures_util::StreamExt;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::fs::File;
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+use tokio::sync::Semaphore;
+use tokio::time::interval;
+
+// =====================================================
+// ExportService
+// =====================================================
+// 역할: 회계/세금용 내역 내보내기 (CSV / JSON) 비동기 작업
+//
+// 처리 방식:
+// - 요청 → export_jobs 행 생성 → 백그라운드 태스크에서 파일 작성
+// - Repository 스트림으로 한 행씩 읽어 바로 파일에 기록 (전체 내역을 메모리에 올리지 않음)
+// - `{id}.{형식}.part`에 작성 후 완료되면 `{id}.{형식}`으로 이름 변경
+// - 동시에 실행하는 작업 수 제한 (MAX_CONCURRENT_EXPORTS)
+//
+// 파일 보관:
+// - 완료 후 EXPORT_RETENTION_DAYS일 동안 다운로드 가능, 이후 정리 태스크가 삭제
+// - 저장 위치: 환경 변수 EXPORT_DIR (기본: ./exports)
+// =====================================================
+
+/// 파일 저장 위치 환경 변수
+const EXPORT_DIR_ENV: &str = "EXPORT_DIR";
+const DEFAULT_EXPORT_DIR: &str = "exports";
+
+/// 동시에 실행하는 작업 수
+const MAX_CONCURRENT_EXPORTS: usize = 2;
+
+/// 다운로드 가능 기간 (일)
+const EXPORT_RETENTION_DAYS: i64 = 7;
+
+/// 한 작업의 최대 기간 (일)
+pub const MAX_EXPORT_RANGE_DAYS: i64 = 366;
+
+/// 만료 파일 정리 간격
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 작업 목록 기본/최대 개수
+const DEFAULT_LIST_LIMIT: i64 = 50;
+const MAX_LIST_LIMIT: i64 = 200;
+
+/// 체결 내보내기 열
+const TRADE_COLUMNS: &[&str] = &[
+    "trade_id", "time", "base_mint", "quote_mint", "side", "order_id",
+    "price", "amount", "quote_amount", "fee", "fee_mint",
+];
+
+/// 주문 내보내기 열
+const ORDER_COLUMNS: &[&str] = &[
+    "order_id", "created_at", "updated_at", "base_mint", "quote_mint", "order_type", "order_side",
+    "price", "amount", "filled_amount", "filled_quote_amount", "status",
+];
+
+/// 일별 잔고 내보내기 열
+const BALANCE_SNAPSHOT_COLUMNS: &[&str] = &[
+    "snapshot_date", "mint", "balance", "available", "locked", "quote_mint", "price", "value", "route",
+];
+
+/// 입금 내보내기 열
+const DEPOSIT_COLUMNS: &[&str] = &[
+    "deposit_id", "time", "mint", "amount", "status", "signature", "address", "token_mint", "slot", "credited_at",
+];
+
+/// 출금 열
+const WITHDRAWAL_COLUMNS: &[&str] = &[
+    "withdrawal_id", "time", "mint", "amount", "status", "destination", "signature", "token_mint", "error", "completed_at",
+];
+
+/// 내보내기 행 (열 순서대로, None은 CSV 빈 칸 / JSON null)
+type ExportRow = Vec<Option<String>>;
+
+/// CSV / JSON 스트리밍 작성기
+/// Streaming CSV / JSON writer
+///
+/// - CSV: 헤더 한 줄 + 행마다 한 줄 (쉼표, 따옴표, 줄바꿈이 있으면 따옴표로 감쌈)
+/// - JSON: `[` + 행마다 객체 하나 + `]` (숫자도 문자열)
+pub struct ExportWriter<W> {
+    inner: W,
+    format: ExportFormat,
+    columns: &'static [&'static str],
+    rows: u64,
+}
+
+impl<W: AsyncWrite + Unpin> ExportWriter<W> {
+    /// 헤더 작성 후 작성기 생성
+    pub async fn begin(mut inner: W, format: ExportFormat, columns: &'static [&'static str]) -> Result<Self> {
+        match format {
+            ExportFormat::Csv => {
+                let header = columns.iter().map(|c| csv_field(c)).collect::<Vec<_>>().join(",");
+                inner.write_all(format!("{}\n", header).as_bytes()).await?;
+            }
+            ExportFormat::Json => inner.write_all(b"[").await?,
+        }
+        Ok(Self { inner, format, columns, rows: 0 })
+    }
+
+    /// 행 하나 작성 (값 개수는 열 개수와 같아야 함)
+    pub async fn write_row(&mut self, values: &[Option<String>]) -> Result<()> {
+        let line = match self.format {
+            ExportFormat::Csv => {
+                let fields: Vec<Cow<'_, str>> = values
+                    .iter()
+                    .map(|value| value.as_deref().map(csv_field).unwrap_or(Cow::Borrowed("")))
+                    .collect();
+                format!("{}\n", fields.join(","))
+            }
+            ExportFormat::Json => {
+                let object: serde_json::Map<String, serde_json::Value> = self
+                    .columns
+                    .iter()
+                    .zip(values)
+                    .map(|(column, value)| {
+                        let value = value.clone().map(serde_json::Value::String).unwrap_or(serde_json::Value::Null);
+                        (column.to_string(), value)
+                    })
+                    .collect();
+                let separator = if self.rows == 0 { "\n" } else { ",\n" };
+                format!("{}{}", separator, serde_json::Value::Object(object))
+            }
+        };
+        self.inner.write_all(line.as_bytes()).await?;
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// 마무리 (JSON 배열 닫기, flush)
+    ///
+    /// # Returns
+    /// (내부 writer, 작성한 행 수)
+    pub async fn finish(mut self) -> Result<(W, u64)> {
+        if self.format == ExportFormat::Json {
+            self.inner.write_all(b"\n]\n").await?;
+        }
+        self.inner.flush().await?;
+        Ok((self.inner, self.rows))
+    }
+}
+
+/// CSV 필드 (쉼표, 따옴표, 줄바꿈이 있으면 따옴표로 감싸고 따옴표는 두 번)
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// 체결 → 내보내기 행 (자기 체결이면 매수/매도 두 행)
+///
+/// 수수료: 정산 시 차감한 금액 (매수: base_mint, 매도: quote_mint)
+fn trade_rows(fill: &UserFill) -> Vec<ExportRow> {
+    let trade = &fill.trade;
+    let quote_amount = trade.price * trade.amount;
+
+    [
+        (fill.buyer_fee, "buy", trade.buy_order_id, &trade.base_mint),
+        (fill.seller_fee, "sell", trade.sell_order_id, &trade.quote_mint),
+    ]
+        .into_iter()
+        .filter_map(|(fee, side, order_id, fee_mint)| fee.map(|fee| (fee, side, order_id, fee_mint)))
+        .map(|(fee, side, order_id, fee_mint)| {
+            vec![
+                Some(trade.id.to_string()),
+                Some(timestamp(trade.created_at)),
+                Some(trade.base_mint.clone()),
+                Some(trade.quote_mint.clone()),
+                Some(side.to_string()),
+                Some(order_id.to_string()),
+                Some(trade.price.to_string()),
+                Some(trade.amount.to_string()),
+                Some(quote_amount.to_string()),
+                Some(fee.to_string()),
+                Some(fee_mint.clone()),
+            ]
+        })
+        .collect()
+}
+
+/// 내보내기 서비스
+/// Export Service
+#[derive(Clone)]
+pub struct ExportService {
+    db: Database,
+    /// 파일 저장 위치
+    export_dir: PathBuf,
+    /// 동시 실행 제한
+    permits: Arc<Semaphore>,
+}
+
+impl ExportService {
+    /// 새 ExportService 생성 (저장 위치는 EXPORT_DIR 환경 변수)
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    pub fn new(db: Database) -> Self {
+        let export_dir = std::env::var(EXPORT_DIR_ENV).unwrap_or_else(|_| DEFAULT_EXPORT_DIR.to_string());
+        Self {
+            db,
+            export_dir: PathBuf::from(export_dir),
+            permits: Arc::new(Semaphore::new(MAX_CONCURRENT_EXPORTS)),
+        }
+    }
+
+    /// 백그라운드 태스크 시작
+    ///
+    /// # 처리 과정
+    /// 1. 재시작 전에 끝나지 않은 작업(pending/running)을 처음부터 다시 실행
+    /// 2. CLEANUP_INTERVAL마다 기한이 지난 파일 삭제
+    pub fn start(&self) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            let job_repo = ExportJobRepository::new(service.db.pool().clone());
+            match job_repo.get_unfinished().await {
+                Ok(jobs) => {
+                    if !jobs.is_empty() {
+                        eprintln!("[Export] Resuming {} unfinished jobs", jobs.len());
+                    }
+                    for job in jobs {
+                        tokio::spawn(service.clone().run(job));
+                    }
+                }
+                Err(e) => eprintln!("[Export] Failed to load unfinished jobs: {:#}", e),
+            }
+
+            let mut ticker = interval(CLEANUP_INTERVAL);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = service.cleanup_expired().await {
+                    eprintln!("[Export] Cleanup failed: {:#}", e);
+                }
+            }
+        });
+
+        eprintln!("[Export] Started (dir: {})", self.export_dir.display());
+    }
+
+    /// 내보내기 작업 생성 (파일은 백그라운드에서 작성)
+    /// Create export job
+    ///
+    /// # Arguments
+    /// * `request` - 데이터 종류, 형식, 기간 (핸들러에서 기간 검증)
+    pub async fn create_export(&self, user_id: u64, request: &CreateExportRequest) -> Result<ExportJob> {
+        let job_repo = ExportJobRepository::new(self.db.pool().clone());
+        let job = job_repo
+            .create(user_id, request.dataset, request.format, request.from, request.to)
+            .await?;
+
+        tokio::spawn(self.clone().run(job.clone()));
+        Ok(job)
+    }
+
+    /// 사용자 작업 목록 (최신순)
+    pub async fn list_jobs(&self, user_id: u64, limit: Option<i64>) -> Result<Vec<ExportJob>> {
+        let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT).clamp(1, MAX_LIST_LIMIT);
+        ExportJobRepository::new(self.db.pool().clone())
+            .list_by_user(user_id, limit)
+            .await
+    }
+
+    /// 사용자 작업 조회
+    pub async fn get_job(&self, user_id: u64, job_id: u64) -> Result<Option<ExportJob>> {
+        ExportJobRepository::new(self.db.pool().clone())
+            .get_by_user(user_id, job_id)
+            .await
+    }
+
+    /// 완료된 작업 파일 열기
+    pub async fn open_file(&self, job: &ExportJob) -> Result<File> {
+        let path = job.file_path.as_deref().context("Export file is not available")?;
+        File::open(path)
+            .await
+            .with_context(|| format!("Failed to open export file {}", path))
+    }
+
+    /// 작업 실행 (파일 작성 → 완료/실패 기록)
+    async fn run(self, job: ExportJob) {
+        let Ok(_permit) = self.permits.clone().acquire_owned().await else {
+            return;
+        };
+
+        let job_repo = ExportJobRepository::new(self.db.pool().clone());
+        if let Err(e) = job_repo.mark_running(job.id).await {
+            eprintln!("[Export] Job {} could not start: {:#}", job.id, e);
+            return;
+        }
+
+        let path = self.export_dir.join(format!("{}.{}", job.id, job.format.as_str()));
+        let part_path = self.export_dir.join(format!("{}.{}.part", job.id, job.format.as_str()));
+
+        let result = async {
+            let rows = self.write_file(&job, &part_path).await?;
+            tokio::fs::rename(&part_path, &path)
+                .await
+                .context("Failed to move export file")?;
+            let expires_at = Utc::now() + ChronoDuration::days(EXPORT_RETENTION_DAYS);
+            job_repo
+                .mark_completed(job.id, rows, &path.to_string_lossy(), expires_at)
+                .await?;
+            Ok::<u64, anyhow::Error>(rows)
+        }
+        .await;
+
+        match result {
+            Ok(rows) => {
+                eprintln!("[Export] Job {} completed ({} {} rows)", job.id, rows, job.dataset);
+            }
+            Err(e) => {
+                eprintln!("[Export] Job {} failed: {:#}", job.id, e);
+                let _ = tokio::fs::remove_file(&part_path).await;
+                if let Err(e) = job_repo.mark_failed(job.id, &format!("{:#}", e)).await {
+                    eprintln!("[Export] Job {} could not be marked failed: {:#}", job.id, e);
+                }
+            }
+        }
+    }
+
+    /// 데이터 종류별로 스트림을 읽어 파일 작성
+    ///
+    /// # Returns
+    /// 작성한 행 수
+    async fn write_file(&self, job: &ExportJob, path: &Path) -> Result<u64> {
+        tokio::fs::create_dir_all(&self.export_dir)
+            .await
+            .with_context(|| format!("Failed to create export dir {}", self.export_dir.display()))?;
+        let file = BufWriter::new(
+            File::create(path)
+                .await
+                .with_context(|| format!("Failed to create export file {}", path.display()))?,
+        );
+
+        let (file, rows) = match job.dataset {
+            ExportDataset::Trades => {
+                let mut writer = ExportWriter::begin(file, job.format, TRADE_COLUMNS).await?;
+                let trade_repo = TradeRepository::new(self.db.pool().clone());
+                let mut fills = trade_repo.stream_by_user(job.user_id, job.from, job.to);
+                while let Some(fill) = fills.next().await {
+                    for row in trade_rows(&fill?) {
+                        writer.write_row(&row).await?;
+                    }
+                }
+                writer.finish().await?
+            }
+            ExportDataset::Orders => {
+                let mut writer = ExportWriter::begin(file, job.format, ORDER_COLUMNS).await?;
+                let order_repo = OrderRepository::new(self.db.pool().clone());
+                let mut orders = order_repo.stream_by_user(job.user_id, job.from, job.to);
+                while let Some(order) = orders.next().await {
+                    let order = order?;
+                    writer
+                        .write_row(&[
+                            Some(order.id.to_string()),
+                            Some(timestamp(order.created_at)),
+                            Some(timestamp(order.updated_at)),
+                            Some(order.base_mint),
+                            Some(order.quote_mint),
+                            Some(order.order_type),
+                            Some(order.order_side),
+                            order.price.map(|price| price.to_string()),
+                            Some(order.amount.to_string()),
+                            Some(order.filled_amount.to_string()),
+                            Some(order.filled_quote_amount.to_string()),
+                            Some(order.status),
+                        ])
+                        .await?;
+                }
+                writer.finish().await?
+            }
+            ExportDataset::BalanceSnapshots => {
+                let mut writer = ExportWriter::begin(file, job.format, BALANCE_SNAPSHOT_COLUMNS).await?;
+                let snapshot_repo = PortfolioSnapshotRepository::new(self.db.pool().clone());
+                let mut assets = snapshot_repo.stream_assets(job.user_id, job.from, job.to);
+                while let Some(asset) = assets.next().await {
+                    let (snapshot_date, quote_mint, asset) = asset?;
+                    writer
+                        .write_row(&[
+                            Some(snapshot_date.to_string()),
+                            Some(asset.mint),
+                            Some(asset.balance.to_string()),
+                            Some(asset.available.to_string()),
+                            Some(asset.locked.to_string()),
+                            Some(quote_mint),
+                            asset.price.map(|price| price.to_string()),
+                            asset.value.map(|value| value.to_string()),
+                            Some(asset.route.join(">")),
+                        ])
+                        .await?;
+                }
+                writer.finish().await?
+            }
+            ExportDataset::Deposits => {
+                let mut writer = ExportWriter::begin(file, job.format, DEPOSIT_COLUMNS).await?;
+                let deposit_repo = DepositRepository::new(self.db.pool().clone());
+                let mut deposits = deposit_repo.stream_by_user(job.user_id, job.from, job.to);
+                while let Some(deposit) = deposits.next().await {
+                    let deposit = deposit?;
+                    writer
+                        .write_row(&[
+                            Some(deposit.id.to_string()),
+                            Some(timestamp(deposit.created_at)),
+                            Some(deposit.mint),
+                            Some(deposit.amount.to_string()),
+                            Some(deposit.status.as_str().to_string()),
+                            Some(deposit.signature),
+                            Some(deposit.address),
+                            deposit.token_mint,
+                            Some(deposit.slot.to_string()),
+                            deposit.credited_at.map(timestamp),
+                        ])
+                        .await?;
+                }
+                writer.finish().await?
+            }
+            ExportDataset::Withdrawals => {
+                let mut writer = ExportWriter::begin(file, job.format, WITHDRAWAL_COLUMNS).await?;
+                let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+                let mut withdrawals = withdrawal_repo.stream_by_user(job.user_id, job.from, job.to);
+                while let Some(withdrawal) = withdrawals.next().await {
+                    let withdrawal = withdrawal?;
+                    writer
+                        .write_row(&[
+                            Some(withdrawal.id.to_string()),
+                            Some(timestamp(withdrawal.created_at)),
+                            Some(withdrawal.mint),
+                            Some(withdrawal.amount.to_string()),
+                            Some(withdrawal.status.as_str().to_string()),
+                            Some(withdrawal.destination),
+                            withdrawal.signature,
+                            withdrawal.token_mint,
+                            withdrawal.error,
+                            withdrawal.completed_at.map(timestamp),
+                        ])
+                        .await?;
+                }
+                writer.finish().await?
+            }
+        };
+
+        file.into_inner()
+            .sync_all()
+            .await
+            .context("Failed to sync export file")?;
+        Ok(rows)
+    }
+
+    /// 기한이 지난 파일 삭제 후 만료 처리
+    async fn cleanup_expired(&self) -> Result<()> {
+        let job_repo = ExportJobRepository::new(self.db.pool().clone());
+        for job in job_repo.get_expired(Utc::now()).await? {
+            if let Some(path) = &job.file_path
+                && let Err(e) = tokio::fs::remove_file(path).await
+                && e.kind() != std::io::ErrorKind::NotFound
+            {
+                eprintln!("[Export] Failed to delete {}: {}", path, e);
+                continue;
+            }
+            job_repo.mark_expired(job.id).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::cex::models::trade::Trade;
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn writes_escaped_csv_and_json_array() {
+        const COLUMNS: &[&str] = &["id", "note"];
+        let rows = [
+            vec![Some("1".to_string()), Some("plain".to_string())],
+            vec![Some("2".to_string()), Some("a,\"b\"".to_string())],
+            vec![Some("3".to_string()), None],
+        ];
+
+        let mut csv = ExportWriter::begin(Vec::new(), ExportFormat::Csv, COLUMNS).await.unwrap();
+        for row in &rows {
+            csv.write_row(row).await.unwrap();
+        }
+        let (bytes, count) = csv.finish().await.unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(String::from_utf8(bytes).unwrap(), "id,note\n1,plain\n2,\"a,\"\"b\"\"\"\n3,\n");
+
+        let mut json = ExportWriter::begin(Vec::new(), ExportFormat::Json, COLUMNS).await.unwrap();
+        for row in &rows {
+            json.write_row(row).await.unwrap();
+        }
+        let (bytes, _) = json.finish().await.unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            parsed,
+            serde_json::json!([
+                { "id": "1", "note": "plain" },
+                { "id": "2", "note": "a,\"b\"" },
+                { "id": "3", "note": null },
+            ])
+        );
+
+        // 행이 없어도 올바른 JSON
+        let empty = ExportWriter::begin(Vec::new(), ExportFormat::Json, COLUMNS).await.unwrap();
+        let (bytes, _) = empty.finish().await.unwrap();
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&bytes).unwrap(), serde_json::json!([]));
+    }
+
+    #[test]
+    fn self_trade_exports_both_sides_with_fee() {
+        let trade = Trade {
+            id: 7,
+            buy_order_id: 70,
+            sell_order_id: 71,
+            base_mint: "SOL".to_string(),
+            quote_mint: "USDT".to_string(),
+            price: Decimal::from(150),
+            amount: Decimal::from(2),
+            created_at: Utc::now(),
+        };
+
+        let mut fill = UserFill {
+            trade,
+            buyer_fee: Some(Decimal::new(2, 3)),
+            seller_fee: Some(Decimal::new(300, 3)),
+        };
+        let rows = trade_rows(&fill);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0][4].as_deref(), Some("buy"));
+        assert_eq!(rows[0][5].as_deref(), Some("70"));
+        assert_eq!(rows[0][9].as_deref(), Some("0.002"));
+        assert_eq!(rows[0][10].as_deref(), Some("SOL"));
+        assert_eq!(rows[1][4].as_deref(), Some("sell"));
+        assert_eq!(rows[1][5].as_deref(), Some("71"));
+        assert_eq!(rows[1][9].as_deref(), Some("0.300"));
+        assert_eq!(rows[1][10].as_deref(), Some("USDT"));
+        assert!(rows.iter().all(|row| row.len() == TRADE_COLUMNS.len()));
+
+        fill.buyer_fee = None;
+        assert_eq!(trade_rows(&fill).len(), 1);
+    }
+}
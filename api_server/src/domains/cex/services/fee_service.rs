@@ -2,11 +2,136 @@
 // 거래소 수수료 서비스
 // 역할: 거래 수수료 설정 조회 및 관리
 
-use crate::shared::database::{Database, FeeConfigRepository};
-use crate::domains::cex::models::fee::FeeConfig;
+use crate::shared::database::{Database, FeeConfigRepository, UserRepository};
+use crate::domains::cex::engine::fee_schedule::{FeeRule, FeeSchedule, FeeType};
+use crate::domains::cex::engine::runtime::HighPerformanceEngine;
+use crate::domains::cex::models::fee::{
+    CreateFeeConfigRequest, FeeConfig, FeeConfigCreate, FeeConfigHistory, UpdateFeeConfigRequest,
+};
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use sqlx::Row;
+use std::str::FromStr;
+
+/// 수수료율 상한 (10%)
+const MAX_FEE_RATE: Decimal = Decimal::from_parts(1, 0, 0, false, 1);
+
+/// 수수료율 최대 소수 자릿수 (DECIMAL(10, 6))
+const MAX_FEE_RATE_SCALE: u32 = 6;
+
+/// 수수료 등급 최대 길이 (VARCHAR(32))
+const MAX_TIER_LEN: usize = 32;
+
+/// 수수료 설정 변경 결과
+#[derive(Debug)]
+pub enum FeeConfigChange {
+    /// 저장 완료 (previous: 변경 전, 생성은 None)
+    Saved {
+        previous: Option<FeeConfig>,
+        config: FeeConfig,
+    },
+    /// 요청 값이 잘못됨
+    Invalid(String),
+    /// 수수료 설정이 없음
+    NotFound,
+    /// 이미 비활성화된 설정
+    Inactive,
+    /// 같은 범위에서 유형과 기간이 겹치는 활성 설정
+    Overlaps(FeeConfig),
+}
+
+/// 엔진 수수료 스케줄 반영 결과
+#[derive(Debug, Clone, Copy)]
+pub struct FeeScheduleReload {
+    /// 엔진이 적용한 스케줄 버전
+    pub version: u64,
+    /// 규칙 수 (종료된 설정 제외)
+    pub rules: usize,
+    /// 수수료 등급이 있는 사용자 수
+    pub tiered_users: usize,
+}
+
+/// 수수료율 검증 (0 ~ 0.1, 소수점 6자리까지)
+fn validate_fee_rate(fee_rate: Decimal) -> Result<(), String> {
+    if fee_rate < Decimal::ZERO || fee_rate > MAX_FEE_RATE {
+        return Err(format!("fee_rate must be between 0 and {}", MAX_FEE_RATE));
+    }
+    if fee_rate.normalize().scale() > MAX_FEE_RATE_SCALE {
+        return Err(format!("fee_rate has more than {} decimal places", MAX_FEE_RATE_SCALE));
+    }
+    Ok(())
+}
+
+/// 적용 기간 검증 (종료가 시작보다 뒤)
+fn validate_window(effective_from: DateTime<Utc>, effective_until: Option<DateTime<Utc>>) -> Result<(), String> {
+    if effective_until.is_some_and(|until| until <= effective_from) {
+        return Err("effective_until must be after effective_from".to_string());
+    }
+    Ok(())
+}
+
+/// 수수료 등급 검증 (영문 소문자, 숫자, _, -, 최대 32자)
+pub fn validate_tier(tier: &str) -> Result<(), String> {
+    if tier.is_empty() || tier.len() > MAX_TIER_LEN {
+        return Err(format!("tier must be 1 to {} characters", MAX_TIER_LEN));
+    }
+    if !tier.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-') {
+        return Err("tier may only contain lowercase letters, digits, '_' and '-'".to_string());
+    }
+    Ok(())
+}
+
+/// 생성 요청 검증 → 저장할 값
+fn validate_create(request: &CreateFeeConfigRequest, now: DateTime<Utc>) -> Result<FeeConfigCreate, String> {
+    let mint = |value: &Option<String>, field: &str| match value.as_deref().map(str::trim) {
+        Some("") => Err(format!("{} must not be empty", field)),
+        other => Ok(other.map(str::to_string)),
+    };
+    let base_mint = mint(&request.base_mint, "base_mint")?;
+    let quote_mint = mint(&request.quote_mint, "quote_mint")?;
+    if let Some(tier) = &request.tier {
+        validate_tier(tier)?;
+    }
+    let fee_type = FeeType::from_str(&request.fee_type).map_err(|e| e.to_string())?;
+    validate_fee_rate(request.fee_rate)?;
+    let effective_from = request.effective_from.unwrap_or(now);
+    validate_window(effective_from, request.effective_until)?;
+
+    Ok(FeeConfigCreate {
+        base_mint,
+        quote_mint,
+        fee_rate: request.fee_rate,
+        fee_type: fee_type.as_str().to_string(),
+        tier: request.tier.clone(),
+        effective_from,
+        effective_until: request.effective_until,
+    })
+}
+
+/// 수수료 설정 → 엔진 규칙
+fn to_rule(config: &FeeConfig) -> Result<FeeRule> {
+    Ok(FeeRule {
+        id: config.id,
+        base_mint: config.base_mint.clone(),
+        quote_mint: config.quote_mint.clone(),
+        tier: config.tier.clone(),
+        fee_type: FeeType::from_str(&config.fee_type)?,
+        fee_rate: config.fee_rate,
+        effective_from: config.effective_from,
+        effective_until: config.effective_until,
+    })
+}
+
+/// 같은 범위의 활성 설정 중 규칙과 겹치는 것 (자기 자신 제외)
+fn find_overlap(rule: &FeeRule, existing: Vec<FeeConfig>) -> Result<Option<FeeConfig>> {
+    for config in existing {
+        if config.id != rule.id && to_rule(&config)?.overlaps(rule) {
+            return Ok(Some(config));
+        }
+    }
+    Ok(None)
+}
 
 /// 거래소 수수료 서비스
 /// Exchange Fee Service
@@ -16,10 +141,13 @@ use sqlx::Row;
 /// - 기본 수수료 조회 (모든 거래쌍에 적용)
 /// - 활성 수수료 설정 목록 조회
 /// 
+/// - 수수료 설정 생성 / 수정 / 비활성화 (겹침 검사 + 변경 이력)
+/// - 엔진 수수료 스케줄 반영 (재시작 없이)
+/// 
 /// 사용처:
-/// - OrderService: 주문 생성 시 수수료 계산
-/// - Engine: 체결 시 수수료 계산
-/// - FeeConfigHandler: 수수료 설정 조회 API
+/// - UserStreamService: 체결 수수료 (엔진 스케줄)
+/// - PositionService, ExportService: 체결 내역 수수료
+/// - AdminHandler: 수수료 설정 관리 API
 #[derive(Clone)]
 pub struct FeeService {
     db: Database,
@@ -119,7 +247,7 @@ impl FeeService {
         // Query config where both base_mint and quote_mint are NULL
         let row = sqlx::query(
             r#"
-            SELECT id, base_mint, quote_mint, fee_rate, fee_type, is_active, created_at, updated_at
+            SELECT id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
             FROM fee_configs
             WHERE is_active = TRUE
               AND base_mint IS NULL
@@ -137,6 +265,9 @@ impl FeeService {
             quote_mint: r.get("quote_mint"),
             fee_rate: r.get("fee_rate"),
             fee_type: r.get("fee_type"),
+            tier: r.get("tier"),
+            effective_from: r.get("effective_from"),
+            effective_until: r.get("effective_until"),
             is_active: r.get("is_active"),
             created_at: r.get("created_at"),
             updated_at: r.get("updated_at"),
@@ -226,6 +357,207 @@ impl FeeService {
         Ok(fees)
     }
 
+    /// 수수료 설정 생성
+    /// Create fee config
+    ///
+    /// # 처리 과정
+    /// 1. 요청 검증 (거래쌍, 등급, 유형, 수수료율, 적용 기간)
+    /// 2. 변경 잠금 → 같은 범위에서 겹치는 활성 설정 확인
+    /// 3. 저장 + 변경 이력 (같은 트랜잭션)
+    ///
+    /// 엔진 반영은 호출하는 쪽에서 `reload_engine()`으로 합니다.
+    pub async fn create_fee_config(
+        &self,
+        request: &CreateFeeConfigRequest,
+        changed_by: u64,
+    ) -> Result<FeeConfigChange> {
+        let create = match validate_create(request, Utc::now()) {
+            Ok(create) => create,
+            Err(message) => return Ok(FeeConfigChange::Invalid(message)),
+        };
+        let rule = FeeRule {
+            id: 0,
+            base_mint: create.base_mint.clone(),
+            quote_mint: create.quote_mint.clone(),
+            tier: create.tier.clone(),
+            fee_type: FeeType::from_str(&create.fee_type)?,
+            fee_rate: create.fee_rate,
+            effective_from: create.effective_from,
+            effective_until: create.effective_until,
+        };
+
+        let mut tx = self.db.pool().begin().await
+            .context("Failed to begin transaction")?;
+        FeeConfigRepository::lock_changes(&mut tx).await?;
+
+        let existing = FeeConfigRepository::get_active_in_scope(
+            &mut tx,
+            create.base_mint.as_deref(),
+            create.quote_mint.as_deref(),
+            create.tier.as_deref(),
+        )
+        .await?;
+        if let Some(conflict) = find_overlap(&rule, existing)? {
+            return Ok(FeeConfigChange::Overlaps(conflict));
+        }
+
+        let config = FeeConfigRepository::insert(&mut tx, &create).await?;
+        FeeConfigRepository::insert_history(&mut tx, "create", None, &config, changed_by).await?;
+        tx.commit().await
+            .context("Failed to commit transaction")?;
+
+        Ok(FeeConfigChange::Saved { previous: None, config })
+    }
+
+    /// 수수료 설정 수정 (수수료율, 적용 기간)
+    /// Update fee config
+    ///
+    /// 비활성화된 설정은 수정할 수 없습니다 (Inactive).
+    pub async fn update_fee_config(
+        &self,
+        fee_config_id: u64,
+        request: &UpdateFeeConfigRequest,
+        changed_by: u64,
+    ) -> Result<FeeConfigChange> {
+        if let Err(message) = validate_fee_rate(request.fee_rate) {
+            return Ok(FeeConfigChange::Invalid(message));
+        }
+
+        let mut tx = self.db.pool().begin().await
+            .context("Failed to begin transaction")?;
+        FeeConfigRepository::lock_changes(&mut tx).await?;
+
+        let Some(previous) = FeeConfigRepository::get_by_id_for_update(&mut tx, fee_config_id).await? else {
+            return Ok(FeeConfigChange::NotFound);
+        };
+        if !previous.is_active {
+            return Ok(FeeConfigChange::Inactive);
+        }
+
+        let effective_from = request.effective_from.unwrap_or(previous.effective_from);
+        if let Err(message) = validate_window(effective_from, request.effective_until) {
+            return Ok(FeeConfigChange::Invalid(message));
+        }
+
+        let rule = FeeRule {
+            fee_rate: request.fee_rate,
+            effective_from,
+            effective_until: request.effective_until,
+            ..to_rule(&previous)?
+        };
+        let existing = FeeConfigRepository::get_active_in_scope(
+            &mut tx,
+            previous.base_mint.as_deref(),
+            previous.quote_mint.as_deref(),
+            previous.tier.as_deref(),
+        )
+        .await?;
+        if let Some(conflict) = find_overlap(&rule, existing)? {
+            return Ok(FeeConfigChange::Overlaps(conflict));
+        }
+
+        let config = FeeConfigRepository::update(
+            &mut tx,
+            fee_config_id,
+            request.fee_rate,
+            effective_from,
+            request.effective_until,
+        )
+        .await?;
+        FeeConfigRepository::insert_history(&mut tx, "update", Some(&previous), &config, changed_by).await?;
+        tx.commit().await
+            .context("Failed to commit transaction")?;
+
+        Ok(FeeConfigChange::Saved { previous: Some(previous), config })
+    }
+
+    /// 수수료 설정 비활성화 (삭제 대신, 이력 보존)
+    /// Deactivate fee config
+    pub async fn deactivate_fee_config(&self, fee_config_id: u64, changed_by: u64) -> Result<FeeConfigChange> {
+        let mut tx = self.db.pool().begin().await
+            .context("Failed to begin transaction")?;
+        FeeConfigRepository::lock_changes(&mut tx).await?;
+
+        let Some(previous) = FeeConfigRepository::get_by_id_for_update(&mut tx, fee_config_id).await? else {
+            return Ok(FeeConfigChange::NotFound);
+        };
+        if !previous.is_active {
+            return Ok(FeeConfigChange::Inactive);
+        }
+
+        let config = FeeConfigRepository::deactivate(&mut tx, fee_config_id).await?;
+        FeeConfigRepository::insert_history(&mut tx, "deactivate", Some(&previous), &config, changed_by).await?;
+        tx.commit().await
+            .context("Failed to commit transaction")?;
+
+        Ok(FeeConfigChange::Saved { previous: Some(previous), config })
+    }
+
+    /// 수수료 설정 변경 이력 (최신순)
+    /// Get fee config change history
+    ///
+    /// # Returns
+    /// * `Ok(None)` - 수수료 설정이 없음
+    pub async fn get_fee_config_history(&self, fee_config_id: u64) -> Result<Option<Vec<FeeConfigHistory>>> {
+        let fee_repo = FeeConfigRepository::new(self.db.pool().clone());
+        if fee_repo.get_by_id(fee_config_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        fee_repo.get_history(fee_config_id).await.map(Some)
+    }
+
+    /// 사용자 수수료 등급 변경 (None이면 해제)
+    /// Set user fee tier
+    ///
+    /// # Returns
+    /// 사용자가 있으면 true
+    pub async fn set_user_fee_tier(&self, user_id: u64, fee_tier: Option<&str>) -> Result<bool> {
+        UserRepository::new(self.db.pool().clone())
+            .set_fee_tier(user_id, fee_tier)
+            .await
+    }
+
+    /// DB에서 수수료 스케줄 구성 (활성 설정 중 종료되지 않은 것 + 사용자 등급)
+    /// Build fee schedule from database
+    ///
+    /// 아직 시작 전인 설정도 포함하므로 시작 시간이 되면 다시 반영하지 않아도 적용됩니다.
+    pub async fn load_schedule(&self) -> Result<FeeSchedule> {
+        let now = Utc::now();
+        let rules = self
+            .get_all_active_fees()
+            .await?
+            .iter()
+            .filter(|config| config.effective_until.is_none_or(|until| until > now))
+            .map(to_rule)
+            .collect::<Result<Vec<_>>>()?;
+        let user_tiers = UserRepository::new(self.db.pool().clone())
+            .list_fee_tiers()
+            .await?;
+
+        Ok(FeeSchedule::new(rules, user_tiers))
+    }
+
+    /// 엔진 수수료 스케줄 반영 (서버 시작 시, 수수료 설정 / 등급 변경 후)
+    /// Push the current fee schedule into the running engine
+    pub async fn reload_engine(
+        &self,
+        engine: &tokio::sync::Mutex<HighPerformanceEngine>,
+    ) -> Result<FeeScheduleReload> {
+        let schedule = self.load_schedule().await?;
+        let rules = schedule.rules().len();
+        let tiered_users = schedule.tiered_users();
+
+        let version = engine
+            .lock()
+            .await
+            .set_fee_schedule(schedule)
+            .await
+            .context("Failed to apply fee schedule in engine")?;
+
+        Ok(FeeScheduleReload { version, rules, tiered_users })
+    }
+
     /// 수수료 계산 헬퍼 메서드
     /// Calculate fee amount helper method
     /// 
@@ -252,3 +584,57 @@ impl FeeService {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn request(fee_rate: &str) -> CreateFeeConfigRequest {
+        CreateFeeConfigRequest {
+            base_mint: Some("SOL".to_string()),
+            quote_mint: Some("USDT".to_string()),
+            tier: None,
+            fee_rate: Decimal::from_str(fee_rate).unwrap(),
+            fee_type: "both".to_string(),
+            effective_from: None,
+            effective_until: None,
+        }
+    }
+
+    /// 테스트: 수수료율 상한 (10%)
+    #[test]
+    fn test_max_fee_rate_is_ten_percent() {
+        assert_eq!(MAX_FEE_RATE, Decimal::from_str("0.1").unwrap());
+    }
+
+    /// 테스트: 수수료 설정 생성 요청 검증 (수수료율 범위/자릿수, 종류, 자산, 등급, 적용 기간)
+    #[test]
+    fn test_validate_create() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+
+        let create = validate_create(&request("0.0008"), now).unwrap();
+        assert_eq!(create.effective_from, now);
+        assert_eq!(create.fee_type, "both");
+
+        assert!(validate_create(&request("0.2"), now).is_err());
+        assert!(validate_create(&request("-0.0001"), now).is_err());
+        assert!(validate_create(&request("0.0000001"), now).is_err());
+
+        let mut invalid = request("0.001");
+        invalid.fee_type = "spot".to_string();
+        assert!(validate_create(&invalid, now).is_err());
+
+        let mut invalid = request("0.001");
+        invalid.quote_mint = Some("  ".to_string());
+        assert!(validate_create(&invalid, now).is_err());
+
+        let mut invalid = request("0.001");
+        invalid.tier = Some("VIP 1".to_string());
+        assert!(validate_create(&invalid, now).is_err());
+
+        let mut invalid = request("0.001");
+        invalid.effective_from = Some(now);
+        invalid.effective_until = Some(now);
+        assert!(validate_create(&invalid, now).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::shared::database::{Database, LedgerRepository};
+use crate::domains::cex::models::ledger::{LedgerFilter, LedgerPosting};
+use crate::shared::utils::cursor::{Page, PageCursor};
+use anyhow::{Context, Result};
+
+/// 원장 서비스
+/// Ledger Service
+/// 
+/// 역할:
+/// - 사용자 계정(available, locked)의 원장 전기 조회
+/// 
+/// 특징:
+/// - 읽기 전용 서비스 (원장 기록은 DB Writer가 잔고 UPDATE와 같은 트랜잭션에서 수행)
+/// - 각 전기는 사유(trade, fee, deposit, ...)와 참조(체결 ID, 주문 ID, 트랜잭션 서명 등)를 가짐
+/// 
+/// # Examples
+/// ```
+/// let service = LedgerService::new(db);
+/// 
+/// // 특정 체결로 인한 내 잔고 변화
+/// let filter = LedgerFilter { reference: Some("123456".into()), ..Default::default() };
+/// let page = service.get_my_ledger(user_id, &filter, None, None).await?;
+/// ```
+#[derive(Clone)]
+pub struct LedgerService {
+    /// 데이터베이스 연결
+    /// Database connection
+    db: Database,
+}
+
+impl LedgerService {
+    /// 생성자
+    /// Constructor
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    /// 내 원장 전기 조회 (커서 페이지)
+    /// Get a page of ledger postings for a user
+    /// 
+    /// # Arguments
+    /// * `user_id` - 사용자 ID
+    /// * `filter` - 자산/사유/참조/기간 필터
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
+    /// * `limit` - 최대 조회 개수 (기본: 100, 최대: 1000)
+    /// 
+    /// # Returns
+    /// * `Ok(Page<LedgerPosting>)` - 전기 목록 (최신순) + 다음 페이지 커서
+    /// * `Err` - 데이터베이스 오류 시
+    pub async fn get_my_ledger(
+        &self,
+        user_id: u64,
+        filter: &LedgerFilter,
+        cursor: Option<PageCursor>,
+        limit: Option<i64>,
+    ) -> Result<Page<LedgerPosting>> {
+        let ledger_repo = LedgerRepository::new(self.db.pool().clone());
+
+        // 제한 설정: 기본 100, 최대 1000
+        let limit = limit.unwrap_or(100).clamp(1, 1000);
+
+        ledger_repo
+            .get_page(user_id, filter, cursor, limit)
+            .await
+            .context(format!("Failed to fetch ledger for user {}", user_id))
+    }
+}
@@ -0,0 +1,871 @@
+// =====================================================
+// MarketDataService - 공개 시세 (WebSocket 피드 원본)
+// =====================================================
+// 역할: 엔진 이벤트 버스를 구독하여 공개 시세 메시지를 만들고 브로드캐스트
+//
+// 채널:
+// - depth: L2 호가 스냅샷 + 증분 업데이트 (거래쌍별 시퀀스)
+// - l3: 주문 단위 스냅샷 + add/delete/execute (거래쌍별 시퀀스, 익명화된 주문 ID)
+// - trades: 체결
+// - ticker: 1초마다 24시간 통계 + 최우선 호가 (TickerService가 발행)
+// - candles: 캔들 갱신 (CandleService가 발행, 모든 주기)
+//
+// 일관성:
+// - 호가 상태 변경과 브로드캐스트는 같은 락 안에서 수행
+// - 구독자는 브로드캐스트 수신기를 먼저 만든 뒤 스냅샷을 받고,
+//   스냅샷 seq 이하의 증분 업데이트는 버림 → 누락/중복 없음
+// =====================================================
+
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::BuildHasher;
+use std::sync::Arc;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio::time::{sleep, Duration};
+use utoipa::ToSchema;
+
+use crate::domains::cex::engine::event_bus::SequencedEvent;
+use crate::domains::cex::engine::runtime::HighPerformanceEngine;
+use crate::domains::cex::engine::types::{EngineEvent, OrderEntry, TradingPair};
+use crate::domains::cex::models::candle::{Candle, CandleInterval};
+use crate::domains::cex::models::ticker::Ticker;
+
+/// 브로드캐스트 버퍼 크기 (느린 WebSocket 세션은 Lagged 후 스냅샷으로 재동기화)
+const BROADCAST_CAPACITY: usize = 8192;
+
+/// 이벤트 버스 재구독 대기 시간
+const RESUBSCRIBE_DELAY: Duration = Duration::from_millis(100);
+
+/// 시세 채널
+/// Market data channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketChannel {
+    /// L2 호가 (스냅샷 + 증분)
+    Depth,
+    /// L3 주문 단위 호가 (스냅샷 + 주문 이벤트)
+    L3,
+    /// 체결
+    Trades,
+    /// 24시간 ticker (1초마다)
+    Ticker,
+    /// 캔들 (모든 주기, 메시지의 `interval`로 구분)
+    Candles,
+}
+
+impl MarketChannel {
+    /// 스냅샷 + 시퀀스 증분 방식 채널인지 확인
+    pub fn is_sequenced(&self) -> bool {
+        matches!(self, MarketChannel::Depth | MarketChannel::L3)
+    }
+}
+
+/// 호가 가격 레벨
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PriceLevel {
+    /// 가격
+    pub price: Decimal,
+    /// 해당 가격의 남은 수량 합계
+    pub amount: Decimal,
+    /// 해당 가격의 주문 개수
+    pub order_count: usize,
+}
+
+/// L3 주문 (익명화된 주문 ID)
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct L3Order {
+    /// 익명화된 주문 ID (서버 프로세스 내에서만 일관됨)
+    pub order_id: u64,
+    /// 가격
+    #[schema(value_type = String, example = "100.0")]
+    pub price: Decimal,
+    /// 남은 수량
+    #[schema(value_type = String, example = "1.5")]
+    pub amount: Decimal,
+}
+
+/// 공개 시세 메시지 (WebSocket으로 전송되는 JSON)
+/// Public market data message
+///
+/// # JSON 형식
+/// ```json
+/// {"type":"depth_update","base_mint":"SOL","quote_mint":"USDT","seq":42,"side":"buy","price":"100","amount":"0","order_count":0}
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MarketDataMessage {
+    /// 전체 호가 스냅샷
+    ///
+    /// 이후 `seq`보다 큰 `depth_update`만 적용하면 됩니다.
+    DepthSnapshot {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        /// 스냅샷 시점의 호가 시퀀스
+        seq: u64,
+        /// 매수 호가 (높은 가격 순)
+        bids: Vec<PriceLevel>,
+        /// 매도 호가 (낮은 가격 순)
+        asks: Vec<PriceLevel>,
+    },
+
+    /// 호가 증분 업데이트
+    ///
+    /// `amount`는 변경 후 절대값이며 0이면 레벨 삭제입니다.
+    /// `seq`는 거래쌍별로 1씩 증가합니다 (건너뛰면 재구독 필요).
+    DepthUpdate {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        seq: u64,
+        /// "buy" or "sell"
+        side: String,
+        price: Decimal,
+        amount: Decimal,
+        order_count: usize,
+    },
+
+    /// L3 전체 주문 스냅샷
+    ///
+    /// 가격별로 큐 순서(시간 우선순위)대로 정렬되어 있습니다.
+    L3Snapshot {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        seq: u64,
+        /// 매수 주문 (높은 가격 순, 같은 가격은 큐 순서)
+        bids: Vec<L3Order>,
+        /// 매도 주문 (낮은 가격 순, 같은 가격은 큐 순서)
+        asks: Vec<L3Order>,
+    },
+
+    /// L3 주문 추가 (해당 가격 큐의 맨 뒤)
+    L3Add {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        seq: u64,
+        order_id: u64,
+        side: String,
+        price: Decimal,
+        amount: Decimal,
+    },
+
+    /// L3 주문 삭제 (취소)
+    L3Delete {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        seq: u64,
+        order_id: u64,
+        side: String,
+        price: Decimal,
+    },
+
+    /// L3 주문 체결 (메이커 주문 기준)
+    ///
+    /// `remaining_amount`가 0이면 오더북에서 제거됩니다.
+    L3Execute {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        seq: u64,
+        order_id: u64,
+        side: String,
+        price: Decimal,
+        /// 체결 수량
+        amount: Decimal,
+        remaining_amount: Decimal,
+    },
+
+    /// 체결
+    Trade {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        price: Decimal,
+        amount: Decimal,
+        /// 익명화한 매수 주문 ID (L3 order_id와 같은 값)
+        buy_order_id: u64,
+        /// 익명화한 매도 주문 ID (L3 order_id와 같은 값)
+        sell_order_id: u64,
+        /// 체결 시간 (밀리초)
+        timestamp: i64,
+    },
+
+    /// 캔들 갱신 (체결마다, 진행 중인 캔들의 현재 값)
+    Candle {
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        interval: CandleInterval,
+        /// 캔들 시작 시간 (밀리초)
+        open_time: i64,
+        /// 캔들 종료 시간 (밀리초, 미포함)
+        close_time: i64,
+        open: Decimal,
+        high: Decimal,
+        low: Decimal,
+        close: Decimal,
+        volume: Decimal,
+        quote_volume: Decimal,
+        trade_count: u64,
+    },
+
+    /// 24시간 ticker (1초마다)
+    Ticker(Ticker),
+}
+
+/// 브로드캐스트 프레임 (JSON은 한 번만 직렬화하여 모든 세션이 공유)
+#[derive(Debug)]
+pub struct MarketDataFrame {
+    /// 채널
+    pub channel: MarketChannel,
+    /// 거래쌍
+    pub trading_pair: TradingPair,
+    /// 채널 시퀀스 (depth/l3만 의미 있음, 재동기화 스냅샷도 같은 채널로 전송)
+    pub seq: u64,
+    /// 직렬화된 메시지
+    pub payload: String,
+}
+
+impl MarketDataFrame {
+    fn new(channel: MarketChannel, trading_pair: TradingPair, seq: u64, message: &MarketDataMessage) -> Self {
+        Self {
+            channel,
+            trading_pair,
+            seq,
+            payload: serde_json::to_string(message).unwrap_or_default(),
+        }
+    }
+}
+
+/// 거래쌍별 L3 주문 상태 (실제 주문 ID로 관리, 메시지 생성 시 익명화)
+#[derive(Debug, Default)]
+struct L3Book {
+    /// 매수 주문 큐 (가격 → [(주문 ID, 남은 수량)])
+    bids: BTreeMap<Decimal, VecDeque<(u64, Decimal)>>,
+    /// 매도 주문 큐 (가격 → [(주문 ID, 남은 수량)])
+    asks: BTreeMap<Decimal, VecDeque<(u64, Decimal)>>,
+    /// 주문 위치 (주문 ID → (방향, 가격))
+    index: HashMap<u64, (String, Decimal)>,
+    /// L3 시퀀스
+    seq: u64,
+}
+
+impl L3Book {
+    fn side_mut(&mut self, side: &str) -> &mut BTreeMap<Decimal, VecDeque<(u64, Decimal)>> {
+        if side == "buy" { &mut self.bids } else { &mut self.asks }
+    }
+
+    fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+        self.index.clear();
+    }
+
+    /// 큐 맨 뒤에 주문 추가
+    fn add(&mut self, order_id: u64, side: &str, price: Decimal, amount: Decimal) {
+        self.side_mut(side).entry(price).or_default().push_back((order_id, amount));
+        self.index.insert(order_id, (side.to_string(), price));
+    }
+
+    /// 주문 제거
+    ///
+    /// # Returns
+    /// (방향, 가격) - 오더북에 없으면 None
+    fn remove(&mut self, order_id: u64) -> Option<(String, Decimal)> {
+        let (side, price) = self.index.remove(&order_id)?;
+        let book = self.side_mut(&side);
+        if let Some(queue) = book.get_mut(&price) {
+            queue.retain(|(id, _)| *id != order_id);
+            if queue.is_empty() {
+                book.remove(&price);
+            }
+        }
+        Some((side, price))
+    }
+
+    /// 주문 수량 변경 (큐 위치 유지)
+    fn set_amount(&mut self, order_id: u64, amount: Decimal) -> Option<(String, Decimal)> {
+        let (side, price) = self.index.get(&order_id).cloned()?;
+        let entry = self
+            .side_mut(&side)
+            .get_mut(&price)?
+            .iter_mut()
+            .find(|(id, _)| *id == order_id)?;
+        entry.1 = amount;
+        Some((side, price))
+    }
+
+    fn amount(&self, order_id: u64) -> Option<Decimal> {
+        let (side, price) = self.index.get(&order_id)?;
+        let book = if side == "buy" { &self.bids } else { &self.asks };
+        book.get(price)?
+            .iter()
+            .find(|(id, _)| *id == order_id)
+            .map(|(_, amount)| *amount)
+    }
+}
+
+/// 거래쌍별 시세 상태
+#[derive(Debug, Default)]
+struct PairMarketState {
+    /// 매수 호가 (가격 → (수량, 주문 수))
+    bids: BTreeMap<Decimal, (Decimal, usize)>,
+    /// 매도 호가 (가격 → (수량, 주문 수))
+    asks: BTreeMap<Decimal, (Decimal, usize)>,
+    /// 호가 시퀀스
+    seq: u64,
+    /// 주문 단위 호가
+    l3: L3Book,
+}
+
+impl PairMarketState {
+    fn snapshot(&self, trading_pair: &TradingPair) -> MarketDataMessage {
+        let level = |(price, (amount, order_count)): (&Decimal, &(Decimal, usize))| PriceLevel {
+            price: *price,
+            amount: *amount,
+            order_count: *order_count,
+        };
+
+        MarketDataMessage::DepthSnapshot {
+            trading_pair: trading_pair.clone(),
+            seq: self.seq,
+            bids: self.bids.iter().rev().map(level).collect(),
+            asks: self.asks.iter().map(level).collect(),
+        }
+    }
+
+    fn top_of_book(&self) -> (Option<Decimal>, Option<Decimal>) {
+        (self.bids.keys().next_back().copied(), self.asks.keys().next().copied())
+    }
+}
+
+/// 전체 거래쌍 시세 상태 (이벤트 → 프레임 변환)
+#[derive(Debug, Default)]
+pub struct MarketBooks {
+    pairs: HashMap<TradingPair, PairMarketState>,
+    /// L3 주문 ID 익명화 키 (프로세스마다 랜덤)
+    anonymizer: RandomState,
+}
+
+impl MarketBooks {
+    /// 엔진 이벤트 적용
+    ///
+    /// # Returns
+    /// 브로드캐스트할 프레임 (시세와 무관한 이벤트면 비어 있음)
+    pub fn apply(&mut self, event: &SequencedEvent) -> Vec<MarketDataFrame> {
+        match &event.event {
+            EngineEvent::BookLevelChanged { trading_pair, side, price, total_amount, order_count } => {
+                let state = self.pairs.entry(trading_pair.clone()).or_default();
+                Self::set_level(state, side, *price, *total_amount, *order_count);
+                state.seq += 1;
+
+                let message = MarketDataMessage::DepthUpdate {
+                    trading_pair: trading_pair.clone(),
+                    seq: state.seq,
+                    side: side.clone(),
+                    price: *price,
+                    amount: *total_amount,
+                    order_count: *order_count,
+                };
+                vec![MarketDataFrame::new(MarketChannel::Depth, trading_pair.clone(), state.seq, &message)]
+            }
+            EngineEvent::BookOrderAdded { trading_pair, order_id, side, price, amount } => {
+                let anon_id = self.anonymize(*order_id);
+                let state = self.pairs.entry(trading_pair.clone()).or_default();
+                state.l3.add(*order_id, side, *price, *amount);
+                state.l3.seq += 1;
+
+                let message = MarketDataMessage::L3Add {
+                    trading_pair: trading_pair.clone(),
+                    seq: state.l3.seq,
+                    order_id: anon_id,
+                    side: side.clone(),
+                    price: *price,
+                    amount: *amount,
+                };
+                vec![MarketDataFrame::new(MarketChannel::L3, trading_pair.clone(), state.l3.seq, &message)]
+            }
+            EngineEvent::OrderCancelled { trading_pair, order_id, .. } => {
+                let anon_id = self.anonymize(*order_id);
+                let state = match self.pairs.get_mut(trading_pair) {
+                    Some(state) => state,
+                    None => return Vec::new(),
+                };
+                let (side, price) = match state.l3.remove(*order_id) {
+                    Some(position) => position,
+                    None => return Vec::new(),
+                };
+                state.l3.seq += 1;
+
+                let message = MarketDataMessage::L3Delete {
+                    trading_pair: trading_pair.clone(),
+                    seq: state.l3.seq,
+                    order_id: anon_id,
+                    side,
+                    price,
+                };
+                vec![MarketDataFrame::new(MarketChannel::L3, trading_pair.clone(), state.l3.seq, &message)]
+            }
+            EngineEvent::TradeExecuted { match_result, .. } => {
+                let trading_pair = TradingPair::new(
+                    match_result.base_mint.clone(),
+                    match_result.quote_mint.clone(),
+                );
+                let anon_ids = [
+                    self.anonymize(match_result.buy_order_id),
+                    self.anonymize(match_result.sell_order_id),
+                ];
+                let state = self.pairs.entry(trading_pair.clone()).or_default();
+
+                let message = MarketDataMessage::Trade {
+                    trading_pair: trading_pair.clone(),
+                    price: match_result.price,
+                    amount: match_result.amount,
+                    buy_order_id: anon_ids[0],
+                    sell_order_id: anon_ids[1],
+                    timestamp: event.timestamp,
+                };
+                let mut frames = vec![MarketDataFrame::new(MarketChannel::Trades, trading_pair.clone(), state.seq, &message)];
+
+                // 메이커 = 오더북에 있던 주문 (테이커는 체결 후에야 BookOrderAdded로 추가됨)
+                for (order_id, anon_id) in [match_result.buy_order_id, match_result.sell_order_id].into_iter().zip(anon_ids) {
+                    let amount = match state.l3.amount(order_id) {
+                        Some(amount) => amount,
+                        None => continue,
+                    };
+                    let remaining_amount = (amount - match_result.amount).max(Decimal::ZERO);
+                    let (side, price) = if remaining_amount.is_zero() {
+                        state.l3.remove(order_id)
+                    } else {
+                        state.l3.set_amount(order_id, remaining_amount)
+                    }
+                    .unwrap_or_default();
+                    state.l3.seq += 1;
+
+                    let message = MarketDataMessage::L3Execute {
+                        trading_pair: trading_pair.clone(),
+                        seq: state.l3.seq,
+                        order_id: anon_id,
+                        side,
+                        price,
+                        amount: match_result.amount,
+                        remaining_amount,
+                    };
+                    frames.push(MarketDataFrame::new(MarketChannel::L3, trading_pair.clone(), state.l3.seq, &message));
+                }
+                frames
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// 엔진 오더북 스냅샷으로 호가 재구성
+    ///
+    /// 최근 체결가는 유지하고 시퀀스는 계속 증가시킵니다 (기존 구독자가 역행하지 않도록).
+    ///
+    /// # Arguments
+    /// * `levels` - `HighPerformanceEngine::book_level_snapshot()` 결과
+    /// * `orders` - `HighPerformanceEngine::resting_orders()` 결과 (가격별 큐 순서)
+    ///
+    /// # Returns
+    /// 거래쌍별 depth/l3 스냅샷 프레임 (기존 구독자 재동기화용)
+    pub fn reset(&mut self, levels: &[EngineEvent], orders: &[OrderEntry]) -> Vec<MarketDataFrame> {
+        for state in self.pairs.values_mut() {
+            state.bids.clear();
+            state.asks.clear();
+            state.l3.clear();
+        }
+
+        for level in levels {
+            if let EngineEvent::BookLevelChanged { trading_pair, side, price, total_amount, order_count } = level {
+                let state = self.pairs.entry(trading_pair.clone()).or_default();
+                Self::set_level(state, side, *price, *total_amount, *order_count);
+            }
+        }
+        for order in orders {
+            if let Some(price) = order.price {
+                let trading_pair = TradingPair::new(order.base_mint.clone(), order.quote_mint.clone());
+                let state = self.pairs.entry(trading_pair).or_default();
+                state.l3.add(order.id, &order.order_type, price, order.remaining_amount);
+            }
+        }
+
+        let mut frames = Vec::new();
+        let pairs: Vec<TradingPair> = self.pairs.keys().cloned().collect();
+        for trading_pair in pairs {
+            let l3_snapshot = self.l3_snapshot(&trading_pair);
+            let state = self.pairs.get_mut(&trading_pair).expect("pair exists");
+            state.seq += 1;
+            state.l3.seq += 1;
+            let depth_snapshot = state.snapshot(&trading_pair);
+            frames.push(MarketDataFrame::new(MarketChannel::Depth, trading_pair.clone(), state.seq, &depth_snapshot));
+            let l3_seq = state.l3.seq;
+            let l3_snapshot = match l3_snapshot {
+                MarketDataMessage::L3Snapshot { trading_pair, bids, asks, .. } => {
+                    MarketDataMessage::L3Snapshot { trading_pair, seq: l3_seq, bids, asks }
+                }
+                other => other,
+            };
+            frames.push(MarketDataFrame::new(MarketChannel::L3, trading_pair, l3_seq, &l3_snapshot));
+        }
+        frames
+    }
+
+    /// 채널 스냅샷 (거래쌍이 없으면 빈 호가, seq 0)
+    ///
+    /// # Returns
+    /// `DepthSnapshot` 또는 `L3Snapshot` (스냅샷이 없는 채널이면 None)
+    pub fn snapshot(&self, channel: MarketChannel, trading_pair: &TradingPair) -> Option<MarketDataMessage> {
+        match channel {
+            MarketChannel::Depth => Some(match self.pairs.get(trading_pair) {
+                Some(state) => state.snapshot(trading_pair),
+                None => PairMarketState::default().snapshot(trading_pair),
+            }),
+            MarketChannel::L3 => Some(self.l3_snapshot(trading_pair)),
+            MarketChannel::Trades | MarketChannel::Ticker | MarketChannel::Candles => None,
+        }
+    }
+
+    /// 최우선 호가 (매수, 매도)
+    pub fn top_of_book(&self, trading_pair: &TradingPair) -> (Option<Decimal>, Option<Decimal>) {
+        self.pairs
+            .get(trading_pair)
+            .map(|state| state.top_of_book())
+            .unwrap_or_default()
+    }
+
+    /// 호가 또는 체결이 있었던 거래쌍
+    pub fn trading_pairs(&self) -> Vec<TradingPair> {
+        self.pairs.keys().cloned().collect()
+    }
+
+    fn l3_snapshot(&self, trading_pair: &TradingPair) -> MarketDataMessage {
+        let (seq, bids, asks) = match self.pairs.get(trading_pair) {
+            Some(state) => {
+                let orders = |levels: Vec<(&Decimal, &VecDeque<(u64, Decimal)>)>| -> Vec<L3Order> {
+                    levels
+                        .into_iter()
+                        .flat_map(|(price, queue)| {
+                            queue.iter().map(move |(order_id, amount)| L3Order {
+                                order_id: self.anonymize(*order_id),
+                                price: *price,
+                                amount: *amount,
+                            })
+                        })
+                        .collect()
+                };
+                (
+                    state.l3.seq,
+                    orders(state.l3.bids.iter().rev().collect()),
+                    orders(state.l3.asks.iter().collect()),
+                )
+            }
+            None => (0, Vec::new(), Vec::new()),
+        };
+
+        MarketDataMessage::L3Snapshot {
+            trading_pair: trading_pair.clone(),
+            seq,
+            bids,
+            asks,
+        }
+    }
+
+    /// 주문 ID 익명화 (같은 프로세스 안에서는 항상 같은 값)
+    fn anonymize(&self, order_id: u64) -> u64 {
+        self.anonymizer.hash_one(order_id)
+    }
+
+    fn set_level(state: &mut PairMarketState, side: &str, price: Decimal, amount: Decimal, order_count: usize) {
+        let book = if side == "buy" { &mut state.bids } else { &mut state.asks };
+        if amount.is_zero() || order_count == 0 {
+            book.remove(&price);
+        } else {
+            book.insert(price, (amount, order_count));
+        }
+    }
+}
+
+/// 공개 시세 서비스
+/// Public market data service
+///
+/// # 사용 흐름
+/// 1. 엔진 시작 후 `start()` 호출 (이벤트 버스 구독 + ticker 태스크)
+/// 2. WebSocket 세션은 `subscribe()`로 수신기를 먼저 만들고 `snapshot()` 조회
+/// 3. 세션은 수신한 프레임을 구독 채널/거래쌍으로 필터링하여 전송
+#[derive(Clone)]
+pub struct MarketDataService {
+    /// 체결 엔진 (이벤트 버스 + 오더북 스냅샷)
+    engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+    /// 시세 상태 (변경과 브로드캐스트를 같은 락 안에서 수행)
+    books: Arc<Mutex<MarketBooks>>,
+    /// 프레임 브로드캐스트
+    sender: broadcast::Sender<Arc<MarketDataFrame>>,
+}
+
+impl MarketDataService {
+    /// 새 MarketDataService 생성
+    ///
+    /// # Arguments
+    /// * `engine` - 체결 엔진
+    pub fn new(engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>) -> Self {
+        let (sender, _) = broadcast::channel(BROADCAST_CAPACITY);
+        Self {
+            engine,
+            books: Arc::new(Mutex::new(MarketBooks::default())),
+            sender,
+        }
+    }
+
+    /// 백그라운드 태스크 시작 (엔진 시작 이후 호출)
+    ///
+    /// # 처리 과정
+    /// 1. 이벤트 버스 구독 → 엔진 오더북 스냅샷으로 호가/L3 초기화
+    /// 2. 이벤트를 프레임으로 변환하여 브로드캐스트
+    /// 3. 이벤트 버스에서 해제되면 (느린 구독자) 재구독 + 스냅샷 재브로드캐스트
+    pub fn start(&self) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let mut subscription = {
+                    let engine = service.engine.lock().await;
+                    let subscription = engine.event_bus().subscribe("market_data");
+                    let levels = engine.book_level_snapshot();
+                    let orders = engine.resting_orders();
+                    let mut books = service.books.lock();
+                    for frame in books.reset(&levels, &orders) {
+                        let _ = service.sender.send(Arc::new(frame));
+                    }
+                    subscription
+                };
+
+                while let Some(event) = subscription.recv().await {
+                    let mut books = service.books.lock();
+                    for frame in books.apply(&event) {
+                        let _ = service.sender.send(Arc::new(frame));
+                    }
+                }
+
+                eprintln!("[Market Data] Event subscription closed, resubscribing");
+                sleep(RESUBSCRIBE_DELAY).await;
+            }
+        });
+
+        eprintln!("[Market Data] Started (depth, l3, trades)");
+    }
+
+    /// 프레임 수신기 생성
+    ///
+    /// 스냅샷보다 먼저 만들어야 스냅샷 이후 업데이트를 놓치지 않습니다.
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<MarketDataFrame>> {
+        self.sender.subscribe()
+    }
+
+    /// 채널 스냅샷 조회 (depth, l3)
+    ///
+    /// # Returns
+    /// (스냅샷 seq, 직렬화된 스냅샷 메시지) - 스냅샷이 없는 채널이면 None
+    pub fn snapshot(&self, channel: MarketChannel, trading_pair: &TradingPair) -> Option<(u64, String)> {
+        let message = self.books.lock().snapshot(channel, trading_pair)?;
+        let seq = match &message {
+            MarketDataMessage::DepthSnapshot { seq, .. } | MarketDataMessage::L3Snapshot { seq, .. } => *seq,
+            _ => 0,
+        };
+        Some((seq, serde_json::to_string(&message).unwrap_or_default()))
+    }
+
+    /// 캔들 갱신 브로드캐스트 (`candles` 채널)
+    ///
+    /// # Arguments
+    /// * `candle` - 갱신된 캔들 (진행 중인 값)
+    pub fn publish_candle(&self, candle: &Candle) {
+        if self.sender.receiver_count() == 0 {
+            return;
+        }
+
+        let trading_pair = TradingPair::new(candle.base_mint.clone(), candle.quote_mint.clone());
+        let message = MarketDataMessage::Candle {
+            trading_pair: trading_pair.clone(),
+            interval: candle.interval,
+            open_time: candle.open_time.timestamp_millis(),
+            close_time: candle.close_time().timestamp_millis(),
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+            quote_volume: candle.quote_volume,
+            trade_count: candle.trade_count,
+        };
+        let _ = self.sender.send(Arc::new(MarketDataFrame::new(MarketChannel::Candles, trading_pair, 0, &message)));
+    }
+
+    /// ticker 브로드캐스트 (`ticker` 채널)
+    ///
+    /// # Arguments
+    /// * `tickers` - 거래쌍별 24시간 ticker
+    pub fn publish_tickers(&self, tickers: Vec<Ticker>) {
+        if self.sender.receiver_count() == 0 {
+            return;
+        }
+
+        for ticker in tickers {
+            let trading_pair = TradingPair::new(ticker.base_mint.clone(), ticker.quote_mint.clone());
+            let message = MarketDataMessage::Ticker(ticker);
+            let _ = self.sender.send(Arc::new(MarketDataFrame::new(MarketChannel::Ticker, trading_pair, 0, &message)));
+        }
+    }
+
+    /// 최우선 호가 조회
+    ///
+    /// # Returns
+    /// (최우선 매수 호가, 최우선 매도 호가)
+    pub fn top_of_book(&self, trading_pair: &TradingPair) -> (Option<Decimal>, Option<Decimal>) {
+        self.books.lock().top_of_book(trading_pair)
+    }
+
+    /// 호가 또는 체결이 있었던 거래쌍
+    pub fn trading_pairs(&self) -> Vec<TradingPair> {
+        self.books.lock().trading_pairs()
+    }
+
+    /// L3 주문 단위 호가 조회 (REST 스냅샷용)
+    ///
+    /// # Returns
+    /// (L3 seq, 매수 주문, 매도 주문) - seq 이후는 `/ws/market`의 l3 채널로 이어받을 수 있음
+    pub fn l3_book(&self, trading_pair: &TradingPair) -> (u64, Vec<L3Order>, Vec<L3Order>) {
+        match self.books.lock().l3_snapshot(trading_pair) {
+            MarketDataMessage::L3Snapshot { seq, bids, asks, .. } => (seq, bids, asks),
+            _ => (0, Vec::new(), Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::cex::engine::types::{MatchResult, TradeFees};
+
+    fn pair() -> TradingPair {
+        TradingPair::new("SOL".to_string(), "USDT".to_string())
+    }
+
+    fn sequenced(seq: u64, event: EngineEvent) -> SequencedEvent {
+        SequencedEvent { seq, timestamp: 1_700_000_000_000, event }
+    }
+
+    fn level(side: &str, price: i64, amount: i64, order_count: usize) -> EngineEvent {
+        EngineEvent::BookLevelChanged {
+            trading_pair: pair(),
+            side: side.to_string(),
+            price: Decimal::new(price, 0),
+            total_amount: Decimal::new(amount, 0),
+            order_count,
+        }
+    }
+
+    fn added(order_id: u64, side: &str, price: i64, amount: i64) -> EngineEvent {
+        EngineEvent::BookOrderAdded {
+            trading_pair: pair(),
+            order_id,
+            side: side.to_string(),
+            price: Decimal::new(price, 0),
+            amount: Decimal::new(amount, 0),
+        }
+    }
+
+    fn trade(buy_order_id: u64, sell_order_id: u64, price: i64, amount: i64) -> EngineEvent {
+        EngineEvent::TradeExecuted {
+            match_result: MatchResult {
+                buy_order_id,
+                sell_order_id,
+                buyer_id: 20,
+                seller_id: 10,
+                price: Decimal::new(price, 0),
+                amount: Decimal::new(amount, 0),
+                base_mint: "SOL".to_string(),
+                quote_mint: "USDT".to_string(),
+            },
+            buyer_id: 20,
+            seller_id: 10,
+            fees: TradeFees::default(),
+        }
+    }
+
+    #[test]
+    fn depth_updates_are_sequenced_after_snapshot() {
+        let mut books = MarketBooks::default();
+        let frames = books.reset(&[level("buy", 99, 5, 1), level("sell", 101, 3, 2)], &[]);
+        assert_eq!(frames.len(), 2);
+        assert!(frames[0].payload.contains("\"type\":\"depth_snapshot\""));
+        assert_eq!(frames[0].seq, 1);
+
+        let update = books.apply(&sequenced(1, level("buy", 100, 2, 1))).remove(0);
+        assert_eq!(update.channel, MarketChannel::Depth);
+        assert_eq!(update.seq, 2);
+        books.apply(&sequenced(2, level("buy", 99, 0, 0)));
+
+        match books.snapshot(MarketChannel::Depth, &pair()).unwrap() {
+            MarketDataMessage::DepthSnapshot { seq, bids, asks, .. } => {
+                assert_eq!(seq, 3);
+                assert_eq!(bids.len(), 1);
+                assert_eq!(bids[0].price, Decimal::new(100, 0));
+                assert_eq!(asks[0].order_count, 2);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn trades_keep_top_of_book_without_advancing_depth_seq() {
+        let mut books = MarketBooks::default();
+        books.apply(&sequenced(1, level("sell", 101, 3, 1)));
+
+        let frames = books.apply(&sequenced(2, trade(2, 1, 101, 1)));
+        assert_eq!(frames[0].channel, MarketChannel::Trades);
+        let trade: serde_json::Value = serde_json::from_str(&frames[0].payload).unwrap();
+        assert_eq!(trade["type"], "trade");
+        assert_eq!(trade["buy_order_id"], books.anonymize(2));
+        assert_eq!(trade["sell_order_id"], books.anonymize(1));
+
+        match books.snapshot(MarketChannel::Depth, &pair()) {
+            Some(MarketDataMessage::DepthSnapshot { seq, .. }) => assert_eq!(seq, 1),
+            other => panic!("unexpected message: {:?}", other),
+        }
+        assert_eq!(books.top_of_book(&pair()), (None, Some(Decimal::from(101))));
+        assert_eq!(books.trading_pairs(), vec![pair()]);
+    }
+
+    #[test]
+    fn l3_tracks_queue_position_through_executions_and_cancels() {
+        let mut books = MarketBooks::default();
+        books.apply(&sequenced(1, added(1, "sell", 101, 2)));
+        books.apply(&sequenced(2, added(2, "sell", 101, 3)));
+        books.apply(&sequenced(3, added(3, "buy", 99, 1)));
+
+        // 메이커(1)만 L3 체결, 테이커(10)는 오더북에 없으므로 무시
+        let frames = books.apply(&sequenced(4, trade(10, 1, 101, 2)));
+        assert_eq!(frames.len(), 2);
+        let execute: serde_json::Value = serde_json::from_str(&frames[1].payload).unwrap();
+        assert_eq!(execute["type"], "l3_execute");
+        assert_eq!(execute["seq"], 4);
+        assert_eq!(execute["remaining_amount"], "0");
+        assert_ne!(execute["order_id"], 1);
+
+        let frames = books.apply(&sequenced(5, EngineEvent::OrderCancelled {
+            order_id: 3,
+            user_id: 20,
+            trading_pair: pair(),
+        }));
+        assert!(frames[0].payload.contains("\"type\":\"l3_delete\""));
+
+        match books.snapshot(MarketChannel::L3, &pair()).unwrap() {
+            MarketDataMessage::L3Snapshot { seq, bids, asks, .. } => {
+                assert_eq!(seq, 5);
+                assert!(bids.is_empty());
+                assert_eq!(asks.len(), 1);
+                assert_eq!(asks[0].amount, Decimal::new(3, 0));
+                assert_eq!(asks[0].order_id, books.anonymize(2));
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+}
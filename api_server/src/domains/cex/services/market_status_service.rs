@@ -0,0 +1,163 @@
+use crate::shared::database::{Database, MarketStatusRepository};
+use crate::domains::cex::models::market_status::{MarketStatus, MarketStatusEntry};
+use anyhow::{Result, bail};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// =====================================================
+// MarketStatusService
+// =====================================================
+// 역할: 거래쌍 상태 (open / cancel_only / halted) 관리와 주문 접수 검사
+//
+// 처리 방식:
+// - 서버 시작 시 market_statuses를 메모리에 로드 (주문마다 DB 조회 없음)
+// - 관리자 변경은 DB 저장 후 메모리에 반영
+// - 주문 생성은 open일 때만, 주문 취소는 halted가 아닐 때만 허용
+// =====================================================
+
+/// (base_mint, quote_mint)
+type PairKey = (String, String);
+
+/// 거래쌍 상태 서비스
+/// Market status service
+#[derive(Clone)]
+pub struct MarketStatusService {
+    db: Database,
+    statuses: Arc<RwLock<HashMap<PairKey, MarketStatusEntry>>>,
+}
+
+impl MarketStatusService {
+    pub fn new(db: Database) -> Self {
+        Self {
+            db,
+            statuses: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// DB에서 거래쌍 상태 로드 (서버 시작 시)
+    /// Load market statuses from database
+    pub async fn load(&self) -> Result<usize> {
+        let entries = MarketStatusRepository::new(self.db.pool().clone()).get_all().await?;
+        let count = entries.len();
+
+        let mut statuses = self.statuses.write();
+        statuses.clear();
+        for entry in entries {
+            statuses.insert((entry.base_mint.clone(), entry.quote_mint.clone()), entry);
+        }
+        Ok(count)
+    }
+
+    /// 거래쌍 상태 (설정이 없으면 open)
+    /// Get market status
+    pub fn status(&self, base_mint: &str, quote_mint: &str) -> MarketStatus {
+        self.statuses
+            .read()
+            .get(&(base_mint.to_string(), quote_mint.to_string()))
+            .map(|entry| entry.status)
+            .unwrap_or_default()
+    }
+
+    /// 설정된 모든 거래쌍 상태
+    /// List configured market statuses
+    pub fn list(&self) -> Vec<MarketStatusEntry> {
+        let mut entries: Vec<MarketStatusEntry> = self.statuses.read().values().cloned().collect();
+        entries.sort_by(|a, b| (&a.base_mint, &a.quote_mint).cmp(&(&b.base_mint, &b.quote_mint)));
+        entries
+    }
+
+    /// 거래쌍 상태 변경
+    /// Set market status
+    ///
+    /// # Returns
+    /// * `(이전 상태, 저장된 상태)`
+    pub async fn set(
+        &self,
+        base_mint: &str,
+        quote_mint: &str,
+        status: MarketStatus,
+        reason: Option<&str>,
+        updated_by: u64,
+    ) -> Result<(MarketStatus, MarketStatusEntry)> {
+        let previous = self.status(base_mint, quote_mint);
+        let entry = MarketStatusRepository::new(self.db.pool().clone())
+            .upsert(base_mint, quote_mint, status, reason, updated_by)
+            .await?;
+
+        self.statuses
+            .write()
+            .insert((base_mint.to_string(), quote_mint.to_string()), entry.clone());
+        eprintln!(
+            "[MarketStatus] {}/{}: {} → {} (by user {})",
+            base_mint, quote_mint, previous.as_str(), status.as_str(), updated_by
+        );
+        Ok((previous, entry))
+    }
+
+    /// 새 주문 접수 검사
+    /// Check that the market accepts new orders
+    pub fn check_order(&self, base_mint: &str, quote_mint: &str) -> Result<()> {
+        let status = self.status(base_mint, quote_mint);
+        if !status.accepts_orders() {
+            bail!("Market {}/{} is {}: new orders are not accepted", base_mint, quote_mint, status.as_str());
+        }
+        Ok(())
+    }
+
+    /// 주문 취소 검사
+    /// Check that the market accepts cancels
+    pub fn check_cancel(&self, base_mint: &str, quote_mint: &str) -> Result<()> {
+        let status = self.status(base_mint, quote_mint);
+        if !status.accepts_cancels() {
+            bail!("Market {}/{} is halted: cancels are not accepted", base_mint, quote_mint);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn service_with(status: MarketStatus) -> MarketStatusService {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .unwrap();
+        let service = MarketStatusService::new(Database::noop(pool));
+        service.statuses.write().insert(
+            ("SOL".to_string(), "USDT".to_string()),
+            MarketStatusEntry {
+                base_mint: "SOL".to_string(),
+                quote_mint: "USDT".to_string(),
+                status,
+                reason: None,
+                updated_by: None,
+                updated_at: Utc::now(),
+            },
+        );
+        service
+    }
+
+    #[tokio::test]
+    async fn test_unconfigured_market_is_open() {
+        let service = service_with(MarketStatus::Halted);
+        assert_eq!(service.status("BTC", "USDT"), MarketStatus::Open);
+        assert!(service.check_order("BTC", "USDT").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_only_blocks_orders_but_not_cancels() {
+        let service = service_with(MarketStatus::CancelOnly);
+        assert!(service.check_order("SOL", "USDT").is_err());
+        assert!(service.check_cancel("SOL", "USDT").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_halted_blocks_orders_and_cancels() {
+        let service = service_with(MarketStatus::Halted);
+        assert!(service.check_order("SOL", "USDT").is_err());
+        assert!(service.check_cancel("SOL", "USDT").is_err());
+    }
+}
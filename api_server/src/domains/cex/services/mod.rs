@@ -6,6 +6,19 @@ pub mod fee_service;
 pub mod order_service;
 pub mod trade_service;
 pub mod position_service;
+pub mod market_data_service;
+pub mod user_stream_service;
+pub mod candle_service;
+pub mod ticker_service;
+pub mod portfolio_service;
+pub mod export_service;
+pub mod deposit_service;
+pub mod withdrawal_service;
+pub mod ledger_service;
+pub mod reconciliation_service;
+pub mod transfer_service;
+pub mod sub_account_service;
+pub mod market_status_service;
 pub mod state;
 
 pub use balance_service::*;
@@ -13,5 +26,18 @@ pub use fee_service::*;
 pub use order_service::*;
 pub use trade_service::*;
 pub use position_service::*;
+pub use market_data_service::*;
+pub use user_stream_service::*;
+pub use candle_service::*;
+pub use ticker_service::*;
+pub use portfolio_service::*;
+pub use export_service::*;
+pub use deposit_service::*;
+pub use withdrawal_service::*;
+pub use ledger_service::*;
+pub use reconciliation_service::*;
+pub use transfer_service::*;
+pub use sub_account_service::*;
+pub use market_status_service::*;
 pub use state::*;
 
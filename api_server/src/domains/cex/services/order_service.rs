@@ -1,8 +1,10 @@
 use std::sync::Arc;
 use crate::shared::database::{Database, OrderRepository};
 use crate::shared::utils::id_generator::OrderIdGenerator;
-use crate::domains::cex::models::order::{Order, CreateOrderRequest};
+use crate::shared::utils::cursor::{Page, PageCursor};
+use crate::domains::cex::models::order::{Order, CreateOrderRequest, OrderFilter};
 use crate::domains::cex::engine::{Engine, TradingPair, OrderEntry, entry_to_order, runtime::HighPerformanceEngine};
+use crate::domains::cex::services::market_status_service::MarketStatusService;
 use anyhow::{Context, Result, bail};
 use rust_decimal::Decimal;
 use chrono::Utc;
@@ -29,7 +31,7 @@ use chrono::Utc;
 /// 
 /// # Examples
 /// ```
-/// let service = OrderService::new(db, engine);
+/// let service = OrderService::new(db, engine, market_status_service);
 /// 
 /// // 주문 생성
 /// let order = service.create_order(user_id, request).await?;
@@ -49,6 +51,10 @@ pub struct OrderService {
     /// 엔진은 하나만 존재하므로 구체 타입을 직접 사용합니다.
     /// Wrapper 없이 동일한 인스턴스를 공유합니다.
     engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+
+    /// 거래쌍 상태 (cancel_only / halted 거래쌍 주문 거부)
+    /// Market status (rejects orders on cancel-only / halted markets)
+    market_status_service: MarketStatusService,
 }
 
 impl OrderService {
@@ -58,6 +64,7 @@ impl OrderService {
     /// # Arguments
     /// * `db` - 데이터베이스 연결
     /// * `engine` - 체결 엔진 (trait 객체)
+    /// * `market_status_service` - 거래쌍 상태
     /// 
     /// # Returns
     /// OrderService 인스턴스
@@ -65,10 +72,14 @@ impl OrderService {
     /// # Examples
     /// ```
     /// let engine = Arc::new(SimpleEngine::new(...));
-    /// let service = OrderService::new(db, engine);
+    /// let service = OrderService::new(db, engine, market_status_service);
     /// ```
-    pub fn new(db: Database, engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>) -> Self {
-        Self { db, engine }
+    pub fn new(
+        db: Database,
+        engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+        market_status_service: MarketStatusService,
+    ) -> Self {
+        Self { db, engine, market_status_service }
     }
 
     /// 주문 생성
@@ -121,6 +132,10 @@ impl OrderService {
         // 1. 주문 유효성 검증
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         self.validate_order_request(&request)?;
+        self.market_status_service.check_order(
+            &request.base_mint,
+            request.quote_mint.as_deref().unwrap_or("USDT"),
+        )?;
 
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         // 2. 필요한 잔고 계산 및 확인
@@ -156,7 +171,7 @@ impl OrderService {
         let order_id = OrderIdGenerator::next();
 
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-        // 4. 엔진에 주문 즉시 제출 (블로킹 없음!)
+        // 4. 엔진에 주문 제출 (WAL 반영 후 응답)
         // 주의: 잔고 잠금은 process_submit_order에서 처리됨 (중복 방지)
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         // ID 생성기로 생성한 ID 사용
@@ -176,17 +191,11 @@ impl OrderService {
             created_at: Utc::now(),
         };
         
-        // 엔진에 제출 (비동기 처리, 백그라운드에서 처리)
-        // 실제 거래소와 동일하게 주문을 즉시 반환하고 백그라운드에서 처리
+        // 엔진에 제출 후 WAL 내구성 확보까지 대기 (WAL_DURABILITY 정책)
+        // 엔진 락은 전송까지만 잡고, fsync 대기는 락 밖에서 (group commit으로 묶임)
         // 엔진이 내부적으로 WAL 기록 + DB 동기화 처리
-        // 주의: DB에 이미 주문이 있으므로, 엔진의 DB Writer는 InsertOrder를 보내지 않음
-        let engine_clone = self.engine.clone();
-        tokio::spawn(async move {
-            let engine_guard = engine_clone.lock().await;
-            if let Err(e) = engine_guard.submit_order(order_entry).await {
-                eprintln!("[Order Service] Failed to submit order to engine (async): {}", e);
-            }
-        });
+        let durable = self.engine.lock().await.submit_order_durable(order_entry)?;
+        durable.await?;
 
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         // 6. Order 객체 반환
@@ -287,6 +296,7 @@ impl OrderService {
             }
             _ => bail!("Invalid order status: {}", order.status),
         }
+        self.market_status_service.check_cancel(&order.base_mint, &order.quote_mint)?;
 
         // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
         // 4. 엔진에 취소 요청
@@ -346,39 +356,33 @@ impl OrderService {
         Ok(order)
     }
 
-    /// 사용자의 모든 주문 조회
-    /// Get all orders for user
+    /// 사용자의 주문 조회 (커서 페이지)
+    /// Get a page of orders for user
     /// 
     /// # Arguments
     /// * `user_id` - 사용자 ID
-    /// * `status` - 주문 상태 필터 (None이면 전체)
-    /// * `limit` - 최대 조회 개수
-    /// * `offset` - 페이지네이션 오프셋
+    /// * `filter` - 거래쌍/유형/방식/상태/기간 필터
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
+    /// * `limit` - 최대 조회 개수 (기본: 50, 최대: 1000)
     /// 
     /// # Returns
-    /// * `Ok(Vec<Order>)` - 주문 목록 (최신순)
+    /// * `Ok(Page<Order>)` - 주문 목록 (최신순) + 다음 페이지 커서
     pub async fn get_my_orders(
         &self,
         user_id: u64,
-        status: Option<&str>,
+        filter: &OrderFilter,
+        cursor: Option<PageCursor>,
         limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<Order>> {
+    ) -> Result<Page<Order>> {
         let order_repo = OrderRepository::new(self.db.pool().clone());
 
-        let orders = if let Some(status) = status {
-            order_repo
-                .get_by_user_and_status(user_id, status, limit, offset)
-                .await
-                .context("Failed to fetch user orders from database")?
-        } else {
-            order_repo
-                .get_all_by_user(user_id, limit, offset)
-                .await
-                .context("Failed to fetch user orders from database")?
-        };
+        // 제한 설정: 기본 50, 최대 1000
+        let limit = limit.unwrap_or(50).clamp(1, 1000);
 
-        Ok(orders)
+        order_repo
+            .get_page_by_user(user_id, filter, cursor, limit)
+            .await
+            .context("Failed to fetch user orders from database")
     }
 
     /// 오더북 조회 (호가창)
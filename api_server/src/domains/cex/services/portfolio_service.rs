@@ -0,0 +1,369 @@
+use crate::shared::database::{Database, PortfolioSnapshotRepository, UserBalanceRepository};
+use crate::domains::cex::models::balance::UserBalance;
+use crate::domains::cex::models::portfolio::{EquitySnapshot, PortfolioAsset, PortfolioValuation};
+use crate::domains::cex::models::ticker::Ticker;
+use crate::domains::cex::services::TickerService;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::Duration;
+use tokio::time::sleep;
+
+// =====================================================
+// PortfolioService
+// =====================================================
+// 역할: 사용자의 모든 CEX 잔고를 기준 통화로 평가 + 일별 자산 기록
+//
+// 가격:
+// - TickerService의 거래쌍별 최우선 호가 / 24시간 최근 체결가 (DB 조회 없음)
+// - 직접 거래쌍이 없으면 최대 MAX_ROUTE_HOPS개 거래쌍을 거쳐 환산
+//
+// 일별 기록:
+// - 매일 00:00 UTC에 잔고가 있는 모든 사용자를 SNAPSHOT_QUOTE_MINT로 평가해서 저장
+// - 서버 시작 시에도 한 번 실행 (같은 날짜는 처음 기록 유지)
+// - 자산별 잔고/평가액도 함께 저장 (balance_snapshots 내보내기)
+// =====================================================
+
+/// 일별 기록 기준 통화
+const SNAPSHOT_QUOTE_MINT: &str = "USDT";
+
+/// 환산 경로 최대 거래쌍 수
+const MAX_ROUTE_HOPS: usize = 3;
+
+/// 일별 기록 조회 기본/최대 개수
+const DEFAULT_HISTORY_LIMIT: i64 = 365;
+const MAX_HISTORY_LIMIT: i64 = 3650;
+
+/// 기록 실패 시 재시도 간격
+const SNAPSHOT_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// 시작 직후 기록 전 대기 (ticker가 최근 체결가를 재구성할 시간)
+const SNAPSHOT_STARTUP_DELAY: Duration = Duration::from_secs(30);
+
+/// 거래쌍 가격 그래프 (자산 → 이웃 자산, 환율)
+/// Price graph built from market tickers
+#[derive(Debug, Default)]
+pub struct PriceGraph {
+    edges: HashMap<String, Vec<(String, Decimal)>>,
+}
+
+impl PriceGraph {
+    /// ticker 목록으로 그래프 생성 (가격이 없는 거래쌍 제외)
+    pub fn from_tickers(tickers: &[Ticker]) -> Self {
+        let mut graph = Self::default();
+        for ticker in tickers {
+            if let Some(price) = Self::mark_price(ticker) {
+                graph.add_pair(&ticker.base_mint, &ticker.quote_mint, price);
+            }
+        }
+        graph
+    }
+
+    /// 거래쌍 평가 가격
+    ///
+    /// # Returns
+    /// 최우선 매수/매도 호가 중간값, 호가가 한쪽뿐이면 24시간 최근 체결가
+    pub fn mark_price(ticker: &Ticker) -> Option<Decimal> {
+        match (ticker.best_bid, ticker.best_ask) {
+            (Some(bid), Some(ask)) => Some((bid + ask) / Decimal::from(2)),
+            _ => ticker.last_price,
+        }
+        .filter(|price| *price > Decimal::ZERO)
+    }
+
+    /// 거래쌍 추가 (양방향: base → quote = price, quote → base = 1 / price)
+    pub fn add_pair(&mut self, base_mint: &str, quote_mint: &str, price: Decimal) {
+        if price <= Decimal::ZERO {
+            return;
+        }
+        self.edges
+            .entry(base_mint.to_string())
+            .or_default()
+            .push((quote_mint.to_string(), price));
+        self.edges
+            .entry(quote_mint.to_string())
+            .or_default()
+            .push((base_mint.to_string(), Decimal::ONE / price));
+    }
+
+    /// 자산 1개의 기준 통화 가격 (거래쌍 수가 가장 적은 경로)
+    ///
+    /// # Returns
+    /// (가격, 경로) - 경로가 없거나 MAX_ROUTE_HOPS보다 길면 None
+    pub fn route(&self, from: &str, to: &str) -> Option<(Decimal, Vec<String>)> {
+        if from == to {
+            return Some((Decimal::ONE, vec![from.to_string()]));
+        }
+
+        // BFS (자산 → (이전 자산, 이전 자산 기준 환율))
+        let mut previous: HashMap<&str, (&str, Decimal)> = HashMap::new();
+        let mut queue: VecDeque<(&str, usize)> = VecDeque::from([(from, 0)]);
+        while let Some((mint, hops)) = queue.pop_front() {
+            if hops == MAX_ROUTE_HOPS {
+                continue;
+            }
+            for (next, rate) in self.edges.get(mint).into_iter().flatten() {
+                if next == from || previous.contains_key(next.as_str()) {
+                    continue;
+                }
+                previous.insert(next, (mint, *rate));
+                if next == to {
+                    return Some(self.unwind(&previous, from, to));
+                }
+                queue.push_back((next, hops + 1));
+            }
+        }
+        None
+    }
+
+    fn unwind(&self, previous: &HashMap<&str, (&str, Decimal)>, from: &str, to: &str) -> (Decimal, Vec<String>) {
+        let mut price = Decimal::ONE;
+        let mut route = vec![to.to_string()];
+        let mut mint = to;
+        while mint != from {
+            let (prev, rate) = previous[mint];
+            price *= rate;
+            route.push(prev.to_string());
+            mint = prev;
+        }
+        route.reverse();
+        (price, route)
+    }
+}
+
+/// 잔고 평가
+///
+/// # Arguments
+/// * `balances` - 한 사용자의 잔고 (수량 0인 자산 제외)
+/// * `graph` - 거래쌍 가격 그래프
+/// * `quote_mint` - 기준 통화
+pub fn value_balances(
+    balances: &[UserBalance],
+    graph: &PriceGraph,
+    quote_mint: &str,
+    valued_at: DateTime<Utc>,
+) -> PortfolioValuation {
+    let mut assets: Vec<PortfolioAsset> = balances
+        .iter()
+        .filter(|b| !(b.available + b.locked).is_zero())
+        .map(|b| {
+            let balance = b.available + b.locked;
+            let (price, route) = match graph.route(&b.mint_address, quote_mint) {
+                Some((price, route)) => (Some(price), route),
+                None => (None, Vec::new()),
+            };
+            PortfolioAsset {
+                mint: b.mint_address.clone(),
+                balance,
+                available: b.available,
+                locked: b.locked,
+                price,
+                value: price.map(|price| price * balance),
+                route,
+            }
+        })
+        .collect();
+
+    // 평가액 큰 순 (평가 불가 자산은 마지막, 같으면 이름순)
+    assets.sort_by(|a, b| b.value.cmp(&a.value).then_with(|| a.mint.cmp(&b.mint)));
+
+    PortfolioValuation {
+        quote_mint: quote_mint.to_string(),
+        total_value: assets.iter().filter_map(|a| a.value).sum(),
+        assets,
+        valued_at,
+    }
+}
+
+/// 포트폴리오 서비스
+/// Portfolio Service
+#[derive(Clone)]
+pub struct PortfolioService {
+    /// 데이터베이스 연결
+    db: Database,
+    /// 거래쌍 가격 (최우선 호가, 24시간 최근 체결가)
+    ticker_service: TickerService,
+}
+
+impl PortfolioService {
+    /// 새 PortfolioService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    /// * `ticker_service` - 거래쌍 가격 조회
+    pub fn new(db: Database, ticker_service: TickerService) -> Self {
+        Self { db, ticker_service }
+    }
+
+    /// 일별 기록 백그라운드 태스크 시작 (ticker 시작 이후 호출)
+    ///
+    /// # 처리 과정
+    /// 1. SNAPSHOT_STARTUP_DELAY 후 한 번 기록 (오늘 기록이 없으면 채움)
+    /// 2. 다음 00:00 UTC까지 대기 후 기록, 반복
+    /// 3. 실패하면 SNAPSHOT_RETRY_DELAY 후 재시도
+    pub fn start(&self) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            sleep(SNAPSHOT_STARTUP_DELAY).await;
+            loop {
+                let delay = match service.snapshot_all().await {
+                    Ok(count) => {
+                        eprintln!("[Portfolio] Saved {} equity snapshots", count);
+                        until_next_snapshot(Utc::now())
+                    }
+                    Err(e) => {
+                        eprintln!("[Portfolio] Snapshot failed: {:#}", e);
+                        SNAPSHOT_RETRY_DELAY
+                    }
+                };
+                sleep(delay).await;
+            }
+        });
+
+        eprintln!("[Portfolio] Started (daily equity snapshot at 00:00 UTC)");
+    }
+
+    /// 사용자 포트폴리오 평가
+    /// Value all balances of a user
+    ///
+    /// # Arguments
+    /// * `user_id` - 사용자 ID
+    /// * `quote_mint` - 기준 통화 (예: "USDT")
+    pub async fn get_portfolio(&self, user_id: u64, quote_mint: &str) -> Result<PortfolioValuation> {
+        let balance_repo = UserBalanceRepository::new(self.db.pool().clone());
+        let balances = balance_repo
+            .get_all_by_user(user_id)
+            .await
+            .context("Failed to fetch user balances")?;
+
+        let graph = PriceGraph::from_tickers(&self.ticker_service.get_tickers());
+        Ok(value_balances(&balances, &graph, quote_mint, Utc::now()))
+    }
+
+    /// 사용자 일별 자산 기록 조회 (날짜 오름차순)
+    /// Get equity history of a user
+    ///
+    /// # Arguments
+    /// * `from`, `to` - 날짜 범위 (포함)
+    /// * `limit` - 최대 개수 (기본 365, 최대 3650)
+    pub async fn get_history(
+        &self,
+        user_id: u64,
+        quote_mint: &str,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        limit: Option<i64>,
+    ) -> Result<Vec<EquitySnapshot>> {
+        let limit = limit.unwrap_or(DEFAULT_HISTORY_LIMIT).clamp(1, MAX_HISTORY_LIMIT);
+        let snapshot_repo = PortfolioSnapshotRepository::new(self.db.pool().clone());
+        snapshot_repo
+            .get_range(user_id, quote_mint, from, to, limit)
+            .await
+    }
+
+    /// 잔고가 있는 모든 사용자의 오늘 자산 기록
+    /// Snapshot equity of every user with balances
+    ///
+    /// # Returns
+    /// 새로 저장된 기록 수 (오늘 이미 기록된 사용자 제외)
+    pub async fn snapshot_all(&self) -> Result<u64> {
+        let balance_repo = UserBalanceRepository::new(self.db.pool().clone());
+        let balances = balance_repo
+            .get_all_balances()
+            .await
+            .context("Failed to fetch balances")?;
+
+        let mut by_user: BTreeMap<u64, Vec<UserBalance>> = BTreeMap::new();
+        for balance in balances {
+            by_user.entry(balance.user_id).or_default().push(balance);
+        }
+
+        let graph = PriceGraph::from_tickers(&self.ticker_service.get_tickers());
+        let now = Utc::now();
+        let snapshots: Vec<(u64, EquitySnapshot, Vec<PortfolioAsset>)> = by_user
+            .into_iter()
+            .map(|(user_id, balances)| {
+                let valuation = value_balances(&balances, &graph, SNAPSHOT_QUOTE_MINT, now);
+                (
+                    user_id,
+                    EquitySnapshot {
+                        snapshot_date: now.date_naive(),
+                        quote_mint: SNAPSHOT_QUOTE_MINT.to_string(),
+                        total_value: valuation.total_value,
+                        created_at: now,
+                    },
+                    valuation.assets,
+                )
+            })
+            .collect();
+
+        let snapshot_repo = PortfolioSnapshotRepository::new(self.db.pool().clone());
+        snapshot_repo.insert_many(&snapshots).await
+    }
+}
+
+/// 다음 00:00 UTC까지 남은 시간
+fn until_next_snapshot(now: DateTime<Utc>) -> Duration {
+    let next = (now.date_naive() + ChronoDuration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .map(|time| time.and_utc())
+        .unwrap_or(now + ChronoDuration::days(1));
+    (next - now).to_std().unwrap_or(Duration::from_secs(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balance(mint: &str, available: i64, locked: i64) -> UserBalance {
+        UserBalance {
+            id: 0,
+            user_id: 1,
+            mint_address: mint.to_string(),
+            available: Decimal::from(available),
+            locked: Decimal::from(locked),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn routes_through_intermediate_and_inverse_pairs() {
+        let mut graph = PriceGraph::default();
+        graph.add_pair("SOL", "USDT", Decimal::from(150));
+        graph.add_pair("BONK", "SOL", Decimal::new(2, 7));
+
+        // BONK → SOL → USDT
+        let (price, route) = graph.route("BONK", "USDT").unwrap();
+        assert_eq!(price, Decimal::new(3, 5));
+        assert_eq!(route, vec!["BONK", "SOL", "USDT"]);
+
+        // 역방향: USDT를 SOL로 평가
+        let (price, route) = graph.route("USDT", "SOL").unwrap();
+        assert_eq!(price, Decimal::ONE / Decimal::from(150));
+        assert_eq!(route, vec!["USDT", "SOL"]);
+
+        assert!(graph.route("ETH", "USDT").is_none());
+    }
+
+    #[test]
+    fn unpriced_assets_are_listed_last_and_excluded_from_total() {
+        let mut graph = PriceGraph::default();
+        graph.add_pair("SOL", "USDT", Decimal::from(150));
+
+        let balances = vec![
+            balance("DOGE", 5, 0),
+            balance("ETH", 0, 0),
+            balance("SOL", 1, 1),
+            balance("USDT", 100, 30),
+        ];
+        let valuation = value_balances(&balances, &graph, "USDT", Utc::now());
+
+        assert_eq!(valuation.total_value, Decimal::from(430));
+        let mints: Vec<&str> = valuation.assets.iter().map(|a| a.mint.as_str()).collect();
+        assert_eq!(mints, vec!["SOL", "USDT", "DOGE"]);
+        assert_eq!(valuation.assets[2].value, None);
+        assert!(valuation.assets[2].route.is_empty());
+    }
+}
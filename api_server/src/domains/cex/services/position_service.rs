@@ -1,58 +1,307 @@
-use crate::shared::database::{Database, TradeRepository, UserBalanceRepository};
-use crate::domains::cex::models::position::{AssetPosition, TradeSummary};
+use crate::shared::database::{Database, PositionRepository, TradeRepository, UserBalanceRepository};
+use crate::domains::cex::models::position::{
+    AssetPosition, CostBasisMethod, PositionLot, PositionLotsResponse, PositionStats,
+    RealizedPnlEntry, TradeSummary,
+};
+use crate::domains::cex::models::trade::Trade;
+use crate::domains::cex::services::FeeService;
 use anyhow::{Context, Result};
 use rust_decimal::Decimal;
+use std::collections::{BTreeSet, HashMap};
+
+/// 금액 자릿수 (DB 컬럼 DECIMAL(30, 9)과 같게 맞춰 저장 전후 값이 같도록)
+const AMOUNT_SCALE: u32 = 9;
+
+/// 증분 반영 시 한 번에 읽는 체결 수
+const FILL_PAGE_SIZE: i64 = 1000;
+
+/// 실현 손익 조회 기본/최대 개수
+const DEFAULT_REALIZED_LIMIT: i64 = 50;
+const MAX_REALIZED_LIMIT: i64 = 1000;
+
+/// 포지션 lot 장부 (사용자 한 명의 자산 하나)
+/// Lot ledger of one user's asset
+///
+/// 역할:
+/// - 매수 체결 → 원가 lot 추가 (price × amount + 수수료)
+/// - 매도 체결 → 원가 계산 방식에 따라 lot 차감, 실현 손익 기록
+/// - 저장 후 변경된 lot / 새 실현 손익만 꺼내서 저장 (`take_changes`)
+///
+/// 수수료: 체결 금액(quote_mint) × 거래쌍 수수료율
+/// - 매수 수수료는 lot 원가에 포함, 매도 수수료는 매도 대금에서 차감
+///
+/// 원가가 없는 수량 (입금 등으로 받은 자산을 매도한 경우):
+/// - lot이 부족한 만큼은 실현 손익 계산에서 제외 (매도 횟수/수수료만 집계)
+#[derive(Debug, Clone)]
+pub struct PositionLedger {
+    method: CostBasisMethod,
+    stats: PositionStats,
+    /// 오래된 순 (남은 수량 0인 lot은 저장 전까지 유지)
+    lots: Vec<PositionLot>,
+    /// 저장 후 변경된 기존 lot ID
+    changed_lot_ids: BTreeSet<u64>,
+    /// 아직 저장하지 않은 실현 손익
+    new_realized: Vec<RealizedPnlEntry>,
+}
+
+impl PositionLedger {
+    /// 저장된 상태로 장부 생성
+    ///
+    /// # Arguments
+    /// * `lots` - 남은 lot (오래된 순)
+    pub fn new(method: CostBasisMethod, stats: PositionStats, lots: Vec<PositionLot>) -> Self {
+        Self {
+            method,
+            stats,
+            lots,
+            changed_lot_ids: BTreeSet::new(),
+            new_realized: Vec::new(),
+        }
+    }
+
+    pub fn method(&self) -> CostBasisMethod {
+        self.method
+    }
+
+    pub fn stats(&self) -> &PositionStats {
+        &self.stats
+    }
+
+    /// 남은 lot (오래된 순)
+    pub fn open_lots(&self) -> Vec<PositionLot> {
+        self.lots
+            .iter()
+            .filter(|lot| lot.remaining_amount > Decimal::ZERO)
+            .cloned()
+            .collect()
+    }
+
+    /// 남은 lot 수량 합계
+    pub fn open_amount(&self) -> Decimal {
+        self.lots.iter().map(|lot| lot.remaining_amount).sum()
+    }
+
+    /// 남은 lot 원가 합계
+    pub fn cost_basis(&self) -> Decimal {
+        self.lots.iter().map(|lot| lot.remaining_cost).sum()
+    }
+
+    /// 체결 반영
+    ///
+    /// # Arguments
+    /// * `trade` - 체결 (`last_trade_id` 이후 체결만 전달)
+    /// * `is_buyer` / `is_seller` - 사용자 쪽 (자기 체결이면 둘 다 true, 매수 먼저 반영)
+    /// * `fee_rate` - 거래쌍 수수료율
+    pub fn apply_fill(&mut self, trade: &Trade, is_buyer: bool, is_seller: bool, fee_rate: Decimal) {
+        let quote_amount = trade.price * trade.amount;
+        let fee = (quote_amount * fee_rate).round_dp(AMOUNT_SCALE);
+
+        if is_buyer {
+            self.buy(trade, quote_amount, fee);
+        }
+        if is_seller {
+            self.sell(trade, quote_amount, fee);
+        }
+
+        self.stats.last_trade_id = self.stats.last_trade_id.max(trade.id);
+    }
+
+    fn buy(&mut self, trade: &Trade, quote_amount: Decimal, fee: Decimal) {
+        let cost = (quote_amount + fee).round_dp(AMOUNT_SCALE);
+
+        self.lots.push(PositionLot {
+            lot_id: 0,
+            trade_id: trade.id,
+            opened_at: trade.created_at,
+            amount: trade.amount,
+            cost,
+            remaining_amount: trade.amount,
+            remaining_cost: cost,
+        });
+
+        self.stats.total_buy_trades += 1;
+        self.stats.total_bought_amount += trade.amount;
+        self.stats.total_bought_cost += quote_amount.round_dp(AMOUNT_SCALE);
+        self.stats.total_fees += fee;
+    }
+
+    fn sell(&mut self, trade: &Trade, quote_amount: Decimal, fee: Decimal) {
+        self.stats.total_sell_trades += 1;
+        self.stats.total_fees += fee;
+
+        let open_amount = self.open_amount();
+        let covered = trade.amount.min(open_amount);
+        if covered <= Decimal::ZERO || trade.amount <= Decimal::ZERO {
+            return;
+        }
+
+        let cost_basis = match self.method {
+            CostBasisMethod::Fifo => self.take_in_order(covered, false),
+            CostBasisMethod::Lifo => self.take_in_order(covered, true),
+            CostBasisMethod::AverageCost => self.take_pro_rata(covered, open_amount),
+        };
+
+        // lot이 덮는 수량 비율만큼의 대금/수수료
+        let (proceeds, fee) = if covered == trade.amount {
+            (quote_amount - fee, fee)
+        } else {
+            let ratio = covered / trade.amount;
+            ((quote_amount - fee) * ratio, fee * ratio)
+        };
+        let proceeds = proceeds.round_dp(AMOUNT_SCALE);
+        let realized_pnl = proceeds - cost_basis;
+
+        self.stats.realized_pnl += realized_pnl;
+        self.new_realized.push(RealizedPnlEntry {
+            trade_id: trade.id,
+            closed_at: trade.created_at,
+            amount: covered,
+            proceeds,
+            cost_basis,
+            fee: fee.round_dp(AMOUNT_SCALE),
+            realized_pnl,
+        });
+    }
+
+    /// FIFO/LIFO 차감 (오래된 순 또는 최신 순으로 lot을 비움)
+    fn take_in_order(&mut self, amount: Decimal, newest_first: bool) -> Decimal {
+        let mut indices: Vec<usize> = (0..self.lots.len()).collect();
+        if newest_first {
+            indices.reverse();
+        }
+
+        let mut remaining = amount;
+        let mut cost = Decimal::ZERO;
+        for index in indices {
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+            let take = remaining.min(self.lots[index].remaining_amount);
+            if take <= Decimal::ZERO {
+                continue;
+            }
+            cost += self.take_from_lot(index, take);
+            remaining -= take;
+        }
+        cost
+    }
+
+    /// 평균 원가 차감 (모든 lot을 같은 비율로 줄여 평균 단가 유지)
+    fn take_pro_rata(&mut self, amount: Decimal, open_amount: Decimal) -> Decimal {
+        let open_indices: Vec<usize> = (0..self.lots.len())
+            .filter(|&i| self.lots[i].remaining_amount > Decimal::ZERO)
+            .collect();
+
+        let mut remaining = amount;
+        let mut cost = Decimal::ZERO;
+        for (n, &index) in open_indices.iter().enumerate() {
+            let lot_amount = self.lots[index].remaining_amount;
+            // 반올림 오차는 마지막 lot에서 맞춤
+            let take = if n + 1 == open_indices.len() {
+                remaining.min(lot_amount)
+            } else {
+                (lot_amount * amount / open_amount).round_dp(AMOUNT_SCALE).min(remaining)
+            };
+            if take <= Decimal::ZERO {
+                continue;
+            }
+            cost += self.take_from_lot(index, take);
+            remaining -= take;
+        }
+        cost
+    }
+
+    /// lot 하나에서 수량 차감
+    ///
+    /// # Returns
+    /// 차감한 수량의 원가
+    fn take_from_lot(&mut self, index: usize, amount: Decimal) -> Decimal {
+        let lot = &mut self.lots[index];
+        let cost = if amount >= lot.remaining_amount {
+            lot.remaining_cost
+        } else {
+            (lot.remaining_cost * amount / lot.remaining_amount).round_dp(AMOUNT_SCALE)
+        };
+
+        lot.remaining_amount -= amount.min(lot.remaining_amount);
+        lot.remaining_cost -= cost;
+        if lot.lot_id != 0 {
+            self.changed_lot_ids.insert(lot.lot_id);
+        }
+        cost
+    }
+
+    /// 저장할 변경분 꺼내기
+    ///
+    /// # Returns
+    /// (새 lot, 변경된 기존 lot, 새 실현 손익)
+    fn take_changes(&mut self) -> (Vec<PositionLot>, Vec<PositionLot>, Vec<RealizedPnlEntry>) {
+        let new_lots = self.lots.iter().filter(|lot| lot.lot_id == 0).cloned().collect();
+        let changed_lots = self
+            .lots
+            .iter()
+            .filter(|lot| self.changed_lot_ids.contains(&lot.lot_id))
+            .cloned()
+            .collect();
+        self.changed_lot_ids.clear();
+        (new_lots, changed_lots, std::mem::take(&mut self.new_realized))
+    }
+}
 
 /// 거래소 포지션 서비스
 /// Exchange Position Service
-/// 
+///
 /// 역할:
 /// - 사용자의 특정 자산 포지션 정보 계산 (평균 매수가, 손익, 수익률 등)
-/// - 거래 내역 기반 통계 계산
+/// - 매수 체결별 원가 lot과 매도 체결별 실현 손익 관리 (FIFO / LIFO / 평균 원가)
 /// - 현재 시장 가격 기반 평가액 계산
-/// 
+///
 /// 포지션 계산 로직:
-/// 1. 사용자의 매수 거래 내역을 조회하여 평균 매수가 계산
+/// 1. 저장된 lot 이후의 새 체결만 장부에 반영 (positions.last_trade_id 기준 증분)
 /// 2. 현재 보유 수량 조회 (user_balances 테이블)
 /// 3. 최근 체결가를 현재 시장 가격으로 사용
-/// 4. 미실현 손익 = (현재 시장 가격 - 평균 매수가) × 보유 수량
-/// 5. 수익률 = (미실현 손익 / 총 매수 금액) × 100
+/// 4. 미실현 손익 = 현재 시장 가격 × 남은 lot 수량 - 남은 lot 원가
+/// 5. 수익률 = (미실현 손익 / 남은 lot 원가) × 100
+///
+/// 동시성:
+/// - 포지션 행을 FOR UPDATE로 잠근 트랜잭션에서 반영 → 동시 조회 시에도 체결이 한 번만 반영
 #[derive(Clone)]
 pub struct PositionService {
     db: Database,
+    fee_service: FeeService,
 }
 
 impl PositionService {
     /// 생성자
     /// Constructor
-    /// 
+    ///
     /// # Arguments
     /// * `db` - 데이터베이스 연결
-    /// 
+    /// * `fee_service` - 거래쌍 수수료율 조회 (lot 원가/매도 대금에 반영)
+    ///
     /// # Returns
     /// PositionService 인스턴스
-    pub fn new(db: Database) -> Self {
-        Self { db }
+    pub fn new(db: Database, fee_service: FeeService) -> Self {
+        Self { db, fee_service }
     }
 
     /// 사용자의 특정 자산 포지션 정보 조회
     /// Get position information for user's specific asset
-    /// 
+    ///
     /// # Arguments
     /// * `user_id` - 사용자 ID
     /// * `mint` - 자산 식별자 (예: "SOL", "USDT")
-    /// 
+    ///
     /// # Returns
     /// * `Ok(Some(AssetPosition))` - 포지션 정보 (자산을 보유한 경우)
-    /// * `Ok(None)` - 포지션 정보 없음 (자산을 보유하지 않거나 매수 거래가 없는 경우)
+    /// * `Ok(None)` - 포지션 정보 없음 (자산을 보유하지 않은 경우)
     /// * `Err` - 데이터베이스 오류 시
-    /// 
+    ///
     /// # 계산 로직
     /// 1. 사용자의 현재 잔고 조회 (available + locked)
-    /// 2. 사용자의 매수 거래 통계 조회 (평균 매수가, 총 매수 수량, 총 매수 금액)
-    /// 3. 사용자의 매도 거래 통계 조회 (매도 횟수, 실현 손익)
-    /// 4. 최근 체결가 조회 (현재 시장 가격)
-    /// 5. 미실현 손익 및 수익률 계산
+    /// 2. 새 체결을 lot 장부에 반영
+    /// 3. 최근 체결가 조회 (현재 시장 가격)
+    /// 4. 남은 lot 기준 평균 매수가, 미실현 손익 및 수익률 계산
     pub async fn get_position(
         &self,
         user_id: u64,
@@ -72,96 +321,41 @@ impl PositionService {
             return Ok(None);
         };
 
-        // 2. 매수 거래 통계 조회 (평균 매수가 계산용)
-        let trade_repo = TradeRepository::new(self.db.pool().clone());
-        let buy_stats = trade_repo
-            .get_buy_statistics(user_id, mint)
-            .await
-            .context("Failed to fetch buy statistics")?;
-
-        // 매수 거래가 없으면 포지션 정보 없음 (보유 수량이 있어도 매수 내역이 없으면 계산 불가)
-        let (total_bought_amount, total_bought_cost, average_entry_price) = match buy_stats {
-            Some((amount, cost, avg_price)) => (amount, cost, Some(avg_price)),
-            None => {
-                // 매수 거래가 없지만 잔고가 있는 경우 (예: 입금만 하고 거래 안 함)
-                // 기본값으로 반환
-                return Ok(Some(AssetPosition {
-                    mint: mint.to_string(),
-                    current_balance,
-                    available,
-                    locked,
-                    average_entry_price: None,
-                    total_bought_amount: Decimal::ZERO,
-                    total_bought_cost: Decimal::ZERO,
-                    current_market_price: None,
-                    current_value: None,
-                    unrealized_pnl: None,
-                    unrealized_pnl_percent: None,
-                    trade_summary: TradeSummary {
-                        total_buy_trades: 0,
-                        total_sell_trades: 0,
-                        realized_pnl: Decimal::ZERO,
-                    },
-                }));
-            }
-        };
+        // 2. lot 장부 갱신
+        let ledger = self.sync_ledger(user_id, mint, None).await?;
+        let stats = ledger.stats();
+        let open_amount = ledger.open_amount();
+        let cost_basis = ledger.cost_basis();
 
-        // 3. 매도 거래 통계 조회
-        let (total_sell_trades, _total_sold_amount, total_sold_value) = trade_repo
-            .get_sell_statistics(user_id, mint)
-            .await
-            .context("Failed to fetch sell statistics")?;
-
-        // 4. 매수 거래 횟수 조회
-        let total_buy_trades = trade_repo
-            .get_buy_trade_count(user_id, mint)
-            .await
-            .context("Failed to fetch buy trade count")?;
+        // 매수 lot이 없으면 (입금만 하고 거래 안 함 등) 평균 매수가/미실현 손익 계산 불가
+        let average_entry_price = if open_amount > Decimal::ZERO {
+            Some(cost_basis / open_amount)
+        } else {
+            None
+        };
 
-        // 5. 최근 체결가 조회 (현재 시장 가격)
+        // 3. 최근 체결가 조회 (현재 시장 가격)
+        let trade_repo = TradeRepository::new(self.db.pool().clone());
         let current_market_price = trade_repo
             .get_latest_price(mint)
             .await
             .context("Failed to fetch latest price")?;
 
-        // 6. 손익 계산
-        let (current_value, unrealized_pnl, unrealized_pnl_percent) = if let Some(market_price) = current_market_price {
-            // 현재 평가액 = 현재 시장 가격 × 현재 보유 수량
-            let value = market_price * current_balance;
-            
-            // 미실현 손익 = 현재 평가액 - (평균 매수가 × 현재 보유 수량)
-            let avg_price = average_entry_price.unwrap();
-            let pnl = value - (avg_price * current_balance);
-            
-            // 수익률 = (현재 가격 - 평균 매수가) / 평균 매수가 × 100
-            // 이렇게 하면 초기 입금과 관계없이 정확한 수익률 계산 가능
-            // 예: 평균 매수가 $100, 현재가 $110 → 수익률 = (110-100)/100 × 100 = 10%
-            let pnl_percent = if !avg_price.is_zero() {
-                Some(((market_price - avg_price) / avg_price) * Decimal::from(100))
-            } else {
-                None
-            };
-
-            (Some(value), Some(pnl), pnl_percent)
-        } else {
-            // 시장 가격 정보가 없으면 계산 불가
-            (None, None, None)
-        };
-
-        // 7. 실현 손익 계산
-        // 실현 손익 = 매도 금액 - (평균 매수가 × 매도 수량)
-        // 단순화: 매도 금액에서 평균 매수가 기준 매도 수량 금액을 뺌
-        // 주의: 정확한 실현 손익 계산을 위해서는 각 매도 거래의 매수가를 추적해야 하지만,
-        // 현재는 평균 매수가 기준으로 계산
-        let realized_pnl = if !total_sold_value.is_zero() && average_entry_price.is_some() {
-            // 매도 수량에 대한 평균 매수가 기준 원가를 계산
-            // 하지만 정확한 매도 수량을 알 수 없으므로, 매도 금액에서 추정 원가를 뺌
-            // 단순화: 매도 금액 - (평균 매수가 × 매도 수량)
-            // 하지만 매도 수량을 정확히 알 수 없으므로, 매도 금액만 반환 (임시)
-            // TODO: 정확한 실현 손익 계산을 위해 매도 거래별 원가 추적 필요
-            Decimal::ZERO // 임시로 0 반환
-        } else {
-            Decimal::ZERO
+        // 4. 손익 계산
+        let current_value = current_market_price.map(|price| price * current_balance);
+        let (unrealized_pnl, unrealized_pnl_percent) = match (current_market_price, average_entry_price) {
+            (Some(market_price), Some(_)) => {
+                // 미실현 손익 = 남은 lot 평가액 - 남은 lot 원가 (수수료 포함)
+                let pnl = market_price * open_amount - cost_basis;
+                let pnl_percent = if !cost_basis.is_zero() {
+                    Some(pnl / cost_basis * Decimal::from(100))
+                } else {
+                    None
+                };
+                (Some(pnl), pnl_percent)
+            }
+            // 시장 가격이나 lot이 없으면 계산 불가
+            _ => (None, None),
         };
 
         Ok(Some(AssetPosition {
@@ -170,30 +364,34 @@ impl PositionService {
             available,
             locked,
             average_entry_price,
-            total_bought_amount,
-            total_bought_cost,
+            cost_basis_method: ledger.method(),
+            open_amount,
+            cost_basis,
+            total_bought_amount: stats.total_bought_amount,
+            total_bought_cost: stats.total_bought_cost,
             current_market_price,
             current_value,
             unrealized_pnl,
             unrealized_pnl_percent,
             trade_summary: TradeSummary {
-                total_buy_trades,
-                total_sell_trades,
-                realized_pnl,
+                total_buy_trades: stats.total_buy_trades,
+                total_sell_trades: stats.total_sell_trades,
+                realized_pnl: stats.realized_pnl,
+                total_fees: stats.total_fees,
             },
         }))
     }
 
     /// 사용자의 모든 자산 포지션 정보 조회
     /// Get all positions for user
-    /// 
+    ///
     /// # Arguments
     /// * `user_id` - 사용자 ID
-    /// 
+    ///
     /// # Returns
     /// * `Ok(Vec<AssetPosition>)` - 모든 자산 포지션 목록
     /// * `Err` - 데이터베이스 오류 시
-    /// 
+    ///
     /// # Note
     /// - 잔고가 있는 자산만 반환
     /// - 매수 거래가 없는 자산도 포함 (평균 매수가 등이 None)
@@ -220,5 +418,233 @@ impl PositionService {
 
         Ok(positions)
     }
+
+    /// 사용자의 특정 자산 원가 lot과 실현 손익 조회
+    /// Get open lots and realized P&L entries of user's asset
+    ///
+    /// # Arguments
+    /// * `user_id` - 사용자 ID
+    /// * `mint` - 자산 식별자
+    /// * `realized_limit` - 실현 손익 최대 개수 (기본 50, 최대 1000)
+    pub async fn get_position_lots(
+        &self,
+        user_id: u64,
+        mint: &str,
+        realized_limit: Option<i64>,
+    ) -> Result<PositionLotsResponse> {
+        let ledger = self.sync_ledger(user_id, mint, None).await?;
+        self.lots_response(user_id, mint, &ledger, realized_limit).await
+    }
+
+    /// 사용자의 특정 자산 원가 계산 방식 변경
+    /// Change cost basis method of user's asset
+    ///
+    /// 방식이 바뀌면 해당 자산의 lot과 실현 손익을 전체 체결 내역으로 다시 계산합니다.
+    /// (같은 방식이면 증분 반영만 수행)
+    pub async fn set_cost_basis_method(
+        &self,
+        user_id: u64,
+        mint: &str,
+        method: CostBasisMethod,
+    ) -> Result<PositionLotsResponse> {
+        let ledger = self.sync_ledger(user_id, mint, Some(method)).await?;
+        self.lots_response(user_id, mint, &ledger, None).await
+    }
+
+    async fn lots_response(
+        &self,
+        user_id: u64,
+        mint: &str,
+        ledger: &PositionLedger,
+        realized_limit: Option<i64>,
+    ) -> Result<PositionLotsResponse> {
+        let limit = realized_limit
+            .unwrap_or(DEFAULT_REALIZED_LIMIT)
+            .clamp(1, MAX_REALIZED_LIMIT);
+        let position_repo = PositionRepository::new(self.db.pool().clone());
+        let realized = position_repo
+            .get_realized(user_id, mint, limit)
+            .await
+            .context("Failed to fetch realized P&L")?;
+
+        Ok(PositionLotsResponse {
+            mint: mint.to_string(),
+            cost_basis_method: ledger.method(),
+            open_amount: ledger.open_amount(),
+            cost_basis: ledger.cost_basis(),
+            realized_pnl: ledger.stats().realized_pnl,
+            lots: ledger.open_lots(),
+            realized,
+        })
+    }
+
+    /// lot 장부에 새 체결 반영 후 저장
+    /// Apply new fills to the lot ledger and persist
+    ///
+    /// # Arguments
+    /// * `method` - Some(방식)이고 저장된 방식과 다르면 초기화 후 전체 체결로 재계산
+    ///
+    /// # 처리 과정
+    /// 1. 포지션 행 잠금 (없으면 생성)
+    /// 2. 저장된 lot 로드 (재계산이면 초기화)
+    /// 3. `last_trade_id` 이후 체결을 페이지 단위로 반영
+    /// 4. 새 lot / 변경된 lot / 실현 손익 / 통계 저장 후 커밋
+    async fn sync_ledger(
+        &self,
+        user_id: u64,
+        mint: &str,
+        method: Option<CostBasisMethod>,
+    ) -> Result<PositionLedger> {
+        let mut tx = self.db.pool().begin().await
+            .context("Failed to begin transaction")?;
+
+        // 1~2. 잠금 + 로드
+        let (current_method, stats) = PositionRepository::lock_position(&mut tx, user_id, mint).await?;
+        let rebuild = method.is_some_and(|method| method != current_method);
+        let mut ledger = if let Some(method) = method.filter(|_| rebuild) {
+            PositionRepository::reset_position(&mut tx, user_id, mint, method).await?;
+            PositionLedger::new(method, PositionStats::default(), Vec::new())
+        } else {
+            let lots = PositionRepository::get_open_lots(&mut tx, user_id, mint).await?;
+            PositionLedger::new(current_method, stats, lots)
+        };
+
+        // 3. 새 체결 반영
+        let trade_repo = TradeRepository::new(self.db.pool().clone());
+        let mut fee_rates: HashMap<String, Decimal> = HashMap::new();
+        let mut applied = 0usize;
+        loop {
+            let fills = trade_repo
+                .get_user_fills_after(user_id, mint, ledger.stats().last_trade_id, FILL_PAGE_SIZE)
+                .await?;
+            let page_len = fills.len();
+
+            for (trade, is_buyer, is_seller) in fills {
+                let fee_rate = match fee_rates.get(&trade.quote_mint) {
+                    Some(rate) => *rate,
+                    None => {
+                        let rate = self
+                            .fee_service
+                            .get_fee_config(mint, &trade.quote_mint)
+                            .await?
+                            .map(|config| config.fee_rate)
+                            .unwrap_or(Decimal::ZERO);
+                        fee_rates.insert(trade.quote_mint.clone(), rate);
+                        rate
+                    }
+                };
+                ledger.apply_fill(&trade, is_buyer, is_seller, fee_rate);
+            }
+
+            applied += page_len;
+            if (page_len as i64) < FILL_PAGE_SIZE {
+                break;
+            }
+        }
+
+        // 4. 저장
+        if applied > 0 || rebuild {
+            let (new_lots, changed_lots, realized) = ledger.take_changes();
+            PositionRepository::insert_lots(&mut tx, user_id, mint, &new_lots).await?;
+            PositionRepository::update_lots(&mut tx, &changed_lots).await?;
+            PositionRepository::insert_realized(&mut tx, user_id, mint, &realized).await?;
+            PositionRepository::save_position(&mut tx, user_id, mint, ledger.method(), ledger.stats()).await?;
+        }
+
+        tx.commit().await
+            .context("Failed to commit transaction")?;
+
+        if rebuild {
+            eprintln!(
+                "[PositionService] Rebuilt {} position of user {} with {} ({} fills)",
+                mint, user_id, ledger.method(), applied
+            );
+        }
+
+        Ok(ledger)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn fill(id: u64, price: i64, amount: i64) -> Trade {
+        Trade {
+            id,
+            buy_order_id: id * 10,
+            sell_order_id: id * 10 + 1,
+            base_mint: "SOL".to_string(),
+            quote_mint: "USDT".to_string(),
+            price: Decimal::from(price),
+            amount: Decimal::from(amount),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn realized_after(method: CostBasisMethod) -> (Decimal, Decimal) {
+        let mut ledger = PositionLedger::new(method, PositionStats::default(), Vec::new());
+        ledger.apply_fill(&fill(1, 100, 10), true, false, Decimal::ZERO);
+        ledger.apply_fill(&fill(2, 120, 10), true, false, Decimal::ZERO);
+        ledger.apply_fill(&fill(3, 130, 15), false, true, Decimal::ZERO);
+        (ledger.stats().realized_pnl, ledger.cost_basis())
+    }
+
+    #[test]
+    fn cost_basis_methods_pick_lots_differently() {
+        // 매도 대금 1950
+        // FIFO: 100×10 + 120×5 = 1600 차감 → 남은 원가 600
+        assert_eq!(realized_after(CostBasisMethod::Fifo), (Decimal::from(350), Decimal::from(600)));
+        // LIFO: 120×10 + 100×5 = 1700 차감 → 남은 원가 500
+        assert_eq!(realized_after(CostBasisMethod::Lifo), (Decimal::from(250), Decimal::from(500)));
+        // 평균 원가: 110×15 = 1650 차감 → 남은 원가 550 (평균 단가 110 유지)
+        assert_eq!(realized_after(CostBasisMethod::AverageCost), (Decimal::from(300), Decimal::from(550)));
+    }
+
+    #[test]
+    fn fees_are_in_cost_basis_and_incremental_replay_matches() {
+        let fee_rate = Decimal::new(1, 3); // 0.1%
+        let fills = [
+            (fill(1, 100, 10), true, false),
+            (fill(2, 110, 4), false, true),
+            (fill(3, 90, 6), true, false),
+            // 남은 lot(12)보다 많이 매도 → 원가 없는 2개는 실현 손익에서 제외
+            (fill(4, 120, 14), false, true),
+        ];
+
+        let mut full = PositionLedger::new(CostBasisMethod::Fifo, PositionStats::default(), Vec::new());
+        for (trade, is_buyer, is_seller) in &fills {
+            full.apply_fill(trade, *is_buyer, *is_seller, fee_rate);
+        }
+
+        // 매수 원가 1000 + 수수료 1 → 4개 매도 원가 400.4, 대금 440 - 0.44
+        let realized = &full.new_realized;
+        assert_eq!(realized[0].cost_basis, Decimal::new(4004, 1));
+        assert_eq!(realized[0].proceeds, Decimal::new(43956, 2));
+        assert_eq!(realized[1].amount, Decimal::from(12));
+        assert_eq!(full.open_amount(), Decimal::ZERO);
+        assert_eq!(full.stats().total_sell_trades, 2);
+
+        // 앞 두 체결 반영 → 저장된 상태로 다시 로드 → 나머지 반영 = 한 번에 반영
+        let mut first = PositionLedger::new(CostBasisMethod::Fifo, PositionStats::default(), Vec::new());
+        for (trade, is_buyer, is_seller) in &fills[..2] {
+            first.apply_fill(trade, *is_buyer, *is_seller, fee_rate);
+        }
+        let (new_lots, _, _) = first.take_changes();
+        let saved: Vec<PositionLot> = new_lots
+            .into_iter()
+            .enumerate()
+            .map(|(i, lot)| PositionLot { lot_id: i as u64 + 1, ..lot })
+            .collect();
+
+        let mut resumed = PositionLedger::new(CostBasisMethod::Fifo, first.stats().clone(), saved);
+        for (trade, is_buyer, is_seller) in &fills[2..] {
+            resumed.apply_fill(trade, *is_buyer, *is_seller, fee_rate);
+        }
+        assert_eq!(resumed.stats(), full.stats());
+        assert_eq!(resumed.cost_basis(), full.cost_basis());
+        assert_eq!(resumed.stats().last_trade_id, 4);
+        assert_eq!(resumed.take_changes().1.len(), 1);
+    }
+}
@@ -0,0 +1,394 @@
+use crate::shared::database::{Database, LedgerRepository};
+use crate::domains::cex::engine::runtime::{BalanceSnapshotEntry, HighPerformanceEngine};
+use crate::domains::cex::models::reconciliation::{
+    BalanceDiscrepancy, ReconciliationMetrics, ReconciliationReport, ReconciliationStatus, StoredBalance,
+};
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use parking_lot::RwLock;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::time::interval;
+
+// =====================================================
+// ReconciliationService
+// =====================================================
+// 역할: 엔진 BalanceCache ↔ user_balances ↔ 원장 합계 정합성 검사
+//
+// 처리 과정:
+// 1. 엔진 스레드에서 잔고 스냅샷 (WAL 시퀀스 S) + DB Writer Barrier
+// 2. Barrier ack 대기 → S 이전 DB 명령이 모두 반영(또는 dead-letter)됨
+// 3. user_balances + 원장 합계 조회 (한 쿼리)
+//    → S 이후 원장 전기를 빼서 S 시점 DB 잔고로 환산 (검사 중 체결이 있어도 오탐 없음)
+// 4. (user, mint)별 비교 → 불일치 보고 (지표 + 마지막 결과)
+// 5. 복구 요청 시 엔진 값과 다른 DB 행을 조정 분개와 함께 엔진 값으로 맞춤
+//
+// 설정 (환경 변수):
+// - RECONCILIATION_INTERVAL_SECS: 주기 실행 간격 (기본 300, 0이면 비활성화)
+// - RECONCILIATION_REPAIR: off (기본) / manual (실행 요청 시 repair 허용) / auto (주기 실행도 복구)
+// =====================================================
+
+/// 실행 간격 환경 변수
+const INTERVAL_ENV: &str = "RECONCILIATION_INTERVAL_SECS";
+
+/// 복구 모드 환경 변수
+const REPAIR_ENV: &str = "RECONCILIATION_REPAIR";
+
+/// 기본 실행 간격
+const DEFAULT_INTERVAL_SECS: u64 = 300;
+
+/// DB Writer Barrier ack 최대 대기 시간
+const BARRIER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 복구 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairMode {
+    /// 복구 안 함 (보고만)
+    Off,
+    /// 실행 요청에서 repair=true일 때만 복구
+    Manual,
+    /// 주기 실행도 복구
+    Auto,
+}
+
+impl RepairMode {
+    fn from_env() -> Self {
+        match std::env::var(REPAIR_ENV).unwrap_or_default().trim().to_lowercase().as_str() {
+            "manual" => RepairMode::Manual,
+            "auto" => RepairMode::Auto,
+            "" | "off" => RepairMode::Off,
+            other => {
+                eprintln!("[Reconciliation] Unknown {}={}, repair disabled", REPAIR_ENV, other);
+                RepairMode::Off
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepairMode::Off => "off",
+            RepairMode::Manual => "manual",
+            RepairMode::Auto => "auto",
+        }
+    }
+}
+
+/// (user, mint)별 엔진 스냅샷 / DB 행
+type BalancePair<'a> = (Option<&'a BalanceSnapshotEntry>, Option<&'a StoredBalance>);
+
+/// (user, mint)별 비교
+///
+/// # Arguments
+/// * `engine` - 엔진 스냅샷
+/// * `stored` - DB 잔고 + 원장 합계 (`*_after`는 스냅샷 이후 원장 전기)
+///
+/// # Returns
+/// 불일치 목록 ((user_id, mint) 순), 비교한 (user, mint) 수
+///
+/// # 비교
+/// - 엔진 ↔ DB: 엔진 값 vs (DB 값 - 스냅샷 이후 원장 전기)
+/// - DB ↔ 원장: DB 값 vs 원장 합계 (같은 트랜잭션에서 기록되므로 시점 무관)
+/// - 한쪽에만 있는 (user, mint)는 다른 쪽을 0으로 간주
+pub fn find_discrepancies(
+    engine: &[BalanceSnapshotEntry],
+    stored: &[StoredBalance],
+) -> (Vec<BalanceDiscrepancy>, u64) {
+    let mut merged: HashMap<(u64, &str), BalancePair> = HashMap::new();
+    for entry in engine {
+        merged.entry((entry.user_id, entry.mint.as_str())).or_default().0 = Some(entry);
+    }
+    for row in stored {
+        merged.entry((row.user_id, row.mint.as_str())).or_default().1 = Some(row);
+    }
+
+    let checked = merged.len() as u64;
+    let mut discrepancies: Vec<BalanceDiscrepancy> = merged
+        .into_iter()
+        .filter_map(|((user_id, mint), (engine, stored))| {
+            let (engine_available, engine_locked) = engine
+                .map(|entry| (entry.available, entry.locked))
+                .unwrap_or((Decimal::ZERO, Decimal::ZERO));
+            let stored = stored.cloned().unwrap_or_default();
+
+            let db_available = stored.available - stored.available_after;
+            let db_locked = stored.locked - stored.locked_after;
+            let engine_mismatch = engine_available != db_available || engine_locked != db_locked;
+            let ledger_mismatch = stored.available != stored.ledger_available || stored.locked != stored.ledger_locked;
+
+            (engine_mismatch || ledger_mismatch).then(|| BalanceDiscrepancy {
+                user_id,
+                mint: mint.to_string(),
+                engine_available,
+                engine_locked,
+                db_available,
+                db_locked,
+                ledger_available: stored.ledger_available - stored.available_after,
+                ledger_locked: stored.ledger_locked - stored.locked_after,
+                engine_mismatch,
+                ledger_mismatch,
+                repaired: false,
+            })
+        })
+        .collect();
+
+    discrepancies.sort_by(|a, b| (a.user_id, &a.mint).cmp(&(b.user_id, &b.mint)));
+    (discrepancies, checked)
+}
+
+/// 정합성 검사 서비스
+/// Reconciliation Service
+#[derive(Clone)]
+pub struct ReconciliationService {
+    db: Database,
+    engine: Arc<Mutex<HighPerformanceEngine>>,
+    interval: Duration,
+    repair_mode: RepairMode,
+    /// 동시 실행 방지
+    running: Arc<Mutex<()>>,
+    metrics: Arc<RwLock<ReconciliationMetrics>>,
+    last_report: Arc<RwLock<Option<ReconciliationReport>>>,
+}
+
+impl ReconciliationService {
+    /// 생성자 (환경 변수로 간격/복구 모드 설정)
+    /// Constructor
+    pub fn new(db: Database, engine: Arc<Mutex<HighPerformanceEngine>>) -> Self {
+        let interval_secs = std::env::var(INTERVAL_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+        Self {
+            db,
+            engine,
+            interval: Duration::from_secs(interval_secs),
+            repair_mode: RepairMode::from_env(),
+            running: Arc::new(Mutex::new(())),
+            metrics: Arc::new(RwLock::new(ReconciliationMetrics::default())),
+            last_report: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 주기 실행 시작
+    pub fn start(&self) {
+        if self.interval.is_zero() {
+            eprintln!("[Reconciliation] {}=0, periodic reconciliation is disabled", INTERVAL_ENV);
+            return;
+        }
+
+        let service = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(service.interval);
+            // 첫 tick은 즉시 반환 (엔진 시작 직후는 건너뜀)
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let repair = service.repair_mode == RepairMode::Auto;
+                if let Err(e) = service.run(repair).await {
+                    eprintln!("[Reconciliation] Run failed: {:#}", e);
+                }
+            }
+        });
+
+        eprintln!(
+            "[Reconciliation] Started (interval: {:?}, repair: {})",
+            self.interval,
+            self.repair_mode.as_str()
+        );
+    }
+
+    /// 복구 허용 여부 (복구 모드 manual/auto)
+    pub fn repair_allowed(&self) -> bool {
+        self.repair_mode != RepairMode::Off
+    }
+
+    /// 실행 중 여부
+    pub fn is_running(&self) -> bool {
+        self.running.try_lock().is_err()
+    }
+
+    /// 상태 조회 (지표 + 마지막 결과)
+    pub fn status(&self) -> ReconciliationStatus {
+        ReconciliationStatus {
+            interval_seconds: self.interval.as_secs(),
+            repair_mode: self.repair_mode.as_str().to_string(),
+            metrics: self.metrics.read().clone(),
+            last_report: self.last_report.read().clone(),
+        }
+    }
+
+    /// 정합성 검사 1회 실행
+    ///
+    /// # Arguments
+    /// * `repair` - 엔진과 다른 DB 행을 엔진 값으로 복구 (복구 모드 off면 에러)
+    ///
+    /// # Returns
+    /// 검사 결과 (마지막 결과로도 저장)
+    pub async fn run(&self, repair: bool) -> Result<ReconciliationReport> {
+        if repair && !self.repair_allowed() {
+            bail!("Repair is disabled ({}=off)", REPAIR_ENV);
+        }
+
+        let _guard = self.running.try_lock()
+            .map_err(|_| anyhow::anyhow!("Reconciliation is already running"))?;
+
+        let started = Instant::now();
+        let result = self.run_once(repair).await;
+
+        let mut metrics = self.metrics.write();
+        metrics.last_run_at = Some(Utc::now());
+        metrics.last_duration_ms = started.elapsed().as_millis() as u64;
+        match &result {
+            Ok(report) => {
+                let repaired = report.discrepancies.iter().filter(|d| d.repaired).count() as u64;
+                metrics.runs += 1;
+                metrics.last_discrepancies = report.discrepancies.len() as u64;
+                metrics.total_discrepancies += report.discrepancies.len() as u64;
+                metrics.total_repaired += repaired;
+                metrics.last_error = None;
+                *self.last_report.write() = Some(report.clone());
+            }
+            Err(e) => {
+                metrics.failures += 1;
+                metrics.last_error = Some(format!("{:#}", e));
+            }
+        }
+
+        result
+    }
+
+    async fn run_once(&self, repair: bool) -> Result<ReconciliationReport> {
+        let started_at = Utc::now();
+
+        // 1. 엔진 스냅샷 + DB Writer Barrier
+        let snapshot = self.engine.lock().await.balance_snapshot().await?;
+
+        // 2. 스냅샷 이전 DB 명령 반영 대기
+        let db_high_water = match snapshot.db_flushed {
+            Some(ack) => tokio::task::spawn_blocking(move || ack.recv_timeout(BARRIER_TIMEOUT))
+                .await
+                .context("Barrier wait task failed")?
+                .map_err(|e| anyhow::anyhow!("DB writer did not acknowledge barrier: {}", e))?
+                .wal_seq,
+            None => bail!("DB writer is not running"),
+        };
+
+        // 3. DB 잔고 + 원장 합계 (스냅샷 이후 전기 별도)
+        let ledger_repo = LedgerRepository::new(self.db.pool().clone());
+        let stored = ledger_repo.get_stored_balances(snapshot.wal_seq).await?;
+
+        // 4. 비교
+        let (mut discrepancies, checked) = find_discrepancies(&snapshot.balances, &stored);
+        for discrepancy in &discrepancies {
+            eprintln!(
+                "[Reconciliation] Discrepancy user_id={}, mint={}: engine={}/{}, db={}/{}, ledger={}/{} (available/locked)",
+                discrepancy.user_id,
+                discrepancy.mint,
+                discrepancy.engine_available,
+                discrepancy.engine_locked,
+                discrepancy.db_available,
+                discrepancy.db_locked,
+                discrepancy.ledger_available,
+                discrepancy.ledger_locked,
+            );
+        }
+
+        // 5. 복구 (엔진 값 기준, 조정 분개 기록)
+        if repair {
+            let adjustments: Vec<(u64, String, Decimal, Decimal)> = discrepancies
+                .iter()
+                .filter(|d| d.engine_mismatch)
+                .map(|d| (
+                    d.user_id,
+                    d.mint.clone(),
+                    d.engine_available - d.db_available,
+                    d.engine_locked - d.db_locked,
+                ))
+                .collect();
+
+            if !adjustments.is_empty() {
+                let reference = format!("reconciliation:{}", started_at.timestamp_millis());
+                ledger_repo
+                    .post_adjustments(snapshot.wal_seq, &reference, &adjustments)
+                    .await?;
+                for discrepancy in discrepancies.iter_mut().filter(|d| d.engine_mismatch) {
+                    discrepancy.repaired = true;
+                }
+                eprintln!("[Reconciliation] Repaired {} balance rows ({})", adjustments.len(), reference);
+            }
+        }
+
+        Ok(ReconciliationReport {
+            wal_seq: snapshot.wal_seq,
+            db_high_water,
+            checked,
+            discrepancies,
+            repair,
+            started_at,
+            finished_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine(user_id: u64, mint: &str, available: i64, locked: i64) -> BalanceSnapshotEntry {
+        BalanceSnapshotEntry {
+            user_id,
+            mint: mint.to_string(),
+            available: Decimal::new(available, 0),
+            locked: Decimal::new(locked, 0),
+        }
+    }
+
+    fn stored(user_id: u64, mint: &str, available: i64, locked: i64, available_after: i64) -> StoredBalance {
+        StoredBalance {
+            user_id,
+            mint: mint.to_string(),
+            available: Decimal::new(available, 0),
+            locked: Decimal::new(locked, 0),
+            ledger_available: Decimal::new(available, 0),
+            ledger_locked: Decimal::new(locked, 0),
+            available_after: Decimal::new(available_after, 0),
+            locked_after: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn postings_after_snapshot_are_not_reported() {
+        // 스냅샷 이후 +25 입금이 이미 DB에 반영된 경우
+        let (discrepancies, checked) = find_discrepancies(
+            &[engine(1, "USDT", 100, 10)],
+            &[stored(1, "USDT", 125, 10, 25)],
+        );
+
+        assert_eq!(checked, 1);
+        assert!(discrepancies.is_empty());
+    }
+
+    #[test]
+    fn reports_engine_and_ledger_mismatches() {
+        let mut drifted_ledger = stored(2, "SOL", 5, 0, 0);
+        drifted_ledger.ledger_available = Decimal::new(4, 0);
+
+        let (discrepancies, checked) = find_discrepancies(
+            &[engine(1, "USDT", 100, 0), engine(3, "SOL", 1, 0)],
+            &[stored(1, "USDT", 90, 10, 0), drifted_ledger],
+        );
+
+        assert_eq!(checked, 3);
+        let summary: Vec<(u64, bool, bool)> = discrepancies
+            .iter()
+            .map(|d| (d.user_id, d.engine_mismatch, d.ledger_mismatch))
+            .collect();
+        // 1: 엔진 locked 0 vs DB 10, 2: DB만 있음 + 원장 불일치, 3: 엔진만 있음
+        assert_eq!(summary, vec![(1, true, false), (2, true, true), (3, true, false)]);
+        assert_eq!(discrepancies[0].db_locked, Decimal::new(10, 0));
+        assert_eq!(discrepancies[1].ledger_available, Decimal::new(4, 0));
+    }
+}
@@ -2,8 +2,9 @@
 // CEX 도메인 상태
 use std::sync::Arc;
 use crate::shared::database::Database;
-use crate::domains::cex::services::{BalanceService, FeeService, OrderService, TradeService, PositionService};
+use crate::domains::cex::services::{BalanceService, FeeService, OrderService, TradeService, PositionService, MarketDataService, UserStreamService, CandleService, TickerService, PortfolioService, ExportService, DepositService, WithdrawalService, LedgerService, ReconciliationService, TransferService, SubAccountService, MarketStatusService};
 use crate::domains::cex::engine::runtime::HighPerformanceEngine;
+use anyhow::Result;
 
 /// CEX domain state
 /// CEX 도메인에서 필요한 서비스들을 포함하는 상태
@@ -15,6 +16,19 @@ pub struct CexState {
     pub order_service: OrderService,
     pub trade_service: TradeService,
     pub position_service: PositionService,
+    pub market_data_service: MarketDataService,
+    pub user_stream_service: UserStreamService,
+    pub candle_service: CandleService,
+    pub ticker_service: TickerService,
+    pub portfolio_service: PortfolioService,
+    pub export_service: ExportService,
+    pub deposit_service: DepositService,
+    pub withdrawal_service: WithdrawalService,
+    pub ledger_service: LedgerService,
+    pub reconciliation_service: ReconciliationService,
+    pub transfer_service: TransferService,
+    pub sub_account_service: SubAccountService,
+    pub market_status_service: MarketStatusService,
 }
 
 impl CexState {
@@ -24,15 +38,37 @@ impl CexState {
     /// # Arguments
     /// * `db` - 데이터베이스 연결
     /// * `engine` - 체결 엔진 (구체 타입 직접 사용)
-    pub fn new(db: Database, engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>) -> Self {
-        Self {
+    pub fn new(db: Database, engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>) -> Result<Self> {
+        let market_data_service = MarketDataService::new(engine.clone());
+        let ticker_service = TickerService::new(db.clone(), engine.clone(), market_data_service.clone());
+        let transfer_service = TransferService::new(db.clone(), engine.clone());
+        let market_status_service = MarketStatusService::new(db.clone());
+        Ok(Self {
             engine: engine.clone(),
             balance_service: BalanceService::new(db.clone()),
             fee_service: FeeService::new(db.clone()),
-            order_service: OrderService::new(db.clone(), engine),
+            order_service: OrderService::new(db.clone(), engine.clone(), market_status_service.clone()),
             trade_service: TradeService::new(db.clone()),
-            position_service: PositionService::new(db),
-        }
+            position_service: PositionService::new(db.clone(), FeeService::new(db.clone())),
+            candle_service: CandleService::new(db.clone(), engine.clone(), market_data_service.clone()),
+            portfolio_service: PortfolioService::new(db.clone(), ticker_service.clone()),
+            export_service: ExportService::new(db.clone()),
+            deposit_service: DepositService::new(db.clone(), engine.clone())?,
+            withdrawal_service: WithdrawalService::new(db.clone(), engine.clone())?,
+            ledger_service: LedgerService::new(db.clone()),
+            reconciliation_service: ReconciliationService::new(db.clone(), engine.clone()),
+            sub_account_service: SubAccountService::new(
+                db.clone(),
+                BalanceService::new(db.clone()),
+                PositionService::new(db.clone(), FeeService::new(db.clone())),
+                transfer_service.clone(),
+            ),
+            transfer_service,
+            market_status_service,
+            ticker_service,
+            market_data_service,
+            user_stream_service: UserStreamService::new(engine),
+        })
     }
 }
 
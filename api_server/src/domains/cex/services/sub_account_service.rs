@@ -0,0 +1,373 @@
+use crate::shared::database::{Database, SubAccountRepository};
+use crate::domains::cex::models::balance::UserBalance;
+use crate::domains::cex::models::position::AssetPosition;
+use crate::domains::cex::models::sub_account::{
+    AccountBalances, AccountPositions, AggregatedBalance, AggregatedPosition, SubAccount,
+    SubAccountBalancesResponse, SubAccountPositionsResponse, SubAccountTransferRequest,
+};
+use crate::domains::cex::models::transfer::CreateTransferRequest;
+use crate::domains::cex::services::{BalanceService, CreateTransferResult, PositionService, TransferService};
+use anyhow::{Result, bail};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+// =====================================================
+// SubAccountService
+// =====================================================
+// 역할: 마스터 사용자가 소유한 서브 계정 관리 / 합산 조회
+//
+// 특징:
+// - 서브 계정은 users 행 (합성 이메일, 로그인 불가)
+//   → 엔진 BalanceCache / 주문 / 체결 / 원장이 user_id 기준으로 그대로 분리됨
+// - 마스터는 X-Sub-Account-Id 헤더로 서브 계정 대신 주문/조회 (TradingAccount extractor)
+// - 계정 간 잔고 이동은 내부 이체 (TransferService, 보내는 계정의 멱등 키)
+// - 합산 조회: 마스터 + 모든 서브 계정의 잔고/포지션을 자산별로 합산
+// =====================================================
+
+/// 서브 계정 합성 이메일 도메인 (회원가입 불가)
+pub const SUB_ACCOUNT_EMAIL_DOMAIN: &str = "sub-accounts.invalid";
+
+/// 서브 계정 비밀번호 해시 (Argon2 형식이 아니라 검증이 항상 실패)
+const SUB_ACCOUNT_PASSWORD_HASH: &str = "!sub-account";
+
+/// 마스터당 최대 서브 계정 수
+const MAX_SUB_ACCOUNTS: i64 = 50;
+
+/// 이름 최대 길이
+const MAX_LABEL_LEN: usize = 32;
+
+/// 이름 검증 (영문 소문자, 숫자, '-', '_')
+fn validate_label(label: &str) -> Result<()> {
+    if label.is_empty()
+        || label.len() > MAX_LABEL_LEN
+        || !label.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    {
+        bail!(
+            "Label must be 1-{} characters of lowercase letters, digits, '-' or '_'",
+            MAX_LABEL_LEN
+        );
+    }
+    Ok(())
+}
+
+/// 서브 계정 합성 이메일
+fn sub_account_email(master_user_id: u64, label: &str) -> String {
+    format!("{}+{}@{}", master_user_id, label, SUB_ACCOUNT_EMAIL_DOMAIN)
+}
+
+/// 계정별 잔고를 자산별로 합산 (자산 이름순)
+fn aggregate_balances<'a>(balances: impl IntoIterator<Item = &'a UserBalance>) -> Vec<AggregatedBalance> {
+    let mut totals: BTreeMap<&str, (Decimal, Decimal)> = BTreeMap::new();
+    for balance in balances {
+        let entry = totals.entry(balance.mint_address.as_str()).or_default();
+        entry.0 += balance.available;
+        entry.1 += balance.locked;
+    }
+
+    totals
+        .into_iter()
+        .map(|(mint, (available, locked))| AggregatedBalance {
+            mint: mint.to_string(),
+            available,
+            locked,
+            total: available + locked,
+        })
+        .collect()
+}
+
+/// 계정별 포지션을 자산별로 합산 (자산 이름순)
+///
+/// 평가액/미실현 손익은 시장 가격이 있는 계정만 더하며,
+/// 어느 계정에도 시장 가격이 없으면 None 입니다.
+fn aggregate_positions<'a>(positions: impl IntoIterator<Item = &'a AssetPosition>) -> Vec<AggregatedPosition> {
+    fn add(total: Option<Decimal>, value: Option<Decimal>) -> Option<Decimal> {
+        match (total, value) {
+            (Some(total), Some(value)) => Some(total + value),
+            (total, value) => total.or(value),
+        }
+    }
+
+    let mut totals: BTreeMap<&str, AggregatedPosition> = BTreeMap::new();
+    for position in positions {
+        let entry = totals
+            .entry(position.mint.as_str())
+            .or_insert_with(|| AggregatedPosition {
+                mint: position.mint.clone(),
+                current_balance: Decimal::ZERO,
+                open_amount: Decimal::ZERO,
+                cost_basis: Decimal::ZERO,
+                average_entry_price: None,
+                realized_pnl: Decimal::ZERO,
+                total_fees: Decimal::ZERO,
+                current_market_price: None,
+                current_value: None,
+                unrealized_pnl: None,
+            });
+
+        entry.current_balance += position.current_balance;
+        entry.open_amount += position.open_amount;
+        entry.cost_basis += position.cost_basis;
+        entry.realized_pnl += position.trade_summary.realized_pnl;
+        entry.total_fees += position.trade_summary.total_fees;
+        entry.current_market_price = entry.current_market_price.or(position.current_market_price);
+        entry.current_value = add(entry.current_value, position.current_value);
+        entry.unrealized_pnl = add(entry.unrealized_pnl, position.unrealized_pnl);
+    }
+
+    totals
+        .into_values()
+        .map(|mut position| {
+            if position.open_amount > Decimal::ZERO {
+                position.average_entry_price = Some(position.cost_basis / position.open_amount);
+            }
+            position
+        })
+        .collect()
+}
+
+/// 서브 계정 서비스
+/// Sub-account Service
+#[derive(Clone)]
+pub struct SubAccountService {
+    db: Database,
+    balance_service: BalanceService,
+    position_service: PositionService,
+    /// 계정 간 잔고 이동 (내부 이체)
+    transfer_service: TransferService,
+}
+
+impl SubAccountService {
+    /// 새 SubAccountService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    /// * `balance_service` / `position_service` - 계정별 잔고/포지션 조회
+    /// * `transfer_service` - 계정 간 내부 이체
+    pub fn new(
+        db: Database,
+        balance_service: BalanceService,
+        position_service: PositionService,
+        transfer_service: TransferService,
+    ) -> Self {
+        Self { db, balance_service, position_service, transfer_service }
+    }
+
+    /// 서브 계정 생성
+    /// Create sub-account
+    ///
+    /// # Arguments
+    /// * `master_user_id` - 마스터 사용자 ID (서브 계정이면 에러)
+    /// * `label` - 이름 (마스터별 유니크)
+    ///
+    /// # Returns
+    /// * `Ok(Some(SubAccount))` - 생성됨
+    /// * `Ok(None)` - 같은 이름의 서브 계정이 이미 있음
+    /// * `Err` - 잘못된 이름, 서브 계정 아래 생성, 개수 초과
+    pub async fn create_sub_account(&self, master_user_id: u64, label: &str) -> Result<Option<SubAccount>> {
+        validate_label(label)?;
+
+        let repo = SubAccountRepository::new(self.db.pool().clone());
+        if repo.get_by_user_id(master_user_id).await?.is_some() {
+            bail!("Sub-accounts cannot own sub-accounts");
+        }
+        if repo.count_by_master(master_user_id).await? >= MAX_SUB_ACCOUNTS {
+            bail!("Sub-account limit reached ({})", MAX_SUB_ACCOUNTS);
+        }
+
+        let sub_account = repo
+            .create(
+                master_user_id,
+                label,
+                &sub_account_email(master_user_id, label),
+                SUB_ACCOUNT_PASSWORD_HASH,
+            )
+            .await?;
+
+        if let Some(sub_account) = &sub_account {
+            eprintln!(
+                "[SubAccount] Created sub-account {} ({}) for user {}",
+                sub_account.user_id, sub_account.label, master_user_id
+            );
+        }
+        Ok(sub_account)
+    }
+
+    /// 서브 계정 목록
+    /// List sub-accounts
+    pub async fn list_sub_accounts(&self, master_user_id: u64) -> Result<Vec<SubAccount>> {
+        SubAccountRepository::new(self.db.pool().clone())
+            .list_by_master(master_user_id)
+            .await
+    }
+
+    /// 마스터가 소유한 서브 계정 조회 (아니면 None)
+    /// Get a sub-account owned by the master
+    pub async fn get_owned(&self, master_user_id: u64, sub_account_id: u64) -> Result<Option<SubAccount>> {
+        let sub_account = SubAccountRepository::new(self.db.pool().clone())
+            .get_by_user_id(sub_account_id)
+            .await?;
+        Ok(sub_account.filter(|sub_account| sub_account.master_user_id == master_user_id))
+    }
+
+    /// 계정 간 이체 (마스터 ↔ 서브, 서브 ↔ 서브)
+    /// Transfer between the master and its sub-accounts
+    ///
+    /// 양쪽 계정 모두 마스터 본인 또는 마스터의 서브 계정이어야 합니다.
+    pub async fn transfer(
+        &self,
+        master_user_id: u64,
+        request: &SubAccountTransferRequest,
+    ) -> Result<CreateTransferResult> {
+        for account_id in [request.from_account_id, request.to_account_id] {
+            if account_id != master_user_id && self.get_owned(master_user_id, account_id).await?.is_none() {
+                bail!("Account {} is not yours or one of your sub-accounts", account_id);
+            }
+        }
+
+        let transfer_request = CreateTransferRequest {
+            to_user_id: Some(request.to_account_id),
+            to_email: None,
+            mint: request.mint.clone(),
+            amount: request.amount,
+            memo: request.memo.clone(),
+            idempotency_key: None,
+        };
+        self.transfer_service
+            .create_transfer(request.from_account_id, &transfer_request, request.idempotency_key.trim())
+            .await
+    }
+
+    /// 마스터 + 서브 계정 (user_id, 이름) 목록 (마스터 먼저)
+    async fn accounts(&self, master_user_id: u64) -> Result<Vec<(u64, Option<String>)>> {
+        let sub_accounts = self.list_sub_accounts(master_user_id).await?;
+        Ok(std::iter::once((master_user_id, None))
+            .chain(sub_accounts.into_iter().map(|sub_account| (sub_account.user_id, Some(sub_account.label))))
+            .collect())
+    }
+
+    /// 합산 잔고 (마스터 + 모든 서브 계정)
+    /// Aggregated balances across the master and its sub-accounts
+    pub async fn aggregated_balances(&self, master_user_id: u64) -> Result<SubAccountBalancesResponse> {
+        let mut accounts = Vec::new();
+        for (user_id, label) in self.accounts(master_user_id).await? {
+            let balances = self.balance_service.get_all_balances(user_id).await?;
+            accounts.push(AccountBalances { user_id, label, balances });
+        }
+
+        let totals = aggregate_balances(accounts.iter().flat_map(|account| &account.balances));
+        Ok(SubAccountBalancesResponse { totals, accounts })
+    }
+
+    /// 합산 포지션 (마스터 + 모든 서브 계정)
+    /// Aggregated positions across the master and its sub-accounts
+    pub async fn aggregated_positions(&self, master_user_id: u64) -> Result<SubAccountPositionsResponse> {
+        let mut accounts = Vec::new();
+        for (user_id, label) in self.accounts(master_user_id).await? {
+            let positions = self.position_service.get_all_positions(user_id).await?;
+            accounts.push(AccountPositions { user_id, label, positions });
+        }
+
+        let totals = aggregate_positions(accounts.iter().flat_map(|account| &account.positions));
+        Ok(SubAccountPositionsResponse { totals, accounts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::cex::models::position::{CostBasisMethod, TradeSummary};
+    use chrono::Utc;
+
+    fn balance(user_id: u64, mint: &str, available: i64, locked: i64) -> UserBalance {
+        UserBalance {
+            id: 0,
+            user_id,
+            mint_address: mint.to_string(),
+            available: Decimal::from(available),
+            locked: Decimal::from(locked),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn position(mint: &str, open_amount: i64, cost_basis: i64, market_price: Option<i64>) -> AssetPosition {
+        let open_amount = Decimal::from(open_amount);
+        let cost_basis = Decimal::from(cost_basis);
+        let market_price = market_price.map(Decimal::from);
+        AssetPosition {
+            mint: mint.to_string(),
+            current_balance: open_amount,
+            available: open_amount,
+            locked: Decimal::ZERO,
+            average_entry_price: None,
+            cost_basis_method: CostBasisMethod::Fifo,
+            open_amount,
+            cost_basis,
+            total_bought_amount: open_amount,
+            total_bought_cost: cost_basis,
+            current_market_price: market_price,
+            current_value: market_price.map(|price| price * open_amount),
+            unrealized_pnl: market_price.map(|price| price * open_amount - cost_basis),
+            unrealized_pnl_percent: None,
+            trade_summary: TradeSummary {
+                total_buy_trades: 1,
+                total_sell_trades: 0,
+                realized_pnl: Decimal::from(5),
+                total_fees: Decimal::ONE,
+            },
+        }
+    }
+
+    /// 테스트: 이름 검증
+    #[test]
+    fn test_validate_label() {
+        assert!(validate_label("grid-sol").is_ok());
+        assert!(validate_label("mm_01").is_ok());
+
+        assert!(validate_label("").is_err());
+        assert!(validate_label("Grid").is_err());
+        assert!(validate_label("has space").is_err());
+        assert!(validate_label(&"a".repeat(MAX_LABEL_LEN + 1)).is_err());
+    }
+
+    /// 테스트: 자산별 잔고 합산
+    #[test]
+    fn test_aggregate_balances() {
+        let balances = [
+            balance(1, "USDT", 100, 10),
+            balance(2, "USDT", 50, 0),
+            balance(2, "SOL", 3, 1),
+        ];
+
+        let totals = aggregate_balances(&balances);
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[0].mint, "SOL");
+        assert_eq!(totals[0].total, Decimal::from(4));
+        assert_eq!(totals[1].mint, "USDT");
+        assert_eq!(totals[1].available, Decimal::from(150));
+        assert_eq!(totals[1].locked, Decimal::from(10));
+        assert_eq!(totals[1].total, Decimal::from(160));
+    }
+
+    /// 테스트: 자산별 포지션 합산 (평균 단가 재계산, 시장 가격 없는 계정)
+    #[test]
+    fn test_aggregate_positions() {
+        let positions = [
+            position("SOL", 10, 1000, Some(110)),
+            position("SOL", 30, 3400, None),
+        ];
+
+        let totals = aggregate_positions(&positions);
+        assert_eq!(totals.len(), 1);
+        let sol = &totals[0];
+        assert_eq!(sol.open_amount, Decimal::from(40));
+        assert_eq!(sol.cost_basis, Decimal::from(4400));
+        assert_eq!(sol.average_entry_price, Some(Decimal::from(110)));
+        assert_eq!(sol.realized_pnl, Decimal::from(10));
+        assert_eq!(sol.total_fees, Decimal::from(2));
+        assert_eq!(sol.current_value, Some(Decimal::from(1100)));
+        assert_eq!(sol.unrealized_pnl, Some(Decimal::from(100)));
+
+        assert!(aggregate_positions(&[position("SOL", 1, 100, None)])[0].current_value.is_none());
+    }
+}
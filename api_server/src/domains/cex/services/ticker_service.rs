@@ -0,0 +1,442 @@
+// =====================================================
+// TickerService - 24시간 롤링 ticker
+// =====================================================
+// 역할: 엔진 체결 이벤트로 거래쌍별 24시간 통계를 메모리에서 유지
+//
+// 흐름:
+// 1. 이벤트 버스 구독 (초기화 동안 들어온 이벤트는 큐에 대기)
+// 2. trades 테이블에서 최근 24시간 체결로 윈도우 재구성 (cutoff 시점까지)
+// 3. cutoff 이후 TradeExecuted → 윈도우 갱신
+// 4. 1초마다 만료 처리 후 `ticker` 채널 발행
+//
+// 윈도우:
+// - 체결을 1초 버킷으로 묶어 보관 (거래쌍당 최대 86,400개)
+// - 버킷이 24시간 밖으로 밀려나면 합계에서 빼고,
+//   고가/저가를 가진 버킷이 빠진 경우에만 남은 버킷으로 재계산
+// =====================================================
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use tokio::time::{interval, sleep, Duration};
+
+use crate::domains::cex::engine::runtime::HighPerformanceEngine;
+use crate::domains::cex::engine::types::{EngineEvent, TradingPair};
+use crate::domains::cex::models::ticker::Ticker;
+use crate::domains::cex::services::MarketDataService;
+use crate::shared::database::{Database, TradeRepository};
+
+/// 통계 윈도우 길이 (밀리초)
+const WINDOW_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// 버킷 크기 (밀리초)
+const BUCKET_MS: i64 = 1000;
+
+/// ticker 발행 주기
+const TICKER_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 초기화 시 체결 내역 페이지 크기
+const BACKFILL_PAGE_SIZE: i64 = 1000;
+
+/// 이벤트 버스 재구독 대기 시간
+const RESUBSCRIBE_DELAY: Duration = Duration::from_millis(100);
+
+/// 1초 버킷
+#[derive(Debug, Clone)]
+struct TickerBucket {
+    /// 버킷 시작 시간 (밀리초)
+    start: i64,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+    volume: Decimal,
+    quote_volume: Decimal,
+    trade_count: u64,
+}
+
+/// 거래쌍별 24시간 윈도우
+#[derive(Debug, Default)]
+struct RollingWindow {
+    /// 윈도우 안의 버킷 (오래된 순)
+    buckets: VecDeque<TickerBucket>,
+    volume: Decimal,
+    quote_volume: Decimal,
+    trade_count: u64,
+    high: Option<Decimal>,
+    low: Option<Decimal>,
+    /// 최근 체결가 (윈도우가 비어도 유지)
+    last_price: Option<Decimal>,
+}
+
+impl RollingWindow {
+    /// 체결 반영 (시간순으로 들어온다고 가정, 늦게 온 체결은 마지막 버킷에 합산)
+    fn apply(&mut self, price: Decimal, amount: Decimal, timestamp: i64) {
+        let start = timestamp - timestamp.rem_euclid(BUCKET_MS);
+        let quote_amount = price * amount;
+
+        match self.buckets.back_mut() {
+            Some(bucket) if start <= bucket.start => {
+                bucket.high = bucket.high.max(price);
+                bucket.low = bucket.low.min(price);
+                bucket.volume += amount;
+                bucket.quote_volume += quote_amount;
+                bucket.trade_count += 1;
+            }
+            _ => self.buckets.push_back(TickerBucket {
+                start,
+                open: price,
+                high: price,
+                low: price,
+                volume: amount,
+                quote_volume: quote_amount,
+                trade_count: 1,
+            }),
+        }
+
+        self.volume += amount;
+        self.quote_volume += quote_amount;
+        self.trade_count += 1;
+        self.high = Some(self.high.map_or(price, |high| high.max(price)));
+        self.low = Some(self.low.map_or(price, |low| low.min(price)));
+        self.last_price = Some(price);
+
+        self.expire(timestamp);
+    }
+
+    /// `now` 기준 24시간보다 오래된 버킷 제거
+    fn expire(&mut self, now: i64) {
+        let cutoff = now - WINDOW_MS;
+        let mut recompute = false;
+
+        while let Some(bucket) = self.buckets.front() {
+            if bucket.start + BUCKET_MS > cutoff {
+                break;
+            }
+            let bucket = self.buckets.pop_front().expect("front exists");
+            self.volume -= bucket.volume;
+            self.quote_volume -= bucket.quote_volume;
+            self.trade_count -= bucket.trade_count;
+            if Some(bucket.high) == self.high || Some(bucket.low) == self.low {
+                recompute = true;
+            }
+        }
+
+        if self.buckets.is_empty() {
+            self.volume = Decimal::ZERO;
+            self.quote_volume = Decimal::ZERO;
+            self.trade_count = 0;
+            self.high = None;
+            self.low = None;
+        } else if recompute {
+            self.high = self.buckets.iter().map(|bucket| bucket.high).max();
+            self.low = self.buckets.iter().map(|bucket| bucket.low).min();
+        }
+    }
+
+    fn ticker(
+        &self,
+        trading_pair: &TradingPair,
+        now: i64,
+        (best_bid, best_ask): (Option<Decimal>, Option<Decimal>),
+    ) -> Ticker {
+        let open = self.buckets.front().map(|bucket| bucket.open);
+        let price_change = match (open, self.last_price) {
+            (Some(open), Some(last_price)) => Some(last_price - open),
+            _ => None,
+        };
+        let price_change_percent = match (open, price_change) {
+            (Some(open), Some(change)) if !open.is_zero() => {
+                Some((change / open * Decimal::ONE_HUNDRED).round_dp(2))
+            }
+            _ => None,
+        };
+
+        Ticker {
+            base_mint: trading_pair.base_mint.clone(),
+            quote_mint: trading_pair.quote_mint.clone(),
+            open,
+            high: self.high,
+            low: self.low,
+            last_price: self.last_price,
+            price_change,
+            price_change_percent,
+            base_volume: self.volume,
+            quote_volume: self.quote_volume,
+            trade_count: self.trade_count,
+            best_bid,
+            best_ask,
+            open_time: now - WINDOW_MS,
+            close_time: now,
+        }
+    }
+}
+
+/// 전체 거래쌍 24시간 윈도우
+#[derive(Debug, Default)]
+pub struct TickerBook {
+    windows: HashMap<TradingPair, RollingWindow>,
+}
+
+impl TickerBook {
+    /// 체결 반영
+    ///
+    /// # Arguments
+    /// * `timestamp` - 체결 시간 (밀리초)
+    pub fn apply_trade(&mut self, trading_pair: &TradingPair, price: Decimal, amount: Decimal, timestamp: i64) {
+        self.windows
+            .entry(trading_pair.clone())
+            .or_default()
+            .apply(price, amount, timestamp);
+    }
+
+    /// 만료 처리 후 ticker 계산
+    ///
+    /// # Arguments
+    /// * `now` - 계산 시점 (밀리초)
+    /// * `quotes` - (최우선 매수 호가, 최우선 매도 호가)
+    ///
+    /// # Returns
+    /// 체결이 한 번도 없었던 거래쌍이면 통계가 비어 있는 ticker
+    pub fn ticker(
+        &mut self,
+        trading_pair: &TradingPair,
+        now: i64,
+        quotes: (Option<Decimal>, Option<Decimal>),
+    ) -> Ticker {
+        match self.windows.get_mut(trading_pair) {
+            Some(window) => {
+                window.expire(now);
+                window.ticker(trading_pair, now, quotes)
+            }
+            None => RollingWindow::default().ticker(trading_pair, now, quotes),
+        }
+    }
+
+    /// 체결이 있었던 거래쌍
+    pub fn trading_pairs(&self) -> Vec<TradingPair> {
+        self.windows.keys().cloned().collect()
+    }
+}
+
+/// 24시간 ticker 서비스
+/// 24-hour ticker service
+#[derive(Clone)]
+pub struct TickerService {
+    /// 데이터베이스 연결 (초기화용)
+    db: Database,
+    /// 체결 엔진 (이벤트 버스)
+    engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+    /// 최우선 호가 조회 + `ticker` 채널 발행
+    market_data: MarketDataService,
+    /// 윈도우 상태
+    book: Arc<Mutex<TickerBook>>,
+}
+
+impl TickerService {
+    /// 새 TickerService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    /// * `engine` - 체결 엔진
+    /// * `market_data` - 공개 시세 서비스 (최우선 호가, `ticker` 채널 발행)
+    pub fn new(
+        db: Database,
+        engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+        market_data: MarketDataService,
+    ) -> Self {
+        Self {
+            db,
+            engine,
+            market_data,
+            book: Arc::new(Mutex::new(TickerBook::default())),
+        }
+    }
+
+    /// 백그라운드 태스크 시작 (엔진 시작 이후 호출)
+    ///
+    /// # 처리 과정
+    /// 1. 이벤트 버스 구독 → cutoff 시점 기록
+    /// 2. 최근 24시간 체결로 윈도우 재구성 (cutoff 이전 체결)
+    /// 3. cutoff 이후 체결 이벤트로 윈도우 갱신
+    /// 4. 이벤트 버스에서 해제되면 재구독 (다시 재구성)
+    /// 5. 별도 태스크에서 1초마다 모든 거래쌍 ticker 발행
+    pub fn start(&self) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let mut subscription = service.engine.lock().await.event_bus().subscribe("ticker");
+                let cutoff = Utc::now();
+
+                if let Err(e) = service.backfill(cutoff).await {
+                    eprintln!("[Ticker] Backfill failed: {:#}", e);
+                }
+
+                let cutoff_ms = cutoff.timestamp_millis();
+                while let Some(event) = subscription.recv().await {
+                    if let EngineEvent::TradeExecuted { match_result, .. } = &event.event {
+                        if event.timestamp <= cutoff_ms {
+                            continue;
+                        }
+
+                        let trading_pair = TradingPair::new(
+                            match_result.base_mint.clone(),
+                            match_result.quote_mint.clone(),
+                        );
+                        service.book.lock().apply_trade(
+                            &trading_pair,
+                            match_result.price,
+                            match_result.amount,
+                            event.timestamp,
+                        );
+                    }
+                }
+
+                eprintln!("[Ticker] Event subscription closed, resubscribing");
+                sleep(RESUBSCRIBE_DELAY).await;
+            }
+        });
+
+        let service = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(TICKER_INTERVAL);
+            loop {
+                ticker.tick().await;
+                service.market_data.publish_tickers(service.get_tickers());
+            }
+        });
+
+        eprintln!("[Ticker] Started (24h rolling window)");
+    }
+
+    /// 거래쌍 ticker 조회
+    ///
+    /// # Returns
+    /// 체결이 없었던 거래쌍도 빈 통계 + 최우선 호가로 반환
+    pub fn get_ticker(&self, base_mint: &str, quote_mint: &str) -> Ticker {
+        let trading_pair = TradingPair::new(base_mint.to_string(), quote_mint.to_string());
+        let quotes = self.market_data.top_of_book(&trading_pair);
+        self.book
+            .lock()
+            .ticker(&trading_pair, Utc::now().timestamp_millis(), quotes)
+    }
+
+    /// 모든 거래쌍 ticker 조회 (호가 또는 체결이 있는 거래쌍, 이름순)
+    pub fn get_tickers(&self) -> Vec<Ticker> {
+        let now = Utc::now().timestamp_millis();
+        let mut trading_pairs = self.market_data.trading_pairs();
+        trading_pairs.extend(self.book.lock().trading_pairs());
+        trading_pairs.sort_by(|a, b| (&a.base_mint, &a.quote_mint).cmp(&(&b.base_mint, &b.quote_mint)));
+        trading_pairs.dedup();
+
+        trading_pairs
+            .iter()
+            .map(|trading_pair| {
+                let quotes = self.market_data.top_of_book(trading_pair);
+                self.book.lock().ticker(trading_pair, now, quotes)
+            })
+            .collect()
+    }
+
+    /// 최근 24시간 체결로 윈도우 재구성
+    ///
+    /// # Arguments
+    /// * `cutoff` - 이 시점 이후 체결은 이벤트로 반영되므로 제외
+    ///
+    /// # Returns
+    /// 반영한 체결 건수
+    async fn backfill(&self, cutoff: DateTime<Utc>) -> Result<usize> {
+        let trade_repo = TradeRepository::new(self.db.pool().clone());
+        let mut rebuilt = TickerBook::default();
+        let mut after = (cutoff - chrono::Duration::milliseconds(WINDOW_MS), 0);
+        let mut count = 0;
+
+        loop {
+            let page = trade_repo
+                .get_since(after, cutoff, BACKFILL_PAGE_SIZE)
+                .await
+                .context("Failed to load trades for ticker")?;
+            for trade in &page {
+                let trading_pair = TradingPair::new(trade.base_mint.clone(), trade.quote_mint.clone());
+                rebuilt.apply_trade(&trading_pair, trade.price, trade.amount, trade.created_at.timestamp_millis());
+            }
+            count += page.len();
+
+            match page.last() {
+                Some(last) if page.len() as i64 == BACKFILL_PAGE_SIZE => after = (last.created_at, last.id),
+                _ => break,
+            }
+        }
+
+        *self.book.lock() = rebuilt;
+        if count > 0 {
+            eprintln!("[Ticker] Loaded {} trades from the last 24h", count);
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair() -> TradingPair {
+        TradingPair::new("SOL".to_string(), "USDT".to_string())
+    }
+
+    fn dec(value: i64) -> Decimal {
+        Decimal::from(value)
+    }
+
+    #[test]
+    fn window_expiry_drops_volume_and_recomputes_high_low() {
+        let mut book = TickerBook::default();
+        book.apply_trade(&pair(), dec(120), dec(2), 0);
+        book.apply_trade(&pair(), dec(90), dec(1), 500);
+        book.apply_trade(&pair(), dec(100), dec(3), 60_000);
+        book.apply_trade(&pair(), dec(105), dec(1), 120_000);
+
+        let ticker = book.ticker(&pair(), 120_000, (Some(dec(104)), Some(dec(106))));
+        assert_eq!(ticker.open, Some(dec(120)));
+        assert_eq!(ticker.high, Some(dec(120)));
+        assert_eq!(ticker.low, Some(dec(90)));
+        assert_eq!(ticker.base_volume, dec(7));
+        assert_eq!(ticker.quote_volume, dec(735));
+        assert_eq!(ticker.trade_count, 4);
+        assert_eq!(ticker.best_bid, Some(dec(104)));
+
+        // 첫 1초 버킷이 만료되면 그 안의 고가/저가도 빠짐
+        let ticker = book.ticker(&pair(), WINDOW_MS + 1000, (None, None));
+        assert_eq!(ticker.open, Some(dec(100)));
+        assert_eq!(ticker.high, Some(dec(105)));
+        assert_eq!(ticker.low, Some(dec(100)));
+        assert_eq!(ticker.base_volume, dec(4));
+        assert_eq!(ticker.quote_volume, dec(405));
+        assert_eq!(ticker.trade_count, 2);
+        assert_eq!(ticker.price_change, Some(dec(5)));
+        assert_eq!(ticker.price_change_percent, Some(dec(5)));
+    }
+
+    #[test]
+    fn empty_window_keeps_last_price_only() {
+        let mut book = TickerBook::default();
+        book.apply_trade(&pair(), dec(3), dec(1), 0);
+        book.apply_trade(&pair(), dec(2), dec(1), 1000);
+
+        let ticker = book.ticker(&pair(), 1000, (None, None));
+        assert_eq!(ticker.price_change_percent, Some(Decimal::new(-3333, 2)));
+
+        let ticker = book.ticker(&pair(), WINDOW_MS + 2000, (None, None));
+        assert_eq!(ticker.last_price, Some(dec(2)));
+        assert_eq!(ticker.open, None);
+        assert_eq!(ticker.high, None);
+        assert_eq!(ticker.price_change, None);
+        assert_eq!(ticker.base_volume, Decimal::ZERO);
+        assert_eq!(ticker.trade_count, 0);
+
+        let unknown = TradingPair::new("BTC".to_string(), "USDT".to_string());
+        assert_eq!(book.ticker(&unknown, 0, (None, None)).last_price, None);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::shared::database::{Database, TradeRepository, OrderRepository};
-use crate::domains::cex::models::trade::Trade;
+use crate::domains::cex::models::trade::{Trade, TradeFilter};
+use crate::shared::utils::cursor::{Page, PageCursor};
 use anyhow::{Context, Result};
-use rust_decimal::Decimal;
 
 /// 체결 내역 서비스
 /// Trade Service
@@ -25,10 +25,11 @@ use rust_decimal::Decimal;
 /// let service = TradeService::new(db);
 /// 
 /// // 특정 거래쌍의 최근 거래
-/// let trades = service.get_trades("SOL", "USDT", Some(50)).await?;
+/// let filter = TradeFilter { base_mint: Some("SOL".into()), quote_mint: Some("USDT".into()), ..Default::default() };
+/// let page = service.get_trades(&filter, None, Some(50)).await?;
 /// 
 /// // 내 거래 내역
-/// let my_trades = service.get_my_trades(user_id, None, None).await?;
+/// let my_page = service.get_my_trades(user_id, &TradeFilter::default(), None, None, None).await?;
 /// ```
 #[derive(Clone)]
 pub struct TradeService {
@@ -50,18 +51,18 @@ impl TradeService {
         Self { db }
     }
 
-    /// 거래쌍별 체결 내역 조회
-    /// Get trades for a trading pair
+    /// 거래쌍별 체결 내역 조회 (커서 페이지)
+    /// Get a page of trades for a trading pair
     /// 
-    /// 특정 거래쌍(예: SOL/USDT)의 최근 체결 내역을 조회합니다.
+    /// 특정 거래쌍(예: SOL/USDT)의 체결 내역을 최신순으로 조회합니다.
     /// 
     /// # Arguments
-    /// * `base_mint` - 기준 자산 (예: "SOL")
-    /// * `quote_mint` - 기준 통화 (예: "USDT")
+    /// * `filter` - 거래쌍/기간 필터
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
     /// * `limit` - 최대 조회 개수 (기본: 50, 최대: 1000)
     /// 
     /// # Returns
-    /// * `Ok(Vec<Trade>)` - 체결 내역 목록 (최신순)
+    /// * `Ok(Page<Trade>)` - 체결 내역 목록 (최신순) + 다음 페이지 커서
     /// * `Err` - 데이터베이스 오류 시
     /// 
     /// # 용도
@@ -72,84 +73,81 @@ impl TradeService {
     /// # Examples
     /// ```
     /// // SOL/USDT 최근 거래 50건
-    /// let trades = service.get_trades("SOL", "USDT", Some(50)).await?;
+    /// let filter = TradeFilter { base_mint: Some("SOL".into()), quote_mint: Some("USDT".into()), ..Default::default() };
+    /// let page = service.get_trades(&filter, None, Some(50)).await?;
     /// 
-    /// for trade in trades {
+    /// for trade in page.items {
     ///     println!("{} SOL @ {} USDT", trade.amount, trade.price);
     /// }
     /// ```
     pub async fn get_trades(
         &self,
-        base_mint: &str,
-        quote_mint: &str,
+        filter: &TradeFilter,
+        cursor: Option<PageCursor>,
         limit: Option<i64>,
-    ) -> Result<Vec<Trade>> {
+    ) -> Result<Page<Trade>> {
         let trade_repo = TradeRepository::new(self.db.pool().clone());
 
         // 제한 설정: 기본 50, 최대 1000
-        let limit = limit.unwrap_or(50).min(1000);
+        let limit = limit.unwrap_or(50).clamp(1, 1000);
 
         // DB에서 거래쌍별 체결 내역 조회 (최신순)
-        let trades = trade_repo
-            .get_by_pair(base_mint, quote_mint, Some(limit), None)
+        trade_repo
+            .get_page(filter, cursor, limit)
             .await
             .context(format!(
                 "Failed to fetch trades for {}/{}",
-                base_mint, quote_mint
-            ))?;
-
-        Ok(trades)
+                filter.base_mint.as_deref().unwrap_or("*"),
+                filter.quote_mint.as_deref().unwrap_or("*")
+            ))
     }
 
-    /// 사용자의 체결 내역 조회
-    /// Get trades for a user
+    /// 사용자의 체결 내역 조회 (커서 페이지)
+    /// Get a page of trades for a user
     /// 
-    /// 특정 사용자가 참여한 모든 체결 내역을 조회합니다.
+    /// 특정 사용자가 참여한 체결 내역을 조회합니다.
     /// (사용자가 매수자 또는 매도자로 참여한 거래)
     /// 
     /// # Arguments
     /// * `user_id` - 사용자 ID
-    /// * `limit` - 최대 조회 개수
-    /// * `offset` - 페이지네이션 오프셋
+    /// * `filter` - 거래쌍/기간 필터
+    /// * `side` - 사용자 기준 방향 ('buy' 또는 'sell', None이면 둘 다)
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
+    /// * `limit` - 최대 조회 개수 (기본: 100, 최대: 1000)
     /// 
     /// # Returns
-    /// * `Ok(Vec<Trade>)` - 체결 내역 목록 (최신순)
+    /// * `Ok(Page<Trade>)` - 체결 내역 목록 (최신순) + 다음 페이지 커서
     /// * `Err` - 데이터베이스 오류 시
     /// 
-    /// # 처리 과정
-    /// 1. 사용자의 모든 주문 조회
-    /// 2. 해당 주문들이 참여한 체결 내역 조회
-    /// 3. 최신순으로 반환
-    /// 
     /// # 용도
     /// - 사용자 마이페이지의 "내 거래 내역" 표시
     /// - 수익/손실 계산
-    /// - 거래 통계 생성
+    /// - 거래 통계 생성 (리포트 작업은 next_cursor로 끝까지 순회)
     /// 
     /// # Examples
     /// ```
     /// // 내 최근 거래 100건
-    /// let my_trades = service.get_my_trades(user_id, Some(100), None).await?;
+    /// let page = service.get_my_trades(user_id, &TradeFilter::default(), None, None, Some(100)).await?;
     /// 
-    /// println!("총 {} 건의 거래", my_trades.len());
+    /// println!("{} 건, 다음 페이지: {:?}", page.items.len(), page.next_cursor);
     /// ```
     pub async fn get_my_trades(
         &self,
         user_id: u64,
-        mint: Option<&str>,
+        filter: &TradeFilter,
+        side: Option<&str>,
+        cursor: Option<PageCursor>,
         limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<Trade>> {
-        // TradeRepository의 get_by_user 메서드 사용 (효율적)
-        // Uses TradeRepository's get_by_user method (efficient)
+    ) -> Result<Page<Trade>> {
         let trade_repo = TradeRepository::new(self.db.pool().clone());
-        
-        let trades = trade_repo
-            .get_by_user(user_id, mint, limit, offset)
+
+        // 제한 설정: 기본 100, 최대 1000
+        let limit = limit.unwrap_or(100).clamp(1, 1000);
+
+        trade_repo
+            .get_page_by_user(user_id, filter, side, cursor, limit)
             .await
-            .context("Failed to fetch user trades")?;
-        
-        Ok(trades)
+            .context("Failed to fetch user trades")
     }
 
     /// 특정 주문의 체결 내역 조회
@@ -275,16 +273,11 @@ impl TradeService {
     ) -> Result<(rust_decimal::Decimal, rust_decimal::Decimal)> {
         let trade_repo = TradeRepository::new(self.db.pool().clone());
 
-        // TODO: TradeRepository에 get_24h_volume 메서드 추가 필요
-        // 일단 임시로 0 반환
-        Ok((Decimal::ZERO, Decimal::ZERO))
-        
-        // 나중에 구현:
-        // let (base_volume, quote_volume) = trade_repo
-        //     .get_24h_volume(base_mint, quote_mint)
-        //     .await
-        //     .context("Failed to fetch 24h volume")?;
-        // Ok((base_volume, quote_volume))
+        let since = chrono::Utc::now() - chrono::Duration::hours(24);
+        trade_repo
+            .get_volume_since(base_mint, quote_mint, since)
+            .await
+            .context("Failed to fetch 24h volume")
     }
 }
 
@@ -0,0 +1,326 @@
+use crate::shared::database::{Database, TransferRepository, UserRepository};
+use crate::domains::cex::models::transfer::{CreateTransferRequest, Transfer, TransferDirection, TransferStatus};
+use crate::domains::cex::engine::{Engine, OutcomeUnknown, runtime::HighPerformanceEngine};
+use anyhow::{Result, bail};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+// =====================================================
+// TransferService
+// =====================================================
+// 역할: 거래소 사용자 간 내부 이체 (온체인 출금/입금 없이 available 이동)
+//
+// 처리 과정:
+// 1. 요청 검증 (수량/메모/멱등 키) → 받는 사용자 확인 (ID 또는 이메일)
+// 2. pending 행 생성 (보내는 사용자 + 멱등 키 UNIQUE)
+//    → 이미 있으면 같은 요청인지 확인 후 기존 이체 반환 (엔진 호출 없음)
+// 3. 엔진 스레드에서 양쪽 잔고 이동 (BalanceCommand::Transfer)
+//    → WAL 기록 + DB Writer가 잔고/원장(reason = transfer) 반영
+// 4. 결과에 따라 completed / failed
+//    → 엔진 응답을 받지 못하면 (OutcomeUnknown) 명령은 큐에 남아 있으므로 pending + error 유지,
+//      원장에 이체 분개가 생기면 completed (SETTLE_INTERVAL마다 SETTLE_ATTEMPTS번 확인)
+//
+// 재시작:
+// - 이전 프로세스에서 pending으로 남은 이체는 원장 분개 유무로 completed / failed
+// =====================================================
+
+/// 이체 목록 기본/최대 개수
+const DEFAULT_LIST_LIMIT: i64 = 50;
+const MAX_LIST_LIMIT: i64 = 200;
+
+/// 멱등 키 최대 길이
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 64;
+
+/// 메모 최대 길이
+const MAX_MEMO_LEN: usize = 256;
+
+/// 수량 최대 소수 자릿수 (DECIMAL(30, 9))
+const MAX_AMOUNT_SCALE: u32 = 9;
+
+/// 결과를 알 수 없는 이체의 원장 확인 간격 / 횟수
+const SETTLE_INTERVAL: Duration = Duration::from_secs(1);
+const SETTLE_ATTEMPTS: u32 = 30;
+
+/// 이체 요청 결과
+#[derive(Debug)]
+pub enum CreateTransferResult {
+    /// 새 이체 (completed, 엔진 응답을 받지 못했으면 pending)
+    Created(Transfer),
+    /// 같은 멱등 키의 기존 이체 (상태 그대로)
+    Replayed(Transfer),
+    /// 같은 멱등 키로 다른 내용의 이체가 이미 있음
+    KeyReused,
+}
+
+/// 요청 검증 (받는 사용자 조회 전)
+fn validate_request(request: &CreateTransferRequest, idempotency_key: &str) -> Result<()> {
+    if request.to_user_id.is_some() == request.to_email.is_some() {
+        bail!("Exactly one of to_user_id or to_email is required");
+    }
+    if request.mint.trim().is_empty() {
+        bail!("Asset is required");
+    }
+    if request.amount <= Decimal::ZERO {
+        bail!("Amount must be positive");
+    }
+    if request.amount.normalize().scale() > MAX_AMOUNT_SCALE {
+        bail!("Amount has more than {} decimal places", MAX_AMOUNT_SCALE);
+    }
+    if request.memo.as_ref().is_some_and(|memo| memo.chars().count() > MAX_MEMO_LEN) {
+        bail!("Memo must be at most {} characters", MAX_MEMO_LEN);
+    }
+    if idempotency_key.is_empty()
+        || idempotency_key.len() > MAX_IDEMPOTENCY_KEY_LEN
+        || !idempotency_key.chars().all(|c| c.is_ascii_graphic())
+    {
+        bail!(
+            "Idempotency key must be 1-{} printable ASCII characters",
+            MAX_IDEMPOTENCY_KEY_LEN
+        );
+    }
+    Ok(())
+}
+
+/// 기존 이체가 같은 요청인지 (받는 사용자, 자산, 수량, 메모)
+fn is_same_request(existing: &Transfer, to_user_id: u64, request: &CreateTransferRequest) -> bool {
+    existing.to_user_id == to_user_id
+        && existing.mint == request.mint
+        && existing.amount == request.amount
+        && existing.memo == request.memo
+}
+
+/// 내부 이체 서비스
+/// Transfer Service
+#[derive(Clone)]
+pub struct TransferService {
+    db: Database,
+    /// 잔고 이동 (BalanceCommand::Transfer)
+    engine: Arc<Mutex<HighPerformanceEngine>>,
+}
+
+impl TransferService {
+    /// 새 TransferService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    /// * `engine` - 체결 엔진 (잔고 채널)
+    pub fn new(db: Database, engine: Arc<Mutex<HighPerformanceEngine>>) -> Self {
+        Self { db, engine }
+    }
+
+    /// 이전 프로세스에서 끝나지 않은 이체 정리 (엔진 시작 이후 호출)
+    pub fn start(&self) {
+        let started_at = Utc::now();
+        let transfer_repo = TransferRepository::new(self.db.pool().clone());
+        tokio::spawn(async move {
+            match transfer_repo.resolve_interrupted(started_at).await {
+                Ok((0, 0)) => {}
+                Ok((completed, failed)) => eprintln!(
+                    "[Transfer] Resolved interrupted transfers: {} completed, {} failed",
+                    completed, failed
+                ),
+                Err(e) => eprintln!("[Transfer] Failed to resolve interrupted transfers: {:#}", e),
+            }
+        });
+    }
+
+    /// 내부 이체 요청
+    /// Request internal transfer
+    ///
+    /// # Arguments
+    /// * `user_id` - 보내는 사용자 ID
+    /// * `request` - 받는 사용자 / 자산 / 수량 / 메모
+    /// * `idempotency_key` - 멱등 키 (같은 키로 재요청하면 기존 이체 반환)
+    ///
+    /// # Returns
+    /// * `Ok(CreateTransferResult)` - 새 이체 / 기존 이체 / 멱등 키 충돌
+    ///   (엔진 응답을 받지 못한 새 이체는 pending, 원장에 반영되면 completed)
+    /// * `Err` - 검증 실패, 받는 사용자 없음, 잔고 부족 (이 경우 failed 행이 남음)
+    pub async fn create_transfer(
+        &self,
+        user_id: u64,
+        request: &CreateTransferRequest,
+        idempotency_key: &str,
+    ) -> Result<CreateTransferResult> {
+        validate_request(request, idempotency_key)?;
+
+        let user_repo = UserRepository::new(self.db.pool().clone());
+        let recipient = match (request.to_user_id, request.to_email.as_deref()) {
+            (Some(to_user_id), _) => user_repo.get_user_by_id(to_user_id).await?,
+            (None, Some(email)) => user_repo.get_user_by_email(email.trim()).await?,
+            (None, None) => None,
+        };
+        let Some(recipient) = recipient else {
+            bail!("Recipient not found");
+        };
+        if recipient.id == user_id {
+            bail!("Cannot transfer to yourself");
+        }
+
+        let transfer_repo = TransferRepository::new(self.db.pool().clone());
+        let created = transfer_repo
+            .create(
+                user_id,
+                recipient.id,
+                &request.mint,
+                request.amount,
+                request.memo.as_deref(),
+                idempotency_key,
+            )
+            .await?;
+
+        let Some(mut transfer) = created else {
+            let Some(existing) = transfer_repo.get_by_idempotency_key(user_id, idempotency_key).await? else {
+                bail!("Transfer with this idempotency key disappeared");
+            };
+            if !is_same_request(&existing, recipient.id, request) {
+                return Ok(CreateTransferResult::KeyReused);
+            }
+            return Ok(CreateTransferResult::Replayed(existing));
+        };
+
+        let result = self
+            .engine
+            .lock()
+            .await
+            .transfer_balance(transfer.id, user_id, recipient.id, &transfer.mint, transfer.amount)
+            .await;
+
+        if let Err(e) = result {
+            if e.downcast_ref::<OutcomeUnknown>().is_some() {
+                // 엔진이 나중에 이동할 수 있으므로 실패로 확정하지 않음 (원장으로 확인)
+                let error = format!("{:#}", e);
+                transfer_repo.set_error(transfer.id, &error).await?;
+                self.settle_from_ledger(transfer.id);
+                transfer.error = Some(error);
+                eprintln!(
+                    "[Transfer] Outcome of transfer {} is unknown, settling from the ledger: {:#}",
+                    transfer.id, e
+                );
+                return Ok(CreateTransferResult::Created(transfer));
+            }
+            transfer_repo.mark_failed(transfer.id, &format!("{:#}", e)).await?;
+            return Err(e.context("Failed to transfer balance"));
+        }
+
+        transfer_repo.mark_completed(transfer.id).await?;
+        transfer.status = TransferStatus::Completed;
+        transfer.completed_at = Some(Utc::now());
+        eprintln!(
+            "[Transfer] {} {} from user {} to user {} (transfer={})",
+            transfer.amount, transfer.mint, user_id, recipient.id, transfer.id
+        );
+        Ok(CreateTransferResult::Created(transfer))
+    }
+
+    /// 결과를 알 수 없는 이체를 원장으로 확인 (분개가 생기면 completed, 끝까지 없으면 pending 유지)
+    fn settle_from_ledger(&self, transfer_id: u64) {
+        let transfer_repo = TransferRepository::new(self.db.pool().clone());
+        tokio::spawn(async move {
+            for _ in 0..SETTLE_ATTEMPTS {
+                tokio::time::sleep(SETTLE_INTERVAL).await;
+                match transfer_repo.complete_if_posted(transfer_id).await {
+                    Ok(true) => {
+                        eprintln!("[Transfer] Transfer {} settled from the ledger", transfer_id);
+                        return;
+                    }
+                    Ok(false) => {}
+                    Err(e) => eprintln!("[Transfer] Failed to settle transfer {}: {:#}", transfer_id, e),
+                }
+            }
+            eprintln!(
+                "[Transfer] Transfer {} is still pending without a ledger entry, manual review required",
+                transfer_id
+            );
+        });
+    }
+
+    /// 사용자 이체 목록 (보낸/받은 이체, 최신순)
+    pub async fn list_transfers(
+        &self,
+        user_id: u64,
+        direction: Option<TransferDirection>,
+        mint: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Transfer>> {
+        let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT).clamp(1, MAX_LIST_LIMIT);
+        TransferRepository::new(self.db.pool().clone())
+            .list_by_user(user_id, direction, mint, limit)
+            .await
+    }
+
+    /// 이체 조회 (보내거나 받은 사용자만, 아니면 None)
+    pub async fn get_transfer(&self, user_id: u64, transfer_id: u64) -> Result<Option<Transfer>> {
+        let transfer = TransferRepository::new(self.db.pool().clone())
+            .get_by_id(transfer_id)
+            .await?;
+        Ok(transfer.filter(|transfer| transfer.from_user_id == user_id || transfer.to_user_id == user_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(amount: Decimal) -> CreateTransferRequest {
+        CreateTransferRequest {
+            to_user_id: Some(2),
+            to_email: None,
+            mint: "USDT".to_string(),
+            amount,
+            memo: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// 테스트: 받는 사용자 / 수량 / 멱등 키 검증
+    #[test]
+    fn test_validate_request() {
+        assert!(validate_request(&request(Decimal::new(2505, 1)), "key-1").is_ok());
+
+        assert!(validate_request(&request(Decimal::ZERO), "key-1").is_err());
+        assert!(validate_request(&request(Decimal::new(-1, 0)), "key-1").is_err());
+        assert!(validate_request(&request(Decimal::new(1, 10)), "key-1").is_err());
+
+        let mut both = request(Decimal::ONE);
+        both.to_email = Some("desk-b@example.com".to_string());
+        assert!(validate_request(&both, "key-1").is_err());
+        let mut neither = request(Decimal::ONE);
+        neither.to_user_id = None;
+        assert!(validate_request(&neither, "key-1").is_err());
+
+        assert!(validate_request(&request(Decimal::ONE), "").is_err());
+        assert!(validate_request(&request(Decimal::ONE), "has space").is_err());
+        assert!(validate_request(&request(Decimal::ONE), &"k".repeat(MAX_IDEMPOTENCY_KEY_LEN + 1)).is_err());
+    }
+
+    /// 테스트: 같은 멱등 키 재요청 비교
+    #[test]
+    fn test_is_same_request() {
+        let existing = Transfer {
+            id: 7,
+            from_user_id: 1,
+            to_user_id: 2,
+            mint: "USDT".to_string(),
+            amount: Decimal::new(100, 0),
+            memo: None,
+            idempotency_key: "key-1".to_string(),
+            status: TransferStatus::Completed,
+            error: None,
+            created_at: Utc::now(),
+            completed_at: Some(Utc::now()),
+        };
+
+        // DECIMAL 스케일이 달라도 같은 수량
+        assert!(is_same_request(&existing, 2, &request(Decimal::new(100_000_000_000, 9))));
+        assert!(!is_same_request(&existing, 3, &request(Decimal::new(100, 0))));
+        assert!(!is_same_request(&existing, 2, &request(Decimal::new(101, 0))));
+
+        let mut other_mint = request(Decimal::new(100, 0));
+        other_mint.mint = "SOL".to_string();
+        assert!(!is_same_request(&existing, 2, &other_mint));
+    }
+}
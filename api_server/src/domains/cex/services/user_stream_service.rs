@@ -0,0 +1,541 @@
+// =====================================================
+// UserStreamService - 사용자별 실시간 스트림
+// =====================================================
+// 역할: 엔진 이벤트 버스를 구독하여 사용자별 주문 상태 변화/체결/잔고 변경을 발행
+//
+// 핵심 설계:
+// 1. 사용자별 시퀀스 (1부터, 건너뜀 없음) → 클라이언트가 누락 감지
+// 2. 사용자별 최근 메시지 버퍼 → 재연결 시 `since` 이후부터 재전송 (resume)
+// 3. 버퍼보다 오래된 시퀀스로 재연결하면 `resync_required` → REST로 다시 조회
+// 4. `stream_id` (서비스 시작 시간) → 서버 재시작으로 시퀀스가 초기화된 것을 감지
+//
+// 주문 상태 추적:
+// - 엔진은 메이커 주문의 체결 후 상태를 이벤트로 보내지 않으므로
+//   서비스가 열린 주문(수량/체결 수량)을 직접 추적합니다.
+// - 시작 시 엔진 오더북의 주문으로 초기화, 이후 OrderAccepted/TradeExecuted/취소/만료로 갱신
+// =====================================================
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio::time::{sleep, Duration};
+
+use crate::domains::cex::engine::event_bus::SequencedEvent;
+use crate::domains::cex::engine::runtime::HighPerformanceEngine;
+use crate::domains::cex::engine::types::{EngineEvent, MatchResult, OrderEntry, TradingPair};
+
+/// 사용자별 재전송 버퍼 크기 (이보다 오래된 시퀀스는 resync 필요)
+const USER_REPLAY_CAPACITY: usize = 1024;
+
+/// 브로드캐스트 버퍼 크기 (느린 세션은 Lagged 후 재전송 버퍼로 복구)
+const BROADCAST_CAPACITY: usize = 8192;
+
+/// 이벤트 버스 재구독 대기 시간
+const RESUBSCRIBE_DELAY: Duration = Duration::from_millis(100);
+
+/// 사용자 스트림 메시지 (WebSocket으로 전송되는 JSON)
+/// User stream message
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UserStreamMessage {
+    /// 주문 상태 변경
+    ///
+    /// status: "new" | "partial" | "filled" | "cancelled" | "expired" | "rejected"
+    OrderUpdate {
+        order_id: u64,
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        status: String,
+        /// 주문 정보 (엔진이 알지 못하는 주문의 거부 이벤트는 None)
+        order_type: Option<String>,
+        order_side: Option<String>,
+        price: Option<Decimal>,
+        amount: Option<Decimal>,
+        filled_amount: Decimal,
+        remaining_amount: Decimal,
+        /// 거부 사유 (rejected만)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+
+    /// 체결 (내 주문 기준)
+    Fill {
+        order_id: u64,
+        #[serde(flatten)]
+        trading_pair: TradingPair,
+        /// "buy" or "sell"
+        side: String,
+        /// "maker" or "taker"
+        liquidity: String,
+        price: Decimal,
+        amount: Decimal,
+        quote_amount: Decimal,
+        /// 정산 시 차감한 수수료 (받은 자산 기준: 매수 base, 매도 quote)
+        fee: Decimal,
+        fee_mint: String,
+        buy_order_id: u64,
+        sell_order_id: u64,
+    },
+
+    /// 잔고 변경 (BalanceCache 기준 변경 후 값)
+    Balance {
+        mint: String,
+        available: Decimal,
+        locked: Decimal,
+    },
+}
+
+/// 시퀀스가 부여된 사용자 메시지 (직렬화는 한 번만)
+#[derive(Debug)]
+pub struct UserStreamFrame {
+    /// 대상 사용자
+    pub user_id: u64,
+    /// 사용자별 시퀀스
+    pub seq: u64,
+    /// 직렬화된 메시지 (`seq`, `timestamp` 포함)
+    pub payload: String,
+}
+
+/// 추적 중인 열린 주문
+#[derive(Debug, Clone)]
+struct TrackedOrder {
+    order: OrderEntry,
+    /// 상태 ("new" or "partial")
+    status: &'static str,
+}
+
+impl TrackedOrder {
+    fn is_done(&self) -> bool {
+        match self.order.remaining_quote_amount {
+            Some(remaining_quote) if self.order.order_side == "market" && self.order.order_type == "buy" => {
+                remaining_quote <= Decimal::ZERO
+            }
+            _ => self.order.remaining_amount <= Decimal::ZERO,
+        }
+    }
+}
+
+/// 재전송 결과
+#[derive(Debug, PartialEq)]
+pub enum ResumeResult {
+    /// `since` 이후 메시지 (비어 있을 수 있음)
+    Replay(Vec<String>),
+    /// 버퍼에 없는 시퀀스 → REST로 다시 조회 필요
+    ResyncRequired {
+        /// 현재 마지막 시퀀스
+        last_seq: u64,
+    },
+}
+
+/// 사용자 스트림 상태 (이벤트 → 사용자 메시지 변환)
+#[derive(Debug, Default)]
+pub struct UserStreams {
+    /// 추적 중인 열린 주문 (order_id → 주문)
+    orders: HashMap<u64, TrackedOrder>,
+    /// 현재 처리 중인 테이커 주문 (OrderAccepted 직후의 체결은 이 주문이 테이커)
+    current_taker: Option<u64>,
+    /// 사용자별 마지막 시퀀스
+    seqs: HashMap<u64, u64>,
+    /// 사용자별 재전송 버퍼
+    buffers: HashMap<u64, VecDeque<Arc<UserStreamFrame>>>,
+}
+
+impl UserStreams {
+    /// 열린 주문으로 추적 상태 초기화
+    pub fn reset_orders(&mut self, orders: Vec<OrderEntry>) {
+        self.orders.clear();
+        self.current_taker = None;
+        for order in orders {
+            let status = if order.filled_amount > Decimal::ZERO { "partial" } else { "new" };
+            self.orders.insert(order.id, TrackedOrder { order, status });
+        }
+    }
+
+    /// 엔진 이벤트 적용
+    ///
+    /// # Arguments
+    /// * `event` - 엔진 이벤트
+    ///
+    /// # Returns
+    /// 사용자에게 발행할 프레임들
+    pub fn apply(&mut self, event: &SequencedEvent) -> Vec<Arc<UserStreamFrame>> {
+        let messages = self.messages_for(&event.event);
+        messages
+            .into_iter()
+            .map(|(user_id, message)| self.push(user_id, event.timestamp, message))
+            .collect()
+    }
+
+    /// `since` 이후 메시지 조회
+    ///
+    /// # Arguments
+    /// * `user_id` - 사용자 ID
+    /// * `since` - 클라이언트가 마지막으로 받은 시퀀스
+    pub fn resume(&self, user_id: u64, since: u64) -> ResumeResult {
+        let last_seq = self.last_seq(user_id);
+        if since == last_seq {
+            return ResumeResult::Replay(Vec::new());
+        }
+        if since > last_seq {
+            return ResumeResult::ResyncRequired { last_seq };
+        }
+
+        let buffer = match self.buffers.get(&user_id) {
+            Some(buffer) => buffer,
+            None => return ResumeResult::ResyncRequired { last_seq },
+        };
+        match buffer.front() {
+            Some(first) if first.seq <= since + 1 => ResumeResult::Replay(
+                buffer
+                    .iter()
+                    .filter(|frame| frame.seq > since)
+                    .map(|frame| frame.payload.clone())
+                    .collect(),
+            ),
+            _ => ResumeResult::ResyncRequired { last_seq },
+        }
+    }
+
+    /// 사용자의 마지막 시퀀스 (메시지가 없으면 0)
+    pub fn last_seq(&self, user_id: u64) -> u64 {
+        self.seqs.get(&user_id).copied().unwrap_or(0)
+    }
+
+    fn push(&mut self, user_id: u64, timestamp: i64, message: UserStreamMessage) -> Arc<UserStreamFrame> {
+        let seq = self.seqs.entry(user_id).or_insert(0);
+        *seq += 1;
+        let seq = *seq;
+
+        let mut payload = serde_json::to_value(&message).unwrap_or_default();
+        if let Some(object) = payload.as_object_mut() {
+            object.insert("seq".to_string(), seq.into());
+            object.insert("timestamp".to_string(), timestamp.into());
+        }
+        let frame = Arc::new(UserStreamFrame {
+            user_id,
+            seq,
+            payload: payload.to_string(),
+        });
+
+        let buffer = self.buffers.entry(user_id).or_default();
+        if buffer.len() >= USER_REPLAY_CAPACITY {
+            buffer.pop_front();
+        }
+        buffer.push_back(Arc::clone(&frame));
+        frame
+    }
+
+    fn messages_for(&mut self, event: &EngineEvent) -> Vec<(u64, UserStreamMessage)> {
+        match event {
+            EngineEvent::OrderAccepted { order } => {
+                self.current_taker = Some(order.id);
+                let tracked = TrackedOrder { order: order.clone(), status: "new" };
+                let message = order_update(&tracked.order, "new");
+                self.orders.insert(order.id, tracked);
+                vec![(order.user_id, message)]
+            }
+            EngineEvent::OrderRejected { order_id, user_id, trading_pair, reason } => {
+                self.orders.remove(order_id);
+                vec![(*user_id, UserStreamMessage::OrderUpdate {
+                    order_id: *order_id,
+                    trading_pair: trading_pair.clone(),
+                    status: "rejected".to_string(),
+                    order_type: None,
+                    order_side: None,
+                    price: None,
+                    amount: None,
+                    filled_amount: Decimal::ZERO,
+                    remaining_amount: Decimal::ZERO,
+                    reason: Some(reason.clone()),
+                })]
+            }
+            EngineEvent::OrderCancelled { order_id, user_id, .. } => {
+                self.close_order(*order_id, *user_id, "cancelled")
+            }
+            EngineEvent::OrderExpired { order_id, user_id, .. } => {
+                self.close_order(*order_id, *user_id, "expired")
+            }
+            EngineEvent::TradeExecuted { match_result, fees, .. } => {
+                let mut messages = Vec::new();
+                for (order_id, user_id, side, fee, fee_mint) in [
+                    (match_result.buy_order_id, match_result.buyer_id, "buy", fees.buyer_fee, &match_result.base_mint),
+                    (match_result.sell_order_id, match_result.seller_id, "sell", fees.seller_fee, &match_result.quote_mint),
+                ] {
+                    messages.push((user_id, self.fill(match_result, order_id, side, fee, fee_mint)));
+                    if let Some(update) = self.apply_fill(order_id, match_result) {
+                        messages.push((user_id, update));
+                    }
+                }
+                messages
+            }
+            EngineEvent::BalanceChanged { user_id, mint, available, locked } => {
+                vec![(*user_id, UserStreamMessage::Balance {
+                    mint: mint.clone(),
+                    available: *available,
+                    locked: *locked,
+                })]
+            }
+            EngineEvent::BookLevelChanged { .. } | EngineEvent::BookOrderAdded { .. } => Vec::new(),
+        }
+    }
+
+    fn fill(&self, m: &MatchResult, order_id: u64, side: &str, fee: Decimal, fee_mint: &str) -> UserStreamMessage {
+        let liquidity = if self.current_taker == Some(order_id) { "taker" } else { "maker" };
+        UserStreamMessage::Fill {
+            order_id,
+            trading_pair: TradingPair::new(m.base_mint.clone(), m.quote_mint.clone()),
+            side: side.to_string(),
+            liquidity: liquidity.to_string(),
+            price: m.price,
+            amount: m.amount,
+            quote_amount: m.price * m.amount,
+            fee,
+            fee_mint: fee_mint.to_string(),
+            buy_order_id: m.buy_order_id,
+            sell_order_id: m.sell_order_id,
+        }
+    }
+
+    /// 추적 중인 주문에 체결 반영
+    ///
+    /// # Returns
+    /// 상태 변경 메시지 (추적하지 않는 주문이면 None)
+    fn apply_fill(&mut self, order_id: u64, m: &MatchResult) -> Option<UserStreamMessage> {
+        let tracked = self.orders.get_mut(&order_id)?;
+        tracked.order.filled_amount += m.amount;
+        tracked.order.remaining_amount = (tracked.order.remaining_amount - m.amount).max(Decimal::ZERO);
+        if let Some(remaining_quote) = tracked.order.remaining_quote_amount.as_mut() {
+            *remaining_quote = (*remaining_quote - m.price * m.amount).max(Decimal::ZERO);
+        }
+
+        if tracked.is_done() {
+            let tracked = self.orders.remove(&order_id)?;
+            Some(order_update(&tracked.order, "filled"))
+        } else {
+            tracked.status = "partial";
+            Some(order_update(&tracked.order, "partial"))
+        }
+    }
+
+    fn close_order(&mut self, order_id: u64, user_id: u64, status: &str) -> Vec<(u64, UserStreamMessage)> {
+        match self.orders.remove(&order_id) {
+            Some(tracked) => vec![(user_id, order_update(&tracked.order, status))],
+            None => Vec::new(),
+        }
+    }
+}
+
+fn order_update(order: &OrderEntry, status: &str) -> UserStreamMessage {
+    UserStreamMessage::OrderUpdate {
+        order_id: order.id,
+        trading_pair: TradingPair::new(order.base_mint.clone(), order.quote_mint.clone()),
+        status: status.to_string(),
+        order_type: Some(order.order_type.clone()),
+        order_side: Some(order.order_side.clone()),
+        price: order.price,
+        amount: Some(order.amount),
+        filled_amount: order.filled_amount,
+        remaining_amount: order.remaining_amount,
+        reason: None,
+    }
+}
+
+/// 사용자 스트림 서비스
+/// User stream service
+///
+/// # 사용 흐름
+/// 1. 엔진 시작 후 `start()` 호출
+/// 2. WebSocket 세션은 `subscribe()`로 수신기를 먼저 만들고 `resume()`으로 놓친 메시지 조회
+/// 3. 세션은 자기 user_id의 프레임만 전송
+#[derive(Clone)]
+pub struct UserStreamService {
+    /// 체결 엔진 (이벤트 버스 + 열린 주문)
+    engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>,
+    /// 스트림 상태 (변경과 브로드캐스트를 같은 락 안에서 수행)
+    streams: Arc<Mutex<UserStreams>>,
+    /// 프레임 브로드캐스트
+    sender: broadcast::Sender<Arc<UserStreamFrame>>,
+    /// 스트림 ID (서비스 생성 시간, 밀리초)
+    stream_id: i64,
+}
+
+impl UserStreamService {
+    /// 새 UserStreamService 생성
+    ///
+    /// # Arguments
+    /// * `engine` - 체결 엔진
+    pub fn new(engine: Arc<tokio::sync::Mutex<HighPerformanceEngine>>) -> Self {
+        let (sender, _) = broadcast::channel(BROADCAST_CAPACITY);
+        Self {
+            engine,
+            streams: Arc::new(Mutex::new(UserStreams::default())),
+            sender,
+            stream_id: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+
+    /// 백그라운드 태스크 시작 (엔진 시작 이후 호출)
+    ///
+    /// # 처리 과정
+    /// 1. 이벤트 버스 구독 → 엔진 오더북의 열린 주문으로 추적 상태 초기화
+    /// 2. 이벤트를 사용자 메시지로 변환하여 브로드캐스트 (체결 수수료는 엔진이 정산 시 차감한 금액)
+    /// 3. 이벤트 버스에서 해제되면 재구독 (사용자 시퀀스는 유지)
+    pub fn start(&self) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let mut subscription = {
+                    let engine = service.engine.lock().await;
+                    let subscription = engine.event_bus().subscribe("user_stream");
+                    service.streams.lock().reset_orders(engine.resting_orders());
+                    subscription
+                };
+
+                while let Some(event) = subscription.recv().await {
+                    let mut streams = service.streams.lock();
+                    for frame in streams.apply(&event) {
+                        let _ = service.sender.send(frame);
+                    }
+                }
+
+                eprintln!("[User Stream] Event subscription closed, resubscribing");
+                sleep(RESUBSCRIBE_DELAY).await;
+            }
+        });
+
+        eprintln!("[User Stream] Started (orders, fills, balances)");
+    }
+
+    /// 스트림 ID (서버 재시작 감지용)
+    pub fn stream_id(&self) -> i64 {
+        self.stream_id
+    }
+
+    /// 프레임 수신기 생성 (resume보다 먼저 만들어야 누락이 없음)
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<UserStreamFrame>> {
+        self.sender.subscribe()
+    }
+
+    /// 놓친 메시지 조회
+    ///
+    /// # Arguments
+    /// * `user_id` - 사용자 ID
+    /// * `since` - 클라이언트가 마지막으로 받은 시퀀스 (None이면 현재 시점부터)
+    ///
+    /// # Returns
+    /// (현재 마지막 시퀀스, 재전송 결과)
+    pub fn resume(&self, user_id: u64, since: Option<u64>) -> (u64, ResumeResult) {
+        let streams = self.streams.lock();
+        let last_seq = streams.last_seq(user_id);
+        match since {
+            Some(since) => (last_seq, streams.resume(user_id, since)),
+            None => (last_seq, ResumeResult::Replay(Vec::new())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::cex::engine::types::TradeFees;
+    use chrono::Utc;
+
+    fn order(id: u64, user_id: u64, order_type: &str, amount: i64) -> OrderEntry {
+        OrderEntry {
+            id,
+            user_id,
+            order_type: order_type.to_string(),
+            order_side: "limit".to_string(),
+            base_mint: "SOL".to_string(),
+            quote_mint: "USDT".to_string(),
+            price: Some(Decimal::new(100, 0)),
+            amount: Decimal::new(amount, 0),
+            quote_amount: None,
+            filled_amount: Decimal::ZERO,
+            remaining_amount: Decimal::new(amount, 0),
+            remaining_quote_amount: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn sequenced(event: EngineEvent) -> SequencedEvent {
+        SequencedEvent { seq: 0, timestamp: 1_700_000_000_000, event }
+    }
+
+    fn trade(buy_order_id: u64, sell_order_id: u64, amount: i64) -> EngineEvent {
+        EngineEvent::TradeExecuted {
+            match_result: MatchResult {
+                buy_order_id,
+                sell_order_id,
+                buyer_id: 2,
+                seller_id: 1,
+                price: Decimal::new(100, 0),
+                amount: Decimal::new(amount, 0),
+                base_mint: "SOL".to_string(),
+                quote_mint: "USDT".to_string(),
+            },
+            buyer_id: 2,
+            seller_id: 1,
+            // 매수 taker 0.1% (base), 매도 maker 0.05% (quote)
+            fees: TradeFees {
+                buyer_fee: Decimal::new(amount, 3),
+                seller_fee: Decimal::new(amount * 500, 4),
+            },
+        }
+    }
+
+    fn payload(frame: &UserStreamFrame) -> serde_json::Value {
+        serde_json::from_str(&frame.payload).unwrap()
+    }
+
+    #[test]
+    fn fills_update_maker_and_taker_orders() {
+        let mut streams = UserStreams::default();
+        // 메이커 매도 주문은 시작 시 오더북에서 로드됨
+        streams.reset_orders(vec![order(10, 1, "sell", 3)]);
+
+        streams.apply(&sequenced(EngineEvent::OrderAccepted { order: order(11, 2, "buy", 1) }));
+        let frames = streams.apply(&sequenced(trade(11, 10, 1)));
+
+        let buyer: Vec<_> = frames.iter().filter(|f| f.user_id == 2).map(|f| payload(f)).collect();
+        assert_eq!(buyer[0]["type"], "fill");
+        assert_eq!(buyer[0]["liquidity"], "taker");
+        assert_eq!(buyer[0]["fee"], "0.001");
+        assert_eq!(buyer[0]["fee_mint"], "SOL");
+        assert_eq!(buyer[1]["status"], "filled");
+        assert_eq!(buyer[1]["seq"], 3);
+
+        let seller: Vec<_> = frames.iter().filter(|f| f.user_id == 1).map(|f| payload(f)).collect();
+        assert_eq!(seller[0]["liquidity"], "maker");
+        assert_eq!(seller[0]["fee"], "0.0500");
+        assert_eq!(seller[0]["fee_mint"], "USDT");
+        assert_eq!(seller[1]["status"], "partial");
+        assert_eq!(seller[1]["remaining_amount"], "2");
+    }
+
+    #[test]
+    fn resume_replays_buffer_or_requires_resync() {
+        let mut streams = UserStreams::default();
+        for mint in 0..(USER_REPLAY_CAPACITY + 5) {
+            streams.apply(&sequenced(EngineEvent::BalanceChanged {
+                user_id: 7,
+                mint: format!("MINT{}", mint),
+                available: Decimal::ONE,
+                locked: Decimal::ZERO,
+            }));
+        }
+        let last_seq = streams.last_seq(7);
+        assert_eq!(last_seq, USER_REPLAY_CAPACITY as u64 + 5);
+
+        match streams.resume(7, last_seq - 2) {
+            ResumeResult::Replay(payloads) => assert_eq!(payloads.len(), 2),
+            other => panic!("unexpected resume result: {:?}", other),
+        }
+        assert_eq!(streams.resume(7, 1), ResumeResult::ResyncRequired { last_seq });
+        assert_eq!(streams.resume(7, last_seq), ResumeResult::Replay(Vec::new()));
+        assert_eq!(streams.resume(8, 3), ResumeResult::ResyncRequired { last_seq: 0 });
+    }
+}
@@ -0,0 +1,637 @@
+use crate::shared::clients::SolanaClient;
+use crate::shared::database::{Database, WithdrawalRepository};
+use crate::domains::cex::models::withdrawal::{CreateWithdrawalRequest, Withdrawal, WithdrawalStatus};
+use crate::domains::cex::engine::{Engine, HoldAction, OutcomeUnknown, runtime::HighPerformanceEngine};
+use crate::domains::cex::services::deposit_service::{
+    DepositAsset, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, associated_token_address,
+};
+use crate::domains::wallet::services::WalletService;
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{Duration as ChronoDuration, Utc};
+use rust_decimal::Decimal;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::{keypair::Keypair, Signer},
+    system_instruction,
+    system_program,
+    transaction::Transaction,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::interval;
+
+// =====================================================
+// WithdrawalService
+// =====================================================
+// 역할: CEX 잔고 → 외부 Solana 주소 온체인 출금
+//
+// 처리 과정:
+// 1. 요청: 자산/수량/받는 주소 검증 → requested 행 생성
+//    → 엔진 잔고 보류 (BalanceCommand::UpdateHold, Place) → held_at 기록
+//    → 엔진 응답을 받지 못하면 (OutcomeUnknown) requested + held_at 없음 + error 유지,
+//      원장에 보류 분개가 생기면 held_at 기록 (끝까지 없으면 수동 확인)
+// 2. 승인 (POLL_INTERVAL마다): 자동 승인 한도 이하면 approved (초과분은 수동 승인 대기)
+// 3. 전송: 핫월렛으로 지급 트랜잭션 서명 → 서명/만료 높이 저장 (broadcast) → 전송
+// 4. 확정: finalized 성공 → confirmed (보류 소진)
+//          실패 / 만료 (블록 높이 > last_valid_block_height) → failed (보류 해제)
+//
+// 상태 변경 순서:
+// - DB 조건부 UPDATE 먼저 → 성공한 경우에만 엔진 보류 전이 (모든 전이는 WAL 기록)
+// - 서명은 전송 전에 저장하므로 재시작해도 같은 출금을 두 번 지급하지 않음
+// - 엔진 전이가 실패하면 상태는 유지하고 error 기록 (수동 확인)
+//
+// 설정 (환경 변수):
+// - WITHDRAWAL_HOT_WALLET_KEY: 핫월렛 키 (base64, 없으면 출금 비활성화)
+// - WITHDRAWAL_AUTO_APPROVE_MAX="SOL:10,USDT:1000": 자산별 자동 승인 한도 (없는 자산은 모두 수동 승인)
+// - 출금 가능 자산은 입금 자산 설정(DEPOSIT_SPL_TOKENS)과 동일
+// =====================================================
+
+/// 핫월렛 키 환경 변수
+const HOT_WALLET_KEY_ENV: &str = "WITHDRAWAL_HOT_WALLET_KEY";
+
+/// 자동 승인 한도 환경 변수
+const AUTO_APPROVE_MAX_ENV: &str = "WITHDRAWAL_AUTO_APPROVE_MAX";
+
+/// 처리 간격
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 한 번에 처리할 출금 수 (상태별)
+const BATCH_SIZE: i64 = 100;
+
+/// 보류 기록 없이 이 시간이 지난 requested 출금은 수동 확인 대상
+const UNHELD_REVIEW_MINUTES: i64 = 1;
+
+/// 결과를 알 수 없는 보류의 원장 확인 간격 / 횟수
+const SETTLE_INTERVAL: Duration = Duration::from_secs(1);
+const SETTLE_ATTEMPTS: u32 = 30;
+
+/// 출금 목록 기본/최대 개수
+const DEFAULT_LIST_LIMIT: i64 = 50;
+const MAX_LIST_LIMIT: i64 = 200;
+
+/// Associated Token Account 생성 (이미 있으면 무시) 명령 번호
+const CREATE_IDEMPOTENT_INSTRUCTION: u8 = 1;
+
+/// SPL Token TransferChecked 명령 번호
+const TRANSFER_CHECKED_INSTRUCTION: u8 = 12;
+
+/// "심볼:한도" 목록 파싱 (쉼표 구분)
+fn parse_auto_approve_limits(value: &str) -> Result<HashMap<String, Decimal>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let Some((mint, limit)) = entry.split_once(':') else {
+                bail!("Invalid {} entry (expected SYMBOL:AMOUNT): {}", AUTO_APPROVE_MAX_ENV, entry);
+            };
+            let limit = Decimal::from_str(limit).context(format!("Invalid amount: {}", limit))?;
+            Ok((mint.to_string(), limit))
+        })
+        .collect()
+}
+
+/// 소수 수량 → 온체인 최소 단위 (lamports / 토큰 최소 단위)
+///
+/// # Returns
+/// 자산 소수 자릿수보다 정밀하거나 0 이하 / 범위 초과면 에러
+fn to_raw_amount(amount: Decimal, decimals: u32) -> Result<u64> {
+    if amount <= Decimal::ZERO {
+        bail!("Amount must be positive");
+    }
+    if amount.normalize().scale() > decimals {
+        bail!("Amount has more than {} decimal places", decimals);
+    }
+    let raw = amount
+        .checked_mul(Decimal::from(10u64.pow(decimals)))
+        .ok_or_else(|| anyhow!("Amount is too large"))?;
+    u64::try_from(raw).map_err(|_| anyhow!("Amount is too large"))
+}
+
+/// 받는 주소 검증 (지갑 주소만 허용, 핫월렛 제외)
+fn parse_destination(destination: &str, hot_wallet: &Pubkey) -> Result<Pubkey> {
+    let pubkey = Pubkey::from_str(destination.trim())
+        .map_err(|_| anyhow!("Invalid destination address: {}", destination))?;
+    if !pubkey.is_on_curve() {
+        bail!("Destination must be a wallet address, not a program-derived account");
+    }
+    if pubkey == *hot_wallet {
+        bail!("Destination must not be the exchange hot wallet");
+    }
+    Ok(pubkey)
+}
+
+/// 지급 명령 생성
+///
+/// - SOL: System Program 전송
+/// - SPL 토큰: 받는 지갑 토큰 계정 생성 (이미 있으면 무시) + TransferChecked
+fn payout_instructions(
+    asset: &DepositAsset,
+    hot_wallet: &Pubkey,
+    destination: &Pubkey,
+    raw_amount: u64,
+) -> Vec<Instruction> {
+    let Some(token_mint) = asset.token_mint else {
+        return vec![system_instruction::transfer(hot_wallet, destination, raw_amount)];
+    };
+
+    let source = associated_token_address(hot_wallet, &token_mint);
+    let destination_account = associated_token_address(destination, &token_mint);
+
+    let create_account = Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*hot_wallet, true),
+            AccountMeta::new(destination_account, false),
+            AccountMeta::new_readonly(*destination, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: vec![CREATE_IDEMPOTENT_INSTRUCTION],
+    };
+
+    let mut data = Vec::with_capacity(10);
+    data.push(TRANSFER_CHECKED_INSTRUCTION);
+    data.extend_from_slice(&raw_amount.to_le_bytes());
+    data.push(asset.decimals as u8);
+    let transfer = Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(source, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(destination_account, false),
+            AccountMeta::new_readonly(*hot_wallet, true),
+        ],
+        data,
+    };
+
+    vec![create_account, transfer]
+}
+
+/// 출금 서비스
+/// Withdrawal Service
+#[derive(Clone)]
+pub struct WithdrawalService {
+    db: Database,
+    /// 잔고 보류 (BalanceCommand::UpdateHold)
+    engine: Arc<Mutex<HighPerformanceEngine>>,
+    solana_client: SolanaClient,
+    /// 출금 가능 자산 (입금 자산과 동일)
+    assets: Arc<Vec<DepositAsset>>,
+    /// 지급 지갑 (없으면 출금 비활성화)
+    hot_wallet: Option<Arc<Keypair>>,
+    /// 자산별 자동 승인 한도
+    auto_approve_max: Arc<HashMap<String, Decimal>>,
+}
+
+impl WithdrawalService {
+    /// 새 WithdrawalService 생성
+    ///
+    /// # Arguments
+    /// * `db` - 데이터베이스 연결
+    /// * `engine` - 체결 엔진 (잔고 채널)
+    pub fn new(db: Database, engine: Arc<Mutex<HighPerformanceEngine>>) -> Result<Self> {
+        let hot_wallet = match std::env::var(HOT_WALLET_KEY_ENV) {
+            Ok(key) => Some(Arc::new(
+                WalletService::keypair_from_base64(&key)
+                    .map_err(|e| anyhow!("Invalid {}: {}", HOT_WALLET_KEY_ENV, e))?,
+            )),
+            Err(_) => None,
+        };
+        let auto_approve_max = match std::env::var(AUTO_APPROVE_MAX_ENV) {
+            Ok(value) => parse_auto_approve_limits(&value)?,
+            Err(_) => HashMap::new(),
+        };
+
+        Ok(Self {
+            solana_client: SolanaClient::new()?,
+            assets: Arc::new(DepositAsset::from_env()?),
+            hot_wallet,
+            auto_approve_max: Arc::new(auto_approve_max),
+            db,
+            engine,
+        })
+    }
+
+    /// 출금 처리 시작
+    ///
+    /// # 처리 과정
+    /// 1. 보류 기록 없이 남은 requested 출금 경고 (수동 확인 필요)
+    /// 2. POLL_INTERVAL마다 자동 승인 → 지급 전송 → 확정 확인
+    pub fn start(&self) {
+        let Some(hot_wallet) = &self.hot_wallet else {
+            eprintln!("[Withdrawal] {} is not set, withdrawals are disabled", HOT_WALLET_KEY_ENV);
+            return;
+        };
+
+        let service = self.clone();
+        tokio::spawn(async move {
+            let withdrawal_repo = WithdrawalRepository::new(service.db.pool().clone());
+            let review_before = Utc::now() - ChronoDuration::minutes(UNHELD_REVIEW_MINUTES);
+            match withdrawal_repo.get_unheld(review_before).await {
+                Ok(unheld) => {
+                    for withdrawal in unheld {
+                        eprintln!(
+                            "[Withdrawal] Withdrawal {} ({} {}) has no recorded balance hold, manual review required",
+                            withdrawal.id, withdrawal.amount, withdrawal.mint
+                        );
+                    }
+                }
+                Err(e) => eprintln!("[Withdrawal] Failed to load unheld withdrawals: {:#}", e),
+            }
+
+            let mut ticker = interval(POLL_INTERVAL);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = service.auto_approve().await {
+                    eprintln!("[Withdrawal] Auto approval failed: {:#}", e);
+                }
+                if let Err(e) = service.broadcast_approved().await {
+                    eprintln!("[Withdrawal] Broadcast failed: {:#}", e);
+                }
+                if let Err(e) = service.process_broadcast().await {
+                    eprintln!("[Withdrawal] Confirmation check failed: {:#}", e);
+                }
+            }
+        });
+
+        eprintln!("[Withdrawal] Started (hot wallet: {})", hot_wallet.pubkey());
+    }
+
+    /// 출금 요청
+    /// Request withdrawal
+    ///
+    /// # Returns
+    /// * `Ok(Withdrawal)` - requested 상태 (잔고 보류됨, 엔진 응답을 받지 못했으면 held_at 없음 + error)
+    /// * `Err` - 검증 실패, 잔고 부족 (이 경우 failed 행이 남음)
+    pub async fn create_withdrawal(&self, user_id: u64, request: &CreateWithdrawalRequest) -> Result<Withdrawal> {
+        let Some(hot_wallet) = &self.hot_wallet else {
+            bail!("Withdrawals are disabled");
+        };
+        let Some(asset) = self.assets.iter().find(|asset| asset.mint == request.mint) else {
+            bail!("Withdrawals are not supported for {}", request.mint);
+        };
+        to_raw_amount(request.amount, asset.decimals)?;
+        let destination = parse_destination(&request.destination, &hot_wallet.pubkey())?;
+
+        let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+        let token_mint = asset.token_mint.map(|mint| mint.to_string());
+        let mut withdrawal = withdrawal_repo
+            .create(user_id, &asset.mint, token_mint.as_deref(), request.amount, &destination.to_string())
+            .await?;
+
+        let result = self
+            .engine
+            .lock()
+            .await
+            .update_hold(withdrawal.id, user_id, &asset.mint, request.amount, HoldAction::Place, "withdrawal:requested")
+            .await;
+
+        if let Err(e) = result {
+            if e.downcast_ref::<OutcomeUnknown>().is_some() {
+                // 엔진이 나중에 보류할 수 있으므로 실패로 확정하지 않음 (보류 해제 경로가 없어짐)
+                let error = format!("{:#}", e);
+                withdrawal_repo.set_error(withdrawal.id, &error).await?;
+                self.settle_hold_from_ledger(withdrawal.id);
+                withdrawal.error = Some(error);
+                eprintln!(
+                    "[Withdrawal] Hold outcome of withdrawal {} is unknown, settling from the ledger: {:#}",
+                    withdrawal.id, e
+                );
+                return Ok(withdrawal);
+            }
+            withdrawal_repo
+                .mark_failed(withdrawal.id, WithdrawalStatus::Requested, &format!("{:#}", e))
+                .await?;
+            return Err(e.context("Failed to hold balance for withdrawal"));
+        }
+
+        withdrawal_repo.mark_held(withdrawal.id).await?;
+        withdrawal.held_at = Some(Utc::now());
+        eprintln!(
+            "[Withdrawal] Requested {} {} by user {} to {} (withdrawal={})",
+            withdrawal.amount, withdrawal.mint, user_id, withdrawal.destination, withdrawal.id
+        );
+        Ok(withdrawal)
+    }
+
+    /// 결과를 알 수 없는 보류를 원장으로 확인 (분개가 생기면 held_at 기록, 끝까지 없으면 수동 확인)
+    fn settle_hold_from_ledger(&self, withdrawal_id: u64) {
+        let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+        tokio::spawn(async move {
+            for _ in 0..SETTLE_ATTEMPTS {
+                tokio::time::sleep(SETTLE_INTERVAL).await;
+                match withdrawal_repo.mark_held_if_posted(withdrawal_id).await {
+                    Ok(true) => {
+                        eprintln!("[Withdrawal] Hold of withdrawal {} settled from the ledger", withdrawal_id);
+                        return;
+                    }
+                    Ok(false) => {}
+                    Err(e) => eprintln!("[Withdrawal] Failed to settle hold of withdrawal {}: {:#}", withdrawal_id, e),
+                }
+            }
+            eprintln!(
+                "[Withdrawal] Withdrawal {} has no recorded balance hold, manual review required",
+                withdrawal_id
+            );
+        });
+    }
+
+    /// 사용자 출금 목록 (최신순)
+    pub async fn list_withdrawals(&self, user_id: u64, mint: Option<&str>, limit: Option<i64>) -> Result<Vec<Withdrawal>> {
+        let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT).clamp(1, MAX_LIST_LIMIT);
+        WithdrawalRepository::new(self.db.pool().clone())
+            .list_by_user(user_id, mint, limit)
+            .await
+    }
+
+    /// 사용자 출금 조회 (다른 사용자 출금은 None)
+    pub async fn get_withdrawal(&self, user_id: u64, withdrawal_id: u64) -> Result<Option<Withdrawal>> {
+        let withdrawal = WithdrawalRepository::new(self.db.pool().clone())
+            .get_by_id(withdrawal_id)
+            .await?;
+        Ok(withdrawal.filter(|withdrawal| withdrawal.user_id == user_id))
+    }
+
+    /// 출금 승인 (requested → approved)
+    ///
+    /// # Returns
+    /// 승인했으면 true (requested 상태가 아니면 false)
+    pub async fn approve(&self, withdrawal_id: u64) -> Result<bool> {
+        let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+        let Some(withdrawal) = withdrawal_repo.get_by_id(withdrawal_id).await? else {
+            return Ok(false);
+        };
+        if !withdrawal_repo.approve(withdrawal_id).await? {
+            return Ok(false);
+        }
+
+        self.apply_hold(&withdrawal_repo, &withdrawal, HoldAction::Keep, "withdrawal:approved").await?;
+        eprintln!("[Withdrawal] Approved withdrawal {}", withdrawal_id);
+        Ok(true)
+    }
+
+    /// 출금 거절 (requested → failed, 보류 해제)
+    ///
+    /// # Returns
+    /// 거절했으면 true (requested 상태가 아니면 false)
+    pub async fn reject(&self, withdrawal_id: u64, reason: &str) -> Result<bool> {
+        let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+        let Some(withdrawal) = withdrawal_repo.get_by_id(withdrawal_id).await? else {
+            return Ok(false);
+        };
+        if withdrawal.held_at.is_none()
+            || !withdrawal_repo
+                .mark_failed(withdrawal_id, WithdrawalStatus::Requested, &format!("Rejected: {}", reason))
+                .await?
+        {
+            return Ok(false);
+        }
+
+        self.apply_hold(&withdrawal_repo, &withdrawal, HoldAction::Release, "withdrawal:rejected").await?;
+        eprintln!("[Withdrawal] Rejected withdrawal {}: {}", withdrawal_id, reason);
+        Ok(true)
+    }
+
+    /// 자동 승인 한도 이하 requested 출금 승인
+    async fn auto_approve(&self) -> Result<()> {
+        let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+        let withdrawals = withdrawal_repo.get_by_status(WithdrawalStatus::Requested, BATCH_SIZE).await?;
+
+        for withdrawal in withdrawals {
+            let within_limit = self
+                .auto_approve_max
+                .get(&withdrawal.mint)
+                .is_some_and(|limit| withdrawal.amount <= *limit);
+            if within_limit {
+                self.approve(withdrawal.id).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// approved 출금 지급 트랜잭션 서명 → 저장 (broadcast) → 전송
+    async fn broadcast_approved(&self) -> Result<()> {
+        let Some(hot_wallet) = &self.hot_wallet else {
+            return Ok(());
+        };
+        let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+        let withdrawals = withdrawal_repo.get_by_status(WithdrawalStatus::Approved, BATCH_SIZE).await?;
+
+        for withdrawal in withdrawals {
+            let instructions = match self.build_payout(&withdrawal, &hot_wallet.pubkey()) {
+                Ok(instructions) => instructions,
+                Err(e) => {
+                    // 요청 이후 자산 설정이 바뀐 경우 등: 지급하지 않고 실패 처리
+                    if withdrawal_repo
+                        .mark_failed(withdrawal.id, WithdrawalStatus::Approved, &format!("{:#}", e))
+                        .await?
+                    {
+                        self.apply_hold(&withdrawal_repo, &withdrawal, HoldAction::Release, "withdrawal:failed").await?;
+                    }
+                    continue;
+                }
+            };
+
+            let (blockhash, last_valid_block_height) = self.solana_client.get_latest_blockhash_with_height().await?;
+            let transaction = Transaction::new_signed_with_payer(
+                &instructions,
+                Some(&hot_wallet.pubkey()),
+                &[hot_wallet.as_ref()],
+                blockhash,
+            );
+            let signature = transaction.signatures[0].to_string();
+
+            // 전송 전에 서명 저장 (재시작 시 같은 출금을 다시 지급하지 않도록)
+            if !withdrawal_repo.mark_broadcast(withdrawal.id, &signature, last_valid_block_height).await? {
+                continue;
+            }
+            self.apply_hold(&withdrawal_repo, &withdrawal, HoldAction::Keep, "withdrawal:broadcast").await?;
+
+            match self.solana_client.send_transaction(&transaction).await {
+                Ok(_) => eprintln!(
+                    "[Withdrawal] Broadcast withdrawal {} ({} {} to {}, signature={})",
+                    withdrawal.id, withdrawal.amount, withdrawal.mint, withdrawal.destination, signature
+                ),
+                Err(e) => {
+                    // 전송 실패: 블록해시 만료 후 failed 처리됨
+                    eprintln!("[Withdrawal] Failed to send withdrawal {}: {:#}", withdrawal.id, e);
+                    withdrawal_repo.set_error(withdrawal.id, &format!("Send failed: {:#}", e)).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// broadcast 출금 확정 확인
+    ///
+    /// - finalized 성공 → confirmed (보류 소진)
+    /// - 실패 / 만료 → failed (보류 해제)
+    async fn process_broadcast(&self) -> Result<()> {
+        let withdrawal_repo = WithdrawalRepository::new(self.db.pool().clone());
+        let withdrawals = withdrawal_repo.get_by_status(WithdrawalStatus::Broadcast, BATCH_SIZE).await?;
+        if withdrawals.is_empty() {
+            return Ok(());
+        }
+
+        let signatures: Vec<String> = withdrawals
+            .iter()
+            .map(|withdrawal| withdrawal.signature.clone().unwrap_or_default())
+            .collect();
+        // 오래 멈춰 있던 경우에도 이미 처리된 트랜잭션을 만료로 오인하지 않도록 원장 기록까지 조회
+        let states = self.solana_client.get_signature_states(&signatures, true).await?;
+        let block_height = self.solana_client.get_block_height().await?;
+
+        for (withdrawal, state) in withdrawals.iter().zip(states) {
+            match state {
+                Some(state) if state.failed => {
+                    self.fail_broadcast(&withdrawal_repo, withdrawal, "Transaction failed").await?;
+                }
+                Some(state) if state.finalized => {
+                    if withdrawal_repo.mark_confirmed(withdrawal.id).await? {
+                        self.apply_hold(&withdrawal_repo, withdrawal, HoldAction::Consume, "withdrawal:confirmed").await?;
+                        eprintln!(
+                            "[Withdrawal] Confirmed withdrawal {} ({} {})",
+                            withdrawal.id, withdrawal.amount, withdrawal.mint
+                        );
+                    }
+                }
+                Some(_) => {}
+                None if withdrawal
+                    .last_valid_block_height
+                    .is_some_and(|last_valid| block_height > last_valid) =>
+                {
+                    self.fail_broadcast(&withdrawal_repo, withdrawal, "Transaction expired").await?;
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// broadcast → failed, 보류 해제
+    async fn fail_broadcast(
+        &self,
+        withdrawal_repo: &WithdrawalRepository,
+        withdrawal: &Withdrawal,
+        error: &str,
+    ) -> Result<()> {
+        if withdrawal_repo.mark_failed(withdrawal.id, WithdrawalStatus::Broadcast, error).await? {
+            self.apply_hold(withdrawal_repo, withdrawal, HoldAction::Release, "withdrawal:failed").await?;
+            eprintln!("[Withdrawal] Withdrawal {} failed: {}", withdrawal.id, error);
+        }
+        Ok(())
+    }
+
+    /// 지급 명령 생성 (자산 설정 확인)
+    fn build_payout(&self, withdrawal: &Withdrawal, hot_wallet: &Pubkey) -> Result<Vec<Instruction>> {
+        let asset = self
+            .assets
+            .iter()
+            .find(|asset| asset.mint == withdrawal.mint)
+            .ok_or_else(|| anyhow!("Withdrawals are not supported for {}", withdrawal.mint))?;
+        if asset.token_mint.map(|mint| mint.to_string()) != withdrawal.token_mint {
+            bail!("Token mint of {} has changed since the request", withdrawal.mint);
+        }
+
+        let raw_amount = to_raw_amount(withdrawal.amount, asset.decimals)?;
+        let destination = parse_destination(&withdrawal.destination, hot_wallet)?;
+        Ok(payout_instructions(asset, hot_wallet, &destination, raw_amount))
+    }
+
+    /// 엔진 보류 전이 (실패 시 상태 유지 + error 기록, 수동 확인)
+    async fn apply_hold(
+        &self,
+        withdrawal_repo: &WithdrawalRepository,
+        withdrawal: &Withdrawal,
+        action: HoldAction,
+        reason: &str,
+    ) -> Result<()> {
+        let result = self
+            .engine
+            .lock()
+            .await
+            .update_hold(withdrawal.id, withdrawal.user_id, &withdrawal.mint, withdrawal.amount, action, reason)
+            .await;
+
+        if let Err(e) = result {
+            eprintln!(
+                "[Withdrawal] Failed to apply {} for withdrawal {}, manual review required: {:#}",
+                reason, withdrawal.id, e
+            );
+            withdrawal_repo
+                .set_error(withdrawal.id, &format!("Hold {} failed: {:#}", action.as_str(), e))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOT_WALLET: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+    const DESTINATION: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+    /// 테스트: 수량 / 받는 주소 검증
+    #[test]
+    fn test_validation() {
+        let hot_wallet = Pubkey::from_str(HOT_WALLET).unwrap();
+
+        assert_eq!(to_raw_amount(Decimal::new(15, 1), 9).unwrap(), 1_500_000_000);
+        assert_eq!(to_raw_amount(Decimal::new(1_500_000, 6), 6).unwrap(), 1_500_000);
+        assert!(to_raw_amount(Decimal::new(1234567, 7), 6).is_err());
+        assert!(to_raw_amount(Decimal::ZERO, 9).is_err());
+        assert!(to_raw_amount(Decimal::new(-1, 0), 9).is_err());
+
+        assert!(parse_destination(DESTINATION, &hot_wallet).is_ok());
+        assert!(parse_destination(HOT_WALLET, &hot_wallet).is_err());
+        assert!(parse_destination("not-an-address", &hot_wallet).is_err());
+        // PDA (Associated Token Account)는 지갑 주소가 아님
+        let token_account = associated_token_address(&hot_wallet, &Pubkey::from_str(USDT_MINT).unwrap());
+        assert!(parse_destination(&token_account.to_string(), &hot_wallet).is_err());
+
+        let limits = parse_auto_approve_limits("SOL:10, USDT:1000.5").unwrap();
+        assert_eq!(limits["SOL"], Decimal::new(10, 0));
+        assert_eq!(limits["USDT"], Decimal::new(10005, 1));
+        assert!(parse_auto_approve_limits("SOL").is_err());
+    }
+
+    /// 테스트: 지급 명령 (SOL 전송 / SPL 토큰 계정 생성 + TransferChecked)
+    #[test]
+    fn test_payout_instructions() {
+        let hot_wallet = Pubkey::from_str(HOT_WALLET).unwrap();
+        let destination = Pubkey::from_str(DESTINATION).unwrap();
+        let token_mint = Pubkey::from_str(USDT_MINT).unwrap();
+
+        let sol = DepositAsset { mint: "SOL".to_string(), token_mint: None, decimals: 9 };
+        let instructions = payout_instructions(&sol, &hot_wallet, &destination, 5);
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].program_id, system_program::id());
+
+        let usdt = DepositAsset { mint: "USDT".to_string(), token_mint: Some(token_mint), decimals: 6 };
+        let instructions = payout_instructions(&usdt, &hot_wallet, &destination, 1_500_000);
+        assert_eq!(instructions.len(), 2);
+
+        let destination_account = associated_token_address(&destination, &token_mint);
+        assert_eq!(instructions[0].program_id, ASSOCIATED_TOKEN_PROGRAM_ID);
+        assert_eq!(instructions[0].data, vec![CREATE_IDEMPOTENT_INSTRUCTION]);
+        assert_eq!(instructions[0].accounts[1].pubkey, destination_account);
+
+        let transfer = &instructions[1];
+        assert_eq!(transfer.program_id, TOKEN_PROGRAM_ID);
+        assert_eq!(transfer.data[0], TRANSFER_CHECKED_INSTRUCTION);
+        assert_eq!(&transfer.data[1..9], &1_500_000u64.to_le_bytes());
+        assert_eq!(transfer.data[9], 6);
+        assert_eq!(transfer.accounts[0].pubkey, associated_token_address(&hot_wallet, &token_mint));
+        assert_eq!(transfer.accounts[2].pubkey, destination_account);
+        assert!(transfer.accounts[3].is_signer);
+    }
+}
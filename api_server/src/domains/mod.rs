@@ -4,4 +4,5 @@ pub mod wallet;
 pub mod swap;
 pub mod cex;
 pub mod bot;
+pub mod admin;
 
@@ -6,6 +6,7 @@ use crate::shared::services::AppState;
 use crate::shared::middleware::auth::AuthenticatedUser;
 use crate::shared::errors::WalletError;
 use crate::domains::cex::engine::Engine;
+use crate::domains::cex::models::ledger::LedgerReason;
 use axum::{extract::{Path, State}, http::StatusCode, Json};
 use rust_decimal::Decimal;
 use std::convert::Into;
@@ -50,6 +51,8 @@ pub async fn create_wallet(
         user_id,
         "SOL",
         Decimal::new(10_000, 0),
+        LedgerReason::Adjustment,
+        "initial_balance",
     ).await
     .map_err(|e| {
         (
@@ -65,6 +68,8 @@ pub async fn create_wallet(
         user_id,
         "USDT",
         Decimal::new(10_000, 0),
+        LedgerReason::Adjustment,
+        "initial_balance",
     ).await
     .map_err(|e| {
         (
@@ -192,7 +197,7 @@ pub async fn get_balance(
 
 /// SOL 전송 핸들러
 /// Transfer SOL handler
-/// Note: 자신의 지갑에서만 전송 가능 (JWT 토큰으로 소유권 검증)
+/// Note: 자신의 지갑에서만 전송 가능 (JWT 토큰으로 소유권 검증), CEX 입금 주소 지갑은 불가
 #[utoipa::path(
     post,
     path = "/api/wallets/{id}/transfer",
@@ -204,7 +209,7 @@ pub async fn get_balance(
         (status = 200, description = "Transfer successful", body = TransferSolResponse),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized (missing or invalid token)"),
-        (status = 403, description = "Forbidden (not your wallet)"),
+        (status = 403, description = "Forbidden (not your wallet, or wallet is a CEX deposit address)"),
         (status = 404, description = "Wallet not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -21,10 +21,25 @@ pub struct SolanaWallet {
     #[schema(example = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU")]
     pub public_key: String,
 
-    /// Encrypted Private Key
-    /// 암호화된 개인 키
+    /// Encrypted Private Key (base64(nonce || AES-256-GCM ciphertext), plain Base64 if key_version is None)
+    /// 암호화된 개인 키 (응답에는 포함하지 않음)
+    #[serde(skip_serializing)]
     pub encrypted_private_key: String,
 
+    /// Data key wrapped by the master key
+    /// 마스터 키로 감싼 데이터 키 (None: 암호화 전 기존 행)
+    #[serde(skip_serializing)]
+    pub wrapped_data_key: Option<String>,
+
+    /// Master key version that wrapped the data key
+    /// 데이터 키를 감싼 마스터 키 버전 (None: 암호화 전 기존 행)
+    #[serde(skip_serializing)]
+    pub key_version: Option<u32>,
+
+    /// Whether this wallet is a CEX deposit address (wallet API transfers are rejected)
+    /// CEX 입금 주소 여부 (true면 지갑 API 전송 불가)
+    pub is_deposit_address: bool,
+
     /// Created timestamp
     /// 생성 시간
     pub created_at: DateTime<Utc>,
@@ -34,3 +49,26 @@ pub struct SolanaWallet {
     pub updated_at: DateTime<Utc>,
 }
 
+
+impl SolanaWallet {
+    /// 저장된 봉투 암호화 값 (암호화 전 기존 행이면 None)
+    pub fn encrypted_key(&self) -> Option<EncryptedKey> {
+        Some(EncryptedKey {
+            encrypted_private_key: self.encrypted_private_key.clone(),
+            wrapped_data_key: self.wrapped_data_key.clone()?,
+            key_version: self.key_version?,
+        })
+    }
+}
+
+/// 봉투 암호화한 개인 키 (solana_wallets 저장 값)
+/// Envelope-encrypted private key as stored in solana_wallets
+#[derive(Debug, Clone)]
+pub struct EncryptedKey {
+    /// base64(nonce || 데이터 키로 암호화한 개인 키)
+    pub encrypted_private_key: String,
+    /// base64(nonce || 마스터 키로 감싼 데이터 키)
+    pub wrapped_data_key: String,
+    /// 데이터 키를 감싼 마스터 키 버전
+    pub key_version: u32,
+}
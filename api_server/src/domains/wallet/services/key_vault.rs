@@ -0,0 +1,326 @@
+use crate::domains::wallet::models::EncryptedKey;
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{Engine as _, engine::general_purpose};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use zeroize::Zeroizing;
+
+// =====================================================
+// 지갑 개인 키 봉투 암호화 (envelope encryption)
+// =====================================================
+// 역할: 지갑 개인 키를 DB에 저장하기 전에 암호화
+//
+// 구조:
+// - 데이터 키: 지갑마다 새로 만드는 AES-256-GCM 키, 개인 키를 암호화 (AAD: 지갑 public key)
+// - 마스터 키: MasterKeyProvider가 관리, 데이터 키를 감쌈 (AAD: 마스터 키 버전)
+//   → 마스터 키를 교체해도 개인 키는 다시 암호화하지 않고 데이터 키만 다시 감쌈 (rewrap)
+//
+// 저장 형식 (모두 Base64):
+// - encrypted_private_key: nonce(12) || 암호문 + 태그
+// - wrapped_data_key: nonce(12) || 감싼 데이터 키 + 태그
+// - key_version: 데이터 키를 감싼 마스터 키 버전
+//
+// 복호화한 데이터 키 / 개인 키는 Zeroizing으로 감싸서 drop 시 메모리에서 지움
+// =====================================================
+
+/// 마스터 키 파일 경로 환경 변수
+const MASTER_KEY_FILE_ENV: &str = "WALLET_MASTER_KEY_FILE";
+
+/// AES-256 키 길이 (bytes)
+const KEY_LEN: usize = 32;
+
+/// AES-GCM nonce 길이 (bytes)
+const NONCE_LEN: usize = 12;
+
+/// 데이터 키 / 마스터 키 (drop 시 메모리에서 지움)
+pub type SecretKey = Zeroizing<[u8; KEY_LEN]>;
+
+/// 마스터 키 제공자
+///
+/// 데이터 키를 감싸고 푸는 역할만 하므로 마스터 키가 프로세스 밖에 있는 구현(KMS 등)도 가능
+pub trait MasterKeyProvider: Send + Sync {
+    /// 새 데이터 키를 감쌀 때 쓰는 마스터 키 버전
+    fn current_version(&self) -> u32;
+
+    /// 현재 버전 마스터 키로 데이터 키 감싸기
+    ///
+    /// # Returns
+    /// (마스터 키 버전, nonce || 감싼 데이터 키)
+    fn wrap(&self, data_key: &[u8; KEY_LEN]) -> Result<(u32, Vec<u8>)>;
+
+    /// 지정한 버전 마스터 키로 데이터 키 풀기
+    fn unwrap(&self, version: u32, wrapped: &[u8]) -> Result<SecretKey>;
+}
+
+/// AES-256-GCM 암호화
+///
+/// # Returns
+/// nonce || 암호문 + 태그
+fn seal(key: &[u8; KEY_LEN], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    let cipher = Aes256Gcm::new_from_slice(key).expect("AES-256-GCM key is 32 bytes");
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = cipher
+        .encrypt(&Nonce::from(nonce), Payload { msg: plaintext, aad })
+        .map_err(|_| anyhow!("AES-GCM encryption failed"))?;
+
+    let mut sealed = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// AES-256-GCM 복호화 (seal의 반대)
+fn open(key: &[u8; KEY_LEN], sealed: &[u8], aad: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+    if sealed.len() < NONCE_LEN {
+        bail!("Ciphertext is too short");
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let nonce: [u8; NONCE_LEN] = nonce.try_into().expect("split at NONCE_LEN");
+    let cipher = Aes256Gcm::new_from_slice(key).expect("AES-256-GCM key is 32 bytes");
+    cipher
+        .decrypt(&Nonce::from(nonce), Payload { msg: ciphertext, aad })
+        .map(Zeroizing::new)
+        .map_err(|_| anyhow!("AES-GCM authentication failed (wrong key or tampered data)"))
+}
+
+/// 데이터 키를 감쌀 때 쓰는 AAD (감싼 키를 다른 버전으로 바꿔 붙일 수 없게)
+fn wrap_aad(version: u32) -> Vec<u8> {
+    format!("wallet-data-key:v{}", version).into_bytes()
+}
+
+/// Base64 → 32바이트 키
+fn decode_key(encoded: &str) -> Result<SecretKey> {
+    let bytes = Zeroizing::new(general_purpose::STANDARD.decode(encoded.trim()).context("Key is not valid Base64")?);
+    if bytes.len() != KEY_LEN {
+        bail!("Key must be {} bytes, got {}", KEY_LEN, bytes.len());
+    }
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    key.copy_from_slice(&bytes);
+    Ok(key)
+}
+
+// =====================================================
+// FileKeyProvider
+// =====================================================
+
+/// 파일 기반 마스터 키 제공자 (로컬 / 단일 서버용)
+///
+/// 파일 형식: 한 줄에 `<버전>:<Base64 32바이트 키>`, 빈 줄과 `#` 주석은 무시
+/// 가장 큰 버전이 현재 버전. 교체 시 새 버전 줄을 추가하고 rotate-wallet-keys 실행 후 이전 줄 삭제
+pub struct FileKeyProvider {
+    keys: BTreeMap<u32, SecretKey>,
+}
+
+impl FileKeyProvider {
+    /// 마스터 키 파일 로드
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = Zeroizing::new(
+            std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read master key file {}", path.display()))?,
+        );
+        Self::parse(&contents).with_context(|| format!("Invalid master key file {}", path.display()))
+    }
+
+    fn parse(contents: &str) -> Result<Self> {
+        let mut keys = BTreeMap::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let line_no = index + 1;
+            let (version, key) = line
+                .split_once(':')
+                .ok_or_else(|| anyhow!("Line {}: expected <version>:<base64 key>", line_no))?;
+            let version: u32 = version
+                .trim()
+                .parse()
+                .ok()
+                .filter(|version| *version > 0)
+                .ok_or_else(|| anyhow!("Line {}: version must be a positive integer", line_no))?;
+            let key = decode_key(key).with_context(|| format!("Line {}", line_no))?;
+            if keys.insert(version, key).is_some() {
+                bail!("Line {}: duplicate key version {}", line_no, version);
+            }
+        }
+        if keys.is_empty() {
+            bail!("No master keys found");
+        }
+        Ok(Self { keys })
+    }
+
+    fn key(&self, version: u32) -> Result<&SecretKey> {
+        self.keys
+            .get(&version)
+            .ok_or_else(|| anyhow!("Master key version {} is not loaded", version))
+    }
+}
+
+impl MasterKeyProvider for FileKeyProvider {
+    fn current_version(&self) -> u32 {
+        *self.keys.keys().next_back().expect("at least one master key is loaded")
+    }
+
+    fn wrap(&self, data_key: &[u8; KEY_LEN]) -> Result<(u32, Vec<u8>)> {
+        let version = self.current_version();
+        let wrapped = seal(self.key(version)?, data_key, &wrap_aad(version))?;
+        Ok((version, wrapped))
+    }
+
+    fn unwrap(&self, version: u32, wrapped: &[u8]) -> Result<SecretKey> {
+        let data_key = open(self.key(version)?, wrapped, &wrap_aad(version))?;
+        if data_key.len() != KEY_LEN {
+            bail!("Unwrapped data key has invalid length {}", data_key.len());
+        }
+        let mut key = Zeroizing::new([0u8; KEY_LEN]);
+        key.copy_from_slice(&data_key);
+        Ok(key)
+    }
+}
+
+// =====================================================
+// WalletKeyCipher
+// =====================================================
+
+/// 지갑 개인 키 암호화/복호화
+#[derive(Clone)]
+pub struct WalletKeyCipher {
+    provider: Arc<dyn MasterKeyProvider>,
+}
+
+impl WalletKeyCipher {
+    pub fn new(provider: Arc<dyn MasterKeyProvider>) -> Self {
+        Self { provider }
+    }
+
+    /// WALLET_MASTER_KEY_FILE에서 마스터 키 로드
+    ///
+    /// # Returns
+    /// 환경 변수가 없으면 None (지갑 생성 / 암호화된 키 사용 불가)
+    pub fn from_env() -> Result<Option<Self>> {
+        let Ok(path) = std::env::var(MASTER_KEY_FILE_ENV) else {
+            return Ok(None);
+        };
+        let provider = FileKeyProvider::load(Path::new(&path))?;
+        Ok(Some(Self::new(Arc::new(provider))))
+    }
+
+    /// 현재 마스터 키 버전
+    pub fn current_version(&self) -> u32 {
+        self.provider.current_version()
+    }
+
+    /// 개인 키 암호화 (새 데이터 키 생성 → 개인 키 암호화 → 데이터 키 감싸기)
+    ///
+    /// # Arguments
+    /// * `public_key` - 지갑 public key (AAD, 다른 지갑 행으로 옮긴 암호문은 복호화 실패)
+    /// * `private_key` - 개인 키 바이트
+    pub fn encrypt(&self, public_key: &str, private_key: &[u8]) -> Result<EncryptedKey> {
+        let mut data_key = Zeroizing::new([0u8; KEY_LEN]);
+        OsRng.fill_bytes(data_key.as_mut());
+
+        let ciphertext = seal(&data_key, private_key, public_key.as_bytes())?;
+        let (key_version, wrapped) = self.provider.wrap(&data_key)?;
+
+        Ok(EncryptedKey {
+            encrypted_private_key: general_purpose::STANDARD.encode(ciphertext),
+            wrapped_data_key: general_purpose::STANDARD.encode(wrapped),
+            key_version,
+        })
+    }
+
+    /// 개인 키 복호화
+    ///
+    /// # Returns
+    /// 개인 키 바이트 (drop 시 메모리에서 지움)
+    pub fn decrypt(&self, public_key: &str, key: &EncryptedKey) -> Result<Zeroizing<Vec<u8>>> {
+        let wrapped = general_purpose::STANDARD
+            .decode(&key.wrapped_data_key)
+            .context("Wrapped data key is not valid Base64")?;
+        let ciphertext = general_purpose::STANDARD
+            .decode(&key.encrypted_private_key)
+            .context("Encrypted private key is not valid Base64")?;
+
+        let data_key = self.provider.unwrap(key.key_version, &wrapped)?;
+        open(&data_key, &ciphertext, public_key.as_bytes())
+    }
+
+    /// 데이터 키를 현재 버전 마스터 키로 다시 감싸기 (개인 키 암호문은 그대로)
+    ///
+    /// # Returns
+    /// (새 마스터 키 버전, 새로 감싼 데이터 키)
+    pub fn rewrap(&self, key: &EncryptedKey) -> Result<(u32, String)> {
+        let wrapped = general_purpose::STANDARD
+            .decode(&key.wrapped_data_key)
+            .context("Wrapped data key is not valid Base64")?;
+        let data_key = self.provider.unwrap(key.key_version, &wrapped)?;
+        let (version, rewrapped) = self.provider.wrap(&data_key)?;
+        Ok((version, general_purpose::STANDARD.encode(rewrapped)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_line(version: u32, byte: u8) -> String {
+        format!("{}:{}", version, general_purpose::STANDARD.encode([byte; KEY_LEN]))
+    }
+
+    fn cipher(contents: &str) -> WalletKeyCipher {
+        WalletKeyCipher::new(Arc::new(FileKeyProvider::parse(contents).unwrap()))
+    }
+
+    /// 테스트: 암호화/복호화 왕복, 다른 지갑 AAD / 변조된 키 버전은 복호화 실패
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let cipher = cipher(&key_line(1, 7));
+        let private_key = [42u8; 64];
+
+        let encrypted = cipher.encrypt("wallet-a", &private_key).unwrap();
+        assert_eq!(encrypted.key_version, 1);
+        assert_ne!(encrypted.encrypted_private_key, general_purpose::STANDARD.encode(private_key));
+        assert_eq!(cipher.decrypt("wallet-a", &encrypted).unwrap().as_slice(), &private_key[..]);
+
+        // 다른 지갑 행으로 옮긴 암호문 / 변조된 암호문은 복호화 실패
+        assert!(cipher.decrypt("wallet-b", &encrypted).is_err());
+        let mut tampered = encrypted.clone();
+        tampered.key_version = 2;
+        assert!(cipher.decrypt("wallet-a", &tampered).is_err());
+    }
+
+    /// 테스트: 마스터 키 교체 후 데이터 키만 다시 감싸기 (이전 버전 삭제 후에도 복호화)
+    #[test]
+    fn test_rewrap_after_master_key_rotation() {
+        let old = cipher(&key_line(1, 7));
+        let encrypted = old.encrypt("wallet-a", &[42u8; 64]).unwrap();
+
+        // 새 버전 추가 → 현재 버전으로 다시 감쌈
+        let rotated = cipher(&format!("# keys\n{}\n\n{}\n", key_line(1, 7), key_line(2, 9)));
+        assert_eq!(rotated.current_version(), 2);
+        let (version, wrapped_data_key) = rotated.rewrap(&encrypted).unwrap();
+        let rewrapped = EncryptedKey { wrapped_data_key, key_version: version, ..encrypted };
+        assert_eq!(version, 2);
+
+        // 이전 버전을 지워도 복호화 가능
+        let new_only = cipher(&key_line(2, 9));
+        assert_eq!(new_only.decrypt("wallet-a", &rewrapped).unwrap().as_slice(), &[42u8; 64][..]);
+        assert!(old.decrypt("wallet-a", &rewrapped).is_err());
+    }
+
+    /// 테스트: 마스터 키 파일 파싱 오류 (빈 파일, Base64 아님, 버전 0, 키 길이, 중복 버전)
+    #[test]
+    fn test_parse_master_key_file() {
+        assert!(FileKeyProvider::parse("").is_err());
+        assert!(FileKeyProvider::parse("1:not-base64!").is_err());
+        assert!(FileKeyProvider::parse(&format!("0:{}", general_purpose::STANDARD.encode([1u8; KEY_LEN]))).is_err());
+        assert!(FileKeyProvider::parse(&format!("1:{}", general_purpose::STANDARD.encode([1u8; 16]))).is_err());
+        assert!(FileKeyProvider::parse(&format!("{}\n{}", key_line(1, 1), key_line(1, 2))).is_err());
+    }
+}
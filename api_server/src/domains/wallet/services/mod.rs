@@ -1,5 +1,6 @@
 // Wallet domain services
 pub mod wallet_service;
+pub mod key_vault;
 pub mod state;
 
 pub use wallet_service::*;
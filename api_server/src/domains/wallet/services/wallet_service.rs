@@ -1,12 +1,31 @@
 use crate::shared::clients::SolanaClient;
 use crate::shared::database::{Database, SolanaWalletRepository};
-use crate::domains::wallet::models::SolanaWallet;
+use crate::domains::wallet::models::{EncryptedKey, SolanaWallet};
+use crate::domains::wallet::services::key_vault::WalletKeyCipher;
 use crate::shared::errors::WalletError;
 use solana_sdk::{
     signer::keypair::Keypair,
     signer::Signer,
 };
 use base64::{Engine as _, engine::general_purpose};
+use zeroize::Zeroizing;
+
+/// 키 재암호화 시 한 번에 조회하는 지갑 수
+const ROTATION_BATCH_SIZE: i64 = 500;
+
+/// 키 재암호화 결과 (지갑 개인 키 / API 키 secret)
+/// Result of re-wrapping stored keys with the current master key
+#[derive(Debug, Default)]
+pub struct KeyRotationReport {
+    /// 현재 마스터 키 버전
+    pub key_version: u32,
+    /// 데이터 키를 다시 감싼 행 수
+    pub rewrapped: u64,
+    /// 평문에서 새로 암호화한 행 수
+    pub encrypted: u64,
+    /// 실패한 행 (id, 에러)
+    pub failed: Vec<(u64, String)>,
+}
 
 /// 지갑 서비스
 /// 역할: NestJS의 Service 같은 것
@@ -15,6 +34,8 @@ use base64::{Engine as _, engine::general_purpose};
 pub struct WalletService {
     db: Database,
     solana_client: SolanaClient,
+    /// 개인 키 암호화 (WALLET_MASTER_KEY_FILE, 없으면 지갑 생성 / 암호화된 키 사용 불가)
+    key_cipher: Option<WalletKeyCipher>,
 }
 
 impl WalletService {
@@ -23,13 +44,25 @@ impl WalletService {
     pub fn new(db: Database) -> Result<Self, WalletError> {
         let solana_client = SolanaClient::new()
             .map_err(|e| WalletError::Internal(format!("Failed to create SolanaClient: {}", e)))?;
+        let key_cipher = WalletKeyCipher::from_env()
+            .map_err(|e| WalletError::Internal(format!("Failed to load wallet master key: {:#}", e)))?;
 
         Ok(Self {
             db,
             solana_client,
+            key_cipher,
         })
     }
 
+    /// 개인 키 암호화 사용 가능 여부 (마스터 키 로드됨)
+    pub fn key_encryption_enabled(&self) -> bool {
+        self.key_cipher.is_some()
+    }
+
+    fn key_cipher(&self) -> Result<&WalletKeyCipher, WalletError> {
+        self.key_cipher.as_ref().ok_or(WalletError::KeyEncryptionUnavailable)
+    }
+
     /// 새 지갑 생성
     /// Create new wallet for user
     /// Note: 사용자당 1개 지갑만 허용 (UNIQUE 제약 + 에러 처리)
@@ -42,18 +75,21 @@ impl WalletService {
         }
 
         // 2. Keypair 생성 (새 지갑)
+        let key_cipher = self.key_cipher()?;
         let keypair = SolanaClient::generate_wallet();
         let public_key = keypair.pubkey().to_string();
 
-        // 3. Private Key 암호화 (일단 Base64 인코딩, 나중에 실제 암호화 추가)
-        // Encrypt private key (currently Base64 encoding, will add real encryption later)
-        let private_key_bytes = keypair.to_bytes();
-        let encrypted_private_key = general_purpose::STANDARD.encode(&private_key_bytes);
+        // 3. Private Key 봉투 암호화 (평문 바이트는 drop 시 메모리에서 지움)
+        // Envelope-encrypt private key
+        let private_key_bytes = Zeroizing::new(keypair.to_bytes());
+        let encrypted_key = key_cipher
+            .encrypt(&public_key, private_key_bytes.as_ref())
+            .map_err(|e| WalletError::EncryptionFailed(format!("{:#}", e)))?;
 
         // 4. DB에 저장 (UNIQUE 제약이 최종 보호 역할)
         let wallet_repo = SolanaWalletRepository::new(self.db.pool().clone());
         let wallet = wallet_repo
-            .create_solana_wallet(user_id, &public_key, &encrypted_private_key)
+            .create_solana_wallet(user_id, &public_key, &encrypted_key)
             .await
             .map_err(|e| {
                 // UNIQUE 제약 위반 에러 처리
@@ -131,13 +167,32 @@ impl WalletService {
     }
 
     /// Private Key 복호화 (Keypair로 변환)
-    /// Decrypt private key (convert to Keypair)
-    /// Note: 현재는 Base64 디코딩, 나중에 실제 복호화 추가
-    pub fn decrypt_private_key(encrypted_private_key: &str) -> Result<Keypair, WalletError> {
+    /// Decrypt wallet private key (convert to Keypair)
+    /// Note: key_version이 없는 기존 행은 Base64 평문 (rotate-wallet-keys로 암호화)
+    /// Keypair는 drop 시 비밀 키를 메모리에서 지우므로 서명 후 바로 drop
+    pub fn decrypt_private_key(&self, wallet: &SolanaWallet) -> Result<Keypair, WalletError> {
+        let Some(encrypted_key) = wallet.encrypted_key() else {
+            return Self::keypair_from_base64(&wallet.encrypted_private_key);
+        };
+
+        let private_key_bytes = self
+            .key_cipher()?
+            .decrypt(&wallet.public_key, &encrypted_key)
+            .map_err(|e| WalletError::DecryptionFailed(format!("{:#}", e)))?;
+
+        Keypair::from_bytes(&private_key_bytes)
+            .map_err(|e| WalletError::DecryptionFailed(format!("Failed to create keypair from private key bytes: {}", e)))
+    }
+
+    /// Base64 평문 개인 키 → Keypair (암호화 전 기존 행, 출금 지갑 환경 변수)
+    /// Decode a plain Base64 private key
+    pub fn keypair_from_base64(encoded: &str) -> Result<Keypair, WalletError> {
         // Base64 디코딩
-        let private_key_bytes = general_purpose::STANDARD
-            .decode(encrypted_private_key)
-            .map_err(|e| WalletError::DecryptionFailed(format!("Failed to decode private key: {}", e)))?;
+        let private_key_bytes = Zeroizing::new(
+            general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(|e| WalletError::DecryptionFailed(format!("Failed to decode private key: {}", e)))?,
+        );
 
         // Keypair로 변환
         let keypair = Keypair::from_bytes(&private_key_bytes)
@@ -146,8 +201,69 @@ impl WalletService {
         Ok(keypair)
     }
 
+    /// 모든 지갑 키를 현재 마스터 키 버전으로 재암호화
+    /// Re-wrap every wallet key with the current master key version
+    ///
+    /// # 처리 과정
+    /// 1. key_version이 현재 버전이 아닌 지갑을 id 순으로 ROTATION_BATCH_SIZE개씩 조회
+    /// 2. 암호화된 행: 데이터 키만 풀어서 현재 버전으로 다시 감쌈 (개인 키 암호문은 그대로)
+    /// 3. Base64 평문 행: 새 데이터 키로 암호화
+    /// 4. 조회 후 key_version이 바뀐 행은 건너뜀 (다른 재암호화가 처리함)
+    ///
+    /// 한 지갑이 실패해도 나머지는 계속 처리하고 실패 목록을 반환
+    pub async fn rotate_keys(&self) -> Result<KeyRotationReport, WalletError> {
+        let key_cipher = self.key_cipher()?;
+        let wallet_repo = SolanaWalletRepository::new(self.db.pool().clone());
+        let mut report = KeyRotationReport {
+            key_version: key_cipher.current_version(),
+            ..Default::default()
+        };
+
+        let mut after_id = 0;
+        loop {
+            let wallets = wallet_repo
+                .get_wallets_for_rotation(report.key_version, after_id, ROTATION_BATCH_SIZE)
+                .await
+                .map_err(|e| WalletError::DatabaseError(format!("Failed to fetch wallets for key rotation: {:#}", e)))?;
+            let Some(last) = wallets.last() else {
+                break;
+            };
+            after_id = last.id;
+
+            for wallet in wallets {
+                let new_key = match wallet.encrypted_key() {
+                    Some(encrypted_key) => key_cipher.rewrap(&encrypted_key).map(|(key_version, wrapped_data_key)| {
+                        EncryptedKey { wrapped_data_key, key_version, ..encrypted_key }
+                    }),
+                    None => general_purpose::STANDARD
+                        .decode(&wallet.encrypted_private_key)
+                        .map(Zeroizing::new)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|private_key| key_cipher.encrypt(&wallet.public_key, &private_key)),
+                };
+                let new_key = match new_key {
+                    Ok(new_key) => new_key,
+                    Err(e) => {
+                        report.failed.push((wallet.id, format!("{:#}", e)));
+                        continue;
+                    }
+                };
+
+                match wallet_repo.update_wallet_key(wallet.id, wallet.key_version, &new_key).await {
+                    Ok(true) if wallet.key_version.is_some() => report.rewrapped += 1,
+                    Ok(true) => report.encrypted += 1,
+                    Ok(false) => {}
+                    Err(e) => report.failed.push((wallet.id, format!("{:#}", e))),
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     /// SOL 전송
     /// Transfer SOL from one wallet to another
+    /// Note: CEX 입금 주소 지갑은 전송 불가 (입금된 SOL은 이미 CEX 잔고로 입금됨)
     pub async fn transfer_sol(
         &self,
         from_wallet_id: u64,
@@ -156,6 +272,9 @@ impl WalletService {
     ) -> Result<String, WalletError> {
         // 1. 송신 지갑 조회
         let from_wallet = self.get_wallet(from_wallet_id).await?;
+        if from_wallet.is_deposit_address {
+            return Err(WalletError::DepositAddressTransfer { id: from_wallet_id });
+        }
 
         // 2. 잔액 확인 (간단한 체크)
         let balance = self.get_balance(from_wallet_id).await?;
@@ -167,7 +286,7 @@ impl WalletService {
         }
 
         // 3. Private Key 복호화하여 Keypair 생성
-        let from_keypair = Self::decrypt_private_key(&from_wallet.encrypted_private_key)?;
+        let from_keypair = self.decrypt_private_key(&from_wallet)?;
 
         // 4. 수신 Public Key 파싱
         let to_pubkey = SolanaClient::parse_pubkey(to_public_key)
@@ -179,6 +298,8 @@ impl WalletService {
             .create_transfer_transaction(&from_keypair, &to_pubkey, amount_lamports)
             .await
             .map_err(|e| WalletError::TransactionCreationFailed(format!("Failed to create transfer transaction: {}", e)))?;
+        // 서명 완료 → 비밀 키 메모리에서 지움
+        drop(from_keypair);
 
         // 6. 트랜잭션 전송
         let signature = self
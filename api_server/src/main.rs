@@ -11,6 +11,8 @@ mod shared;
 mod routes;
 
 use routes::create_router;
+use crate::shared::middleware::api_key::api_key_auth;
+use crate::shared::middleware::rate_limit::rate_limit;
 use crate::shared::database::Database;
 use crate::shared::services::AppState;
 
@@ -19,6 +21,7 @@ use crate::domains::swap::models::*;
 use crate::domains::auth::models::*;
 use crate::domains::wallet::models::*;
 use crate::domains::cex::models::*;
+use crate::domains::admin::models::*;
 
 // OpenAPI 스키마 정의: Swagger 문서 자동 생성
 #[derive(OpenApi)]
@@ -32,6 +35,9 @@ use crate::domains::cex::models::*;
         crate::domains::auth::handlers::auth_handler::refresh,
         crate::domains::auth::handlers::auth_handler::logout,
         crate::domains::auth::handlers::auth_handler::get_me,
+        crate::domains::auth::handlers::api_key_handler::create_api_key,
+        crate::domains::auth::handlers::api_key_handler::get_api_keys,
+        crate::domains::auth::handlers::api_key_handler::revoke_api_key,
         crate::domains::wallet::handlers::wallet_handler::create_wallet,
         crate::domains::wallet::handlers::wallet_handler::get_wallet,
         crate::domains::wallet::handlers::wallet_handler::get_user_wallets,
@@ -40,17 +46,62 @@ use crate::domains::cex::models::*;
         crate::domains::wallet::handlers::wallet_handler::get_transaction_status,
         crate::domains::cex::handlers::balance_handler::get_all_balances,
         crate::domains::cex::handlers::balance_handler::get_balance,
+        crate::domains::cex::handlers::deposit_handler::get_deposit_address,
+        crate::domains::cex::handlers::deposit_handler::get_my_deposits,
+        crate::domains::cex::handlers::withdrawal_handler::create_withdrawal,
+        crate::domains::cex::handlers::withdrawal_handler::get_my_withdrawals,
+        crate::domains::cex::handlers::withdrawal_handler::get_withdrawal,
+        crate::domains::cex::handlers::ledger_handler::get_my_ledger,
+        crate::domains::cex::handlers::transfer_handler::create_transfer,
+        crate::domains::cex::handlers::transfer_handler::get_my_transfers,
+        crate::domains::cex::handlers::transfer_handler::get_transfer,
+        crate::domains::cex::handlers::sub_account_handler::create_sub_account,
+        crate::domains::cex::handlers::sub_account_handler::get_sub_accounts,
+        crate::domains::cex::handlers::sub_account_handler::transfer_between_sub_accounts,
+        crate::domains::cex::handlers::sub_account_handler::get_sub_account_balances,
+        crate::domains::cex::handlers::sub_account_handler::get_sub_account_positions,
         crate::domains::cex::handlers::order_handler::create_order,
         crate::domains::cex::handlers::order_handler::cancel_order,
         crate::domains::cex::handlers::order_handler::get_order,
         crate::domains::cex::handlers::order_handler::get_my_orders,
         crate::domains::cex::handlers::order_handler::get_orderbook,
+        crate::domains::cex::handlers::order_handler::get_l3_orderbook,
         crate::domains::cex::handlers::trade_handler::get_trades,
         crate::domains::cex::handlers::trade_handler::get_my_trades,
         crate::domains::cex::handlers::trade_handler::get_latest_price,
         crate::domains::cex::handlers::trade_handler::get_24h_volume,
+        crate::domains::cex::handlers::candle_handler::get_candles,
+        crate::domains::cex::handlers::ticker_handler::get_ticker,
         crate::domains::cex::handlers::position_handler::get_position,
         crate::domains::cex::handlers::position_handler::get_all_positions,
+        crate::domains::cex::handlers::position_handler::get_position_lots,
+        crate::domains::cex::handlers::position_handler::set_cost_basis_method,
+        crate::domains::cex::handlers::portfolio_handler::get_portfolio,
+        crate::domains::cex::handlers::portfolio_handler::get_portfolio_history,
+        crate::domains::cex::handlers::export_handler::create_export,
+        crate::domains::cex::handlers::export_handler::list_exports,
+        crate::domains::cex::handlers::export_handler::get_export,
+        crate::domains::cex::handlers::export_handler::download_export,
+        crate::domains::admin::handlers::admin_handler::get_user,
+        crate::domains::admin::handlers::admin_handler::set_user_role,
+        crate::domains::admin::handlers::admin_handler::freeze_user,
+        crate::domains::admin::handlers::admin_handler::unfreeze_user,
+        crate::domains::admin::handlers::admin_handler::set_user_fee_tier,
+        crate::domains::admin::handlers::admin_handler::adjust_balance,
+        crate::domains::admin::handlers::admin_handler::get_wal_status,
+        crate::domains::admin::handlers::admin_handler::get_reconciliation_status,
+        crate::domains::admin::handlers::admin_handler::run_reconciliation,
+        crate::domains::admin::handlers::admin_handler::get_market_statuses,
+        crate::domains::admin::handlers::admin_handler::set_market_status,
+        crate::domains::admin::handlers::admin_handler::get_fee_configs,
+        crate::domains::admin::handlers::admin_handler::create_fee_config,
+        crate::domains::admin::handlers::admin_handler::update_fee_config,
+        crate::domains::admin::handlers::admin_handler::deactivate_fee_config,
+        crate::domains::admin::handlers::admin_handler::get_fee_config_history,
+        crate::domains::admin::handlers::admin_handler::reload_fee_schedule,
+        crate::domains::admin::handlers::admin_handler::approve_withdrawal,
+        crate::domains::admin::handlers::admin_handler::reject_withdrawal,
+        crate::domains::admin::handlers::admin_handler::get_audit_logs,
         crate::domains::bot::handlers::bot_handler::delete_bot_data,
         crate::domains::bot::handlers::bot_handler::get_cleanup_scheduler_status,
         crate::domains::bot::handlers::bot_handler::enable_cleanup_scheduler,
@@ -74,7 +125,13 @@ use crate::domains::cex::models::*;
         RefreshTokenRequest,
         RefreshTokenResponse,
         LogoutRequest,
+        User,
         UserResponse,
+        Role,
+        ApiKey,
+        ApiKeyScope,
+        CreateApiKeyRequest,
+        CreateApiKeyResponse,
         CreateWalletResponse,
         WalletResponse,
         WalletsResponse,
@@ -86,18 +143,89 @@ use crate::domains::cex::models::*;
         UserBalance,
         ExchangeBalancesResponse,
         ExchangeBalanceResponse,
+        Deposit,
+        DepositStatus,
+        DepositAddressResponse,
+        crate::domains::cex::handlers::deposit_handler::DepositAddressQuery,
+        crate::domains::cex::handlers::deposit_handler::DepositListQuery,
+        Withdrawal,
+        WithdrawalStatus,
+        CreateWithdrawalRequest,
+        crate::domains::cex::handlers::withdrawal_handler::WithdrawalListQuery,
+        LedgerPosting,
+        LedgerReason,
+        LedgerAccount,
+        LedgerSide,
+        crate::domains::cex::handlers::ledger_handler::LedgerQuery,
+        Transfer,
+        TransferStatus,
+        TransferDirection,
+        CreateTransferRequest,
+        crate::domains::cex::handlers::transfer_handler::TransferListQuery,
+        SubAccount,
+        CreateSubAccountRequest,
+        SubAccountTransferRequest,
+        AggregatedBalance,
+        AccountBalances,
+        SubAccountBalancesResponse,
+        AggregatedPosition,
+        AccountPositions,
+        SubAccountPositionsResponse,
         Order,
         CreateOrderRequest,
         OrderResponse,
         OrdersResponse,
         OrderBookEntry,
         OrderBookResponse,
+        crate::domains::cex::handlers::order_handler::L3OrderbookResponse,
+        crate::domains::cex::services::L3Order,
         Trade,
         TradesResponse,
         AssetPosition,
         AssetPositionResponse,
         AllPositionsResponse,
         TradeSummary,
+        CostBasisMethod,
+        PositionLot,
+        RealizedPnlEntry,
+        PositionLotsResponse,
+        CostBasisMethodRequest,
+        crate::domains::cex::handlers::position_handler::PositionLotsQuery,
+        PortfolioAsset,
+        PortfolioValuation,
+        EquitySnapshot,
+        crate::domains::cex::handlers::portfolio_handler::PortfolioQuery,
+        crate::domains::cex::handlers::portfolio_handler::PortfolioHistoryQuery,
+        ExportDataset,
+        ExportFormat,
+        ExportStatus,
+        ExportJob,
+        CreateExportRequest,
+        crate::domains::cex::handlers::export_handler::ExportListQuery,
+        BalanceDiscrepancy,
+        ReconciliationReport,
+        ReconciliationMetrics,
+        ReconciliationStatus,
+        RunReconciliationRequest,
+        FeeConfig,
+        FeeConfigHistory,
+        CreateFeeConfigRequest,
+        UpdateFeeConfigRequest,
+        MarketStatus,
+        MarketStatusEntry,
+        SetRoleRequest,
+        FreezeUserRequest,
+        AdjustBalanceRequest,
+        AdjustBalanceResponse,
+        SetMarketStatusRequest,
+        SetFeeTierRequest,
+        WalStatusResponse,
+        RejectWithdrawalRequest,
+        AuditLog,
+        crate::domains::admin::handlers::admin_handler::AuditLogQuery,
+        Candle,
+        CandleInterval,
+        Ticker,
         crate::domains::bot::handlers::bot_handler::DeleteBotDataRequest,
         crate::domains::bot::handlers::bot_handler::DeleteBotDataResponse
     )),
@@ -107,13 +235,16 @@ use crate::domains::cex::models::*;
     tags(
         (name = "Swap", description = "Swap API endpoints (Jupiter integration)"),
         (name = "Tokens", description = "Token search API endpoints"),
-        (name = "Auth", description = "Authentication API endpoints"),
+        (name = "Auth", description = "Authentication API endpoints (JWT sign-in, API keys for programmatic access signed with HMAC-SHA256)"),
         (name = "Wallets", description = "Wallet API endpoints (Solana wallet management)"),
-        (name = "CEX Balances", description = "CEX Exchange balance API endpoints"),
+        (name = "CEX Balances", description = "CEX Exchange balance API endpoints (balances, on-chain deposits and withdrawals, internal transfers, ledger)"),
         (name = "CEX Orders", description = "CEX Exchange order API endpoints"),
         (name = "CEX Trades", description = "CEX Exchange trade API endpoints"),
-        (name = "CEX Positions", description = "CEX Exchange position API endpoints (P&L, average entry price)"),
-        (name = "Bot", description = "Bot management API endpoints (delete bot data)")
+        (name = "CEX Positions", description = "CEX Exchange position API endpoints (P&L, cost basis lots, portfolio valuation and equity history)"),
+        (name = "CEX Sub-accounts", description = "CEX Exchange sub-account API endpoints (create, transfers between accounts, aggregated balances and positions; act on a sub-account with the X-Sub-Account-Id header)"),
+        (name = "CEX Exports", description = "CEX Exchange statement export API endpoints (trades, orders, balance snapshots, deposits, withdrawals as CSV/JSON)"),
+        (name = "Admin", description = "Admin API endpoints (user roles and freezes, balance adjustments, engine vs database balance reconciliation, market status, fee configs and tiers, withdrawal approval, audit log; support or admin role required)"),
+        (name = "Bot", description = "Bot management API endpoints under /api/admin/bot (admin role required)")
     ),
     info(
         title = "Solana API Server",
@@ -137,7 +268,19 @@ impl utoipa::Modify for SecurityAddon {
                         .bearer_format("JWT")
                         .build(),
                 ),
-            )
+            );
+            // API 키 (X-Api-Timestamp / X-Api-Signature 헤더와 함께 전송)
+            components.add_security_scheme(
+                "ApiKeyAuth",
+                utoipa::openapi::security::SecurityScheme::ApiKey(
+                    utoipa::openapi::security::ApiKey::Header(
+                        utoipa::openapi::security::ApiKeyValue::with_description(
+                            "X-Api-Key",
+                            "API key ID. Also send X-Api-Timestamp (Unix ms) and X-Api-Signature = hex(HMAC-SHA256(secret, timestamp + METHOD + path?query + body))",
+                        ),
+                    ),
+                ),
+            );
         }
     }
 }
@@ -154,6 +297,51 @@ async fn main() {
         .await
         .expect("Failed to initialize database");
 
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    // 지갑 키 재암호화 명령 (`api_server rotate-wallet-keys`)
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    // WALLET_MASTER_KEY_FILE에 새 버전 키를 추가한 뒤 실행 → 모든 지갑 / API 키 secret을 현재 버전으로 다시 감싸고 종료
+    if std::env::args().nth(1).as_deref() == Some("rotate-wallet-keys") {
+        use crate::domains::auth::services::ApiKeyService;
+        use crate::domains::wallet::services::WalletService;
+
+        let wallet_service = WalletService::new(db.clone()).expect("Failed to create WalletService");
+        let report = wallet_service
+            .rotate_keys()
+            .await
+            .expect("Failed to rotate wallet keys");
+        for (wallet_id, error) in &report.failed {
+            eprintln!("[KeyRotation] Wallet {} failed: {}", wallet_id, error);
+        }
+        eprintln!(
+            "[KeyRotation] Master key version {}: {} re-wrapped, {} encrypted from plaintext, {} failed",
+            report.key_version,
+            report.rewrapped,
+            report.encrypted,
+            report.failed.len()
+        );
+
+        let api_key_service = ApiKeyService::new(db.clone()).expect("Failed to create ApiKeyService");
+        let api_key_report = api_key_service
+            .rotate_secrets()
+            .await
+            .expect("Failed to rotate API key secrets");
+        for (api_key_id, error) in &api_key_report.failed {
+            eprintln!("[KeyRotation] API key {} failed: {}", api_key_id, error);
+        }
+        eprintln!(
+            "[KeyRotation] API keys: {} re-wrapped, {} encrypted from plaintext, {} failed",
+            api_key_report.rewrapped,
+            api_key_report.encrypted,
+            api_key_report.failed.len()
+        );
+
+        if !report.failed.is_empty() || !api_key_report.failed.is_empty() {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     // ID 생성기 초기화 (메모리 기반, DB 접근 없음)
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -169,6 +357,42 @@ async fn main() {
     let mut app_state = AppState::new(db.clone())
         .expect("Failed to initialize AppState");
     
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    // 관리자 / 동결 계정 / 거래쌍 상태 로드
+    // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+    // ADMIN_USER_IDS (쉼표 구분): 첫 관리자 부트스트랩 (이후 역할은 /api/admin/users/:id/role로 관리)
+    let admin_user_ids: Vec<u64> = std::env::var("ADMIN_USER_IDS")
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|id| id.trim().parse::<u64>().ok())
+        .collect();
+    if !admin_user_ids.is_empty() {
+        match crate::shared::database::UserRepository::new(db.pool().clone())
+            .grant_admin(&admin_user_ids)
+            .await
+        {
+            Ok(granted) => eprintln!("[Main] Admin role granted to {} user(s) from ADMIN_USER_IDS", granted),
+            Err(e) => eprintln!("[Main] Failed to grant admin role from ADMIN_USER_IDS: {:#}", e),
+        }
+    }
+    
+    let frozen = app_state
+        .auth_state
+        .frozen_accounts
+        .load()
+        .await
+        .expect("Failed to load frozen accounts");
+    let market_statuses = app_state
+        .cex_state
+        .market_status_service
+        .load()
+        .await
+        .expect("Failed to load market statuses");
+    eprintln!("[Main] Loaded {} frozen account(s), {} market status override(s)", frozen, market_statuses);
+    if !app_state.wallet_state.wallet_service.key_encryption_enabled() {
+        eprintln!("[Main] WALLET_MASTER_KEY_FILE is not set: wallet creation and encrypted wallet keys are unavailable");
+    }
+    
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
     // 봇 준비 (엔진 시작 전 - 계정 생성 및 데이터 삭제)
     // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -178,7 +402,6 @@ async fn main() {
     use crate::domains::bot::services::{
         BotManager, BinanceClient, OrderbookSync,
     };
-    use crate::domains::cex::services::order_service::OrderService;
     
     // 봇 설정 로드
     eprintln!("[Main] Loading bot config from environment...");
@@ -226,14 +449,51 @@ async fn main() {
     
     eprintln!("[Main] Engine started successfully (bot balances loaded from DB)");
     
+    // 수수료 스케줄 반영 (이후 변경은 /api/admin/fees에서 저장 즉시 반영)
+    let fee_schedule = app_state
+        .cex_state
+        .fee_service
+        .reload_engine(&app_state.engine)
+        .await
+        .expect("Failed to load fee schedule into engine");
+    eprintln!(
+        "[Main] Fee schedule v{} applied ({} rule(s), {} tiered user(s))",
+        fee_schedule.version, fee_schedule.rules, fee_schedule.tiered_users
+    );
+    
+    // 공개 시세 시작 (엔진 이벤트 버스 구독, 엔진 시작 이후여야 오더북 스냅샷이 채워짐)
+    app_state.cex_state.market_data_service.start();
+    
+    // 사용자 스트림 시작 (열린 주문 추적 + 주문/체결/잔고 발행)
+    app_state.cex_state.user_stream_service.start();
+    
+    // 캔들 집계 시작 (trades 테이블 backfill 후 체결 이벤트로 증분 집계)
+    app_state.cex_state.candle_service.start();
+    
+    // 24시간 ticker 시작 (최근 24시간 체결로 초기화 후 체결 이벤트로 갱신, 1초마다 발행)
+    app_state.cex_state.ticker_service.start();
+    
+    // 일별 자산 기록 시작 (시작 시 한 번 + 매일 00:00 UTC, ticker 가격 사용)
+    app_state.cex_state.portfolio_service.start();
+    
+    // 내역 내보내기 시작 (끝나지 않은 작업 재실행 + 기한 지난 파일 정리)
+    app_state.cex_state.export_service.start();
+    
+    // 입금 감시 시작 (지갑/토큰 계정 트랜잭션 감시 → finalized 후 엔진 잔고 채널로 입금)
+    app_state.cex_state.deposit_service.start();
+    app_state.cex_state.withdrawal_service.start();
+    
+    // 내부 이체 정리 (이전 프로세스에서 pending으로 남은 이체 → 원장 기준 completed / failed)
+    app_state.cex_state.transfer_service.start();
+    
+    // 엔진 ↔ DB 잔고 정합성 검사 (주기 실행)
+    app_state.cex_state.reconciliation_service.start();
+    
     // 바이낸스 클라이언트 생성
     let binance_client = BinanceClient::new(bot_config.binance_ws_url.clone());
     
-    // 주문 서비스 생성
-    let order_service = OrderService::new(
-        db.clone(),
-        app_state.engine.clone(),
-    );
+    // 주문 서비스 (API와 같은 인스턴스, 거래쌍 상태 검사 공유)
+    let order_service = app_state.cex_state.order_service.clone();
     
     // 오더북 동기화 서비스 생성
     let mut orderbook_sync = OrderbookSync::new(
@@ -275,6 +535,7 @@ async fn main() {
             Method::GET,
             Method::POST,
             Method::PUT,
+            Method::PATCH,
             Method::DELETE,
             Method::OPTIONS,
         ])
@@ -282,12 +543,36 @@ async fn main() {
             axum::http::header::CONTENT_TYPE,
             axum::http::header::AUTHORIZATION,
             axum::http::header::ACCEPT,
+            // 내부 이체 멱등 키
+            axum::http::HeaderName::from_static("idempotency-key"),
+            // 서브 계정 대신 주문/조회
+            axum::http::HeaderName::from_static("x-sub-account-id"),
+            // API 키 요청 서명
+            axum::http::HeaderName::from_static("x-api-key"),
+            axum::http::HeaderName::from_static("x-api-timestamp"),
+            axum::http::HeaderName::from_static("x-api-signature"),
+        ])
+        // 목록 API의 다음 페이지 커서 (브라우저에서 읽을 수 있도록 노출)
+        .expose_headers([
+            axum::http::HeaderName::from_static("x-next-cursor"),
+            // 요청 제한 상태
+            axum::http::HeaderName::from_static("ratelimit-limit"),
+            axum::http::HeaderName::from_static("ratelimit-remaining"),
+            axum::http::HeaderName::from_static("ratelimit-reset"),
+            axum::http::HeaderName::from_static("x-order-ratelimit-limit"),
+            axum::http::HeaderName::from_static("x-order-ratelimit-remaining"),
+            axum::http::HeaderName::from_static("x-order-ratelimit-reset"),
+            axum::http::header::RETRY_AFTER,
         ])
         .allow_credentials(true);
 
-    // Router 생성
+    // Router 생성 (API 키 서명 검증 → 요청 제한, API 라우트에만)
     let app = Router::new()
-        .merge(create_router())
+        .merge(
+            create_router()
+                .layer(axum::middleware::from_fn_with_state(app_state.clone(), rate_limit))
+                .layer(axum::middleware::from_fn_with_state(app_state.clone(), api_key_auth)),
+        )
         .merge(
             SwaggerUi::new("/api")
                 .url("/api-docs/openapi.json", ApiDoc::openapi())
@@ -304,8 +589,8 @@ async fn main() {
     eprintln!("[Main] Swagger UI available at http://localhost:3002/api");
     eprintln!("[Main] Database: PostgreSQL (solana_api)");
     
-    // 서버 실행
-    axum::serve(listener, app)
+    // 서버 실행 (연결 주소: API 키 IP 허용 목록, IP별 요청 제한)
+    axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>())
         .await
         .unwrap();
 }
@@ -10,7 +10,7 @@ use crate::domains::auth::routes::create_auth_router;
 use crate::domains::wallet::routes::create_wallet_router;
 use crate::domains::swap::routes::{create_swap_router, create_tokens_router};
 use crate::domains::cex::routes::create_cex_router;
-use crate::domains::bot::routes::create_bot_router;
+use crate::domains::admin::routes::create_admin_router;
 
 /// Create main router (combines all domain routers)
 /// 메인 라우터 생성 (모든 도메인 라우터 조합)
@@ -21,5 +21,5 @@ pub fn create_router() -> Router<AppState> {
         .nest("/api/swap", create_swap_router())
         .nest("/api/tokens", create_tokens_router())
         .nest("/api/cex", create_cex_router())
-        .nest("/api/bot", create_bot_router())
+        .nest("/api/admin", create_admin_router())
 }
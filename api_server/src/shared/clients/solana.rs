@@ -1,5 +1,8 @@
 use anyhow::{Context, Result};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_request::RpcRequest;
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     pubkey::Pubkey,
@@ -12,9 +15,23 @@ use solana_sdk::{
     system_instruction,
     signature::Signature,
 };
+use serde_json::json;
 use std::str::FromStr;
 use std::sync::Arc;
 
+/// getSignatureStatuses 한 번에 조회할 수 있는 최대 서명 수
+const MAX_SIGNATURE_STATUSES: usize = 256;
+
+/// 트랜잭션 확정 상태
+/// Transaction commitment state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureState {
+    /// finalized 커밋 도달 여부 (롤백 불가)
+    pub finalized: bool,
+    /// 트랜잭션 실행 실패 여부
+    pub failed: bool,
+}
+
 /// Solana RPC 클라이언트
 /// 로컬 노드 (http://localhost:8899)와 통신
 #[derive(Clone)]
@@ -103,6 +120,27 @@ impl SolanaClient {
             .context("Failed to get latest blockhash")
     }
 
+    /// 최신 블록해시 + 유효 마지막 블록 높이 조회
+    /// Get latest blockhash with the last block height at which it is valid
+    ///
+    /// # Returns
+    /// (블록해시, last_valid_block_height) - 현재 블록 높이가 이 값을 넘으면 트랜잭션은 만료
+    pub async fn get_latest_blockhash_with_height(&self) -> Result<(Hash, u64)> {
+        self.rpc_client
+            .get_latest_blockhash_with_commitment(self.commitment)
+            .await
+            .context("Failed to get latest blockhash")
+    }
+
+    /// 현재 블록 높이 조회
+    /// Get current block height
+    pub async fn get_block_height(&self) -> Result<u64> {
+        self.rpc_client
+            .get_block_height()
+            .await
+            .context("Failed to get block height")
+    }
+
     /// SOL 전송 트랜잭션 생성
     /// Create SOL transfer transaction
     pub async fn create_transfer_transaction(
@@ -177,6 +215,102 @@ impl SolanaClient {
         }
     }
 
+    /// 주소 관련 트랜잭션 서명 조회 (최신순, confirmed 이상)
+    /// Get signatures for address, newest first
+    ///
+    /// # Arguments
+    /// * `before` - 이 서명보다 오래된 것부터 (페이지 이동)
+    /// * `until` - 이 서명까지 (포함하지 않음, 마지막으로 처리한 서명)
+    /// * `limit` - 최대 개수 (RPC 최대 1000)
+    pub async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let parse = |signature: Option<&str>| -> Result<Option<Signature>> {
+            signature
+                .map(|s| s.parse().context(format!("Failed to parse signature: {}", s)))
+                .transpose()
+        };
+
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before: parse(before)?,
+            until: parse(until)?,
+            limit: Some(limit),
+            commitment: Some(self.commitment),
+        };
+
+        self.rpc_client
+            .get_signatures_for_address_with_config(address, config)
+            .await
+            .context(format!("Failed to get signatures for {}", address))
+    }
+
+    /// 트랜잭션 조회 (jsonParsed 인코딩, 원본 JSON)
+    /// Get transaction as raw jsonParsed JSON
+    ///
+    /// # Returns
+    /// 아직 조회할 수 없으면 None
+    pub async fn get_transaction_json(&self, signature: &str) -> Result<Option<serde_json::Value>> {
+        let params = json!([
+            signature,
+            {
+                "encoding": "jsonParsed",
+                "commitment": self.commitment.commitment,
+                "maxSupportedTransactionVersion": 0,
+            }
+        ]);
+
+        self.rpc_client
+            .send(RpcRequest::GetTransaction, params)
+            .await
+            .context(format!("Failed to get transaction {}", signature))
+    }
+
+    /// 여러 트랜잭션 확정 상태 조회
+    /// Get commitment state of signatures
+    ///
+    /// # Arguments
+    /// * `signatures` - 조회할 서명 목록
+    /// * `search_history` - 최근 상태 캐시에 없으면 원장 기록까지 조회 (오래된 트랜잭션 확인용)
+    ///
+    /// # Returns
+    /// 입력 순서대로 상태 (노드가 모르는 서명은 None)
+    pub async fn get_signature_states(
+        &self,
+        signatures: &[String],
+        search_history: bool,
+    ) -> Result<Vec<Option<SignatureState>>> {
+        let mut states = Vec::with_capacity(signatures.len());
+
+        for chunk in signatures.chunks(MAX_SIGNATURE_STATUSES) {
+            let parsed = chunk
+                .iter()
+                .map(|s| s.parse().context(format!("Failed to parse signature: {}", s)))
+                .collect::<Result<Vec<Signature>>>()?;
+
+            let statuses = if search_history {
+                self.rpc_client.get_signature_statuses_with_history(&parsed).await
+            } else {
+                self.rpc_client.get_signature_statuses(&parsed).await
+            };
+            let statuses = statuses
+                .context("Failed to get signature statuses")?
+                .value;
+
+            states.extend(statuses.into_iter().map(|status| {
+                status.map(|status| SignatureState {
+                    finalized: status.satisfies_commitment(CommitmentConfig::finalized()),
+                    failed: status.err.is_some(),
+                })
+            }));
+        }
+
+        Ok(states)
+    }
+
     /// RPC URL 반환
     /// Get RPC URL
     pub fn rpc_url(&self) -> &str {
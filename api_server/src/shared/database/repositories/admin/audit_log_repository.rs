@@ -0,0 +1,120 @@
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
+use anyhow::{Context, Result};
+use crate::domains::admin::models::audit_log::{AuditLog, AuditLogFilter};
+use crate::shared::utils::cursor::{Page, PageCursor};
+
+/// 관리자 감사 로그 Repository (추가 / 조회만)
+/// Admin audit log repository
+pub struct AuditLogRepository {
+    pool: PgPool,
+}
+
+impl AuditLogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 감사 로그 추가
+    /// Insert audit log entry
+    pub async fn insert(
+        &self,
+        actor_user_id: u64,
+        action: &str,
+        target_type: &str,
+        target_id: Option<&str>,
+        details: &serde_json::Value,
+    ) -> Result<u64> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO admin_audit_logs (actor_user_id, action, target_type, target_id, details)
+            VALUES ($1, $2, $3, $4, $5::JSONB)
+            RETURNING id
+            "#,
+        )
+        .bind(actor_user_id as i64)
+        .bind(action)
+        .bind(target_type)
+        .bind(target_id)
+        .bind(details.to_string())
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to insert audit log")?;
+
+        Ok(row.get::<i64, _>("id") as u64)
+    }
+
+    /// 감사 로그 페이지 조회 (최신순, keyset)
+    /// Get a page of audit logs (newest first)
+    pub async fn get_page(
+        &self,
+        filter: &AuditLogFilter,
+        cursor: Option<PageCursor>,
+        limit: i64,
+    ) -> Result<Page<AuditLog>> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT id, actor_user_id, action, target_type, target_id, details::TEXT AS details, created_at
+            FROM admin_audit_logs
+            WHERE TRUE"#,
+        );
+
+        if let Some(actor_user_id) = filter.actor_user_id {
+            query.push(" AND actor_user_id = ").push_bind(actor_user_id as i64);
+        }
+        if let Some(action) = &filter.action {
+            query.push(" AND action = ").push_bind(action.clone());
+        }
+        if let Some(target_type) = &filter.target_type {
+            query.push(" AND target_type = ").push_bind(target_type.clone());
+        }
+        if let Some(target_id) = &filter.target_id {
+            query.push(" AND target_id = ").push_bind(target_id.clone());
+        }
+        if let Some(from) = filter.from {
+            query.push(" AND created_at >= ").push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            query.push(" AND created_at < ").push_bind(to);
+        }
+        if let Some(cursor) = cursor {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(cursor.created_at)
+                .push(", ")
+                .push_bind(cursor.id as i64)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit + 1);
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch audit logs")?;
+
+        let logs = rows
+            .iter()
+            .map(Self::row_to_audit_log)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Page::from_rows(logs, limit, |log: &AuditLog| {
+            PageCursor::new(log.created_at, log.id)
+        }))
+    }
+
+    fn row_to_audit_log(row: &sqlx::postgres::PgRow) -> Result<AuditLog> {
+        let details: String = row.get("details");
+
+        Ok(AuditLog {
+            id: row.get::<i64, _>("id") as u64,
+            actor_user_id: row.get::<i64, _>("actor_user_id") as u64,
+            action: row.get("action"),
+            target_type: row.get("target_type"),
+            target_id: row.get("target_id"),
+            details: serde_json::from_str(&details).context("Invalid audit log details")?,
+            created_at: row.get("created_at"),
+        })
+    }
+}
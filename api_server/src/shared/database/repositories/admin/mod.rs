@@ -0,0 +1,4 @@
+// Admin repositories
+pub mod audit_log_repository;
+
+pub use audit_log_repository::*;
@@ -0,0 +1,239 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use crate::domains::auth::models::api_key::{ApiKey, ApiKeyScope};
+use crate::domains::wallet::models::EncryptedKey;
+
+/// API 키 Repository
+/// API key repository
+pub struct ApiKeyRepository {
+    pool: PgPool,
+}
+
+impl ApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// API 키 생성 (secret은 봉투 암호화한 값)
+    /// Create API key with an envelope-encrypted secret
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        user_id: u64,
+        key_id: &str,
+        secret: &EncryptedKey,
+        label: &str,
+        scopes: &[ApiKeyScope],
+        ip_allowlist: Option<&[String]>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<ApiKey> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO api_keys (user_id, key_id, secret, wrapped_data_key, key_version,
+                                  label, scopes, ip_allowlist, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id, user_id, key_id, secret, wrapped_data_key, key_version, label, scopes, ip_allowlist,
+                      expires_at, last_used_at, revoked_at, created_at
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(key_id)
+        .bind(&secret.encrypted_private_key)
+        .bind(&secret.wrapped_data_key)
+        .bind(secret.key_version as i32)
+        .bind(label)
+        .bind(scopes.iter().map(|scope| scope.as_str().to_string()).collect::<Vec<_>>())
+        .bind(ip_allowlist.map(|ips| ips.to_vec()))
+        .bind(expires_at)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to create API key")?;
+
+        Self::row_to_api_key(&row)
+    }
+
+    /// 사용자 API 키 목록 (최신순, 폐기된 키 포함)
+    /// List user's API keys
+    pub async fn list_by_user(&self, user_id: u64) -> Result<Vec<ApiKey>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, key_id, secret, wrapped_data_key, key_version, label, scopes, ip_allowlist,
+                   expires_at, last_used_at, revoked_at, created_at
+            FROM api_keys
+            WHERE user_id = $1
+            ORDER BY created_at DESC, id DESC
+            "#,
+        )
+        .bind(user_id as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch API keys")?;
+
+        rows.iter().map(Self::row_to_api_key).collect()
+    }
+
+    /// 공개 키 ID로 API 키 + 소유자 이메일 조회
+    /// Get API key and owner email by public key ID
+    pub async fn get_by_key_id(&self, key_id: &str) -> Result<Option<(ApiKey, String)>> {
+        let row = sqlx::query(
+            r#"
+            SELECT k.id, k.user_id, k.key_id, k.secret, k.wrapped_data_key, k.key_version, k.label, k.scopes, k.ip_allowlist,
+                   k.expires_at, k.last_used_at, k.revoked_at, k.created_at, u.email
+            FROM api_keys k
+            JOIN users u ON u.id = k.user_id
+            WHERE k.key_id = $1
+            "#,
+        )
+        .bind(key_id)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch API key")?;
+
+        row.map(|row| Ok((Self::row_to_api_key(&row)?, row.get("email")))).transpose()
+    }
+
+    /// API 키 폐기
+    /// Revoke API key
+    ///
+    /// # Returns
+    /// 폐기되었는지 (없거나 이미 폐기된 키면 false)
+    pub async fn revoke(&self, user_id: u64, id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE api_keys SET revoked_at = $3
+            WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(id as i64)
+        .bind(user_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to revoke API key")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 마지막 사용 시간 갱신 (1분에 한 번까지)
+    /// Touch last used timestamp (at most once a minute)
+    pub async fn touch_last_used(&self, id: u64) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE api_keys SET last_used_at = NOW()
+            WHERE id = $1 AND (last_used_at IS NULL OR last_used_at < NOW() - INTERVAL '1 minute')
+            "#,
+        )
+        .bind(id as i64)
+        .execute(&self.pool)
+        .await
+        .context("Failed to update API key last used time")?;
+
+        Ok(())
+    }
+
+    /// 사용된 요청 서명 기록
+    /// Record a used request signature
+    ///
+    /// # Returns
+    /// 새로 기록했으면 true (이미 있으면 재사용된 서명)
+    pub async fn record_signature(&self, key_id: &str, signature: &str, expires_at: DateTime<Utc>) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO api_key_signatures (signature, key_id, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (signature) DO NOTHING
+            "#,
+        )
+        .bind(signature)
+        .bind(key_id)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .context("Failed to record API key signature")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// 만료된 요청 서명 삭제
+    /// Delete expired request signatures
+    ///
+    /// # Returns
+    /// 삭제된 행 수
+    pub async fn delete_expired_signatures(&self) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM api_key_signatures WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await
+            .context("Failed to delete expired API key signatures")?;
+
+        Ok(result.rows_affected())
+    }
+
+    // 재암호화 대상 키 (현재 마스터 키 버전이 아닌 행, 폐기된 키 포함)
+    // Keys whose secret is plaintext or wrapped by an older master key version
+    pub async fn get_keys_for_rotation(&self, key_version: u32, after_id: u64, limit: i64) -> Result<Vec<ApiKey>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, key_id, secret, wrapped_data_key, key_version, label, scopes, ip_allowlist,
+                   expires_at, last_used_at, revoked_at, created_at
+            FROM api_keys
+            WHERE key_version IS DISTINCT FROM $1 AND id > $2
+            ORDER BY id ASC
+            LIMIT $3
+            "#,
+        )
+        .bind(key_version as i32)
+        .bind(after_id as i64)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch API keys for key rotation")?;
+
+        rows.iter().map(Self::row_to_api_key).collect()
+    }
+
+    // 암호화한 secret 교체 (조회 후 다른 곳에서 바뀌지 않았을 때만)
+    // Replace the stored secret envelope if key_version is still the one that was read
+    //
+    // Returns: 교체했으면 true
+    pub async fn update_secret(&self, id: u64, expected_key_version: Option<u32>, secret: &EncryptedKey) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET secret = $3, wrapped_data_key = $4, key_version = $5
+            WHERE id = $1 AND key_version IS NOT DISTINCT FROM $2
+            "#,
+        )
+        .bind(id as i64)
+        .bind(expected_key_version.map(|version| version as i32))
+        .bind(&secret.encrypted_private_key)
+        .bind(&secret.wrapped_data_key)
+        .bind(secret.key_version as i32)
+        .execute(&self.pool)
+        .await
+        .context("Failed to update API key secret")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    fn row_to_api_key(row: &sqlx::postgres::PgRow) -> Result<ApiKey> {
+        let scopes: Vec<String> = row.get("scopes");
+
+        Ok(ApiKey {
+            id: row.get::<i64, _>("id") as u64,
+            user_id: row.get::<i64, _>("user_id") as u64,
+            key_id: row.get("key_id"),
+            secret: row.get("secret"),
+            wrapped_data_key: row.get("wrapped_data_key"),
+            key_version: row.get::<Option<i32>, _>("key_version").map(|version| version as u32),
+            label: row.get("label"),
+            scopes: scopes.iter().map(|scope| scope.parse()).collect::<Result<_>>()?,
+            ip_allowlist: row.get("ip_allowlist"),
+            expires_at: row.get("expires_at"),
+            last_used_at: row.get("last_used_at"),
+            revoked_at: row.get("revoked_at"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use sqlx::{PgPool, Row};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use crate::domains::auth::models::role::Role;
 use crate::domains::auth::models::user::User;
+use std::collections::HashMap;
 
 
 pub struct UserRepository {
@@ -23,7 +25,7 @@ impl UserRepository {
             r#"
             INSERT INTO users (email, password_hash, username, created_at, updated_at)
             VALUES ($1, $2, $3, $4, $5)
-            RETURNING id, email, password_hash, username, created_at, updated_at
+            RETURNING id, email, password_hash, username, role, frozen_at, frozen_reason, fee_tier, created_at, updated_at
             "#,
         )
         .bind(email)
@@ -40,6 +42,10 @@ impl UserRepository {
             email: row.get("email"),
             password_hash: row.get("password_hash"),
             username: row.get("username"),
+            role: row.get::<String, _>("role").parse()?,
+            frozen_at: row.get("frozen_at"),
+            frozen_reason: row.get("frozen_reason"),
+            fee_tier: row.get("fee_tier"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         })
@@ -50,7 +56,7 @@ impl UserRepository {
     pub async fn get_user_by_email(&self, email: &str) -> Result<Option<User>> {
         let row = sqlx::query(
             r#"
-            SELECT id, email, password_hash, username, created_at, updated_at
+            SELECT id, email, password_hash, username, role, frozen_at, frozen_reason, fee_tier, created_at, updated_at
             FROM users
             WHERE email = $1
             "#,
@@ -70,6 +76,10 @@ impl UserRepository {
             email: row.get("email"),
             password_hash: row.get("password_hash"),
             username: row.get("username"),
+            role: row.get::<String, _>("role").parse()?,
+            frozen_at: row.get("frozen_at"),
+            frozen_reason: row.get("frozen_reason"),
+            fee_tier: row.get("fee_tier"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         }))
@@ -80,7 +90,7 @@ impl UserRepository {
     pub async fn get_user_by_id(&self, id: u64) -> Result<Option<User>> {
         let row = sqlx::query(
             r#"
-            SELECT id, email, password_hash, username, created_at, updated_at
+            SELECT id, email, password_hash, username, role, frozen_at, frozen_reason, fee_tier, created_at, updated_at
             FROM users
             WHERE id = $1
             "#,
@@ -100,8 +110,127 @@ impl UserRepository {
             email: row.get("email"),
             password_hash: row.get("password_hash"),
             username: row.get("username"),
+            role: row.get::<String, _>("role").parse()?,
+            frozen_at: row.get("frozen_at"),
+            frozen_reason: row.get("frozen_reason"),
+            fee_tier: row.get("fee_tier"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         }))
     }
+
+    // 역할 변경
+    // Set user role
+    pub async fn set_role(&self, id: u64, role: Role) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET role = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id as i64)
+        .bind(role.as_str())
+        .execute(&self.pool)
+        .await
+        .context("Failed to set user role")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 역할 조회 (관리자 API 요청마다 확인)
+    // Get user role
+    pub async fn get_role(&self, id: u64) -> Result<Option<Role>> {
+        let row = sqlx::query("SELECT role FROM users WHERE id = $1")
+            .bind(id as i64)
+            .fetch_optional(&self.pool)
+            .await
+            .context("Failed to fetch user role")?;
+
+        row.map(|row| row.get::<String, _>("role").parse()).transpose()
+    }
+
+    // 계정 동결 / 해제 (reason이 None이면 해제)
+    // Freeze or unfreeze a user
+    pub async fn set_frozen(&self, id: u64, reason: Option<&str>) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET frozen_at = CASE WHEN $2::TEXT IS NULL THEN NULL ELSE NOW() END,
+                frozen_reason = $2,
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id as i64)
+        .bind(reason)
+        .execute(&self.pool)
+        .await
+        .context("Failed to set user frozen state")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 동결된 사용자 ID 목록 (서버 시작 시 캐시 로드)
+    // List frozen user IDs
+    pub async fn list_frozen_ids(&self) -> Result<Vec<u64>> {
+        let rows = sqlx::query("SELECT id FROM users WHERE frozen_at IS NOT NULL")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch frozen users")?;
+
+        Ok(rows.iter().map(|row| row.get::<i64, _>("id") as u64).collect())
+    }
+
+    // 수수료 등급 변경 (None이면 등급 해제)
+    // Set user fee tier
+    pub async fn set_fee_tier(&self, id: u64, fee_tier: Option<&str>) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET fee_tier = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id as i64)
+        .bind(fee_tier)
+        .execute(&self.pool)
+        .await
+        .context("Failed to set user fee tier")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 수수료 등급이 있는 사용자 목록 (엔진 수수료 스케줄용)
+    // List users with a fee tier
+    pub async fn list_fee_tiers(&self) -> Result<HashMap<u64, String>> {
+        let rows = sqlx::query("SELECT id, fee_tier FROM users WHERE fee_tier IS NOT NULL")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch user fee tiers")?;
+
+        Ok(rows
+            .iter()
+            .map(|row| (row.get::<i64, _>("id") as u64, row.get("fee_tier")))
+            .collect())
+    }
+
+    // 관리자 역할 부여 (ADMIN_USER_IDS 부트스트랩)
+    // Grant admin role to the given users
+    pub async fn grant_admin(&self, ids: &[u64]) -> Result<u64> {
+        let ids: Vec<i64> = ids.iter().map(|id| *id as i64).collect();
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET role = 'admin', updated_at = NOW()
+            WHERE id = ANY($1) AND role <> 'admin'
+            "#,
+        )
+        .bind(&ids)
+        .execute(&self.pool)
+        .await
+        .context("Failed to grant admin role")?;
+
+        Ok(result.rows_affected())
+    }
 }
@@ -0,0 +1,155 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use crate::domains::cex::models::candle::{Candle, CandleInterval};
+
+/// 캔들 Repository
+/// Candle repository
+///
+/// 캔들은 CandleService가 메모리에서 집계한 최신 값으로 덮어씁니다 (upsert, 멱등).
+pub struct CandleRepository {
+    pool: PgPool,
+}
+
+impl CandleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 캔들 일괄 저장 (같은 키는 덮어씀)
+    /// Bulk upsert candles
+    ///
+    /// # Arguments
+    /// * `candles` - 저장할 캔들 (거래쌍/주기/시작 시간 중복 없이)
+    pub async fn upsert_many(&self, candles: &[Candle]) -> Result<()> {
+        if candles.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO candles (
+                base_mint, quote_mint, interval, open_time,
+                open, high, low, close, volume, quote_volume, trade_count, updated_at
+            )
+            SELECT *, NOW() FROM UNNEST(
+                $1::TEXT[], $2::TEXT[], $3::TEXT[], $4::TIMESTAMPTZ[],
+                $5::NUMERIC[], $6::NUMERIC[], $7::NUMERIC[], $8::NUMERIC[],
+                $9::NUMERIC[], $10::NUMERIC[], $11::BIGINT[]
+            )
+            ON CONFLICT (base_mint, quote_mint, interval, open_time) DO UPDATE SET
+                open = EXCLUDED.open,
+                high = EXCLUDED.high,
+                low = EXCLUDED.low,
+                close = EXCLUDED.close,
+                volume = EXCLUDED.volume,
+                quote_volume = EXCLUDED.quote_volume,
+                trade_count = EXCLUDED.trade_count,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(candles.iter().map(|c| c.base_mint.clone()).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.quote_mint.clone()).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.interval.as_str().to_string()).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.open_time).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.open).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.high).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.low).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.close).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.volume).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.quote_volume).collect::<Vec<_>>())
+        .bind(candles.iter().map(|c| c.trade_count as i64).collect::<Vec<_>>())
+        .execute(&self.pool)
+        .await
+        .with_context(|| format!("Failed to upsert {} candles", candles.len()))?;
+
+        Ok(())
+    }
+
+    /// 기간별 캔들 조회 (open_time 오름차순)
+    /// Get candles in range
+    ///
+    /// # Arguments
+    /// * `from` - 시작 시간 (포함, open_time 기준)
+    /// * `to` - 끝 시간 (미포함, open_time 기준)
+    /// * `limit` - 최대 개수 (범위가 더 넓으면 `to`에 가까운 캔들 우선)
+    pub async fn get_range(
+        &self,
+        base_mint: &str,
+        quote_mint: &str,
+        interval: CandleInterval,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Candle>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT base_mint, quote_mint, interval, open_time,
+                   open, high, low, close, volume, quote_volume, trade_count
+            FROM candles
+            WHERE base_mint = $1 AND quote_mint = $2 AND interval = $3
+              AND open_time >= $4 AND open_time < $5
+            ORDER BY open_time DESC
+            LIMIT $6
+            "#,
+        )
+        .bind(base_mint)
+        .bind(quote_mint)
+        .bind(interval.as_str())
+        .bind(from)
+        .bind(to)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch candles")?;
+
+        let mut candles = rows
+            .iter()
+            .map(|r| self.row_to_candle(r))
+            .collect::<Result<Vec<_>>>()?;
+        candles.reverse();
+        Ok(candles)
+    }
+
+    /// 가장 최근 캔들의 시작 시간 (backfill 재개 위치)
+    /// Get open time of the latest candle
+    pub async fn get_latest_open_time(
+        &self,
+        base_mint: &str,
+        quote_mint: &str,
+        interval: CandleInterval,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let row = sqlx::query(
+            r#"
+            SELECT MAX(open_time) AS open_time
+            FROM candles
+            WHERE base_mint = $1 AND quote_mint = $2 AND interval = $3
+            "#,
+        )
+        .bind(base_mint)
+        .bind(quote_mint)
+        .bind(interval.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to fetch latest candle open time")?;
+
+        Ok(row.get("open_time"))
+    }
+
+    /// Row를 Candle로 변환하는 헬퍼 메서드
+    fn row_to_candle(&self, row: &sqlx::postgres::PgRow) -> Result<Candle> {
+        Ok(Candle {
+            base_mint: row.get("base_mint"),
+            quote_mint: row.get("quote_mint"),
+            interval: row.get::<String, _>("interval").parse()?,
+            open_time: row.get("open_time"),
+            open: row.get("open"),
+            high: row.get("high"),
+            low: row.get("low"),
+            close: row.get("close"),
+            volume: row.get("volume"),
+            quote_volume: row.get("quote_volume"),
+            trade_count: row.get::<i64, _>("trade_count") as u64,
+        })
+    }
+}
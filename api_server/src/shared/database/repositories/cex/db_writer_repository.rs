@@ -0,0 +1,121 @@
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use crate::domains::cex::engine::runtime::db_commands::{DbWriteCursor, PendingDbCommand};
+
+/// DB Writer 상태 Repository
+/// DB Writer state repository
+///
+/// 엔진 DB Writer 스레드의 high-water mark와 dead-letter 명령을 관리합니다.
+///
+/// # 트랜잭션
+/// high-water mark 저장과 dead-letter 기록은 배치 트랜잭션 안에서 호출해야
+/// 명령 반영과 원자적으로 처리됩니다.
+pub struct DbWriterStateRepository {
+    pool: PgPool,
+}
+
+impl DbWriterStateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 저장된 high-water mark 조회
+    /// Get persisted high-water mark
+    ///
+    /// # Returns
+    /// DB에 반영된 마지막 명령 위치 (행이 없으면 기본값)
+    pub async fn get_high_water_mark(&self) -> Result<DbWriteCursor> {
+        let row = sqlx::query(
+            r#"
+            SELECT high_water_seq, high_water_index
+            FROM db_writer_state
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch DB writer high-water mark")?;
+
+        Ok(row
+            .map(|r| DbWriteCursor {
+                wal_seq: r.get::<i64, _>("high_water_seq") as u64,
+                index: r.get::<i32, _>("high_water_index") as u32,
+            })
+            .unwrap_or_default())
+    }
+
+    /// high-water mark 저장 (트랜잭션 안에서)
+    /// Save high-water mark within a transaction
+    ///
+    /// # Arguments
+    /// * `tx` - 배치 트랜잭션
+    /// * `cursor` - 반영 완료된 마지막 명령 위치
+    ///
+    /// # Note
+    /// 현재 값보다 작은 위치로는 되돌리지 않습니다.
+    pub async fn save_high_water_mark(
+        tx: &mut Transaction<'_, Postgres>,
+        cursor: DbWriteCursor,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO db_writer_state (id, high_water_seq, high_water_index, updated_at)
+            VALUES (1, $1, $2, $3)
+            ON CONFLICT (id) DO UPDATE SET
+                high_water_seq = EXCLUDED.high_water_seq,
+                high_water_index = EXCLUDED.high_water_index,
+                updated_at = EXCLUDED.updated_at
+            WHERE (db_writer_state.high_water_seq, db_writer_state.high_water_index)
+                < (EXCLUDED.high_water_seq, EXCLUDED.high_water_index)
+            "#,
+        )
+        .bind(cursor.wal_seq as i64)
+        .bind(cursor.index as i32)
+        .bind(Utc::now())
+        .execute(&mut **tx)
+        .await
+        .context("Failed to save DB writer high-water mark")?;
+
+        Ok(())
+    }
+
+    /// dead-letter 명령 기록 (트랜잭션 안에서)
+    /// Insert dead-letter command within a transaction
+    ///
+    /// # Arguments
+    /// * `tx` - 트랜잭션
+    /// * `pending` - 반영에 실패한 명령
+    /// * `error` - 마지막 에러 메시지
+    /// * `attempts` - 시도 횟수
+    pub async fn insert_dead_letter(
+        tx: &mut Transaction<'_, Postgres>,
+        pending: &PendingDbCommand,
+        error: &str,
+        attempts: u32,
+    ) -> Result<()> {
+        let payload = serde_json::to_string(&pending.command)
+            .context("Failed to serialize dead-letter command")?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO db_writer_dead_letters (
+                wal_seq, command_index, command_type, payload, error, attempts, created_at
+            )
+            VALUES ($1, $2, $3, $4::jsonb, $5, $6, $7)
+            "#,
+        )
+        .bind(pending.cursor.wal_seq as i64)
+        .bind(pending.cursor.index as i32)
+        .bind(pending.command.kind())
+        .bind(payload)
+        .bind(error)
+        .bind(attempts as i32)
+        .bind(Utc::now())
+        .execute(&mut **tx)
+        .await
+        .context("Failed to insert dead-letter command")?;
+
+        Ok(())
+    }
+}
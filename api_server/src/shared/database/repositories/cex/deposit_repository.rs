@@ -0,0 +1,237 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{BoxStream, StreamExt};
+use crate::domains::cex::models::deposit::{Deposit, DepositCreate, DepositStatus};
+
+/// 입금 Repository
+/// Deposit repository
+pub struct DepositRepository {
+    pool: PgPool,
+}
+
+impl DepositRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 입금 기록 (같은 서명 + 자산이 이미 있으면 건너뜀)
+    /// Insert deposit unless (signature, mint) already exists
+    ///
+    /// # Returns
+    /// 새로 기록했으면 true
+    pub async fn insert_if_new(&self, deposit: &DepositCreate) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO deposits (user_id, mint, token_mint, amount, address, signature, slot, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (signature, mint) DO NOTHING
+            "#,
+        )
+        .bind(deposit.user_id as i64)
+        .bind(&deposit.mint)
+        .bind(&deposit.token_mint)
+        .bind(deposit.amount)
+        .bind(&deposit.address)
+        .bind(&deposit.signature)
+        .bind(deposit.slot as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .with_context(|| format!("Failed to insert deposit {}", deposit.signature))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 상태별 입금 조회 (오래된 순)
+    /// Get deposits by status
+    pub async fn get_by_status(&self, status: DepositStatus, limit: i64) -> Result<Vec<Deposit>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, address, signature, slot, status, error,
+                   created_at, finalized_at, credited_at
+            FROM deposits
+            WHERE status = $1
+            ORDER BY id ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(status.as_str())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch deposits by status")?;
+
+        rows.iter().map(Self::row_to_deposit).collect()
+    }
+
+    /// 입금 처리 선점 (confirming → crediting)
+    /// Claim a finalized deposit for crediting
+    ///
+    /// # Returns
+    /// 선점에 성공했으면 true (이미 다른 곳에서 처리했으면 false)
+    pub async fn claim_for_credit(&self, deposit_id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE deposits SET status = 'crediting', finalized_at = $2
+            WHERE id = $1 AND status = 'confirming'
+            "#,
+        )
+        .bind(deposit_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to claim deposit")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 입금 완료
+    /// Mark deposit as credited
+    pub async fn mark_credited(&self, deposit_id: u64) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE deposits SET status = 'credited', error = NULL, credited_at = $2
+            WHERE id = $1 AND status = 'crediting'
+            "#,
+        )
+        .bind(deposit_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark deposit credited")?;
+        Ok(())
+    }
+
+    /// 입금 실패 (confirming 상태만)
+    /// Mark deposit as failed
+    pub async fn mark_failed(&self, deposit_id: u64, error: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE deposits SET status = 'failed', error = $2
+            WHERE id = $1 AND status = 'confirming'
+            "#,
+        )
+        .bind(deposit_id as i64)
+        .bind(error)
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark deposit failed")?;
+        Ok(())
+    }
+
+    /// 오류 기록 (상태 유지, 수동 확인용)
+    /// Record error without changing status
+    pub async fn set_error(&self, deposit_id: u64, error: &str) -> Result<()> {
+        sqlx::query("UPDATE deposits SET error = $2 WHERE id = $1")
+            .bind(deposit_id as i64)
+            .bind(error)
+            .execute(&self.pool)
+            .await
+            .context("Failed to record deposit error")?;
+        Ok(())
+    }
+
+    /// 사용자 입금 목록 (최신순)
+    /// List user's deposits, newest first
+    pub async fn list_by_user(&self, user_id: u64, mint: Option<&str>, limit: i64) -> Result<Vec<Deposit>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, address, signature, slot, status, error,
+                   created_at, finalized_at, credited_at
+            FROM deposits
+            WHERE user_id = $1 AND ($2::TEXT IS NULL OR mint = $2)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch deposits")?;
+
+        rows.iter().map(Self::row_to_deposit).collect()
+    }
+
+    /// 기간 내 사용자 입금 스트리밍 조회 (발견 시간 오름차순, 내보내기용)
+    /// Stream user's deposits detected in a time range
+    ///
+    /// # Arguments
+    /// * `from` - 시작 시간 (포함)
+    /// * `to` - 끝 시간 (미포함)
+    pub fn stream_by_user(
+        &self,
+        user_id: u64,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<Deposit>> {
+        sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, address, signature, slot, status, error,
+                   created_at, finalized_at, credited_at
+            FROM deposits
+            WHERE user_id = $1 AND created_at >= $2 AND created_at < $3
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(from)
+        .bind(to)
+        .fetch(&self.pool)
+        .map(|row| Self::row_to_deposit(&row.context("Failed to stream deposits")?))
+        .boxed()
+    }
+
+    /// 주소별 마지막 처리 서명
+    /// Get last processed signature for address
+    pub async fn get_cursor(&self, address: &str) -> Result<Option<String>> {
+        sqlx::query_scalar::<_, String>("SELECT last_signature FROM deposit_cursors WHERE address = $1")
+            .bind(address)
+            .fetch_optional(&self.pool)
+            .await
+            .context("Failed to fetch deposit cursor")
+    }
+
+    /// 주소별 마지막 처리 서명 저장
+    /// Save last processed signature for address
+    pub async fn set_cursor(&self, address: &str, last_signature: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO deposit_cursors (address, last_signature, updated_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (address) DO UPDATE SET
+                last_signature = EXCLUDED.last_signature,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(address)
+        .bind(last_signature)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to save deposit cursor")?;
+        Ok(())
+    }
+
+    fn row_to_deposit(row: &sqlx::postgres::PgRow) -> Result<Deposit> {
+        let status: String = row.get("status");
+
+        Ok(Deposit {
+            id: row.get::<i64, _>("id") as u64,
+            user_id: row.get::<i64, _>("user_id") as u64,
+            mint: row.get("mint"),
+            token_mint: row.get("token_mint"),
+            amount: row.get("amount"),
+            address: row.get("address"),
+            signature: row.get("signature"),
+            slot: row.get::<i64, _>("slot") as u64,
+            status: status.parse()?,
+            error: row.get("error"),
+            created_at: row.get("created_at"),
+            finalized_at: row.get("finalized_at"),
+            credited_at: row.get("credited_at"),
+        })
+    }
+}
@@ -0,0 +1,220 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use crate::domains::cex::models::export::{ExportDataset, ExportFormat, ExportJob, ExportStatus};
+
+/// 내보내기 작업 Repository
+/// Export job repository
+pub struct ExportJobRepository {
+    pool: PgPool,
+}
+
+impl ExportJobRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 작업 생성 (pending)
+    /// Create export job
+    pub async fn create(
+        &self,
+        user_id: u64,
+        dataset: ExportDataset,
+        format: ExportFormat,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<ExportJob> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO export_jobs (user_id, dataset, format, range_from, range_to, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, dataset, format, range_from, range_to, status, row_count,
+                      file_path, error, created_at, completed_at, expires_at
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(dataset.as_str())
+        .bind(format.as_str())
+        .bind(from)
+        .bind(to)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to create export job")?;
+
+        Self::row_to_job(&row)
+    }
+
+    /// 사용자 작업 조회
+    /// Get user's export job
+    pub async fn get_by_user(&self, user_id: u64, job_id: u64) -> Result<Option<ExportJob>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, user_id, dataset, format, range_from, range_to, status, row_count,
+                   file_path, error, created_at, completed_at, expires_at
+            FROM export_jobs
+            WHERE id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(job_id as i64)
+        .bind(user_id as i64)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch export job")?;
+
+        row.as_ref().map(Self::row_to_job).transpose()
+    }
+
+    /// 사용자 작업 목록 (최신순)
+    /// List user's export jobs, newest first
+    pub async fn list_by_user(&self, user_id: u64, limit: i64) -> Result<Vec<ExportJob>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, dataset, format, range_from, range_to, status, row_count,
+                   file_path, error, created_at, completed_at, expires_at
+            FROM export_jobs
+            WHERE user_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch export jobs")?;
+
+        rows.iter().map(Self::row_to_job).collect()
+    }
+
+    /// 끝나지 않은 작업 (pending/running, 오래된 순)
+    /// Get unfinished jobs (to resume after restart)
+    pub async fn get_unfinished(&self) -> Result<Vec<ExportJob>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, dataset, format, range_from, range_to, status, row_count,
+                   file_path, error, created_at, completed_at, expires_at
+            FROM export_jobs
+            WHERE status IN ('pending', 'running')
+            ORDER BY id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch unfinished export jobs")?;
+
+        rows.iter().map(Self::row_to_job).collect()
+    }
+
+    /// 기한이 지난 완료 작업
+    /// Get completed jobs past their download deadline
+    pub async fn get_expired(&self, now: DateTime<Utc>) -> Result<Vec<ExportJob>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, dataset, format, range_from, range_to, status, row_count,
+                   file_path, error, created_at, completed_at, expires_at
+            FROM export_jobs
+            WHERE status = 'completed' AND expires_at <= $1
+            "#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch expired export jobs")?;
+
+        rows.iter().map(Self::row_to_job).collect()
+    }
+
+    /// 작성 시작
+    /// Mark job as running
+    pub async fn mark_running(&self, job_id: u64) -> Result<()> {
+        sqlx::query("UPDATE export_jobs SET status = 'running', row_count = 0, error = NULL WHERE id = $1")
+            .bind(job_id as i64)
+            .execute(&self.pool)
+            .await
+            .context("Failed to mark export job running")?;
+        Ok(())
+    }
+
+    /// 완료 (파일 경로, 행 수, 다운로드 기한)
+    /// Mark job as completed
+    pub async fn mark_completed(
+        &self,
+        job_id: u64,
+        row_count: u64,
+        file_path: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE export_jobs SET
+                status = 'completed',
+                row_count = $2,
+                file_path = $3,
+                completed_at = $4,
+                expires_at = $5
+            WHERE id = $1
+            "#,
+        )
+        .bind(job_id as i64)
+        .bind(row_count as i64)
+        .bind(file_path)
+        .bind(Utc::now())
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark export job completed")?;
+        Ok(())
+    }
+
+    /// 실패
+    /// Mark job as failed
+    pub async fn mark_failed(&self, job_id: u64, error: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE export_jobs SET status = 'failed', error = $2, completed_at = $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(job_id as i64)
+        .bind(error)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark export job failed")?;
+        Ok(())
+    }
+
+    /// 만료 (파일 삭제 후 호출)
+    /// Mark job as expired
+    pub async fn mark_expired(&self, job_id: u64) -> Result<()> {
+        sqlx::query("UPDATE export_jobs SET status = 'expired', file_path = NULL WHERE id = $1")
+            .bind(job_id as i64)
+            .execute(&self.pool)
+            .await
+            .context("Failed to mark export job expired")?;
+        Ok(())
+    }
+
+    fn row_to_job(row: &sqlx::postgres::PgRow) -> Result<ExportJob> {
+        let dataset: String = row.get("dataset");
+        let format: String = row.get("format");
+        let status: String = row.get("status");
+
+        Ok(ExportJob {
+            id: row.get::<i64, _>("id") as u64,
+            user_id: row.get::<i64, _>("user_id") as u64,
+            dataset: dataset.parse()?,
+            format: format.parse()?,
+            from: row.get("range_from"),
+            to: row.get("range_to"),
+            status: status.parse::<ExportStatus>()?,
+            row_count: row.get::<i64, _>("row_count") as u64,
+            error: row.get("error"),
+            created_at: row.get("created_at"),
+            completed_at: row.get("completed_at"),
+            expires_at: row.get("expires_at"),
+            file_path: row.get("file_path"),
+        })
+    }
+}
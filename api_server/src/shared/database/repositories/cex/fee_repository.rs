@@ -1,8 +1,8 @@
-use sqlx::{PgPool, Row};
+use sqlx::{PgPool, Postgres, Row, Transaction};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use crate::domains::cex::models::fee::FeeConfig;
+use crate::domains::cex::models::fee::{FeeConfig, FeeConfigCreate, FeeConfigHistory};
 
 pub struct FeeConfigRepository {
     pool: PgPool,
@@ -16,6 +16,8 @@ impl FeeConfigRepository {
     /// 거래쌍별 수수료 설정 조회 (가장 구체적인 것부터 검색)
     /// Get fee config by trading pair (most specific first)
     /// 
+    /// 모든 등급에 적용되고(tier = NULL) 지금 적용 기간인 설정만 봅니다.
+    /// 
     /// 검색 순서:
     /// 1. base_mint와 quote_mint가 정확히 일치하는 설정
     /// 2. base_mint만 일치하는 설정 (quote_mint = NULL)
@@ -29,9 +31,12 @@ impl FeeConfigRepository {
         // 가장 구체적인 설정부터 찾기
         let row = sqlx::query(
             r#"
-            SELECT id, base_mint, quote_mint, fee_rate, fee_type, is_active, created_at, updated_at
+            SELECT id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
             FROM fee_configs
             WHERE is_active = TRUE
+              AND tier IS NULL
+              AND effective_from <= NOW()
+              AND (effective_until IS NULL OR effective_until > NOW())
               AND (
                 (base_mint = $1 AND quote_mint = $2)
                 OR (base_mint = $1 AND quote_mint IS NULL)
@@ -43,7 +48,9 @@ impl FeeConfigRepository {
                    WHEN base_mint IS NOT NULL THEN 2
                    WHEN quote_mint IS NOT NULL THEN 3
                    ELSE 4
-              END
+              END,
+              CASE WHEN fee_type = 'both' THEN 1 ELSE 0 END,
+              effective_from DESC
             LIMIT 1
             "#,
         )
@@ -53,7 +60,7 @@ impl FeeConfigRepository {
         .await
         .context("Failed to fetch fee config")?;
 
-        Ok(row.map(|r| self.row_to_fee_config(&r)))
+        Ok(row.map(|r| Self::row_to_fee_config(&r)))
     }
 
     /// 모든 활성 수수료 설정 조회
@@ -61,7 +68,7 @@ impl FeeConfigRepository {
     pub async fn get_all_active(&self) -> Result<Vec<FeeConfig>> {
         let rows = sqlx::query(
             r#"
-            SELECT id, base_mint, quote_mint, fee_rate, fee_type, is_active, created_at, updated_at
+            SELECT id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
             FROM fee_configs
             WHERE is_active = TRUE
             ORDER BY 
@@ -70,14 +77,14 @@ impl FeeConfigRepository {
                    WHEN quote_mint IS NOT NULL THEN 3
                    ELSE 4
               END,
-              base_mint ASC, quote_mint ASC
+              base_mint ASC, quote_mint ASC, tier ASC NULLS FIRST, fee_type ASC, effective_from ASC
             "#,
         )
         .fetch_all(&self.pool)
         .await
         .context("Failed to fetch all active fee configs")?;
 
-        Ok(rows.iter().map(|r| self.row_to_fee_config(r)).collect())
+        Ok(rows.iter().map(Self::row_to_fee_config).collect())
     }
 
     /// 수수료 설정 ID로 조회
@@ -85,7 +92,7 @@ impl FeeConfigRepository {
     pub async fn get_by_id(&self, fee_config_id: u64) -> Result<Option<FeeConfig>> {
         let row = sqlx::query(
             r#"
-            SELECT id, base_mint, quote_mint, fee_rate, fee_type, is_active, created_at, updated_at
+            SELECT id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
             FROM fee_configs
             WHERE id = $1
             "#,
@@ -95,18 +102,229 @@ impl FeeConfigRepository {
         .await
         .context("Failed to fetch fee config by id")?;
 
-        Ok(row.map(|r| self.row_to_fee_config(&r)))
+        Ok(row.map(|r| Self::row_to_fee_config(&r)))
+    }
+
+    /// 수수료 설정 변경 잠금 (트랜잭션 종료 시 해제)
+    /// Serialize fee config changes
+    ///
+    /// 겹침 검사와 저장 사이에 다른 변경이 끼지 않도록 모든 변경이 같은 advisory lock을 잡습니다.
+    pub async fn lock_changes(tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('fee_configs'))")
+            .execute(&mut **tx)
+            .await
+            .context("Failed to lock fee configs")?;
+        Ok(())
+    }
+
+    /// 같은 (거래쌍, 등급) 범위의 활성 설정 조회
+    /// Get active fee configs with the same pair and tier scope
+    pub async fn get_active_in_scope(
+        tx: &mut Transaction<'_, Postgres>,
+        base_mint: Option<&str>,
+        quote_mint: Option<&str>,
+        tier: Option<&str>,
+    ) -> Result<Vec<FeeConfig>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
+            FROM fee_configs
+            WHERE is_active = TRUE
+              AND base_mint IS NOT DISTINCT FROM $1
+              AND quote_mint IS NOT DISTINCT FROM $2
+              AND tier IS NOT DISTINCT FROM $3
+            ORDER BY effective_from ASC, id ASC
+            "#,
+        )
+        .bind(base_mint)
+        .bind(quote_mint)
+        .bind(tier)
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to fetch fee configs in scope")?;
+
+        Ok(rows.iter().map(Self::row_to_fee_config).collect())
+    }
+
+    /// 수수료 설정 ID로 조회 (행 잠금)
+    /// Get fee config by ID for update
+    pub async fn get_by_id_for_update(
+        tx: &mut Transaction<'_, Postgres>,
+        fee_config_id: u64,
+    ) -> Result<Option<FeeConfig>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
+            FROM fee_configs
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(fee_config_id as i64)
+        .fetch_optional(&mut **tx)
+        .await
+        .context("Failed to lock fee config")?;
+
+        Ok(row.map(|r| Self::row_to_fee_config(&r)))
+    }
+
+    /// 수수료 설정 생성 (활성)
+    /// Insert fee config
+    pub async fn insert(tx: &mut Transaction<'_, Postgres>, config: &FeeConfigCreate) -> Result<FeeConfig> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO fee_configs (base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, TRUE)
+            RETURNING id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(&config.base_mint)
+        .bind(&config.quote_mint)
+        .bind(config.fee_rate)
+        .bind(&config.fee_type)
+        .bind(&config.tier)
+        .bind(config.effective_from)
+        .bind(config.effective_until)
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to insert fee config")?;
+
+        Ok(Self::row_to_fee_config(&row))
+    }
+
+    /// 수수료율 / 적용 기간 변경
+    /// Update fee rate and effective window
+    pub async fn update(
+        tx: &mut Transaction<'_, Postgres>,
+        fee_config_id: u64,
+        fee_rate: Decimal,
+        effective_from: DateTime<Utc>,
+        effective_until: Option<DateTime<Utc>>,
+    ) -> Result<FeeConfig> {
+        let row = sqlx::query(
+            r#"
+            UPDATE fee_configs
+            SET fee_rate = $2, effective_from = $3, effective_until = $4, updated_at = NOW()
+            WHERE id = $1
+            RETURNING id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(fee_config_id as i64)
+        .bind(fee_rate)
+        .bind(effective_from)
+        .bind(effective_until)
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to update fee config")?;
+
+        Ok(Self::row_to_fee_config(&row))
+    }
+
+    /// 수수료 설정 비활성화
+    /// Deactivate fee config
+    pub async fn deactivate(tx: &mut Transaction<'_, Postgres>, fee_config_id: u64) -> Result<FeeConfig> {
+        let row = sqlx::query(
+            r#"
+            UPDATE fee_configs
+            SET is_active = FALSE, updated_at = NOW()
+            WHERE id = $1
+            RETURNING id, base_mint, quote_mint, fee_rate, fee_type, tier, effective_from, effective_until, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(fee_config_id as i64)
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to deactivate fee config")?;
+
+        Ok(Self::row_to_fee_config(&row))
+    }
+
+    /// 변경 이력 추가
+    /// Insert fee config history entry
+    ///
+    /// # Arguments
+    /// * `action` - create / update / deactivate
+    /// * `old_config` - 변경 전 (create는 None)
+    pub async fn insert_history(
+        tx: &mut Transaction<'_, Postgres>,
+        action: &str,
+        old_config: Option<&FeeConfig>,
+        new_config: &FeeConfig,
+        changed_by: u64,
+    ) -> Result<()> {
+        let old_json = old_config
+            .map(serde_json::to_string)
+            .transpose()
+            .context("Failed to serialize fee config")?;
+        let new_json = serde_json::to_string(new_config).context("Failed to serialize fee config")?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO fee_config_history (fee_config_id, action, old_config, new_config, changed_by)
+            VALUES ($1, $2, $3::JSONB, $4::JSONB, $5)
+            "#,
+        )
+        .bind(new_config.id as i64)
+        .bind(action)
+        .bind(old_json)
+        .bind(new_json)
+        .bind(changed_by as i64)
+        .execute(&mut **tx)
+        .await
+        .context("Failed to insert fee config history")?;
+
+        Ok(())
+    }
+
+    /// 변경 이력 조회 (최신순)
+    /// Get change history of a fee config
+    pub async fn get_history(&self, fee_config_id: u64) -> Result<Vec<FeeConfigHistory>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, fee_config_id, action, old_config::TEXT AS old_config, new_config::TEXT AS new_config,
+                   changed_by, changed_at
+            FROM fee_config_history
+            WHERE fee_config_id = $1
+            ORDER BY changed_at DESC, id DESC
+            "#,
+        )
+        .bind(fee_config_id as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch fee config history")?;
+
+        rows.iter()
+            .map(|row| {
+                let old_config: Option<String> = row.get("old_config");
+                let new_config: String = row.get("new_config");
+                Ok(FeeConfigHistory {
+                    id: row.get::<i64, _>("id") as u64,
+                    fee_config_id: row.get::<i64, _>("fee_config_id") as u64,
+                    action: row.get("action"),
+                    old_config: old_config
+                        .map(|json| serde_json::from_str(&json))
+                        .transpose()
+                        .context("Invalid fee config history JSON")?,
+                    new_config: serde_json::from_str(&new_config).context("Invalid fee config history JSON")?,
+                    changed_by: row.get::<Option<i64>, _>("changed_by").map(|id| id as u64),
+                    changed_at: row.get("changed_at"),
+                })
+            })
+            .collect()
     }
 
     /// Row를 FeeConfig로 변환하는 헬퍼 메서드
     /// Helper method to convert Row to FeeConfig
-    fn row_to_fee_config(&self, row: &sqlx::postgres::PgRow) -> FeeConfig {
+    fn row_to_fee_config(row: &sqlx::postgres::PgRow) -> FeeConfig {
         FeeConfig {
             id: row.get::<i64, _>("id") as u64,
             base_mint: row.get("base_mint"),
             quote_mint: row.get("quote_mint"),
             fee_rate: row.get("fee_rate"),
             fee_type: row.get("fee_type"),
+            tier: row.get("tier"),
+            effective_from: row.get("effective_from"),
+            effective_until: row.get("effective_until"),
             is_active: row.get("is_active"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
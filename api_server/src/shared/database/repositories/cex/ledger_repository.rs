@@ -0,0 +1,218 @@
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
+use anyhow::{Context, Result};
+use rust_decimal::Decimal;
+use crate::domains::cex::models::ledger::{LedgerFilter, LedgerPosting, LedgerReason, LedgerSide};
+use crate::domains::cex::models::reconciliation::StoredBalance;
+use crate::domains::cex::engine::runtime::db_batch::{insert_ledger, LedgerPostingRow};
+use crate::shared::utils::cursor::{Page, PageCursor};
+
+/// 원장 Repository (조회 전용)
+/// Ledger repository
+///
+/// 거래/입출금 원장 쓰기는 DB Writer가 잔고 UPDATE와 같은 트랜잭션에서 수행합니다 (db_batch::insert_ledger).
+/// 이 Repository는 조회와 정합성 복구 조정 분개만 씁니다.
+pub struct LedgerRepository {
+    pool: PgPool,
+}
+
+impl LedgerRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 사용자 계정 전기 페이지 조회 (최신순, keyset)
+    /// Get a page of postings on the user's accounts (newest first)
+    ///
+    /// # Arguments
+    /// * `user_id` - 사용자 ID (시스템 계정 전기는 반환하지 않음)
+    /// * `filter` - 자산/사유/참조/기간 필터
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
+    /// * `limit` - 페이지 크기
+    pub async fn get_page(
+        &self,
+        user_id: u64,
+        filter: &LedgerFilter,
+        cursor: Option<PageCursor>,
+        limit: i64,
+    ) -> Result<Page<LedgerPosting>> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT p.id, p.entry_id, e.reason, e.reference, p.mint, p.account, p.amount, p.created_at
+            FROM ledger_postings p
+            JOIN ledger_entries e ON e.id = p.entry_id
+            WHERE p.user_id = "#,
+        );
+        query.push_bind(user_id as i64);
+
+        if let Some(mint) = &filter.mint {
+            query.push(" AND p.mint = ").push_bind(mint.clone());
+        }
+        if let Some(reason) = filter.reason {
+            query.push(" AND e.reason = ").push_bind(reason.as_str());
+        }
+        if let Some(reference) = &filter.reference {
+            query.push(" AND e.reference = ").push_bind(reference.clone());
+        }
+        if let Some(from) = filter.from {
+            query.push(" AND p.created_at >= ").push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            query.push(" AND p.created_at < ").push_bind(to);
+        }
+        if let Some(cursor) = cursor {
+            query
+                .push(" AND (p.created_at, p.id) < (")
+                .push_bind(cursor.created_at)
+                .push(", ")
+                .push_bind(cursor.id as i64)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY p.created_at DESC, p.id DESC LIMIT ")
+            .push_bind(limit + 1);
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch ledger postings")?;
+
+        let postings = rows
+            .iter()
+            .map(Self::row_to_posting)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Page::from_rows(postings, limit, |posting: &LedgerPosting| {
+            PageCursor::new(posting.created_at, posting.id)
+        }))
+    }
+
+    /// DB 잔고 + 원장 합계 조회 (정합성 검사용)
+    /// Get stored balances with ledger totals
+    ///
+    /// user_balances와 사용자 계정 원장 합계를 (user_id, mint)로 FULL OUTER JOIN합니다.
+    /// 한 쿼리이므로 두 값은 같은 시점입니다.
+    ///
+    /// # Arguments
+    /// * `after_wal_seq` - 이 시퀀스 이후 원장 전기를 `*_after`로 따로 합산
+    pub async fn get_stored_balances(&self, after_wal_seq: u64) -> Result<Vec<StoredBalance>> {
+        let rows = sqlx::query(
+            r#"
+            WITH ledger AS (
+                SELECT p.user_id, p.mint,
+                       COALESCE(SUM(p.amount) FILTER (WHERE p.account = 'available'), 0) AS available,
+                       COALESCE(SUM(p.amount) FILTER (WHERE p.account = 'locked'), 0) AS locked,
+                       COALESCE(SUM(p.amount) FILTER (WHERE p.account = 'available' AND e.wal_seq > $1), 0) AS available_after,
+                       COALESCE(SUM(p.amount) FILTER (WHERE p.account = 'locked' AND e.wal_seq > $1), 0) AS locked_after
+                FROM ledger_postings p
+                JOIN ledger_entries e ON e.id = p.entry_id
+                WHERE p.user_id IS NOT NULL
+                GROUP BY p.user_id, p.mint
+            )
+            SELECT COALESCE(b.user_id, l.user_id) AS user_id,
+                   COALESCE(b.mint_address, l.mint) AS mint,
+                   COALESCE(b.available, 0) AS available,
+                   COALESCE(b.locked, 0) AS locked,
+                   COALESCE(l.available, 0) AS ledger_available,
+                   COALESCE(l.locked, 0) AS ledger_locked,
+                   COALESCE(l.available_after, 0) AS available_after,
+                   COALESCE(l.locked_after, 0) AS locked_after
+            FROM user_balances b
+            FULL OUTER JOIN ledger l ON l.user_id = b.user_id AND l.mint = b.mint_address
+            "#,
+        )
+        .bind(after_wal_seq as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch stored balances")?;
+
+        Ok(rows
+            .iter()
+            .map(|row| StoredBalance {
+                user_id: row.get::<i64, _>("user_id") as u64,
+                mint: row.get("mint"),
+                available: row.get("available"),
+                locked: row.get("locked"),
+                ledger_available: row.get("ledger_available"),
+                ledger_locked: row.get("ledger_locked"),
+                available_after: row.get("available_after"),
+                locked_after: row.get("locked_after"),
+            })
+            .collect())
+    }
+
+    /// 잔고 조정 + 조정 분개 기록 (한 트랜잭션)
+    /// Apply balance adjustments with adjustment ledger entries
+    ///
+    /// # Arguments
+    /// * `wal_seq` - 분개 WAL 시퀀스 (정합성 검사 스냅샷 시퀀스)
+    /// * `reference` - 분개 참조 (예: "reconciliation:1700000000000")
+    /// * `adjustments` - (user_id, mint, available 증감, locked 증감)
+    ///
+    /// # 처리
+    /// 1. user_balances 증감 (없으면 생성)
+    /// 2. 원장: 사용자 계정 전기 + adjustments 시스템 계정 반대편 전기
+    pub async fn post_adjustments(
+        &self,
+        wal_seq: u64,
+        reference: &str,
+        adjustments: &[(u64, String, Decimal, Decimal)],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        let now = chrono::Utc::now();
+        let mut postings = Vec::new();
+
+        for (user_id, mint, available_delta, locked_delta) in adjustments {
+            sqlx::query(
+                r#"
+                INSERT INTO user_balances (user_id, mint_address, available, locked, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $5)
+                ON CONFLICT (user_id, mint_address)
+                DO UPDATE SET
+                    available = user_balances.available + $3,
+                    locked = user_balances.locked + $4,
+                    updated_at = $5
+                "#,
+            )
+            .bind(*user_id as i64)
+            .bind(mint)
+            .bind(available_delta)
+            .bind(locked_delta)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!("Failed to adjust balance: user_id={}, mint={}", user_id, mint))?;
+
+            postings.extend(LedgerPostingRow::for_balance_update(
+                wal_seq,
+                *user_id,
+                mint,
+                Some(*available_delta),
+                Some(*locked_delta),
+                LedgerReason::Adjustment,
+                reference,
+            ));
+        }
+
+        insert_ledger(&mut tx, &postings).await?;
+        tx.commit().await.context("Failed to commit transaction")?;
+        Ok(())
+    }
+
+    fn row_to_posting(row: &sqlx::postgres::PgRow) -> Result<LedgerPosting> {
+        let reason: String = row.get("reason");
+        let account: String = row.get("account");
+        let amount: Decimal = row.get("amount");
+
+        Ok(LedgerPosting {
+            id: row.get::<i64, _>("id") as u64,
+            entry_id: row.get::<i64, _>("entry_id") as u64,
+            reason: reason.parse()?,
+            reference: row.get("reference"),
+            mint: row.get("mint"),
+            account: account.parse()?,
+            side: if amount.is_sign_negative() { LedgerSide::Credit } else { LedgerSide::Debit },
+            amount: amount.abs(),
+            created_at: row.get("created_at"),
+        })
+    }
+}
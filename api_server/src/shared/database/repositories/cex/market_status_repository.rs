@@ -0,0 +1,77 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use crate::domains::cex::models::market_status::{MarketStatus, MarketStatusEntry};
+
+/// 거래쌍 상태 Repository
+/// Market status repository
+pub struct MarketStatusRepository {
+    pool: PgPool,
+}
+
+impl MarketStatusRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 모든 거래쌍 상태 조회 (행이 없는 거래쌍은 open)
+    /// Get all market statuses
+    pub async fn get_all(&self) -> Result<Vec<MarketStatusEntry>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT base_mint, quote_mint, status, reason, updated_by, updated_at
+            FROM market_statuses
+            ORDER BY base_mint ASC, quote_mint ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch market statuses")?;
+
+        rows.iter().map(Self::row_to_entry).collect()
+    }
+
+    /// 거래쌍 상태 저장 (없으면 생성)
+    /// Upsert market status
+    pub async fn upsert(
+        &self,
+        base_mint: &str,
+        quote_mint: &str,
+        status: MarketStatus,
+        reason: Option<&str>,
+        updated_by: u64,
+    ) -> Result<MarketStatusEntry> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO market_statuses (base_mint, quote_mint, status, reason, updated_by, updated_at)
+            VALUES ($1, $2, $3, $4, $5, NOW())
+            ON CONFLICT (base_mint, quote_mint) DO UPDATE
+            SET status = EXCLUDED.status,
+                reason = EXCLUDED.reason,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = EXCLUDED.updated_at
+            RETURNING base_mint, quote_mint, status, reason, updated_by, updated_at
+            "#,
+        )
+        .bind(base_mint)
+        .bind(quote_mint)
+        .bind(status.as_str())
+        .bind(reason)
+        .bind(updated_by as i64)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to upsert market status")?;
+
+        Self::row_to_entry(&row)
+    }
+
+    fn row_to_entry(row: &sqlx::postgres::PgRow) -> Result<MarketStatusEntry> {
+        Ok(MarketStatusEntry {
+            base_mint: row.get("base_mint"),
+            quote_mint: row.get("quote_mint"),
+            status: row.get::<String, _>("status").parse()?,
+            reason: row.get("reason"),
+            updated_by: row.get::<Option<i64>, _>("updated_by").map(|id| id as u64),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
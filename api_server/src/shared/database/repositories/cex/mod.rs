@@ -3,9 +3,31 @@ pub mod order_repository;
 pub mod trade_repository;
 pub mod balance_repository;
 pub mod fee_repository;
+pub mod db_writer_repository;
+pub mod candle_repository;
+pub mod position_repository;
+pub mod portfolio_repository;
+pub mod export_repository;
+pub mod deposit_repository;
+pub mod withdrawal_repository;
+pub mod ledger_repository;
+pub mod transfer_repository;
+pub mod sub_account_repository;
+pub mod market_status_repository;
 
 pub use order_repository::*;
 pub use trade_repository::*;
 pub use balance_repository::*;
 pub use fee_repository::*;
+pub use db_writer_repository::*;
+pub use candle_repository::*;
+pub use position_repository::*;
+pub use portfolio_repository::*;
+pub use export_repository::*;
+pub use deposit_repository::*;
+pub use withdrawal_repository::*;
+pub use ledger_repository::*;
+pub use transfer_repository::*;
+pub use sub_account_repository::*;
+pub use market_status_repository::*;
 
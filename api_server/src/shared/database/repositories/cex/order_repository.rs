@@ -1,8 +1,10 @@
-use sqlx::{PgPool, Row};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use futures_util::stream::{BoxStream, StreamExt};
 use rust_decimal::Decimal;
-use crate::domains::cex::models::order::{Order, OrderCreate};
+use crate::domains::cex::models::order::{Order, OrderCreate, OrderFilter};
+use crate::shared::utils::cursor::{Page, PageCursor};
 
 pub struct OrderRepository {
     pool: PgPool,
@@ -65,68 +67,109 @@ impl OrderRepository {
         Ok(row.map(|r| self.row_to_order(&r)))
     }
 
-    /// 사용자 ID로 모든 주문 조회
-    /// Get all orders by user ID
-    pub async fn get_all_by_user(
+    /// 사용자 주문 페이지 조회 (최신순, keyset)
+    /// Get a page of user orders (newest first)
+    ///
+    /// # Arguments
+    /// * `filter` - 거래쌍/유형/방식/상태/기간 필터
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
+    /// * `limit` - 페이지 크기
+    ///
+    /// # 인덱스
+    /// `(user_id, created_at DESC, id DESC)` 계열 인덱스로 OFFSET 없이 이어서 조회
+    pub async fn get_page_by_user(
         &self,
         user_id: u64,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<Order>> {
-        let limit = limit.unwrap_or(100);
-        let offset = offset.unwrap_or(0);
-
-        let rows = sqlx::query(
+        filter: &OrderFilter,
+        cursor: Option<PageCursor>,
+        limit: i64,
+    ) -> Result<Page<Order>> {
+        let mut query = QueryBuilder::<Postgres>::new(
             r#"
             SELECT id, user_id, order_type, order_side, base_mint, quote_mint,
                    price, amount, filled_amount, filled_quote_amount, status, created_at, updated_at
             FROM orders
-            WHERE user_id = $1
-            ORDER BY created_at DESC
-            LIMIT $2 OFFSET $3
-            "#,
-        )
-        .bind(user_id as i64)
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(&self.pool)
-        .await
-        .context("Failed to fetch orders by user")?;
+            WHERE user_id = "#,
+        );
+        query.push_bind(user_id as i64);
 
-        Ok(rows.iter().map(|r| self.row_to_order(r)).collect())
+        if let Some(base_mint) = &filter.base_mint {
+            query.push(" AND base_mint = ").push_bind(base_mint.clone());
+        }
+        if let Some(quote_mint) = &filter.quote_mint {
+            query.push(" AND quote_mint = ").push_bind(quote_mint.clone());
+        }
+        if let Some(order_type) = &filter.order_type {
+            query.push(" AND order_type = ").push_bind(order_type.clone());
+        }
+        if let Some(order_side) = &filter.order_side {
+            query.push(" AND order_side = ").push_bind(order_side.clone());
+        }
+        if let Some(status) = &filter.status {
+            query.push(" AND status = ").push_bind(status.clone());
+        }
+        if let Some(from) = filter.from {
+            query.push(" AND created_at >= ").push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            query.push(" AND created_at < ").push_bind(to);
+        }
+        if let Some(cursor) = cursor {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(cursor.created_at)
+                .push(", ")
+                .push_bind(cursor.id as i64)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit + 1);
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch orders by user")?;
+
+        let orders = rows.iter().map(|r| self.row_to_order(r)).collect();
+        Ok(Page::from_rows(orders, limit, |order: &Order| {
+            PageCursor::new(order.created_at, order.id)
+        }))
     }
 
-    /// 사용자 ID와 상태로 주문 조회
-    /// Get orders by user ID and status
-    pub async fn get_by_user_and_status(
+    /// 기간 내 사용자 주문 스트리밍 조회 (생성 시간순, 내보내기용)
+    /// Stream user's orders created in a time range, oldest first
+    ///
+    /// 전체 결과를 메모리에 올리지 않고 행 단위로 읽습니다 (조회 동안 연결 하나 사용).
+    ///
+    /// # Arguments
+    /// * `from` - 시작 시간 (포함)
+    /// * `to` - 끝 시간 (미포함)
+    pub fn stream_by_user(
         &self,
         user_id: u64,
-        status: &str,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<Order>> {
-        let limit = limit.unwrap_or(100);
-        let offset = offset.unwrap_or(0);
-
-        let rows = sqlx::query(
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<Order>> {
+        sqlx::query(
             r#"
             SELECT id, user_id, order_type, order_side, base_mint, quote_mint,
                    price, amount, filled_amount, filled_quote_amount, status, created_at, updated_at
             FROM orders
-            WHERE user_id = $1 AND status = $2
-            ORDER BY created_at DESC
-            LIMIT $3 OFFSET $4
+            WHERE user_id = $1 AND created_at >= $2 AND created_at < $3
+            ORDER BY created_at ASC, id ASC
             "#,
         )
         .bind(user_id as i64)
-        .bind(status)
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(&self.pool)
-        .await
-        .context("Failed to fetch orders by user and status")?;
-
-        Ok(rows.iter().map(|r| self.row_to_order(r)).collect())
+        .bind(from)
+        .bind(to)
+        .fetch(&self.pool)
+        .map(move |row| {
+            let row = row.context("Failed to stream user orders")?;
+            Ok(self.row_to_order(&row))
+        })
+        .boxed()
     }
 
     /// 오더북 조회 (거래쌍별, 상태별, 주문 타입별, 가격순)
@@ -0,0 +1,167 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use futures_util::stream::{BoxStream, StreamExt};
+use crate::domains::cex::models::portfolio::{EquitySnapshot, PortfolioAsset};
+
+/// 일별 자산 기록 Repository
+/// Portfolio snapshot repository
+///
+/// 같은 사용자/기준 통화/날짜는 처음 기록을 유지합니다 (재실행해도 멱등).
+pub struct PortfolioSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PortfolioSnapshotRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 일별 기록 일괄 저장 (이미 있는 날짜는 건너뜀)
+    /// Bulk insert snapshots, keeping existing ones
+    ///
+    /// # Arguments
+    /// * `snapshots` - (사용자 ID, 기록, 자산별 평가) 목록
+    ///
+    /// # Returns
+    /// 새로 저장된 행 수
+    pub async fn insert_many(&self, snapshots: &[(u64, EquitySnapshot, Vec<PortfolioAsset>)]) -> Result<u64> {
+        if snapshots.is_empty() {
+            return Ok(0);
+        }
+
+        let assets = snapshots
+            .iter()
+            .map(|(_, _, assets)| serde_json::to_string(assets))
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to encode snapshot assets")?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO portfolio_snapshots (user_id, quote_mint, snapshot_date, total_value, created_at, assets)
+            SELECT user_id, quote_mint, snapshot_date, total_value, created_at, assets::JSONB
+            FROM UNNEST(
+                $1::BIGINT[], $2::TEXT[], $3::DATE[], $4::NUMERIC[], $5::TIMESTAMPTZ[], $6::TEXT[]
+            ) AS s(user_id, quote_mint, snapshot_date, total_value, created_at, assets)
+            ON CONFLICT (user_id, quote_mint, snapshot_date) DO NOTHING
+            "#,
+        )
+        .bind(snapshots.iter().map(|(user_id, _, _)| *user_id as i64).collect::<Vec<_>>())
+        .bind(snapshots.iter().map(|(_, s, _)| s.quote_mint.clone()).collect::<Vec<_>>())
+        .bind(snapshots.iter().map(|(_, s, _)| s.snapshot_date).collect::<Vec<_>>())
+        .bind(snapshots.iter().map(|(_, s, _)| s.total_value).collect::<Vec<_>>())
+        .bind(snapshots.iter().map(|(_, s, _)| s.created_at).collect::<Vec<_>>())
+        .bind(assets)
+        .execute(&self.pool)
+        .await
+        .with_context(|| format!("Failed to insert {} portfolio snapshots", snapshots.len()))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// 기간별 기록 조회 (날짜 오름차순)
+    /// Get snapshots in range
+    ///
+    /// # Arguments
+    /// * `from` - 시작 날짜 (포함)
+    /// * `to` - 끝 날짜 (포함)
+    /// * `limit` - 최대 개수 (범위가 더 넓으면 최근 날짜 우선)
+    pub async fn get_range(
+        &self,
+        user_id: u64,
+        quote_mint: &str,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        limit: i64,
+    ) -> Result<Vec<EquitySnapshot>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT quote_mint, snapshot_date, total_value, created_at
+            FROM (
+                SELECT quote_mint, snapshot_date, total_value, created_at
+                FROM portfolio_snapshots
+                WHERE user_id = $1 AND quote_mint = $2
+                  AND ($3::DATE IS NULL OR snapshot_date >= $3)
+                  AND ($4::DATE IS NULL OR snapshot_date <= $4)
+                ORDER BY snapshot_date DESC
+                LIMIT $5
+            ) recent
+            ORDER BY snapshot_date ASC
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(quote_mint)
+        .bind(from)
+        .bind(to)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch portfolio snapshots")?;
+
+        Ok(rows
+            .iter()
+            .map(|r| EquitySnapshot {
+                snapshot_date: r.get("snapshot_date"),
+                quote_mint: r.get("quote_mint"),
+                total_value: r.get("total_value"),
+                created_at: r.get("created_at"),
+            })
+            .collect())
+    }
+
+    /// 기간 내 자산별 잔고 기록 스트리밍 조회 (날짜순, 내보내기용)
+    /// Stream per-asset balances of snapshots taken in a time range
+    ///
+    /// # Arguments
+    /// * `from` - 시작 시간 (포함, 기록 시간 기준)
+    /// * `to` - 끝 시간 (미포함)
+    ///
+    /// # Returns
+    /// (기록 날짜, 기준 통화, 자산별 평가) 스트림
+    pub fn stream_assets(
+        &self,
+        user_id: u64,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<(NaiveDate, String, PortfolioAsset)>> {
+        sqlx::query(
+            r#"
+            SELECT s.snapshot_date, s.quote_mint,
+                   a.mint, a.balance, a.available, a.locked, a.price, a.value, a.route::TEXT AS route
+            FROM portfolio_snapshots s,
+                 jsonb_to_recordset(s.assets) AS a(
+                     mint TEXT, balance NUMERIC, available NUMERIC, locked NUMERIC,
+                     price NUMERIC, value NUMERIC, route JSONB
+                 )
+            WHERE s.user_id = $1 AND s.created_at >= $2 AND s.created_at < $3
+            ORDER BY s.snapshot_date ASC, s.quote_mint ASC, a.mint ASC
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(from)
+        .bind(to)
+        .fetch(&self.pool)
+        .map(|row| {
+            let row = row.context("Failed to stream snapshot assets")?;
+            let route: Option<String> = row.get("route");
+            let route = match route {
+                Some(route) => serde_json::from_str(&route).context("Invalid snapshot route")?,
+                None => Vec::new(),
+            };
+            Ok((
+                row.get("snapshot_date"),
+                row.get("quote_mint"),
+                PortfolioAsset {
+                    mint: row.get("mint"),
+                    balance: row.get("balance"),
+                    available: row.get("available"),
+                    locked: row.get("locked"),
+                    price: row.get("price"),
+                    value: row.get("value"),
+                    route,
+                },
+            ))
+        })
+        .boxed()
+    }
+}
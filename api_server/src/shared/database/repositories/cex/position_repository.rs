@@ -0,0 +1,324 @@
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use crate::domains::cex::models::position::{CostBasisMethod, PositionLot, PositionStats, RealizedPnlEntry};
+
+/// 포지션 lot Repository
+/// Position lot repository
+///
+/// 사용자/자산별 포지션 통계, 원가 lot, 매도 체결별 실현 손익을 관리합니다.
+///
+/// # 트랜잭션
+/// 쓰기 메서드는 `lock_position`으로 포지션 행을 잠근 트랜잭션 안에서 호출해야
+/// 같은 체결이 두 번 반영되지 않습니다.
+pub struct PositionRepository {
+    pool: PgPool,
+}
+
+impl PositionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 포지션 행 잠금 (없으면 생성)
+    /// Lock (and create if missing) the position row
+    ///
+    /// # Returns
+    /// (원가 계산 방식, 누적 통계)
+    pub async fn lock_position(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: u64,
+        mint: &str,
+    ) -> Result<(CostBasisMethod, PositionStats)> {
+        sqlx::query(
+            r#"
+            INSERT INTO positions (user_id, mint)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id, mint) DO NOTHING
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .execute(&mut **tx)
+        .await
+        .context("Failed to create position")?;
+
+        let row = sqlx::query(
+            r#"
+            SELECT cost_basis_method, realized_pnl, total_fees,
+                   total_buy_trades, total_sell_trades,
+                   total_bought_amount, total_bought_cost, last_trade_id
+            FROM positions
+            WHERE user_id = $1 AND mint = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .fetch_one(&mut **tx)
+        .await
+        .context("Failed to lock position")?;
+
+        let method: String = row.get("cost_basis_method");
+        let stats = PositionStats {
+            realized_pnl: row.get("realized_pnl"),
+            total_fees: row.get("total_fees"),
+            total_buy_trades: row.get::<i64, _>("total_buy_trades") as u64,
+            total_sell_trades: row.get::<i64, _>("total_sell_trades") as u64,
+            total_bought_amount: row.get("total_bought_amount"),
+            total_bought_cost: row.get("total_bought_cost"),
+            last_trade_id: row.get::<i64, _>("last_trade_id") as u64,
+        };
+
+        Ok((method.parse()?, stats))
+    }
+
+    /// 남은 lot 조회 (오래된 순)
+    /// Get open lots, oldest first
+    pub async fn get_open_lots(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: u64,
+        mint: &str,
+    ) -> Result<Vec<PositionLot>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, trade_id, opened_at, amount, cost, remaining_amount, remaining_cost
+            FROM position_lots
+            WHERE user_id = $1 AND mint = $2 AND remaining_amount > 0
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .fetch_all(&mut **tx)
+        .await
+        .context("Failed to fetch open position lots")?;
+
+        Ok(rows
+            .iter()
+            .map(|r| PositionLot {
+                lot_id: r.get::<i64, _>("id") as u64,
+                trade_id: r.get::<i64, _>("trade_id") as u64,
+                opened_at: r.get("opened_at"),
+                amount: r.get("amount"),
+                cost: r.get("cost"),
+                remaining_amount: r.get("remaining_amount"),
+                remaining_cost: r.get("remaining_cost"),
+            })
+            .collect())
+    }
+
+    /// 포지션 통계 저장
+    /// Save position statistics
+    pub async fn save_position(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: u64,
+        mint: &str,
+        method: CostBasisMethod,
+        stats: &PositionStats,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE positions SET
+                cost_basis_method = $3,
+                realized_pnl = $4,
+                total_fees = $5,
+                total_buy_trades = $6,
+                total_sell_trades = $7,
+                total_bought_amount = $8,
+                total_bought_cost = $9,
+                last_trade_id = $10,
+                updated_at = $11
+            WHERE user_id = $1 AND mint = $2
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .bind(method.as_str())
+        .bind(stats.realized_pnl)
+        .bind(stats.total_fees)
+        .bind(stats.total_buy_trades as i64)
+        .bind(stats.total_sell_trades as i64)
+        .bind(stats.total_bought_amount)
+        .bind(stats.total_bought_cost)
+        .bind(stats.last_trade_id as i64)
+        .bind(Utc::now())
+        .execute(&mut **tx)
+        .await
+        .context("Failed to save position")?;
+
+        Ok(())
+    }
+
+    /// 새 lot 일괄 저장 (`lot_id`는 무시하고 새로 발급)
+    /// Bulk insert new lots
+    pub async fn insert_lots(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: u64,
+        mint: &str,
+        lots: &[PositionLot],
+    ) -> Result<()> {
+        if lots.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO position_lots (
+                user_id, mint, trade_id, opened_at,
+                amount, cost, remaining_amount, remaining_cost
+            )
+            SELECT $1, $2, * FROM UNNEST(
+                $3::BIGINT[], $4::TIMESTAMPTZ[],
+                $5::NUMERIC[], $6::NUMERIC[], $7::NUMERIC[], $8::NUMERIC[]
+            )
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .bind(lots.iter().map(|l| l.trade_id as i64).collect::<Vec<_>>())
+        .bind(lots.iter().map(|l| l.opened_at).collect::<Vec<_>>())
+        .bind(lots.iter().map(|l| l.amount).collect::<Vec<_>>())
+        .bind(lots.iter().map(|l| l.cost).collect::<Vec<_>>())
+        .bind(lots.iter().map(|l| l.remaining_amount).collect::<Vec<_>>())
+        .bind(lots.iter().map(|l| l.remaining_cost).collect::<Vec<_>>())
+        .execute(&mut **tx)
+        .await
+        .with_context(|| format!("Failed to insert {} position lots", lots.len()))?;
+
+        Ok(())
+    }
+
+    /// 기존 lot의 남은 수량/원가 일괄 갱신
+    /// Bulk update remaining amount and cost of existing lots
+    pub async fn update_lots(
+        tx: &mut Transaction<'_, Postgres>,
+        lots: &[PositionLot],
+    ) -> Result<()> {
+        if lots.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE position_lots AS l SET
+                remaining_amount = u.remaining_amount,
+                remaining_cost = u.remaining_cost
+            FROM UNNEST($1::BIGINT[], $2::NUMERIC[], $3::NUMERIC[])
+                AS u(id, remaining_amount, remaining_cost)
+            WHERE l.id = u.id
+            "#,
+        )
+        .bind(lots.iter().map(|l| l.lot_id as i64).collect::<Vec<_>>())
+        .bind(lots.iter().map(|l| l.remaining_amount).collect::<Vec<_>>())
+        .bind(lots.iter().map(|l| l.remaining_cost).collect::<Vec<_>>())
+        .execute(&mut **tx)
+        .await
+        .with_context(|| format!("Failed to update {} position lots", lots.len()))?;
+
+        Ok(())
+    }
+
+    /// 매도 체결별 실현 손익 일괄 저장
+    /// Bulk insert realized P&L entries
+    pub async fn insert_realized(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: u64,
+        mint: &str,
+        entries: &[RealizedPnlEntry],
+    ) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO position_realized_pnl (
+                user_id, mint, trade_id, closed_at,
+                amount, proceeds, cost_basis, fee, realized_pnl
+            )
+            SELECT $1, $2, * FROM UNNEST(
+                $3::BIGINT[], $4::TIMESTAMPTZ[],
+                $5::NUMERIC[], $6::NUMERIC[], $7::NUMERIC[], $8::NUMERIC[], $9::NUMERIC[]
+            )
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .bind(entries.iter().map(|e| e.trade_id as i64).collect::<Vec<_>>())
+        .bind(entries.iter().map(|e| e.closed_at).collect::<Vec<_>>())
+        .bind(entries.iter().map(|e| e.amount).collect::<Vec<_>>())
+        .bind(entries.iter().map(|e| e.proceeds).collect::<Vec<_>>())
+        .bind(entries.iter().map(|e| e.cost_basis).collect::<Vec<_>>())
+        .bind(entries.iter().map(|e| e.fee).collect::<Vec<_>>())
+        .bind(entries.iter().map(|e| e.realized_pnl).collect::<Vec<_>>())
+        .execute(&mut **tx)
+        .await
+        .with_context(|| format!("Failed to insert {} realized P&L entries", entries.len()))?;
+
+        Ok(())
+    }
+
+    /// 포지션 초기화 (lot/실현 손익 삭제, 통계 0, 반영 위치 처음으로)
+    /// Reset position so it can be rebuilt from trade history
+    pub async fn reset_position(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: u64,
+        mint: &str,
+        method: CostBasisMethod,
+    ) -> Result<()> {
+        sqlx::query("DELETE FROM position_lots WHERE user_id = $1 AND mint = $2")
+            .bind(user_id as i64)
+            .bind(mint)
+            .execute(&mut **tx)
+            .await
+            .context("Failed to delete position lots")?;
+
+        sqlx::query("DELETE FROM position_realized_pnl WHERE user_id = $1 AND mint = $2")
+            .bind(user_id as i64)
+            .bind(mint)
+            .execute(&mut **tx)
+            .await
+            .context("Failed to delete realized P&L entries")?;
+
+        Self::save_position(tx, user_id, mint, method, &PositionStats::default()).await
+    }
+
+    /// 최근 매도 체결별 실현 손익 조회 (최신순)
+    /// Get recent realized P&L entries, newest first
+    pub async fn get_realized(
+        &self,
+        user_id: u64,
+        mint: &str,
+        limit: i64,
+    ) -> Result<Vec<RealizedPnlEntry>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT trade_id, closed_at, amount, proceeds, cost_basis, fee, realized_pnl
+            FROM position_realized_pnl
+            WHERE user_id = $1 AND mint = $2
+            ORDER BY trade_id DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch realized P&L entries")?;
+
+        Ok(rows
+            .iter()
+            .map(|r| RealizedPnlEntry {
+                trade_id: r.get::<i64, _>("trade_id") as u64,
+                closed_at: r.get("closed_at"),
+                amount: r.get("amount"),
+                proceeds: r.get("proceeds"),
+                cost_basis: r.get("cost_basis"),
+                fee: r.get("fee"),
+                realized_pnl: r.get("realized_pnl"),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,144 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use crate::domains::cex::models::sub_account::SubAccount;
+
+/// 서브 계정 Repository
+/// Sub-account repository
+///
+/// 서브 계정은 users 행 + sub_accounts 행으로 저장됩니다.
+pub struct SubAccountRepository {
+    pool: PgPool,
+}
+
+impl SubAccountRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 서브 계정 생성 (users 행 + sub_accounts 행, 한 트랜잭션)
+    /// Create sub-account
+    ///
+    /// # Arguments
+    /// * `master_user_id` - 마스터 사용자 ID
+    /// * `label` - 이름 (마스터별 유니크)
+    /// * `email` - 합성 이메일 (로그인에 사용되지 않음)
+    /// * `password_hash` - 사용 불가 비밀번호 해시
+    ///
+    /// # Returns
+    /// * `Some(SubAccount)` - 새로 생성됨
+    /// * `None` - 같은 이름의 서브 계정이 이미 있음
+    pub async fn create(
+        &self,
+        master_user_id: u64,
+        label: &str,
+        email: &str,
+        password_hash: &str,
+    ) -> Result<Option<SubAccount>> {
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        let now = Utc::now();
+
+        let user_id: Option<i64> = sqlx::query_scalar(
+            r#"
+            INSERT INTO users (email, password_hash, username, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $4)
+            ON CONFLICT (email) DO NOTHING
+            RETURNING id
+            "#,
+        )
+        .bind(email)
+        .bind(password_hash)
+        .bind(label)
+        .bind(now)
+        .fetch_optional(&mut *tx)
+        .await
+        .context("Failed to create sub-account user")?;
+
+        let Some(user_id) = user_id else {
+            return Ok(None);
+        };
+
+        let row = sqlx::query(
+            r#"
+            INSERT INTO sub_accounts (user_id, master_user_id, label, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (master_user_id, label) DO NOTHING
+            RETURNING user_id, master_user_id, label, created_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(master_user_id as i64)
+        .bind(label)
+        .bind(now)
+        .fetch_optional(&mut *tx)
+        .await
+        .context("Failed to create sub-account")?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        tx.commit().await.context("Failed to commit transaction")?;
+        Ok(Some(Self::row_to_sub_account(&row)))
+    }
+
+    /// 마스터의 서브 계정 목록 (생성순)
+    /// List sub-accounts of a master user
+    pub async fn list_by_master(&self, master_user_id: u64) -> Result<Vec<SubAccount>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT user_id, master_user_id, label, created_at
+            FROM sub_accounts
+            WHERE master_user_id = $1
+            ORDER BY user_id
+            "#,
+        )
+        .bind(master_user_id as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch sub-accounts")?;
+
+        Ok(rows.iter().map(Self::row_to_sub_account).collect())
+    }
+
+    /// 서브 계정 개수
+    /// Count sub-accounts of a master user
+    pub async fn count_by_master(&self, master_user_id: u64) -> Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM sub_accounts WHERE master_user_id = $1")
+            .bind(master_user_id as i64)
+            .fetch_one(&self.pool)
+            .await
+            .context("Failed to count sub-accounts")
+    }
+
+    /// 서브 계정 조회 (사용자 ID)
+    /// Get sub-account by its user ID
+    ///
+    /// # Returns
+    /// * `Some(SubAccount)` - 서브 계정
+    /// * `None` - 서브 계정이 아닌 사용자 (일반/마스터 계정)
+    pub async fn get_by_user_id(&self, user_id: u64) -> Result<Option<SubAccount>> {
+        let row = sqlx::query(
+            r#"
+            SELECT user_id, master_user_id, label, created_at
+            FROM sub_accounts
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id as i64)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch sub-account")?;
+
+        Ok(row.as_ref().map(Self::row_to_sub_account))
+    }
+
+    fn row_to_sub_account(row: &sqlx::postgres::PgRow) -> SubAccount {
+        SubAccount {
+            user_id: row.get::<i64, _>("user_id") as u64,
+            master_user_id: row.get::<i64, _>("master_user_id") as u64,
+            label: row.get("label"),
+            created_at: row.get("created_at"),
+        }
+    }
+}
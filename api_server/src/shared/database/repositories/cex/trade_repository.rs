@@ -1,8 +1,10 @@
-use sqlx::{PgPool, Row};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use futures_util::stream::{BoxStream, StreamExt};
 use rust_decimal::Decimal;
-use crate::domains::cex::models::trade::{Trade, TradeCreate};
+use crate::domains::cex::models::trade::{Trade, TradeCreate, TradeFilter, UserFill};
+use crate::shared::utils::cursor::{Page, PageCursor};
 
 pub struct TradeRepository {
     pool: PgPool,
@@ -180,189 +182,358 @@ impl TradeRepository {
         Ok(rows.iter().map(|r| self.row_to_trade(r)).collect())
     }
 
-    /// 사용자별 거래 내역 조회
-    /// Get trades by user ID
-    /// 
-    /// 사용자가 매수한 거래(buy_order의 user_id) 또는 매도한 거래(sell_order의 user_id)를 모두 조회
-    /// Returns all trades where user was either buyer or seller
-    pub async fn get_by_user(
+    /// 거래쌍 체결 내역 페이지 조회 (최신순, keyset)
+    /// Get a page of trades (newest first)
+    ///
+    /// # Arguments
+    /// * `filter` - 거래쌍/기간 필터
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
+    /// * `limit` - 페이지 크기
+    pub async fn get_page(
         &self,
-        user_id: u64,
-        mint: Option<&str>,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<Trade>> {
-        let limit = limit.unwrap_or(100);
-        let offset = offset.unwrap_or(0);
+        filter: &TradeFilter,
+        cursor: Option<PageCursor>,
+        limit: i64,
+    ) -> Result<Page<Trade>> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT id, buy_order_id, sell_order_id, base_mint, quote_mint,
+                   price, amount, created_at
+            FROM trades
+            WHERE TRUE"#,
+        );
+        Self::push_conditions(&mut query, filter, cursor);
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit + 1);
 
-        let query = if let Some(mint) = mint {
-            sqlx::query(
-                r#"
-                SELECT t.id, t.buy_order_id, t.sell_order_id, t.base_mint, t.quote_mint,
-                       t.price, t.amount, t.created_at
-                FROM trades t
-                INNER JOIN orders buy_order ON t.buy_order_id = buy_order.id
-                INNER JOIN orders sell_order ON t.sell_order_id = sell_order.id
-                WHERE (buy_order.user_id = $1 OR sell_order.user_id = $1)
-                  AND t.base_mint = $2
-                ORDER BY t.created_at DESC
-                LIMIT $3 OFFSET $4
-                "#,
-            )
-            .bind(user_id as i64)
-            .bind(mint)
-            .bind(limit)
-            .bind(offset)
-        } else {
-            sqlx::query(
-                r#"
-                SELECT t.id, t.buy_order_id, t.sell_order_id, t.base_mint, t.quote_mint,
-                       t.price, t.amount, t.created_at
-                FROM trades t
-                INNER JOIN orders buy_order ON t.buy_order_id = buy_order.id
-                INNER JOIN orders sell_order ON t.sell_order_id = sell_order.id
-                WHERE buy_order.user_id = $1 OR sell_order.user_id = $1
-                ORDER BY t.created_at DESC
-                LIMIT $2 OFFSET $3
-                "#,
-            )
-            .bind(user_id as i64)
-            .bind(limit)
-            .bind(offset)
-        };
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch trades")?;
+
+        let trades = rows.iter().map(|r| self.row_to_trade(r)).collect();
+        Ok(Page::from_rows(trades, limit, |trade: &Trade| {
+            PageCursor::new(trade.created_at, trade.id)
+        }))
+    }
+
+    /// 사용자별 체결 내역 페이지 조회 (최신순, keyset)
+    /// Get a page of trades by user ID (newest first)
+    ///
+    /// 사용자가 매수자(buyer_id) 또는 매도자(seller_id)인 체결을 조회합니다.
+    ///
+    /// # Arguments
+    /// * `filter` - 거래쌍/기간 필터
+    /// * `side` - 사용자 기준 방향 ('buy' 또는 'sell', None이면 둘 다)
+    /// * `cursor` - 이전 페이지의 `next_cursor` (None이면 첫 페이지)
+    /// * `limit` - 페이지 크기
+    ///
+    /// # 처리 과정
+    /// 1. 매수/매도 쪽을 각각 `(buyer_id|seller_id, created_at DESC, id DESC)` 인덱스로 limit + 1개 조회
+    /// 2. UNION ALL 후 다시 정렬하여 limit + 1개 (자전 거래는 매수 쪽에서만 반환)
+    pub async fn get_page_by_user(
+        &self,
+        user_id: u64,
+        filter: &TradeFilter,
+        side: Option<&str>,
+        cursor: Option<PageCursor>,
+        limit: i64,
+    ) -> Result<Page<Trade>> {
+        let mut query = Self::page_by_user_query(user_id, filter, side, cursor, limit);
 
         let rows = query
+            .build()
             .fetch_all(&self.pool)
             .await
             .context("Failed to fetch trades by user")?;
 
-        Ok(rows.iter().map(|r| self.row_to_trade(r)).collect())
+        let trades = rows.iter().map(|r| self.row_to_trade(r)).collect();
+        Ok(Page::from_rows(trades, limit, |trade: &Trade| {
+            PageCursor::new(trade.created_at, trade.id)
+        }))
     }
 
-    /// 사용자의 특정 자산 매수 거래 통계 (평균 매수가 계산용)
-    /// Get buy trade statistics for user's specific asset (for calculating average entry price)
-    /// 
-    /// Returns:
-    /// - total_bought_amount: 총 매수 수량
-    /// - total_bought_cost: 총 매수 금액 (price × amount의 합)
-    /// - average_entry_price: 평균 매수가 (total_bought_cost / total_bought_amount)
-    pub async fn get_buy_statistics(
-        &self,
+    /// get_page_by_user 쿼리 생성 (매수/매도 쪽 UNION ALL)
+    fn page_by_user_query(
         user_id: u64,
+        filter: &TradeFilter,
+        side: Option<&str>,
+        cursor: Option<PageCursor>,
+        limit: i64,
+    ) -> QueryBuilder<'static, Postgres> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT id, buy_order_id, sell_order_id, base_mint, quote_mint,
+                   price, amount, created_at
+            FROM ("#,
+        );
+
+        // (사용자 컬럼, 자전 거래 제외 여부)
+        let branches: &[(&str, bool)] = match side {
+            Some("buy") => &[("buyer_id", false)],
+            Some("sell") => &[("seller_id", false)],
+            _ => &[("buyer_id", false), ("seller_id", true)],
+        };
+        for (index, (user_column, exclude_self_trades)) in branches.iter().enumerate() {
+            if index > 0 {
+                query.push(" UNION ALL ");
+            }
+            query
+                .push(
+                    r#"(
+                SELECT id, buy_order_id, sell_order_id, base_mint, quote_mint,
+                       price, amount, created_at
+                FROM trades
+                WHERE "#,
+                )
+                .push(user_column)
+                .push(" = ")
+                .push_bind(user_id as i64);
+            if *exclude_self_trades {
+                query.push(" AND buyer_id <> ").push_bind(user_id as i64);
+            }
+            Self::push_conditions(&mut query, filter, cursor);
+            query
+                .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+                .push_bind(limit + 1)
+                .push(")");
+        }
+        query
+            .push(") t ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit + 1);
+        query
+    }
+
+    /// 필터/커서 조건 추가 (WHERE 절 뒤에 AND로 이어붙임)
+    fn push_conditions(
+        query: &mut QueryBuilder<'_, Postgres>,
+        filter: &TradeFilter,
+        cursor: Option<PageCursor>,
+    ) {
+        if let Some(base_mint) = &filter.base_mint {
+            query.push(" AND base_mint = ").push_bind(base_mint.clone());
+        }
+        if let Some(quote_mint) = &filter.quote_mint {
+            query.push(" AND quote_mint = ").push_bind(quote_mint.clone());
+        }
+        if let Some(from) = filter.from {
+            query.push(" AND created_at >= ").push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            query.push(" AND created_at < ").push_bind(to);
+        }
+        if let Some(cursor) = cursor {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(cursor.created_at)
+                .push(", ")
+                .push_bind(cursor.id as i64)
+                .push(")");
+        }
+    }
+
+    /// 특정 자산의 최근 체결가 조회 (현재 시장 가격 추정용)
+    /// Get latest trade price for specific asset (for estimating current market price)
+    pub async fn get_latest_price(
+        &self,
         base_mint: &str,
-    ) -> Result<Option<(Decimal, Decimal, Decimal)>> {
+    ) -> Result<Option<Decimal>> {
         let row = sqlx::query(
             r#"
-            SELECT 
-                COALESCE(SUM(t.amount), 0) as total_bought_amount,
-                COALESCE(SUM(t.price * t.amount), 0) as total_bought_cost
-            FROM trades t
-            INNER JOIN orders o ON t.buy_order_id = o.id
-            WHERE o.user_id = $1 AND t.base_mint = $2
+            SELECT price
+            FROM trades
+            WHERE base_mint = $1
+            ORDER BY created_at DESC
+            LIMIT 1
             "#,
         )
-        .bind(user_id as i64)
         .bind(base_mint)
         .fetch_optional(&self.pool)
         .await
-        .context("Failed to fetch buy statistics")?;
+        .context("Failed to fetch latest price")?;
 
-        if let Some(row) = row {
-            let total_bought_amount: Decimal = row.get("total_bought_amount");
-            let total_bought_cost: Decimal = row.get("total_bought_cost");
+        Ok(row.map(|r| r.get("price")))
+    }
 
-            if total_bought_amount.is_zero() {
-                return Ok(None);
-            }
+    /// 체결이 있었던 모든 거래쌍 조회
+    /// Get all trading pairs that have trades
+    ///
+    /// # Returns
+    /// (base_mint, quote_mint) 목록
+    pub async fn get_trading_pairs(&self) -> Result<Vec<(String, String)>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT base_mint, quote_mint
+            FROM trades
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch trading pairs")?;
 
-            let average_entry_price = total_bought_cost / total_bought_amount;
-            Ok(Some((total_bought_amount, total_bought_cost, average_entry_price)))
-        } else {
-            Ok(None)
-        }
+        Ok(rows
+            .iter()
+            .map(|r| (r.get("base_mint"), r.get("quote_mint")))
+            .collect())
     }
 
-    /// 사용자의 특정 자산 매도 거래 통계 (실현 손익 계산용)
-    /// Get sell trade statistics for user's specific asset (for calculating realized P&L)
-    /// 
-    /// Returns:
-    /// - total_sell_trades: 총 매도 횟수
-    /// - total_sold_amount: 총 매도 수량
-    /// - total_sold_value: 총 매도 금액 (price × amount의 합)
-    pub async fn get_sell_statistics(
+    /// 특정 시간 이후 모든 거래쌍 체결 내역 조회 (시간순, keyset 페이지)
+    /// Get trades of all pairs after a point in time (oldest first)
+    ///
+    /// # Arguments
+    /// * `after` - (created_at, id) 커서 (해당 체결 제외, 첫 페이지는 (시작 시간, 0))
+    /// * `until` - 끝 시간 (포함)
+    /// * `limit` - 페이지 크기
+    pub async fn get_since(
         &self,
-        user_id: u64,
-        base_mint: &str,
-    ) -> Result<(u64, Decimal, Decimal)> {
-        let row = sqlx::query(
+        after: (DateTime<Utc>, u64),
+        until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Trade>> {
+        let rows = sqlx::query(
             r#"
-            SELECT 
-                COUNT(*)::BIGINT as total_sell_trades,
-                COALESCE(SUM(t.amount), 0) as total_sold_amount,
-                COALESCE(SUM(t.price * t.amount), 0) as total_sold_value
-            FROM trades t
-            INNER JOIN orders o ON t.sell_order_id = o.id
-            WHERE o.user_id = $1 AND t.base_mint = $2
+            SELECT id, buy_order_id, sell_order_id, base_mint, quote_mint,
+                   price, amount, created_at
+            FROM trades
+            WHERE (created_at, id) > ($1, $2) AND created_at <= $3
+            ORDER BY created_at ASC, id ASC
+            LIMIT $4
             "#,
         )
-        .bind(user_id as i64)
-        .bind(base_mint)
-        .fetch_one(&self.pool)
+        .bind(after.0)
+        .bind(after.1 as i64)
+        .bind(until)
+        .bind(limit)
+        .fetch_all(&self.pool)
         .await
-        .context("Failed to fetch sell statistics")?;
+        .context("Failed to fetch trades since")?;
 
-        let total_sell_trades = row.get::<i64, _>("total_sell_trades") as u64;
-        let total_sold_amount: Decimal = row.get("total_sold_amount");
-        let total_sold_value: Decimal = row.get("total_sold_value");
-
-        Ok((total_sell_trades, total_sold_amount, total_sold_value))
+        Ok(rows.iter().map(|r| self.row_to_trade(r)).collect())
     }
 
-    /// 사용자의 특정 자산 매수 거래 횟수
-    /// Get total number of buy trades for user's specific asset
-    pub async fn get_buy_trade_count(
+    /// 특정 시간 이후 거래쌍 거래량 합계
+    /// Get traded volume of a pair since a point in time
+    ///
+    /// # Returns
+    /// (기준 자산 거래량, 기준 통화 거래대금)
+    pub async fn get_volume_since(
         &self,
-        user_id: u64,
         base_mint: &str,
-    ) -> Result<u64> {
+        quote_mint: &str,
+        since: DateTime<Utc>,
+    ) -> Result<(Decimal, Decimal)> {
         let row = sqlx::query(
             r#"
-            SELECT COUNT(DISTINCT t.id)::BIGINT as count
-            FROM trades t
-            INNER JOIN orders o ON t.buy_order_id = o.id
-            WHERE o.user_id = $1 AND t.base_mint = $2
+            SELECT COALESCE(SUM(amount), 0) AS base_volume,
+                   COALESCE(SUM(price * amount), 0) AS quote_volume
+            FROM trades
+            WHERE base_mint = $1 AND quote_mint = $2 AND created_at >= $3
             "#,
         )
-        .bind(user_id as i64)
         .bind(base_mint)
+        .bind(quote_mint)
+        .bind(since)
         .fetch_one(&self.pool)
         .await
-        .context("Failed to fetch buy trade count")?;
+        .context("Failed to fetch trade volume")?;
 
-        Ok(row.get::<i64, _>("count") as u64)
+        Ok((row.get("base_volume"), row.get("quote_volume")))
     }
 
-    /// 특정 자산의 최근 체결가 조회 (현재 시장 가격 추정용)
-    /// Get latest trade price for specific asset (for estimating current market price)
-    pub async fn get_latest_price(
+    /// 특정 체결 이후 사용자의 자산 체결 조회 (체결 ID 오름차순)
+    /// Get user's fills of an asset after a trade ID, oldest first
+    ///
+    /// # Arguments
+    /// * `after_trade_id` - 이 ID 이후 체결만 (해당 체결 제외)
+    /// * `limit` - 최대 개수
+    ///
+    /// # Returns
+    /// (체결, 매수 여부, 매도 여부) 목록 (자기 체결이면 둘 다 true)
+    pub async fn get_user_fills_after(
         &self,
+        user_id: u64,
         base_mint: &str,
-    ) -> Result<Option<Decimal>> {
-        let row = sqlx::query(
+        after_trade_id: u64,
+        limit: i64,
+    ) -> Result<Vec<(Trade, bool, bool)>> {
+        let rows = sqlx::query(
             r#"
-            SELECT price
+            SELECT id, buy_order_id, sell_order_id, base_mint, quote_mint,
+                   price, amount, created_at, buyer_id, seller_id
             FROM trades
-            WHERE base_mint = $1
-            ORDER BY created_at DESC
-            LIMIT 1
+            WHERE (buyer_id = $1 OR seller_id = $1) AND base_mint = $2 AND id > $3
+            ORDER BY id ASC
+            LIMIT $4
             "#,
         )
+        .bind(user_id as i64)
         .bind(base_mint)
-        .fetch_optional(&self.pool)
+        .bind(after_trade_id as i64)
+        .bind(limit)
+        .fetch_all(&self.pool)
         .await
-        .context("Failed to fetch latest price")?;
+        .context("Failed to fetch user fills")?;
 
-        Ok(row.map(|r| r.get("price")))
+        Ok(rows.iter().map(|r| self.row_to_user_fill(r, user_id)).collect())
+    }
+
+    /// 기간 내 사용자 체결 스트리밍 조회 (시간순, 내보내기용)
+    /// Stream user's fills in a time range with the fees charged, oldest first
+    ///
+    /// 전체 결과를 메모리에 올리지 않고 행 단위로 읽습니다 (조회 동안 연결 하나 사용).
+    /// 수수료는 원장의 fee 분개(참조: 체결 ID)에서 사용자 available 차감분을 합산합니다.
+    ///
+    /// # Arguments
+    /// * `from` - 시작 시간 (포함)
+    /// * `to` - 끝 시간 (미포함)
+    ///
+    pub fn stream_by_user(
+        &self,
+        user_id: u64,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<UserFill>> {
+        sqlx::query(
+            r#"
+            SELECT t.id, t.buy_order_id, t.sell_order_id, t.base_mint, t.quote_mint,
+                   t.price, t.amount, t.created_at, t.buyer_id, t.seller_id,
+                   f.buyer_fee, f.seller_fee
+            FROM trades t
+            CROSS JOIN LATERAL (
+                SELECT COALESCE(-SUM(p.amount) FILTER (WHERE p.mint = t.base_mint), 0) AS buyer_fee,
+                       COALESCE(-SUM(p.amount) FILTER (WHERE p.mint = t.quote_mint), 0) AS seller_fee
+                FROM ledger_entries e
+                JOIN ledger_postings p ON p.entry_id = e.id
+                WHERE e.reason = 'fee' AND e.reference = t.id::text
+                  AND p.user_id = $1 AND p.account = 'available'
+            ) f
+            WHERE (t.buyer_id = $1 OR t.seller_id = $1) AND t.created_at >= $2 AND t.created_at < $3
+            ORDER BY t.created_at ASC, t.id ASC
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(from)
+        .bind(to)
+        .fetch(&self.pool)
+        .map(move |row| {
+            let row = row.context("Failed to stream user trades")?;
+            let (trade, is_buyer, is_seller) = self.row_to_user_fill(&row, user_id);
+            Ok(UserFill {
+                trade,
+                buyer_fee: is_buyer.then(|| row.get::<Decimal, _>("buyer_fee")),
+                seller_fee: is_seller.then(|| row.get::<Decimal, _>("seller_fee")),
+            })
+        })
+        .boxed()
+    }
+
+    /// buyer_id/seller_id를 포함한 Row를 (체결, 매수 여부, 매도 여부)로 변환
+    fn row_to_user_fill(&self, row: &sqlx::postgres::PgRow, user_id: u64) -> (Trade, bool, bool) {
+        let is_buyer = row.get::<i64, _>("buyer_id") as u64 == user_id;
+        let is_seller = row.get::<i64, _>("seller_id") as u64 == user_id;
+        (self.row_to_trade(row), is_buyer, is_seller)
     }
 
     /// Row를 Trade로 변환하는 헬퍼 메서드
@@ -381,3 +552,49 @@ impl TradeRepository {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_page_by_user_query_excludes_self_trades_from_seller_branch() {
+        let query =
+            TradeRepository::page_by_user_query(7, &TradeFilter::default(), None, None, 50);
+        let sql = query.sql();
+
+        assert_eq!(sql.matches("UNION ALL").count(), 1);
+        let (buy_branch, sell_branch) = sql.split_once("UNION ALL").unwrap();
+        assert!(buy_branch.contains("buyer_id = $1"));
+        assert!(!buy_branch.contains("buyer_id <>"));
+        assert!(sell_branch.contains("seller_id = $3 AND buyer_id <> $4"));
+    }
+
+    #[test]
+    fn test_page_by_user_query_single_side() {
+        for (side, column) in [("buy", "buyer_id = $1"), ("sell", "seller_id = $1")] {
+            let query = TradeRepository::page_by_user_query(
+                7,
+                &TradeFilter::default(),
+                Some(side),
+                None,
+                50,
+            );
+            let sql = query.sql();
+
+            assert!(!sql.contains("UNION ALL"));
+            assert!(sql.contains(column));
+            assert!(!sql.contains("buyer_id <>"));
+        }
+    }
+
+    #[test]
+    fn test_page_by_user_query_applies_cursor_to_each_branch() {
+        let cursor = PageCursor::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(), 42);
+        let query =
+            TradeRepository::page_by_user_query(7, &TradeFilter::default(), None, Some(cursor), 50);
+
+        assert_eq!(query.sql().matches("AND (created_at, id) < (").count(), 2);
+    }
+}
@@ -0,0 +1,278 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use crate::domains::cex::models::transfer::{Transfer, TransferDirection};
+
+/// 내부 이체 Repository
+/// Internal transfer repository
+///
+/// 상태 변경은 모두 조건부 UPDATE (WHERE status = 'pending')이며,
+/// 변경에 성공했는지 bool로 반환합니다.
+pub struct TransferRepository {
+    pool: PgPool,
+}
+
+impl TransferRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 이체 생성 (pending)
+    /// Create pending transfer
+    ///
+    /// # Returns
+    /// * `Some(Transfer)` - 새로 생성됨
+    /// * `None` - 같은 보내는 사용자 + 멱등 키의 이체가 이미 있음
+    pub async fn create(
+        &self,
+        from_user_id: u64,
+        to_user_id: u64,
+        mint: &str,
+        amount: Decimal,
+        memo: Option<&str>,
+        idempotency_key: &str,
+    ) -> Result<Option<Transfer>> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO internal_transfers (from_user_id, to_user_id, mint, amount, memo, idempotency_key, status, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, 'pending', $7)
+            ON CONFLICT (from_user_id, idempotency_key) DO NOTHING
+            RETURNING id, from_user_id, to_user_id, mint, amount, memo, idempotency_key,
+                      status, error, created_at, completed_at
+            "#,
+        )
+        .bind(from_user_id as i64)
+        .bind(to_user_id as i64)
+        .bind(mint)
+        .bind(amount)
+        .bind(memo)
+        .bind(idempotency_key)
+        .bind(Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to create transfer")?;
+
+        row.as_ref().map(Self::row_to_transfer).transpose()
+    }
+
+    /// 멱등 키로 이체 조회
+    /// Get transfer by sender and idempotency key
+    pub async fn get_by_idempotency_key(&self, from_user_id: u64, idempotency_key: &str) -> Result<Option<Transfer>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, from_user_id, to_user_id, mint, amount, memo, idempotency_key,
+                   status, error, created_at, completed_at
+            FROM internal_transfers
+            WHERE from_user_id = $1 AND idempotency_key = $2
+            "#,
+        )
+        .bind(from_user_id as i64)
+        .bind(idempotency_key)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch transfer by idempotency key")?;
+
+        row.as_ref().map(Self::row_to_transfer).transpose()
+    }
+
+    /// 이체 완료 (pending → completed)
+    /// Mark transfer as completed
+    pub async fn mark_completed(&self, transfer_id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE internal_transfers SET status = 'completed', error = NULL, completed_at = $2
+            WHERE id = $1 AND status = 'pending'
+            "#,
+        )
+        .bind(transfer_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark transfer completed")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 이체 실패 (pending → failed)
+    /// Mark transfer as failed
+    pub async fn mark_failed(&self, transfer_id: u64, error: &str) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE internal_transfers SET status = 'failed', error = $2, completed_at = $3
+            WHERE id = $1 AND status = 'pending'
+            "#,
+        )
+        .bind(transfer_id as i64)
+        .bind(error)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark transfer failed")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 결과를 알 수 없는 이체에 에러 기록 (pending 유지)
+    /// Record an error on a pending transfer whose outcome is unknown
+    pub async fn set_error(&self, transfer_id: u64, error: &str) -> Result<()> {
+        sqlx::query("UPDATE internal_transfers SET error = $2 WHERE id = $1 AND status = 'pending'")
+            .bind(transfer_id as i64)
+            .bind(error)
+            .execute(&self.pool)
+            .await
+            .context("Failed to record transfer error")?;
+        Ok(())
+    }
+
+    /// 원장에 이체 분개가 있으면 완료 (pending → completed)
+    /// Complete a pending transfer once its ledger entry is persisted
+    ///
+    /// # Returns
+    /// 완료 처리했으면 true (분개가 아직 없거나 pending이 아니면 false)
+    pub async fn complete_if_posted(&self, transfer_id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE internal_transfers t SET status = 'completed', error = NULL, completed_at = $2
+            WHERE t.id = $1 AND t.status = 'pending'
+              AND EXISTS (
+                  SELECT 1 FROM ledger_entries e
+                  WHERE e.reason = 'transfer' AND e.reference = t.id::TEXT
+              )
+            "#,
+        )
+        .bind(transfer_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to complete posted transfer")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 이전 프로세스에서 끝나지 않은 pending 이체 정리
+    /// Resolve pending transfers left by a previous process
+    ///
+    /// 원장에 이체 분개(reason = 'transfer', reference = 이체 ID)가 있으면
+    /// 잔고 이동이 DB에 반영된 것이므로 completed, 없으면 failed 입니다.
+    ///
+    /// # Arguments
+    /// * `created_before` - 이 시간 이전에 생성된 pending 이체만 (현재 프로세스 요청 제외)
+    ///
+    /// # Returns
+    /// (completed 수, failed 수)
+    pub async fn resolve_interrupted(&self, created_before: DateTime<Utc>) -> Result<(u64, u64)> {
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+
+        let completed = sqlx::query(
+            r#"
+            UPDATE internal_transfers t SET status = 'completed', error = NULL, completed_at = $2
+            WHERE t.status = 'pending' AND t.created_at < $1
+              AND EXISTS (
+                  SELECT 1 FROM ledger_entries e
+                  WHERE e.reason = 'transfer' AND e.reference = t.id::TEXT
+              )
+            "#,
+        )
+        .bind(created_before)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await
+        .context("Failed to complete interrupted transfers")?
+        .rows_affected();
+
+        let failed = sqlx::query(
+            r#"
+            UPDATE internal_transfers SET status = 'failed', error = 'Interrupted before the balance move was persisted', completed_at = $2
+            WHERE status = 'pending' AND created_at < $1
+            "#,
+        )
+        .bind(created_before)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await
+        .context("Failed to fail interrupted transfers")?
+        .rows_affected();
+
+        tx.commit().await.context("Failed to commit transaction")?;
+        Ok((completed, failed))
+    }
+
+    /// 이체 조회
+    /// Get transfer by ID
+    pub async fn get_by_id(&self, transfer_id: u64) -> Result<Option<Transfer>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, from_user_id, to_user_id, mint, amount, memo, idempotency_key,
+                   status, error, created_at, completed_at
+            FROM internal_transfers
+            WHERE id = $1
+            "#,
+        )
+        .bind(transfer_id as i64)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch transfer")?;
+
+        row.as_ref().map(Self::row_to_transfer).transpose()
+    }
+
+    /// 사용자 이체 목록 (보낸/받은 이체, 최신순)
+    /// List user's transfers (sent and received), newest first
+    ///
+    /// # Arguments
+    /// * `direction` - 보낸 이체만 / 받은 이체만 (None이면 둘 다)
+    pub async fn list_by_user(
+        &self,
+        user_id: u64,
+        direction: Option<TransferDirection>,
+        mint: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<Transfer>> {
+        let (outgoing, incoming) = match direction {
+            Some(TransferDirection::Outgoing) => (true, false),
+            Some(TransferDirection::Incoming) => (false, true),
+            None => (true, true),
+        };
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, from_user_id, to_user_id, mint, amount, memo, idempotency_key,
+                   status, error, created_at, completed_at
+            FROM internal_transfers
+            WHERE (($2 AND from_user_id = $1) OR ($3 AND to_user_id = $1))
+              AND ($4::TEXT IS NULL OR mint = $4)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(outgoing)
+        .bind(incoming)
+        .bind(mint)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch transfers")?;
+
+        rows.iter().map(Self::row_to_transfer).collect()
+    }
+
+    fn row_to_transfer(row: &sqlx::postgres::PgRow) -> Result<Transfer> {
+        let status: String = row.get("status");
+
+        Ok(Transfer {
+            id: row.get::<i64, _>("id") as u64,
+            from_user_id: row.get::<i64, _>("from_user_id") as u64,
+            to_user_id: row.get::<i64, _>("to_user_id") as u64,
+            mint: row.get("mint"),
+            amount: row.get("amount"),
+            memo: row.get("memo"),
+            idempotency_key: row.get("idempotency_key"),
+            status: status.parse()?,
+            error: row.get("error"),
+            created_at: row.get("created_at"),
+            completed_at: row.get("completed_at"),
+        })
+    }
+}
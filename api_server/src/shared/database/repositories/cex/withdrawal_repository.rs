@@ -0,0 +1,339 @@
+use sqlx::{PgPool, Row};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{BoxStream, StreamExt};
+use rust_decimal::Decimal;
+use crate::domains::cex::models::withdrawal::{Withdrawal, WithdrawalStatus};
+
+/// 출금 Repository
+/// Withdrawal repository
+///
+/// 상태 변경은 모두 조건부 UPDATE (WHERE status = 이전 상태)이며,
+/// 변경에 성공했는지 bool로 반환합니다.
+pub struct WithdrawalRepository {
+    pool: PgPool,
+}
+
+impl WithdrawalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 출금 요청 생성 (requested)
+    /// Create withdrawal request
+    pub async fn create(
+        &self,
+        user_id: u64,
+        mint: &str,
+        token_mint: Option<&str>,
+        amount: Decimal,
+        destination: &str,
+    ) -> Result<Withdrawal> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO withdrawals (user_id, mint, token_mint, amount, destination, status, created_at)
+            VALUES ($1, $2, $3, $4, $5, 'requested', $6)
+            RETURNING id, user_id, mint, token_mint, amount, destination, signature, last_valid_block_height,
+                      status, error, created_at, held_at, approved_at, broadcast_at, completed_at
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .bind(token_mint)
+        .bind(amount)
+        .bind(destination)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to create withdrawal")?;
+
+        Self::row_to_withdrawal(&row)
+    }
+
+    /// 잔고 보류 완료 기록
+    /// Record that the engine hold was placed
+    pub async fn mark_held(&self, withdrawal_id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE withdrawals SET held_at = $2 WHERE id = $1 AND status = 'requested' AND held_at IS NULL",
+        )
+        .bind(withdrawal_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark withdrawal held")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 원장에 보류 분개가 있으면 보류 완료 기록 (엔진 응답을 받지 못한 출금)
+    /// Record the hold once its ledger entry is persisted
+    ///
+    /// # Returns
+    /// 기록했으면 true (분개가 아직 없거나 이미 기록됐으면 false)
+    pub async fn mark_held_if_posted(&self, withdrawal_id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawals w SET held_at = $2, error = NULL
+            WHERE w.id = $1 AND w.status = 'requested' AND w.held_at IS NULL
+              AND EXISTS (
+                  SELECT 1 FROM ledger_entries e
+                  WHERE e.reason = 'withdrawal' AND e.reference = w.id::TEXT
+              )
+            "#,
+        )
+        .bind(withdrawal_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark posted withdrawal held")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 승인 (requested → approved)
+    /// Approve withdrawal
+    pub async fn approve(&self, withdrawal_id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawals SET status = 'approved', approved_at = $2
+            WHERE id = $1 AND status = 'requested' AND held_at IS NOT NULL
+            "#,
+        )
+        .bind(withdrawal_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to approve withdrawal")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 지급 트랜잭션 기록 (approved → broadcast, 전송 전에 호출)
+    /// Record signed payout transaction before sending
+    pub async fn mark_broadcast(
+        &self,
+        withdrawal_id: u64,
+        signature: &str,
+        last_valid_block_height: u64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawals SET status = 'broadcast', signature = $2, last_valid_block_height = $3, broadcast_at = $4
+            WHERE id = $1 AND status = 'approved'
+            "#,
+        )
+        .bind(withdrawal_id as i64)
+        .bind(signature)
+        .bind(last_valid_block_height as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark withdrawal broadcast")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 지급 완료 (broadcast → confirmed)
+    /// Mark withdrawal as confirmed
+    pub async fn mark_confirmed(&self, withdrawal_id: u64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawals SET status = 'confirmed', error = NULL, completed_at = $2
+            WHERE id = $1 AND status = 'broadcast'
+            "#,
+        )
+        .bind(withdrawal_id as i64)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark withdrawal confirmed")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 출금 실패 (from → failed)
+    /// Mark withdrawal as failed
+    pub async fn mark_failed(&self, withdrawal_id: u64, from: WithdrawalStatus, error: &str) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawals SET status = 'failed', error = $3, completed_at = $4
+            WHERE id = $1 AND status = $2
+            "#,
+        )
+        .bind(withdrawal_id as i64)
+        .bind(from.as_str())
+        .bind(error)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark withdrawal failed")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 오류 기록 (상태 유지, 수동 확인용)
+    /// Record error without changing status
+    pub async fn set_error(&self, withdrawal_id: u64, error: &str) -> Result<()> {
+        sqlx::query("UPDATE withdrawals SET error = $2 WHERE id = $1")
+            .bind(withdrawal_id as i64)
+            .bind(error)
+            .execute(&self.pool)
+            .await
+            .context("Failed to record withdrawal error")?;
+        Ok(())
+    }
+
+    /// 상태별 보류 중인 출금 조회 (오래된 순)
+    /// Get held withdrawals by status
+    pub async fn get_by_status(&self, status: WithdrawalStatus, limit: i64) -> Result<Vec<Withdrawal>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, destination, signature, last_valid_block_height,
+                   status, error, created_at, held_at, approved_at, broadcast_at, completed_at
+            FROM withdrawals
+            WHERE status = $1 AND held_at IS NOT NULL
+            ORDER BY id ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(status.as_str())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch withdrawals by status")?;
+
+        rows.iter().map(Self::row_to_withdrawal).collect()
+    }
+
+    /// 잔고 보류 중인 진행 중 출금 전체 (엔진 시작 시 보류 복원용)
+    /// Get all in-flight withdrawals holding balance
+    pub async fn get_held(&self) -> Result<Vec<Withdrawal>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, destination, signature, last_valid_block_height,
+                   status, error, created_at, held_at, approved_at, broadcast_at, completed_at
+            FROM withdrawals
+            WHERE status IN ('requested', 'approved', 'broadcast') AND held_at IS NOT NULL
+            ORDER BY id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch held withdrawals")?;
+
+        rows.iter().map(Self::row_to_withdrawal).collect()
+    }
+
+    /// 보류 없이 requested에 남은 출금 (보류 결과를 알 수 없음, 수동 확인용)
+    /// Get requested withdrawals whose hold was never recorded
+    pub async fn get_unheld(&self, created_before: DateTime<Utc>) -> Result<Vec<Withdrawal>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, destination, signature, last_valid_block_height,
+                   status, error, created_at, held_at, approved_at, broadcast_at, completed_at
+            FROM withdrawals
+            WHERE status = 'requested' AND held_at IS NULL AND created_at < $1
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(created_before)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch unheld withdrawals")?;
+
+        rows.iter().map(Self::row_to_withdrawal).collect()
+    }
+
+    /// 출금 조회
+    /// Get withdrawal by ID
+    pub async fn get_by_id(&self, withdrawal_id: u64) -> Result<Option<Withdrawal>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, destination, signature, last_valid_block_height,
+                   status, error, created_at, held_at, approved_at, broadcast_at, completed_at
+            FROM withdrawals
+            WHERE id = $1
+            "#,
+        )
+        .bind(withdrawal_id as i64)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch withdrawal")?;
+
+        row.as_ref().map(Self::row_to_withdrawal).transpose()
+    }
+
+    /// 사용자 출금 목록 (최신순)
+    /// List user's withdrawals, newest first
+    pub async fn list_by_user(&self, user_id: u64, mint: Option<&str>, limit: i64) -> Result<Vec<Withdrawal>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, destination, signature, last_valid_block_height,
+                   status, error, created_at, held_at, approved_at, broadcast_at, completed_at
+            FROM withdrawals
+            WHERE user_id = $1 AND ($2::TEXT IS NULL OR mint = $2)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(mint)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch withdrawals")?;
+
+        rows.iter().map(Self::row_to_withdrawal).collect()
+    }
+
+    /// 기간 내 사용자 출금 스트리밍 조회 (요청 시간 오름차순, 내보내기용)
+    /// Stream user's withdrawals requested in a time range
+    ///
+    /// # Arguments
+    /// * `from` - 시작 시간 (포함)
+    /// * `to` - 끝 시간 (미포함)
+    pub fn stream_by_user(
+        &self,
+        user_id: u64,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxStream<'_, Result<Withdrawal>> {
+        sqlx::query(
+            r#"
+            SELECT id, user_id, mint, token_mint, amount, destination, signature, last_valid_block_height,
+                   status, error, created_at, held_at, approved_at, broadcast_at, completed_at
+            FROM withdrawals
+            WHERE user_id = $1 AND created_at >= $2 AND created_at < $3
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(user_id as i64)
+        .bind(from)
+        .bind(to)
+        .fetch(&self.pool)
+        .map(|row| Self::row_to_withdrawal(&row.context("Failed to stream withdrawals")?))
+        .boxed()
+    }
+
+    fn row_to_withdrawal(row: &sqlx::postgres::PgRow) -> Result<Withdrawal> {
+        let status: String = row.get("status");
+
+        Ok(Withdrawal {
+            id: row.get::<i64, _>("id") as u64,
+            user_id: row.get::<i64, _>("user_id") as u64,
+            mint: row.get("mint"),
+            token_mint: row.get("token_mint"),
+            amount: row.get("amount"),
+            destination: row.get("destination"),
+            signature: row.get("signature"),
+            last_valid_block_height: row.get::<Option<i64>, _>("last_valid_block_height").map(|height| height as u64),
+            status: status.parse()?,
+            error: row.get("error"),
+            created_at: row.get("created_at"),
+            held_at: row.get("held_at"),
+            approved_at: row.get("approved_at"),
+            broadcast_at: row.get("broadcast_at"),
+            completed_at: row.get("completed_at"),
+        })
+    }
+}
@@ -2,9 +2,11 @@
 pub mod auth;
 pub mod wallet;
 pub mod cex;
+pub mod admin;
 
 // Re-export all repositories for convenience
 pub use auth::*;
 pub use wallet::*;
 pub use cex::*;
+pub use admin::*;
 
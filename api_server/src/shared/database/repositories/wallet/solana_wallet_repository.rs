@@ -1,7 +1,7 @@
 use sqlx::{PgPool, Row};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use crate::domains::wallet::models::SolanaWallet;
+use crate::domains::wallet::models::{EncryptedKey, SolanaWallet};
 
 // Solana 지갑 레포지토리
 // 역할: NestJS의 Repository 같은 것
@@ -25,7 +25,7 @@ impl SolanaWalletRepository {
         &self,
         user_id: u64,
         public_key: &str,
-        encrypted_private_key: &str,
+        key: &EncryptedKey,
     ) -> Result<SolanaWallet> {
         // 논리적 관계 검증: user_id가 실제로 존재하는지 확인
         // Logical relationship validation: check if user_id actually exists
@@ -45,14 +45,16 @@ impl SolanaWalletRepository {
         // Create Solana wallet
         let row = sqlx::query(
             r#"
-            INSERT INTO solana_wallets (user_id, public_key, encrypted_private_key, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5)
-            RETURNING id, user_id, public_key, encrypted_private_key, created_at, updated_at
+            INSERT INTO solana_wallets (user_id, public_key, encrypted_private_key, wrapped_data_key, key_version, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, user_id, public_key, encrypted_private_key, wrapped_data_key, key_version, is_deposit_address, created_at, updated_at
             "#,
         )
         .bind(user_id as i64)
         .bind(public_key)
-        .bind(encrypted_private_key)
+        .bind(&key.encrypted_private_key)
+        .bind(&key.wrapped_data_key)
+        .bind(key.key_version as i32)
         .bind(Utc::now())
         .bind(Utc::now())
         .fetch_one(&self.pool)
@@ -64,6 +66,9 @@ impl SolanaWalletRepository {
             user_id: row.get::<i64, _>("user_id") as u64,
             public_key: row.get("public_key"),
             encrypted_private_key: row.get("encrypted_private_key"),
+            wrapped_data_key: row.get("wrapped_data_key"),
+            key_version: row.get::<Option<i32>, _>("key_version").map(|version| version as u32),
+            is_deposit_address: row.get("is_deposit_address"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         })
@@ -74,7 +79,7 @@ impl SolanaWalletRepository {
     pub async fn get_solana_wallets_by_user_id(&self, user_id: u64) -> Result<Vec<SolanaWallet>> {
         let rows = sqlx::query(
             r#"
-            SELECT id, user_id, public_key, encrypted_private_key, created_at, updated_at
+            SELECT id, user_id, public_key, encrypted_private_key, wrapped_data_key, key_version, is_deposit_address, created_at, updated_at
             FROM solana_wallets
             WHERE user_id = $1
             ORDER BY created_at DESC
@@ -92,6 +97,9 @@ impl SolanaWalletRepository {
                 user_id: row.get::<i64, _>("user_id") as u64,
                 public_key: row.get("public_key"),
                 encrypted_private_key: row.get("encrypted_private_key"),
+                wrapped_data_key: row.get("wrapped_data_key"),
+                key_version: row.get::<Option<i32>, _>("key_version").map(|version| version as u32),
+                is_deposit_address: row.get("is_deposit_address"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             })
@@ -105,7 +113,7 @@ impl SolanaWalletRepository {
     pub async fn get_solana_wallet_by_public_key(&self, public_key: &str) -> Result<Option<SolanaWallet>> {
         let row = sqlx::query(
             r#"
-            SELECT id, user_id, public_key, encrypted_private_key, created_at, updated_at
+            SELECT id, user_id, public_key, encrypted_private_key, wrapped_data_key, key_version, is_deposit_address, created_at, updated_at
             FROM solana_wallets
             WHERE public_key = $1
             "#,
@@ -125,6 +133,9 @@ impl SolanaWalletRepository {
             user_id: row.get::<i64, _>("user_id") as u64,
             public_key: row.get("public_key"),
             encrypted_private_key: row.get("encrypted_private_key"),
+            wrapped_data_key: row.get("wrapped_data_key"),
+            key_version: row.get::<Option<i32>, _>("key_version").map(|version| version as u32),
+            is_deposit_address: row.get("is_deposit_address"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         }))
@@ -135,7 +146,7 @@ impl SolanaWalletRepository {
     pub async fn get_solana_wallet_by_id(&self, wallet_id: u64) -> Result<Option<SolanaWallet>> {
         let row = sqlx::query(
             r#"
-            SELECT id, user_id, public_key, encrypted_private_key, created_at, updated_at
+            SELECT id, user_id, public_key, encrypted_private_key, wrapped_data_key, key_version, is_deposit_address, created_at, updated_at
             FROM solana_wallets
             WHERE id = $1
             "#,
@@ -155,9 +166,114 @@ impl SolanaWalletRepository {
             user_id: row.get::<i64, _>("user_id") as u64,
             public_key: row.get("public_key"),
             encrypted_private_key: row.get("encrypted_private_key"),
+            wrapped_data_key: row.get("wrapped_data_key"),
+            key_version: row.get::<Option<i32>, _>("key_version").map(|version| version as u32),
+            is_deposit_address: row.get("is_deposit_address"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         }))
     }
-}
 
+    // 모든 지갑 주소 조회 (수탁 지갑 판별용, 개인 키 제외)
+    // Get all wallet addresses as (user_id, public_key, is_deposit_address)
+    pub async fn get_all_wallet_addresses(&self) -> Result<Vec<(u64, String, bool)>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT user_id, public_key, is_deposit_address
+            FROM solana_wallets
+            ORDER BY id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch Solana wallet addresses")?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    row.get::<i64, _>("user_id") as u64,
+                    row.get("public_key"),
+                    row.get("is_deposit_address"),
+                )
+            })
+            .collect())
+    }
+
+    // 입금 주소로 전환 (이후 입금 감시 대상, 지갑 API 전송 불가)
+    // Mark wallet as a CEX deposit address
+    pub async fn mark_deposit_address(&self, wallet_id: u64) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE solana_wallets
+            SET is_deposit_address = TRUE, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(wallet_id as i64)
+        .execute(&self.pool)
+        .await
+        .context("Failed to mark Solana wallet as deposit address")?;
+
+        Ok(())
+    }
+
+    // 재암호화 대상 지갑 조회 (key_version이 현재 버전이 아닌 행, id 순)
+    // Get wallets whose private key is not wrapped with the given master key version
+    pub async fn get_wallets_for_rotation(&self, key_version: u32, after_id: u64, limit: i64) -> Result<Vec<SolanaWallet>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, public_key, encrypted_private_key, wrapped_data_key, key_version, is_deposit_address, created_at, updated_at
+            FROM solana_wallets
+            WHERE key_version IS DISTINCT FROM $1 AND id > $2
+            ORDER BY id ASC
+            LIMIT $3
+            "#,
+        )
+        .bind(key_version as i32)
+        .bind(after_id as i64)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch Solana wallets for key rotation")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| SolanaWallet {
+                id: row.get::<i64, _>("id") as u64,
+                user_id: row.get::<i64, _>("user_id") as u64,
+                public_key: row.get("public_key"),
+                encrypted_private_key: row.get("encrypted_private_key"),
+                wrapped_data_key: row.get("wrapped_data_key"),
+                key_version: row.get::<Option<i32>, _>("key_version").map(|version| version as u32),
+                is_deposit_address: row.get("is_deposit_address"),
+                created_at: row.get("created_at"),
+                updated_at: row.get("updated_at"),
+            })
+            .collect())
+    }
+
+    // 암호화한 개인 키 교체 (조회 후 다른 곳에서 바뀌지 않았을 때만)
+    // Replace the stored key envelope if key_version is still the one that was read
+    //
+    // Returns: 교체했으면 true
+    pub async fn update_wallet_key(&self, wallet_id: u64, expected_key_version: Option<u32>, key: &EncryptedKey) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE solana_wallets
+            SET encrypted_private_key = $3, wrapped_data_key = $4, key_version = $5, updated_at = NOW()
+            WHERE id = $1 AND key_version IS NOT DISTINCT FROM $2
+            "#,
+        )
+        .bind(wallet_id as i64)
+        .bind(expected_key_version.map(|version| version as i32))
+        .bind(&key.encrypted_private_key)
+        .bind(&key.wrapped_data_key)
+        .bind(key.key_version as i32)
+        .execute(&self.pool)
+        .await
+        .context("Failed to update Solana wallet key")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
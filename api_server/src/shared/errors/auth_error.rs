@@ -55,6 +55,26 @@ pub enum AuthError {
     /// Token not provided
     #[error("Token not provided")]
     MissingToken,
+
+    /// 잘못된 요청 값
+    /// Invalid request
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    /// API 키 인증 실패 (키 없음, 서명 불일치, 시간 초과, 재사용)
+    /// API key authentication failed
+    #[error("Invalid API key request: {0}")]
+    InvalidApiKey(String),
+
+    /// 권한 없음 (API 키 권한 / IP 제한)
+    /// Forbidden
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    /// 마스터 키 미설정 (WALLET_MASTER_KEY_FILE, API 키 secret 암호화 불가)
+    /// API key secret encryption is not configured
+    #[error("API key secret encryption is not configured")]
+    KeyEncryptionUnavailable,
 }
 
 /// AuthError를 HTTP 응답으로 변환
@@ -88,6 +108,18 @@ impl From<AuthError> for (StatusCode, Json<serde_json::Value>) {
             AuthError::InvalidToken | AuthError::MissingToken => {
                 (StatusCode::UNAUTHORIZED, err.to_string())
             }
+            AuthError::InvalidRequest(_) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            AuthError::InvalidApiKey(_) => {
+                (StatusCode::UNAUTHORIZED, err.to_string())
+            }
+            AuthError::Forbidden(_) => {
+                (StatusCode::FORBIDDEN, err.to_string())
+            }
+            AuthError::KeyEncryptionUnavailable => {
+                (StatusCode::SERVICE_UNAVAILABLE, err.to_string())
+            }
         };
 
         (status, Json(json!({ "error": message })))
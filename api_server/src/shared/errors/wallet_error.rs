@@ -26,6 +26,11 @@ pub enum WalletError {
     #[error("Insufficient balance: required={required}, available={available}")]
     InsufficientBalance { required: u64, available: u64 },
 
+    /// CEX 입금 주소 지갑에서 전송 시도 (출금은 /api/cex/withdrawals)
+    /// Transfers from deposit-address wallets are not allowed
+    #[error("Wallet {id} is a CEX deposit address; use /api/cex/withdrawals to withdraw")]
+    DepositAddressTransfer { id: u64 },
+
     /// Public Key 파싱 실패
     /// Failed to parse public key
     #[error("Failed to parse public key: {public_key}")]
@@ -36,6 +41,16 @@ pub enum WalletError {
     #[error("Failed to decrypt private key: {0}")]
     DecryptionFailed(String),
 
+    /// Private Key 암호화 실패
+    /// Failed to encrypt private key
+    #[error("Failed to encrypt private key: {0}")]
+    EncryptionFailed(String),
+
+    /// 마스터 키 미설정 (WALLET_MASTER_KEY_FILE)
+    /// Wallet key encryption is not configured
+    #[error("Wallet key encryption is not configured")]
+    KeyEncryptionUnavailable,
+
     /// Solana 네트워크 에러
     /// Solana network error
     #[error("Solana network error: {0}")]
@@ -78,12 +93,21 @@ impl From<WalletError> for (StatusCode, Json<serde_json::Value>) {
             WalletError::InsufficientBalance { .. } => {
                 (StatusCode::BAD_REQUEST, err.to_string())
             }
+            WalletError::DepositAddressTransfer { .. } => {
+                (StatusCode::FORBIDDEN, err.to_string())
+            }
             WalletError::InvalidPublicKey { .. } => {
                 (StatusCode::BAD_REQUEST, err.to_string())
             }
             WalletError::DecryptionFailed(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
             }
+            WalletError::EncryptionFailed(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+            }
+            WalletError::KeyEncryptionUnavailable => {
+                (StatusCode::SERVICE_UNAVAILABLE, err.to_string())
+            }
             WalletError::SolanaNetworkError(_) => {
                 (StatusCode::BAD_GATEWAY, err.to_string())
             }
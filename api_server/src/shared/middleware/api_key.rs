@@ -0,0 +1,129 @@
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use crate::domains::auth::models::api_key::ApiKeyScope;
+use crate::domains::auth::services::api_key_service::SignedRequest;
+use crate::shared::services::AppState;
+use crate::shared::utils::client_ip::client_ip;
+use serde_json::json;
+
+// =====================================================
+// API 키 인증 미들웨어
+// =====================================================
+// 역할: X-Api-Key 요청의 HMAC 서명 검증
+//
+// 처리 방식:
+// - X-Api-Key 헤더가 없으면 그대로 통과 (JWT 요청)
+// - 있으면 본문을 읽어 서명 검증 후 ApiKeyPrincipal을 요청 extension에 넣음
+//   → AuthenticatedUser extractor가 Authorization 헤더 대신 사용
+// - 서명에 본문이 포함되므로 extractor가 아닌 미들웨어에서 검증
+// =====================================================
+
+/// 공개 키 ID 헤더
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
+/// 요청 시간 헤더 (Unix ms)
+pub const API_TIMESTAMP_HEADER: &str = "X-Api-Timestamp";
+
+/// 서명 헤더 (hex)
+pub const API_SIGNATURE_HEADER: &str = "X-Api-Signature";
+
+/// 서명 검증 시 읽는 본문 최대 크기
+const MAX_SIGNED_BODY_BYTES: usize = 1024 * 1024;
+
+fn reject(status: StatusCode, message: impl Into<String>) -> Response {
+    (status, axum::Json(json!({ "error": message.into() }))).into_response()
+}
+
+/// API 키 인증 미들웨어
+/// API key authentication middleware
+///
+/// 사용법:
+/// ```rust
+/// create_router().layer(axum::middleware::from_fn_with_state(app_state.clone(), api_key_auth))
+/// ```
+pub async fn api_key_auth(
+    State(app_state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !request.headers().contains_key(API_KEY_HEADER) {
+        return next.run(request).await;
+    }
+
+    // 1. 헤더
+    if request.headers().contains_key("Authorization") {
+        return reject(
+            StatusCode::BAD_REQUEST,
+            format!("Send either Authorization or {}, not both", API_KEY_HEADER),
+        );
+    }
+    let headers = {
+        let header = |name: &str| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        (header(API_KEY_HEADER), header(API_TIMESTAMP_HEADER), header(API_SIGNATURE_HEADER))
+    };
+    let (Some(key_id), Some(timestamp), Some(signature)) = headers else {
+        return reject(
+            StatusCode::UNAUTHORIZED,
+            format!(
+                "{}, {} and {} headers are required",
+                API_KEY_HEADER, API_TIMESTAMP_HEADER, API_SIGNATURE_HEADER
+            ),
+        );
+    };
+
+    // 2. 경로별 필요 권한 (인증/키 관리, 봇 관리는 API 키 사용 불가)
+    let Some(required_scope) = ApiKeyScope::required_for(request.method(), request.uri().path()) else {
+        return reject(StatusCode::FORBIDDEN, "This endpoint cannot be called with an API key");
+    };
+
+    // 3. 본문 읽기 (서명 대상)
+    let (mut parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, MAX_SIGNED_BODY_BYTES).await {
+        Ok(body) => body,
+        Err(_) => return reject(StatusCode::PAYLOAD_TOO_LARGE, "Request body is too large"),
+    };
+
+    // 4. 서명 검증
+    let path_and_query = parts
+        .uri
+        .path_and_query()
+        .map(|value| value.as_str().to_string())
+        .unwrap_or_else(|| parts.uri.path().to_string());
+    let result = app_state
+        .auth_state
+        .api_key_service
+        .authenticate(&SignedRequest {
+            key_id: &key_id,
+            timestamp: &timestamp,
+            signature: &signature,
+            method: parts.method.as_str(),
+            path_and_query: &path_and_query,
+            body: &body,
+            client_ip: client_ip(&parts.headers, &parts.extensions),
+            required_scope,
+        })
+        .await;
+
+    match result {
+        Ok(principal) => {
+            parts.extensions.insert(principal);
+            next.run(Request::from_parts(parts, Body::from(body))).await
+        }
+        Err(e) => {
+            let (status, body): (StatusCode, axum::Json<serde_json::Value>) = e.into();
+            (status, body).into_response()
+        }
+    }
+}
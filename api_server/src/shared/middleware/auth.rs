@@ -1,18 +1,27 @@
 use axum::{
     async_trait,
     extract::FromRequestParts,
-    http::{request::Parts, StatusCode},
+    http::{request::Parts, Method, StatusCode},
 };
+use crate::domains::auth::models::api_key::ApiKeyPrincipal;
+use crate::domains::auth::models::role::Role;
 use crate::shared::services::AppState;
 use crate::shared::errors::AuthError;
 use serde_json::json;
 
 /// 인증된 사용자 정보 (JWT 토큰에서 추출)
 /// Authenticated user information (extracted from JWT token)
+///
+/// Authorization 헤더가 없고 API 키 서명 검증을 통과한 요청이면
+/// (`api_key_auth` 미들웨어) 그 API 키의 소유자로 인증됩니다.
+///
+/// 동결된 계정은 조회(GET/HEAD) 요청만 통과합니다 (그 외 403).
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser {
     pub user_id: u64,
     pub email: String,
+    /// 역할 (JWT Claims, API 키 요청은 항상 user)
+    pub role: Role,
 }
 
 /// AuthenticatedUser를 Axum Extractor로 구현
@@ -36,6 +45,19 @@ impl FromRequestParts<AppState> for AuthenticatedUser {
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
+        // 0. API 키 요청 (api_key_auth 미들웨어에서 서명 검증 완료)
+        if !parts.headers.contains_key("Authorization")
+            && let Some(principal) = parts.extensions.get::<ApiKeyPrincipal>()
+        {
+            let user = AuthenticatedUser {
+                user_id: principal.user_id,
+                email: principal.email.clone(),
+                role: Role::User,
+            };
+            check_not_frozen(state, &parts.method, user.user_id)?;
+            return Ok(user);
+        }
+
         // 1. Authorization 헤더에서 토큰 추출
         let headers = &parts.headers;
         let auth_header = headers
@@ -66,7 +88,50 @@ impl FromRequestParts<AppState> for AuthenticatedUser {
                 )
             })?;
 
-        // 3. JWT Service로 토큰 검증 (AppState에서 가져옴)
+        // 3. JWT 검증 및 AuthenticatedUser 반환
+        let user = AuthenticatedUser::from_token(state, token)?;
+
+        // 4. 동결 계정은 조회만 허용
+        check_not_frozen(state, &parts.method, user.user_id)?;
+        Ok(user)
+    }
+}
+
+/// 동결된 계정의 변경 요청 거부 (조회 요청은 통과)
+/// Reject non-read requests from frozen accounts
+pub fn check_not_frozen(
+    state: &AppState,
+    method: &Method,
+    user_id: u64,
+) -> Result<(), (StatusCode, axum::Json<serde_json::Value>)> {
+    let read_only = *method == Method::GET || *method == Method::HEAD;
+    if !read_only && state.auth_state.frozen_accounts.is_frozen(user_id) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            axum::Json(json!({ "error": "Account is frozen" })),
+        ));
+    }
+    Ok(())
+}
+
+impl AuthenticatedUser {
+    /// JWT Access Token으로 사용자 인증
+    /// Authenticate user from JWT access token
+    /// 
+    /// Authorization 헤더를 쓸 수 없는 경우(브라우저 WebSocket 등)에도
+    /// 같은 검증 로직을 사용하기 위해 분리되어 있습니다.
+    /// 
+    /// # Arguments
+    /// * `state` - AppState (JWT Service 포함)
+    /// * `token` - Access Token ("Bearer " 접두사 없이)
+    /// 
+    /// # Returns
+    /// * `Ok(AuthenticatedUser)` - 검증 성공
+    /// * `Err((StatusCode, Json))` - 토큰이 유효하지 않음
+    pub fn from_token(
+        state: &AppState,
+        token: &str,
+    ) -> Result<Self, (StatusCode, axum::Json<serde_json::Value>)> {
         let claims = state
             .auth_state
             .jwt_service
@@ -84,10 +149,10 @@ impl FromRequestParts<AppState> for AuthenticatedUser {
                 )
             })?;
 
-        // 4. AuthenticatedUser 반환
         Ok(AuthenticatedUser {
             user_id: claims.user_id,
             email: claims.email,
+            role: claims.role,
         })
     }
 }
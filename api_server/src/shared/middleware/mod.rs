@@ -1,4 +1,8 @@
 // Shared middleware
 pub mod auth;
+pub mod api_key;
+pub mod rate_limit;
+pub mod role;
+pub mod sub_account;
 
 pub use auth::*;
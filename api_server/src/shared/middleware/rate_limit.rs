@@ -0,0 +1,150 @@
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use crate::domains::auth::models::api_key::ApiKeyPrincipal;
+use crate::shared::services::AppState;
+use crate::shared::services::rate_limiter::{RateLimitExceeded, RateLimitKey, RateLimitStatus, RouteGroup};
+use crate::shared::utils::client_ip::client_ip;
+use serde_json::json;
+
+// =====================================================
+// 요청 제한 미들웨어
+// =====================================================
+// 역할: 호출자별 요청 가중치 / 사용자별 주문 수 제한
+//
+// 처리 방식:
+// - 호출자: API 키 (api_key_auth 통과) → JWT 사용자 → IP 순으로 결정
+//   → api_key_auth 안쪽에 두어야 ApiKeyPrincipal을 볼 수 있음
+// - 요청 제한 통과 후 주문 생성/취소면 주문 제한도 검사
+// - 응답 헤더:
+//   - RateLimit-Limit / RateLimit-Remaining / RateLimit-Reset: 요청 가중치
+//   - X-Order-RateLimit-Limit / -Remaining / -Reset: 주문 수 (주문 생성/취소만)
+//   - 초과 시 429 + Retry-After (초)
+// =====================================================
+
+fn set_headers(headers: &mut HeaderMap, names: [HeaderName; 3], status: &RateLimitStatus) {
+    let [limit, remaining, reset] = names;
+    headers.insert(limit, HeaderValue::from(status.limit));
+    headers.insert(remaining, HeaderValue::from(status.remaining));
+    headers.insert(reset, HeaderValue::from(status.reset_secs));
+}
+
+/// 요청 가중치 헤더 (limit, remaining, reset)
+fn request_headers() -> [HeaderName; 3] {
+    [
+        HeaderName::from_static("ratelimit-limit"),
+        HeaderName::from_static("ratelimit-remaining"),
+        HeaderName::from_static("ratelimit-reset"),
+    ]
+}
+
+/// 주문 수 헤더 (limit, remaining, reset)
+fn order_headers() -> [HeaderName; 3] {
+    [
+        HeaderName::from_static("x-order-ratelimit-limit"),
+        HeaderName::from_static("x-order-ratelimit-remaining"),
+        HeaderName::from_static("x-order-ratelimit-reset"),
+    ]
+}
+
+/// 429 응답
+fn too_many_requests(
+    message: &str,
+    exceeded: &RateLimitExceeded,
+    names: [HeaderName; 3],
+    request_status: Option<&RateLimitStatus>,
+) -> Response {
+    let mut response = (
+        StatusCode::TOO_MANY_REQUESTS,
+        axum::Json(json!({
+            "error": message,
+            "retry_after": exceeded.retry_after_secs,
+        })),
+    )
+        .into_response();
+    let headers = response.headers_mut();
+    if let Some(request_status) = request_status {
+        set_headers(headers, request_headers(), request_status);
+    }
+    set_headers(headers, names, &exceeded.status);
+    headers.insert(axum::http::header::RETRY_AFTER, HeaderValue::from(exceeded.retry_after_secs));
+    response
+}
+
+/// 요청 제한 미들웨어
+/// Rate limit middleware
+///
+/// 사용법:
+/// ```rust
+/// create_router()
+///     .layer(axum::middleware::from_fn_with_state(app_state.clone(), rate_limit))
+///     .layer(axum::middleware::from_fn_with_state(app_state.clone(), api_key_auth))
+/// ```
+pub async fn rate_limit(
+    State(app_state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let limiter = &app_state.rate_limiter;
+    if !limiter.enabled() {
+        return next.run(request).await;
+    }
+
+    // 1. 호출자 (API 키 → JWT 사용자 → IP)
+    let (key, user_id) = if let Some(principal) = request.extensions().get::<ApiKeyPrincipal>() {
+        (RateLimitKey::ApiKey(principal.key_id.clone()), Some(principal.user_id))
+    } else {
+        let user_id = request
+            .headers()
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|token| app_state.auth_state.jwt_service.verify_access_token(token).ok())
+            .map(|claims| claims.user_id);
+        match user_id {
+            Some(user_id) => (RateLimitKey::User(user_id), Some(user_id)),
+            None => (
+                RateLimitKey::Ip(client_ip(request.headers(), request.extensions())),
+                None,
+            ),
+        }
+    };
+
+    // 2. 요청 가중치
+    let group = RouteGroup::classify(request.method(), request.uri().path());
+    let request_status = match limiter.check_request(&key, group) {
+        Ok(status) => status,
+        Err(exceeded) => {
+            return too_many_requests("Request rate limit exceeded", &exceeded, request_headers(), None);
+        }
+    };
+
+    // 3. 주문 수 (인증된 사용자의 주문 생성/취소만, 인증 실패는 핸들러에서 401)
+    let order_status = match user_id {
+        Some(user_id) if RouteGroup::is_order_entry(request.method(), request.uri().path()) => {
+            match limiter.check_order(user_id) {
+                Ok(status) => Some(status),
+                Err(exceeded) => {
+                    return too_many_requests(
+                        "Order rate limit exceeded",
+                        &exceeded,
+                        order_headers(),
+                        Some(&request_status),
+                    );
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    set_headers(headers, request_headers(), &request_status);
+    if let Some(order_status) = order_status {
+        set_headers(headers, order_headers(), &order_status);
+    }
+    response
+}
@@ -0,0 +1,92 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{request::Parts, StatusCode},
+};
+use crate::domains::auth::models::role::Role;
+use crate::shared::database::UserRepository;
+use crate::shared::middleware::auth::AuthenticatedUser;
+use crate::shared::services::AppState;
+use serde_json::json;
+
+// =====================================================
+// 역할 확인 Extractor
+// =====================================================
+// 역할: 관리자 API (/api/admin) 권한 확인
+//
+// 처리 방식:
+// 1. JWT 인증 (AuthenticatedUser, API 키 요청은 역할이 user라서 항상 403)
+// 2. Claims 역할 확인 (권한이 없으면 DB 조회 없이 403)
+// 3. DB 역할 다시 확인 → 토큰 발급 뒤 역할이 내려간 사용자도 바로 차단
+// =====================================================
+
+type Rejection = (StatusCode, axum::Json<serde_json::Value>);
+
+/// 필요한 역할 확인
+async fn require_role(parts: &mut Parts, state: &AppState, required: Role) -> Result<AuthenticatedUser, Rejection> {
+    let user = AuthenticatedUser::from_request_parts(parts, state).await?;
+
+    let forbidden = || {
+        (
+            StatusCode::FORBIDDEN,
+            axum::Json(json!({ "error": format!("{} role required", required.as_str()) })),
+        )
+    };
+
+    if !user.role.allows(required) {
+        return Err(forbidden());
+    }
+
+    let current = UserRepository::new(state.db.pool().clone())
+        .get_role(user.user_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                axum::Json(json!({ "error": format!("Failed to fetch user role: {}", e) })),
+            )
+        })?;
+
+    match current {
+        Some(role) if role.allows(required) => Ok(AuthenticatedUser { role, ..user }),
+        _ => Err(forbidden()),
+    }
+}
+
+/// 고객 지원 이상 (support, admin)
+/// Support staff or admin
+///
+/// 사용법:
+/// ```rust
+/// pub async fn get_user(
+///     State(app_state): State<AppState>,
+///     StaffUser(staff): StaffUser,
+/// ) -> Result<...> {
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StaffUser(pub AuthenticatedUser);
+
+#[async_trait]
+impl FromRequestParts<AppState> for StaffUser {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        require_role(parts, state, Role::Support).await.map(StaffUser)
+    }
+}
+
+/// 관리자 (admin)
+/// Admin
+#[derive(Debug, Clone)]
+pub struct AdminUser(pub AuthenticatedUser);
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminUser {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        require_role(parts, state, Role::Admin).await.map(AdminUser)
+    }
+}
@@ -0,0 +1,86 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{request::Parts, StatusCode},
+};
+use crate::shared::middleware::auth::{check_not_frozen, AuthenticatedUser};
+use crate::shared::services::AppState;
+use serde_json::json;
+
+/// 서브 계정 지정 헤더
+/// Header selecting the sub-account to act on
+pub const SUB_ACCOUNT_HEADER: &str = "X-Sub-Account-Id";
+
+/// 거래/조회 대상 계정 (본인 또는 본인의 서브 계정)
+/// Account a request acts on (the authenticated user or one of their sub-accounts)
+///
+/// `X-Sub-Account-Id` 헤더가 없으면 로그인한 사용자 본인,
+/// 있으면 그 서브 계정이 로그인한 사용자 소유인지 확인한 뒤 서브 계정으로 동작합니다.
+///
+/// 사용법:
+/// ```rust
+/// pub async fn create_order(
+///     State(app_state): State<AppState>,
+///     TradingAccount { user_id }: TradingAccount,  // 서브 계정이면 서브 계정 ID
+/// ) -> Result<...> {
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TradingAccount {
+    /// 대상 계정 ID (서브 계정이면 서브 계정 ID)
+    pub user_id: u64,
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for TradingAccount {
+    type Rejection = (StatusCode, axum::Json<serde_json::Value>);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        // 1. JWT 인증
+        let authenticated_user = AuthenticatedUser::from_request_parts(parts, state).await?;
+
+        // 2. 서브 계정 헤더 (없으면 본인)
+        let Some(header) = parts.headers.get(SUB_ACCOUNT_HEADER) else {
+            return Ok(TradingAccount { user_id: authenticated_user.user_id });
+        };
+
+        let sub_account_id = header
+            .to_str()
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .ok_or_else(|| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    axum::Json(json!({ "error": format!("Invalid {} header", SUB_ACCOUNT_HEADER) })),
+                )
+            })?;
+
+        // 3. 소유 확인 (남의 서브 계정은 존재 여부도 노출하지 않음)
+        let sub_account = state
+            .cex_state
+            .sub_account_service
+            .get_owned(authenticated_user.user_id, sub_account_id)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    axum::Json(json!({ "error": format!("Failed to fetch sub-account: {}", e) })),
+                )
+            })?
+            .ok_or_else(|| {
+                (
+                    StatusCode::FORBIDDEN,
+                    axum::Json(json!({ "error": "Sub-account not found" })),
+                )
+            })?;
+
+        // 4. 서브 계정만 동결된 경우
+        check_not_frozen(state, &parts.method, sub_account.user_id)?;
+
+        Ok(TradingAccount { user_id: sub_account.user_id })
+    }
+}
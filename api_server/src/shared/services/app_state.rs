@@ -4,9 +4,11 @@ use crate::domains::auth::services::state::AuthState;
 use crate::domains::wallet::services::state::WalletState;
 use crate::domains::swap::services::state::SwapState;
 use crate::domains::cex::services::state::CexState;
+use crate::domains::admin::services::state::AdminState;
 use crate::domains::cex::engine::runtime::HighPerformanceEngine;
 use crate::domains::auth::services::JwtService;
 use crate::domains::bot::services::cleanup_scheduler::BotCleanupScheduler;
+use crate::shared::services::rate_limiter::{RateLimitConfig, RateLimiter};
 use anyhow::Result;
 use tokio::sync::Mutex;
 
@@ -24,12 +26,18 @@ pub struct AppState {
     pub wallet_state: WalletState,
     pub swap_state: SwapState,
     pub cex_state: CexState,
+    /// 관리자 API (감사 로그 등)
+    /// Admin API state
+    pub admin_state: AdminState,
     /// 엔진 인스턴스 (시작/정지용)
     /// Engine instance (for start/stop)
     pub engine: Arc<Mutex<HighPerformanceEngine>>,
     /// 봇 데이터 정리 스케줄러
     /// Bot data cleanup scheduler
     pub bot_cleanup_scheduler: BotCleanupScheduler,
+    /// 요청 수 / 주문 수 제한
+    /// Request and order rate limiter
+    pub rate_limiter: RateLimiter,
 }
 
 impl AppState {
@@ -42,7 +50,7 @@ impl AppState {
         let jwt_service = JwtService::new(jwt_secret);
 
         // 2. 각 도메인 State 생성
-        let auth_state = AuthState::new(db.clone(), jwt_service);
+        let auth_state = AuthState::new(db.clone(), jwt_service)?;
         let wallet_state = WalletState::new(db.clone())?;
         let swap_state = SwapState::new(db.clone());
         
@@ -52,7 +60,8 @@ impl AppState {
         let engine = Arc::new(Mutex::new(engine_instance));
         
         // 서비스에도 같은 엔진 인스턴스 전달 (Wrapper 불필요)
-        let cex_state = CexState::new(db.clone(), engine.clone());
+        let cex_state = CexState::new(db.clone(), engine.clone())?;
+        let admin_state = AdminState::new(db.clone(), engine.clone());
         
         // 봇 데이터 정리 스케줄러 생성 (봇 user_id는 나중에 설정)
         let bot_cleanup_scheduler = BotCleanupScheduler::new(
@@ -61,6 +70,9 @@ impl AppState {
             None, // bot2_user_id는 나중에 설정
         );
         
+        // 요청 제한 (RATE_LIMIT_* 환경 변수)
+        let rate_limiter = RateLimiter::new(RateLimitConfig::from_env());
+        
         // 3. AppState 조합
         Ok(Self {
             db: db.clone(),
@@ -68,8 +80,10 @@ impl AppState {
             wallet_state,
             swap_state,
             cex_state,
+            admin_state,
             engine,
             bot_cleanup_scheduler,
+            rate_limiter,
         })
     }
     
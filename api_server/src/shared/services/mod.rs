@@ -1,5 +1,6 @@
 // Shared services (AppState)
 pub mod app_state;
+pub mod rate_limiter;
 
 pub use app_state::*;
 
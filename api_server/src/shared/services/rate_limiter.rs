@@ -0,0 +1,519 @@
+use axum::http::Method;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+// =====================================================
+// RateLimiter
+// =====================================================
+// 역할: 요청 수 / 주문 수 제한 (token bucket)
+//
+// 처리 방식:
+// - 요청 제한: 호출자(API 키 / 사용자 / IP)별 bucket, 경로 그룹별 가중치만큼 차감
+//   → 1분에 limit만큼 채워짐 (최대 limit, 한 번에 몰아서 쓰기 가능)
+// - 주문 제한: 사용자별 bucket, 주문 생성/취소마다 1 차감 (요청 제한과 별도)
+//   → 가중치가 남아도 엔진 채널로 들어가는 주문 수는 따로 제한
+// - bucket은 메모리에만 보관 (재시작 시 초기화, 가득 찬 bucket은 주기적으로 정리)
+//
+// 환경 변수:
+// - RATE_LIMIT_ENABLED: false면 제한 안 함 (기본 true)
+// - RATE_LIMIT_USER_WEIGHT_PER_MINUTE: 인증된 호출자의 분당 가중치 (기본 1200)
+// - RATE_LIMIT_IP_WEIGHT_PER_MINUTE: 인증 없는 호출자(IP)의 분당 가중치 (기본 600)
+// - RATE_LIMIT_ORDERS_PER_10S: 사용자별 10초당 주문 생성/취소 수 (기본 50)
+// - RATE_LIMIT_WEIGHTS: 경로 그룹별 가중치 (예: "market_data=1,account=2,order_entry=4,heavy=10")
+// =====================================================
+
+/// 활성화 환경 변수
+const ENABLED_ENV: &str = "RATE_LIMIT_ENABLED";
+
+/// 인증된 호출자 분당 가중치 환경 변수
+const USER_WEIGHT_ENV: &str = "RATE_LIMIT_USER_WEIGHT_PER_MINUTE";
+
+/// IP 분당 가중치 환경 변수
+const IP_WEIGHT_ENV: &str = "RATE_LIMIT_IP_WEIGHT_PER_MINUTE";
+
+/// 10초당 주문 수 환경 변수
+const ORDERS_ENV: &str = "RATE_LIMIT_ORDERS_PER_10S";
+
+/// 경로 그룹별 가중치 환경 변수
+const WEIGHTS_ENV: &str = "RATE_LIMIT_WEIGHTS";
+
+/// 요청 제한 구간 (초)
+const REQUEST_WINDOW_SECS: f64 = 60.0;
+
+/// 주문 제한 구간 (초)
+const ORDER_WINDOW_SECS: f64 = 10.0;
+
+/// bucket 수가 이만큼 되면 가득 찬(쉬고 있는) bucket 정리
+const PRUNE_AT: usize = 10_000;
+
+/// 경로 그룹 (가중치 단위)
+/// Route group used for request weights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteGroup {
+    /// 공개 시세 (오더북, 체결, ticker, 캔들, 토큰 검색)
+    MarketData,
+    /// 계정 조회/변경 (잔고, 주문 조회, 포지션 등 기본값)
+    Account,
+    /// 주문 생성/취소
+    OrderEntry,
+    /// 외부 호출 / 큰 조회 (스왑, 온체인 지갑, 내역 내보내기, 정합성 검사)
+    Heavy,
+}
+
+impl RouteGroup {
+    /// 요청의 경로 그룹
+    /// Route group of a request
+    pub fn classify(method: &Method, path: &str) -> RouteGroup {
+        const MARKET_DATA_PATHS: [&str; 8] = [
+            "/api/cex/orderbook",
+            "/api/cex/orderbook/l3",
+            "/api/cex/trades",
+            "/api/cex/price",
+            "/api/cex/volume",
+            "/api/cex/ticker",
+            "/api/cex/candles",
+            "/api/cex/ws/market",
+        ];
+        const HEAVY_PREFIXES: [&str; 4] = [
+            "/api/swap/",
+            "/api/wallets",
+            "/api/cex/exports",
+            "/api/admin/reconciliation",
+        ];
+
+        if Self::is_order_entry(method, path) {
+            return RouteGroup::OrderEntry;
+        }
+        if MARKET_DATA_PATHS.contains(&path) || path.starts_with("/api/tokens") {
+            return RouteGroup::MarketData;
+        }
+        if HEAVY_PREFIXES.iter().any(|prefix| path.starts_with(prefix)) {
+            return RouteGroup::Heavy;
+        }
+        RouteGroup::Account
+    }
+
+    /// 주문 생성/취소 요청인지 (주문 제한 대상)
+    pub fn is_order_entry(method: &Method, path: &str) -> bool {
+        (method == Method::POST && path == "/api/cex/orders")
+            || (method == Method::DELETE && path.starts_with("/api/cex/orders/"))
+    }
+}
+
+/// 경로 그룹별 가중치
+/// Request weight per route group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteWeights {
+    pub market_data: u32,
+    pub account: u32,
+    pub order_entry: u32,
+    pub heavy: u32,
+}
+
+impl Default for RouteWeights {
+    fn default() -> Self {
+        Self {
+            market_data: 1,
+            account: 2,
+            order_entry: 4,
+            heavy: 10,
+        }
+    }
+}
+
+impl RouteWeights {
+    /// 그룹 가중치
+    pub fn weight(&self, group: RouteGroup) -> u32 {
+        match group {
+            RouteGroup::MarketData => self.market_data,
+            RouteGroup::Account => self.account,
+            RouteGroup::OrderEntry => self.order_entry,
+            RouteGroup::Heavy => self.heavy,
+        }
+    }
+
+    /// "group=weight,..." 파싱 (지정하지 않은 그룹은 기본값)
+    ///
+    /// # Returns
+    /// * `Ok(RouteWeights)` - 파싱 성공
+    /// * `Err(String)` - 알 수 없는 그룹, 잘못된 값 (0 이하 포함)
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut weights = Self::default();
+        for entry in value.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (group, weight) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("Expected group=weight, got '{}'", entry))?;
+            let weight = weight
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|weight| *weight > 0)
+                .ok_or_else(|| format!("Invalid weight for '{}': {}", group.trim(), weight.trim()))?;
+            match group.trim() {
+                "market_data" => weights.market_data = weight,
+                "account" => weights.account = weight,
+                "order_entry" => weights.order_entry = weight,
+                "heavy" => weights.heavy = weight,
+                other => return Err(format!("Unknown route group '{}'", other)),
+            }
+        }
+        Ok(weights)
+    }
+}
+
+/// 요청 제한 설정
+/// Rate limit configuration
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// 제한 사용 여부
+    pub enabled: bool,
+    /// 인증된 호출자(API 키 / 사용자) 분당 가중치
+    pub user_weight_per_minute: u32,
+    /// 인증 없는 호출자(IP) 분당 가중치
+    pub ip_weight_per_minute: u32,
+    /// 사용자별 10초당 주문 생성/취소 수
+    pub orders_per_10s: u32,
+    /// 경로 그룹별 가중치
+    pub weights: RouteWeights,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            user_weight_per_minute: 1200,
+            ip_weight_per_minute: 600,
+            orders_per_10s: 50,
+            weights: RouteWeights::default(),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// 환경 변수에서 설정 로드 (없거나 잘못된 값은 기본값)
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let positive = |name: &str, default: u32| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u32>().ok())
+                .filter(|value| *value > 0)
+                .unwrap_or(default)
+        };
+
+        let weights = match std::env::var(WEIGHTS_ENV) {
+            Ok(value) => RouteWeights::parse(&value).unwrap_or_else(|e| {
+                eprintln!("[RateLimit] Invalid {}: {}, using defaults", WEIGHTS_ENV, e);
+                RouteWeights::default()
+            }),
+            Err(_) => defaults.weights,
+        };
+
+        Self {
+            enabled: !std::env::var(ENABLED_ENV).is_ok_and(|value| value.trim().eq_ignore_ascii_case("false")),
+            user_weight_per_minute: positive(USER_WEIGHT_ENV, defaults.user_weight_per_minute),
+            ip_weight_per_minute: positive(IP_WEIGHT_ENV, defaults.ip_weight_per_minute),
+            orders_per_10s: positive(ORDERS_ENV, defaults.orders_per_10s),
+            weights,
+        }
+    }
+}
+
+/// 요청 제한 대상 (bucket 키)
+/// Caller a request is limited as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateLimitKey {
+    /// API 키 (키마다 별도 bucket)
+    ApiKey(String),
+    /// JWT 사용자
+    User(u64),
+    /// 인증 없는 요청 (연결 정보가 없으면 None)
+    Ip(Option<IpAddr>),
+}
+
+impl fmt::Display for RateLimitKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitKey::ApiKey(key_id) => write!(f, "key:{}", key_id),
+            RateLimitKey::User(user_id) => write!(f, "user:{}", user_id),
+            RateLimitKey::Ip(Some(ip)) => write!(f, "ip:{}", ip),
+            RateLimitKey::Ip(None) => write!(f, "ip:unknown"),
+        }
+    }
+}
+
+/// 제한 상태 (응답 헤더)
+/// Rate limit status after a check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// 구간당 한도
+    pub limit: u32,
+    /// 남은 양
+    pub remaining: u32,
+    /// 가득 찰 때까지 남은 시간 (초)
+    pub reset_secs: u64,
+}
+
+/// 제한 초과
+/// Rate limit exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitExceeded {
+    pub status: RateLimitStatus,
+    /// 이 요청이 통과할 수 있을 때까지 기다릴 시간 (초, Retry-After)
+    pub retry_after_secs: u64,
+}
+
+/// token bucket
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32, window_secs: f64, now: Instant) -> Self {
+        Self {
+            capacity: capacity as f64,
+            refill_per_sec: capacity as f64 / window_secs,
+            tokens: capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    fn status(&self) -> RateLimitStatus {
+        RateLimitStatus {
+            limit: self.capacity as u32,
+            remaining: self.tokens.floor() as u32,
+            reset_secs: ((self.capacity - self.tokens) / self.refill_per_sec).ceil() as u64,
+        }
+    }
+
+    /// cost만큼 차감 (모자라면 차감하지 않음)
+    fn try_acquire(&mut self, cost: u32, now: Instant) -> Result<RateLimitStatus, RateLimitExceeded> {
+        self.refill(now);
+        // 한도보다 큰 가중치는 한도로 (영원히 통과하지 못하는 요청 방지)
+        let cost = (cost as f64).min(self.capacity);
+        if self.tokens >= cost {
+            self.tokens -= cost;
+            Ok(self.status())
+        } else {
+            Err(RateLimitExceeded {
+                status: self.status(),
+                retry_after_secs: (((cost - self.tokens) / self.refill_per_sec).ceil() as u64).max(1),
+            })
+        }
+    }
+}
+
+/// 키별 bucket 모음
+#[derive(Clone, Default)]
+struct Buckets {
+    inner: Arc<Mutex<HashMap<String, TokenBucket>>>,
+}
+
+impl Buckets {
+    fn acquire(
+        &self,
+        key: String,
+        capacity: u32,
+        window_secs: f64,
+        cost: u32,
+        now: Instant,
+    ) -> Result<RateLimitStatus, RateLimitExceeded> {
+        let mut buckets = self.inner.lock();
+        if buckets.len() >= PRUNE_AT {
+            buckets.retain(|_, bucket| {
+                bucket.refill(now);
+                bucket.tokens < bucket.capacity
+            });
+        }
+        buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(capacity, window_secs, now))
+            .try_acquire(cost, now)
+    }
+}
+
+/// 요청 수 / 주문 수 제한
+/// Request and order rate limiter
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: Arc<RateLimitConfig>,
+    request_buckets: Buckets,
+    order_buckets: Buckets,
+}
+
+impl RateLimiter {
+    /// 새 RateLimiter 생성
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            request_buckets: Buckets::default(),
+            order_buckets: Buckets::default(),
+        }
+    }
+
+    /// 제한 사용 여부
+    pub fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// 요청 가중치 차감
+    /// Charge a request's weight to its caller
+    ///
+    /// # Returns
+    /// * `Ok(RateLimitStatus)` - 통과 (남은 가중치)
+    /// * `Err(RateLimitExceeded)` - 초과 (차감하지 않음)
+    pub fn check_request(&self, key: &RateLimitKey, group: RouteGroup) -> Result<RateLimitStatus, RateLimitExceeded> {
+        self.check_request_at(key, group, Instant::now())
+    }
+
+    fn check_request_at(
+        &self,
+        key: &RateLimitKey,
+        group: RouteGroup,
+        now: Instant,
+    ) -> Result<RateLimitStatus, RateLimitExceeded> {
+        let capacity = match key {
+            RateLimitKey::Ip(_) => self.config.ip_weight_per_minute,
+            RateLimitKey::ApiKey(_) | RateLimitKey::User(_) => self.config.user_weight_per_minute,
+        };
+        self.request_buckets.acquire(
+            key.to_string(),
+            capacity,
+            REQUEST_WINDOW_SECS,
+            self.config.weights.weight(group),
+            now,
+        )
+    }
+
+    /// 주문 생성/취소 1건 차감 (사용자별, 모든 API 키 합산)
+    /// Charge one order action to a user
+    ///
+    /// # Returns
+    /// * `Ok(RateLimitStatus)` - 통과 (남은 주문 수)
+    /// * `Err(RateLimitExceeded)` - 초과 (차감하지 않음)
+    pub fn check_order(&self, user_id: u64) -> Result<RateLimitStatus, RateLimitExceeded> {
+        self.check_order_at(user_id, Instant::now())
+    }
+
+    fn check_order_at(&self, user_id: u64, now: Instant) -> Result<RateLimitStatus, RateLimitExceeded> {
+        self.order_buckets.acquire(
+            user_id.to_string(),
+            self.config.orders_per_10s,
+            ORDER_WINDOW_SECS,
+            1,
+            now,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn limiter() -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            enabled: true,
+            user_weight_per_minute: 60,
+            ip_weight_per_minute: 10,
+            orders_per_10s: 3,
+            weights: RouteWeights::default(),
+        })
+    }
+
+    /// 테스트: 가중치 차감, 초과 시 Retry-After, 시간이 지나면 다시 채워짐
+    #[test]
+    fn test_request_bucket() {
+        let limiter = limiter();
+        let now = Instant::now();
+        let ip = RateLimitKey::Ip(Some("203.0.113.10".parse().unwrap()));
+
+        // IP 한도 10, 계정 조회 가중치 2 → 5번 통과
+        for expected_remaining in [8, 6, 4, 2, 0] {
+            let status = limiter.check_request_at(&ip, RouteGroup::Account, now).unwrap();
+            assert_eq!(status.limit, 10);
+            assert_eq!(status.remaining, expected_remaining);
+        }
+
+        // 초과: 차감하지 않고 2 채워질 때까지 (10/60 per sec → 12초) 대기
+        let exceeded = limiter.check_request_at(&ip, RouteGroup::Account, now).unwrap_err();
+        assert_eq!(exceeded.retry_after_secs, 12);
+        assert_eq!(exceeded.status.remaining, 0);
+        assert_eq!(exceeded.status.reset_secs, 60);
+
+        // 12초 뒤 다시 통과
+        let later = now + Duration::from_secs(12);
+        assert!(limiter.check_request_at(&ip, RouteGroup::Account, later).is_ok());
+
+        // 다른 호출자는 별도 bucket, 인증된 호출자는 큰 한도
+        let user = RateLimitKey::User(7);
+        let status = limiter.check_request_at(&user, RouteGroup::Heavy, now).unwrap();
+        assert_eq!((status.limit, status.remaining), (60, 50));
+        let key = RateLimitKey::ApiKey("ak_test".to_string());
+        assert_eq!(limiter.check_request_at(&key, RouteGroup::MarketData, now).unwrap().remaining, 59);
+    }
+
+    /// 테스트: 주문 제한은 요청 제한과 별도
+    #[test]
+    fn test_order_bucket() {
+        let limiter = limiter();
+        let now = Instant::now();
+
+        for expected_remaining in [2, 1, 0] {
+            assert_eq!(limiter.check_order_at(7, now).unwrap().remaining, expected_remaining);
+        }
+        let exceeded = limiter.check_order_at(7, now).unwrap_err();
+        assert_eq!(exceeded.retry_after_secs, 4); // 3/10 per sec → 1건에 3.33초
+        assert!(limiter.check_order_at(8, now).is_ok());
+
+        // 요청 bucket은 그대로
+        let status = limiter.check_request_at(&RateLimitKey::User(7), RouteGroup::OrderEntry, now).unwrap();
+        assert_eq!(status.remaining, 56);
+    }
+
+    /// 테스트: 경로 그룹 분류
+    #[test]
+    fn test_classify() {
+        assert_eq!(RouteGroup::classify(&Method::POST, "/api/cex/orders"), RouteGroup::OrderEntry);
+        assert_eq!(RouteGroup::classify(&Method::DELETE, "/api/cex/orders/42"), RouteGroup::OrderEntry);
+        assert_eq!(RouteGroup::classify(&Method::GET, "/api/cex/orders/42"), RouteGroup::Account);
+        assert_eq!(RouteGroup::classify(&Method::GET, "/api/cex/orders/my"), RouteGroup::Account);
+        assert_eq!(RouteGroup::classify(&Method::GET, "/api/cex/trades"), RouteGroup::MarketData);
+        assert_eq!(RouteGroup::classify(&Method::GET, "/api/cex/trades/my"), RouteGroup::Account);
+        assert_eq!(RouteGroup::classify(&Method::GET, "/api/cex/orderbook/l3"), RouteGroup::MarketData);
+        assert_eq!(RouteGroup::classify(&Method::GET, "/api/tokens/search"), RouteGroup::MarketData);
+        assert_eq!(RouteGroup::classify(&Method::POST, "/api/cex/exports"), RouteGroup::Heavy);
+        assert_eq!(RouteGroup::classify(&Method::GET, "/api/swap/quote"), RouteGroup::Heavy);
+
+        assert!(RouteGroup::is_order_entry(&Method::POST, "/api/cex/orders"));
+        assert!(!RouteGroup::is_order_entry(&Method::GET, "/api/cex/orders/my"));
+    }
+
+    /// 테스트: 가중치 설정 파싱
+    #[test]
+    fn test_parse_weights() {
+        let weights = RouteWeights::parse("order_entry=8, heavy=20").unwrap();
+        assert_eq!(weights.order_entry, 8);
+        assert_eq!(weights.heavy, 20);
+        assert_eq!(weights.market_data, 1);
+
+        assert!(RouteWeights::parse("orders=2").is_err());
+        assert!(RouteWeights::parse("account=0").is_err());
+        assert!(RouteWeights::parse("account").is_err());
+        assert_eq!(RouteWeights::parse("").unwrap(), RouteWeights::default());
+    }
+}
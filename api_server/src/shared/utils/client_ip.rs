@@ -0,0 +1,108 @@
+//! 클라이언트 IP
+//! Client IP address
+//!
+//! 역할:
+//! - 요청을 보낸 클라이언트 IP 추출 (API 키 IP 허용 목록, IP별 요청 제한)
+//!
+//! 처리 방식:
+//! - 기본: TCP 연결 주소 (`ConnectInfo<SocketAddr>`)
+//! - `TRUST_PROXY_HEADERS=true`: 리버스 프록시 뒤에서 X-Forwarded-For 주소
+//!   → 프록시 없이 켜면 클라이언트가 IP를 위조할 수 있으므로 기본은 꺼짐
+//! - 프록시는 받은 연결 주소를 X-Forwarded-For 오른쪽에 덧붙이므로 왼쪽 항목은 클라이언트가 위조할 수 있음
+//!   → 오른쪽부터 신뢰하는 프록시 수(`TRUSTED_PROXY_HOPS`, 기본 1)만큼 건너온 주소를 사용
+
+use axum::extract::ConnectInfo;
+use axum::http::{Extensions, HeaderMap};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::OnceLock;
+
+/// 프록시 헤더 신뢰 여부 환경 변수
+const TRUST_PROXY_HEADERS_ENV: &str = "TRUST_PROXY_HEADERS";
+
+/// 서버 앞의 신뢰하는 프록시 수 환경 변수
+const TRUSTED_PROXY_HOPS_ENV: &str = "TRUSTED_PROXY_HOPS";
+
+/// 기본 신뢰 프록시 수 (리버스 프록시 1개)
+const DEFAULT_TRUSTED_PROXY_HOPS: usize = 1;
+
+fn trust_proxy_headers() -> bool {
+    static TRUST: OnceLock<bool> = OnceLock::new();
+    *TRUST.get_or_init(|| {
+        std::env::var(TRUST_PROXY_HEADERS_ENV)
+            .map(|value| value.eq_ignore_ascii_case("true") || value == "1")
+            .unwrap_or(false)
+    })
+}
+
+fn trusted_proxy_hops() -> usize {
+    static HOPS: OnceLock<usize> = OnceLock::new();
+    *HOPS.get_or_init(|| {
+        std::env::var(TRUSTED_PROXY_HOPS_ENV)
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .filter(|hops| *hops > 0)
+            .unwrap_or(DEFAULT_TRUSTED_PROXY_HOPS)
+    })
+}
+
+/// X-Forwarded-For에서 클라이언트 IP 선택
+///
+/// 오른쪽 끝 항목은 서버에 가장 가까운 프록시가 덧붙인 주소이므로, 오른쪽부터 `trusted_hops`번째 항목을 사용
+/// 항목이 `trusted_hops`보다 적으면 모두 신뢰하는 프록시가 덧붙인 것이므로 가장 왼쪽 항목
+///
+/// # Returns
+/// 선택한 항목이 IP가 아니면 None
+fn forwarded_client_ip(forwarded_for: &str, trusted_hops: usize) -> Option<IpAddr> {
+    let entries: Vec<&str> = forwarded_for.split(',').map(str::trim).collect();
+    let index = entries.len().saturating_sub(trusted_hops);
+    entries.get(index)?.parse::<IpAddr>().ok()
+}
+
+/// 요청의 클라이언트 IP
+///
+/// # Returns
+/// * `Some(ip)` - 클라이언트 IP
+/// * `None` - 연결 정보가 없음 (테스트 등 `into_make_service_with_connect_info` 없이 실행)
+pub fn client_ip(headers: &HeaderMap, extensions: &Extensions) -> Option<IpAddr> {
+    if trust_proxy_headers() {
+        let forwarded = headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| forwarded_client_ip(value, trusted_proxy_hops()));
+        if forwarded.is_some() {
+            return forwarded;
+        }
+    }
+
+    extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 테스트: 클라이언트가 보낸 왼쪽 항목은 무시하고 신뢰하는 프록시가 덧붙인 항목 사용
+    #[test]
+    fn test_forwarded_client_ip_uses_rightmost_trusted_entry() {
+        // 클라이언트가 "1.1.1.1"을 위조, 프록시가 실제 연결 주소 203.0.113.7을 덧붙임
+        let forwarded_for = "1.1.1.1, 203.0.113.7";
+        assert_eq!(forwarded_client_ip(forwarded_for, 1), Some("203.0.113.7".parse().unwrap()));
+
+        // CDN → 리버스 프록시 (2단계): 오른쪽 항목은 CDN 주소
+        let forwarded_for = "1.1.1.1, 198.51.100.4, 10.0.0.2";
+        assert_eq!(forwarded_client_ip(forwarded_for, 2), Some("198.51.100.4".parse().unwrap()));
+
+        // 항목이 신뢰 프록시 수보다 적으면 가장 왼쪽
+        assert_eq!(forwarded_client_ip("198.51.100.4", 2), Some("198.51.100.4".parse().unwrap()));
+        assert_eq!(forwarded_client_ip("2001:db8::1", 1), Some("2001:db8::1".parse().unwrap()));
+    }
+
+    /// 테스트: 선택한 항목이 IP가 아니면 None (연결 주소 사용)
+    #[test]
+    fn test_forwarded_client_ip_rejects_invalid_entry() {
+        assert_eq!(forwarded_client_ip("203.0.113.7, unknown", 1), None);
+        assert_eq!(forwarded_client_ip("", 1), None);
+    }
+}
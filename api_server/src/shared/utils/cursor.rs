@@ -0,0 +1,136 @@
+//! 페이지 커서 (keyset 페이지네이션)
+//! Page cursor for keyset pagination
+//!
+//! 역할:
+//! - 목록 API의 "다음 페이지" 위치를 (created_at, id)로 표현
+//! - 클라이언트에는 불투명 문자열(base64url)로 전달
+//!
+//! 사용 방법:
+//! ```rust
+//! // 응답: 마지막 항목 기준으로 커서 생성
+//! let next = PageCursor::new(order.created_at, order.id).encode();
+//!
+//! // 요청: 커서 이후(더 오래된) 항목만 조회
+//! let cursor = PageCursor::decode(&query.cursor)?;
+//! // WHERE (created_at, id) < (cursor.created_at, cursor.id)
+//! ```
+//!
+//! 특징:
+//! - OFFSET 스캔 없이 인덱스 (…, created_at DESC, id DESC)로 바로 이어서 조회
+//! - 페이지 사이에 새 항목이 추가되어도 누락/중복 없음
+
+use anyhow::{anyhow, Context, Result};
+use base64::{Engine as _, engine::general_purpose};
+use chrono::{DateTime, TimeZone, Utc};
+
+/// 페이지 커서
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageCursor {
+    /// 마지막 항목의 생성 시간
+    pub created_at: DateTime<Utc>,
+    /// 마지막 항목의 ID (같은 시간 항목 구분)
+    pub id: u64,
+}
+
+impl PageCursor {
+    pub fn new(created_at: DateTime<Utc>, id: u64) -> Self {
+        Self { created_at, id }
+    }
+
+    /// 불투명 문자열로 인코딩 ("{마이크로초}:{id}"의 base64url)
+    pub fn encode(&self) -> String {
+        general_purpose::URL_SAFE_NO_PAD.encode(format!("{}:{}", self.created_at.timestamp_micros(), self.id))
+    }
+
+    /// 인코딩된 커서 해석
+    ///
+    /// # Returns
+    /// * `Err` - 이 서버가 발급하지 않은 형식의 커서
+    pub fn decode(cursor: &str) -> Result<Self> {
+        let bytes = general_purpose::URL_SAFE_NO_PAD
+            .decode(cursor)
+            .context("Invalid cursor encoding")?;
+        let text = String::from_utf8(bytes).context("Invalid cursor encoding")?;
+        let (micros, id) = text.split_once(':').ok_or_else(|| anyhow!("Invalid cursor format"))?;
+
+        let micros: i64 = micros.parse().context("Invalid cursor timestamp")?;
+        let created_at = Utc
+            .timestamp_micros(micros)
+            .single()
+            .ok_or_else(|| anyhow!("Invalid cursor timestamp"))?;
+        let id = id.parse().context("Invalid cursor id")?;
+
+        Ok(Self { created_at, id })
+    }
+}
+
+/// 커서 페이지 조회 결과
+#[derive(Debug)]
+pub struct Page<T> {
+    /// 항목 (최신순)
+    pub items: Vec<T>,
+    /// 다음 페이지 커서 (마지막 페이지면 None)
+    pub next_cursor: Option<PageCursor>,
+}
+
+impl<T> Page<T> {
+    /// `limit + 1`개 조회 결과로 페이지 생성
+    ///
+    /// # Arguments
+    /// * `items` - 최대 `limit + 1`개 (초과분이 있으면 다음 페이지 존재)
+    /// * `limit` - 페이지 크기
+    /// * `cursor_of` - 항목의 (created_at, id)
+    pub fn from_rows(mut items: Vec<T>, limit: i64, cursor_of: impl Fn(&T) -> PageCursor) -> Self {
+        let has_more = items.len() as i64 > limit;
+        items.truncate(limit.max(0) as usize);
+        let next_cursor = if has_more { items.last().map(cursor_of) } else { None };
+        Self { items, next_cursor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let created_at = Utc.timestamp_micros(1_700_000_000_123_456).unwrap();
+        let cursor = PageCursor::new(created_at, 987_654_321);
+
+        let encoded = cursor.encode();
+        assert!(!encoded.contains(':'));
+        assert_eq!(PageCursor::decode(&encoded).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_cursor() {
+        let encode = |text: &str| general_purpose::URL_SAFE_NO_PAD.encode(text);
+
+        assert!(PageCursor::decode("").is_err());
+        assert!(PageCursor::decode("not base64!").is_err());
+        assert!(PageCursor::decode(&general_purpose::URL_SAFE_NO_PAD.encode([0xff, 0xfe])).is_err());
+        assert!(PageCursor::decode(&encode("1700000000000000")).is_err());
+        assert!(PageCursor::decode(&encode("abc:1")).is_err());
+        assert!(PageCursor::decode(&encode("1700000000000000:-1")).is_err());
+        assert!(PageCursor::decode(&encode(&format!("{}:1", i64::MAX))).is_err());
+    }
+
+    #[test]
+    fn test_page_from_rows() {
+        let cursor_of = |id: &u64| PageCursor::new(Utc.timestamp_micros(*id as i64).unwrap(), *id);
+
+        // limit + 1개 → 다음 페이지 있음, 커서는 잘린 뒤 마지막 항목
+        let page = Page::from_rows(vec![5, 4, 3, 2], 3, cursor_of);
+        assert_eq!(page.items, vec![5, 4, 3]);
+        assert_eq!(page.next_cursor.map(|cursor| cursor.id), Some(3));
+
+        // limit개 이하 → 마지막 페이지
+        let page = Page::from_rows(vec![5, 4, 3], 3, cursor_of);
+        assert_eq!(page.items, vec![5, 4, 3]);
+        assert_eq!(page.next_cursor, None);
+
+        let page = Page::from_rows(Vec::new(), 3, cursor_of);
+        assert!(page.items.is_empty());
+        assert_eq!(page.next_cursor, None);
+    }
+}
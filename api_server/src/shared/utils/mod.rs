@@ -3,6 +3,9 @@
 ///
 /// 역할:
 /// - ID 생성기 (Order, Trade 등)
+/// - 페이지 커서 (keyset 페이지네이션)
+/// - 클라이언트 IP (연결 주소 / 신뢰하는 프록시 헤더)
 /// - 기타 공통 유틸리티 함수
 pub mod id_generator;
-
+pub mod cursor;
+pub mod client_ip;
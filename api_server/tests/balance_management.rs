@@ -6,6 +6,7 @@ mod common;
 use common::*;
 use rust_decimal::Decimal;
 use api_server::domains::cex::engine::Engine;
+use api_server::domains::cex::models::ledger::LedgerReason;
 
 /// 테스트: 엔진 시작 및 중지
 /// 
@@ -102,7 +103,7 @@ async fn test_update_balance_deposit() {
     
     // 100 USDT 입금
     let deposit_amount = Decimal::new(100, 0);
-    engine.update_balance(TEST_USER_ID, "USDT", deposit_amount).await
+    engine.update_balance(TEST_USER_ID, "USDT", deposit_amount, LedgerReason::Adjustment, "test").await
         .expect("Failed to update balance (deposit)");
     
     // 업데이트 후 잔고 확인
@@ -136,7 +137,7 @@ async fn test_update_balance_withdrawal() {
     if initial_available < withdrawal_amount {
         // 잔고가 부족하면 입금 먼저
         let deposit_amount = withdrawal_amount - initial_available + Decimal::new(10, 0);
-        engine.update_balance(TEST_USER_ID, "USDT", deposit_amount).await
+        engine.update_balance(TEST_USER_ID, "USDT", deposit_amount, LedgerReason::Adjustment, "test").await
             .expect("Failed to deposit for withdrawal test");
     }
     
@@ -146,7 +147,7 @@ async fn test_update_balance_withdrawal() {
     
     // 50 USDT 출금 (음수 delta)
     let withdrawal_delta = Decimal::new(-50, 0);
-    engine.update_balance(TEST_USER_ID, "USDT", withdrawal_delta).await
+    engine.update_balance(TEST_USER_ID, "USDT", withdrawal_delta, LedgerReason::Adjustment, "test").await
         .expect("Failed to update balance (withdrawal)");
     
     // 업데이트 후 잔고 확인
@@ -178,7 +179,7 @@ async fn test_balance_update_priority() {
     
     // 입금 (1000 USDT)
     let deposit_amount = Decimal::new(1000, 0);
-    engine.update_balance(TEST_USER_ID, "USDT", deposit_amount).await
+    engine.update_balance(TEST_USER_ID, "USDT", deposit_amount, LedgerReason::Adjustment, "test").await
         .expect("Failed to update balance (deposit)");
     
     // 입금 후 즉시 잔고 확인 (입금이 처리되었는지)